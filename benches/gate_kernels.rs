@@ -0,0 +1,51 @@
+//! Times the single- and two-qubit gate kernels of a [`StateVector`], which can be compared with
+//! and without the `simd` feature:
+//!
+//! ```text
+//! cargo bench --bench gate_kernels
+//! cargo bench --bench gate_kernels --features simd
+//! ```
+
+use std::{hint::black_box, time::Instant};
+
+use num_complex::Complex;
+use rquant::quantum::types::{
+    quantum_gate::QuantumGate, quantum_operators::QuantumOperator, state_vector::StateVector,
+};
+
+const NUM_QUBITS: usize = 20;
+const REPETITIONS: usize = 20;
+
+fn time(name: &str, mut kernel: impl FnMut(usize)) {
+    let start = Instant::now();
+    for repetition in 0..REPETITIONS {
+        kernel(repetition);
+    }
+    let per_gate = start.elapsed() / (REPETITIONS * NUM_QUBITS) as u32;
+    println!("{name}: {per_gate:?} per gate on {NUM_QUBITS} qubits");
+}
+
+fn main() {
+    let mut state_vector = StateVector::new(NUM_QUBITS);
+    let rotation = QuantumGate::new(QuantumOperator::RY(0.3));
+    time("single-qubit gate", |_| {
+        for target in 0..NUM_QUBITS {
+            state_vector.apply_gate(black_box(&rotation), target);
+        }
+    });
+
+    let entry = Complex::new(0.5, 0.0);
+    let two_qubit = vec![
+        vec![entry, entry, entry, entry],
+        vec![entry, -entry, entry, -entry],
+        vec![entry, entry, -entry, -entry],
+        vec![entry, -entry, -entry, entry],
+    ];
+    time("two-qubit unitary", |_| {
+        for target in 0..NUM_QUBITS {
+            let targets = [target, (target + 1) % NUM_QUBITS];
+            state_vector.apply_unitary(black_box(&two_qubit), &targets, &[]);
+        }
+    });
+    black_box(&state_vector);
+}
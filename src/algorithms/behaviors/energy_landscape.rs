@@ -0,0 +1,232 @@
+use std::fmt;
+
+use crate::{
+    algorithms::types::{energy_landscape::EnergyLandscape, vqe::Vqe},
+    quantum::types::{hamiltonian::Hamiltonian, quantum_circuit::QuantumCircuit},
+};
+
+/// The characters of the contour levels, from the lowest to the highest energy.
+const LEVELS: [char; 9] = ['.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+impl EnergyLandscape {
+    /// [`EnergyLandscape::scan`] will get the expectation value of a [`Hamiltonian`] for every
+    /// combination of `resolution` evenly spaced values in each of the `param_ranges` of a
+    /// [`QuantumCircuit`] with one or two parameters.
+    ///
+    /// # Example
+    /// [`EnergyLandscape::scan`] can be used to look at the energy of a single qubit rotation:
+    /// ```rust
+    /// use std::f64::consts::PI;
+    /// use rquant::{
+    ///     algorithms::types::energy_landscape::EnergyLandscape,
+    ///     quantum::types::{
+    ///         hamiltonian::Hamiltonian,
+    ///         pauli::Pauli,
+    ///         pauli_string::PauliString,
+    ///         quantum_circuit::QuantumCircuit,
+    ///         quantum_gate::QuantumGate,
+    ///         quantum_operators::QuantumOperator,
+    ///     },
+    /// };
+    ///
+    /// fn scan_rotation() -> EnergyLandscape {
+    ///     let z = PauliString { negative: false, paulis: vec![Pauli::Z] };
+    ///     EnergyLandscape::scan(
+    ///         &QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RY(0.0)), 0),
+    ///         &Hamiltonian::new(1, &[(1.0, z)]),
+    ///         &[(-PI, PI)],
+    ///         21,
+    ///     )
+    /// }
+    /// ```
+    pub fn scan(
+        circuit: &QuantumCircuit,
+        hamiltonian: &Hamiltonian,
+        param_ranges: &[(f64, f64)],
+        resolution: usize,
+    ) -> Self {
+        assert!(
+            param_ranges.len() == 1 || param_ranges.len() == 2,
+            "Energy landscapes can only scan one or two parameters"
+        );
+        assert!(
+            circuit.parameters().len() == param_ranges.len(),
+            "Circuit must have a parameter for every range"
+        );
+        assert!(
+            resolution >= 2,
+            "Energy landscapes need at least two points in each range"
+        );
+
+        let vqe = Vqe::new(circuit.clone(), hamiltonian.clone());
+        let mut landscape = EnergyLandscape {
+            ranges: param_ranges.to_vec(),
+            resolution,
+            energies: vec![],
+        };
+        let rows = if param_ranges.len() == 2 {
+            resolution
+        } else {
+            1
+        };
+        landscape.energies = (0..rows)
+            .map(|row| {
+                (0..resolution)
+                    .map(|column| vqe.energy(&landscape.parameters(column, row)))
+                    .collect()
+            })
+            .collect();
+        landscape
+    }
+
+    /// [`EnergyLandscape::parameters`] will get the parameters that were scanned at a `column`
+    /// and `row` of the [`EnergyLandscape`], where the `row` is ignored for a single parameter.
+    ///
+    /// # Example
+    /// [`EnergyLandscape::parameters`] can be used to find the parameters of the first point:
+    /// ```rust
+    /// use rquant::algorithms::types::energy_landscape::EnergyLandscape;
+    ///
+    /// fn get_first_parameters(landscape: &EnergyLandscape) -> Vec<f64> {
+    ///     landscape.parameters(0, 0)
+    /// }
+    /// ```
+    pub fn parameters(&self, column: usize, row: usize) -> Vec<f64> {
+        self.ranges
+            .iter()
+            .zip([column, row])
+            .map(|((start, end), point)| {
+                start + (end - start) * point as f64 / (self.resolution - 1) as f64
+            })
+            .collect()
+    }
+
+    /// [`EnergyLandscape::minimum`] will get the parameters and the energy of the lowest point of
+    /// the [`EnergyLandscape`], which is a good place to start an optimizer.
+    ///
+    /// # Example
+    /// [`EnergyLandscape::minimum`] can be used to pick starting parameters:
+    /// ```rust
+    /// use rquant::algorithms::types::energy_landscape::EnergyLandscape;
+    ///
+    /// fn get_starting_parameters(landscape: &EnergyLandscape) -> Vec<f64> {
+    ///     landscape.minimum().0
+    /// }
+    /// ```
+    pub fn minimum(&self) -> (Vec<f64>, f64) {
+        let (column, row, energy) = self
+            .energies
+            .iter()
+            .enumerate()
+            .flat_map(|(row, energies)| {
+                energies
+                    .iter()
+                    .enumerate()
+                    .map(move |(column, energy)| (column, row, *energy))
+            })
+            .fold((0, 0, f64::INFINITY), |lowest, point| {
+                if point.2 < lowest.2 {
+                    point
+                } else {
+                    lowest
+                }
+            });
+        (self.parameters(column, row), energy)
+    }
+
+    /// [`EnergyLandscape::to_csv`] will export every point of an [`EnergyLandscape`] as CSV with a
+    /// column for each parameter, followed by the energy.
+    ///
+    /// # Example
+    /// [`EnergyLandscape::to_csv`] can be used to plot a landscape in a spreadsheet:
+    /// ```rust
+    /// use rquant::algorithms::types::energy_landscape::EnergyLandscape;
+    ///
+    /// fn export_landscape(landscape: &EnergyLandscape) -> String {
+    ///     landscape.to_csv()
+    /// }
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let header: Vec<String> = (0..self.ranges.len())
+            .map(|parameter| format!("parameter_{parameter}"))
+            .collect();
+        let rows: String = self
+            .energies
+            .iter()
+            .enumerate()
+            .flat_map(|(row, energies)| {
+                energies.iter().enumerate().map(move |(column, energy)| {
+                    let parameters: Vec<String> = self
+                        .parameters(column, row)
+                        .iter()
+                        .map(f64::to_string)
+                        .collect();
+                    format!("{},{energy}\n", parameters.join(","))
+                })
+            })
+            .collect();
+        format!("{},energy\n{rows}", header.join(","))
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`EnergyLandscape`].
+impl fmt::Display for EnergyLandscape {
+    /// Gets the [`String`] representation of an [`EnergyLandscape`] as an ASCII contour, with a
+    /// character for the energy level of every point, the highest values of the second parameter
+    /// at the top, followed by a legend. For instance, a scan of $\langle Z \rangle$ over an
+    /// [`QuantumOperator::RY`](crate::quantum::types::quantum_operators::QuantumOperator::RY) and
+    /// an [`QuantumOperator::RX`](crate::quantum::types::quantum_operators::QuantumOperator::RX)
+    /// from $-\pi$ to $\pi$ will be presented as:
+    /// ```text
+    /// @%+:.:+%@
+    /// %#+-:-+#%
+    /// +++++++++
+    /// :-+#%#+-:
+    /// .:+%@%+:.
+    /// :-+#%#+-:
+    /// +++++++++
+    /// %#+-:-+#%
+    /// @%+:.:+%@
+    ///
+    /// energy: . -1.0000  @ 1.0000
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print an [`EnergyLandscape`] to the console:
+    /// ```rust
+    /// use rquant::algorithms::types::energy_landscape::EnergyLandscape;
+    ///
+    /// fn print_landscape(landscape: EnergyLandscape) {
+    ///     println!("{landscape}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (lowest, highest) = self.energies.iter().flatten().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(lowest, highest), energy| (lowest.min(*energy), highest.max(*energy)),
+        );
+        let span = highest - lowest;
+        let top = (LEVELS.len() - 1) as f64;
+
+        for energies in self.energies.iter().rev() {
+            for energy in energies {
+                // A flat landscape has no levels, so every point is drawn as the lowest.
+                let level = if span > 1e-12 {
+                    ((energy - lowest) / span * top).round() as usize
+                } else {
+                    0
+                };
+                write!(f, "{}", LEVELS[level])?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f)?;
+        write!(
+            f,
+            "energy: {} {lowest:.4}  {} {highest:.4}",
+            LEVELS[0],
+            LEVELS[LEVELS.len() - 1]
+        )
+    }
+}
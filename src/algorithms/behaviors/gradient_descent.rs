@@ -0,0 +1,32 @@
+use crate::algorithms::types::{gradient_descent::GradientDescent, optimizer::Optimizer};
+
+impl GradientDescent {
+    /// [`GradientDescent::new`] will create a new [`GradientDescent`] optimizer with a
+    /// `learning_rate`.
+    ///
+    /// # Example
+    /// [`GradientDescent::new`] can be used to create a new [`GradientDescent`] optimizer:
+    /// ```rust
+    /// use rquant::algorithms::types::gradient_descent::GradientDescent;
+    ///
+    /// fn create_optimizer() -> GradientDescent {
+    ///     GradientDescent::new(0.1)
+    /// }
+    /// ```
+    pub fn new(learning_rate: f64) -> Self {
+        assert!(learning_rate > 0.0, "Learning rate must be positive");
+
+        GradientDescent { learning_rate }
+    }
+}
+
+/// Implement the [`Optimizer`] trait for [`GradientDescent`].
+impl Optimizer for GradientDescent {
+    fn step(&mut self, parameters: &[f64], gradient: &[f64]) -> Vec<f64> {
+        parameters
+            .iter()
+            .zip(gradient)
+            .map(|(parameter, slope)| parameter - self.learning_rate * slope)
+            .collect()
+    }
+}
@@ -0,0 +1,181 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::{
+    algorithms::types::{hhl::Hhl, hhl_result::HhlResult},
+    quantum::types::{
+        hamiltonian::Hamiltonian, quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+        state_vector::StateVector,
+    },
+};
+
+/// The index of the ancilla qubit, which is followed by the clock and then the system qubits.
+const ANCILLA: usize = 0;
+
+impl Hhl {
+    /// [`Hhl::new`] will create a new [`Hhl`] for the linear system of a [`Hamiltonian`], with an
+    /// amount of `clock_qubits` and an `evolution_time` for phase estimation.
+    ///
+    /// # Example
+    /// [`Hhl::new`] can be used to solve the system $A = 2I - X$, which has the eigenvalues $1$ and
+    /// $3$ that two clock qubits can represent exactly:
+    /// ```rust
+    /// use rquant::{
+    ///     algorithms::types::hhl::Hhl,
+    ///     quantum::types::{hamiltonian::Hamiltonian, pauli::Pauli, pauli_string::PauliString},
+    /// };
+    ///
+    /// fn create_hhl() -> Hhl {
+    ///     let hamiltonian = Hamiltonian::new(
+    ///         1,
+    ///         &[
+    ///             (2.0, PauliString { negative: false, paulis: vec![Pauli::I] }),
+    ///             (-1.0, PauliString { negative: false, paulis: vec![Pauli::X] }),
+    ///         ],
+    ///     );
+    ///     Hhl::new(hamiltonian, 2, std::f64::consts::PI / 2.0)
+    /// }
+    /// ```
+    pub fn new(hamiltonian: Hamiltonian, clock_qubits: usize, evolution_time: f64) -> Self {
+        assert!(clock_qubits > 0, "HHL must have at least one clock qubit");
+        assert!(evolution_time > 0.0, "HHL evolution time must be positive");
+
+        Hhl {
+            hamiltonian,
+            clock_qubits,
+            evolution_time,
+        }
+    }
+
+    /// [`Hhl::solve`] will prepare the state $|b\rangle$ from the amplitudes of `b`, run [`Hhl`] on
+    /// it, and return the normalized state proportional to $A^{-1}b$, along with how likely the
+    /// algorithm is to succeed.
+    ///
+    /// The solution is exact when every eigenvalue $\lambda$ of $A$ makes $\lambda t / 2\pi$ a
+    /// whole multiple of $2^{-m}$ for $m$ clock qubits, and approximate otherwise.
+    ///
+    /// # Example
+    /// [`Hhl::solve`] can be used to solve for the state of $A^{-1}|0\rangle$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::algorithms::types::hhl::Hhl;
+    ///
+    /// fn solve_for_zero_state(hhl: &Hhl) -> Vec<Complex<f64>> {
+    ///     hhl.solve(&[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]).solution
+    /// }
+    /// ```
+    pub fn solve(&self, b: &[Complex<f64>]) -> HhlResult {
+        let system_qubits = self.hamiltonian.num_qubits;
+        assert!(
+            b.len() == 1 << system_qubits,
+            "HHL input must have an amplitude for every system basis state"
+        );
+        let norm = b
+            .iter()
+            .map(|amplitude| amplitude.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        assert!(norm > 0.0, "HHL input must not be zero");
+
+        let clock: Vec<usize> = (1..=self.clock_qubits).collect();
+        let system: Vec<usize> = (0..system_qubits)
+            .map(|qubit| self.clock_qubits + 1 + qubit)
+            .collect();
+        let mut state_vector = StateVector::new(1 + self.clock_qubits + system_qubits);
+        for (index, amplitude) in b.iter().enumerate() {
+            state_vector.amplitudes[index] = amplitude / norm;
+        }
+
+        // Estimate the eigenvalues of A onto the clock qubits.
+        self.phase_estimation(&mut state_vector, &clock, &system, false);
+
+        // Rotate the ancilla by an amount inversely proportional to each eigenvalue.
+        let clock_values = 1 << self.clock_qubits;
+        let smallest_eigenvalue = 2.0 * PI / (clock_values as f64 * self.evolution_time);
+        for value in 1..clock_values {
+            let eigenvalue = value as f64 * smallest_eigenvalue;
+            let angle = 2.0 * (smallest_eigenvalue / eigenvalue).asin();
+            let rotation = QuantumGate::new(QuantumOperator::RY(angle)).matrix();
+            let unset: Vec<usize> = clock
+                .iter()
+                .enumerate()
+                .filter(|(bit, _)| value & (1 << (self.clock_qubits - 1 - bit)) == 0)
+                .map(|(_, &qubit)| qubit)
+                .collect();
+            for &qubit in &unset {
+                state_vector.apply_gate(&QuantumGate::NOT, qubit);
+            }
+            state_vector.apply_unitary(&rotation, &[ANCILLA], &clock);
+            for &qubit in &unset {
+                state_vector.apply_gate(&QuantumGate::NOT, qubit);
+            }
+        }
+
+        // Uncompute the eigenvalues, so the clock qubits return to |0...0>.
+        self.phase_estimation(&mut state_vector, &clock, &system, true);
+
+        let solution: Vec<Complex<f64>> = (0..b.len())
+            .map(|index| state_vector.amplitudes[state_vector.mask(ANCILLA) | index])
+            .collect();
+        let success_probability: f64 = solution.iter().map(|amplitude| amplitude.norm_sqr()).sum();
+        HhlResult {
+            solution: solution
+                .iter()
+                .map(|amplitude| amplitude / success_probability.sqrt())
+                .collect(),
+            success_probability,
+        }
+    }
+
+    /// [`Hhl::phase_estimation`] will estimate the phases of $e^{iAt}$ onto the `clock` qubits, or
+    /// undo the estimation when `inverse` is `true`.
+    fn phase_estimation(
+        &self,
+        state_vector: &mut StateVector,
+        clock: &[usize],
+        system: &[usize],
+        inverse: bool,
+    ) {
+        let controlled_evolutions = |state_vector: &mut StateVector, direction: f64| {
+            for (bit, &qubit) in clock.iter().enumerate() {
+                let power = (1 << (clock.len() - 1 - bit)) as f64;
+                let evolution = self
+                    .hamiltonian
+                    .propagator(-direction * power * self.evolution_time);
+                state_vector.apply_unitary(&evolution, system, &[qubit]);
+            }
+        };
+
+        if !inverse {
+            for &qubit in clock {
+                state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+            }
+            controlled_evolutions(state_vector, 1.0);
+            state_vector.apply_unitary(&fourier_matrix(clock.len(), true), clock, &[]);
+        } else {
+            state_vector.apply_unitary(&fourier_matrix(clock.len(), false), clock, &[]);
+            controlled_evolutions(state_vector, -1.0);
+            for &qubit in clock {
+                state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+            }
+        }
+    }
+}
+
+/// [`fourier_matrix`] will get the matrix of the quantum Fourier transform on an amount of
+/// `qubits`, or of it's inverse when `inverse` is `true`.
+fn fourier_matrix(qubits: usize, inverse: bool) -> Vec<Vec<Complex<f64>>> {
+    let dimension = 1 << qubits;
+    let direction = if inverse { -1.0 } else { 1.0 };
+    (0..dimension)
+        .map(|row| {
+            (0..dimension)
+                .map(|column| {
+                    let angle = direction * 2.0 * PI * (row * column) as f64 / dimension as f64;
+                    Complex::from_polar(1.0 / (dimension as f64).sqrt(), angle)
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,152 @@
+use std::fmt;
+
+use rand::Rng;
+
+use crate::{
+    algorithms::types::mermin_ghz::MerminGhz,
+    quantum::types::{basis::Basis, state_vector::StateVector},
+    simulation::behaviors::simulation_outcome::sample_index,
+};
+
+impl MerminGhz {
+    /// [`MerminGhz::LOCAL_REALISM_BOUND`] is the largest Mermin value that any local hidden
+    /// variable theory can explain.
+    pub const LOCAL_REALISM_BOUND: f64 = 2.0;
+
+    /// [`MerminGhz::run`] will prepare and measure a three-qubit GHZ state `shots` times in each of
+    /// the $XXX$, $XYY$, $YXY$, and $YYX$ bases, and get the average parity of each basis.
+    ///
+    /// # Example
+    /// [`MerminGhz::run`] can be used to show the contradiction with local realism:
+    /// ```rust
+    /// use rquant::algorithms::types::mermin_ghz::MerminGhz;
+    ///
+    /// fn disproves_local_realism() -> bool {
+    ///     MerminGhz::run(1000).violates_local_realism()
+    /// }
+    /// ```
+    pub fn run(shots: usize) -> Self {
+        Self::run_with(shots, &mut rand::rng())
+    }
+
+    /// [`MerminGhz::run_with`] will run the Mermin–GHZ experiment in the same way as
+    /// [`MerminGhz::run`], using a random number generator `rng` for the measurements instead of
+    /// the thread's generator.
+    ///
+    /// # Example
+    /// [`MerminGhz::run_with`] can be used to reproduce the Mermin–GHZ experiment:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::algorithms::types::mermin_ghz::MerminGhz;
+    ///
+    /// fn run_seeded_experiment(seed: u64) -> MerminGhz {
+    ///     MerminGhz::run_with(1000, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn run_with(shots: usize, rng: &mut impl Rng) -> Self {
+        assert!(shots > 0, "Mermin-GHZ experiment needs at least one shot");
+
+        let mut parity = |bases: [Basis; 3]| {
+            let mut state_vector = StateVector::ghz(3);
+            for (qubit, basis) in bases.into_iter().enumerate() {
+                state_vector.rotate_basis(basis, &[qubit]);
+            }
+            let probabilities: Vec<f64> = state_vector
+                .amplitudes
+                .iter()
+                .map(|amplitude| amplitude.norm_sqr())
+                .collect();
+
+            // Each measured |1> flips the sign of the parity.
+            let total: f64 = (0..shots)
+                .map(
+                    |_| match sample_index(&probabilities, rng.random()).count_ones() % 2 {
+                        0 => 1.0,
+                        _ => -1.0,
+                    },
+                )
+                .sum();
+            total / shots as f64
+        };
+
+        MerminGhz {
+            shots,
+            xxx: parity([Basis::X, Basis::X, Basis::X]),
+            xyy: parity([Basis::X, Basis::Y, Basis::Y]),
+            yxy: parity([Basis::Y, Basis::X, Basis::Y]),
+            yyx: parity([Basis::Y, Basis::Y, Basis::X]),
+        }
+    }
+
+    /// [`MerminGhz::mermin_value`] will get the Mermin value
+    /// $\langle XXX \rangle - \langle XYY \rangle - \langle YXY \rangle - \langle YYX \rangle$,
+    /// which is at most [`MerminGhz::LOCAL_REALISM_BOUND`] for local realism, and $4$ for an ideal
+    /// GHZ state.
+    ///
+    /// # Example
+    /// [`MerminGhz::mermin_value`] can be used to see how far past the bound an experiment got:
+    /// ```rust
+    /// use rquant::algorithms::types::mermin_ghz::MerminGhz;
+    ///
+    /// fn get_violation(experiment: &MerminGhz) -> f64 {
+    ///     experiment.mermin_value() - MerminGhz::LOCAL_REALISM_BOUND
+    /// }
+    /// ```
+    pub fn mermin_value(&self) -> f64 {
+        self.xxx - self.xyy - self.yxy - self.yyx
+    }
+
+    /// [`MerminGhz::violates_local_realism`] returns `true` if the
+    /// [`mermin_value`](MerminGhz::mermin_value) of the experiment is larger than
+    /// [`MerminGhz::LOCAL_REALISM_BOUND`], and `false` otherwise.
+    ///
+    /// # Example
+    /// [`MerminGhz::violates_local_realism`] can be used to check an experiment:
+    /// ```rust
+    /// use rquant::algorithms::types::mermin_ghz::MerminGhz;
+    ///
+    /// fn report(experiment: &MerminGhz) {
+    ///     if experiment.violates_local_realism() {
+    ///         println!("no local hidden variables can explain these parities");
+    ///     }
+    /// }
+    /// ```
+    pub fn violates_local_realism(&self) -> bool {
+        self.mermin_value() > Self::LOCAL_REALISM_BOUND
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`MerminGhz`].
+impl fmt::Display for MerminGhz {
+    /// Will return the average parity of every basis of a [`MerminGhz`] experiment, followed by
+    /// it's Mermin value and the local realism bound:
+    /// ```text
+    /// XXX: +1.0000
+    /// XYY: -1.0000
+    /// YXY: -1.0000
+    /// YYX: -1.0000
+    /// mermin value: 4.0000 (local realism allows at most 2)
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`MerminGhz`] experiment to the console:
+    /// ```rust
+    /// use rquant::algorithms::types::mermin_ghz::MerminGhz;
+    ///
+    /// fn print_experiment(experiment: MerminGhz) {
+    ///     println!("{experiment}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "XXX: {:+.4}", self.xxx)?;
+        writeln!(f, "XYY: {:+.4}", self.xyy)?;
+        writeln!(f, "YXY: {:+.4}", self.yxy)?;
+        writeln!(f, "YYX: {:+.4}", self.yyx)?;
+        write!(
+            f,
+            "mermin value: {:.4} (local realism allows at most {})",
+            self.mermin_value(),
+            Self::LOCAL_REALISM_BOUND
+        )
+    }
+}
@@ -0,0 +1,233 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    algorithms::types::{
+        order_finding::OrderFinding, quantum_fourier_transform::QuantumFourierTransform,
+    },
+    quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector},
+    simulation::behaviors::simulation_outcome::sample_index,
+};
+
+impl OrderFinding {
+    /// [`OrderFinding::MAX_MODULUS`] is the largest modulus that [`OrderFinding`] will simulate.
+    pub const MAX_MODULUS: u64 = 21;
+
+    /// [`OrderFinding::new`] will create a new [`OrderFinding`] for a `base` and `modulus`, using
+    /// twice as many counting qubits as it takes to store the `modulus`.
+    ///
+    /// The `base` must share no factors with the `modulus`, and the `modulus` can be at most
+    /// [`OrderFinding::MAX_MODULUS`].
+    ///
+    /// # Example
+    /// [`OrderFinding::new`] can be used to find the order of 7 modulo 15:
+    /// ```rust
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn create_order_finding() -> OrderFinding {
+    ///     OrderFinding::new(7, 15)
+    /// }
+    /// ```
+    pub fn new(base: u64, modulus: u64) -> Self {
+        assert!(
+            (3..=Self::MAX_MODULUS).contains(&modulus),
+            "Order finding modulus must be between 3 and {}",
+            Self::MAX_MODULUS
+        );
+        assert!(
+            base > 1 && base < modulus && greatest_common_divisor(base, modulus) == 1,
+            "Order finding base must be coprime with the modulus"
+        );
+
+        OrderFinding {
+            base,
+            modulus,
+            precision: 2 * (u64::BITS - modulus.leading_zeros()),
+        }
+    }
+
+    /// [`OrderFinding::phase_distribution`] will get the exact probability of measuring each value of
+    /// the counting qubits, after the modular exponentiation oracle and the inverse quantum Fourier
+    /// transform, where the first counting qubit is the most significant bit.
+    ///
+    /// The counting and work qubits are simulated together on a [`StateVector`], where each counting
+    /// qubit controls multiplying the work qubits by a power of the `base`, so they become entangled
+    /// in the $\frac{1}{\sqrt{Q}}\sum_x |x\rangle|a^x \bmod N\rangle$ state before the inverse
+    /// [`QuantumFourierTransform`] is run on the counting qubits.
+    ///
+    /// # Example
+    /// [`OrderFinding::phase_distribution`] can be used to see where the phase peaks are:
+    /// ```rust
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn get_phase_distribution() -> Vec<f64> {
+    ///     OrderFinding::new(7, 15).phase_distribution()
+    /// }
+    /// ```
+    pub fn phase_distribution(&self) -> Vec<f64> {
+        let counting_qubits: Vec<usize> = (0..self.precision as usize).collect();
+        self.run().marginal_probabilities(&counting_qubits)
+    }
+
+    /// [`OrderFinding::measure`] will run the order finding circuit on a [`StateVector`], and
+    /// measure the value of the counting qubits.
+    ///
+    /// # Example
+    /// [`OrderFinding::measure`] can be used to run the quantum part of order finding once:
+    /// ```rust
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn measure_phase() -> u64 {
+    ///     OrderFinding::new(7, 15).measure()
+    /// }
+    /// ```
+    pub fn measure(&self) -> u64 {
+        self.measure_with(&mut rand::rng())
+    }
+
+    /// [`OrderFinding::measure_with`] will measure the value of the counting qubits in the same
+    /// way as [`OrderFinding::measure`], using a random number generator `rng` instead of the
+    /// thread's generator.
+    ///
+    /// # Example
+    /// [`OrderFinding::measure_with`] can be used to reproduce a measured phase:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn measure_seeded_phase(seed: u64) -> u64 {
+    ///     OrderFinding::new(7, 15).measure_with(&mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn measure_with(&self, rng: &mut impl Rng) -> u64 {
+        let mut state_vector = self.run();
+        // A measured qubit is "truthy" when it is |0>, so each bit of the value is flipped.
+        (0..self.precision as usize).fold(0, |value, target| {
+            (value << 1) | u64::from(!state_vector.measure_with(target, rng))
+        })
+    }
+
+    /// [`OrderFinding::find_order`] will run [`OrderFinding::measure`] up to `attempts` times, and use
+    /// the continued fraction expansion of each measured phase to find the order of the `base`.
+    ///
+    /// Returns [`None`] if the order was not found in any attempt.
+    ///
+    /// # Example
+    /// [`OrderFinding::find_order`] can be used to find the order of 7 modulo 15:
+    /// ```rust
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn find_order_of_seven() -> Option<u64> {
+    ///     OrderFinding::new(7, 15).find_order(10)
+    /// }
+    /// ```
+    pub fn find_order(&self, attempts: usize) -> Option<u64> {
+        self.find_order_with(attempts, &mut rand::rng())
+    }
+
+    /// [`OrderFinding::find_order_with`] will find the order of the `base` in the same way as
+    /// [`OrderFinding::find_order`], using a random number generator `rng` for the measured phases
+    /// instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`OrderFinding::find_order_with`] can be used to reproduce a search for the order of 7
+    /// modulo 15:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::algorithms::types::order_finding::OrderFinding;
+    ///
+    /// fn find_seeded_order_of_seven(seed: u64) -> Option<u64> {
+    ///     OrderFinding::new(7, 15).find_order_with(10, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn find_order_with(&self, attempts: usize, rng: &mut impl Rng) -> Option<u64> {
+        // Every attempt runs the same circuit, so it is only simulated once and sampled from.
+        let distribution = self.phase_distribution();
+        (0..attempts).find_map(|_| {
+            let phase = sample_index(&distribution, rng.random()) as u64;
+            convergent_denominators(phase, 1 << self.precision, self.modulus)
+                .into_iter()
+                .find(|&order| modular_power(self.base, order, self.modulus) == 1)
+        })
+    }
+
+    /// [`OrderFinding::run`] will prepare the counting qubits in superposition and the work qubits
+    /// in $|1\rangle$, apply every controlled $U^{2^k}$ oracle, where $U|y\rangle = |ay \bmod N\rangle$,
+    /// then apply the inverse [`QuantumFourierTransform`] to the counting qubits.
+    fn run(&self) -> StateVector {
+        let precision = self.precision as usize;
+        let work_qubits = (u64::BITS - self.modulus.leading_zeros()) as usize;
+        let mut state_vector = StateVector::new(precision + work_qubits);
+        let counting: Vec<usize> = (0..precision).collect();
+        let work: Vec<usize> = (precision..precision + work_qubits).collect();
+
+        state_vector.apply_gate(&QuantumGate::NOT, precision + work_qubits - 1);
+        for &qubit in &counting {
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+        }
+        // The last counting qubit is the least significant bit, so it controls a^1.
+        let mut factor = self.base;
+        for &control in counting.iter().rev() {
+            let oracle = multiplication_matrix(factor, self.modulus, work_qubits);
+            state_vector.apply_unitary(&oracle, &work, &[control]);
+            factor = factor * factor % self.modulus;
+        }
+        QuantumFourierTransform::new(precision).apply_inverse(&mut state_vector, &counting);
+        state_vector
+    }
+}
+
+/// [`multiplication_matrix`] will get the permutation matrix of $|y\rangle \to |fy \bmod N\rangle$
+/// on `qubits` for a `factor` $f$ and `modulus` $N$, which leaves every $y \ge N$ unchanged.
+fn multiplication_matrix(factor: u64, modulus: u64, qubits: usize) -> Vec<Vec<Complex<f64>>> {
+    let product = |value: usize| {
+        if (value as u64) < modulus {
+            (value as u64 * factor % modulus) as usize
+        } else {
+            value
+        }
+    };
+    let dimension = 1 << qubits;
+    (0..dimension)
+        .map(|row| {
+            (0..dimension)
+                .map(|column| match product(column) == row {
+                    true => Complex::new(1.0, 0.0),
+                    false => Complex::new(0.0, 0.0),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// [`convergent_denominators`] will get the denominators below `limit` of every convergent of the
+/// continued fraction expansion of `numerator / denominator`.
+fn convergent_denominators(numerator: u64, denominator: u64, limit: u64) -> Vec<u64> {
+    let (mut numerator, mut denominator) = (numerator, denominator);
+    let (mut previous, mut current) = (0, 1);
+    let mut denominators = vec![];
+    while numerator != 0 {
+        let term = denominator / numerator;
+        (denominator, numerator) = (numerator, denominator % numerator);
+        (previous, current) = (current, term * current + previous);
+        if current >= limit {
+            break;
+        }
+        denominators.push(current);
+    }
+    denominators
+}
+
+/// [`modular_power`] will get $base^{exponent} \bmod modulus$.
+pub(crate) fn modular_power(base: u64, exponent: u64, modulus: u64) -> u64 {
+    (0..exponent).fold(1, |value, _| value * base % modulus)
+}
+
+/// [`greatest_common_divisor`] will get the largest integer that divides both `a` and `b`.
+pub(crate) fn greatest_common_divisor(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        greatest_common_divisor(b, a % b)
+    }
+}
@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::{
+    algorithms::types::{
+        qft_approximation::QftApproximation, quantum_fourier_transform::QuantumFourierTransform,
+    },
+    quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector},
+};
+
+impl QuantumFourierTransform {
+    /// [`QuantumFourierTransform::new`] will create the exact [`QuantumFourierTransform`] on an
+    /// amount of `num_qubits`, which keeps every controlled phase rotation.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::new`] can be used to transform three qubits:
+    /// ```rust
+    /// use rquant::algorithms::types::quantum_fourier_transform::QuantumFourierTransform;
+    ///
+    /// fn create_qft() -> QuantumFourierTransform {
+    ///     QuantumFourierTransform::new(3)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        assert!(
+            num_qubits > 0,
+            "Fourier transform must have at least one qubit"
+        );
+
+        QuantumFourierTransform {
+            num_qubits,
+            threshold: 0.0,
+        }
+    }
+
+    /// [`QuantumFourierTransform::approximate_qft`] will create a copy of the
+    /// [`QuantumFourierTransform`] that drops every controlled phase rotation with an angle below
+    /// `threshold`, trading accuracy for a shallower circuit.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::approximate_qft`] can be used to drop the rotations smaller than
+    /// $\pi/4$:
+    /// ```rust
+    /// use rquant::algorithms::types::quantum_fourier_transform::QuantumFourierTransform;
+    ///
+    /// fn create_approximate_qft() -> QuantumFourierTransform {
+    ///     QuantumFourierTransform::new(5).approximate_qft(std::f64::consts::FRAC_PI_4)
+    /// }
+    /// ```
+    pub fn approximate_qft(&self, threshold: f64) -> Self {
+        assert!(threshold >= 0.0, "Rotation cutoff must not be negative");
+
+        QuantumFourierTransform {
+            num_qubits: self.num_qubits,
+            threshold,
+        }
+    }
+
+    /// [`QuantumFourierTransform::rotations`] will get every controlled phase rotation that is kept,
+    /// in the order they are applied, as the control qubit, the target qubit, and the angle.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::rotations`] can be used to count the two-qubit gates of a transform:
+    /// ```rust
+    /// use rquant::algorithms::types::quantum_fourier_transform::QuantumFourierTransform;
+    ///
+    /// fn count_rotations(qft: &QuantumFourierTransform) -> usize {
+    ///     qft.rotations().len()
+    /// }
+    /// ```
+    pub fn rotations(&self) -> Vec<(usize, usize, f64)> {
+        (0..self.num_qubits)
+            .flat_map(|target| {
+                (target + 1..self.num_qubits)
+                    .map(move |control| (control, target, PI / (1 << (control - target)) as f64))
+            })
+            .filter(|&(_, _, angle)| angle >= self.threshold)
+            .collect()
+    }
+
+    /// [`QuantumFourierTransform::apply`] will apply the [`QuantumFourierTransform`] to the `qubits`
+    /// of a [`StateVector`], where the first qubit is the most significant bit.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::apply`] can be used to transform the first two qubits of a state:
+    /// ```rust
+    /// use rquant::{
+    ///     algorithms::types::quantum_fourier_transform::QuantumFourierTransform,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn transform_first_two_qubits(state_vector: &mut StateVector) {
+    ///     QuantumFourierTransform::new(2).apply(state_vector, &[0, 1])
+    /// }
+    /// ```
+    pub fn apply(&self, state_vector: &mut StateVector, qubits: &[usize]) {
+        assert!(
+            qubits.len() == self.num_qubits,
+            "Fourier transform must be applied to every one of it's qubits"
+        );
+
+        let rotations = self.rotations();
+        for (position, &qubit) in qubits.iter().enumerate() {
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+            for &(control, _, angle) in rotations.iter().filter(|rotation| rotation.1 == position) {
+                apply_controlled_phase(state_vector, angle, qubit, qubits[control]);
+            }
+        }
+
+        // The cascade leaves the output bits reversed, so swap them back into order.
+        for position in 0..self.num_qubits / 2 {
+            state_vector.apply_swap(qubits[position], qubits[self.num_qubits - 1 - position]);
+        }
+    }
+
+    /// [`QuantumFourierTransform::apply_inverse`] will undo [`QuantumFourierTransform::apply`] on the
+    /// `qubits` of a [`StateVector`], by running it's gates backwards with opposite rotations.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::apply_inverse`] can be used to read a phase out of a counting
+    /// register:
+    /// ```rust
+    /// use rquant::{
+    ///     algorithms::types::quantum_fourier_transform::QuantumFourierTransform,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn read_phase_of_first_two_qubits(state_vector: &mut StateVector) {
+    ///     QuantumFourierTransform::new(2).apply_inverse(state_vector, &[0, 1])
+    /// }
+    /// ```
+    pub fn apply_inverse(&self, state_vector: &mut StateVector, qubits: &[usize]) {
+        assert!(
+            qubits.len() == self.num_qubits,
+            "Fourier transform must be applied to every one of it's qubits"
+        );
+
+        for position in 0..self.num_qubits / 2 {
+            state_vector.apply_swap(qubits[position], qubits[self.num_qubits - 1 - position]);
+        }
+        let rotations = self.rotations();
+        for (position, &qubit) in qubits.iter().enumerate().rev() {
+            for &(control, _, angle) in rotations.iter().filter(|rotation| rotation.1 == position) {
+                apply_controlled_phase(state_vector, -angle, qubit, qubits[control]);
+            }
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+        }
+    }
+
+    /// [`QuantumFourierTransform::unitary`] will get the $2^n \times 2^n$ matrix of the
+    /// [`QuantumFourierTransform`], including any dropped rotations.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::unitary`] can be used to inspect an approximate transform:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::algorithms::types::quantum_fourier_transform::QuantumFourierTransform;
+    ///
+    /// fn approximate_matrix() -> Vec<Vec<Complex<f64>>> {
+    ///     QuantumFourierTransform::new(3).approximate_qft(1.0).unitary()
+    /// }
+    /// ```
+    pub fn unitary(&self) -> Vec<Vec<Complex<f64>>> {
+        let dimension = 1 << self.num_qubits;
+        let qubits: Vec<usize> = (0..self.num_qubits).collect();
+        let columns: Vec<Vec<Complex<f64>>> = (0..dimension)
+            .map(|column| {
+                let mut amplitudes = vec![Complex::new(0.0, 0.0); dimension];
+                amplitudes[column] = Complex::new(1.0, 0.0);
+                let mut state_vector = StateVector {
+                    num_qubits: self.num_qubits,
+                    amplitudes,
+                };
+                self.apply(&mut state_vector, &qubits);
+                state_vector.amplitudes
+            })
+            .collect();
+        (0..dimension)
+            .map(|row| columns.iter().map(|column| column[row]).collect())
+            .collect()
+    }
+
+    /// [`QuantumFourierTransform::fidelity`] will get the process fidelity between the
+    /// [`QuantumFourierTransform`] and the exact transform on the same amount of qubits.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::fidelity`] can be used to check how much accuracy a cutoff costs:
+    /// ```rust
+    /// use rquant::algorithms::types::quantum_fourier_transform::QuantumFourierTransform;
+    ///
+    /// fn cutoff_fidelity(threshold: f64) -> f64 {
+    ///     QuantumFourierTransform::new(4).approximate_qft(threshold).fidelity()
+    /// }
+    /// ```
+    pub fn fidelity(&self) -> f64 {
+        let exact = QuantumFourierTransform::new(self.num_qubits).unitary();
+        let approximate = self.unitary();
+        let trace: Complex<f64> = exact
+            .iter()
+            .flatten()
+            .zip(approximate.iter().flatten())
+            .map(|(first, second)| first.conj() * second)
+            .sum();
+        trace.norm_sqr() / (exact.len() * exact.len()) as f64
+    }
+
+    /// [`QuantumFourierTransform::cutoff_report`] will get a [`QftApproximation`] for each of the
+    /// `thresholds`, to show how the fidelity falls as more controlled phase rotations are dropped.
+    ///
+    /// # Example
+    /// [`QuantumFourierTransform::cutoff_report`] can be used to compare a few cutoffs:
+    /// ```rust
+    /// use rquant::algorithms::types::{
+    ///     qft_approximation::QftApproximation,
+    ///     quantum_fourier_transform::QuantumFourierTransform,
+    /// };
+    ///
+    /// fn compare_cutoffs() -> Vec<QftApproximation> {
+    ///     QuantumFourierTransform::new(4).cutoff_report(&[0.0, 0.2, 0.5, 1.0])
+    /// }
+    /// ```
+    pub fn cutoff_report(&self, thresholds: &[f64]) -> Vec<QftApproximation> {
+        thresholds
+            .iter()
+            .map(|&threshold| {
+                let approximation = self.approximate_qft(threshold);
+                QftApproximation {
+                    threshold,
+                    rotations: approximation.rotations().len(),
+                    fidelity: approximation.fidelity(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// [`apply_controlled_phase`] will rotate the phase of the $|1\rangle$ state of the `target` qubit of
+/// a [`StateVector`] by an `angle`, only where the `control` qubit is $|1\rangle$.
+fn apply_controlled_phase(
+    state_vector: &mut StateVector,
+    angle: f64,
+    target: usize,
+    control: usize,
+) {
+    let phase = vec![
+        vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+        vec![Complex::new(0.0, 0.0), Complex::from_polar(1.0, angle)],
+    ];
+    state_vector.apply_unitary(&phase, &[target], &[control]);
+}
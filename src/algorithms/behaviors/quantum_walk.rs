@@ -0,0 +1,133 @@
+use num_complex::Complex;
+
+use crate::{
+    algorithms::types::{quantum_walk::QuantumWalk, walk_topology::WalkTopology},
+    quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator, qubit::Qubit},
+};
+
+impl QuantumWalk {
+    /// [`QuantumWalk::new`] will create a Hadamard [`QuantumWalk`] over an amount of `positions`,
+    /// starting at the `start` position with a $|0\rangle$ coin.
+    ///
+    /// # Example
+    /// [`QuantumWalk::new`] can be used to create a walk on a cycle of eight positions:
+    /// ```rust
+    /// use rquant::algorithms::types::{quantum_walk::QuantumWalk, walk_topology::WalkTopology};
+    ///
+    /// fn create_cycle_walk() -> QuantumWalk {
+    ///     QuantumWalk::new(WalkTopology::Cycle, 8, 0)
+    /// }
+    /// ```
+    pub fn new(topology: WalkTopology, positions: usize, start: usize) -> Self {
+        assert!(start < positions, "Invalid walk start position");
+
+        QuantumWalk {
+            topology,
+            positions,
+            start,
+            coin: QuantumOperator::SUPERPOSITION,
+            initial_coin: Qubit::zero(),
+        }
+    }
+
+    /// [`QuantumWalk::with_coin`] will replace the `coin` operator and `initial_coin` state of a
+    /// [`QuantumWalk`], then return the modified [`QuantumWalk`].
+    ///
+    /// # Example
+    /// [`QuantumWalk::with_coin`] can be used to start a Hadamard walk with a balanced coin, which
+    /// spreads the walker out symmetrically:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::{
+    ///     algorithms::types::{quantum_walk::QuantumWalk, walk_topology::WalkTopology},
+    ///     quantum::types::{
+    ///         quantum_operators::QuantumOperator,
+    ///         quantum_position::QuantumPosition,
+    ///         qubit::Qubit,
+    ///     },
+    /// };
+    ///
+    /// fn create_symmetric_walk() -> QuantumWalk {
+    ///     let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+    ///     let coin = Qubit::new(QuantumPosition::new(
+    ///         Complex::new(amplitude, 0.0),
+    ///         Complex::new(0.0, amplitude),
+    ///     ));
+    ///     QuantumWalk::new(WalkTopology::Line, 41, 20).with_coin(QuantumOperator::SUPERPOSITION, coin)
+    /// }
+    /// ```
+    pub fn with_coin(mut self, coin: QuantumOperator, initial_coin: Qubit) -> Self {
+        self.coin = coin;
+        self.initial_coin = initial_coin;
+        self
+    }
+
+    /// [`QuantumWalk::probabilities`] will run the [`QuantumWalk`] for an amount of `steps`, and get
+    /// the probability of finding the walker at each position.
+    ///
+    /// # Example
+    /// [`QuantumWalk::probabilities`] can be used to get the most likely position after a walk:
+    /// ```rust
+    /// use rquant::algorithms::types::quantum_walk::QuantumWalk;
+    ///
+    /// fn most_likely_position(walk: &QuantumWalk, steps: usize) -> usize {
+    ///     let probabilities = walk.probabilities(steps);
+    ///     (0..probabilities.len())
+    ///         .max_by(|&a, &b| probabilities[a].total_cmp(&probabilities[b]))
+    ///         .unwrap_or(walk.start)
+    /// }
+    /// ```
+    pub fn probabilities(&self, steps: usize) -> Vec<f64> {
+        let gate = QuantumGate::new(self.coin.clone());
+        let zero = Complex::new(0.0, 0.0);
+
+        // Each position holds the amplitudes of the coin being |0> and |1>.
+        let mut amplitudes = vec![[zero, zero]; self.positions];
+        amplitudes[self.start] = [
+            self.initial_coin.initial_position(),
+            self.initial_coin.possible_position(),
+        ];
+
+        for _ in 0..steps {
+            let mut shifted = vec![[zero, zero]; self.positions];
+            for (position, [heads, tails]) in amplitudes.iter().enumerate() {
+                let flipped = [
+                    gate.transform[0].initial_position * heads
+                        + gate.transform[0].possible_position * tails,
+                    gate.transform[1].initial_position * heads
+                        + gate.transform[1].possible_position * tails,
+                ];
+                let (left, right) = self.neighbors(position);
+                match left {
+                    Some(left) => shifted[left][0] += flipped[0],
+                    None => shifted[position][1] += flipped[0],
+                }
+                match right {
+                    Some(right) => shifted[right][1] += flipped[1],
+                    None => shifted[position][0] += flipped[1],
+                }
+            }
+            amplitudes = shifted;
+        }
+
+        amplitudes
+            .iter()
+            .map(|[heads, tails]| heads.norm_sqr() + tails.norm_sqr())
+            .collect()
+    }
+
+    /// [`QuantumWalk::neighbors`] will get the positions to the left and right of a `position`, or
+    /// [`None`] where a [`WalkTopology::Line`] ends.
+    fn neighbors(&self, position: usize) -> (Option<usize>, Option<usize>) {
+        match self.topology {
+            WalkTopology::Line => (
+                position.checked_sub(1),
+                Some(position + 1).filter(|&right| right < self.positions),
+            ),
+            WalkTopology::Cycle => (
+                Some((position + self.positions - 1) % self.positions),
+                Some((position + 1) % self.positions),
+            ),
+        }
+    }
+}
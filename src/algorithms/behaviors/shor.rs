@@ -0,0 +1,67 @@
+use rand::Rng;
+
+use crate::algorithms::{
+    behaviors::order_finding::{greatest_common_divisor, modular_power},
+    types::{order_finding::OrderFinding, shor::Shor},
+};
+
+impl Shor {
+    /// [`Shor::factor`] will find two non-trivial factors of a composite `modulus` of at most
+    /// [`OrderFinding::MAX_MODULUS`], by running [`OrderFinding`] on random bases up to `attempts` times.
+    ///
+    /// Returns [`None`] if no factors were found, which is always the case for a prime `modulus`, a
+    /// `modulus` below 4, or a `modulus` above [`OrderFinding::MAX_MODULUS`].
+    ///
+    /// # Example
+    /// [`Shor::factor`] can be used to factor 15:
+    /// ```rust
+    /// use rquant::algorithms::types::shor::Shor;
+    ///
+    /// fn factor_fifteen() -> Option<(u64, u64)> {
+    ///     Shor::factor(15, 20)
+    /// }
+    /// ```
+    pub fn factor(modulus: u64, attempts: usize) -> Option<(u64, u64)> {
+        Self::factor_with(modulus, attempts, &mut rand::rng())
+    }
+
+    /// [`Shor::factor_with`] will find two non-trivial factors of a composite `modulus` in the same
+    /// way as [`Shor::factor`], using a random number generator `rng` for the bases and the
+    /// [`OrderFinding`] instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`Shor::factor_with`] can be used to reproduce the factoring of 21:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::algorithms::types::shor::Shor;
+    ///
+    /// fn factor_seeded_twenty_one(seed: u64) -> Option<(u64, u64)> {
+    ///     Shor::factor_with(21, 20, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn factor_with(modulus: u64, attempts: usize, rng: &mut impl Rng) -> Option<(u64, u64)> {
+        // 4 is the smallest composite number, and anything larger than the limit is not simulated.
+        if !(4..=OrderFinding::MAX_MODULUS).contains(&modulus) {
+            return None;
+        }
+        if modulus.is_multiple_of(2) {
+            return Some((2, modulus / 2));
+        }
+
+        (0..attempts).find_map(|_| {
+            let base = rng.random_range(2..modulus);
+            let divisor = greatest_common_divisor(base, modulus);
+            if divisor > 1 {
+                return Some((divisor, modulus / divisor));
+            }
+
+            let order = OrderFinding::new(base, modulus).find_order_with(1, rng)?;
+            let half_power = modular_power(base, order / 2, modulus);
+            if !order.is_multiple_of(2) || half_power == modulus - 1 {
+                return None;
+            }
+            let divisor = greatest_common_divisor(half_power + 1, modulus);
+            (divisor > 1 && divisor < modulus).then_some((divisor, modulus / divisor))
+        })
+    }
+}
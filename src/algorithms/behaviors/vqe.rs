@@ -0,0 +1,126 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    algorithms::types::{optimizer::Optimizer, vqe::Vqe, vqe_result::VqeResult},
+    quantum::types::{
+        hamiltonian::Hamiltonian, quantum_circuit::QuantumCircuit, state_vector::StateVector,
+    },
+};
+
+impl Vqe {
+    /// [`Vqe::new`] will create a new [`Vqe`] that minimizes the energy of a [`Hamiltonian`] using
+    /// an `ansatz` [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`Vqe::new`] can be used to find the ground state of a single qubit:
+    /// ```rust
+    /// use rquant::{
+    ///     algorithms::types::vqe::Vqe,
+    ///     quantum::types::{
+    ///         hamiltonian::Hamiltonian,
+    ///         pauli::Pauli,
+    ///         pauli_string::PauliString,
+    ///         quantum_circuit::QuantumCircuit,
+    ///         quantum_gate::QuantumGate,
+    ///         quantum_operators::QuantumOperator,
+    ///     },
+    /// };
+    ///
+    /// fn create_single_qubit_vqe() -> Vqe {
+    ///     let z = PauliString { negative: false, paulis: vec![Pauli::Z] };
+    ///     Vqe::new(
+    ///         QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RY(0.1)), 0),
+    ///         Hamiltonian::new(1, &[(1.0, z)]),
+    ///     )
+    /// }
+    /// ```
+    pub fn new(ansatz: QuantumCircuit, hamiltonian: Hamiltonian) -> Self {
+        // The ansatz must prepare a state for every qubit the hamiltonian acts on.
+        assert!(
+            ansatz.num_qubits == hamiltonian.num_qubits,
+            "Ansatz and hamiltonian must have the same amount of qubits"
+        );
+
+        Vqe {
+            ansatz,
+            hamiltonian,
+        }
+    }
+
+    /// [`Vqe::energy`] will get the expectation value of the [`Hamiltonian`] on the state the
+    /// `ansatz` prepares from $|0\ldots0\rangle$ with the `parameters`.
+    ///
+    /// # Example
+    /// [`Vqe::energy`] can be used to get the energy of the starting parameters:
+    /// ```rust
+    /// use rquant::algorithms::types::vqe::Vqe;
+    ///
+    /// fn get_starting_energy(vqe: &Vqe) -> f64 {
+    ///     vqe.energy(&vqe.ansatz.parameters())
+    /// }
+    /// ```
+    pub fn energy(&self, parameters: &[f64]) -> f64 {
+        let mut state_vector = StateVector::new(self.ansatz.num_qubits);
+        state_vector.apply_circuit(&self.ansatz.with_parameters(parameters));
+        self.hamiltonian.expectation(&state_vector)
+    }
+
+    /// [`Vqe::gradient`] will get the exact gradient of [`Vqe::energy`] at the `parameters`, using
+    /// the parameter-shift rule for rotation gates:
+    /// $$ \frac{\partial E}{\partial \theta_i} = \frac{E(\theta_i + \frac{\pi}{2}) - E(\theta_i - \frac{\pi}{2})}{2} $$
+    ///
+    /// # Example
+    /// [`Vqe::gradient`] can be used to get the slope of the energy:
+    /// ```rust
+    /// use rquant::algorithms::types::vqe::Vqe;
+    ///
+    /// fn get_starting_slope(vqe: &Vqe) -> Vec<f64> {
+    ///     vqe.gradient(&vqe.ansatz.parameters())
+    /// }
+    /// ```
+    pub fn gradient(&self, parameters: &[f64]) -> Vec<f64> {
+        (0..parameters.len())
+            .map(|index| {
+                let mut shifted = parameters.to_vec();
+                shifted[index] += FRAC_PI_2;
+                let forward = self.energy(&shifted);
+                shifted[index] -= 2.0 * FRAC_PI_2;
+                let backward = self.energy(&shifted);
+                (forward - backward) / 2.0
+            })
+            .collect()
+    }
+
+    /// [`Vqe::run`] will start from the angles of the `ansatz`, and update them with an
+    /// [`Optimizer`] for an amount of `iterations`, keeping track of every energy along the way.
+    ///
+    /// # Example
+    /// [`Vqe::run`] can be used to estimate the ground state energy of a [`Hamiltonian`]:
+    /// ```rust
+    /// use rquant::algorithms::types::{gradient_descent::GradientDescent, vqe::Vqe};
+    ///
+    /// fn estimate_ground_state_energy(vqe: &Vqe) -> f64 {
+    ///     vqe.run(&mut GradientDescent::new(0.2), 100).optimal_energy
+    /// }
+    /// ```
+    pub fn run(&self, optimizer: &mut impl Optimizer, iterations: usize) -> VqeResult {
+        let mut parameters = self.ansatz.parameters();
+        let mut energy = self.energy(&parameters);
+        let mut result = VqeResult {
+            energy_history: vec![energy],
+            optimal_parameters: parameters.clone(),
+            optimal_energy: energy,
+        };
+
+        for _ in 0..iterations {
+            parameters = optimizer.step(&parameters, &self.gradient(&parameters));
+            energy = self.energy(&parameters);
+            result.energy_history.push(energy);
+            if energy < result.optimal_energy {
+                result.optimal_energy = energy;
+                result.optimal_parameters = parameters.clone();
+            }
+        }
+        result
+    }
+}
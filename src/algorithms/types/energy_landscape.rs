@@ -0,0 +1,18 @@
+/// [`EnergyLandscape`] is a grid of the energies of a
+/// [`Hamiltonian`](crate::quantum::types::hamiltonian::Hamiltonian) over every combination of one
+/// or two parameters of a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit),
+/// which shows the shape an optimizer will have to search.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnergyLandscape {
+    /// The `(start, end)` range that was scanned for each parameter.
+    pub ranges: Vec<(f64, f64)>,
+
+    /// The amount of evenly spaced points that were scanned in each range, including both ends.
+    pub resolution: usize,
+
+    /// The energy at every point of the grid, where the row is the point of the second parameter
+    /// and the column is the point of the first parameter.
+    ///
+    /// A single parameter scan only has one row.
+    pub energies: Vec<Vec<f64>>,
+}
@@ -0,0 +1,7 @@
+/// [`GradientDescent`] is an [`Optimizer`](crate::algorithms::types::optimizer::Optimizer) that
+/// moves every parameter against it's gradient by a fixed `learning_rate`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientDescent {
+    /// The step size $\eta$ of each update $\theta \leftarrow \theta - \eta \nabla E(\theta)$.
+    pub learning_rate: f64,
+}
@@ -0,0 +1,20 @@
+use crate::quantum::types::hamiltonian::Hamiltonian;
+
+/// [`Hhl`] is the Harrow–Hassidim–Lloyd algorithm, which prepares a state proportional to the
+/// solution $x = A^{-1}b$ of a linear system, where $A$ is a small Hermitian [`Hamiltonian`].
+///
+/// It estimates the eigenvalues of $A$ with phase estimation on the `clock_qubits`, rotates an
+/// ancilla by an amount inversely proportional to each eigenvalue, then uncomputes the phase
+/// estimation and keeps the shots where the ancilla is $|1\rangle$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hhl {
+    /// The Hermitian matrix $A$ of the linear system.
+    pub hamiltonian: Hamiltonian,
+
+    /// The amount of qubits used to estimate the eigenvalues of $A$.
+    pub clock_qubits: usize,
+
+    /// The time $t$ that $e^{iAt}$ is evolved for in phase estimation, which must keep every
+    /// eigenvalue of $A$ between $0$ and $2\pi/t$.
+    pub evolution_time: f64,
+}
@@ -0,0 +1,12 @@
+use num_complex::Complex;
+
+/// [`HhlResult`] is the outcome of running [`Hhl`](crate::algorithms::types::hhl::Hhl).
+#[derive(Clone, Debug, PartialEq)]
+pub struct HhlResult {
+    /// The normalized amplitudes of the state proportional to $A^{-1}b$.
+    pub solution: Vec<Complex<f64>>,
+
+    /// The probability of measuring the ancilla as $|1\rangle$, which is how often the algorithm
+    /// succeeds.
+    pub success_probability: f64,
+}
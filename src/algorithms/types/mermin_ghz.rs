@@ -0,0 +1,24 @@
+/// [`MerminGhz`] is the outcome of the Mermin–GHZ experiment, which measures a three-qubit GHZ
+/// state $\frac{1}{\sqrt{2}}(|000\rangle + |111\rangle)$ in the $XXX$, $XYY$, $YXY$, and $YYX$
+/// bases.
+///
+/// Local realism requires the product of the last three parities to equal the first one, which
+/// bounds the Mermin value $\langle XXX \rangle - \langle XYY \rangle - \langle YXY \rangle -
+/// \langle YYX \rangle$ by $2$, while quantum mechanics reaches $4$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MerminGhz {
+    /// The amount of measurements of each of the four bases.
+    pub shots: usize,
+
+    /// The average parity measured in the $XXX$ basis.
+    pub xxx: f64,
+
+    /// The average parity measured in the $XYY$ basis.
+    pub xyy: f64,
+
+    /// The average parity measured in the $YXY$ basis.
+    pub yxy: f64,
+
+    /// The average parity measured in the $YYX$ basis.
+    pub yyx: f64,
+}
@@ -0,0 +1,7 @@
+/// [`Optimizer`] is a [`trait`] that will allow any type to act as the classical optimizer of a
+/// variational algorithm, such as [`Vqe`](crate::algorithms::types::vqe::Vqe).
+pub trait Optimizer {
+    /// [`Optimizer::step`] will get the next `parameters` to try, from the current `parameters`
+    /// and the `gradient` of the cost at those `parameters`.
+    fn step(&mut self, parameters: &[f64], gradient: &[f64]) -> Vec<f64>;
+}
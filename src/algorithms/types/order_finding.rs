@@ -0,0 +1,13 @@
+/// [`OrderFinding`] is the quantum subroutine of Shor's algorithm, which finds the smallest
+/// $r > 0$ such that $a^r \equiv 1 \pmod N$ for a `base` $a$ and `modulus` $N$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OrderFinding {
+    /// The base $a$ of the modular exponentiation oracle.
+    pub base: u64,
+
+    /// The modulus $N$ of the modular exponentiation oracle.
+    pub modulus: u64,
+
+    /// The amount of counting qubits used to estimate the phase of the oracle.
+    pub precision: u32,
+}
@@ -0,0 +1,15 @@
+/// [`QftApproximation`] is how closely an approximate
+/// [`QuantumFourierTransform`](crate::algorithms::types::quantum_fourier_transform::QuantumFourierTransform)
+/// matches the exact transform at one rotation cutoff.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QftApproximation {
+    /// The smallest controlled phase rotation angle that was kept.
+    pub threshold: f64,
+
+    /// The amount of controlled phase rotations that were kept.
+    pub rotations: usize,
+
+    /// The process fidelity $|\mathrm{Tr}(U^\dagger V)|^2 / N^2$ between the exact transform $U$
+    /// and the approximate transform $V$.
+    pub fidelity: f64,
+}
@@ -0,0 +1,12 @@
+/// [`QuantumFourierTransform`] is the circuit that maps every basis state $|x\rangle$ to
+/// $\frac{1}{\sqrt{N}}\sum_k e^{2\pi i x k / N}|k\rangle$ with a cascade of Hadamard and
+/// controlled phase rotations.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QuantumFourierTransform {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) the transform operates on.
+    pub num_qubits: usize,
+
+    /// The smallest controlled phase rotation angle that is kept, where every smaller rotation is
+    /// dropped to make the circuit shallower.
+    pub threshold: f64,
+}
@@ -0,0 +1,25 @@
+use crate::{
+    algorithms::types::walk_topology::WalkTopology,
+    quantum::types::{quantum_operators::QuantumOperator, qubit::Qubit},
+};
+
+/// [`QuantumWalk`] is a discrete-time quantum walk, where each step flips a coin
+/// [`Qubit`] with a `coin` operator, then shifts the walker left for $|0\rangle$ and right for
+/// $|1\rangle$ along a [`WalkTopology`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumWalk {
+    /// The [`WalkTopology`] of the positions.
+    pub topology: WalkTopology,
+
+    /// The amount of positions the walker can be at.
+    pub positions: usize,
+
+    /// The position the walker starts at.
+    pub start: usize,
+
+    /// The [`QuantumOperator`] applied to the coin at every step.
+    pub coin: QuantumOperator,
+
+    /// The state of the coin [`Qubit`] before the first step.
+    pub initial_coin: Qubit,
+}
@@ -0,0 +1,2 @@
+/// [`Shor`] will factor small integers using Shor's algorithm.
+pub struct Shor;
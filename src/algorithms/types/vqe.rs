@@ -0,0 +1,14 @@
+use crate::quantum::types::{hamiltonian::Hamiltonian, quantum_circuit::QuantumCircuit};
+
+/// [`Vqe`] is the variational quantum eigensolver, which searches for the lowest energy of a
+/// [`Hamiltonian`] by tuning the angles of an `ansatz` [`QuantumCircuit`] with a classical
+/// [`Optimizer`](crate::algorithms::types::optimizer::Optimizer).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vqe {
+    /// The parameterized [`QuantumCircuit`] that prepares the trial state, where the angles of
+    /// it's operations are the starting parameters.
+    pub ansatz: QuantumCircuit,
+
+    /// The [`Hamiltonian`] whose energy is minimized.
+    pub hamiltonian: Hamiltonian,
+}
@@ -0,0 +1,12 @@
+/// [`VqeResult`] is the outcome of running a [`Vqe`](crate::algorithms::types::vqe::Vqe).
+#[derive(Clone, Debug, PartialEq)]
+pub struct VqeResult {
+    /// The energy at the start, and after every iteration of the optimizer.
+    pub energy_history: Vec<f64>,
+
+    /// The parameters that reached the lowest energy.
+    pub optimal_parameters: Vec<f64>,
+
+    /// The lowest energy that was reached.
+    pub optimal_energy: f64,
+}
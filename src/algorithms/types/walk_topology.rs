@@ -0,0 +1,10 @@
+/// [`WalkTopology`] is the graph of positions a
+/// [`QuantumWalk`](crate::algorithms::types::quantum_walk::QuantumWalk) moves along.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalkTopology {
+    /// Positions in a line, where the walker is reflected at each end.
+    Line,
+
+    /// Positions in a cycle, where the last position is joined to the first.
+    Cycle,
+}
@@ -0,0 +1,124 @@
+use std::f64::consts::FRAC_PI_2;
+
+use num_complex::Complex;
+
+use crate::{
+    benchmarking::types::clifford::Clifford,
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator},
+    },
+};
+
+impl Clifford {
+    /// [`Clifford::group`] will get all 24 single-qubit [`Cliffords`](Clifford), each written with
+    /// the fewest Hadamard and $R_Z(\pi/2)$ gates.
+    ///
+    /// # Example
+    /// [`Clifford::group`] can be used to pick a random [`Clifford`]:
+    /// ```rust
+    /// use rquant::benchmarking::types::clifford::Clifford;
+    ///
+    /// fn first_clifford() -> Clifford {
+    ///     Clifford::group().remove(0)
+    /// }
+    /// ```
+    pub fn group() -> Vec<Clifford> {
+        let generators = [
+            QuantumOperator::SUPERPOSITION,
+            QuantumOperator::RZ(FRAC_PI_2),
+        ];
+        let mut group = vec![Clifford { operators: vec![] }];
+
+        // Every element is found by extending a shorter element, so the search ends once no
+        // extension is new.
+        let mut index = 0;
+        while index < group.len() {
+            for generator in &generators {
+                let mut operators = group[index].operators.clone();
+                operators.push(generator.clone());
+                let candidate = Clifford { operators };
+                if !group
+                    .iter()
+                    .any(|element| is_equivalent(&element.matrix(), &candidate.matrix()))
+                {
+                    group.push(candidate);
+                }
+            }
+            index += 1;
+        }
+        group
+    }
+
+    /// [`Clifford::from_gate`] will get the [`Clifford`] that matches a [`QuantumGate`] up to a
+    /// global phase, and returns [`None`] if the [`QuantumGate`] is not a Clifford gate.
+    ///
+    /// # Example
+    /// [`Clifford::from_gate`] can be used to check that a gate can be benchmarked:
+    /// ```rust
+    /// use rquant::{benchmarking::types::clifford::Clifford, quantum::types::quantum_gate::QuantumGate};
+    ///
+    /// fn is_clifford(gate: &QuantumGate) -> bool {
+    ///     Clifford::from_gate(gate).is_some()
+    /// }
+    /// ```
+    pub fn from_gate(gate: &QuantumGate) -> Option<Clifford> {
+        Self::from_matrix(&gate.matrix())
+    }
+
+    /// [`Clifford::matrix`] will get the matrix of every operator of a [`Clifford`] combined.
+    ///
+    /// # Example
+    /// [`Clifford::matrix`] can be used to get the unitary of a [`Clifford`]:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::benchmarking::types::clifford::Clifford;
+    ///
+    /// fn clifford_unitary(clifford: &Clifford) -> Vec<Vec<Complex<f64>>> {
+    ///     clifford.matrix()
+    /// }
+    /// ```
+    pub fn matrix(&self) -> Vec<Vec<Complex<f64>>> {
+        let identity = QuantumGate::new(QuantumOperator::RZ(0.0)).matrix();
+        self.operators.iter().fold(identity, |matrix, operator| {
+            matrix_multiply(&QuantumGate::new(operator.clone()).matrix(), &matrix)
+        })
+    }
+
+    /// [`Clifford::inverse`] will get the [`Clifford`] that undoes a `matrix` of Clifford gates, up
+    /// to a global phase.
+    ///
+    /// # Example
+    /// [`Clifford::inverse`] can be used to end a random sequence back at $|0\rangle$:
+    /// ```rust
+    /// use rquant::benchmarking::types::clifford::Clifford;
+    ///
+    /// fn undo(clifford: &Clifford) -> Clifford {
+    ///     Clifford::inverse(&clifford.matrix())
+    /// }
+    /// ```
+    pub fn inverse(matrix: &[Vec<Complex<f64>>]) -> Clifford {
+        let adjoint: Vec<Vec<Complex<f64>>> = (0..2)
+            .map(|row| (0..2).map(|column| matrix[column][row].conj()).collect())
+            .collect();
+        Self::from_matrix(&adjoint).expect("Inverted matrix must be a Clifford gate")
+    }
+
+    /// [`Clifford::from_matrix`] will get the [`Clifford`] that matches a `matrix` up to a global
+    /// phase, if there is one.
+    fn from_matrix(matrix: &[Vec<Complex<f64>>]) -> Option<Clifford> {
+        Self::group()
+            .into_iter()
+            .find(|element| is_equivalent(&element.matrix(), matrix))
+    }
+}
+
+/// [`is_equivalent`] returns `true` if two single-qubit unitaries are equal up to a global phase,
+/// which is when $|\mathrm{Tr}(A^\dagger B)| = 2$.
+fn is_equivalent(a: &[Vec<Complex<f64>>], b: &[Vec<Complex<f64>>]) -> bool {
+    let overlap: Complex<f64> = (0..2)
+        .flat_map(|row| (0..2).map(move |column| (row, column)))
+        .map(|(row, column)| a[row][column].conj() * b[row][column])
+        .sum();
+    (overlap.norm() - 2.0).abs() < 1e-9
+}
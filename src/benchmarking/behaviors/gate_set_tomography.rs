@@ -0,0 +1,309 @@
+use std::f64::consts::FRAC_PI_2;
+
+use num_complex::Complex;
+
+use crate::{
+    benchmarking::types::{gate_estimate::GateEstimate, gate_set_tomography::GateSetTomography},
+    noise::types::noise_model::NoiseModel,
+    quantum::types::{
+        pauli::Pauli, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+/// A $4 \times 4$ Pauli transfer matrix.
+type Ptm = [[f64; 4]; 4];
+
+impl GateSetTomography {
+    /// [`GateSetTomography::new`] will create a new [`GateSetTomography`] that characterizes the
+    /// gates of the `operators` under a [`NoiseModel`], measuring every circuit `shots` times.
+    ///
+    /// # Example
+    /// [`GateSetTomography::new`] can be used to characterize the Hadamard and NOT gates:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::gate_set_tomography::GateSetTomography,
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::quantum_operators::QuantumOperator,
+    /// };
+    ///
+    /// fn create_gate_set_tomography() -> GateSetTomography {
+    ///     GateSetTomography::new(
+    ///         NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.01)),
+    ///         &[QuantumOperator::SUPERPOSITION, QuantumOperator::NOT],
+    ///         1000,
+    ///     )
+    /// }
+    /// ```
+    pub fn new(noise_model: NoiseModel, operators: &[QuantumOperator], shots: usize) -> Self {
+        assert!(!operators.is_empty(), "Gate set tomography needs a gate");
+        assert!(shots > 0, "Gate set tomography needs at least one shot");
+
+        GateSetTomography {
+            noise_model,
+            operators: operators.to_vec(),
+            shots,
+        }
+    }
+
+    /// [`GateSetTomography::run`] will estimate every gate from the probability of measuring
+    /// $|0\rangle$ after each pair of fiducials around it, then fix the gauge of the estimates
+    /// with the ideal fiducials:
+    /// $$ \hat{G} = B \tilde{I}^{-1} \tilde{G} B^{-1} $$
+    /// where $\tilde{I}_{ij}$ and $\tilde{G}_{ij}$ are measured after fiducial $j$ then fiducial $i$,
+    /// without and with the gate between them, and $B$ holds the ideal states the fiducials
+    /// prepare.
+    ///
+    /// # Example
+    /// [`GateSetTomography::run`] can be used to find the fidelity of every gate:
+    /// ```rust
+    /// use rquant::benchmarking::types::gate_set_tomography::GateSetTomography;
+    ///
+    /// fn gate_fidelities(gst: &GateSetTomography) -> Vec<f64> {
+    ///     gst.run()
+    ///         .iter()
+    ///         .map(|estimate| estimate.average_gate_fidelity)
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn run(&self) -> Vec<GateEstimate> {
+        let fiducials = fiducials();
+        let gram = self.data(&fiducials, None);
+        let gram_inverse = inverse(&gram);
+
+        // Each column of the preparation matrix is the ideal state a fiducial prepares from |0>.
+        let zero_state = [1.0, 0.0, 0.0, 1.0];
+        let mut preparations = [[0.0; 4]; 4];
+        for (column, fiducial) in fiducials.iter().enumerate() {
+            let state = apply(&circuit_ptm(fiducial), &zero_state);
+            for (row, value) in state.iter().enumerate() {
+                preparations[row][column] = *value;
+            }
+        }
+        let preparations_inverse = inverse(&preparations);
+
+        self.operators
+            .iter()
+            .map(|operator| {
+                let ideal = gate_ptm(operator);
+                let data = self.data(&fiducials, Some(operator));
+                let estimated = multiply(
+                    &multiply(&preparations, &multiply(&gram_inverse, &data)),
+                    &preparations_inverse,
+                );
+                let error_generator = logarithm(&multiply(&estimated, &inverse(&ideal)));
+
+                // The process fidelity of two Pauli transfer matrices is Tr(A^T B) / 4.
+                let process_fidelity: f64 = (0..4)
+                    .flat_map(|row| (0..4).map(move |column| (row, column)))
+                    .map(|(row, column)| ideal[row][column] * estimated[row][column])
+                    .sum::<f64>()
+                    / 4.0;
+
+                GateEstimate {
+                    operator: operator.clone(),
+                    ideal,
+                    estimated,
+                    error_generator,
+                    average_gate_fidelity: (2.0 * process_fidelity + 1.0) / 3.0,
+                }
+            })
+            .collect()
+    }
+
+    /// [`GateSetTomography::data`] will get the probability of measuring $|0\rangle$ after
+    /// preparing with fiducial $j$, applying the gate of an `operator` if there is one, and
+    /// measuring with fiducial $i$, as a matrix where each value is at row $i$ and column $j$.
+    fn data(&self, fiducials: &[Vec<QuantumOperator>], operator: Option<&QuantumOperator>) -> Ptm {
+        let mut data = [[0.0; 4]; 4];
+        for (row, measurement) in fiducials.iter().enumerate() {
+            for (column, preparation) in fiducials.iter().enumerate() {
+                let circuit = preparation
+                    .iter()
+                    .chain(operator)
+                    .chain(measurement)
+                    .fold(QuantumCircuit::new(1), |circuit, operator| {
+                        circuit.add_gate(&QuantumGate::new(operator.clone()), 0)
+                    });
+                data[row][column] =
+                    SimulationOutcome::from_noisy_circuit(&circuit, self.shots, &self.noise_model)
+                        .probability(0);
+            }
+        }
+        data
+    }
+}
+
+/// [`fiducials`] will get the four fiducial circuits, which prepare $|0\rangle$, $|{-i}\rangle$,
+/// $|{+}\rangle$, and $|1\rangle$ from $|0\rangle$.
+fn fiducials() -> Vec<Vec<QuantumOperator>> {
+    vec![
+        vec![],
+        vec![QuantumOperator::RX(FRAC_PI_2)],
+        vec![QuantumOperator::RY(FRAC_PI_2)],
+        vec![
+            QuantumOperator::RX(FRAC_PI_2),
+            QuantumOperator::RX(FRAC_PI_2),
+        ],
+    ]
+}
+
+/// [`gate_ptm`] will get the Pauli transfer matrix $R_{ij} = \text{Tr}(P_i U P_j U^\dagger) / 2$ of
+/// the noiseless gate of an `operator`.
+fn gate_ptm(operator: &QuantumOperator) -> Ptm {
+    let unitary = QuantumGate::new(operator.clone()).matrix();
+    let paulis = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z].map(pauli_matrix);
+    let mut ptm = [[0.0; 4]; 4];
+    for (row, first) in paulis.iter().enumerate() {
+        for (column, second) in paulis.iter().enumerate() {
+            let mut trace = Complex::new(0.0, 0.0);
+            for a in 0..2 {
+                for b in 0..2 {
+                    for c in 0..2 {
+                        for d in 0..2 {
+                            trace +=
+                                first[a][b] * unitary[b][c] * second[c][d] * unitary[a][d].conj();
+                        }
+                    }
+                }
+            }
+            ptm[row][column] = trace.re / 2.0;
+        }
+    }
+    ptm
+}
+
+/// [`circuit_ptm`] will get the Pauli transfer matrix of the noiseless gates of a fiducial,
+/// applied in order.
+fn circuit_ptm(operators: &[QuantumOperator]) -> Ptm {
+    operators.iter().fold(identity(), |ptm, operator| {
+        multiply(&gate_ptm(operator), &ptm)
+    })
+}
+
+/// [`pauli_matrix`] will get the $2 \times 2$ matrix of a [`Pauli`].
+fn pauli_matrix(pauli: Pauli) -> Vec<Vec<Complex<f64>>> {
+    match pauli.operator() {
+        Some(operator) => QuantumGate::new(operator).matrix(),
+        None => vec![
+            vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+            vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+        ],
+    }
+}
+
+/// [`identity`] will get the $4 \times 4$ identity matrix.
+fn identity() -> Ptm {
+    let mut identity = [[0.0; 4]; 4];
+    for (index, row) in identity.iter_mut().enumerate() {
+        row[index] = 1.0;
+    }
+    identity
+}
+
+/// [`apply`] will multiply a matrix with a vector.
+fn apply(matrix: &Ptm, vector: &[f64; 4]) -> [f64; 4] {
+    matrix.map(|row| row.iter().zip(vector).map(|(a, b)| a * b).sum())
+}
+
+/// [`multiply`] will multiply two matrices.
+fn multiply(a: &Ptm, b: &Ptm) -> Ptm {
+    let mut product = [[0.0; 4]; 4];
+    for (row, a_row) in a.iter().enumerate() {
+        for (column, entry) in product[row].iter_mut().enumerate() {
+            *entry = a_row
+                .iter()
+                .zip(b)
+                .map(|(value, b_row)| value * b_row[column])
+                .sum();
+        }
+    }
+    product
+}
+
+/// [`inverse`] will invert a matrix with Gauss-Jordan elimination.
+fn inverse(matrix: &Ptm) -> Ptm {
+    let mut left = *matrix;
+    let mut right = identity();
+    for column in 0..4 {
+        let pivot = (column..4)
+            .max_by(|&a, &b| left[a][column].abs().total_cmp(&left[b][column].abs()))
+            .unwrap();
+        assert!(
+            left[pivot][column].abs() > 1e-12,
+            "Gate set tomography data is singular"
+        );
+        left.swap(column, pivot);
+        right.swap(column, pivot);
+
+        let scale = left[column][column];
+        for index in 0..4 {
+            left[column][index] /= scale;
+            right[column][index] /= scale;
+        }
+        for row in (0..4).filter(|&row| row != column) {
+            let factor = left[row][column];
+            for index in 0..4 {
+                left[row][index] -= factor * left[column][index];
+                right[row][index] -= factor * right[column][index];
+            }
+        }
+    }
+    right
+}
+
+/// [`logarithm`] will get the matrix logarithm of a matrix near the identity, by taking square
+/// roots until it is close enough for the series of $\ln(I + X)$ to converge quickly.
+fn logarithm(matrix: &Ptm) -> Ptm {
+    let distance = |matrix: &Ptm| {
+        (0..4)
+            .flat_map(|row| (0..4).map(move |column| (row, column)))
+            .map(|(row, column)| (matrix[row][column] - identity()[row][column]).abs())
+            .fold(0.0, f64::max)
+    };
+
+    let mut root = *matrix;
+    let mut square_roots = 0;
+    while distance(&root) > 0.1 && square_roots < 20 {
+        root = square_root(&root);
+        square_roots += 1;
+    }
+
+    let mut difference = root;
+    for (index, row) in difference.iter_mut().enumerate() {
+        row[index] -= 1.0;
+    }
+    let mut power = difference;
+    let mut logarithm = [[0.0; 4]; 4];
+    for order in 1..=30 {
+        let sign = if order % 2 == 1 { 1.0 } else { -1.0 };
+        for (row, power_row) in logarithm.iter_mut().zip(&power) {
+            for (entry, value) in row.iter_mut().zip(power_row) {
+                *entry += sign * value / order as f64;
+            }
+        }
+        power = multiply(&power, &difference);
+    }
+
+    let scale = (1 << square_roots) as f64;
+    logarithm.map(|row| row.map(|entry| entry * scale))
+}
+
+/// [`square_root`] will get the principal square root of a matrix with the Denman-Beavers
+/// iteration.
+fn square_root(matrix: &Ptm) -> Ptm {
+    let mut root = *matrix;
+    let mut inverse_root = identity();
+    for _ in 0..50 {
+        let (root_inverse, inverse_root_inverse) = (inverse(&root), inverse(&inverse_root));
+        for row in 0..4 {
+            for column in 0..4 {
+                root[row][column] = (root[row][column] + inverse_root_inverse[row][column]) / 2.0;
+                inverse_root[row][column] =
+                    (inverse_root[row][column] + root_inverse[row][column]) / 2.0;
+            }
+        }
+    }
+    root
+}
@@ -0,0 +1,264 @@
+use rand::Rng;
+
+use crate::{
+    benchmarking::types::{
+        clifford::Clifford, interleaved_rb_result::InterleavedRbResult,
+        randomized_benchmarking::RandomizedBenchmarking, rb_result::RbResult,
+    },
+    noise::types::noise_model::NoiseModel,
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+/// The z-score of a two-sided 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+impl RandomizedBenchmarking {
+    /// [`RandomizedBenchmarking::new`] will create a new [`RandomizedBenchmarking`] that runs an
+    /// amount of `sequences` for each of the `lengths` under a [`NoiseModel`], measuring each
+    /// sequence an amount of `shots` times.
+    ///
+    /// # Example
+    /// [`RandomizedBenchmarking::new`] can be used to benchmark depolarizing noise:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::randomized_benchmarking::RandomizedBenchmarking,
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    /// };
+    ///
+    /// fn create_benchmark() -> RandomizedBenchmarking {
+    ///     let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.01));
+    ///     RandomizedBenchmarking::new(noise_model, &[1, 10, 20, 50], 20, 100)
+    /// }
+    /// ```
+    pub fn new(noise_model: NoiseModel, lengths: &[usize], sequences: usize, shots: usize) -> Self {
+        assert!(
+            lengths.iter().any(|&length| length != lengths[0]),
+            "Randomized benchmarking needs at least two different lengths"
+        );
+        assert!(
+            sequences > 0 && shots > 0,
+            "Randomized benchmarking needs sequences and shots"
+        );
+
+        RandomizedBenchmarking {
+            noise_model,
+            lengths: lengths.to_vec(),
+            sequences,
+            shots,
+        }
+    }
+
+    /// [`RandomizedBenchmarking::run`] will run random sequences of [`Cliffords`](Clifford) that
+    /// each end with the [`Clifford`] that inverts them, and fit how quickly the probability of
+    /// returning to $|0\rangle$ decays.
+    ///
+    /// # Example
+    /// [`RandomizedBenchmarking::run`] can be used to get the average gate fidelity of a
+    /// [`NoiseModel`]:
+    /// ```rust
+    /// use rquant::benchmarking::types::randomized_benchmarking::RandomizedBenchmarking;
+    ///
+    /// fn average_gate_fidelity(benchmark: &RandomizedBenchmarking) -> f64 {
+    ///     benchmark.run().average_gate_fidelity
+    /// }
+    /// ```
+    pub fn run(&self) -> RbResult {
+        self.run_with(&mut rand::rng())
+    }
+
+    /// [`RandomizedBenchmarking::run_with`] will run random sequences in the same way as
+    /// [`RandomizedBenchmarking::run`], using a random number generator `rng` to pick the
+    /// [`Cliffords`](Clifford) and to sample the noisy shots instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`RandomizedBenchmarking::run_with`] can be used to reproduce a benchmark:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::benchmarking::types::randomized_benchmarking::RandomizedBenchmarking;
+    ///
+    /// fn seeded_average_gate_fidelity(benchmark: &RandomizedBenchmarking, seed: u64) -> f64 {
+    ///     benchmark
+    ///         .run_with(&mut StdRng::seed_from_u64(seed))
+    ///         .average_gate_fidelity
+    /// }
+    /// ```
+    pub fn run_with(&self, rng: &mut impl Rng) -> RbResult {
+        self.benchmark(None, rng)
+    }
+
+    /// [`RandomizedBenchmarking::run_interleaved`] will run [`RandomizedBenchmarking::run`], then
+    /// run it again with a Clifford [`QuantumGate`] after every random [`Clifford`], and compare
+    /// the decays to estimate the fidelity of the [`QuantumGate`] alone.
+    ///
+    /// # Example
+    /// [`RandomizedBenchmarking::run_interleaved`] can be used to get the fidelity of a Hadamard
+    /// gate:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::randomized_benchmarking::RandomizedBenchmarking,
+    ///     quantum::types::quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn hadamard_fidelity(benchmark: &RandomizedBenchmarking) -> f64 {
+    ///     benchmark.run_interleaved(&QuantumGate::SUPERPOSITION).gate_fidelity
+    /// }
+    /// ```
+    pub fn run_interleaved(&self, gate: &QuantumGate) -> InterleavedRbResult {
+        self.run_interleaved_with(gate, &mut rand::rng())
+    }
+
+    /// [`RandomizedBenchmarking::run_interleaved_with`] will run interleaved randomized
+    /// benchmarking in the same way as [`RandomizedBenchmarking::run_interleaved`], using a random
+    /// number generator `rng` instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`RandomizedBenchmarking::run_interleaved_with`] can be used to reproduce the fidelity of a
+    /// Hadamard gate:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     benchmarking::types::randomized_benchmarking::RandomizedBenchmarking,
+    ///     quantum::types::quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn seeded_hadamard_fidelity(benchmark: &RandomizedBenchmarking, seed: u64) -> f64 {
+    ///     benchmark
+    ///         .run_interleaved_with(&QuantumGate::SUPERPOSITION, &mut StdRng::seed_from_u64(seed))
+    ///         .gate_fidelity
+    /// }
+    /// ```
+    pub fn run_interleaved_with(
+        &self,
+        gate: &QuantumGate,
+        rng: &mut impl Rng,
+    ) -> InterleavedRbResult {
+        assert!(
+            Clifford::from_gate(gate).is_some(),
+            "Interleaved gate must be a Clifford gate"
+        );
+
+        let reference = self.benchmark(None, rng);
+        let interleaved = self.benchmark(Some(gate), rng);
+
+        // The decays are compared in log space, where their fit errors add in quadrature.
+        let log_ratio = interleaved.decay.ln() - reference.decay.ln();
+        let std_error = interleaved.decay_std_error.hypot(reference.decay_std_error);
+        let fidelity = |log_decay: f64| (1.0 + log_decay.exp()) / 2.0;
+
+        InterleavedRbResult {
+            gate_fidelity: fidelity(log_ratio),
+            confidence_interval: (
+                fidelity(log_ratio - CONFIDENCE_Z * std_error),
+                fidelity(log_ratio + CONFIDENCE_Z * std_error),
+            ),
+            reference,
+            interleaved,
+        }
+    }
+
+    /// [`RandomizedBenchmarking::benchmark`] will run the random sequences of every length, with
+    /// an `interleaved` [`QuantumGate`] after every [`Clifford`] if there is one, and fit their
+    /// decay.
+    fn benchmark(&self, interleaved: Option<&QuantumGate>, rng: &mut impl Rng) -> RbResult {
+        let group = Clifford::group();
+
+        let survival_probabilities: Vec<f64> = self
+            .lengths
+            .iter()
+            .map(|&length| {
+                let total: f64 = (0..self.sequences)
+                    .map(|_| {
+                        let cliffords: Vec<&Clifford> = (0..length)
+                            .map(|_| &group[rng.random_range(0..group.len())])
+                            .collect();
+                        let circuit = sequence_circuit(&cliffords, interleaved);
+                        SimulationOutcome::from_noisy_circuit_with(
+                            &circuit,
+                            self.shots,
+                            &self.noise_model,
+                            rng,
+                        )
+                        .probability(0)
+                    })
+                    .sum();
+                total / self.sequences as f64
+            })
+            .collect();
+
+        let (decay, decay_std_error) = fit_decay(&self.lengths, &survival_probabilities);
+        RbResult {
+            lengths: self.lengths.clone(),
+            survival_probabilities,
+            decay,
+            decay_std_error,
+            average_gate_fidelity: (1.0 + decay) / 2.0,
+        }
+    }
+}
+
+/// [`sequence_circuit`] will build a single-qubit [`QuantumCircuit`] of `cliffords`, with an
+/// `interleaved` [`QuantumGate`] after each one, followed by the [`Clifford`] that inverts the
+/// whole sequence.
+fn sequence_circuit(cliffords: &[&Clifford], interleaved: Option<&QuantumGate>) -> QuantumCircuit {
+    let mut circuit = QuantumCircuit::new(1);
+    let mut total = Clifford { operators: vec![] }.matrix();
+    for clifford in cliffords {
+        for operator in &clifford.operators {
+            circuit = circuit.add_gate(&QuantumGate::new(operator.clone()), 0);
+        }
+        total = matrix_multiply(&clifford.matrix(), &total);
+        if let Some(gate) = interleaved {
+            circuit = circuit.add_gate(gate, 0);
+            total = matrix_multiply(&gate.matrix(), &total);
+        }
+    }
+    for operator in Clifford::inverse(&total).operators {
+        circuit = circuit.add_gate(&QuantumGate::new(operator), 0);
+    }
+    circuit
+}
+
+/// [`fit_decay`] will fit survival probabilities to $A p^m + \frac{1}{2}$ with a linear fit of
+/// $\ln(S - \frac{1}{2})$ against the `lengths` $m$, and get $p$ along with the standard error of
+/// $\ln p$.
+fn fit_decay(lengths: &[usize], survival_probabilities: &[f64]) -> (f64, f64) {
+    // Sampling can push a survival probability to or below the asymptote.
+    let points: Vec<(f64, f64, f64)> = lengths
+        .iter()
+        .zip(survival_probabilities)
+        .map(|(&length, &survival)| {
+            let height = (survival - 0.5).max(1e-6);
+            (length as f64, height.ln(), height.powi(2))
+        })
+        .collect();
+
+    // Weight each point by it's squared height above the asymptote, since sampling noise is
+    // amplified by the logarithm as the height shrinks.
+    let weight: f64 = points.iter().map(|(_, _, w)| w).sum();
+    let mean_x = points.iter().map(|(x, _, w)| w * x).sum::<f64>() / weight;
+    let mean_y = points.iter().map(|(_, y, w)| w * y).sum::<f64>() / weight;
+    let spread: f64 = points
+        .iter()
+        .map(|(x, _, w)| w * (x - mean_x).powi(2))
+        .sum();
+    let slope = points
+        .iter()
+        .map(|(x, y, w)| w * (x - mean_x) * (y - mean_y))
+        .sum::<f64>()
+        / spread;
+
+    let std_error = if points.len() > 2 {
+        let residuals: f64 = points
+            .iter()
+            .map(|(x, y, w)| w * (y - mean_y - slope * (x - mean_x)).powi(2))
+            .sum();
+        (residuals / (points.len() as f64 - 2.0) / spread).sqrt()
+    } else {
+        0.0
+    };
+    (slope.exp(), std_error)
+}
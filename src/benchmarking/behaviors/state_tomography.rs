@@ -0,0 +1,372 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    benchmarking::types::state_tomography::StateTomography,
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{
+            basis::Basis, density_matrix::DensityMatrix, pauli::Pauli, pauli_string::PauliString,
+            state_vector::StateVector,
+        },
+    },
+    simulation::behaviors::simulation_outcome::sample_index,
+};
+
+impl StateTomography {
+    /// [`StateTomography::new`] will create a new [`StateTomography`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) without any measurements.
+    ///
+    /// # Example
+    /// [`StateTomography::new`] can be used to start the tomography of a single qubit:
+    /// ```rust
+    /// use rquant::benchmarking::types::state_tomography::StateTomography;
+    ///
+    /// fn create_state_tomography() -> StateTomography {
+    ///     StateTomography::new(1)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        assert!(num_qubits > 0, "State tomography needs at least one qubit");
+
+        StateTomography {
+            num_qubits,
+            measurements: vec![],
+        }
+    }
+
+    /// [`StateTomography::with_counts`] will add the `counts` of every basis state measured with
+    /// one [`Basis`] per [`Qubit`](crate::quantum::types::qubit::Qubit) to the
+    /// [`StateTomography`], then return the modified [`StateTomography`].
+    ///
+    /// # Example
+    /// [`StateTomography::with_counts`] can be used to enter counts measured on hardware:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::state_tomography::StateTomography, quantum::types::basis::Basis,
+    /// };
+    ///
+    /// fn create_plus_state_tomography() -> StateTomography {
+    ///     StateTomography::new(1)
+    ///         .with_counts(&[Basis::X], &[1000, 0])
+    ///         .with_counts(&[Basis::Y], &[497, 503])
+    ///         .with_counts(&[Basis::Z], &[512, 488])
+    /// }
+    /// ```
+    pub fn with_counts(mut self, bases: &[Basis], counts: &[usize]) -> Self {
+        assert!(
+            bases.len() == self.num_qubits && !bases.contains(&Basis::Bell),
+            "State tomography needs an X, Y, or Z basis for every qubit"
+        );
+        assert!(
+            counts.len() == 1 << self.num_qubits,
+            "State tomography needs a count for every basis state"
+        );
+
+        self.measurements.push((bases.to_vec(), counts.to_vec()));
+        self
+    }
+
+    /// [`StateTomography::measure`] will add `shots` simulated measurements of a [`StateVector`] in
+    /// every combination of the $X$, $Y$, and $Z$ [`Basis`] to the [`StateTomography`], then return
+    /// the modified [`StateTomography`].
+    ///
+    /// # Example
+    /// [`StateTomography::measure`] can be used to check how well a state can be reconstructed:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::state_tomography::StateTomography,
+    ///     quantum::types::{density_matrix::DensityMatrix, state_vector::StateVector},
+    /// };
+    ///
+    /// fn reconstruct_bell_state() -> DensityMatrix {
+    ///     StateTomography::new(2)
+    ///         .measure(&StateVector::ghz(2), 1000)
+    ///         .linear_inversion()
+    /// }
+    /// ```
+    pub fn measure(self, state_vector: &StateVector, shots: usize) -> Self {
+        self.measure_with(state_vector, shots, &mut rand::rng())
+    }
+
+    /// [`StateTomography::measure_with`] will add simulated measurements in the same way as
+    /// [`StateTomography::measure`], using a random number generator `rng` instead of the thread's
+    /// generator.
+    ///
+    /// # Example
+    /// [`StateTomography::measure_with`] can be used to reproduce a reconstruction:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     benchmarking::types::state_tomography::StateTomography,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn seeded_tomography(seed: u64) -> StateTomography {
+    ///     StateTomography::new(1).measure_with(
+    ///         &StateVector::new(1),
+    ///         1000,
+    ///         &mut StdRng::seed_from_u64(seed),
+    ///     )
+    /// }
+    /// ```
+    pub fn measure_with(
+        mut self,
+        state_vector: &StateVector,
+        shots: usize,
+        rng: &mut impl Rng,
+    ) -> Self {
+        assert!(
+            state_vector.num_qubits == self.num_qubits,
+            "State vector must have every tomography qubit"
+        );
+        assert!(shots > 0, "State tomography needs at least one shot");
+
+        let settings = 3usize.pow(self.num_qubits as u32);
+        for setting in 0..settings {
+            let bases: Vec<Basis> = (0..self.num_qubits)
+                .map(|qubit| match setting / 3usize.pow(qubit as u32) % 3 {
+                    0 => Basis::X,
+                    1 => Basis::Y,
+                    _ => Basis::Z,
+                })
+                .collect();
+
+            // Build the rotated state directly, so an entangled state is not flagged as a clone.
+            let mut rotated = StateVector {
+                num_qubits: state_vector.num_qubits,
+                amplitudes: state_vector.amplitudes.clone(),
+            };
+            for (qubit, basis) in bases.iter().enumerate() {
+                rotated.rotate_basis(*basis, &[qubit]);
+            }
+            let probabilities: Vec<f64> = rotated
+                .amplitudes
+                .iter()
+                .map(|amplitude| amplitude.norm_sqr())
+                .collect();
+
+            let mut counts = vec![0; probabilities.len()];
+            for _ in 0..shots {
+                counts[sample_index(&probabilities, rng.random())] += 1;
+            }
+            self = self.with_counts(&bases, &counts);
+        }
+        self
+    }
+
+    /// [`StateTomography::linear_inversion`] will reconstruct the [`DensityMatrix`] from the
+    /// expectation value of every [`PauliString`] $P$:
+    /// $$ \rho = \frac{1}{2^n} \sum_P \langle P \rangle P $$
+    /// where each $\langle P \rangle$ is averaged over every measurement setting that measures it,
+    /// and is `0` if none do, except for the identity, which is always `1`.
+    ///
+    /// With few shots the result can have small negative eigenvalues, which
+    /// [`StateTomography::maximum_likelihood`] avoids.
+    ///
+    /// # Example
+    /// [`StateTomography::linear_inversion`] can be used to get the purity of a measured state:
+    /// ```rust
+    /// use rquant::benchmarking::types::state_tomography::StateTomography;
+    ///
+    /// fn get_purity(tomography: &StateTomography) -> f64 {
+    ///     tomography.linear_inversion().purity()
+    /// }
+    /// ```
+    pub fn linear_inversion(&self) -> DensityMatrix {
+        let dimension = 1 << self.num_qubits;
+        let mut elements = vec![vec![Complex::new(0.0, 0.0); dimension]; dimension];
+        for pauli_index in 0..4usize.pow(self.num_qubits as u32) {
+            let paulis: Vec<Pauli> = (0..self.num_qubits)
+                .map(|qubit| match pauli_index / 4usize.pow(qubit as u32) % 4 {
+                    0 => Pauli::I,
+                    1 => Pauli::X,
+                    2 => Pauli::Y,
+                    _ => Pauli::Z,
+                })
+                .collect();
+            let expectation = self.expectation(&paulis);
+            if expectation == 0.0 {
+                continue;
+            }
+
+            let pauli_string = PauliString {
+                negative: false,
+                paulis,
+            };
+            let images = (0..dimension).map(|column| pauli_string.basis_image(column));
+            for (column, (row, phase)) in images.enumerate() {
+                elements[row][column] += phase * expectation / dimension as f64;
+            }
+        }
+
+        DensityMatrix {
+            num_qubits: self.num_qubits,
+            elements,
+        }
+    }
+
+    /// [`StateTomography::maximum_likelihood`] will reconstruct the physical [`DensityMatrix`] that
+    /// most likely produced the measured counts, with `iterations` of the iterative $R \rho R$
+    /// algorithm starting from the maximally mixed state:
+    /// $$ \rho \leftarrow \frac{R \rho R}{\mathrm{Tr}(R \rho R)}, \quad
+    /// R = \sum_j \frac{f_j}{\mathrm{Tr}(\rho \Pi_j)} \Pi_j $$
+    /// where $f_j$ is the measured frequency of each outcome $\Pi_j$.
+    ///
+    /// # Example
+    /// [`StateTomography::maximum_likelihood`] can be used to reconstruct a state from few shots:
+    /// ```rust
+    /// use rquant::{
+    ///     benchmarking::types::state_tomography::StateTomography,
+    ///     quantum::types::density_matrix::DensityMatrix,
+    /// };
+    ///
+    /// fn reconstruct(tomography: &StateTomography) -> DensityMatrix {
+    ///     tomography.maximum_likelihood(200)
+    /// }
+    /// ```
+    pub fn maximum_likelihood(&self, iterations: usize) -> DensityMatrix {
+        let dimension = 1 << self.num_qubits;
+        // Every outcome of every setting is the projector onto a product of basis states.
+        let outcomes: Vec<(f64, Vec<Complex<f64>>)> = self
+            .measurements
+            .iter()
+            .flat_map(|(bases, counts)| {
+                let total: usize = counts.iter().sum();
+                counts
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, count)| **count > 0)
+                    .map(move |(index, count)| {
+                        (*count as f64 / total as f64, self.eigenstate(bases, index))
+                    })
+            })
+            .collect();
+
+        let mut rho: Vec<Vec<Complex<f64>>> = (0..dimension)
+            .map(|row| {
+                (0..dimension)
+                    .map(|column| {
+                        let value = if row == column { 1.0 } else { 0.0 };
+                        Complex::new(value / dimension as f64, 0.0)
+                    })
+                    .collect()
+            })
+            .collect();
+        for _ in 0..iterations {
+            let mut r = vec![vec![Complex::new(0.0, 0.0); dimension]; dimension];
+            for (frequency, state) in &outcomes {
+                let probability: f64 = (0..dimension)
+                    .flat_map(|row| (0..dimension).map(move |column| (row, column)))
+                    .map(|(row, column)| state[row].conj() * rho[row][column] * state[column])
+                    .sum::<Complex<f64>>()
+                    .re;
+                if probability <= f64::EPSILON {
+                    continue;
+                }
+                for (row, values) in r.iter_mut().enumerate() {
+                    for (column, value) in values.iter_mut().enumerate() {
+                        *value += state[row] * state[column].conj() * frequency / probability;
+                    }
+                }
+            }
+
+            let product = matrix_multiply(&matrix_multiply(&r, &rho), &r);
+            let trace: f64 = (0..dimension).map(|index| product[index][index].re).sum();
+            rho = product
+                .iter()
+                .map(|row| row.iter().map(|value| value / trace).collect())
+                .collect();
+        }
+
+        DensityMatrix {
+            num_qubits: self.num_qubits,
+            elements: rho,
+        }
+    }
+
+    /// [`StateTomography::expectation`] will get the average expectation value of the `paulis`
+    /// over every measurement setting that measures each non-identity [`Pauli`] in it's
+    /// [`Basis`], `1` for the identity, or `0` if no setting measures them.
+    fn expectation(&self, paulis: &[Pauli]) -> f64 {
+        // The identity has an expectation of 1 for every state, measured or not.
+        if paulis.iter().all(|pauli| *pauli == Pauli::I) {
+            return 1.0;
+        }
+
+        let parity_mask = paulis
+            .iter()
+            .enumerate()
+            .filter(|(_, pauli)| **pauli != Pauli::I)
+            .fold(0, |mask, (qubit, _)| {
+                mask | 1 << (self.num_qubits - 1 - qubit)
+            });
+        let estimates: Vec<f64> = self
+            .measurements
+            .iter()
+            .filter(|(bases, _)| {
+                paulis.iter().zip(bases).all(|(pauli, basis)| {
+                    matches!(
+                        (pauli, basis),
+                        (Pauli::I, _)
+                            | (Pauli::X, Basis::X)
+                            | (Pauli::Y, Basis::Y)
+                            | (Pauli::Z, Basis::Z)
+                    )
+                })
+            })
+            .filter_map(|(_, counts)| {
+                let total: usize = counts.iter().sum();
+                (total > 0).then(|| {
+                    counts
+                        .iter()
+                        .enumerate()
+                        .map(
+                            |(index, count)| match (index & parity_mask).count_ones() % 2 {
+                                0 => *count as f64,
+                                _ => -(*count as f64),
+                            },
+                        )
+                        .sum::<f64>()
+                        / total as f64
+                })
+            })
+            .collect();
+
+        if estimates.is_empty() {
+            0.0
+        } else {
+            estimates.iter().sum::<f64>() / estimates.len() as f64
+        }
+    }
+
+    /// [`StateTomography::eigenstate`] will get the product state that is measured as the basis
+    /// state at `index` in the `bases`, where the first qubit is the most significant bit.
+    fn eigenstate(&self, bases: &[Basis], index: usize) -> Vec<Complex<f64>> {
+        bases
+            .iter()
+            .enumerate()
+            .fold(vec![Complex::new(1.0, 0.0)], |state, (qubit, basis)| {
+                let is_one = index & (1 << (self.num_qubits - 1 - qubit)) != 0;
+                let sign = if is_one { -1.0 } else { 1.0 };
+                let [zero, one] = match (basis, is_one) {
+                    (Basis::Z, false) => [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+                    (Basis::Z, true) => [Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+                    (Basis::X, _) => [
+                        Complex::new(FRAC_1_SQRT_2, 0.0),
+                        Complex::new(sign * FRAC_1_SQRT_2, 0.0),
+                    ],
+                    _ => [
+                        Complex::new(FRAC_1_SQRT_2, 0.0),
+                        Complex::new(0.0, sign * FRAC_1_SQRT_2),
+                    ],
+                };
+                state
+                    .iter()
+                    .flat_map(|amplitude| [amplitude * zero, amplitude * one])
+                    .collect()
+            })
+    }
+}
@@ -0,0 +1,10 @@
+use crate::quantum::types::quantum_operators::QuantumOperator;
+
+/// [`Clifford`] is one of the 24 single-qubit Clifford gates, which map Pauli operators to Pauli
+/// operators, written as a sequence of Hadamard and $R_Z(\pi/2)$ gates.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Clifford {
+    /// The [`QuantumOperators`](QuantumOperator) that make up the [`Clifford`], in the order they
+    /// are applied.
+    pub operators: Vec<QuantumOperator>,
+}
@@ -0,0 +1,23 @@
+use crate::quantum::types::quantum_operators::QuantumOperator;
+
+/// [`GateEstimate`] is the outcome of characterizing one gate with
+/// [`GateSetTomography`](crate::benchmarking::types::gate_set_tomography::GateSetTomography),
+/// where every matrix is a Pauli transfer matrix in the $I, X, Y, Z$ basis.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GateEstimate {
+    /// The [`QuantumOperator`] of the gate.
+    pub operator: QuantumOperator,
+
+    /// The Pauli transfer matrix of the noiseless gate.
+    pub ideal: [[f64; 4]; 4],
+
+    /// The estimated Pauli transfer matrix of the noisy gate.
+    pub estimated: [[f64; 4]; 4],
+
+    /// The error generator $L$ of the gate, where the estimated gate is $e^L$ applied after the
+    /// ideal gate.
+    pub error_generator: [[f64; 4]; 4],
+
+    /// The average gate fidelity of the estimated gate against the ideal gate.
+    pub average_gate_fidelity: f64,
+}
@@ -0,0 +1,21 @@
+use crate::{
+    noise::types::noise_model::NoiseModel, quantum::types::quantum_operators::QuantumOperator,
+};
+
+/// [`GateSetTomography`] is a simplified, linear inversion form of gate set tomography, which
+/// estimates the Pauli transfer matrix of every single-qubit gate in a set under a [`NoiseModel`].
+///
+/// The state preparation and measurement are characterized together with the gates, through
+/// the same fiducial circuits, so their errors are not mistaken for gate errors like they are in
+/// individual process tomography.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GateSetTomography {
+    /// The [`NoiseModel`] every circuit is run under.
+    pub noise_model: NoiseModel,
+
+    /// The [`QuantumOperators`](QuantumOperator) of the gates that are characterized.
+    pub operators: Vec<QuantumOperator>,
+
+    /// The amount of shots each circuit is measured with.
+    pub shots: usize,
+}
@@ -0,0 +1,22 @@
+use crate::benchmarking::types::rb_result::RbResult;
+
+/// [`InterleavedRbResult`] is the outcome of interleaved randomized benchmarking, which compares
+/// random sequences with and without a specific gate after every
+/// [`Clifford`](crate::benchmarking::types::clifford::Clifford) to estimate the fidelity of that
+/// gate alone.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterleavedRbResult {
+    /// The [`RbResult`] of the random sequences.
+    pub reference: RbResult,
+
+    /// The [`RbResult`] of the random sequences with the gate after every
+    /// [`Clifford`](crate::benchmarking::types::clifford::Clifford).
+    pub interleaved: RbResult,
+
+    /// The estimated fidelity of the gate, $\frac{1 + p_{int}/p_{ref}}{2}$.
+    pub gate_fidelity: f64,
+
+    /// The 95% confidence interval of `gate_fidelity` from the fit errors, as the lower and
+    /// upper bound.
+    pub confidence_interval: (f64, f64),
+}
@@ -0,0 +1,21 @@
+use crate::noise::types::noise_model::NoiseModel;
+
+/// [`RandomizedBenchmarking`] estimates the average error of single-qubit gates under a
+/// [`NoiseModel`], by running random sequences of [`Cliffords`](crate::benchmarking::types::clifford::Clifford)
+/// that should return to $|0\rangle$ and measuring how quickly the survival probability decays
+/// with the length of the sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomizedBenchmarking {
+    /// The [`NoiseModel`] every sequence is run under.
+    pub noise_model: NoiseModel,
+
+    /// The amounts of random [`Cliffords`](crate::benchmarking::types::clifford::Clifford) in each
+    /// sequence.
+    pub lengths: Vec<usize>,
+
+    /// The amount of random sequences run for each length.
+    pub sequences: usize,
+
+    /// The amount of shots each sequence is measured with.
+    pub shots: usize,
+}
@@ -0,0 +1,20 @@
+/// [`RbResult`] is the outcome of running
+/// [`RandomizedBenchmarking`](crate::benchmarking::types::randomized_benchmarking::RandomizedBenchmarking),
+/// where the survival probability of a sequence of $m$ gates is fit to $A p^m + \frac{1}{2}$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RbResult {
+    /// The amounts of gates in each sequence.
+    pub lengths: Vec<usize>,
+
+    /// The average probability of measuring $|0\rangle$ at the end of the sequences of each length.
+    pub survival_probabilities: Vec<f64>,
+
+    /// The fitted decay $p$ of the survival probability per gate.
+    pub decay: f64,
+
+    /// The standard error of $\ln p$ from the fit.
+    pub decay_std_error: f64,
+
+    /// The average gate fidelity $\frac{1 + p}{2}$.
+    pub average_gate_fidelity: f64,
+}
@@ -0,0 +1,15 @@
+use crate::quantum::types::basis::Basis;
+
+/// [`StateTomography`] reconstructs the
+/// [`DensityMatrix`](crate::quantum::types::density_matrix::DensityMatrix) of a state from the
+/// counts of measuring it in the $X$, $Y$, and $Z$ [`Basis`] of every
+/// [`Qubit`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateTomography {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) of the reconstructed state.
+    pub num_qubits: usize,
+
+    /// The [`Basis`] of every qubit for each measurement setting, and the counts of every basis
+    /// state measured with it, where the first qubit is the most significant bit.
+    pub measurements: Vec<(Vec<Basis>, Vec<usize>)>,
+}
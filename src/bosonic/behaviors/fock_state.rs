@@ -0,0 +1,187 @@
+use num_complex::Complex;
+
+use crate::bosonic::types::fock_state::FockState;
+
+impl FockState {
+    /// [`FockState::new`] will create a new [`FockState`] with `cutoff` levels in the vacuum
+    /// state $|0\rangle$.
+    ///
+    /// # Example
+    /// [`FockState::new`] can be used to create an empty cavity:
+    /// ```rust
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn create_empty_cavity() -> FockState {
+    ///     FockState::new(10)
+    /// }
+    /// ```
+    pub fn new(cutoff: usize) -> Self {
+        FockState::number(cutoff, 0)
+    }
+
+    /// [`FockState::number`] will create a new [`FockState`] with `cutoff` levels and exactly
+    /// `excitations` quanta, which is the number state $|n\rangle$.
+    ///
+    /// # Example
+    /// [`FockState::number`] can be used to create a cavity with a single photon:
+    /// ```rust
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn create_single_photon() -> FockState {
+    ///     FockState::number(10, 1)
+    /// }
+    /// ```
+    pub fn number(cutoff: usize, excitations: usize) -> Self {
+        assert!(
+            excitations < cutoff,
+            "Fock state must have a level for every excitation"
+        );
+
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); cutoff];
+        amplitudes[excitations] = Complex::new(1.0, 0.0);
+        FockState { cutoff, amplitudes }
+    }
+
+    /// [`FockState::coherent`] will create a new [`FockState`] with `cutoff` levels in the
+    /// coherent state $|\alpha\rangle$, which is the closest an oscillator gets to a classical
+    /// wave:
+    /// $$ |\alpha\rangle = e^{-|\alpha|^2 / 2} \sum_n \frac{\alpha^n}{\sqrt{n!}} |n\rangle $$
+    ///
+    /// The amplitudes are renormalized after truncation, so `cutoff` should be well above
+    /// $|\alpha|^2$.
+    ///
+    /// # Example
+    /// [`FockState::coherent`] can be used to create a weak laser pulse:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn create_laser_pulse() -> FockState {
+    ///     FockState::coherent(20, Complex::new(1.5, 0.0))
+    /// }
+    /// ```
+    pub fn coherent(cutoff: usize, alpha: Complex<f64>) -> Self {
+        assert!(cutoff > 0, "Fock state must have at least one level");
+
+        let mut amplitudes = Vec::with_capacity(cutoff);
+        let mut amplitude = Complex::new(1.0, 0.0);
+        for level in 0..cutoff {
+            if level > 0 {
+                amplitude = amplitude * alpha / (level as f64).sqrt();
+            }
+            amplitudes.push(amplitude);
+        }
+        let norm = amplitudes
+            .iter()
+            .map(|amplitude| amplitude.norm_sqr())
+            .sum::<f64>()
+            .sqrt();
+        FockState {
+            cutoff,
+            amplitudes: amplitudes
+                .iter()
+                .map(|amplitude| amplitude / norm)
+                .collect(),
+        }
+    }
+
+    /// [`FockState::annihilation`] will get the matrix of the annihilation operator $a$ on
+    /// `cutoff` levels, which removes one quantum: $a|n\rangle = \sqrt{n}|n - 1\rangle$.
+    ///
+    /// # Example
+    /// [`FockState::annihilation`] can be used to build the position quadrature $a + a^\dagger$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn position_quadrature(cutoff: usize) -> Vec<Vec<Complex<f64>>> {
+    ///     let lower = FockState::annihilation(cutoff);
+    ///     let raise = FockState::creation(cutoff);
+    ///     lower
+    ///         .iter()
+    ///         .zip(&raise)
+    ///         .map(|(a, b)| a.iter().zip(b).map(|(x, y)| x + y).collect())
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn annihilation(cutoff: usize) -> Vec<Vec<Complex<f64>>> {
+        (0..cutoff)
+            .map(|row| {
+                (0..cutoff)
+                    .map(|column| match column == row + 1 {
+                        true => Complex::new((column as f64).sqrt(), 0.0),
+                        false => Complex::new(0.0, 0.0),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// [`FockState::creation`] will get the matrix of the creation operator $a^\dagger$ on
+    /// `cutoff` levels, which adds one quantum: $a^\dagger|n\rangle = \sqrt{n + 1}|n + 1\rangle$.
+    ///
+    /// The highest level has nowhere to go, so $a^\dagger$ sends it to zero.
+    ///
+    /// # Example
+    /// [`FockState::creation`] can be used to add a photon to a cavity:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn add_photon(state: &FockState) -> Vec<Complex<f64>> {
+    ///     FockState::creation(state.cutoff)
+    ///         .iter()
+    ///         .map(|row| row.iter().zip(&state.amplitudes).map(|(a, b)| a * b).sum())
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn creation(cutoff: usize) -> Vec<Vec<Complex<f64>>> {
+        let annihilation = FockState::annihilation(cutoff);
+        (0..cutoff)
+            .map(|row| {
+                (0..cutoff)
+                    .map(|column| annihilation[column][row].conj())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// [`FockState::probabilities`] will get the probability of measuring each amount of
+    /// excitations in a [`FockState`].
+    ///
+    /// # Example
+    /// [`FockState::probabilities`] can be used to check if a cavity is empty:
+    /// ```rust
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn is_empty(state: &FockState) -> bool {
+    ///     (state.probabilities()[0] - 1.0).abs() < 1e-9
+    /// }
+    /// ```
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.amplitudes
+            .iter()
+            .map(|amplitude| amplitude.norm_sqr())
+            .collect()
+    }
+
+    /// [`FockState::mean_number`] will get the average amount of excitations
+    /// $\langle a^\dagger a \rangle$ of a [`FockState`].
+    ///
+    /// # Example
+    /// [`FockState::mean_number`] can be used to get the average amount of photons in a cavity:
+    /// ```rust
+    /// use rquant::bosonic::types::fock_state::FockState;
+    ///
+    /// fn average_photons(state: &FockState) -> f64 {
+    ///     state.mean_number()
+    /// }
+    /// ```
+    pub fn mean_number(&self) -> f64 {
+        self.probabilities()
+            .iter()
+            .enumerate()
+            .map(|(level, probability)| level as f64 * probability)
+            .sum()
+    }
+}
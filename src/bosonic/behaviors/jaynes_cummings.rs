@@ -0,0 +1,161 @@
+use num_complex::Complex;
+
+use crate::{
+    bosonic::types::{fock_state::FockState, jaynes_cummings::JaynesCummings},
+    quantum::{behaviors::hamiltonian::matrix_exponential, types::qubit::Qubit},
+};
+
+impl JaynesCummings {
+    /// [`JaynesCummings::new`] will create a new [`JaynesCummings`] system from the product of a
+    /// [`Qubit`] and a [`FockState`], with a `cavity_frequency` $\omega_c$, a `qubit_frequency`
+    /// $\omega_q$ and a `coupling` $g$.
+    ///
+    /// # Example
+    /// [`JaynesCummings::new`] can be used to put an excited atom in an empty resonant cavity:
+    /// ```rust
+    /// use rquant::{
+    ///     bosonic::types::{fock_state::FockState, jaynes_cummings::JaynesCummings},
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn create_excited_atom() -> JaynesCummings {
+    ///     JaynesCummings::new(&Qubit::one(), &FockState::new(5), 1.0, 1.0, 0.1)
+    /// }
+    /// ```
+    pub fn new(
+        qubit: &Qubit,
+        field: &FockState,
+        cavity_frequency: f64,
+        qubit_frequency: f64,
+        coupling: f64,
+    ) -> Self {
+        let amplitudes = [qubit.initial_position(), qubit.possible_position()]
+            .iter()
+            .flat_map(|qubit_amplitude| {
+                field
+                    .amplitudes
+                    .iter()
+                    .map(move |field_amplitude| qubit_amplitude * field_amplitude)
+            })
+            .collect();
+
+        JaynesCummings {
+            cutoff: field.cutoff,
+            cavity_frequency,
+            qubit_frequency,
+            coupling,
+            amplitudes,
+        }
+    }
+
+    /// [`JaynesCummings::hamiltonian`] will get the matrix of the Jaynes–Cummings Hamiltonian, in
+    /// the same joint basis as the [`amplitudes`](JaynesCummings::amplitudes).
+    ///
+    /// # Example
+    /// [`JaynesCummings::hamiltonian`] can be used to get the energy of a joint basis state:
+    /// ```rust
+    /// use rquant::bosonic::types::jaynes_cummings::JaynesCummings;
+    ///
+    /// fn basis_energy(system: &JaynesCummings, index: usize) -> f64 {
+    ///     system.hamiltonian()[index][index].re
+    /// }
+    /// ```
+    pub fn hamiltonian(&self) -> Vec<Vec<Complex<f64>>> {
+        let dimension = 2 * self.cutoff;
+        let annihilation = FockState::annihilation(self.cutoff);
+        let mut hamiltonian = vec![vec![Complex::new(0.0, 0.0); dimension]; dimension];
+        for level in 0..self.cutoff {
+            let energy = self.cavity_frequency * level as f64;
+            hamiltonian[level][level] += energy;
+            hamiltonian[self.cutoff + level][self.cutoff + level] += energy + self.qubit_frequency;
+        }
+
+        // a σ+ takes |0>|n> to |1>|n - 1>, and a† σ- is it's conjugate.
+        for row in 0..self.cutoff {
+            for column in 0..self.cutoff {
+                let exchange = annihilation[row][column] * self.coupling;
+                hamiltonian[self.cutoff + row][column] += exchange;
+                hamiltonian[column][self.cutoff + row] += exchange.conj();
+            }
+        }
+        hamiltonian
+    }
+
+    /// [`JaynesCummings::evolve`] will evolve the joint state for a `time` $t$ by applying
+    /// $e^{-iHt}$.
+    ///
+    /// # Example
+    /// [`JaynesCummings::evolve`] can be used to let an excited atom emit a photon into a resonant
+    /// cavity, which takes half of a vacuum Rabi period:
+    /// ```rust
+    /// use rquant::{
+    ///     bosonic::types::{fock_state::FockState, jaynes_cummings::JaynesCummings},
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn emit_photon(coupling: f64) -> JaynesCummings {
+    ///     let mut system = JaynesCummings::new(&Qubit::one(), &FockState::new(3), 1.0, 1.0, coupling);
+    ///     system.evolve(std::f64::consts::FRAC_PI_2 / coupling);
+    ///     system
+    /// }
+    /// ```
+    pub fn evolve(&mut self, time: f64) {
+        let generator: Vec<Vec<Complex<f64>>> = self
+            .hamiltonian()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| entry * Complex::new(0.0, -time))
+                    .collect()
+            })
+            .collect();
+        self.amplitudes = matrix_exponential(&generator)
+            .iter()
+            .map(|row| row.iter().zip(&self.amplitudes).map(|(a, b)| a * b).sum())
+            .collect();
+    }
+
+    /// [`JaynesCummings::excited_probability`] will get the probability of measuring the qubit in
+    /// $|1\rangle$.
+    ///
+    /// # Example
+    /// [`JaynesCummings::excited_probability`] can be used to check if the atom has decayed:
+    /// ```rust
+    /// use rquant::bosonic::types::jaynes_cummings::JaynesCummings;
+    ///
+    /// fn has_decayed(system: &JaynesCummings) -> bool {
+    ///     system.excited_probability() < 1e-9
+    /// }
+    /// ```
+    pub fn excited_probability(&self) -> f64 {
+        self.amplitudes[self.cutoff..]
+            .iter()
+            .map(|amplitude| amplitude.norm_sqr())
+            .sum()
+    }
+
+    /// [`JaynesCummings::field_probabilities`] will get the probability of measuring each amount of
+    /// excitations in the oscillator, summed over both states of the qubit.
+    ///
+    /// # Example
+    /// [`JaynesCummings::field_probabilities`] can be used to get the average amount of photons:
+    /// ```rust
+    /// use rquant::bosonic::types::jaynes_cummings::JaynesCummings;
+    ///
+    /// fn average_photons(system: &JaynesCummings) -> f64 {
+    ///     system
+    ///         .field_probabilities()
+    ///         .iter()
+    ///         .enumerate()
+    ///         .map(|(level, probability)| level as f64 * probability)
+    ///         .sum()
+    /// }
+    /// ```
+    pub fn field_probabilities(&self) -> Vec<f64> {
+        (0..self.cutoff)
+            .map(|level| {
+                self.amplitudes[level].norm_sqr() + self.amplitudes[self.cutoff + level].norm_sqr()
+            })
+            .collect()
+    }
+}
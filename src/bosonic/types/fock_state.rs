@@ -0,0 +1,16 @@
+use num_complex::Complex;
+
+/// [`FockState`] holds the amplitudes of a quantum harmonic oscillator, such as a single mode of
+/// light in a cavity, in the number basis $|0\rangle, |1\rangle, \ldots, |N - 1\rangle$.
+///
+/// An oscillator has infinitely many levels, so a [`FockState`] is truncated to the first `cutoff`
+/// levels, which is accurate as long as the higher levels are never populated.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FockState {
+    /// The amount of levels $N$ kept in the [`FockState`].
+    pub cutoff: usize,
+
+    /// The [`Complex<f64>`] amplitude of every number state, where the index is the amount of
+    /// excitations.
+    pub amplitudes: Vec<Complex<f64>>,
+}
@@ -0,0 +1,25 @@
+use num_complex::Complex;
+
+/// [`JaynesCummings`] holds the joint state of a [`Qubit`](crate::quantum::types::qubit::Qubit)
+/// coupled to a truncated [`FockState`](crate::bosonic::types::fock_state::FockState), which
+/// evolves under the Jaynes–Cummings [`Hamiltonian`](crate::quantum::types::hamiltonian::Hamiltonian):
+/// $$ H = \omega_c a^\dagger a + \omega_q |1\rangle\langle 1| + g (a \sigma_+ + a^\dagger \sigma_-) $$
+#[derive(Clone, Debug, PartialEq)]
+pub struct JaynesCummings {
+    /// The amount of levels kept in the oscillator.
+    pub cutoff: usize,
+
+    /// The energy $\omega_c$ of a single excitation of the oscillator.
+    pub cavity_frequency: f64,
+
+    /// The energy $\omega_q$ of the excited state $|1\rangle$ of the qubit.
+    pub qubit_frequency: f64,
+
+    /// The strength $g$ of the exchange of excitations between the qubit and the oscillator.
+    pub coupling: f64,
+
+    /// The [`Complex<f64>`] amplitude of every joint basis state, where the qubit is the most
+    /// significant part of the index, so the amplitude of $|q\rangle|n\rangle$ is at
+    /// $q \cdot N + n$.
+    pub amplitudes: Vec<Complex<f64>>,
+}
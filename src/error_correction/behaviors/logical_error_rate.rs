@@ -0,0 +1,26 @@
+use std::fmt;
+
+use crate::error_correction::types::logical_error_rate::LogicalErrorRate;
+
+/// Implement the [`fmt::Display`] trait for [`LogicalErrorRate`].
+impl fmt::Display for LogicalErrorRate {
+    /// Will return a [`String`] representation of a [`LogicalErrorRate`], which compares the
+    /// physical and logical error rates.
+    ///
+    /// # Example
+    /// Can be used to print a [`LogicalErrorRate`] to the console:
+    /// ```rust
+    /// use rquant::error_correction::types::logical_error_rate::LogicalErrorRate;
+    ///
+    /// fn print_logical_error_rate(logical_error_rate: LogicalErrorRate) {
+    ///     println!("{logical_error_rate}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "physical: {:.4}, logical: {:.4} ({} rounds, {} shots)",
+            self.physical_error_rate, self.logical_error_rate, self.rounds, self.shots
+        )
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+
+use crate::error_correction::types::{
+    decoder::Decoder, lookup_table_decoder::LookupTableDecoder, parity_check_code::ParityCheckCode,
+};
+
+impl LookupTableDecoder {
+    /// [`LookupTableDecoder::new`] will create a new [`LookupTableDecoder`] for a [`ParityCheckCode`]
+    /// by calculating the syndrome of every error of up to `max_weight` flipped
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), keeping the lowest weight error for each syndrome.
+    ///
+    /// # Example
+    /// [`LookupTableDecoder::new`] can be used to decode any single bit flip:
+    /// ```rust
+    /// use rquant::error_correction::types::{
+    ///     lookup_table_decoder::LookupTableDecoder,
+    ///     parity_check_code::ParityCheckCode,
+    /// };
+    ///
+    /// fn create_single_error_decoder(code: &ParityCheckCode) -> LookupTableDecoder {
+    ///     LookupTableDecoder::new(code, 1)
+    /// }
+    /// ```
+    pub fn new(code: &ParityCheckCode, max_weight: usize) -> Self {
+        let mut table = HashMap::new();
+        for weight in 0..=max_weight.min(code.num_qubits) {
+            for errors in combinations(code.num_qubits, weight) {
+                table.entry(code.syndrome(&errors)).or_insert(errors);
+            }
+        }
+        LookupTableDecoder { table }
+    }
+}
+
+/// Implement the [`Decoder`] trait for [`LookupTableDecoder`].
+impl Decoder for LookupTableDecoder {
+    /// Looks up the correction for a `syndrome`, and returns no correction for a
+    /// `syndrome` that is not in the table.
+    fn decode(&self, syndrome: &[bool]) -> Vec<usize> {
+        self.table.get(syndrome).cloned().unwrap_or_default()
+    }
+}
+
+/// [`combinations`] will get every ascending collection of `size` indices below `count`.
+fn combinations(count: usize, size: usize) -> Vec<Vec<usize>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+    (size - 1..count)
+        .flat_map(|last| {
+            combinations(last, size - 1)
+                .into_iter()
+                .map(move |mut combination| {
+                    combination.push(last);
+                    combination
+                })
+        })
+        .collect()
+}
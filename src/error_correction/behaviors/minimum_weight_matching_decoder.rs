@@ -0,0 +1,139 @@
+use std::collections::VecDeque;
+
+use crate::error_correction::types::{
+    decoder::Decoder, minimum_weight_matching_decoder::MinimumWeightMatchingDecoder,
+    parity_check_code::ParityCheckCode,
+};
+
+impl MinimumWeightMatchingDecoder {
+    /// [`MinimumWeightMatchingDecoder::new`] will create a new [`MinimumWeightMatchingDecoder`] for a
+    /// [`ParityCheckCode`].
+    ///
+    /// Every [`Qubit`](crate::quantum::types::qubit::Qubit) of the [`ParityCheckCode`] must be in at most
+    /// two parity checks, so that each qubit is an edge between two checks (or a check and the boundary).
+    ///
+    /// # Example
+    /// [`MinimumWeightMatchingDecoder::new`] can be used to decode a repetition code:
+    /// ```rust
+    /// use rquant::error_correction::types::{
+    ///     minimum_weight_matching_decoder::MinimumWeightMatchingDecoder,
+    ///     parity_check_code::ParityCheckCode,
+    /// };
+    ///
+    /// fn create_repetition_decoder() -> MinimumWeightMatchingDecoder {
+    ///     let code = ParityCheckCode::new(5, vec![vec![0, 1], vec![1, 2], vec![2, 3], vec![3, 4]]);
+    ///     MinimumWeightMatchingDecoder::new(code)
+    /// }
+    /// ```
+    pub fn new(code: ParityCheckCode) -> Self {
+        // Matching only works when each qubit connects at most two checks.
+        assert!(
+            (0..code.num_qubits).all(|qubit| code.checks_for(qubit).len() <= 2),
+            "Every qubit must be in at most two parity checks"
+        );
+
+        MinimumWeightMatchingDecoder { code }
+    }
+
+    /// [`MinimumWeightMatchingDecoder::shortest_path`] will get the qubits along the shortest path from
+    /// the check at `from` to the check at `to`, where the boundary is the index after the last check,
+    /// and returns [`None`] if the checks are not connected.
+    fn shortest_path(&self, from: usize, to: usize) -> Option<Vec<usize>> {
+        let boundary = self.code.checks.len();
+        let mut previous: Vec<Option<(usize, usize)>> = vec![None; boundary + 1];
+        let mut visited = vec![false; boundary + 1];
+        let mut queue = VecDeque::from([from]);
+        visited[from] = true;
+
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                let mut path = vec![];
+                let mut current = to;
+                while let Some((node, qubit)) = previous[current] {
+                    path.push(qubit);
+                    current = node;
+                }
+                return Some(path);
+            }
+            for qubit in 0..self.code.num_qubits {
+                let checks = self.code.checks_for(qubit);
+                let neighbor = match checks.as_slice() {
+                    [check] if *check == node => boundary,
+                    [first, second] if *first == node => *second,
+                    [first, second] if *second == node => *first,
+                    [check] if node == boundary => *check,
+                    _ => continue,
+                };
+                if !visited[neighbor] {
+                    visited[neighbor] = true;
+                    previous[neighbor] = Some((node, qubit));
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        None
+    }
+
+    /// [`MinimumWeightMatchingDecoder::best_matching`] will get the lowest weight correction that pairs
+    /// up every flagged check in `defects`, either with each other or with the boundary.
+    fn best_matching(&self, defects: &[usize]) -> Option<Vec<usize>> {
+        let Some((&first, rest)) = defects.split_first() else {
+            return Some(vec![]);
+        };
+        let boundary = self.code.checks.len();
+
+        let mut candidates = vec![];
+        if let (Some(path), Some(matching)) = (
+            self.shortest_path(first, boundary),
+            self.best_matching(rest),
+        ) {
+            candidates.push(symmetric_difference(path, matching));
+        }
+        for (index, &partner) in rest.iter().enumerate() {
+            let remaining: Vec<usize> = rest
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .map(|(_, &defect)| defect)
+                .collect();
+            if let (Some(path), Some(matching)) = (
+                self.shortest_path(first, partner),
+                self.best_matching(&remaining),
+            ) {
+                candidates.push(symmetric_difference(path, matching));
+            }
+        }
+        candidates
+            .into_iter()
+            .min_by_key(|correction| correction.len())
+    }
+}
+
+/// Implement the [`Decoder`] trait for [`MinimumWeightMatchingDecoder`].
+impl Decoder for MinimumWeightMatchingDecoder {
+    /// Matches the flagged checks of a `syndrome` with the fewest corrections, and returns
+    /// no correction if the flagged checks can not be matched.
+    fn decode(&self, syndrome: &[bool]) -> Vec<usize> {
+        let defects: Vec<usize> = syndrome
+            .iter()
+            .enumerate()
+            .filter(|(_, &flagged)| flagged)
+            .map(|(index, _)| index)
+            .collect();
+        let mut correction = self.best_matching(&defects).unwrap_or_default();
+        correction.sort_unstable();
+        correction
+    }
+}
+
+/// [`symmetric_difference`] will get the qubits that are in exactly one of two corrections,
+/// because correcting a qubit twice leaves it unchanged.
+fn symmetric_difference(first: Vec<usize>, second: Vec<usize>) -> Vec<usize> {
+    let mut difference: Vec<usize> = first
+        .iter()
+        .filter(|qubit| !second.contains(qubit))
+        .copied()
+        .collect();
+    difference.extend(second.iter().filter(|qubit| !first.contains(qubit)));
+    difference
+}
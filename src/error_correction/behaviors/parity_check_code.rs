@@ -0,0 +1,181 @@
+use rand::Rng;
+
+use crate::{
+    error_correction::types::{
+        decoder::Decoder, logical_error_rate::LogicalErrorRate, parity_check_code::ParityCheckCode,
+    },
+    quantum::types::{quantum_gate::QuantumGate, qubit_register::QubitRegister},
+};
+
+impl ParityCheckCode {
+    /// [`ParityCheckCode::new`] will create a new [`ParityCheckCode`] over `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), given the qubit indices of each parity check.
+    ///
+    /// # Example
+    /// [`ParityCheckCode::new`] can be used to create the three qubit bit-flip code:
+    /// ```rust
+    /// use rquant::error_correction::types::parity_check_code::ParityCheckCode;
+    ///
+    /// fn create_bit_flip_code() -> ParityCheckCode {
+    ///     ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]])
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize, checks: Vec<Vec<usize>>) -> Self {
+        // Every parity check must only measure qubits that are part of the code.
+        assert!(
+            checks.iter().flatten().all(|&qubit| qubit < num_qubits),
+            "Invalid parity check qubit index"
+        );
+
+        ParityCheckCode { num_qubits, checks }
+    }
+
+    /// [`ParityCheckCode::syndrome`] will get the outcome of every parity check when the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices in `errors` are flipped.
+    ///
+    /// # Example
+    /// [`ParityCheckCode::syndrome`] can be used to find which checks a bit flip triggers:
+    /// ```rust
+    /// use rquant::error_correction::types::parity_check_code::ParityCheckCode;
+    ///
+    /// fn middle_qubit_syndrome(code: &ParityCheckCode) -> Vec<bool> {
+    ///     code.syndrome(&[1])
+    /// }
+    /// ```
+    pub fn syndrome(&self, errors: &[usize]) -> Vec<bool> {
+        self.checks
+            .iter()
+            .map(|check| errors.iter().filter(|qubit| check.contains(qubit)).count() % 2 == 1)
+            .collect()
+    }
+
+    /// [`ParityCheckCode::checks_for`] will get the indices of every parity check that
+    /// measures the [`Qubit`](crate::quantum::types::qubit::Qubit) at index `qubit`.
+    ///
+    /// # Example
+    /// [`ParityCheckCode::checks_for`] can be used to see how many checks protect a qubit:
+    /// ```rust
+    /// use rquant::error_correction::types::parity_check_code::ParityCheckCode;
+    ///
+    /// fn count_checks_for_first_qubit(code: &ParityCheckCode) -> usize {
+    ///     code.checks_for(0).len()
+    /// }
+    /// ```
+    pub fn checks_for(&self, qubit: usize) -> Vec<usize> {
+        self.checks
+            .iter()
+            .enumerate()
+            .filter(|(_, check)| check.contains(&qubit))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// [`ParityCheckCode::logical_error_rate`] will estimate how often the encoded $|0\rangle$ state of a
+    /// [`ParityCheckCode`] fails, by running `shots` of `rounds` stabilizer measurement cycles.
+    ///
+    /// Each round, every [`Qubit`](crate::quantum::types::qubit::Qubit) is flipped with a probability of
+    /// `physical_error_rate`, then the syndrome is measured and the `decoder` correction is applied.
+    /// A shot fails if any [`Qubit`](crate::quantum::types::qubit::Qubit) is flipped after the last round.
+    ///
+    /// # Example
+    /// [`ParityCheckCode::logical_error_rate`] can be used to produce threshold-style data:
+    /// ```rust
+    /// use rquant::error_correction::types::{
+    ///     logical_error_rate::LogicalErrorRate,
+    ///     lookup_table_decoder::LookupTableDecoder,
+    ///     parity_check_code::ParityCheckCode,
+    /// };
+    ///
+    /// fn bit_flip_code_threshold() -> Vec<LogicalErrorRate> {
+    ///     let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    ///     let decoder = LookupTableDecoder::new(&code, 1);
+    ///     [0.01, 0.05, 0.1]
+    ///         .iter()
+    ///         .map(|&rate| code.logical_error_rate(&decoder, rate, 3, 100))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn logical_error_rate(
+        &self,
+        decoder: &impl Decoder,
+        physical_error_rate: f64,
+        rounds: usize,
+        shots: usize,
+    ) -> LogicalErrorRate {
+        self.logical_error_rate_with(
+            decoder,
+            physical_error_rate,
+            rounds,
+            shots,
+            &mut rand::rng(),
+        )
+    }
+
+    /// [`ParityCheckCode::logical_error_rate_with`] will estimate the logical error rate of a
+    /// [`ParityCheckCode`] in the same way as [`ParityCheckCode::logical_error_rate`], using a
+    /// random number generator `rng` for the errors and measurements instead of the thread's
+    /// generator, so a seeded `rng` always has the same failures.
+    ///
+    /// # Example
+    /// [`ParityCheckCode::logical_error_rate_with`] can be used to reproduce a threshold point:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::error_correction::types::{
+    ///     logical_error_rate::LogicalErrorRate,
+    ///     lookup_table_decoder::LookupTableDecoder,
+    ///     parity_check_code::ParityCheckCode,
+    /// };
+    ///
+    /// fn seeded_bit_flip_code_rate(seed: u64) -> LogicalErrorRate {
+    ///     let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    ///     let decoder = LookupTableDecoder::new(&code, 1);
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     code.logical_error_rate_with(&decoder, 0.05, 3, 100, &mut rng)
+    /// }
+    /// ```
+    pub fn logical_error_rate_with(
+        &self,
+        decoder: &impl Decoder,
+        physical_error_rate: f64,
+        rounds: usize,
+        shots: usize,
+        rng: &mut impl Rng,
+    ) -> LogicalErrorRate {
+        let failures = (0..shots)
+            .filter(|_| {
+                let mut qubit_register = QubitRegister::new(self.num_qubits);
+                for _ in 0..rounds {
+                    for qubit in 0..self.num_qubits {
+                        if rng.random_bool(physical_error_rate) {
+                            qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, qubit);
+                        }
+                    }
+                    let syndrome = self.syndrome(&flipped_qubits(&qubit_register, rng));
+                    for qubit in decoder.decode(&syndrome) {
+                        qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, qubit);
+                    }
+                }
+                !flipped_qubits(&qubit_register, rng).is_empty()
+            })
+            .count();
+
+        LogicalErrorRate {
+            physical_error_rate,
+            logical_error_rate: failures as f64 / shots as f64,
+            rounds,
+            shots,
+        }
+    }
+}
+
+/// [`flipped_qubits`] will measure every [`Qubit`](crate::quantum::types::qubit::Qubit) in a
+/// [`QubitRegister`] with a random number generator `rng`, and get the indices of the qubits that were observed as $|1\rangle$.
+fn flipped_qubits(qubit_register: &QubitRegister, rng: &mut impl Rng) -> Vec<usize> {
+    qubit_register
+        .qubits
+        .iter()
+        .enumerate()
+        .filter(|(_, qubit)| !qubit.measure_with(rng))
+        .map(|(index, _)| index)
+        .collect()
+}
@@ -0,0 +1,218 @@
+use num_complex::Complex;
+
+use crate::{
+    error_correction::types::{
+        decoder::Decoder, lookup_table_decoder::LookupTableDecoder,
+        parity_check_code::ParityCheckCode, repetition_code::RepetitionCode,
+    },
+    noise::types::noise_channel::NoiseChannel,
+    quantum::types::{
+        quantum_gate::QuantumGate, quantum_position::QuantumPosition, qubit::Qubit,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+};
+
+/// The indices of the data [`Qubits`](crate::quantum::types::qubit::Qubit) of a [`RepetitionCode`].
+const DATA_QUBITS: [usize; 3] = [0, 1, 2];
+
+/// The indices of the ancilla [`Qubits`](crate::quantum::types::qubit::Qubit) that measure each
+/// parity check of a [`RepetitionCode`].
+const ANCILLA_QUBITS: [usize; 2] = [3, 4];
+
+impl RepetitionCode {
+    /// [`RepetitionCode::parity_check_code`] will get the [`ParityCheckCode`] of a [`RepetitionCode`],
+    /// which compares the first and second, then the second and third data
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`RepetitionCode::parity_check_code`] can be used to build a decoder for a [`RepetitionCode`]:
+    /// ```rust
+    /// use rquant::error_correction::types::{
+    ///     lookup_table_decoder::LookupTableDecoder,
+    ///     repetition_code::RepetitionCode,
+    /// };
+    ///
+    /// fn create_bit_flip_decoder() -> LookupTableDecoder {
+    ///     LookupTableDecoder::new(&RepetitionCode::BitFlip.parity_check_code(), 1)
+    /// }
+    /// ```
+    pub fn parity_check_code(&self) -> ParityCheckCode {
+        ParityCheckCode::new(DATA_QUBITS.len(), vec![vec![0, 1], vec![1, 2]])
+    }
+
+    /// [`RepetitionCode::encode`] will encode a [`Qubit`] into a [`StateVector`] of three data
+    /// [`Qubits`](Qubit) followed by two ancilla [`Qubits`](Qubit) initialized as $|0\rangle$.
+    ///
+    /// # Example
+    /// [`RepetitionCode::encode`] can be used to encode a superpositioned [`Qubit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::repetition_code::RepetitionCode,
+    ///     quantum::types::{qubit::Qubit, state_vector::StateVector},
+    /// };
+    ///
+    /// fn encode_superposition() -> StateVector {
+    ///     RepetitionCode::BitFlip.encode(&Qubit::flip())
+    /// }
+    /// ```
+    pub fn encode(&self, qubit: &Qubit) -> StateVector {
+        let mut qubits = vec![qubit.clone()];
+        qubits.extend((1..DATA_QUBITS.len() + ANCILLA_QUBITS.len()).map(|_| Qubit::zero()));
+        let mut state_vector = StateVector::from_register(&QubitRegister { qubits });
+
+        state_vector.apply_cnot(0, 1);
+        state_vector.apply_cnot(0, 2);
+        self.change_basis(&mut state_vector);
+        state_vector
+    }
+
+    /// [`RepetitionCode::syndrome`] will measure both parity checks of an encoded [`StateVector`] with
+    /// it's ancilla [`Qubits`](Qubit), reset the ancillas to $|0\rangle$, and return `true` for each
+    /// check that found an error.
+    ///
+    /// # Example
+    /// [`RepetitionCode::syndrome`] can be used to locate a bit flip on the first data qubit:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::repetition_code::RepetitionCode,
+    ///     quantum::types::{quantum_gate::QuantumGate, qubit::Qubit},
+    /// };
+    ///
+    /// fn first_qubit_syndrome() -> Vec<bool> {
+    ///     let code = RepetitionCode::BitFlip;
+    ///     let mut state_vector = code.encode(&Qubit::zero());
+    ///     state_vector.apply_gate(&QuantumGate::NOT, 0);
+    ///     code.syndrome(&mut state_vector)
+    /// }
+    /// ```
+    pub fn syndrome(&self, state_vector: &mut StateVector) -> Vec<bool> {
+        assert!(
+            state_vector.num_qubits == DATA_QUBITS.len() + ANCILLA_QUBITS.len(),
+            "Invalid repetition code state vector"
+        );
+
+        self.change_basis(state_vector);
+        for (check, &ancilla) in self.parity_check_code().checks.iter().zip(&ANCILLA_QUBITS) {
+            for &data in check {
+                state_vector.apply_cnot(data, ancilla);
+            }
+        }
+        self.change_basis(state_vector);
+
+        ANCILLA_QUBITS
+            .iter()
+            .map(|&ancilla| {
+                // Measuring false means the ancilla is |1>, so the parity check found an error.
+                let flagged = !state_vector.measure(ancilla);
+                if flagged {
+                    state_vector.apply_gate(&QuantumGate::NOT, ancilla);
+                }
+                flagged
+            })
+            .collect()
+    }
+
+    /// [`RepetitionCode::correct`] will undo the lowest weight error that produces a `syndrome` on an
+    /// encoded [`StateVector`].
+    ///
+    /// # Example
+    /// [`RepetitionCode::correct`] can be used to run a full error correction cycle:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::repetition_code::RepetitionCode,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn error_correction_cycle(code: &RepetitionCode, state_vector: &mut StateVector) {
+    ///     let syndrome = code.syndrome(state_vector);
+    ///     code.correct(state_vector, &syndrome);
+    /// }
+    /// ```
+    pub fn correct(&self, state_vector: &mut StateVector, syndrome: &[bool]) {
+        let decoder = LookupTableDecoder::new(&self.parity_check_code(), 1);
+        let correction = match self {
+            RepetitionCode::BitFlip => QuantumGate::NOT,
+            RepetitionCode::PhaseFlip => QuantumGate::PHASE,
+        };
+        for qubit in decoder.decode(syndrome) {
+            state_vector.apply_gate(&correction, qubit);
+        }
+    }
+
+    /// [`RepetitionCode::decode`] will undo the encoding of a [`StateVector`] and return the logical
+    /// [`Qubit`].
+    ///
+    /// # Example
+    /// [`RepetitionCode::decode`] can be used to get back an encoded [`Qubit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::repetition_code::RepetitionCode,
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn encode_and_decode(qubit: &Qubit) -> Qubit {
+    ///     let code = RepetitionCode::PhaseFlip;
+    ///     code.decode(code.encode(qubit))
+    /// }
+    /// ```
+    pub fn decode(&self, mut state_vector: StateVector) -> Qubit {
+        self.change_basis(&mut state_vector);
+        state_vector.apply_cnot(0, 2);
+        state_vector.apply_cnot(0, 1);
+
+        // An uncorrectable error leaves the other qubits flipped, so collapse them before reading
+        // the logical qubit out of the remaining amplitudes.
+        for qubit in 1..state_vector.num_qubits {
+            state_vector.measure(qubit);
+        }
+        let mask = state_vector.mask(0);
+        let (zero, one): (Vec<_>, Vec<_>) = state_vector
+            .amplitudes
+            .iter()
+            .enumerate()
+            .partition(|(index, _)| index & mask == 0);
+        let sum = |amplitudes: Vec<(usize, &Complex<f64>)>| -> Complex<f64> {
+            amplitudes.into_iter().map(|(_, amplitude)| amplitude).sum()
+        };
+        let (initial_position, possible_position) = (sum(zero), sum(one));
+
+        Qubit::new(QuantumPosition::new(initial_position, possible_position).normalized())
+    }
+
+    /// [`RepetitionCode::transmit`] will encode a [`Qubit`], apply a [`NoiseChannel`] to every data
+    /// [`Qubit`], run one error correction cycle, and decode the result.
+    ///
+    /// # Example
+    /// [`RepetitionCode::transmit`] can be used to protect a [`Qubit`] from bit flips:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::repetition_code::RepetitionCode,
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn transmit_one(qubit: &Qubit) -> Qubit {
+    ///     RepetitionCode::BitFlip.transmit(qubit, &NoiseChannel::BitFlip(0.1))
+    /// }
+    /// ```
+    pub fn transmit(&self, qubit: &Qubit, channel: &NoiseChannel) -> Qubit {
+        let mut state_vector = self.encode(qubit);
+        for qubit in DATA_QUBITS {
+            channel.apply_to_state_vector(&mut state_vector, qubit);
+        }
+        let syndrome = self.syndrome(&mut state_vector);
+        self.correct(&mut state_vector, &syndrome);
+        self.decode(state_vector)
+    }
+
+    /// [`RepetitionCode::change_basis`] will apply a
+    /// [`SUPERPOSITION`](QuantumGate::SUPERPOSITION) gate to every data [`Qubit`] of a
+    /// [`RepetitionCode::PhaseFlip`] code, which swaps it between the bit flip and phase flip bases.
+    fn change_basis(&self, state_vector: &mut StateVector) {
+        if *self == RepetitionCode::PhaseFlip {
+            for qubit in DATA_QUBITS {
+                state_vector.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+            }
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use std::iter;
+
+use num_complex::Complex;
+
+use crate::{
+    error_correction::types::{
+        decoder::Decoder, logical_error_rate::LogicalErrorRate,
+        lookup_table_decoder::LookupTableDecoder, parity_check_code::ParityCheckCode,
+        steane_code::SteaneCode,
+    },
+    noise::types::noise_channel::NoiseChannel,
+    quantum::types::{
+        quantum_gate::QuantumGate, quantum_position::QuantumPosition, qubit::Qubit,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+};
+
+/// The amount of data [`Qubits`](crate::quantum::types::qubit::Qubit) in a [`SteaneCode`].
+const DATA_QUBITS: usize = 7;
+
+/// The index of the data [`Qubit`](crate::quantum::types::qubit::Qubit) that holds the logical
+/// qubit before encoding and after decoding.
+const INPUT_QUBIT: usize = 2;
+
+/// The data [`Qubits`](crate::quantum::types::qubit::Qubit) that the logical $X$ operator flips,
+/// which only overlap each parity check on an even amount of qubits.
+const LOGICAL_X: [usize; 3] = [2, 4, 5];
+
+/// The data [`Qubit`](crate::quantum::types::qubit::Qubit) of each parity check that is not part of
+/// any other check.
+const PIVOTS: [usize; 3] = [0, 1, 3];
+
+impl SteaneCode {
+    /// [`SteaneCode::parity_check_code`] will get the [`ParityCheckCode`] of the Hamming code, which
+    /// a [`SteaneCode`] measures once to find bit flips and once to find phase flips.
+    ///
+    /// # Example
+    /// [`SteaneCode::parity_check_code`] can be used to build a decoder for a [`SteaneCode`]:
+    /// ```rust
+    /// use rquant::error_correction::types::{
+    ///     lookup_table_decoder::LookupTableDecoder,
+    ///     steane_code::SteaneCode,
+    /// };
+    ///
+    /// fn create_steane_decoder() -> LookupTableDecoder {
+    ///     LookupTableDecoder::new(&SteaneCode.parity_check_code(), 1)
+    /// }
+    /// ```
+    pub fn parity_check_code(&self) -> ParityCheckCode {
+        ParityCheckCode::new(
+            DATA_QUBITS,
+            vec![vec![0, 2, 4, 6], vec![1, 2, 5, 6], vec![3, 4, 5, 6]],
+        )
+    }
+
+    /// [`SteaneCode::encode`] will encode a [`Qubit`] into a [`StateVector`] of seven data
+    /// [`Qubits`](Qubit).
+    ///
+    /// # Example
+    /// [`SteaneCode::encode`] can be used to encode a superpositioned [`Qubit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::steane_code::SteaneCode,
+    ///     quantum::types::{qubit::Qubit, state_vector::StateVector},
+    /// };
+    ///
+    /// fn encode_superposition() -> StateVector {
+    ///     SteaneCode.encode(&Qubit::flip())
+    /// }
+    /// ```
+    pub fn encode(&self, qubit: &Qubit) -> StateVector {
+        let qubits = (0..DATA_QUBITS)
+            .map(|index| {
+                if index == INPUT_QUBIT {
+                    qubit.clone()
+                } else {
+                    Qubit::zero()
+                }
+            })
+            .collect();
+        let mut state_vector = StateVector::from_register(&QubitRegister { qubits });
+
+        // Copy the input onto the logical X qubits, then project onto the code space by spreading
+        // each pivot's superposition over it's parity check.
+        for &target in LOGICAL_X.iter().skip(1) {
+            state_vector.apply_cnot(INPUT_QUBIT, target);
+        }
+        for (check, &pivot) in self.parity_check_code().checks.iter().zip(&PIVOTS) {
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, pivot);
+            for &target in check.iter().filter(|&&target| target != pivot) {
+                state_vector.apply_cnot(pivot, target);
+            }
+        }
+        state_vector
+    }
+
+    /// [`SteaneCode::syndrome`] will measure every stabilizer of an encoded [`StateVector`] with six
+    /// ancilla [`Qubits`](Qubit) that are only added for the measurement, and return `true` for each
+    /// check that found an error, with the three bit flip checks followed by the three phase flip
+    /// checks.
+    ///
+    /// # Example
+    /// [`SteaneCode::syndrome`] can be used to locate a phase flip on the last data qubit:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::steane_code::SteaneCode,
+    ///     quantum::types::{quantum_gate::QuantumGate, qubit::Qubit},
+    /// };
+    ///
+    /// fn last_qubit_syndrome() -> Vec<bool> {
+    ///     let mut state_vector = SteaneCode.encode(&Qubit::zero());
+    ///     state_vector.apply_gate(&QuantumGate::PHASE, 6);
+    ///     SteaneCode.syndrome(&mut state_vector)
+    /// }
+    /// ```
+    pub fn syndrome(&self, state_vector: &mut StateVector) -> Vec<bool> {
+        assert!(
+            state_vector.num_qubits == DATA_QUBITS,
+            "Invalid steane code state vector"
+        );
+
+        // Append the ancillas as the least significant qubits, all initialized as |0>.
+        let checks = self.parity_check_code().checks;
+        let ancilla_states = 1 << (2 * checks.len());
+        let mut extended = StateVector {
+            num_qubits: DATA_QUBITS + 2 * checks.len(),
+            amplitudes: state_vector
+                .amplitudes
+                .iter()
+                .flat_map(|&amplitude| {
+                    iter::once(amplitude)
+                        .chain(iter::repeat_n(Complex::new(0.0, 0.0), ancilla_states - 1))
+                })
+                .collect(),
+        };
+
+        // The Z stabilizers copy the parity of their data qubits onto an ancilla.
+        for (index, check) in checks.iter().enumerate() {
+            for &data in check {
+                extended.apply_cnot(data, DATA_QUBITS + index);
+            }
+        }
+        // The X stabilizers kick the parity back onto an ancilla in the superposition basis.
+        for (index, check) in checks.iter().enumerate() {
+            let ancilla = DATA_QUBITS + checks.len() + index;
+            extended.apply_gate(&QuantumGate::SUPERPOSITION, ancilla);
+            for &data in check {
+                extended.apply_cnot(ancilla, data);
+            }
+            extended.apply_gate(&QuantumGate::SUPERPOSITION, ancilla);
+        }
+
+        let syndrome = (DATA_QUBITS..extended.num_qubits)
+            .map(|ancilla| {
+                // Measuring false means the ancilla is |1>, so the stabilizer found an error.
+                let flagged = !extended.measure(ancilla);
+                if flagged {
+                    extended.apply_gate(&QuantumGate::NOT, ancilla);
+                }
+                flagged
+            })
+            .collect();
+
+        // Every ancilla was reset to |0>, so the data amplitudes can be read back out.
+        state_vector.amplitudes = extended
+            .amplitudes
+            .into_iter()
+            .step_by(ancilla_states)
+            .collect();
+        syndrome
+    }
+
+    /// [`SteaneCode::correct`] will undo the lowest weight bit flip and phase flip that produce a
+    /// `syndrome` on an encoded [`StateVector`].
+    ///
+    /// # Example
+    /// [`SteaneCode::correct`] can be used to run a full error correction cycle:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::steane_code::SteaneCode,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn error_correction_cycle(state_vector: &mut StateVector) {
+    ///     let syndrome = SteaneCode.syndrome(state_vector);
+    ///     SteaneCode.correct(state_vector, &syndrome);
+    /// }
+    /// ```
+    pub fn correct(&self, state_vector: &mut StateVector, syndrome: &[bool]) {
+        let code = self.parity_check_code();
+        assert!(
+            syndrome.len() == 2 * code.checks.len(),
+            "Invalid steane code syndrome"
+        );
+
+        let decoder = LookupTableDecoder::new(&code, 1);
+        let (bit_flips, phase_flips) = syndrome.split_at(code.checks.len());
+        for qubit in decoder.decode(bit_flips) {
+            state_vector.apply_gate(&QuantumGate::NOT, qubit);
+        }
+        for qubit in decoder.decode(phase_flips) {
+            state_vector.apply_gate(&QuantumGate::PHASE, qubit);
+        }
+    }
+
+    /// [`SteaneCode::decode`] will undo the encoding of a [`StateVector`] and return the logical
+    /// [`Qubit`].
+    ///
+    /// # Example
+    /// [`SteaneCode::decode`] can be used to get back an encoded [`Qubit`]:
+    /// ```rust
+    /// use rquant::{error_correction::types::steane_code::SteaneCode, quantum::types::qubit::Qubit};
+    ///
+    /// fn encode_and_decode(qubit: &Qubit) -> Qubit {
+    ///     SteaneCode.decode(SteaneCode.encode(qubit))
+    /// }
+    /// ```
+    pub fn decode(&self, mut state_vector: StateVector) -> Qubit {
+        for (check, &pivot) in self.parity_check_code().checks.iter().zip(&PIVOTS).rev() {
+            for &target in check.iter().filter(|&&target| target != pivot) {
+                state_vector.apply_cnot(pivot, target);
+            }
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, pivot);
+        }
+        for &target in LOGICAL_X.iter().skip(1) {
+            state_vector.apply_cnot(INPUT_QUBIT, target);
+        }
+
+        // An uncorrectable error leaves the other qubits flipped, so collapse them before reading
+        // the logical qubit out of the remaining amplitudes.
+        for qubit in (0..state_vector.num_qubits).filter(|&qubit| qubit != INPUT_QUBIT) {
+            state_vector.measure(qubit);
+        }
+        let mask = state_vector.mask(INPUT_QUBIT);
+        let sum = |is_one: bool| -> Complex<f64> {
+            state_vector
+                .amplitudes
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| (index & mask != 0) == is_one)
+                .map(|(_, amplitude)| amplitude)
+                .sum()
+        };
+        let (initial_position, possible_position) = (sum(false), sum(true));
+
+        Qubit::new(QuantumPosition::new(initial_position, possible_position).normalized())
+    }
+
+    /// [`SteaneCode::transmit`] will encode a [`Qubit`], apply a [`NoiseChannel`] to every data
+    /// [`Qubit`], run one error correction cycle, and decode the result.
+    ///
+    /// # Example
+    /// [`SteaneCode::transmit`] can be used to protect a [`Qubit`] from depolarizing noise:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::steane_code::SteaneCode,
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn transmit_one(qubit: &Qubit) -> Qubit {
+    ///     SteaneCode.transmit(qubit, &NoiseChannel::Depolarizing(0.05))
+    /// }
+    /// ```
+    pub fn transmit(&self, qubit: &Qubit, channel: &NoiseChannel) -> Qubit {
+        let mut state_vector = self.encode(qubit);
+        for qubit in 0..DATA_QUBITS {
+            channel.apply_to_state_vector(&mut state_vector, qubit);
+        }
+        let syndrome = self.syndrome(&mut state_vector);
+        self.correct(&mut state_vector, &syndrome);
+        self.decode(state_vector)
+    }
+
+    /// [`SteaneCode::logical_error_rate`] will estimate how often a [`Qubit`] sent through
+    /// [`SteaneCode::transmit`] comes back changed, where the physical error rate is the
+    /// probability of the [`NoiseChannel`].
+    ///
+    /// # Example
+    /// [`SteaneCode::logical_error_rate`] can be used to compare logical and physical error rates:
+    /// ```rust
+    /// use rquant::{
+    ///     error_correction::types::{
+    ///         logical_error_rate::LogicalErrorRate,
+    ///         steane_code::SteaneCode,
+    ///     },
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn steane_code_threshold() -> Vec<LogicalErrorRate> {
+    ///     [0.01, 0.05, 0.1]
+    ///         .iter()
+    ///         .map(|&rate| {
+    ///             SteaneCode.logical_error_rate(&Qubit::flip(), &NoiseChannel::Depolarizing(rate), 10)
+    ///         })
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn logical_error_rate(
+        &self,
+        qubit: &Qubit,
+        channel: &NoiseChannel,
+        shots: usize,
+    ) -> LogicalErrorRate {
+        let failures = (0..shots)
+            .filter(|_| {
+                let received = self.transmit(qubit, channel);
+                let overlap = qubit.initial_position().conj() * received.initial_position()
+                    + qubit.possible_position().conj() * received.possible_position();
+                overlap.norm_sqr() < 1.0 - 1e-6
+            })
+            .count();
+
+        LogicalErrorRate {
+            physical_error_rate: channel.probability(),
+            logical_error_rate: failures as f64 / shots as f64,
+            rounds: 1,
+            shots,
+        }
+    }
+}
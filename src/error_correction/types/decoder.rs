@@ -0,0 +1,7 @@
+/// [`Decoder`] is a [`trait`] that will allow any type to turn a syndrome into a correction.
+pub trait Decoder {
+    /// [`Decoder::decode`] will get the indices of the [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// to correct for a `syndrome`, which holds the outcome of each parity check of a
+    /// [`ParityCheckCode`](crate::error_correction::types::parity_check_code::ParityCheckCode).
+    fn decode(&self, syndrome: &[bool]) -> Vec<usize>;
+}
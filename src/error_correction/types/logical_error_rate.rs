@@ -0,0 +1,17 @@
+/// [`LogicalErrorRate`] is the outcome of repeatedly running a
+/// [`ParityCheckCode`](crate::error_correction::types::parity_check_code::ParityCheckCode) under noise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogicalErrorRate {
+    /// The probability of each [`Qubit`](crate::quantum::types::qubit::Qubit) being flipped
+    /// during a round.
+    pub physical_error_rate: f64,
+
+    /// The observed probability of the encoded state being wrong after every round.
+    pub logical_error_rate: f64,
+
+    /// The amount of error correction rounds in each shot.
+    pub rounds: usize,
+
+    /// The amount of shots that were run.
+    pub shots: usize,
+}
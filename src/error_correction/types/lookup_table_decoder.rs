@@ -0,0 +1,10 @@
+use std::collections::HashMap;
+
+/// [`LookupTableDecoder`] is a [`Decoder`](crate::error_correction::types::decoder::Decoder) that
+/// corrects each known syndrome with the lowest weight error that produces it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupTableDecoder {
+    /// A map of syndromes to the indices of the [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// that correct them.
+    pub table: HashMap<Vec<bool>, Vec<usize>>,
+}
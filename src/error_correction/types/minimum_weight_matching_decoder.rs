@@ -0,0 +1,13 @@
+use crate::error_correction::types::parity_check_code::ParityCheckCode;
+
+/// [`MinimumWeightMatchingDecoder`] is a [`Decoder`](crate::error_correction::types::decoder::Decoder)
+/// that pairs up flagged parity checks (or pairs them with the code boundary) using the fewest
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) corrections.
+///
+/// The matching is exhaustive, so it is intended for small codes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MinimumWeightMatchingDecoder {
+    /// The [`ParityCheckCode`] that is decoded, where every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is in at most two parity checks.
+    pub code: ParityCheckCode,
+}
@@ -0,0 +1,11 @@
+/// [`ParityCheckCode`] is an error correcting code described by parity checks over a
+/// collection of [`Qubits`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParityCheckCode {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) that make up the code.
+    pub num_qubits: usize,
+
+    /// A collection of parity checks, where each check is the indices of the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) it measures the parity of.
+    pub checks: Vec<Vec<usize>>,
+}
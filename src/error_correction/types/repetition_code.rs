@@ -0,0 +1,13 @@
+/// [`RepetitionCode`] is the three [`Qubit`](crate::quantum::types::qubit::Qubit) repetition code,
+/// which protects one logical qubit from a single bit flip or phase flip by measuring two parity
+/// checks with ancilla qubits.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RepetitionCode {
+    /// Protects against a single [`NOT`](crate::quantum::types::quantum_gate::QuantumGate::NOT)
+    /// error by encoding $\alpha|0\rangle + \beta|1\rangle$ as $\alpha|000\rangle + \beta|111\rangle$.
+    BitFlip,
+
+    /// Protects against a single [`PHASE`](crate::quantum::types::quantum_gate::QuantumGate::PHASE)
+    /// error by encoding $\alpha|0\rangle + \beta|1\rangle$ as $\alpha|{+}{+}{+}\rangle + \beta|{-}{-}{-}\rangle$.
+    PhaseFlip,
+}
@@ -0,0 +1,5 @@
+/// [`SteaneCode`] is the seven [`Qubit`](crate::quantum::types::qubit::Qubit) Steane code, which
+/// protects one logical qubit from any single qubit error by measuring the parity checks of the
+/// Hamming code in both the bit flip and phase flip bases.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SteaneCode;
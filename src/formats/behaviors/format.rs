@@ -0,0 +1,49 @@
+use std::fmt;
+
+use crate::{
+    formats::types::{format::Format, format_error::FormatError},
+    quantum::types::quantum_circuit::QuantumCircuit,
+};
+
+/// [`validate`] will check that `input` can be read as a [`QuantumCircuit`] of a [`Format`],
+/// without running it, and return the [`FormatError`] that describes the first problem otherwise.
+///
+/// # Example
+/// [`validate`] can be used to check a file before attempting to run it:
+/// ```rust
+/// use rquant::formats::{self, types::format::Format};
+///
+/// fn is_runnable(program: &str) -> bool {
+///     formats::validate(program, Format::Qasm).is_ok()
+/// }
+/// ```
+pub fn validate(input: &str, format: Format) -> Result<(), FormatError> {
+    match format {
+        Format::Qasm => QuantumCircuit::from_qasm(input),
+        Format::Json => QuantumCircuit::from_json(input),
+        Format::Quirk => QuantumCircuit::from_quirk(input),
+    }
+    .map(drop)
+}
+
+/// Implement the [`fmt::Display`] trait for [`Format`].
+impl fmt::Display for Format {
+    /// Will return the name of a [`Format`], like `QASM`.
+    ///
+    /// # Example
+    /// Can be used to print a [`Format`] to the console:
+    /// ```rust
+    /// use rquant::formats::types::format::Format;
+    ///
+    /// fn print_format(format: Format) {
+    ///     println!("{format}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Qasm => write!(f, "QASM"),
+            Format::Json => write!(f, "JSON"),
+            Format::Quirk => write!(f, "Quirk"),
+        }
+    }
+}
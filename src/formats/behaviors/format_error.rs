@@ -0,0 +1,31 @@
+use std::{error, fmt};
+
+use crate::formats::types::format_error::FormatError;
+
+/// Implement the [`fmt::Display`] trait for [`FormatError`].
+impl fmt::Display for FormatError {
+    /// Will return the [`Format`](crate::formats::types::format::Format) and a short description
+    /// of why a file could not be read:
+    /// ```text
+    /// Invalid QASM: line 2: unsupported gate "u3"
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`FormatError`] to the console:
+    /// ```rust
+    /// use rquant::formats::{self, types::format::Format};
+    ///
+    /// fn print_format_error(program: &str) {
+    ///     if let Err(error) = formats::validate(program, Format::Qasm) {
+    ///         println!("{error}");
+    ///     }
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid {}: {}", self.format, self.message)
+    }
+}
+
+/// Implement the [`error::Error`] trait for [`FormatError`], so it can be returned with `?`
+/// alongside other errors.
+impl error::Error for FormatError {}
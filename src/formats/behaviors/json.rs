@@ -0,0 +1,167 @@
+use crate::{
+    formats::types::{format::Format, format_error::FormatError, json_value::JsonValue},
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operation::QuantumOperation,
+    },
+};
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::to_json`] will export a [`QuantumCircuit`] as a JSON object with a
+    /// `num_qubits` count, an `operations` array of objects with `gate` and `target` keys, and a
+    /// `post_selections` array of objects with `target` and `value` keys. Every `gate` is written
+    /// by [`QuantumGate::name`], and a controlled gate has a `controls` array of qubit indices.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::to_json`] can be used to save a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn save_superposition_circuit() -> String {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .to_json()
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let operations: Vec<String> = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let controls = if operation.controls.is_empty() {
+                    String::new()
+                } else {
+                    let controls: Vec<String> = operation
+                        .controls
+                        .iter()
+                        .map(|control| control.to_string())
+                        .collect();
+                    format!(",\"controls\":[{}]", controls.join(","))
+                };
+                format!(
+                    "{{\"gate\":\"{}\",\"target\":{}{controls}}}",
+                    QuantumGate::new(operation.operator.clone()).name(),
+                    operation.target
+                )
+            })
+            .collect();
+        let post_selections: Vec<String> = self
+            .post_selections
+            .iter()
+            .map(|(target, value)| format!("{{\"target\":{target},\"value\":{value}}}"))
+            .collect();
+        format!(
+            "{{\"num_qubits\":{},\"operations\":[{}],\"post_selections\":[{}]}}",
+            self.num_qubits,
+            operations.join(","),
+            post_selections.join(",")
+        )
+    }
+
+    /// [`QuantumCircuit::from_json`] will import a [`QuantumCircuit`] from a JSON `document`
+    /// written by [`QuantumCircuit::to_json`], or return a [`FormatError`] that describes the first
+    /// problem with it. The `post_selections` and `controls` keys can be left out.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::from_json`] can be used to load a saved [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     formats::types::format_error::FormatError,
+    ///     quantum::types::quantum_circuit::QuantumCircuit,
+    /// };
+    ///
+    /// fn load_superposition_circuit() -> Result<QuantumCircuit, FormatError> {
+    ///     QuantumCircuit::from_json(r#"{"num_qubits":1,"operations":[{"gate":"h","target":0}]}"#)
+    /// }
+    /// ```
+    pub fn from_json(document: &str) -> Result<Self, FormatError> {
+        let error = |message| FormatError {
+            format: Format::Json,
+            message,
+        };
+        let document = JsonValue::parse(document).map_err(error)?;
+        let num_qubits = index(document.get("num_qubits"))
+            .ok_or_else(|| error("\"num_qubits\" must be a qubit count".to_string()))?;
+        let target = |entry: &JsonValue, location: &str| {
+            index(entry.get("target"))
+                .filter(|&target| target < num_qubits)
+                .ok_or_else(|| error(format!("{location}: \"target\" is not a qubit index")))
+        };
+
+        let operations = match document.get("operations") {
+            Some(JsonValue::Array(operations)) => operations
+                .iter()
+                .enumerate()
+                .map(|(position, operation)| {
+                    let location = format!("operation {position}");
+                    let gate = match operation.get("gate") {
+                        Some(JsonValue::String(name)) => QuantumGate::from_name(name)
+                            .map_err(|gate_error| error(format!("{location}: {gate_error}")))?,
+                        _ => return Err(error(format!("{location}: \"gate\" must be a string"))),
+                    };
+                    let target = target(operation, &location)?;
+                    let controls: Vec<usize> = match operation.get("controls") {
+                        Some(JsonValue::Array(controls)) => controls
+                            .iter()
+                            .map(|control| index(Some(control)).filter(|&index| index < num_qubits))
+                            .collect::<Option<Vec<_>>>()
+                            .filter(|controls| {
+                                controls.iter().enumerate().all(|(position, control)| {
+                                    *control != target && !controls[..position].contains(control)
+                                })
+                            })
+                            .ok_or_else(|| {
+                                error(format!(
+                                    "{location}: \"controls\" must be other qubit indices"
+                                ))
+                            })?,
+                        None => vec![],
+                        _ => {
+                            return Err(error(format!("{location}: \"controls\" must be an array")))
+                        }
+                    };
+                    Ok(QuantumOperation {
+                        operator: gate.operator,
+                        target,
+                        controls,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            _ => return Err(error("\"operations\" must be an array".to_string())),
+        };
+
+        let post_selections = match document.get("post_selections") {
+            Some(JsonValue::Array(post_selections)) => post_selections
+                .iter()
+                .enumerate()
+                .map(|(position, post_selection)| {
+                    let location = format!("post-selection {position}");
+                    match post_selection.get("value") {
+                        Some(&JsonValue::Bool(value)) => {
+                            Ok((target(post_selection, &location)?, value))
+                        }
+                        _ => Err(error(format!("{location}: \"value\" must be a boolean"))),
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => vec![],
+            _ => return Err(error("\"post_selections\" must be an array".to_string())),
+        };
+
+        Ok(QuantumCircuit {
+            num_qubits,
+            operations,
+            post_selections,
+        })
+    }
+}
+
+/// [`index`] will get a `value` as a qubit count or index, if it is a whole, non-negative number.
+fn index(value: Option<&JsonValue>) -> Option<usize> {
+    match value {
+        Some(&JsonValue::Number(number)) if number >= 0.0 && number.fract() == 0.0 => {
+            Some(number as usize)
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,188 @@
+use crate::formats::types::json_value::JsonValue;
+
+impl JsonValue {
+    /// [`JsonValue::parse`] will read a whole JSON document from `input`, or return a message that
+    /// points at the character that could not be read.
+    pub(crate) fn parse(input: &str) -> Result<Self, String> {
+        let characters: Vec<char> = input.chars().collect();
+        let mut position = 0;
+        let value = value(&characters, &mut position)?;
+        skip_whitespace(&characters, &mut position);
+        if position != characters.len() {
+            return Err(error(position, "unexpected text after the document"));
+        }
+        Ok(value)
+    }
+
+    /// [`JsonValue::get`] will get the value of a `key` if the [`JsonValue`] is an object that has
+    /// it.
+    pub(crate) fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+/// [`value`] will read the JSON value that starts at `position`.
+fn value(characters: &[char], position: &mut usize) -> Result<JsonValue, String> {
+    skip_whitespace(characters, position);
+    match characters.get(*position) {
+        Some('{') => {
+            *position += 1;
+            let mut entries = vec![];
+            if next_is(characters, position, '}') {
+                return Ok(JsonValue::Object(entries));
+            }
+            loop {
+                skip_whitespace(characters, position);
+                if characters.get(*position) != Some(&'"') {
+                    return Err(error(*position, "expected an object key"));
+                }
+                let key = string(characters, position)?;
+                if !next_is(characters, position, ':') {
+                    return Err(error(*position, "expected ':'"));
+                }
+                entries.push((key, value(characters, position)?));
+                if next_is(characters, position, '}') {
+                    return Ok(JsonValue::Object(entries));
+                }
+                if !next_is(characters, position, ',') {
+                    return Err(error(*position, "expected ',' or '}'"));
+                }
+            }
+        }
+        Some('[') => {
+            *position += 1;
+            let mut items = vec![];
+            if next_is(characters, position, ']') {
+                return Ok(JsonValue::Array(items));
+            }
+            loop {
+                items.push(value(characters, position)?);
+                if next_is(characters, position, ']') {
+                    return Ok(JsonValue::Array(items));
+                }
+                if !next_is(characters, position, ',') {
+                    return Err(error(*position, "expected ',' or ']'"));
+                }
+            }
+        }
+        Some('"') => string(characters, position).map(JsonValue::String),
+        Some('t') => literal(characters, position, "true", JsonValue::Bool(true)),
+        Some('f') => literal(characters, position, "false", JsonValue::Bool(false)),
+        Some('n') => literal(characters, position, "null", JsonValue::Null),
+        Some(_) => {
+            let start = *position;
+            while characters
+                .get(*position)
+                .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+            {
+                *position += 1;
+            }
+            characters[start..*position]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .map(JsonValue::Number)
+                .map_err(|_| error(start, "invalid value"))
+        }
+        None => Err(error(*position, "unexpected end of the document")),
+    }
+}
+
+/// [`string`] will read the JSON string that starts at `position`, and resolve it's escapes.
+fn string(characters: &[char], position: &mut usize) -> Result<String, String> {
+    let start = *position;
+    *position += 1;
+    let mut text = String::new();
+    loop {
+        let character = *characters
+            .get(*position)
+            .ok_or_else(|| error(start, "unclosed string"))?;
+        *position += 1;
+        match character {
+            '"' => return Ok(text),
+            '\\' => {
+                let escape = characters
+                    .get(*position)
+                    .ok_or_else(|| error(start, "unclosed string"))?;
+                *position += 1;
+                text.push(match escape {
+                    '"' | '\\' | '/' => *escape,
+                    'b' => '\u{8}',
+                    'f' => '\u{c}',
+                    'n' => '\n',
+                    'r' => '\r',
+                    't' => '\t',
+                    'u' => {
+                        let mut code = hex(characters, position)?;
+                        // Characters outside the basic plane are escaped as a surrogate pair.
+                        if (0xD800..0xDC00).contains(&code)
+                            && characters.get(*position) == Some(&'\\')
+                            && characters.get(*position + 1) == Some(&'u')
+                        {
+                            *position += 2;
+                            let low = hex(characters, position)?;
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low.wrapping_sub(0xDC00));
+                        }
+                        char::from_u32(code).ok_or_else(|| error(*position, "invalid escape"))?
+                    }
+                    _ => return Err(error(*position - 1, "invalid escape")),
+                });
+            }
+            _ => text.push(character),
+        }
+    }
+}
+
+/// [`hex`] will read the four hexadecimal digits of a `\u` escape.
+fn hex(characters: &[char], position: &mut usize) -> Result<u32, String> {
+    let digits: String = characters.iter().skip(*position).take(4).collect();
+    let code = u32::from_str_radix(&digits, 16).map_err(|_| error(*position, "invalid escape"))?;
+    *position += 4;
+    Ok(code)
+}
+
+/// [`literal`] will read a `true`, `false` or `null` literal `word`.
+fn literal(
+    characters: &[char],
+    position: &mut usize,
+    word: &str,
+    value: JsonValue,
+) -> Result<JsonValue, String> {
+    let matches = word
+        .chars()
+        .enumerate()
+        .all(|(offset, c)| characters.get(*position + offset) == Some(&c));
+    if !matches {
+        return Err(error(*position, "invalid value"));
+    }
+    *position += word.len();
+    Ok(value)
+}
+
+/// [`next_is`] will skip whitespace, then step over the next character if it is `expected`.
+fn next_is(characters: &[char], position: &mut usize, expected: char) -> bool {
+    skip_whitespace(characters, position);
+    let found = characters.get(*position) == Some(&expected);
+    if found {
+        *position += 1;
+    }
+    found
+}
+
+/// [`skip_whitespace`] will step over any whitespace at `position`.
+fn skip_whitespace(characters: &[char], position: &mut usize) {
+    while characters.get(*position).is_some_and(|c| c.is_whitespace()) {
+        *position += 1;
+    }
+}
+
+/// [`error`] will get a `message` that points at the character at `position`.
+fn error(position: usize, message: &str) -> String {
+    format!("character {}: {message}", position + 1)
+}
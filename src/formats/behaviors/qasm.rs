@@ -0,0 +1,112 @@
+use crate::{
+    formats::types::{format::Format, format_error::FormatError},
+    quantum::types::{
+        qasm_statement::QasmStatement, qasm_stream::QasmStream, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate, quantum_operation::QuantumOperation,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::to_qasm`] will export a [`QuantumCircuit`] as an OpenQASM 2.0 program,
+    /// with one quantum register `q`, one classical register `c`, and every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) measured at the end of the program.
+    ///
+    /// A controlled gate is written with a `c` for each control in front of it's name, like `cx`
+    /// or `ccx`, with it's controls before it's target. OpenQASM 2.0 has no post-selection, so the
+    /// `post_selections` of the [`QuantumCircuit`] are not exported.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::to_qasm`] can be used to run a [`QuantumCircuit`] with another toolchain:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_program() -> String {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .to_qasm()
+    /// }
+    /// ```
+    pub fn to_qasm(&self) -> String {
+        let gates: String = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let qubits: Vec<String> = operation
+                    .qubits()
+                    .iter()
+                    .map(|index| format!("q[{index}]"))
+                    .collect();
+                format!(
+                    "{}{} {};\n",
+                    "c".repeat(operation.controls.len()),
+                    QuantumGate::new(operation.operator.clone()).name(),
+                    qubits.join(",")
+                )
+            })
+            .collect();
+        format!(
+            "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[{0}];\ncreg c[{0}];\n{gates}measure q -> c;\n",
+            self.num_qubits
+        )
+    }
+
+    /// [`QuantumCircuit::from_qasm`] will import a [`QuantumCircuit`] from an OpenQASM 2.0
+    /// `program`, which operates on every qubit of every quantum register, or return a
+    /// [`FormatError`] that points at the first line that could not be read.
+    ///
+    /// Measurements are skipped, because every [`Qubit`](crate::quantum::types::qubit::Qubit) of a
+    /// [`QuantumCircuit`] is measured after it's gates. The `cx` and `cz` gates are read as
+    /// controlled gates, and any other multi-qubit gate is rejected.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::from_qasm`] can be used to read a program from another toolchain:
+    /// ```rust
+    /// use rquant::{
+    ///     formats::types::format_error::FormatError,
+    ///     quantum::types::quantum_circuit::QuantumCircuit,
+    /// };
+    ///
+    /// fn read_superposition_program() -> Result<QuantumCircuit, FormatError> {
+    ///     QuantumCircuit::from_qasm("OPENQASM 2.0;\nqreg q[1];\nh q[0];\n")
+    /// }
+    /// ```
+    pub fn from_qasm(program: &str) -> Result<Self, FormatError> {
+        let error = |message| FormatError {
+            format: Format::Qasm,
+            message,
+        };
+        let mut stream = QasmStream::new(program.as_bytes());
+        let mut operations = vec![];
+        while let Some(statement) = stream.try_next().map_err(error)? {
+            match statement {
+                QasmStatement::Gate(operator, target) => operations.push(QuantumOperation {
+                    operator,
+                    target,
+                    controls: vec![],
+                }),
+                QasmStatement::ControlledNot(control, target) => {
+                    operations.push(QuantumOperation {
+                        operator: QuantumOperator::NOT,
+                        target,
+                        controls: vec![control],
+                    })
+                }
+                QasmStatement::ControlledPhase(control, target) => {
+                    operations.push(QuantumOperation {
+                        operator: QuantumOperator::PHASE,
+                        target,
+                        controls: vec![control],
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        Ok(QuantumCircuit {
+            num_qubits: stream.num_qubits(),
+            operations,
+            post_selections: vec![],
+        })
+    }
+}
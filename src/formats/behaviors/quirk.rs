@@ -0,0 +1,197 @@
+use std::f64::consts::PI;
+
+use crate::{
+    formats::types::{format::Format, format_error::FormatError, json_value::JsonValue},
+    quantum::{
+        behaviors::qasm_stream::evaluate,
+        types::{
+            quantum_circuit::QuantumCircuit, quantum_operation::QuantumOperation,
+            quantum_operators::QuantumOperator,
+        },
+    },
+};
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::to_quirk`] will export a [`QuantumCircuit`] as a
+    /// [Quirk](https://algassert.com/quirk) circuit, which can be opened by appending it to
+    /// `https://algassert.com/quirk#circuit=`.
+    ///
+    /// Every operation gets it's own column, padded to the width of the [`QuantumCircuit`] so
+    /// unused [`Qubits`](crate::quantum::types::qubit::Qubit) keep their wire, with a `•` control
+    /// on each of it's `controls`. Rotations are
+    /// written as Quirk's formula gates, and the `post_selections` are written as post-selection
+    /// gates in the last columns.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::to_quirk`] can be used to inspect a [`QuantumCircuit`] in Quirk:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_link() -> String {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     format!("https://algassert.com/quirk#circuit={}", circuit.to_quirk())
+    /// }
+    /// ```
+    pub fn to_quirk(&self) -> String {
+        let column = |target: usize, controls: &[usize], cell: String| {
+            let cells: Vec<String> = (0..self.num_qubits)
+                .map(|index| {
+                    if index == target {
+                        cell.clone()
+                    } else if controls.contains(&index) {
+                        "\"•\"".to_string()
+                    } else {
+                        "1".to_string()
+                    }
+                })
+                .collect();
+            format!("[{}]", cells.join(","))
+        };
+        let columns: Vec<String> = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let cell = match operation.operator {
+                    QuantumOperator::NOT => "\"X\"".to_string(),
+                    QuantumOperator::ROTATE => "\"Y\"".to_string(),
+                    QuantumOperator::PHASE => "\"Z\"".to_string(),
+                    QuantumOperator::SUPERPOSITION => "\"H\"".to_string(),
+                    QuantumOperator::RX(angle) => {
+                        format!("{{\"id\":\"Rxft\",\"arg\":\"{angle}\"}}")
+                    }
+                    QuantumOperator::RY(angle) => {
+                        format!("{{\"id\":\"Ryft\",\"arg\":\"{angle}\"}}")
+                    }
+                    QuantumOperator::RZ(angle) => {
+                        format!("{{\"id\":\"Rzft\",\"arg\":\"{angle}\"}}")
+                    }
+                };
+                column(operation.target, &operation.controls, cell)
+            })
+            .chain(self.post_selections.iter().map(|&(target, value)| {
+                column(target, &[], format!("\"|{0}⟩⟨{0}|\"", u8::from(value)))
+            }))
+            .collect();
+        format!("{{\"cols\":[{}]}}", columns.join(","))
+    }
+
+    /// [`QuantumCircuit::from_quirk`] will import a [`QuantumCircuit`] from a
+    /// [Quirk](https://algassert.com/quirk) circuit `document`, or return a [`FormatError`] that
+    /// points at the first column that could not be read.
+    ///
+    /// Quirk circuits have no qubit count, so the [`QuantumCircuit`] is as wide as the widest
+    /// column. The `H`, `X`, `Y` and `Z` gates, their square and fourth roots, the `Rxft`, `Ryft`
+    /// and `Rzft` formula gates with a constant argument, `•` controls on every gate of their
+    /// column, and post-selection gates after every other gate are supported. Roots of gates are run as rotations, which only differ from them
+    /// by a global phase.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::from_quirk`] can be used to run a circuit that was built in Quirk:
+    /// ```rust
+    /// use rquant::{
+    ///     formats::types::format_error::FormatError,
+    ///     quantum::types::quantum_circuit::QuantumCircuit,
+    /// };
+    ///
+    /// fn read_bell_columns() -> Result<QuantumCircuit, FormatError> {
+    ///     QuantumCircuit::from_quirk(r#"{"cols":[["H"],[1,"X"]]}"#)
+    /// }
+    /// ```
+    pub fn from_quirk(document: &str) -> Result<Self, FormatError> {
+        let error = |message| FormatError {
+            format: Format::Quirk,
+            message,
+        };
+        let document = JsonValue::parse(document).map_err(error)?;
+        let Some(JsonValue::Array(columns)) = document.get("cols") else {
+            return Err(error("\"cols\" must be an array".to_string()));
+        };
+
+        let mut circuit = QuantumCircuit::new(0);
+        for (position, column) in columns.iter().enumerate() {
+            let JsonValue::Array(cells) = column else {
+                return Err(error(format!("column {position}: must be an array")));
+            };
+            circuit.num_qubits = circuit.num_qubits.max(cells.len());
+            let controls: Vec<usize> = cells
+                .iter()
+                .enumerate()
+                .filter(|(_, cell)| matches!(cell, JsonValue::String(id) if id == "•"))
+                .map(|(control, _)| control)
+                .collect();
+            for (target, cell) in cells.iter().enumerate() {
+                let id = match cell {
+                    JsonValue::Number(number) if *number == 1.0 => continue,
+                    JsonValue::String(id) if id == "•" => continue,
+                    JsonValue::String(id) => id.as_str(),
+                    JsonValue::Object(_) => match cell.get("id") {
+                        Some(JsonValue::String(id)) => id.as_str(),
+                        _ => {
+                            return Err(error(format!(
+                                "column {position}: gate is missing it's id"
+                            )))
+                        }
+                    },
+                    _ => return Err(error(format!("column {position}: invalid gate"))),
+                };
+                let post_selection = match id {
+                    "|0⟩⟨0|" => Some(false),
+                    "|1⟩⟨1|" => Some(true),
+                    _ => None,
+                };
+                if let Some(value) = post_selection {
+                    circuit.post_selections.push((target, value));
+                    continue;
+                }
+                if !circuit.post_selections.is_empty() {
+                    return Err(error(format!(
+                        "column {position}: gates after a post-selection are not supported"
+                    )));
+                }
+
+                let argument = || match cell.get("arg") {
+                    Some(JsonValue::String(argument)) => evaluate(argument),
+                    Some(&JsonValue::Number(argument)) => Some(argument),
+                    _ => None,
+                };
+                let operator = match id {
+                    "H" => QuantumOperator::SUPERPOSITION,
+                    "X" => QuantumOperator::NOT,
+                    "Y" => QuantumOperator::ROTATE,
+                    "Z" => QuantumOperator::PHASE,
+                    "X^½" => QuantumOperator::RX(PI / 2.0),
+                    "X^-½" => QuantumOperator::RX(-PI / 2.0),
+                    "Y^½" => QuantumOperator::RY(PI / 2.0),
+                    "Y^-½" => QuantumOperator::RY(-PI / 2.0),
+                    "Z^½" => QuantumOperator::RZ(PI / 2.0),
+                    "Z^-½" => QuantumOperator::RZ(-PI / 2.0),
+                    "Z^¼" => QuantumOperator::RZ(PI / 4.0),
+                    "Z^-¼" => QuantumOperator::RZ(-PI / 4.0),
+                    "Rxft" | "Ryft" | "Rzft" => {
+                        let angle = argument().ok_or_else(|| {
+                            error(format!(
+                                "column {position}: \"{id}\" needs a constant argument"
+                            ))
+                        })?;
+                        match id {
+                            "Rxft" => QuantumOperator::RX(angle),
+                            "Ryft" => QuantumOperator::RY(angle),
+                            _ => QuantumOperator::RZ(angle),
+                        }
+                    }
+                    _ => {
+                        return Err(error(format!(
+                            "column {position}: unsupported gate \"{id}\""
+                        )))
+                    }
+                };
+                circuit.operations.push(QuantumOperation {
+                    operator,
+                    target,
+                    controls: controls.clone(),
+                });
+            }
+        }
+        Ok(circuit)
+    }
+}
@@ -0,0 +1,13 @@
+/// [`Format`] is a file format that a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit)
+/// can be exported to and imported from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// An OpenQASM 2.0 program.
+    Qasm,
+
+    /// The JSON document written by [`QuantumCircuit::to_json`](crate::quantum::types::quantum_circuit::QuantumCircuit::to_json).
+    Json,
+
+    /// The JSON document used by the Quirk circuit simulator.
+    Quirk,
+}
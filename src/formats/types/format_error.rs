@@ -0,0 +1,12 @@
+use crate::formats::types::format::Format;
+
+/// [`FormatError`] is the reason a file could not be read as a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) of a [`Format`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatError {
+    /// The [`Format`] the file was read as.
+    pub format: Format,
+
+    /// A short description of what is wrong with the file, and where.
+    pub message: String,
+}
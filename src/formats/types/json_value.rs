@@ -0,0 +1,22 @@
+/// [`JsonValue`] is a parsed JSON document, which is read by the JSON and Quirk
+/// [`formats`](crate::formats).
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum JsonValue {
+    /// The `null` literal.
+    Null,
+
+    /// The `true` or `false` literal.
+    Bool(bool),
+
+    /// A number.
+    Number(f64),
+
+    /// A string, with it's escapes resolved.
+    String(String),
+
+    /// An array of values.
+    Array(Vec<JsonValue>),
+
+    /// An object, with it's keys in the order they were written.
+    Object(Vec<(String, JsonValue)>),
+}
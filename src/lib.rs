@@ -4,15 +4,124 @@
 //! It allows for qubit measurement and basic quantum logic in complex vector space.
 
 /// [`quantum`] is a collection of [`types`](crate::quantum::types), [`constants`](crate::quantum::constants),
-/// and [`behaviors`](crate::quantum::behaviors) for quantum measurements and observations.
+/// [`behaviors`](crate::quantum::behaviors), and [`macros`](crate::quantum::macros) for quantum
+/// measurements and observations.
 pub mod quantum {
     /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
     /// [`behaviors`].
     pub mod types {
+        /// [`amplitude_error`](crate::quantum::types::amplitude_error::AmplitudeError) contains all
+        /// the [`types`](crate::quantum::types) for amplitudes that can not make a qubit.
+        pub mod amplitude_error;
+
+        /// [`basis`](crate::quantum::types::basis::Basis) contains all the
+        /// [`types`](crate::quantum::types) for measurement bases.
+        pub mod basis;
+
+        /// [`bloch_sphere`](crate::quantum::types::bloch_sphere::BlochSphere) contains all the
+        /// [`types`](crate::quantum::types) for drawing a qubit on the Bloch sphere.
+        pub mod bloch_sphere;
+
+        /// [`bloch_trajectory`](crate::quantum::types::bloch_trajectory::BlochTrajectory) contains all
+        /// the [`types`](crate::quantum::types) for timelines of Bloch sphere movement.
+        pub mod bloch_trajectory;
+
+        /// [`clifford_tableau`](crate::quantum::types::clifford_tableau::CliffordTableau) contains all
+        /// the [`types`](crate::quantum::types) for stabilizer states.
+        pub mod clifford_tableau;
+
+        /// [`clone_audit`](crate::quantum::types::clone_audit::CloneAudit) contains all the
+        /// [`types`](crate::quantum::types) for flagging copies of entangled states.
+        pub mod clone_audit;
+
+        /// [`cost_model`](crate::quantum::types::cost_model::CostModel) contains all the
+        /// [`types`](crate::quantum::types) for weighing the gates of a circuit.
+        pub mod cost_model;
+
+        /// [`compact_state_vector`](crate::quantum::types::compact_state_vector::CompactStateVector)
+        /// contains all the [`types`](crate::quantum::types) for single precision states of many qubits.
+        pub mod compact_state_vector;
+
+        /// [`decision_diagram`](crate::quantum::types::decision_diagram::DecisionDiagram) contains all
+        /// the [`types`](crate::quantum::types) for experimental decision diagram states of many qubits.
+        pub mod decision_diagram;
+
+        /// [`decision_node`](crate::quantum::types::decision_node::DecisionNode) contains all the
+        /// [`types`](crate::quantum::types) for the nodes of a decision diagram.
+        pub mod decision_node;
+
+        /// [`density_matrix`](crate::quantum::types::density_matrix::DensityMatrix) contains all the
+        /// [`types`](crate::quantum::types) for mixed states of many qubits.
+        pub mod density_matrix;
+
+        /// [`display_precision`](crate::quantum::types::display_precision::DisplayPrecision) contains
+        /// all the [`types`](crate::quantum::types) for the precision of displayed amplitudes.
+        pub mod display_precision;
+
+        /// [`dual_number`](crate::quantum::types::dual_number::DualNumber) contains all the
+        /// [`types`](crate::quantum::types) for numbers that carry their own derivatives.
+        pub mod dual_number;
+
+        /// [`gate_name_error`](crate::quantum::types::gate_name_error::GateNameError) contains all the
+        /// [`types`](crate::quantum::types) for gate names that could not be read.
+        pub mod gate_name_error;
+
+        /// [`gate_recording`](crate::quantum::types::gate_recording::GateRecording) contains all the
+        /// [`types`](crate::quantum::types) for remembering the gates applied to a qubit.
+        pub mod gate_recording;
+
+        /// [`graph_state`](crate::quantum::types::graph_state::GraphState) contains all the
+        /// [`types`](crate::quantum::types) for entangled states described by graphs.
+        pub mod graph_state;
+
+        /// [`hamiltonian`](crate::quantum::types::hamiltonian::Hamiltonian) contains all the
+        /// [`types`](crate::quantum::types) for weighted sums of Pauli strings.
+        pub mod hamiltonian;
+
+        /// [`heatmap`](crate::quantum::types::heatmap::Heatmap) contains all the
+        /// [`types`](crate::quantum::types) for rendering gate matrices as shaded text.
+        pub mod heatmap;
+
+        /// [`matrix_product_state`](crate::quantum::types::matrix_product_state::MatrixProductState)
+        /// contains all the [`types`](crate::quantum::types) for weakly entangled states of many qubits.
+        pub mod matrix_product_state;
+
+        /// [`observable`](crate::quantum::types::observable::Observable) contains all the
+        /// [`types`](crate::quantum::types) for measured weighted sums of Pauli strings.
+        pub mod observable;
+
+        /// [`pauli`](crate::quantum::types::pauli::Pauli) contains all the
+        /// [`types`](crate::quantum::types) for Pauli operators.
+        pub mod pauli;
+
+        /// [`pauli_string`](crate::quantum::types::pauli_string::PauliString) contains all the
+        /// [`types`](crate::quantum::types) for tensor products of Pauli operators.
+        pub mod pauli_string;
+
+        /// [`qasm_statement`](crate::quantum::types::qasm_statement::QasmStatement) contains all the
+        /// [`types`](crate::quantum::types) for instructions read from OpenQASM programs.
+        pub mod qasm_statement;
+
+        /// [`qasm_stream`](crate::quantum::types::qasm_stream::QasmStream) contains all the
+        /// [`types`](crate::quantum::types) for reading OpenQASM programs one line at a time.
+        pub mod qasm_stream;
+
+        /// [`quantum_circuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) contains all the
+        /// [`types`](crate::quantum::types) for ordered sequences of quantum logic gates.
+        pub mod quantum_circuit;
+
         /// [`quantum_gate`](crate::quantum::types::quantum_gate::QuantumGate) contains all the
         /// [`types`](crate::quantum::types) for quantum logic gates.
         pub mod quantum_gate;
 
+        /// [`quantum_error`](crate::quantum::types::quantum_error::QuantumError) contains all the
+        /// [`types`](crate::quantum::types) for register operations that could not be done.
+        pub mod quantum_error;
+
+        /// [`quantum_operation`](crate::quantum::types::quantum_operation::QuantumOperation) contains
+        /// all the [`types`](crate::quantum::types) for single steps of a quantum circuit.
+        pub mod quantum_operation;
+
         /// [`quantum_operators`](crate::quantum::types::quantum_operators::QuantumOperator) contains
         /// all the [`types`](crate::quantum::types) for logical operation types.
         pub mod quantum_operators;
@@ -29,6 +138,22 @@ pub mod quantum {
         /// [`qubit`](crate::quantum::types::qubit::Qubit) contains all the [`types`](crate::quantum::types)
         /// for anything related to qubits.
         pub mod qubit;
+
+        /// [`qubit_view`](crate::quantum::types::qubit_view::QubitView) contains all the
+        /// [`types`](crate::quantum::types) for addressing a subset of the qubits in a register.
+        pub mod qubit_view;
+
+        /// [`state_vector`](crate::quantum::types::state_vector::StateVector) contains all the
+        /// [`types`](crate::quantum::types) for entangled states of many qubits.
+        pub mod state_vector;
+
+        /// [`uncomputation_check`](crate::quantum::types::uncomputation_check::UncomputationCheck)
+        /// contains all the [`types`](crate::quantum::types) for verifying ancillas return to $|0\rangle$.
+        pub mod uncomputation_check;
+
+        /// [`unique_qubit`](crate::quantum::types::unique_qubit::UniqueQubit) contains all the
+        /// [`types`](crate::quantum::types) for move-only qubits that can not be cloned.
+        pub mod unique_qubit;
     }
 
     /// [`constants`] is a collection of `const` values that will never change, and are
@@ -41,15 +166,117 @@ pub mod quantum {
 
     /// [`behaviors`] is a collection of implementations for each [`type`](crate::quantum::types).
     pub mod behaviors {
+        /// [`amplitude_error`](crate::quantum::types::amplitude_error::AmplitudeError) contains all
+        /// the [`behaviors`](crate::quantum::behaviors) for amplitudes that can not make a qubit.
+        pub mod amplitude_error;
+
+        /// [`basis`](crate::quantum::types::basis::Basis) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for measurement bases.
+        pub mod basis;
+
+        /// [`bloch_sphere`](crate::quantum::types::bloch_sphere::BlochSphere) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for drawing a qubit on the Bloch sphere.
+        pub mod bloch_sphere;
+
+        /// [`bloch_trajectory`](crate::quantum::types::bloch_trajectory::BlochTrajectory) contains all
+        /// the [`behaviors`](crate::quantum::behaviors) for exporting timelines of Bloch sphere movement.
+        pub mod bloch_trajectory;
+
+        /// [`clifford_tableau`](crate::quantum::types::clifford_tableau::CliffordTableau) contains all
+        /// the [`types`](crate::quantum::types) for stabilizer states.
+        pub mod clifford_tableau;
+
+        /// [`clone_audit`](crate::quantum::types::clone_audit::CloneAudit) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for flagging copies of entangled states.
+        pub mod clone_audit;
+
+        /// [`cost_model`](crate::quantum::types::cost_model::CostModel) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for weighing the gates of a circuit.
+        pub mod cost_model;
+
+        /// [`compact_state_vector`](crate::quantum::types::compact_state_vector::CompactStateVector)
+        /// contains all the [`behaviors`](crate::quantum::behaviors) for single precision states of many qubits.
+        pub mod compact_state_vector;
+
+        /// [`decision_diagram`](crate::quantum::types::decision_diagram::DecisionDiagram) contains all
+        /// the [`behaviors`](crate::quantum::behaviors) for experimental decision diagram states of many qubits.
+        pub mod decision_diagram;
+
+        /// [`density_matrix`](crate::quantum::types::density_matrix::DensityMatrix) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for mixed states of many qubits.
+        pub mod density_matrix;
+
+        /// [`display_precision`](crate::quantum::types::display_precision::DisplayPrecision) contains
+        /// all the [`behaviors`](crate::quantum::behaviors) for the precision of displayed amplitudes.
+        pub mod display_precision;
+
+        /// [`dual_number`](crate::quantum::types::dual_number::DualNumber) contains all the
+        /// [`types`](crate::quantum::types) for numbers that carry their own derivatives.
+        pub mod dual_number;
+
+        /// [`gate_name_error`](crate::quantum::types::gate_name_error::GateNameError) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for gate names that could not be read.
+        pub mod gate_name_error;
+
+        /// [`gate_recording`](crate::quantum::types::gate_recording::GateRecording) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for remembering the gates applied to a qubit.
+        pub mod gate_recording;
+
+        /// [`graph_state`](crate::quantum::types::graph_state::GraphState) contains all the
+        /// [`types`](crate::quantum::types) for entangled states described by graphs.
+        pub mod graph_state;
+
+        /// [`hamiltonian`](crate::quantum::types::hamiltonian::Hamiltonian) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for weighted sums of Pauli strings.
+        pub mod hamiltonian;
+
+        /// [`heatmap`](crate::quantum::types::heatmap::Heatmap) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for rendering gate matrices as shaded text.
+        pub mod heatmap;
+
+        /// [`matrix_product_state`](crate::quantum::types::matrix_product_state::MatrixProductState)
+        /// contains all the [`behaviors`](crate::quantum::behaviors) for weakly entangled states of many qubits.
+        pub mod matrix_product_state;
+
+        /// [`pauli`](crate::quantum::types::pauli::Pauli) contains all the
+        /// [`types`](crate::quantum::types) for Pauli operators.
+        pub mod pauli;
+
+        /// [`pauli_string`](crate::quantum::types::pauli_string::PauliString) contains all the
+        /// [`types`](crate::quantum::types) for tensor products of Pauli operators.
+        pub mod pauli_string;
+
+        /// [`qasm_stream`](crate::quantum::types::qasm_stream::QasmStream) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for reading OpenQASM programs one line at a time.
+        pub mod qasm_stream;
+
+        /// [`quantum_circuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for ordered sequences of quantum logic gates.
+        pub mod quantum_circuit;
+
         /// [`quantum_gate`](crate::quantum::types::quantum_gate::QuantumGate) contains all the
         /// [`behaviors`](crate::quantum::behaviors) for quantum logic gates.
         pub mod quantum_gate;
 
+        /// [`quantum_operation`](crate::quantum::types::quantum_operation::QuantumOperation) contains
+        /// all the [`behaviors`](crate::quantum::behaviors) for single steps of a quantum circuit.
+        pub mod quantum_operation;
+
+        /// [`quantum_error`](crate::quantum::types::quantum_error::QuantumError) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for register operations that could not be done.
+        pub mod quantum_error;
+
         /// [`quantum_position`](crate::quantum::types::quantum_position::QuantumPosition) contains all the
         /// [`behaviors`](crate::quantum::behaviors) for expressing qubit position in complex
         /// vector space.
         pub mod quantum_position;
 
+        /// [`qir`] contains all the [`behaviors`](crate::quantum::behaviors) for lowering a
+        /// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) to the
+        /// Quantum Intermediate Representation, and is enabled with the `qir` feature.
+        #[cfg(feature = "qir")]
+        pub mod qir;
+
         /// [`qubit_register`](crate::quantum::types::qubit_register::QubitRegister) contains all the
         /// [`behaviors`](crate::quantum::behaviors) for interacting with multiple qubits.
         pub mod qubit_register;
@@ -57,6 +284,34 @@ pub mod quantum {
         /// [`qubit`](crate::quantum::types::qubit::Qubit) contains all the
         /// [`behaviors`](crate::quantum::behaviors) for anything related to qubits.
         pub mod qubit;
+
+        /// [`qubit_view`](crate::quantum::types::qubit_view::QubitView) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for addressing a subset of the qubits in a register.
+        pub mod qubit_view;
+
+        /// [`simd`] contains the SSE2 kernels that apply gates to a
+        /// [`StateVector`](crate::quantum::types::state_vector::StateVector), and is enabled with the
+        /// `simd` feature on `x86_64` targets.
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        pub(crate) mod simd;
+
+        /// [`state_vector`](crate::quantum::types::state_vector::StateVector) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for entangled states of many qubits.
+        pub mod state_vector;
+
+        /// [`uncomputation_check`](crate::quantum::types::uncomputation_check::UncomputationCheck)
+        /// contains all the [`behaviors`](crate::quantum::behaviors) for verifying ancillas return to $|0\rangle$.
+        pub mod uncomputation_check;
+
+        /// [`unique_qubit`](crate::quantum::types::unique_qubit::UniqueQubit) contains all the
+        /// [`behaviors`](crate::quantum::behaviors) for move-only qubits that can not be cloned.
+        pub mod unique_qubit;
+    }
+
+    /// [`macros`] is a collection of macros that are used for [`quantum`](crate::quantum).
+    pub mod macros {
+        /// [`circuit`] contains all the `macro_rules` for building quantum circuits.
+        pub mod circuit;
     }
 }
 
@@ -109,21 +364,470 @@ pub mod simulation {
     /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
     /// [`simulation`](crate::simulation) [`behaviors`].
     pub mod types {
+        /// [`amplitude_change`](`crate::simulation::types::amplitude_change::AmplitudeChange`) contains
+        /// all the [`types`](crate::simulation::types) for one amplitude changed by a traced operation.
+        pub mod amplitude_change;
+        /// [`backend`](`crate::simulation::types::backend::Backend`) contains all the
+        /// [`types`](crate::simulation::types) for state representations that run gates.
+        pub mod backend;
+        /// [`backend_kind`](`crate::simulation::types::backend_kind::BackendKind`) contains all the
+        /// [`types`](crate::simulation::types) for choosing the backend of a simulation.
+        pub mod backend_kind;
+        /// [`cancellation_token`](`crate::simulation::types::cancellation_token::CancellationToken`)
+        /// contains all the [`types`](crate::simulation::types) for stopping long runs early.
+        pub mod cancellation_token;
+        /// [`csv_writer`](`crate::simulation::types::csv_writer::CsvWriter`) contains all the
+        /// [`types`](crate::simulation::types) for streaming shots to CSV.
+        pub mod csv_writer;
+        /// [`executor`](`crate::simulation::types::executor::Executor`) contains all the
+        /// [`types`](crate::simulation::types) for running shots that are reported to observers.
+        pub mod executor;
+        /// [`goodness_of_fit`](`crate::simulation::types::goodness_of_fit::GoodnessOfFit`) contains
+        /// all the [`types`](crate::simulation::types) for testing if shots fit a distribution.
+        pub mod goodness_of_fit;
+        /// [`observer`](`crate::simulation::types::observer::Observer`) contains all the
+        /// [`types`](crate::simulation::types) for watching shots as they run.
+        pub mod observer;
+        /// [`progress_hook`](`crate::simulation::types::progress_hook::ProgressHook`) contains all
+        /// the [`types`](crate::simulation::types) for watching the progress of long simulations.
+        pub mod progress_hook;
+        /// [`qubit_marginal`](`crate::simulation::types::qubit_marginal::QubitMarginal`) contains all
+        /// the [`types`](crate::simulation::types) for the observed outcomes of a single qubit.
+        pub mod qubit_marginal;
+        /// [`report`](`crate::simulation::types::report::Report`) contains all the
+        /// [`types`](crate::simulation::types) for the structured results of a simulation.
+        pub mod report;
         /// [`simulation`](`crate::simulation::types::simulation::Simulation<T>`) contains all the
         /// [`types`](crate::simulation::types) for running simulations.
         pub mod simulation;
+        /// [`simulation_config`](`crate::simulation::types::simulation_config::SimulationConfig`)
+        /// contains all the [`types`](crate::simulation::types) for the settings of a simulation.
+        pub mod simulation_config;
+        /// [`simulation_outcome`](`crate::simulation::types::simulation_outcome::SimulationOutcome`)
+        /// contains all the [`types`](crate::simulation::types) for measured shots of a simulation.
+        pub mod simulation_outcome;
         /// [`simulation_report`](`crate::simulation::types::simulation_report::SimulationReport<T>`)
         /// contains all the [`types`](crate::simulation::types) for reporting simulation results.
         pub mod simulation_report;
+        /// [`state_trace`](`crate::simulation::types::state_trace::StateTrace`) contains all the
+        /// [`types`](crate::simulation::types) for recording how every operation changes a state.
+        pub mod state_trace;
+        /// [`sweep`](`crate::simulation::types::sweep::Sweep`) contains all the
+        /// [`types`](crate::simulation::types) for simulating a circuit over a grid of parameters.
+        pub mod sweep;
+        /// [`trace_step`](`crate::simulation::types::trace_step::TraceStep`) contains all the
+        /// [`types`](crate::simulation::types) for one operation of a state trace.
+        pub mod trace_step;
     }
 
     /// [`behaviors`] is a collection of implementations for each [`type`](crate::simulation::types).
     pub mod behaviors {
+        /// [`cancellation_token`](`crate::simulation::types::cancellation_token::CancellationToken`)
+        /// contains all the [`behaviors`](crate::simulation::behaviors) for stopping long runs early.
+        pub mod cancellation_token;
+        /// [`csv_writer`](`crate::simulation::types::csv_writer::CsvWriter`) contains all the
+        /// [`behaviors`](crate::simulation::behaviors) for streaming shots to CSV.
+        pub mod csv_writer;
+        /// [`executor`](`crate::simulation::types::executor::Executor`) contains all the
+        /// [`behaviors`](crate::simulation::behaviors) for running shots that are reported to observers.
+        pub mod executor;
+        /// [`goodness_of_fit`](`crate::simulation::types::goodness_of_fit::GoodnessOfFit`) contains
+        /// all the [`behaviors`](crate::simulation::behaviors) for testing if shots fit a
+        /// distribution.
+        pub mod goodness_of_fit;
+        /// [`progress_hook`](`crate::simulation::types::progress_hook::ProgressHook`) contains all
+        /// the [`behaviors`](crate::simulation::behaviors) for watching the progress of long
+        /// simulations.
+        pub mod progress_hook;
+        /// [`report`](`crate::simulation::types::report::Report`) contains all the
+        /// [`behaviors`](crate::simulation::behaviors) for the structured results of a simulation.
+        pub mod report;
         /// [`simulation`](`crate::simulation::types::simulation::Simulation<T>`) contains all the
         /// [`behaviors`](crate::simulation::behaviors) for running simulations.
         pub mod simulation;
+        /// [`simulation_config`](`crate::simulation::types::simulation_config::SimulationConfig`)
+        /// contains all the [`behaviors`](crate::simulation::behaviors) for the settings of a
+        /// simulation.
+        pub mod simulation_config;
+        /// [`simulation_outcome`](`crate::simulation::types::simulation_outcome::SimulationOutcome`)
+        /// contains all the [`behaviors`](crate::simulation::behaviors) for measured shots of a simulation.
+        pub mod simulation_outcome;
         /// [`simulation_report`](`crate::simulation::types::simulation_report::SimulationReport<T>`)
         /// contains all the [`behaviors`](crate::simulation::behaviors) for reporting simulation results.
         pub mod simulation_report;
+        /// [`state_trace`](`crate::simulation::types::state_trace::StateTrace`) contains all the
+        /// [`behaviors`](crate::simulation::behaviors) for recording how every operation changes a state.
+        pub mod state_trace;
+        /// [`sweep`](`crate::simulation::types::sweep::Sweep`) contains all the
+        /// [`behaviors`](crate::simulation::behaviors) for simulating a circuit over a grid of
+        /// parameters.
+        pub mod sweep;
     }
 }
+
+/// [`error_correction`] is a collection of [`types`](crate::error_correction::types) and
+/// [`behaviors`](crate::error_correction::behaviors) for detecting and correcting errors on qubits.
+pub mod error_correction {
+    /// [`types`] is a collection of `struct`, `enum`, and `trait` that is used to hold data for
+    /// [`error_correction`](crate::error_correction) [`behaviors`].
+    pub mod types {
+        /// [`decoder`](`crate::error_correction::types::decoder::Decoder`) contains all the
+        /// [`types`](crate::error_correction::types) for turning syndromes into corrections.
+        pub mod decoder;
+        /// [`logical_error_rate`](`crate::error_correction::types::logical_error_rate::LogicalErrorRate`)
+        /// contains all the [`types`](crate::error_correction::types) for logical error rate experiments.
+        pub mod logical_error_rate;
+        /// [`lookup_table_decoder`](`crate::error_correction::types::lookup_table_decoder::LookupTableDecoder`)
+        /// contains all the [`types`](crate::error_correction::types) for decoding with a syndrome table.
+        pub mod lookup_table_decoder;
+        /// [`minimum_weight_matching_decoder`](`crate::error_correction::types::minimum_weight_matching_decoder::MinimumWeightMatchingDecoder`)
+        /// contains all the [`types`](crate::error_correction::types) for decoding with minimum weight matching.
+        pub mod minimum_weight_matching_decoder;
+        /// [`parity_check_code`](`crate::error_correction::types::parity_check_code::ParityCheckCode`)
+        /// contains all the [`types`](crate::error_correction::types) for codes made of parity checks.
+        pub mod parity_check_code;
+        /// [`repetition_code`](`crate::error_correction::types::repetition_code::RepetitionCode`)
+        /// contains all the [`types`](crate::error_correction::types) for the three qubit repetition code.
+        pub mod repetition_code;
+        /// [`steane_code`](`crate::error_correction::types::steane_code::SteaneCode`)
+        /// contains all the [`types`](crate::error_correction::types) for the seven qubit Steane code.
+        pub mod steane_code;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::error_correction::types).
+    pub mod behaviors {
+        /// [`logical_error_rate`](`crate::error_correction::types::logical_error_rate::LogicalErrorRate`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for logical error rate experiments.
+        pub mod logical_error_rate;
+        /// [`lookup_table_decoder`](`crate::error_correction::types::lookup_table_decoder::LookupTableDecoder`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for decoding with a syndrome table.
+        pub mod lookup_table_decoder;
+        /// [`minimum_weight_matching_decoder`](`crate::error_correction::types::minimum_weight_matching_decoder::MinimumWeightMatchingDecoder`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for decoding with minimum weight matching.
+        pub mod minimum_weight_matching_decoder;
+        /// [`parity_check_code`](`crate::error_correction::types::parity_check_code::ParityCheckCode`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for codes made of parity checks.
+        pub mod parity_check_code;
+        /// [`repetition_code`](`crate::error_correction::types::repetition_code::RepetitionCode`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for the three qubit repetition code.
+        pub mod repetition_code;
+        /// [`steane_code`](`crate::error_correction::types::steane_code::SteaneCode`)
+        /// contains all the [`behaviors`](crate::error_correction::behaviors) for the seven qubit Steane code.
+        pub mod steane_code;
+    }
+}
+
+/// [`algorithms`] is a collection of [`types`](crate::algorithms::types) and
+/// [`behaviors`](crate::algorithms::behaviors) for well known quantum algorithms.
+pub mod algorithms {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`algorithms`](crate::algorithms) [`behaviors`].
+    pub mod types {
+        /// [`energy_landscape`](`crate::algorithms::types::energy_landscape::EnergyLandscape`) contains
+        /// all the [`types`](crate::algorithms::types) for scanning the energy of circuit parameters.
+        pub mod energy_landscape;
+        /// [`gradient_descent`](`crate::algorithms::types::gradient_descent::GradientDescent`) contains all
+        /// the [`types`](crate::algorithms::types) for optimizing parameters along their gradient.
+        pub mod gradient_descent;
+        /// [`hhl`](`crate::algorithms::types::hhl::Hhl`) contains all the
+        /// [`types`](crate::algorithms::types) for the HHL quantum linear systems algorithm.
+        pub mod hhl;
+        /// [`hhl_result`](`crate::algorithms::types::hhl_result::HhlResult`) contains all the
+        /// [`types`](crate::algorithms::types) for the outcome of the HHL algorithm.
+        pub mod hhl_result;
+        /// [`mermin_ghz`](`crate::algorithms::types::mermin_ghz::MerminGhz`) contains all the
+        /// [`types`](crate::algorithms::types) for the Mermin–GHZ test of local realism.
+        pub mod mermin_ghz;
+        /// [`optimizer`](`crate::algorithms::types::optimizer::Optimizer`) contains all the
+        /// [`types`](crate::algorithms::types) for classical optimizers of variational algorithms.
+        pub mod optimizer;
+        /// [`order_finding`](`crate::algorithms::types::order_finding::OrderFinding`) contains all the
+        /// [`types`](crate::algorithms::types) for finding the order of modular exponentiation.
+        pub mod order_finding;
+        /// [`qft_approximation`](`crate::algorithms::types::qft_approximation::QftApproximation`) contains
+        /// all the [`types`](crate::algorithms::types) for the accuracy of an approximate Fourier transform.
+        pub mod qft_approximation;
+        /// [`quantum_fourier_transform`](`crate::algorithms::types::quantum_fourier_transform::QuantumFourierTransform`)
+        /// contains all the [`types`](crate::algorithms::types) for the quantum Fourier transform.
+        pub mod quantum_fourier_transform;
+        /// [`quantum_walk`](`crate::algorithms::types::quantum_walk::QuantumWalk`) contains all the
+        /// [`types`](crate::algorithms::types) for discrete-time quantum walks.
+        pub mod quantum_walk;
+        /// [`shor`](`crate::algorithms::types::shor::Shor`) contains all the
+        /// [`types`](crate::algorithms::types) for Shor's factoring algorithm.
+        pub mod shor;
+        /// [`vqe`](`crate::algorithms::types::vqe::Vqe`) contains all the
+        /// [`types`](crate::algorithms::types) for the variational quantum eigensolver.
+        pub mod vqe;
+        /// [`vqe_result`](`crate::algorithms::types::vqe_result::VqeResult`) contains all the
+        /// [`types`](crate::algorithms::types) for the outcome of the variational quantum eigensolver.
+        pub mod vqe_result;
+        /// [`walk_topology`](`crate::algorithms::types::walk_topology::WalkTopology`) contains all the
+        /// [`types`](crate::algorithms::types) for the positions of quantum walks.
+        pub mod walk_topology;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::algorithms::types).
+    pub mod behaviors {
+        /// [`energy_landscape`](`crate::algorithms::types::energy_landscape::EnergyLandscape`) contains
+        /// all the [`behaviors`](crate::algorithms::behaviors) for scanning the energy of circuit parameters.
+        pub mod energy_landscape;
+        /// [`gradient_descent`](`crate::algorithms::types::gradient_descent::GradientDescent`) contains all
+        /// the [`behaviors`](crate::algorithms::behaviors) for optimizing parameters along their gradient.
+        pub mod gradient_descent;
+        /// [`hhl`](`crate::algorithms::types::hhl::Hhl`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for the HHL quantum linear systems algorithm.
+        pub mod hhl;
+        /// [`mermin_ghz`](`crate::algorithms::types::mermin_ghz::MerminGhz`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for the Mermin–GHZ test of local realism.
+        pub mod mermin_ghz;
+        /// [`order_finding`](`crate::algorithms::types::order_finding::OrderFinding`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for finding the order of modular exponentiation.
+        pub mod order_finding;
+        /// [`quantum_fourier_transform`](`crate::algorithms::types::quantum_fourier_transform::QuantumFourierTransform`)
+        /// contains all the [`behaviors`](crate::algorithms::behaviors) for the quantum Fourier transform.
+        pub mod quantum_fourier_transform;
+        /// [`quantum_walk`](`crate::algorithms::types::quantum_walk::QuantumWalk`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for discrete-time quantum walks.
+        pub mod quantum_walk;
+        /// [`shor`](`crate::algorithms::types::shor::Shor`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for Shor's factoring algorithm.
+        pub mod shor;
+        /// [`vqe`](`crate::algorithms::types::vqe::Vqe`) contains all the
+        /// [`behaviors`](crate::algorithms::behaviors) for the variational quantum eigensolver.
+        pub mod vqe;
+    }
+}
+
+/// [`noise`] is a collection of [`types`](crate::noise::types) and [`behaviors`](crate::noise::behaviors)
+/// for errors that happen on real quantum hardware.
+pub mod noise {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`noise`](crate::noise) [`behaviors`].
+    pub mod types {
+        /// [`decoherence_model`](`crate::noise::types::decoherence_model::DecoherenceModel`) contains
+        /// all the [`types`](crate::noise::types) for relaxation and dephasing over time.
+        pub mod decoherence_model;
+        /// [`error_budget`](`crate::noise::types::error_budget::ErrorBudget`) contains all the
+        /// [`types`](crate::noise::types) for estimating the success of a noisy circuit.
+        pub mod error_budget;
+        /// [`error_contribution`](`crate::noise::types::error_contribution::ErrorContribution`)
+        /// contains all the [`types`](crate::noise::types) for the fidelity of one step of a circuit.
+        pub mod error_contribution;
+        /// [`error_source`](`crate::noise::types::error_source::ErrorSource`) contains all the
+        /// [`types`](crate::noise::types) for where the error of a circuit comes from.
+        pub mod error_source;
+        /// [`kraus_channel`](`crate::noise::types::kraus_channel::KrausChannel`) contains all the
+        /// [`types`](crate::noise::types) for arbitrary channels described by Kraus operators.
+        pub mod kraus_channel;
+        /// [`lindblad_equation`](`crate::noise::types::lindblad_equation::LindbladEquation`) contains
+        /// all the [`types`](crate::noise::types) for the dissipative evolution of open systems.
+        pub mod lindblad_equation;
+        /// [`noise_channel`](`crate::noise::types::noise_channel::NoiseChannel`) contains all the
+        /// [`types`](crate::noise::types) for standard single-qubit errors.
+        pub mod noise_channel;
+        /// [`noise_model`](`crate::noise::types::noise_model::NoiseModel`) contains all the
+        /// [`types`](crate::noise::types) for attaching errors to simulation runs.
+        pub mod noise_model;
+        /// [`readout_error`](`crate::noise::types::readout_error::ReadoutError`) contains all the
+        /// [`types`](crate::noise::types) for misread measurements.
+        pub mod readout_error;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::noise::types).
+    pub mod behaviors {
+        /// [`decoherence_model`](`crate::noise::types::decoherence_model::DecoherenceModel`) contains
+        /// all the [`behaviors`](crate::noise::behaviors) for relaxation and dephasing over time.
+        pub mod decoherence_model;
+        /// [`error_budget`](`crate::noise::types::error_budget::ErrorBudget`) contains all the
+        /// [`behaviors`](crate::noise::behaviors) for estimating the success of a noisy circuit.
+        pub mod error_budget;
+        /// [`kraus_channel`](`crate::noise::types::kraus_channel::KrausChannel`) contains all the
+        /// [`behaviors`](crate::noise::behaviors) for arbitrary channels described by Kraus operators.
+        pub mod kraus_channel;
+        /// [`lindblad_equation`](`crate::noise::types::lindblad_equation::LindbladEquation`) contains
+        /// all the [`behaviors`](crate::noise::behaviors) for the dissipative evolution of open systems.
+        pub mod lindblad_equation;
+        /// [`noise_channel`](`crate::noise::types::noise_channel::NoiseChannel`) contains all the
+        /// [`behaviors`](crate::noise::behaviors) for standard single-qubit errors.
+        pub mod noise_channel;
+        /// [`noise_model`](`crate::noise::types::noise_model::NoiseModel`) contains all the
+        /// [`behaviors`](crate::noise::behaviors) for attaching errors to simulation runs.
+        pub mod noise_model;
+        /// [`readout_error`](`crate::noise::types::readout_error::ReadoutError`) contains all the
+        /// [`behaviors`](crate::noise::behaviors) for misread measurements.
+        pub mod readout_error;
+    }
+}
+
+/// [`benchmarking`] is a collection of [`types`](crate::benchmarking::types) and
+/// [`behaviors`](crate::benchmarking::behaviors) for characterizing the error of quantum gates.
+pub mod benchmarking {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`benchmarking`](crate::benchmarking) [`behaviors`].
+    pub mod types {
+        /// [`clifford`](`crate::benchmarking::types::clifford::Clifford`) contains all the
+        /// [`types`](crate::benchmarking::types) for single-qubit Clifford gates.
+        pub mod clifford;
+        /// [`gate_estimate`](`crate::benchmarking::types::gate_estimate::GateEstimate`) contains all the
+        /// [`types`](crate::benchmarking::types) for the outcome of gate set tomography.
+        pub mod gate_estimate;
+        /// [`gate_set_tomography`](`crate::benchmarking::types::gate_set_tomography::GateSetTomography`)
+        /// contains all the [`types`](crate::benchmarking::types) for gate set tomography.
+        pub mod gate_set_tomography;
+        /// [`interleaved_rb_result`](`crate::benchmarking::types::interleaved_rb_result::InterleavedRbResult`)
+        /// contains all the [`types`](crate::benchmarking::types) for the outcome of interleaved
+        /// randomized benchmarking.
+        pub mod interleaved_rb_result;
+        /// [`randomized_benchmarking`](`crate::benchmarking::types::randomized_benchmarking::RandomizedBenchmarking`)
+        /// contains all the [`types`](crate::benchmarking::types) for randomized benchmarking.
+        pub mod randomized_benchmarking;
+        /// [`rb_result`](`crate::benchmarking::types::rb_result::RbResult`) contains all the
+        /// [`types`](crate::benchmarking::types) for the outcome of randomized benchmarking.
+        pub mod rb_result;
+        /// [`state_tomography`](`crate::benchmarking::types::state_tomography::StateTomography`)
+        /// contains all the [`types`](crate::benchmarking::types) for reconstructing states from measurements.
+        pub mod state_tomography;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::benchmarking::types).
+    pub mod behaviors {
+        /// [`clifford`](`crate::benchmarking::types::clifford::Clifford`) contains all the
+        /// [`behaviors`](crate::benchmarking::behaviors) for single-qubit Clifford gates.
+        pub mod clifford;
+        /// [`gate_set_tomography`](`crate::benchmarking::types::gate_set_tomography::GateSetTomography`)
+        /// contains all the [`behaviors`](crate::benchmarking::behaviors) for gate set tomography.
+        pub mod gate_set_tomography;
+        /// [`randomized_benchmarking`](`crate::benchmarking::types::randomized_benchmarking::RandomizedBenchmarking`)
+        /// contains all the [`behaviors`](crate::benchmarking::behaviors) for randomized benchmarking.
+        pub mod randomized_benchmarking;
+        /// [`state_tomography`](`crate::benchmarking::types::state_tomography::StateTomography`)
+        /// contains all the [`behaviors`](crate::benchmarking::behaviors) for reconstructing states from measurements.
+        pub mod state_tomography;
+    }
+}
+
+/// [`mbqc`] is a collection of [`types`](crate::mbqc::types) and [`behaviors`](crate::mbqc::behaviors)
+/// for measurement-based (one-way) quantum computation.
+pub mod mbqc {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`mbqc`](crate::mbqc) [`behaviors`].
+    pub mod types {
+        /// [`measurement_pattern`](`crate::mbqc::types::measurement_pattern::MeasurementPattern`) contains
+        /// all the [`types`](crate::mbqc::types) for measurement patterns on cluster states.
+        pub mod measurement_pattern;
+        /// [`pattern_correction`](`crate::mbqc::types::pattern_correction::PatternCorrection`) contains
+        /// all the [`types`](crate::mbqc::types) for byproduct corrections of measurement patterns.
+        pub mod pattern_correction;
+        /// [`pattern_measurement`](`crate::mbqc::types::pattern_measurement::PatternMeasurement`) contains
+        /// all the [`types`](crate::mbqc::types) for adaptive measurements of measurement patterns.
+        pub mod pattern_measurement;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::mbqc::types).
+    pub mod behaviors {
+        /// [`measurement_pattern`](`crate::mbqc::types::measurement_pattern::MeasurementPattern`) contains
+        /// all the [`behaviors`](crate::mbqc::behaviors) for measurement patterns on cluster states.
+        pub mod measurement_pattern;
+    }
+}
+
+/// [`bosonic`] is a collection of [`types`](crate::bosonic::types) and [`behaviors`](crate::bosonic::behaviors)
+/// for truncated harmonic oscillators and their coupling to qubits.
+pub mod bosonic {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`bosonic`](crate::bosonic) [`behaviors`].
+    pub mod types {
+        /// [`fock_state`](`crate::bosonic::types::fock_state::FockState`) contains all the
+        /// [`types`](crate::bosonic::types) for truncated harmonic oscillators.
+        pub mod fock_state;
+        /// [`jaynes_cummings`](`crate::bosonic::types::jaynes_cummings::JaynesCummings`) contains
+        /// all the [`types`](crate::bosonic::types) for a qubit coupled to an oscillator.
+        pub mod jaynes_cummings;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::bosonic::types).
+    pub mod behaviors {
+        /// [`fock_state`](`crate::bosonic::types::fock_state::FockState`) contains all the
+        /// [`behaviors`](crate::bosonic::behaviors) for truncated harmonic oscillators.
+        pub mod fock_state;
+        /// [`jaynes_cummings`](`crate::bosonic::types::jaynes_cummings::JaynesCummings`) contains
+        /// all the [`behaviors`](crate::bosonic::behaviors) for a qubit coupled to an oscillator.
+        pub mod jaynes_cummings;
+    }
+}
+
+/// [`formats`] is a collection of [`types`](crate::formats::types) and [`behaviors`](crate::formats::behaviors)
+/// for exporting, importing and validating circuit files.
+pub mod formats {
+    /// [`types`] is a collection of `struct` and `enum` that is used to hold data for
+    /// [`formats`](crate::formats) [`behaviors`].
+    pub mod types {
+        /// [`format`](`crate::formats::types::format::Format`) contains all the
+        /// [`types`](crate::formats::types) for circuit file formats.
+        pub mod format;
+        /// [`format_error`](`crate::formats::types::format_error::FormatError`) contains all the
+        /// [`types`](crate::formats::types) for circuit files that could not be read.
+        pub mod format_error;
+        /// [`json_value`] contains all the [`types`](crate::formats::types) for parsed JSON
+        /// documents.
+        pub mod json_value;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::formats::types).
+    pub mod behaviors {
+        /// [`format`](`crate::formats::types::format::Format`) contains all the
+        /// [`behaviors`](crate::formats::behaviors) for circuit file formats.
+        pub mod format;
+        /// [`format_error`](`crate::formats::types::format_error::FormatError`) contains all the
+        /// [`behaviors`](crate::formats::behaviors) for circuit files that could not be read.
+        pub mod format_error;
+        /// [`json`] contains all the [`behaviors`](crate::formats::behaviors) for exporting and
+        /// importing a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit)
+        /// as JSON.
+        pub mod json;
+        /// [`json_value`] contains all the [`behaviors`](crate::formats::behaviors) for parsed JSON
+        /// documents.
+        pub mod json_value;
+        /// [`qasm`] contains all the [`behaviors`](crate::formats::behaviors) for exporting and
+        /// importing a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit)
+        /// as OpenQASM 2.0.
+        pub mod qasm;
+        /// [`quirk`] contains all the [`behaviors`](crate::formats::behaviors) for exporting and
+        /// importing a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit)
+        /// as a Quirk circuit.
+        pub mod quirk;
+    }
+
+    pub use behaviors::format::validate;
+}
+
+/// [`testing`] is a collection of [`types`](crate::testing::types), [`behaviors`](crate::testing::behaviors),
+/// and [`macros`](crate::testing::macros) for asserting on quantum states in tests.
+pub mod testing {
+    /// [`types`] is a collection of `trait` that is used to hold data for
+    /// [`testing`](crate::testing) [`macros`].
+    pub mod types {
+        /// [`quantum_state`](`crate::testing::types::quantum_state::QuantumState`) contains all the
+        /// [`types`](crate::testing::types) for comparing quantum states by amplitude.
+        pub mod quantum_state;
+    }
+
+    /// [`behaviors`] is a collection of implementations for each [`type`](crate::testing::types).
+    pub mod behaviors {
+        /// [`quantum_state`](`crate::testing::types::quantum_state::QuantumState`) contains all the
+        /// [`behaviors`](crate::testing::behaviors) for comparing quantum states by amplitude.
+        pub mod quantum_state;
+    }
+
+    /// [`macros`] is a collection of macros that are used for [`testing`](crate::testing).
+    pub mod macros {
+        /// [`assert`] contains all the `macro_rules` for [`testing`](crate::testing).
+        pub mod assert;
+    }
+}
+
+// The exported macros name `Complex` through the crate, so callers do not need their own
+// `num_complex` dependency.
+#[doc(hidden)]
+pub use num_complex;
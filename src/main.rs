@@ -1,25 +1,28 @@
 use rquant::{
     quantum::types::{qubit::Qubit, qubit_register::QubitRegister},
-    simulation::types::{simulation::Simulation, simulation_report::SimulationReport},
+    simulation::types::{
+        simulation::Simulation, simulation_config::SimulationConfig,
+        simulation_report::SimulationReport,
+    },
 };
 
 fn main() {
-    let qubit_simulations = 10000;
+    let config = SimulationConfig::new(10000);
 
     Qubit::one()
-        .simulate_superposition(qubit_simulations)
+        .simulate_superposition(&config)
         .report(Qubit::one());
     Qubit::zero()
-        .simulate_superposition(qubit_simulations)
+        .simulate_superposition(&config)
         .report(Qubit::zero());
     Qubit::quarter_turn()
-        .simulate_superposition(qubit_simulations)
+        .simulate_superposition(&config)
         .report(Qubit::quarter_turn());
     Qubit::flip()
-        .simulate_superposition(qubit_simulations)
+        .simulate_superposition(&config)
         .report(Qubit::flip());
 
     QubitRegister::new(5)
-        .simulate_superposition(qubit_simulations)
+        .simulate_counts(&config)
         .report(QubitRegister::new(5));
 }
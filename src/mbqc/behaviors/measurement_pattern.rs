@@ -0,0 +1,254 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+
+use crate::{
+    mbqc::types::{
+        measurement_pattern::MeasurementPattern, pattern_correction::PatternCorrection,
+        pattern_measurement::PatternMeasurement,
+    },
+    quantum::types::{
+        graph_state::GraphState, pauli::Pauli, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, qubit::Qubit, qubit_register::QubitRegister,
+        state_vector::StateVector,
+    },
+};
+
+impl MeasurementPattern {
+    /// [`MeasurementPattern::new`] will create a [`MeasurementPattern`] on a cluster [`GraphState`],
+    /// with no measurements or corrections.
+    ///
+    /// # Example
+    /// [`MeasurementPattern::new`] can be used to create a pattern on a two qubit cluster:
+    /// ```rust
+    /// use rquant::{mbqc::types::measurement_pattern::MeasurementPattern, quantum::types::graph_state::GraphState};
+    ///
+    /// fn create_two_qubit_pattern() -> MeasurementPattern {
+    ///     MeasurementPattern::new(GraphState::new(2, &[(0, 1)]), &[0], &[1])
+    /// }
+    /// ```
+    pub fn new(graph_state: GraphState, inputs: &[usize], outputs: &[usize]) -> Self {
+        assert!(
+            inputs
+                .iter()
+                .chain(outputs)
+                .all(|&qubit| qubit < graph_state.num_qubits),
+            "Invalid pattern qubit index"
+        );
+
+        MeasurementPattern {
+            graph_state,
+            inputs: inputs.to_vec(),
+            outputs: outputs.to_vec(),
+            measurements: vec![],
+            corrections: vec![],
+        }
+    }
+
+    /// [`MeasurementPattern::measure`] will add a [`PatternMeasurement`] of the [`Qubit`] at index
+    /// `qubit` to the end of the [`MeasurementPattern`], then return the modified [`MeasurementPattern`].
+    ///
+    /// The `angle` is adapted by the outcomes of the `x_signals` and `z_signals`, which must
+    /// already be measured.
+    ///
+    /// # Example
+    /// [`MeasurementPattern::measure`] can be chained to measure a cluster one qubit at a time:
+    /// ```rust
+    /// use rquant::{mbqc::types::measurement_pattern::MeasurementPattern, quantum::types::graph_state::GraphState};
+    ///
+    /// fn measure_three_qubit_chain(alpha: f64, beta: f64) -> MeasurementPattern {
+    ///     MeasurementPattern::new(GraphState::new(3, &[(0, 1), (1, 2)]), &[0], &[2])
+    ///         .measure(0, -alpha, &[], &[])
+    ///         .measure(1, -beta, &[0], &[])
+    /// }
+    /// ```
+    pub fn measure(
+        mut self,
+        qubit: usize,
+        angle: f64,
+        x_signals: &[usize],
+        z_signals: &[usize],
+    ) -> Self {
+        assert!(
+            qubit < self.graph_state.num_qubits,
+            "Invalid pattern qubit index"
+        );
+        assert!(
+            !self.outputs.contains(&qubit) && !self.is_measured(qubit),
+            "Pattern qubit can not be measured"
+        );
+        assert!(
+            x_signals
+                .iter()
+                .chain(z_signals)
+                .all(|&signal| self.is_measured(signal)),
+            "Pattern signals must be measured first"
+        );
+
+        self.measurements.push(PatternMeasurement {
+            qubit,
+            angle,
+            x_signals: x_signals.to_vec(),
+            z_signals: z_signals.to_vec(),
+        });
+        self
+    }
+
+    /// [`MeasurementPattern::correct`] will add a [`PatternCorrection`] of the output [`Qubit`] at
+    /// index `qubit` to the end of the [`MeasurementPattern`], then return the modified
+    /// [`MeasurementPattern`].
+    ///
+    /// # Example
+    /// [`MeasurementPattern::correct`] can be used to remove the byproduct of a measurement:
+    /// ```rust
+    /// use rquant::{
+    ///     mbqc::types::measurement_pattern::MeasurementPattern,
+    ///     quantum::types::{graph_state::GraphState, pauli::Pauli},
+    /// };
+    ///
+    /// fn create_rotation_pattern(alpha: f64) -> MeasurementPattern {
+    ///     MeasurementPattern::new(GraphState::new(2, &[(0, 1)]), &[0], &[1])
+    ///         .measure(0, -alpha, &[], &[])
+    ///         .correct(1, Pauli::X, &[0])
+    /// }
+    /// ```
+    pub fn correct(mut self, qubit: usize, pauli: Pauli, signals: &[usize]) -> Self {
+        assert!(
+            self.outputs.contains(&qubit),
+            "Pattern corrections must target outputs"
+        );
+        assert!(
+            signals.iter().all(|&signal| self.is_measured(signal)),
+            "Pattern signals must be measured first"
+        );
+
+        self.corrections.push(PatternCorrection {
+            qubit,
+            pauli,
+            signals: signals.to_vec(),
+        });
+        self
+    }
+
+    /// [`MeasurementPattern::execute`] will run the [`MeasurementPattern`] on the [`Qubits`](Qubit)
+    /// of an `input` [`QubitRegister`], and return the [`StateVector`] of the outputs.
+    ///
+    /// Every non-input [`Qubit`] starts as $|+\rangle$, and the [`GraphState`] is entangled with
+    /// controlled phase gates before any measurement happens. Each measurement is random, but the
+    /// corrections make the returned [`StateVector`] the same for every outcome, up to a global phase.
+    ///
+    /// # Example
+    /// [`MeasurementPattern::execute`] can be used to apply the $H \cdot R_Z(\alpha)$ gate without
+    /// any gates:
+    /// ```rust
+    /// use rquant::{
+    ///     mbqc::types::measurement_pattern::MeasurementPattern,
+    ///     quantum::types::{
+    ///         graph_state::GraphState,
+    ///         pauli::Pauli,
+    ///         qubit_register::QubitRegister,
+    ///         state_vector::StateVector,
+    ///     },
+    /// };
+    ///
+    /// fn rotate_then_superposition(alpha: f64) -> StateVector {
+    ///     MeasurementPattern::new(GraphState::new(2, &[(0, 1)]), &[0], &[1])
+    ///         .measure(0, -alpha, &[], &[])
+    ///         .correct(1, Pauli::X, &[0])
+    ///         .execute(&QubitRegister::new(1))
+    /// }
+    /// ```
+    pub fn execute(&self, input: &QubitRegister) -> StateVector {
+        let num_qubits = self.graph_state.num_qubits;
+        assert!(
+            input.len() == self.inputs.len(),
+            "Pattern input must have a qubit for every input"
+        );
+        assert!(
+            (0..num_qubits).all(|qubit| self.outputs.contains(&qubit) || self.is_measured(qubit)),
+            "Pattern must measure every non-output qubit"
+        );
+
+        let qubits = (0..num_qubits)
+            .map(
+                |qubit| match self.inputs.iter().position(|&index| index == qubit) {
+                    Some(index) => input.qubits[index].clone(),
+                    None => Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION),
+                },
+            )
+            .collect();
+        let mut state_vector = StateVector::from_register(&QubitRegister { qubits });
+        for a in 0..num_qubits {
+            for b in (a + 1)..num_qubits {
+                if self.graph_state.adjacency[a][b] {
+                    state_vector.apply_cz(a, b);
+                }
+            }
+        }
+
+        // Measuring in the XY-plane is the same as rotating the basis onto Pauli-X, and then
+        // measuring in the computational basis, where an outcome of `true` is a |1> state.
+        let mut outcomes = vec![false; num_qubits];
+        for measurement in &self.measurements {
+            let mut angle = measurement.angle;
+            if signal(&outcomes, &measurement.x_signals) {
+                angle = -angle;
+            }
+            if signal(&outcomes, &measurement.z_signals) {
+                angle += PI;
+            }
+            state_vector.apply_gate(
+                &QuantumGate::new(QuantumOperator::RZ(-angle)),
+                measurement.qubit,
+            );
+            state_vector.apply_gate(&QuantumGate::SUPERPOSITION, measurement.qubit);
+            outcomes[measurement.qubit] = !state_vector.measure(measurement.qubit);
+        }
+
+        for correction in &self.corrections {
+            if let Some(operator) = correction.pauli.operator() {
+                if signal(&outcomes, &correction.signals) {
+                    state_vector.apply_gate(&QuantumGate::new(operator), correction.qubit);
+                }
+            }
+        }
+
+        // Every measured qubit is now a known basis state, so the outputs can be separated
+        // from the rest of the state vector.
+        let measured = (0..num_qubits)
+            .filter(|&qubit| outcomes[qubit])
+            .fold(0, |index, qubit| index | state_vector.mask(qubit));
+        let amplitudes = (0..1usize << self.outputs.len())
+            .map(|output_index| {
+                let index = self
+                    .outputs
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| output_index & (1 << (self.outputs.len() - 1 - bit)) != 0)
+                    .fold(measured, |index, (_, &qubit)| {
+                        index | state_vector.mask(qubit)
+                    });
+                state_vector.amplitudes[index]
+            })
+            .collect::<Vec<Complex<f64>>>();
+        StateVector {
+            num_qubits: self.outputs.len(),
+            amplitudes,
+        }
+    }
+
+    /// [`MeasurementPattern::is_measured`] will determine if the [`Qubit`] at index `qubit` has a
+    /// [`PatternMeasurement`] in the [`MeasurementPattern`].
+    fn is_measured(&self, qubit: usize) -> bool {
+        self.measurements
+            .iter()
+            .any(|measurement| measurement.qubit == qubit)
+    }
+}
+
+/// [`signal`] will get the parity of the `outcomes` of the `signals`.
+fn signal(outcomes: &[bool], signals: &[usize]) -> bool {
+    signals
+        .iter()
+        .fold(false, |parity, &signal| parity ^ outcomes[signal])
+}
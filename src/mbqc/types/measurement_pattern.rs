@@ -0,0 +1,26 @@
+use crate::{
+    mbqc::types::{pattern_correction::PatternCorrection, pattern_measurement::PatternMeasurement},
+    quantum::types::graph_state::GraphState,
+};
+
+/// [`MeasurementPattern`] is a one-way quantum computation, which entangles a cluster
+/// [`GraphState`], measures every non-output [`Qubit`](crate::quantum::types::qubit::Qubit) in an
+/// adaptive order, then corrects the outputs based on the outcomes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeasurementPattern {
+    /// The cluster [`GraphState`] the [`MeasurementPattern`] is executed on.
+    pub graph_state: GraphState,
+
+    /// The [`Qubits`](crate::quantum::types::qubit::Qubit) that are initialized with the input
+    /// state, instead of $|+\rangle$.
+    pub inputs: Vec<usize>,
+
+    /// The [`Qubits`](crate::quantum::types::qubit::Qubit) that hold the result, in order.
+    pub outputs: Vec<usize>,
+
+    /// The [`PatternMeasurements`](PatternMeasurement), in the order they are performed.
+    pub measurements: Vec<PatternMeasurement>,
+
+    /// The [`PatternCorrections`](PatternCorrection), in the order they are applied.
+    pub corrections: Vec<PatternCorrection>,
+}
@@ -0,0 +1,18 @@
+use crate::quantum::types::pauli::Pauli;
+
+/// [`PatternCorrection`] is a byproduct correction of a
+/// [`MeasurementPattern`](crate::mbqc::types::measurement_pattern::MeasurementPattern), which applies a
+/// [`Pauli`] to an output [`Qubit`](crate::quantum::types::qubit::Qubit) when the parity of the
+/// outcomes of its `signals` is odd.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternCorrection {
+    /// The index of the corrected [`Qubit`](crate::quantum::types::qubit::Qubit).
+    pub qubit: usize,
+
+    /// The [`Pauli`] that is applied as the correction.
+    pub pauli: Pauli,
+
+    /// The [`Qubits`](crate::quantum::types::qubit::Qubit) whose outcomes decide if the correction
+    /// is applied.
+    pub signals: Vec<usize>,
+}
@@ -0,0 +1,21 @@
+/// [`PatternMeasurement`] is a single measurement of a
+/// [`MeasurementPattern`](crate::mbqc::types::measurement_pattern::MeasurementPattern), in the
+/// basis $|\pm_\alpha\rangle = (|0\rangle \pm e^{i\alpha}|1\rangle)/\sqrt{2}$ of the XY-plane.
+///
+/// The angle adapts to earlier outcomes, and is measured as:
+/// $$ (-1)^{s_X}\alpha + s_Z\pi $$
+/// where $s_X$ and $s_Z$ are the parity of the outcomes of `x_signals` and `z_signals`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PatternMeasurement {
+    /// The index of the measured [`Qubit`](crate::quantum::types::qubit::Qubit).
+    pub qubit: usize,
+
+    /// The angle $\alpha$ of the measurement basis, in radians.
+    pub angle: f64,
+
+    /// The [`Qubits`](crate::quantum::types::qubit::Qubit) whose outcomes flip the sign of the angle.
+    pub x_signals: Vec<usize>,
+
+    /// The [`Qubits`](crate::quantum::types::qubit::Qubit) whose outcomes add $\pi$ to the angle.
+    pub z_signals: Vec<usize>,
+}
@@ -0,0 +1,214 @@
+use std::mem::discriminant;
+
+use rand::Rng;
+
+use crate::{
+    noise::types::{decoherence_model::DecoherenceModel, noise_channel::NoiseChannel},
+    quantum::types::{quantum_operators::QuantumOperator, qubit::Qubit, state_vector::StateVector},
+};
+
+impl DecoherenceModel {
+    /// [`DecoherenceModel::new`] will create a new [`DecoherenceModel`] with relaxation time `t1`,
+    /// dephasing time `t2`, and a `gate_duration` for every gate, all in the same unit of time.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::new`] can be used to approximate a superconducting qubit, in
+    /// microseconds:
+    /// ```rust
+    /// use rquant::noise::types::decoherence_model::DecoherenceModel;
+    ///
+    /// fn create_transmon_model() -> DecoherenceModel {
+    ///     DecoherenceModel::new(100.0, 80.0, 0.05)
+    /// }
+    /// ```
+    pub fn new(t1: f64, t2: f64, gate_duration: f64) -> Self {
+        assert!(t1 > 0.0, "T1 must be positive");
+        assert!(
+            t2 > 0.0 && t2 <= 2.0 * t1,
+            "T2 must be positive and at most twice T1"
+        );
+        assert!(gate_duration >= 0.0, "Gate duration must not be negative");
+
+        DecoherenceModel {
+            t1,
+            t2,
+            gate_duration,
+            gate_durations: vec![],
+            twirled: false,
+        }
+    }
+
+    /// [`DecoherenceModel::with_gate_duration`] will set the `duration` of every gate with the
+    /// same kind of [`QuantumOperator`], then return the modified [`DecoherenceModel`].
+    ///
+    /// # Example
+    /// [`DecoherenceModel::with_gate_duration`] can be used to make rotations slower than other
+    /// gates:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::decoherence_model::DecoherenceModel,
+    ///     quantum::types::quantum_operators::QuantumOperator,
+    /// };
+    ///
+    /// fn create_slow_rotation_model() -> DecoherenceModel {
+    ///     DecoherenceModel::new(100.0, 80.0, 0.05)
+    ///         .with_gate_duration(QuantumOperator::RX(0.0), 0.1)
+    /// }
+    /// ```
+    pub fn with_gate_duration(mut self, operator: QuantumOperator, duration: f64) -> Self {
+        assert!(duration >= 0.0, "Gate duration must not be negative");
+
+        self.gate_durations
+            .retain(|(existing, _)| discriminant(existing) != discriminant(&operator));
+        self.gate_durations.push((operator, duration));
+        self
+    }
+
+    /// [`DecoherenceModel::twirled`] will get a copy of the [`DecoherenceModel`] where every
+    /// [`NoiseChannel`] it produces is replaced by it's [`twirled`](NoiseChannel::twirled) Pauli
+    /// channel.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::twirled`] can be used to approximate relaxation with Pauli errors:
+    /// ```rust
+    /// use rquant::noise::types::decoherence_model::DecoherenceModel;
+    ///
+    /// fn create_twirled_transmon_model() -> DecoherenceModel {
+    ///     DecoherenceModel::new(100.0, 80.0, 0.05).twirled()
+    /// }
+    /// ```
+    pub fn twirled(&self) -> Self {
+        DecoherenceModel {
+            twirled: true,
+            ..self.clone()
+        }
+    }
+
+    /// [`DecoherenceModel::duration`] will get how long a gate with a [`QuantumOperator`] takes.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::duration`] can be used to get the length of a Hadamard gate:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::decoherence_model::DecoherenceModel,
+    ///     quantum::types::quantum_operators::QuantumOperator,
+    /// };
+    ///
+    /// fn hadamard_duration(model: &DecoherenceModel) -> f64 {
+    ///     model.duration(&QuantumOperator::SUPERPOSITION)
+    /// }
+    /// ```
+    pub fn duration(&self, operator: &QuantumOperator) -> f64 {
+        self.gate_durations
+            .iter()
+            .find(|(existing, _)| discriminant(existing) == discriminant(operator))
+            .map(|(_, duration)| *duration)
+            .unwrap_or(self.gate_duration)
+    }
+
+    /// [`DecoherenceModel::channels`] will get the [`NoiseChannels`](NoiseChannel) a
+    /// [`Qubit`] goes through over a `time`, which are amplitude damping with
+    /// $\gamma = 1 - e^{-t/T_1}$ and the remaining dephasing from $T_2$ as a phase flip, or their
+    /// twirled Pauli channels if the [`DecoherenceModel`] is twirled.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::channels`] can be used to inspect the noise of one microsecond:
+    /// ```rust
+    /// use rquant::noise::types::{
+    ///     decoherence_model::DecoherenceModel, noise_channel::NoiseChannel,
+    /// };
+    ///
+    /// fn one_microsecond_of_noise(model: &DecoherenceModel) -> Vec<NoiseChannel> {
+    ///     model.channels(1.0)
+    /// }
+    /// ```
+    pub fn channels(&self, time: f64) -> Vec<NoiseChannel> {
+        let damping = 1.0 - (-time / self.t1).exp();
+        // Amplitude damping already decays coherence at 1/(2T1), so only the rest is dephasing.
+        let dephasing_rate = (1.0 / self.t2 - 1.0 / (2.0 * self.t1)).max(0.0);
+        let phase_flip = (1.0 - (-time * dephasing_rate).exp()) / 2.0;
+
+        let channels = vec![
+            NoiseChannel::AmplitudeDamping(damping),
+            NoiseChannel::PhaseFlip(phase_flip),
+        ];
+        if self.twirled {
+            channels.iter().map(NoiseChannel::twirled).collect()
+        } else {
+            channels
+        }
+    }
+
+    /// [`DecoherenceModel::idle`] will let a [`Qubit`] decay for a `time`, then return the
+    /// modified [`Qubit`].
+    ///
+    /// # Example
+    /// [`DecoherenceModel::idle`] can be used to see how long $|1\rangle$ survives:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::decoherence_model::DecoherenceModel, quantum::types::qubit::Qubit,
+    /// };
+    ///
+    /// fn wait_on_one(model: &DecoherenceModel) -> bool {
+    ///     model.idle(&Qubit::one(), 50.0).measure()
+    /// }
+    /// ```
+    pub fn idle(&self, qubit: &Qubit, time: f64) -> Qubit {
+        self.channels(time)
+            .iter()
+            .fold(qubit.clone(), |qubit, channel| channel.apply(&qubit))
+    }
+
+    /// [`DecoherenceModel::idle_state_vector`] will let the
+    /// [`Qubit`] at index `target` of a [`StateVector`] decay for a `time`.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::idle_state_vector`] can be used to let half of a Bell state decay:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::decoherence_model::DecoherenceModel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn decayed_bell_state(model: &DecoherenceModel) -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     model.idle_state_vector(&mut state_vector, 1, 10.0);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn idle_state_vector(&self, state_vector: &mut StateVector, target: usize, time: f64) {
+        self.idle_state_vector_with(state_vector, target, time, &mut rand::rng());
+    }
+
+    /// [`DecoherenceModel::idle_state_vector_with`] will let the [`Qubit`] at index `target` of a
+    /// [`StateVector`] decay in the same way as [`DecoherenceModel::idle_state_vector`], using a
+    /// random number generator `rng` instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`DecoherenceModel::idle_state_vector_with`] can be used to reproduce a decayed Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     noise::types::decoherence_model::DecoherenceModel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn seeded_decayed_bell_state(model: &DecoherenceModel, seed: u64) -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     model.idle_state_vector_with(&mut state_vector, 1, 10.0, &mut rng);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn idle_state_vector_with(
+        &self,
+        state_vector: &mut StateVector,
+        target: usize,
+        time: f64,
+        rng: &mut impl Rng,
+    ) {
+        for channel in self.channels(time) {
+            channel.apply_to_state_vector_with(state_vector, target, rng);
+        }
+    }
+}
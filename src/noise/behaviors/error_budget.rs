@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::noise::types::{
+    error_budget::ErrorBudget, error_contribution::ErrorContribution, error_source::ErrorSource,
+};
+
+impl ErrorBudget {
+    /// [`ErrorBudget::largest_contributors`] will get up to an `amount` of the
+    /// [`ErrorContributions`](ErrorContribution) with the lowest fidelity, from the lowest to the
+    /// highest.
+    ///
+    /// # Example
+    /// [`ErrorBudget::largest_contributors`] can be used to find the worst step of a circuit:
+    /// ```rust
+    /// use rquant::noise::types::{
+    ///     error_budget::ErrorBudget, error_contribution::ErrorContribution,
+    /// };
+    ///
+    /// fn worst_step(budget: &ErrorBudget) -> Option<ErrorContribution> {
+    ///     budget.largest_contributors(1).first().copied()
+    /// }
+    /// ```
+    pub fn largest_contributors(&self, amount: usize) -> Vec<ErrorContribution> {
+        let mut contributions = self.contributions.clone();
+        contributions.sort_by(|first, second| first.fidelity.total_cmp(&second.fidelity));
+        contributions.truncate(amount);
+        contributions
+    }
+
+    /// [`ErrorBudget::share`] will get the fraction of the total error of the [`ErrorBudget`]
+    /// that comes from an [`ErrorContribution`], where the shares of every contribution add up to
+    /// `1.0`.
+    ///
+    /// Fidelities multiply, so the share is $\frac{\ln F_i}{\ln F}$, and returns `0.0` when the
+    /// [`ErrorBudget`] has no error.
+    ///
+    /// # Example
+    /// [`ErrorBudget::share`] can be used to check if one step dominates a circuit:
+    /// ```rust
+    /// use rquant::noise::types::error_budget::ErrorBudget;
+    ///
+    /// fn is_dominated(budget: &ErrorBudget) -> bool {
+    ///     budget
+    ///         .largest_contributors(1)
+    ///         .first()
+    ///         .is_some_and(|worst| budget.share(worst) > 0.5)
+    /// }
+    /// ```
+    pub fn share(&self, contribution: &ErrorContribution) -> f64 {
+        if self.success_probability >= 1.0 || self.success_probability <= 0.0 {
+            return 0.0;
+        }
+        contribution.fidelity.ln() / self.success_probability.ln()
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`ErrorBudget`].
+impl fmt::Display for ErrorBudget {
+    /// Gets the [`String`] representation of an [`ErrorBudget`], with the success probability
+    /// followed by every [`ErrorContribution`] from the largest to the smallest share of the
+    /// error. For instance, a circuit with a fast gate, a slow gate, and readout error will be
+    /// presented as:
+    /// ```text
+    /// success probability: 0.9605
+    /// gate 1 on qubit 0: fidelity 0.9800 (50.1% of error)
+    /// gate 0 on qubit 0: fidelity 0.9900 (24.9% of error)
+    /// readout of qubit 0: fidelity 0.9900 (24.9% of error)
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print an [`ErrorBudget`] to the console:
+    /// ```rust
+    /// use rquant::noise::types::error_budget::ErrorBudget;
+    ///
+    /// fn print_budget(budget: ErrorBudget) {
+    ///     println!("{budget}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "success probability: {:.4}", self.success_probability)?;
+        for contribution in self.largest_contributors(self.contributions.len()) {
+            let target = contribution.target;
+            let step = match contribution.source {
+                ErrorSource::Gate(index) => format!("gate {index} on qubit {target}"),
+                ErrorSource::Idle => format!("idle of qubit {target}"),
+                ErrorSource::Measurement => format!("measurement of qubit {target}"),
+                ErrorSource::Readout => format!("readout of qubit {target}"),
+            };
+            write!(
+                f,
+                "\n{step}: fidelity {:.4} ({:.1}% of error)",
+                contribution.fidelity,
+                self.share(&contribution) * 100.0
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,388 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    noise::types::kraus_channel::KrausChannel,
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{
+            density_matrix::DensityMatrix, quantum_position::QuantumPosition, qubit::Qubit,
+            state_vector::StateVector,
+        },
+    },
+    simulation::behaviors::simulation_outcome::sample_index,
+};
+
+impl KrausChannel {
+    /// [`KrausChannel::new`] will create a new [`KrausChannel`] from a set of Kraus `operators`,
+    /// which must all be square matrices of the same power of two size, and satisfy
+    /// $\sum_k K_k^\dagger K_k = I$.
+    ///
+    /// # Example
+    /// [`KrausChannel::new`] can be used to define a channel that resets a
+    /// [`Qubit`] to $|0\rangle$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::noise::types::kraus_channel::KrausChannel;
+    ///
+    /// fn create_reset_channel() -> KrausChannel {
+    ///     let (zero, one) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+    ///     KrausChannel::new(vec![
+    ///         vec![vec![one, zero], vec![zero, zero]],
+    ///         vec![vec![zero, one], vec![zero, zero]],
+    ///     ])
+    /// }
+    /// ```
+    pub fn new(operators: Vec<Vec<Vec<Complex<f64>>>>) -> Self {
+        assert!(!operators.is_empty(), "Kraus channel must have an operator");
+        let dimension = operators[0].len();
+        assert!(
+            dimension.is_power_of_two()
+                && operators.iter().all(|operator| {
+                    operator.len() == dimension && operator.iter().all(|row| row.len() == dimension)
+                }),
+            "Kraus operators must be square matrices of the same power of two size"
+        );
+
+        let kraus_channel = KrausChannel { operators };
+        assert!(
+            kraus_channel.is_complete(1e-9),
+            "Kraus operators must satisfy completeness"
+        );
+        kraus_channel
+    }
+
+    /// [`KrausChannel::is_complete`] returns `true` if every element of $\sum_k K_k^\dagger K_k$ is
+    /// within a `tolerance` of the identity, which means the [`KrausChannel`] preserves
+    /// probability, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`KrausChannel::is_complete`] can be used to check a channel built from measured data:
+    /// ```rust
+    /// use rquant::noise::types::kraus_channel::KrausChannel;
+    ///
+    /// fn is_physical(kraus_channel: &KrausChannel) -> bool {
+    ///     kraus_channel.is_complete(1e-6)
+    /// }
+    /// ```
+    pub fn is_complete(&self, tolerance: f64) -> bool {
+        let dimension = self.operators[0].len();
+        (0..dimension).all(|row| {
+            (0..dimension).all(|column| {
+                let sum: Complex<f64> = self
+                    .operators
+                    .iter()
+                    .map(|operator| {
+                        (0..dimension)
+                            .map(|k| operator[k][row].conj() * operator[k][column])
+                            .sum::<Complex<f64>>()
+                    })
+                    .sum();
+                let identity = if row == column { 1.0 } else { 0.0 };
+                (sum - Complex::new(identity, 0.0)).norm() <= tolerance
+            })
+        })
+    }
+
+    /// [`KrausChannel::num_qubits`] returns the amount of
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) the [`KrausChannel`] acts on.
+    ///
+    /// # Example
+    /// [`KrausChannel::num_qubits`] can be used to check if a channel is single-qubit:
+    /// ```rust
+    /// use rquant::noise::types::kraus_channel::KrausChannel;
+    ///
+    /// fn is_single_qubit(kraus_channel: &KrausChannel) -> bool {
+    ///     kraus_channel.num_qubits() == 1
+    /// }
+    /// ```
+    pub fn num_qubits(&self) -> usize {
+        self.operators[0].len().trailing_zeros() as usize
+    }
+
+    /// [`KrausChannel::process_fidelity`] will get how close a [`KrausChannel`] is to doing
+    /// nothing, from the trace of each of it's operators:
+    /// $$ F = \frac{1}{d^2} \sum_k |\mathrm{Tr}(K_k)|^2 $$
+    ///
+    /// For a Pauli channel this is exactly the probability that no error happens.
+    ///
+    /// # Example
+    /// [`KrausChannel::process_fidelity`] can be used to compare custom channels:
+    /// ```rust
+    /// use rquant::noise::types::kraus_channel::KrausChannel;
+    ///
+    /// fn is_better(first: &KrausChannel, second: &KrausChannel) -> bool {
+    ///     first.process_fidelity() > second.process_fidelity()
+    /// }
+    /// ```
+    pub fn process_fidelity(&self) -> f64 {
+        let dimension = self.operators[0].len();
+        let sum: f64 = self
+            .operators
+            .iter()
+            .map(|operator| {
+                (0..dimension)
+                    .map(|index| operator[index][index])
+                    .sum::<Complex<f64>>()
+                    .norm_sqr()
+            })
+            .sum();
+        sum / (dimension * dimension) as f64
+    }
+
+    /// [`KrausChannel::compose`] will get the [`KrausChannel`] that applies the [`KrausChannel`]
+    /// first and the `other` [`KrausChannel`] after it, on the same
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), which has a Kraus operator $B_j A_i$ for
+    /// every pair of operators.
+    ///
+    /// # Example
+    /// [`KrausChannel::compose`] can be used to dephase a qubit after it relaxes:
+    /// ```rust
+    /// use rquant::noise::types::{kraus_channel::KrausChannel, noise_channel::NoiseChannel};
+    ///
+    /// fn create_relax_then_dephase_channel() -> KrausChannel {
+    ///     NoiseChannel::AmplitudeDamping(0.1)
+    ///         .kraus_channel()
+    ///         .compose(&NoiseChannel::PhaseFlip(0.05).kraus_channel())
+    /// }
+    /// ```
+    pub fn compose(&self, other: &KrausChannel) -> Self {
+        assert!(
+            self.num_qubits() == other.num_qubits(),
+            "Composed Kraus channels must act on the same amount of qubits"
+        );
+
+        KrausChannel {
+            operators: other
+                .operators
+                .iter()
+                .flat_map(|second| {
+                    self.operators
+                        .iter()
+                        .map(move |first| matrix_multiply(second, first))
+                })
+                .collect(),
+        }
+    }
+
+    /// [`KrausChannel::tensor`] will get the [`KrausChannel`] that applies the [`KrausChannel`] to
+    /// the first [`Qubits`](crate::quantum::types::qubit::Qubit) and the `other` [`KrausChannel`]
+    /// to the qubits after them at the same time, which has a Kraus operator $A_i \otimes B_j$ for
+    /// every pair of operators.
+    ///
+    /// # Example
+    /// [`KrausChannel::tensor`] can be used to depolarize two qubits independently:
+    /// ```rust
+    /// use rquant::noise::types::{kraus_channel::KrausChannel, noise_channel::NoiseChannel};
+    ///
+    /// fn create_two_qubit_depolarizing_channel() -> KrausChannel {
+    ///     let depolarizing = NoiseChannel::Depolarizing(0.01).kraus_channel();
+    ///     depolarizing.tensor(&depolarizing)
+    /// }
+    /// ```
+    pub fn tensor(&self, other: &KrausChannel) -> Self {
+        let other_dimension = other.operators[0].len();
+        KrausChannel {
+            operators: self
+                .operators
+                .iter()
+                .flat_map(|first| {
+                    other.operators.iter().map(move |second| {
+                        let dimension = first.len() * other_dimension;
+                        (0..dimension)
+                            .map(|row| {
+                                (0..dimension)
+                                    .map(|column| {
+                                        first[row / other_dimension][column / other_dimension]
+                                            * second[row % other_dimension]
+                                                [column % other_dimension]
+                                    })
+                                    .collect()
+                            })
+                            .collect()
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// [`KrausChannel::apply`] will apply a single-qubit [`KrausChannel`] to a [`Qubit`], by
+    /// randomly picking one of it's operators with the probability of that outcome, then return
+    /// the modified [`Qubit`].
+    ///
+    /// # Example
+    /// [`KrausChannel::apply`] can be used to add a custom error to a [`Qubit`]:
+    /// ```rust
+    /// use rquant::{noise::types::kraus_channel::KrausChannel, quantum::types::qubit::Qubit};
+    ///
+    /// fn apply_custom_error(kraus_channel: &KrausChannel, qubit: &Qubit) -> Qubit {
+    ///     kraus_channel.apply(qubit)
+    /// }
+    /// ```
+    pub fn apply(&self, qubit: &Qubit) -> Qubit {
+        let mut state_vector = StateVector {
+            num_qubits: 1,
+            amplitudes: vec![qubit.initial_position(), qubit.possible_position()],
+        };
+        self.apply_to_state_vector(&mut state_vector, &[0]);
+
+        Qubit::new(QuantumPosition::new(
+            state_vector.amplitudes[0],
+            state_vector.amplitudes[1],
+        ))
+    }
+
+    /// [`KrausChannel::apply_to_state_vector`] will apply a [`KrausChannel`] to the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of `targets` in a
+    /// [`StateVector`], by randomly picking one of it's operators with the probability of that
+    /// outcome.
+    ///
+    /// Averaged over many runs, this matches [`KrausChannel::apply_to_density_matrix`].
+    ///
+    /// # Example
+    /// [`KrausChannel::apply_to_state_vector`] can be used to add a custom error to one half of a
+    /// Bell state:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::kraus_channel::KrausChannel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn noisy_bell_state(kraus_channel: &KrausChannel) -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     kraus_channel.apply_to_state_vector(&mut state_vector, &[1]);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_to_state_vector(&self, state_vector: &mut StateVector, targets: &[usize]) {
+        self.apply_to_state_vector_with(state_vector, targets, &mut rand::rng());
+    }
+
+    /// [`KrausChannel::apply_to_state_vector_with`] will apply a [`KrausChannel`] in the same way
+    /// as [`KrausChannel::apply_to_state_vector`], using a random number generator `rng` to pick
+    /// the operator instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`KrausChannel::apply_to_state_vector_with`] can be used to reproduce a noisy Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     noise::types::kraus_channel::KrausChannel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn seeded_noisy_bell_state(kraus_channel: &KrausChannel, seed: u64) -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     kraus_channel.apply_to_state_vector_with(&mut state_vector, &[1], &mut rng);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_to_state_vector_with(
+        &self,
+        state_vector: &mut StateVector,
+        targets: &[usize],
+        rng: &mut impl Rng,
+    ) {
+        // Build each candidate directly, so an entangled state is not flagged as a clone.
+        let candidates: Vec<Vec<Complex<f64>>> = self
+            .operators
+            .iter()
+            .map(|operator| {
+                let mut candidate = StateVector {
+                    num_qubits: state_vector.num_qubits,
+                    amplitudes: state_vector.amplitudes.clone(),
+                };
+                candidate.apply_unitary(operator, targets, &[]);
+                candidate.amplitudes
+            })
+            .collect();
+        let probabilities: Vec<f64> = candidates
+            .iter()
+            .map(|amplitudes| {
+                amplitudes
+                    .iter()
+                    .map(|amplitude| amplitude.norm_sqr())
+                    .sum()
+            })
+            .collect();
+
+        let chosen = sample_index(&probabilities, rng.random());
+        let norm = probabilities[chosen].sqrt();
+        state_vector.amplitudes = candidates[chosen]
+            .iter()
+            .map(|amplitude| amplitude / norm)
+            .collect();
+    }
+
+    /// [`KrausChannel::apply_to_density_matrix`] will get the [`DensityMatrix`] after applying a
+    /// [`KrausChannel`] to the [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of
+    /// `targets`:
+    /// $$ \rho \mapsto \sum_k K_k \rho K_k^\dagger $$
+    ///
+    /// # Example
+    /// [`KrausChannel::apply_to_density_matrix`] can be used to get the exact effect of noise:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::kraus_channel::KrausChannel,
+    ///     quantum::types::{density_matrix::DensityMatrix, qubit::Qubit},
+    /// };
+    ///
+    /// fn noisy_zero(kraus_channel: &KrausChannel) -> DensityMatrix {
+    ///     kraus_channel.apply_to_density_matrix(&DensityMatrix::from_qubit(&Qubit::zero()), &[0])
+    /// }
+    /// ```
+    pub fn apply_to_density_matrix(
+        &self,
+        density_matrix: &DensityMatrix,
+        targets: &[usize],
+    ) -> DensityMatrix {
+        let dimension = density_matrix.elements.len();
+        let mut elements = vec![vec![Complex::new(0.0, 0.0); dimension]; dimension];
+        for operator in &self.operators {
+            // K ρ K† is (K (K ρ)†)†, so both products apply K to the columns of a matrix.
+            let left = multiply_columns(operator, &density_matrix.elements, targets);
+            let both = multiply_columns(operator, &adjoint(&left), targets);
+            for (row, values) in adjoint(&both).iter().enumerate() {
+                for (column, value) in values.iter().enumerate() {
+                    elements[row][column] += value;
+                }
+            }
+        }
+
+        DensityMatrix {
+            num_qubits: density_matrix.num_qubits,
+            elements,
+        }
+    }
+}
+
+/// [`multiply_columns`] will apply an `operator` on the qubits at the indices of `targets` to
+/// every column of a square `matrix`.
+fn multiply_columns(
+    operator: &[Vec<Complex<f64>>],
+    matrix: &[Vec<Complex<f64>>],
+    targets: &[usize],
+) -> Vec<Vec<Complex<f64>>> {
+    let dimension = matrix.len();
+    let columns: Vec<Vec<Complex<f64>>> = (0..dimension)
+        .map(|column| {
+            let mut state_vector = StateVector {
+                num_qubits: dimension.trailing_zeros() as usize,
+                amplitudes: matrix.iter().map(|row| row[column]).collect(),
+            };
+            state_vector.apply_unitary(operator, targets, &[]);
+            state_vector.amplitudes
+        })
+        .collect();
+    (0..dimension)
+        .map(|row| columns.iter().map(|column| column[row]).collect())
+        .collect()
+}
+
+/// [`adjoint`] will get the conjugate transpose of a square `matrix`.
+fn adjoint(matrix: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    (0..matrix.len())
+        .map(|row| matrix.iter().map(|values| values[row].conj()).collect())
+        .collect()
+}
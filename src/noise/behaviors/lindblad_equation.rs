@@ -0,0 +1,159 @@
+use num_complex::Complex;
+
+use crate::{
+    noise::types::lindblad_equation::LindbladEquation,
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{density_matrix::DensityMatrix, hamiltonian::Hamiltonian},
+    },
+};
+
+impl LindbladEquation {
+    /// [`LindbladEquation::new`] will create a new [`LindbladEquation`] from a [`Hamiltonian`] and
+    /// the `collapse_operators` of it's environment, which must all be square matrices with one
+    /// row for every basis state of the [`Hamiltonian`].
+    ///
+    /// # Example
+    /// [`LindbladEquation::new`] can be used to describe a qubit that relaxes with a rate $\gamma$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::{
+    ///     noise::types::lindblad_equation::LindbladEquation,
+    ///     quantum::types::hamiltonian::Hamiltonian,
+    /// };
+    ///
+    /// fn create_relaxation(rate: f64) -> LindbladEquation {
+    ///     let (zero, decay) = (Complex::new(0.0, 0.0), Complex::new(rate.sqrt(), 0.0));
+    ///     LindbladEquation::new(
+    ///         Hamiltonian::new(1, &[]),
+    ///         vec![vec![vec![zero, decay], vec![zero, zero]]],
+    ///     )
+    /// }
+    /// ```
+    pub fn new(hamiltonian: Hamiltonian, collapse_operators: Vec<Vec<Vec<Complex<f64>>>>) -> Self {
+        let dimension = 1 << hamiltonian.num_qubits;
+        assert!(
+            collapse_operators.iter().all(|operator| {
+                operator.len() == dimension && operator.iter().all(|row| row.len() == dimension)
+            }),
+            "Collapse operators must act on every hamiltonian qubit"
+        );
+
+        LindbladEquation {
+            hamiltonian,
+            collapse_operators,
+        }
+    }
+
+    /// [`LindbladEquation::derivative`] will get $\frac{d\rho}{dt}$ of a [`DensityMatrix`] under the
+    /// [`LindbladEquation`].
+    ///
+    /// # Example
+    /// [`LindbladEquation::derivative`] can be used to check if a state is stationary:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::lindblad_equation::LindbladEquation,
+    ///     quantum::types::density_matrix::DensityMatrix,
+    /// };
+    ///
+    /// fn is_stationary(equation: &LindbladEquation, density_matrix: &DensityMatrix) -> bool {
+    ///     equation
+    ///         .derivative(density_matrix)
+    ///         .iter()
+    ///         .flatten()
+    ///         .all(|element| element.norm() < 1e-9)
+    /// }
+    /// ```
+    pub fn derivative(&self, density_matrix: &DensityMatrix) -> Vec<Vec<Complex<f64>>> {
+        assert!(
+            density_matrix.num_qubits == self.hamiltonian.num_qubits,
+            "Density matrix and hamiltonian must have the same amount of qubits"
+        );
+
+        let rho = &density_matrix.elements;
+        let hamiltonian = self.hamiltonian.matrix();
+        let commutator = subtract(
+            &matrix_multiply(&hamiltonian, rho),
+            &matrix_multiply(rho, &hamiltonian),
+        );
+        let mut derivative = scale(&commutator, Complex::new(0.0, -1.0));
+        for operator in &self.collapse_operators {
+            let operator_adjoint = adjoint(operator);
+            let decay = matrix_multiply(&operator_adjoint, operator);
+            let jump = matrix_multiply(&matrix_multiply(operator, rho), &operator_adjoint);
+            let anticommutator = add(&matrix_multiply(&decay, rho), &matrix_multiply(rho, &decay));
+            derivative = add(
+                &derivative,
+                &subtract(&jump, &scale(&anticommutator, Complex::new(0.5, 0.0))),
+            );
+        }
+        derivative
+    }
+
+    /// [`LindbladEquation::evolve`] will integrate the [`LindbladEquation`] for a `time`, starting
+    /// from a [`DensityMatrix`], with `steps` fourth order Runge-Kutta steps, then return the
+    /// evolved [`DensityMatrix`].
+    ///
+    /// # Example
+    /// [`LindbladEquation::evolve`] can be used to relax an excited qubit:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::lindblad_equation::LindbladEquation,
+    ///     quantum::types::{density_matrix::DensityMatrix, qubit::Qubit},
+    /// };
+    ///
+    /// fn relax(equation: &LindbladEquation, time: f64) -> DensityMatrix {
+    ///     equation.evolve(&DensityMatrix::from_qubit(&Qubit::one()), time, 1000)
+    /// }
+    /// ```
+    pub fn evolve(&self, density_matrix: &DensityMatrix, time: f64, steps: usize) -> DensityMatrix {
+        assert!(steps > 0, "Lindblad evolution needs at least one step");
+
+        let step = time / steps as f64;
+        let at = |elements: &[Vec<Complex<f64>>]| DensityMatrix {
+            num_qubits: density_matrix.num_qubits,
+            elements: elements.to_vec(),
+        };
+        let mut rho = density_matrix.elements.clone();
+        for _ in 0..steps {
+            let k1 = self.derivative(&at(&rho));
+            let k2 = self.derivative(&at(&add(&rho, &scale(&k1, Complex::new(step / 2.0, 0.0)))));
+            let k3 = self.derivative(&at(&add(&rho, &scale(&k2, Complex::new(step / 2.0, 0.0)))));
+            let k4 = self.derivative(&at(&add(&rho, &scale(&k3, Complex::new(step, 0.0)))));
+            let slope = add(
+                &add(&k1, &scale(&k2, Complex::new(2.0, 0.0))),
+                &add(&scale(&k3, Complex::new(2.0, 0.0)), &k4),
+            );
+            rho = add(&rho, &scale(&slope, Complex::new(step / 6.0, 0.0)));
+        }
+        at(&rho)
+    }
+}
+
+/// [`add`] will add two square matrices.
+fn add(a: &[Vec<Complex<f64>>], b: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    a.iter()
+        .zip(b)
+        .map(|(a_row, b_row)| a_row.iter().zip(b_row).map(|(x, y)| x + y).collect())
+        .collect()
+}
+
+/// [`subtract`] will subtract two square matrices.
+fn subtract(a: &[Vec<Complex<f64>>], b: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    add(a, &scale(b, Complex::new(-1.0, 0.0)))
+}
+
+/// [`scale`] will multiply every element of a square matrix by a `factor`.
+fn scale(matrix: &[Vec<Complex<f64>>], factor: Complex<f64>) -> Vec<Vec<Complex<f64>>> {
+    matrix
+        .iter()
+        .map(|row| row.iter().map(|element| element * factor).collect())
+        .collect()
+}
+
+/// [`adjoint`] will get the conjugate transpose of a square `matrix`.
+fn adjoint(matrix: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    (0..matrix.len())
+        .map(|row| matrix.iter().map(|values| values[row].conj()).collect())
+        .collect()
+}
@@ -0,0 +1,251 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    noise::types::{kraus_channel::KrausChannel, noise_channel::NoiseChannel},
+    quantum::types::{
+        pauli::Pauli, quantum_gate::QuantumGate, quantum_operators::QuantumOperator, qubit::Qubit,
+        state_vector::StateVector,
+    },
+};
+
+impl NoiseChannel {
+    /// [`NoiseChannel::probability`] will get the probability of the error of a [`NoiseChannel`],
+    /// where an [`OverRotation`](NoiseChannel::OverRotation) by $\epsilon$ flips $|0\rangle$ with a
+    /// probability of $\sin^2(\epsilon / 2)$.
+    ///
+    /// # Example
+    /// [`NoiseChannel::probability`] can be used to compare the strength of two channels:
+    /// ```rust
+    /// use rquant::noise::types::noise_channel::NoiseChannel;
+    ///
+    /// fn is_stronger(first: NoiseChannel, second: NoiseChannel) -> bool {
+    ///     first.probability() > second.probability()
+    /// }
+    /// ```
+    pub fn probability(&self) -> f64 {
+        match self {
+            NoiseChannel::BitFlip(probability)
+            | NoiseChannel::PhaseFlip(probability)
+            | NoiseChannel::Depolarizing(probability)
+            | NoiseChannel::AmplitudeDamping(probability) => *probability,
+            NoiseChannel::Pauli(x, y, z) => x + y + z,
+            NoiseChannel::OverRotation(angle) => (angle / 2.0).sin().powi(2),
+        }
+    }
+
+    /// [`NoiseChannel::kraus_operators`] will get the Kraus operators $K_k$ of a [`NoiseChannel`],
+    /// which describe it's effect on a density matrix as:
+    /// $$ \rho \mapsto \sum_k K_k \rho K_k^\dagger $$
+    ///
+    /// # Example
+    /// [`NoiseChannel::kraus_operators`] can be used to count the outcomes of a channel:
+    /// ```rust
+    /// use rquant::noise::types::noise_channel::NoiseChannel;
+    ///
+    /// fn depolarizing_outcomes() -> usize {
+    ///     NoiseChannel::Depolarizing(0.1).kraus_operators().len()
+    /// }
+    /// ```
+    pub fn kraus_operators(&self) -> Vec<Vec<Vec<Complex<f64>>>> {
+        let probability = self.probability();
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "Noise probability must be between 0 and 1"
+        );
+        if let NoiseChannel::Pauli(x, y, z) = self {
+            assert!(
+                [x, y, z].iter().all(|&&probability| probability >= 0.0),
+                "Noise probability must be between 0 and 1"
+            );
+        }
+
+        let zero = Complex::new(0.0, 0.0);
+        let one = Complex::new(1.0, 0.0);
+        let i = Complex::new(0.0, 1.0);
+        let identity = vec![vec![one, zero], vec![zero, one]];
+        let x = vec![vec![zero, one], vec![one, zero]];
+        let y = vec![vec![zero, -i], vec![i, zero]];
+        let z = vec![vec![one, zero], vec![zero, -one]];
+        let scale = |matrix: Vec<Vec<Complex<f64>>>, weight: f64| -> Vec<Vec<Complex<f64>>> {
+            matrix
+                .into_iter()
+                .map(|row| row.into_iter().map(|entry| entry * weight.sqrt()).collect())
+                .collect()
+        };
+
+        match self {
+            NoiseChannel::BitFlip(_) => {
+                vec![scale(identity, 1.0 - probability), scale(x, probability)]
+            }
+            NoiseChannel::PhaseFlip(_) => {
+                vec![scale(identity, 1.0 - probability), scale(z, probability)]
+            }
+            NoiseChannel::Depolarizing(_) => vec![
+                scale(identity, 1.0 - 3.0 * probability / 4.0),
+                scale(x, probability / 4.0),
+                scale(y, probability / 4.0),
+                scale(z, probability / 4.0),
+            ],
+            NoiseChannel::AmplitudeDamping(_) => vec![
+                vec![
+                    vec![one, zero],
+                    vec![zero, Complex::new((1.0 - probability).sqrt(), 0.0)],
+                ],
+                vec![
+                    vec![zero, Complex::new(probability.sqrt(), 0.0)],
+                    vec![zero, zero],
+                ],
+            ],
+            NoiseChannel::Pauli(px, py, pz) => vec![
+                scale(identity, 1.0 - probability),
+                scale(x, *px),
+                scale(y, *py),
+                scale(z, *pz),
+            ],
+            NoiseChannel::OverRotation(angle) => {
+                vec![QuantumGate::new(QuantumOperator::RX(*angle)).matrix()]
+            }
+        }
+    }
+
+    /// [`NoiseChannel::twirled`] will get the [`NoiseChannel::Pauli`] that a [`NoiseChannel`] becomes
+    /// when it is conjugated by a uniformly random Pauli, where each Pauli $P$ happens with a
+    /// probability of:
+    /// $$ p_P = \sum_k \frac{|\text{Tr}(P^\dagger K_k)|^2}{4} $$
+    ///
+    /// Channels that only apply Paulis are already unchanged by twirling, so they are returned as is.
+    ///
+    /// # Example
+    /// [`NoiseChannel::twirled`] can be used to turn a coherent error into a stochastic one:
+    /// ```rust
+    /// use rquant::noise::types::noise_channel::NoiseChannel;
+    ///
+    /// fn stochastic_over_rotation() -> NoiseChannel {
+    ///     NoiseChannel::OverRotation(0.1).twirled()
+    /// }
+    /// ```
+    pub fn twirled(&self) -> NoiseChannel {
+        match self {
+            NoiseChannel::BitFlip(_)
+            | NoiseChannel::PhaseFlip(_)
+            | NoiseChannel::Depolarizing(_)
+            | NoiseChannel::Pauli(..) => *self,
+            NoiseChannel::AmplitudeDamping(_) | NoiseChannel::OverRotation(_) => {
+                let operators = self.kraus_operators();
+                let [x, y, z] = [Pauli::X, Pauli::Y, Pauli::Z].map(|pauli| {
+                    let pauli_matrix = pauli.operator().map(QuantumGate::new).unwrap().matrix();
+                    operators
+                        .iter()
+                        .map(|operator| {
+                            let trace: Complex<f64> = pauli_matrix
+                                .iter()
+                                .flatten()
+                                .zip(operator.iter().flatten())
+                                .map(|(pauli_entry, entry)| pauli_entry.conj() * entry)
+                                .sum();
+                            trace.norm_sqr() / 4.0
+                        })
+                        .sum()
+                });
+                NoiseChannel::Pauli(x, y, z)
+            }
+        }
+    }
+
+    /// [`NoiseChannel::kraus_channel`] will get the [`KrausChannel`] of a [`NoiseChannel`], which
+    /// can be applied to a [`DensityMatrix`](crate::quantum::types::density_matrix::DensityMatrix).
+    ///
+    /// # Example
+    /// [`NoiseChannel::kraus_channel`] can be used to get the exact effect of depolarizing noise:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::{density_matrix::DensityMatrix, qubit::Qubit},
+    /// };
+    ///
+    /// fn depolarized_zero() -> DensityMatrix {
+    ///     NoiseChannel::Depolarizing(0.1)
+    ///         .kraus_channel()
+    ///         .apply_to_density_matrix(&DensityMatrix::from_qubit(&Qubit::zero()), &[0])
+    /// }
+    /// ```
+    pub fn kraus_channel(&self) -> KrausChannel {
+        KrausChannel {
+            operators: self.kraus_operators(),
+        }
+    }
+
+    /// [`NoiseChannel::apply`] will apply a [`NoiseChannel`] to a [`Qubit`], by randomly picking one
+    /// of it's [`kraus_operators`](NoiseChannel::kraus_operators) with the probability of that
+    /// outcome, then return the modified [`Qubit`].
+    ///
+    /// Averaged over many runs, this matches the effect of the [`NoiseChannel`] on a density
+    /// matrix.
+    ///
+    /// # Example
+    /// [`NoiseChannel::apply`] can be used to add noise to a [`Qubit`]:
+    /// ```rust
+    /// use rquant::{noise::types::noise_channel::NoiseChannel, quantum::types::qubit::Qubit};
+    ///
+    /// fn noisy_zero() -> Qubit {
+    ///     NoiseChannel::BitFlip(0.1).apply(&Qubit::zero())
+    /// }
+    /// ```
+    pub fn apply(&self, qubit: &Qubit) -> Qubit {
+        self.kraus_channel().apply(qubit)
+    }
+
+    /// [`NoiseChannel::apply_to_state_vector`] will apply a [`NoiseChannel`] to the
+    /// [`Qubit`] at index `target` of a [`StateVector`], by randomly picking one of it's
+    /// [`kraus_operators`](NoiseChannel::kraus_operators) with the probability of that outcome.
+    ///
+    /// # Example
+    /// [`NoiseChannel::apply_to_state_vector`] can be used to add noise to one half of a Bell
+    /// state:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn noisy_bell_state() -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     NoiseChannel::PhaseFlip(0.1).apply_to_state_vector(&mut state_vector, 1);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_to_state_vector(&self, state_vector: &mut StateVector, target: usize) {
+        self.apply_to_state_vector_with(state_vector, target, &mut rand::rng());
+    }
+
+    /// [`NoiseChannel::apply_to_state_vector_with`] will apply a [`NoiseChannel`] in the same way
+    /// as [`NoiseChannel::apply_to_state_vector`], using a random number generator `rng` to pick
+    /// the operator instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`NoiseChannel::apply_to_state_vector_with`] can be used to reproduce a noisy Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     noise::types::noise_channel::NoiseChannel,
+    ///     quantum::types::state_vector::StateVector,
+    /// };
+    ///
+    /// fn seeded_noisy_bell_state(seed: u64) -> StateVector {
+    ///     let mut state_vector = StateVector::ghz(2);
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     NoiseChannel::PhaseFlip(0.1).apply_to_state_vector_with(&mut state_vector, 1, &mut rng);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_to_state_vector_with(
+        &self,
+        state_vector: &mut StateVector,
+        target: usize,
+        rng: &mut impl Rng,
+    ) {
+        self.kraus_channel()
+            .apply_to_state_vector_with(state_vector, &[target], rng);
+    }
+}
@@ -0,0 +1,267 @@
+use rand::Rng;
+
+use crate::{
+    noise::types::{
+        decoherence_model::DecoherenceModel, noise_channel::NoiseChannel, noise_model::NoiseModel,
+        readout_error::ReadoutError,
+    },
+    quantum::types::{quantum_circuit::QuantumCircuit, state_vector::StateVector},
+};
+
+impl NoiseModel {
+    /// [`NoiseModel::new`] will create a new [`NoiseModel`] without any
+    /// [`NoiseChannels`](NoiseChannel).
+    ///
+    /// # Example
+    /// [`NoiseModel::new`] can be used to create a noiseless [`NoiseModel`]:
+    /// ```rust
+    /// use rquant::noise::types::noise_model::NoiseModel;
+    ///
+    /// fn create_noiseless_model() -> NoiseModel {
+    ///     NoiseModel::new()
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        NoiseModel {
+            gate_noise: vec![],
+            measurement_noise: vec![],
+            decoherence: None,
+            readout_error: None,
+        }
+    }
+
+    /// [`NoiseModel::with_gate_noise`] will add a [`NoiseChannel`] to the [`NoiseModel`] that is
+    /// applied to the target of every gate, after the gate, then return the modified
+    /// [`NoiseModel`].
+    ///
+    /// # Example
+    /// [`NoiseModel::with_gate_noise`] can be used to depolarize every gate:
+    /// ```rust
+    /// use rquant::noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel};
+    ///
+    /// fn create_depolarizing_model() -> NoiseModel {
+    ///     NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.01))
+    /// }
+    /// ```
+    pub fn with_gate_noise(mut self, channel: NoiseChannel) -> Self {
+        self.gate_noise.push(channel);
+        self
+    }
+
+    /// [`NoiseModel::with_measurement_noise`] will add a [`NoiseChannel`] to the [`NoiseModel`]
+    /// that is applied to every [`Qubit`](crate::quantum::types::qubit::Qubit) right before it is
+    /// measured, then return the modified [`NoiseModel`].
+    ///
+    /// # Example
+    /// [`NoiseModel::with_measurement_noise`] can be used to flip measured bits:
+    /// ```rust
+    /// use rquant::noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel};
+    ///
+    /// fn create_bit_flip_model() -> NoiseModel {
+    ///     NoiseModel::new().with_measurement_noise(NoiseChannel::BitFlip(0.02))
+    /// }
+    /// ```
+    pub fn with_measurement_noise(mut self, channel: NoiseChannel) -> Self {
+        self.measurement_noise.push(channel);
+        self
+    }
+
+    /// [`NoiseModel::with_decoherence`] will make every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) decay with a [`DecoherenceModel`] during
+    /// each of it's gates, and while it idles until the end of the [`QuantumCircuit`], then return
+    /// the modified [`NoiseModel`].
+    ///
+    /// # Example
+    /// [`NoiseModel::with_decoherence`] can be used to approximate a real device:
+    /// ```rust
+    /// use rquant::noise::types::{decoherence_model::DecoherenceModel, noise_model::NoiseModel};
+    ///
+    /// fn create_device_model() -> NoiseModel {
+    ///     NoiseModel::new().with_decoherence(DecoherenceModel::new(100.0, 80.0, 0.05))
+    /// }
+    /// ```
+    pub fn with_decoherence(mut self, decoherence: DecoherenceModel) -> Self {
+        self.decoherence = Some(decoherence);
+        self
+    }
+
+    /// [`NoiseModel::with_readout_error`] will misread every measurement with a
+    /// [`ReadoutError`], then return the modified [`NoiseModel`].
+    ///
+    /// # Example
+    /// [`NoiseModel::with_readout_error`] can be used to add readout error to a simulation:
+    /// ```rust
+    /// use rquant::noise::types::{noise_model::NoiseModel, readout_error::ReadoutError};
+    ///
+    /// fn create_readout_model() -> NoiseModel {
+    ///     NoiseModel::new().with_readout_error(ReadoutError::new(0.01, 0.03))
+    /// }
+    /// ```
+    pub fn with_readout_error(mut self, readout_error: ReadoutError) -> Self {
+        self.readout_error = Some(readout_error);
+        self
+    }
+
+    /// [`NoiseModel::twirled`] will get a copy of the [`NoiseModel`] where every gate noise,
+    /// measurement noise, and decoherence [`NoiseChannel`] is replaced by it's
+    /// [`twirled`](NoiseChannel::twirled) Pauli channel, which is the noise a circuit sees on
+    /// average when it is run with random Pauli twirling.
+    ///
+    /// # Example
+    /// [`NoiseModel::twirled`] can be used to compare coherent and stochastic errors:
+    /// ```rust
+    /// use rquant::noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel};
+    ///
+    /// fn create_twirled_over_rotation_model() -> NoiseModel {
+    ///     NoiseModel::new()
+    ///         .with_gate_noise(NoiseChannel::OverRotation(0.05))
+    ///         .twirled()
+    /// }
+    /// ```
+    pub fn twirled(&self) -> Self {
+        NoiseModel {
+            gate_noise: self.gate_noise.iter().map(NoiseChannel::twirled).collect(),
+            measurement_noise: self
+                .measurement_noise
+                .iter()
+                .map(NoiseChannel::twirled)
+                .collect(),
+            decoherence: self.decoherence.as_ref().map(DecoherenceModel::twirled),
+            readout_error: self.readout_error,
+        }
+    }
+
+    /// [`NoiseModel::read`] will misread a `measured` value with the [`ReadoutError`] of the
+    /// [`NoiseModel`], or return it as it is without one.
+    ///
+    /// # Example
+    /// [`NoiseModel::read`] can be used to read out a measurement:
+    /// ```rust
+    /// use rquant::{noise::types::noise_model::NoiseModel, quantum::types::qubit::Qubit};
+    ///
+    /// fn read_qubit(noise_model: &NoiseModel, qubit: &Qubit) -> bool {
+    ///     noise_model.read(qubit.measure())
+    /// }
+    /// ```
+    pub fn read(&self, measured: bool) -> bool {
+        self.read_with(measured, &mut rand::rng())
+    }
+
+    /// [`NoiseModel::read_with`] will misread a `measured` value in the same way as
+    /// [`NoiseModel::read`], using a random number generator `rng` instead of the thread's
+    /// generator.
+    ///
+    /// # Example
+    /// [`NoiseModel::read_with`] can be used to reproduce a read out measurement:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::noise::types::noise_model::NoiseModel;
+    ///
+    /// fn seeded_read(noise_model: &NoiseModel, seed: u64) -> bool {
+    ///     noise_model.read_with(true, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn read_with(&self, measured: bool, rng: &mut impl Rng) -> bool {
+        match &self.readout_error {
+            Some(readout_error) => readout_error.read_with(measured, rng),
+            None => measured,
+        }
+    }
+
+    /// [`NoiseModel::run`] will apply every
+    /// [`QuantumOperation`](crate::quantum::types::quantum_operation::QuantumOperation) of a
+    /// [`QuantumCircuit`] to a [`StateVector`], applying the gate noise of the [`NoiseModel`] to
+    /// every qubit of each gate after it, the decoherence over the duration of each gate and any idle
+    /// time after it, and the measurement noise to every qubit at the end.
+    ///
+    /// The noise is applied stochastically, so each run is one possible outcome of the noisy
+    /// [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`NoiseModel::run`] can be used to run one noisy shot of a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    ///     },
+    /// };
+    ///
+    /// fn run_noisy_superposition() -> StateVector {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     let mut state_vector = StateVector::new(1);
+    ///     NoiseModel::new()
+    ///         .with_gate_noise(NoiseChannel::PhaseFlip(0.1))
+    ///         .run(&circuit, &mut state_vector);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn run(&self, circuit: &QuantumCircuit, state_vector: &mut StateVector) {
+        self.run_with(circuit, state_vector, &mut rand::rng());
+    }
+
+    /// [`NoiseModel::run_with`] will run a [`QuantumCircuit`] on a [`StateVector`] in the same way
+    /// as [`NoiseModel::run`], using a random number generator `rng` for the noise instead of the
+    /// thread's generator, so a seeded `rng` always gives the same noisy shot.
+    ///
+    /// # Example
+    /// [`NoiseModel::run_with`] can be used to reproduce one noisy shot of a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    ///     },
+    /// };
+    ///
+    /// fn run_seeded_noisy_superposition(seed: u64) -> StateVector {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     let mut state_vector = StateVector::new(1);
+    ///     NoiseModel::new()
+    ///         .with_gate_noise(NoiseChannel::PhaseFlip(0.1))
+    ///         .run_with(&circuit, &mut state_vector, &mut StdRng::seed_from_u64(seed));
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn run_with(
+        &self,
+        circuit: &QuantumCircuit,
+        state_vector: &mut StateVector,
+        rng: &mut impl Rng,
+    ) {
+        assert!(
+            circuit.num_qubits == state_vector.num_qubits,
+            "Circuit must operate on every state vector qubit"
+        );
+
+        let mut elapsed = vec![0.0; state_vector.num_qubits];
+        for operation in &circuit.operations {
+            state_vector.apply_operation(operation);
+            for target in operation.qubits() {
+                for channel in &self.gate_noise {
+                    channel.apply_to_state_vector_with(state_vector, target, rng);
+                }
+                if let Some(decoherence) = &self.decoherence {
+                    let duration = decoherence.duration(&operation.operator);
+                    decoherence.idle_state_vector_with(state_vector, target, duration, rng);
+                    elapsed[target] += duration;
+                }
+            }
+        }
+        if let Some(decoherence) = &self.decoherence {
+            // Every qubit waits for the slowest qubit before it is measured.
+            let end = elapsed.iter().cloned().fold(0.0, f64::max);
+            for (target, time) in elapsed.iter().enumerate() {
+                if end > *time {
+                    decoherence.idle_state_vector_with(state_vector, target, end - time, rng);
+                }
+            }
+        }
+        for target in 0..state_vector.num_qubits {
+            for channel in &self.measurement_noise {
+                channel.apply_to_state_vector_with(state_vector, target, rng);
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use rand::Rng;
+
+use crate::{noise::types::readout_error::ReadoutError, quantum::types::qubit::Qubit};
+
+impl ReadoutError {
+    /// [`ReadoutError::new`] will create a new [`ReadoutError`] with the probability of reading
+    /// $|1\rangle$ for $|0\rangle$, `zero_to_one`, and of reading $|0\rangle$ for $|1\rangle$,
+    /// `one_to_zero`.
+    ///
+    /// # Example
+    /// [`ReadoutError::new`] can be used to model a readout that favors $|0\rangle$:
+    /// ```rust
+    /// use rquant::noise::types::readout_error::ReadoutError;
+    ///
+    /// fn create_asymmetric_readout() -> ReadoutError {
+    ///     ReadoutError::new(0.01, 0.05)
+    /// }
+    /// ```
+    pub fn new(zero_to_one: f64, one_to_zero: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&zero_to_one) && (0.0..=1.0).contains(&one_to_zero),
+            "Readout error probabilities must be between 0 and 1"
+        );
+
+        ReadoutError {
+            zero_to_one,
+            one_to_zero,
+        }
+    }
+
+    /// [`ReadoutError::read`] will randomly misread a `measured` value, where `true` is
+    /// $|0\rangle$ like [`Qubit::measure`].
+    ///
+    /// # Example
+    /// [`ReadoutError::read`] can be used to add readout error to recorded measurements:
+    /// ```rust
+    /// use rquant::noise::types::readout_error::ReadoutError;
+    ///
+    /// fn misread_all(readout_error: &ReadoutError, measurements: &[bool]) -> Vec<bool> {
+    ///     measurements.iter().map(|&measured| readout_error.read(measured)).collect()
+    /// }
+    /// ```
+    pub fn read(&self, measured: bool) -> bool {
+        self.read_with(measured, &mut rand::rng())
+    }
+
+    /// [`ReadoutError::read_with`] will misread a `measured` value in the same way as
+    /// [`ReadoutError::read`], using a random number generator `rng` instead of the thread's
+    /// generator.
+    ///
+    /// # Example
+    /// [`ReadoutError::read_with`] can be used to reproduce a misread measurement:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::noise::types::readout_error::ReadoutError;
+    ///
+    /// fn seeded_read(readout_error: &ReadoutError, seed: u64) -> bool {
+    ///     readout_error.read_with(true, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn read_with(&self, measured: bool, rng: &mut impl Rng) -> bool {
+        let flip_probability = if measured {
+            self.zero_to_one
+        } else {
+            self.one_to_zero
+        };
+        measured != rng.random_bool(flip_probability)
+    }
+
+    /// [`ReadoutError::measure`] will measure a [`Qubit`] like [`Qubit::measure`], then randomly
+    /// misread the outcome.
+    ///
+    /// # Example
+    /// [`ReadoutError::measure`] can be used to measure a [`Qubit`] on noisy hardware:
+    /// ```rust
+    /// use rquant::{noise::types::readout_error::ReadoutError, quantum::types::qubit::Qubit};
+    ///
+    /// fn noisy_measure(qubit: &Qubit) -> bool {
+    ///     ReadoutError::new(0.02, 0.02).measure(qubit)
+    /// }
+    /// ```
+    pub fn measure(&self, qubit: &Qubit) -> bool {
+        self.read(qubit.measure())
+    }
+
+    /// [`ReadoutError::confusion_matrix`] will get the probability of each read value, by row,
+    /// for each actual value, by column, where index `0` is $|0\rangle$ and index `1` is
+    /// $|1\rangle$.
+    ///
+    /// # Example
+    /// [`ReadoutError::confusion_matrix`] can be used to get the probability of a correct
+    /// $|1\rangle$ readout:
+    /// ```rust
+    /// use rquant::noise::types::readout_error::ReadoutError;
+    ///
+    /// fn one_fidelity(readout_error: &ReadoutError) -> f64 {
+    ///     readout_error.confusion_matrix()[1][1]
+    /// }
+    /// ```
+    pub fn confusion_matrix(&self) -> [[f64; 2]; 2] {
+        [
+            [1.0 - self.zero_to_one, self.one_to_zero],
+            [self.zero_to_one, 1.0 - self.one_to_zero],
+        ]
+    }
+
+    /// [`ReadoutError::mitigate`] will estimate the probabilities before readout error from
+    /// measured `probabilities` over one or many qubits, ordered like
+    /// [`SimulationOutcome::marginal_probabilities`](crate::simulation::types::simulation_outcome::SimulationOutcome::marginal_probabilities),
+    /// by inverting the [`ReadoutError::confusion_matrix`] of every qubit.
+    ///
+    /// Sampling noise can make mitigated probabilities slightly negative, so they are clipped to
+    /// zero and renormalized.
+    ///
+    /// # Example
+    /// [`ReadoutError::mitigate`] can be used to correct a sampled distribution:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::readout_error::ReadoutError,
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn mitigated_distribution(outcome: &SimulationOutcome, readout_error: &ReadoutError) -> Vec<f64> {
+    ///     readout_error.mitigate(&outcome.marginal_probabilities(&[0, 1]))
+    /// }
+    /// ```
+    pub fn mitigate(&self, probabilities: &[f64]) -> Vec<f64> {
+        assert!(
+            probabilities.len().is_power_of_two(),
+            "Mitigated probabilities must cover every basis state"
+        );
+        let [[a, b], [c, d]] = self.confusion_matrix();
+        let determinant = a * d - b * c;
+        assert!(
+            determinant.abs() > f64::EPSILON,
+            "Readout error must be invertible to mitigate"
+        );
+        let inverse = [[d, -b], [-c, a]].map(|row| row.map(|value| value / determinant));
+
+        // The inverse of every qubit is applied along it's own bit of the basis index.
+        let mut mitigated = probabilities.to_vec();
+        let mut mask = 1;
+        while mask < mitigated.len() {
+            for index in (0..mitigated.len()).filter(|index| index & mask == 0) {
+                let (zero, one) = (mitigated[index], mitigated[index | mask]);
+                mitigated[index] = inverse[0][0] * zero + inverse[0][1] * one;
+                mitigated[index | mask] = inverse[1][0] * zero + inverse[1][1] * one;
+            }
+            mask <<= 1;
+        }
+
+        let clipped: Vec<f64> = mitigated.iter().map(|value| value.max(0.0)).collect();
+        let total: f64 = clipped.iter().sum();
+        if total == 0.0 {
+            return clipped;
+        }
+        clipped.iter().map(|value| value / total).collect()
+    }
+}
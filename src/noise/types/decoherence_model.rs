@@ -0,0 +1,25 @@
+use crate::quantum::types::quantum_operators::QuantumOperator;
+
+/// [`DecoherenceModel`] is the relaxation and dephasing of real qubits over time, where a
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) decays from $|1\rangle$ to $|0\rangle$ with a
+/// time constant $T_1$ and loses it's phase with a time constant $T_2$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecoherenceModel {
+    /// The relaxation time $T_1$, after which $|1\rangle$ has a $1/e$ probability of remaining.
+    pub t1: f64,
+
+    /// The dephasing time $T_2$, after which the coherence of a superposition has decayed by
+    /// $1/e$, which can be at most $2T_1$.
+    pub t2: f64,
+
+    /// The time every gate takes, unless it has a duration in `gate_durations`.
+    pub gate_duration: f64,
+
+    /// The time specific [`QuantumOperators`](QuantumOperator) take, where rotation angles are
+    /// ignored when matching an operator.
+    pub gate_durations: Vec<(QuantumOperator, f64)>,
+
+    /// Whether the [`NoiseChannels`](crate::noise::types::noise_channel::NoiseChannel) of the
+    /// [`DecoherenceModel`] are replaced with their Pauli twirled approximations.
+    pub twirled: bool,
+}
@@ -0,0 +1,14 @@
+use crate::noise::types::error_contribution::ErrorContribution;
+
+/// [`ErrorBudget`] is an analytic estimate of how likely a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) is to run without an
+/// error on a [`NoiseModel`](crate::noise::types::noise_model::NoiseModel), and which steps
+/// are to blame when it does not.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBudget {
+    /// The product of the fidelity of every [`ErrorContribution`].
+    pub success_probability: f64,
+
+    /// Every step of the circuit that can have an error, in the order they happen.
+    pub contributions: Vec<ErrorContribution>,
+}
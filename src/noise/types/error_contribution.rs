@@ -0,0 +1,16 @@
+use crate::noise::types::error_source::ErrorSource;
+
+/// [`ErrorContribution`] is the fidelity of one step of a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) run with a
+/// [`NoiseModel`](crate::noise::types::noise_model::NoiseModel).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ErrorContribution {
+    /// Where the error comes from.
+    pub source: ErrorSource,
+
+    /// The index of the [`Qubit`](crate::quantum::types::qubit::Qubit) the error happens on.
+    pub target: usize,
+
+    /// The probability that the step happens without an error.
+    pub fidelity: f64,
+}
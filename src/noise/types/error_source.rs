@@ -0,0 +1,19 @@
+/// [`ErrorSource`] is where an [`ErrorContribution`](crate::noise::types::error_contribution::ErrorContribution)
+/// of an [`ErrorBudget`](crate::noise::types::error_budget::ErrorBudget) comes from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorSource {
+    /// The gate noise and decoherence of the operation at an index of a
+    /// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit).
+    Gate(usize),
+
+    /// The decoherence while a [`Qubit`](crate::quantum::types::qubit::Qubit) waits for the
+    /// slowest [`Qubit`](crate::quantum::types::qubit::Qubit) before it is measured.
+    Idle,
+
+    /// The measurement noise right before a [`Qubit`](crate::quantum::types::qubit::Qubit) is
+    /// measured.
+    Measurement,
+
+    /// The [`ReadoutError`](crate::noise::types::readout_error::ReadoutError) of a measurement.
+    Readout,
+}
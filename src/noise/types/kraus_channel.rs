@@ -0,0 +1,10 @@
+use num_complex::Complex;
+
+/// [`KrausChannel`] is any completely positive, trace preserving map on one or many
+/// [`Qubits`](crate::quantum::types::qubit::Qubit), described by Kraus operators $K_k$ that
+/// satisfy $\sum_k K_k^\dagger K_k = I$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KrausChannel {
+    /// The Kraus operators of the [`KrausChannel`], where each operator is a matrix by row.
+    pub operators: Vec<Vec<Vec<Complex<f64>>>>,
+}
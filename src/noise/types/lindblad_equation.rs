@@ -0,0 +1,19 @@
+use num_complex::Complex;
+
+use crate::quantum::types::hamiltonian::Hamiltonian;
+
+/// [`LindbladEquation`] is the master equation of an open quantum system, which evolves a
+/// [`DensityMatrix`](crate::quantum::types::density_matrix::DensityMatrix) with a [`Hamiltonian`]
+/// $H$ and collapse operators $L_k$ that describe how the system leaks into it's environment:
+/// $$ \frac{d\rho}{dt} = -i[H, \rho] + \sum_k \left( L_k \rho L_k^\dagger - \frac{1}{2}
+/// \{ L_k^\dagger L_k, \rho \} \right) $$
+#[derive(Clone, Debug, PartialEq)]
+pub struct LindbladEquation {
+    /// The [`Hamiltonian`] of the coherent part of the evolution.
+    pub hamiltonian: Hamiltonian,
+
+    /// The collapse operators $L_k$ of the [`LindbladEquation`], where each operator is a matrix by
+    /// row that acts on every [`Qubit`](crate::quantum::types::qubit::Qubit) of the
+    /// [`Hamiltonian`], and already includes the square root of it's rate.
+    pub collapse_operators: Vec<Vec<Vec<Complex<f64>>>>,
+}
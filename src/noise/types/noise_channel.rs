@@ -0,0 +1,25 @@
+/// [`NoiseChannel`] is a standard single-qubit error, where each variant holds the probability
+/// of the error happening, or the angle of a coherent error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NoiseChannel {
+    /// Flips $|0\rangle$ and $|1\rangle$ with a probability $p$, like an unwanted NOT gate.
+    BitFlip(f64),
+
+    /// Flips the sign of $|1\rangle$ with a probability $p$, like an unwanted PHASE gate.
+    PhaseFlip(f64),
+
+    /// Replaces the state with the maximally mixed state with a probability $p$.
+    Depolarizing(f64),
+
+    /// Decays $|1\rangle$ to $|0\rangle$ with a probability $\gamma$, like energy lost to the
+    /// environment.
+    AmplitudeDamping(f64),
+
+    /// Flips $|0\rangle$ and $|1\rangle$ with a probability $p_x$, applies an unwanted ROTATE gate
+    /// with a probability $p_y$, and flips the sign of $|1\rangle$ with a probability $p_z$.
+    Pauli(f64, f64, f64),
+
+    /// Rotates every state around the X axis by an unwanted angle $\epsilon$, like a miscalibrated
+    /// pulse, which is a coherent error rather than a probabilistic one.
+    OverRotation(f64),
+}
@@ -0,0 +1,22 @@
+use crate::noise::types::{
+    decoherence_model::DecoherenceModel, noise_channel::NoiseChannel, readout_error::ReadoutError,
+};
+
+/// [`NoiseModel`] is a collection of [`NoiseChannels`](NoiseChannel) that are applied during a
+/// simulation run.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NoiseModel {
+    /// The [`NoiseChannels`](NoiseChannel) applied to the target of every gate, after the gate.
+    pub gate_noise: Vec<NoiseChannel>,
+
+    /// The [`NoiseChannels`](NoiseChannel) applied to every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), right before it is measured.
+    pub measurement_noise: Vec<NoiseChannel>,
+
+    /// The [`DecoherenceModel`] that every [`Qubit`](crate::quantum::types::qubit::Qubit) decays
+    /// with during gates and while idle, if any.
+    pub decoherence: Option<DecoherenceModel>,
+
+    /// The [`ReadoutError`] of every measurement, if any.
+    pub readout_error: Option<ReadoutError>,
+}
@@ -0,0 +1,13 @@
+/// [`ReadoutError`] is the chance of reading the wrong value when a
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) is measured, which happens after the state has
+/// collapsed and so cannot be undone by quantum error correction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReadoutError {
+    /// The probability of reading $|1\rangle$ when the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// collapsed to $|0\rangle$.
+    pub zero_to_one: f64,
+
+    /// The probability of reading $|0\rangle$ when the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// collapsed to $|1\rangle$.
+    pub one_to_zero: f64,
+}
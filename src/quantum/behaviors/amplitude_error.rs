@@ -0,0 +1,34 @@
+use std::{error, fmt};
+
+use crate::quantum::types::amplitude_error::AmplitudeError;
+
+/// Implement the [`fmt::Display`] trait for [`AmplitudeError`].
+impl fmt::Display for AmplitudeError {
+    /// Will return a short description of why the amplitudes are invalid:
+    /// ```text
+    /// qubit amplitudes square to 1.5 instead of 1
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print an [`AmplitudeError`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_position::QuantumPosition, qubit::Qubit};
+    ///
+    /// fn print_amplitude_error(position: QuantumPosition) {
+    ///     if let Err(error) = Qubit::try_new(position) {
+    ///         println!("{error}");
+    ///     }
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "qubit amplitudes square to {} instead of 1",
+            self.sum_of_squares
+        )
+    }
+}
+
+/// Implement the [`error::Error`] trait for [`AmplitudeError`], so it can be returned with `?`
+/// alongside other errors.
+impl error::Error for AmplitudeError {}
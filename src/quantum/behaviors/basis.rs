@@ -0,0 +1,21 @@
+use std::f64::consts::FRAC_PI_2;
+
+use crate::quantum::types::{basis::Basis, quantum_operators::QuantumOperator};
+
+impl Basis {
+    /// [`Basis::single_qubit_rotation`] will get the [`QuantumOperators`](QuantumOperator) that
+    /// rotate a single-qubit [`Basis`] onto the computational basis, in the order they are
+    /// applied, and [`None`] for the two-qubit [`Basis::Bell`].
+    pub(crate) fn single_qubit_rotation(&self) -> Option<Vec<QuantumOperator>> {
+        match self {
+            Basis::Z => Some(vec![]),
+            Basis::X => Some(vec![QuantumOperator::SUPERPOSITION]),
+            // S† is a quarter turn back around Z, up to a global phase.
+            Basis::Y => Some(vec![
+                QuantumOperator::RZ(-FRAC_PI_2),
+                QuantumOperator::SUPERPOSITION,
+            ]),
+            Basis::Bell => None,
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt;
+
+use crate::quantum::types::{bloch_sphere::BlochSphere, qubit::Qubit};
+
+/// The amount of rows between the center and the edge of a drawn [`BlochSphere`].
+const RADIUS: usize = 5;
+
+/// The marker of a state on the near half of the sphere, where $y \geq 0$.
+const NEAR: char = '●';
+
+/// The marker of a state on the far half of the sphere, where $y < 0$.
+const FAR: char = '○';
+
+impl BlochSphere {
+    /// [`BlochSphere::new`] will create a new [`BlochSphere`] that shows an `[x, y, z]` Bloch
+    /// `vector`.
+    ///
+    /// # Example
+    /// [`BlochSphere::new`] can be used to draw a point of a
+    /// [`BlochTrajectory`](crate::quantum::types::bloch_trajectory::BlochTrajectory):
+    /// ```rust
+    /// use rquant::quantum::types::{bloch_sphere::BlochSphere, bloch_trajectory::BlochTrajectory};
+    ///
+    /// fn draw_first_frame(trajectory: &BlochTrajectory) -> String {
+    ///     BlochSphere::new(trajectory.frames[0]).to_string()
+    /// }
+    /// ```
+    pub fn new(vector: [f64; 3]) -> Self {
+        BlochSphere { vector }
+    }
+}
+
+impl Qubit {
+    /// [`Qubit::draw_bloch`] will get a [`BlochSphere`] of the current position of a [`Qubit`],
+    /// which can be printed to see how a gate moved it.
+    ///
+    /// # Example
+    /// [`Qubit::draw_bloch`] can be used to print a superposition on the Bloch sphere:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn print_superposition() {
+    ///     println!("{}", Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION).draw_bloch());
+    /// }
+    /// ```
+    pub fn draw_bloch(&self) -> BlochSphere {
+        let (x, y, z) = self.bloch_coordinates();
+        BlochSphere::new([x, y, z])
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`BlochSphere`].
+impl fmt::Display for BlochSphere {
+    /// Gets the [`String`] representation of a [`BlochSphere`], with the outline of the sphere,
+    /// it's x and z axes, the marker of the state, and the coordinates of the state. For instance,
+    /// [`Qubit::zero`] will be presented as:
+    /// ```text
+    ///              |0〉
+    ///          ····●····
+    ///       ····   │   ····
+    ///     ···      │      ···
+    ///    ··        │        ··
+    ///    ·         │         ·
+    /// |-〉·─────────┼─────────· |+〉
+    ///    ·         │         ·
+    ///    ··        │        ··
+    ///     ···      │      ···
+    ///       ····   │   ····
+    ///          ·········
+    ///              |1〉
+    /// x: 0.00  y: 0.00  z: 1.00  (● near, ○ far)
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`BlochSphere`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::bloch_sphere::BlochSphere;
+    ///
+    /// fn print_bloch_sphere(bloch_sphere: BlochSphere) {
+    ///     println!("{bloch_sphere}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Terminal cells are about twice as tall as they are wide, so the sphere is twice as wide.
+        let mut grid = vec![vec![' '; 4 * RADIUS + 1]; 2 * RADIUS + 1];
+        grid[RADIUS].fill('─');
+        grid.iter_mut().for_each(|row| row[2 * RADIUS] = '│');
+        grid[RADIUS][2 * RADIUS] = '┼';
+        for degree in 0..360 {
+            let (sin, cos) = (degree as f64).to_radians().sin_cos();
+            let (row, column) = cell(cos, sin);
+            grid[row][column] = '·';
+        }
+        let [x, y, z] = self.vector;
+        let (row, column) = cell(x, z);
+        grid[row][column] = if y >= 0.0 { NEAR } else { FAR };
+
+        writeln!(f, "{:pad$}|0〉", "", pad = 2 * RADIUS + 3)?;
+        for (index, row) in grid.iter().enumerate() {
+            let line: String = row.iter().collect();
+            if index == RADIUS {
+                writeln!(f, "|-〉{line} |+〉")?;
+            } else {
+                writeln!(f, "   {}", line.trim_end())?;
+            }
+        }
+        writeln!(f, "{:pad$}|1〉", "", pad = 2 * RADIUS + 3)?;
+        write!(
+            f,
+            "x: {x:.2}  y: {y:.2}  z: {z:.2}  ({NEAR} near, {FAR} far)"
+        )
+    }
+}
+
+/// [`cell`] will get the row and column of the grid that a point of the sphere is drawn in, from
+/// it's `horizontal` x and `vertical` z coordinates.
+fn cell(horizontal: f64, vertical: f64) -> (usize, usize) {
+    let row = (RADIUS as f64 * (1.0 - vertical.clamp(-1.0, 1.0))).round() as usize;
+    let column = (2.0 * RADIUS as f64 * (1.0 + horizontal.clamp(-1.0, 1.0))).round() as usize;
+    (row, column)
+}
@@ -0,0 +1,51 @@
+use crate::quantum::types::bloch_trajectory::BlochTrajectory;
+
+impl BlochTrajectory {
+    /// [`BlochTrajectory::to_json`] will export every frame of a [`BlochTrajectory`] as a JSON
+    /// array of objects with `frame`, `x`, `y`, and `z` keys.
+    ///
+    /// # Example
+    /// [`BlochTrajectory::to_json`] can be used to export the trajectory of a
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) for an animation tool:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, qubit::Qubit};
+    ///
+    /// fn export_qubit_trajectory(qubit: &Qubit, gates: &[QuantumGate]) -> String {
+    ///     qubit.bloch_trajectory(gates).to_json()
+    /// }
+    /// ```
+    pub fn to_json(&self) -> String {
+        let frames: Vec<String> = self
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(frame, [x, y, z])| {
+                format!("{{\"frame\":{frame},\"x\":{x},\"y\":{y},\"z\":{z}}}")
+            })
+            .collect();
+        format!("[{}]", frames.join(","))
+    }
+
+    /// [`BlochTrajectory::to_csv`] will export every frame of a [`BlochTrajectory`] as CSV
+    /// with a `frame,x,y,z` header.
+    ///
+    /// # Example
+    /// [`BlochTrajectory::to_csv`] can be used to export the trajectory of a
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) for a spreadsheet:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, qubit::Qubit};
+    ///
+    /// fn export_qubit_trajectory(qubit: &Qubit, gates: &[QuantumGate]) -> String {
+    ///     qubit.bloch_trajectory(gates).to_csv()
+    /// }
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let rows: String = self
+            .frames
+            .iter()
+            .enumerate()
+            .map(|(frame, [x, y, z])| format!("{frame},{x},{y},{z}\n"))
+            .collect();
+        format!("frame,x,y,z\n{rows}")
+    }
+}
@@ -0,0 +1,319 @@
+use std::{f64::consts::FRAC_PI_2, fmt};
+
+use crate::quantum::types::{
+    clifford_tableau::CliffordTableau, pauli::Pauli, pauli_string::PauliString,
+    quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+};
+
+impl CliffordTableau {
+    /// [`CliffordTableau::new`] will create a new [`CliffordTableau`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), all initialized as $|0\rangle$.
+    ///
+    /// # Example
+    /// [`CliffordTableau::new`] can be used to create a new [`CliffordTableau`]:
+    /// ```rust
+    /// use rquant::quantum::types::clifford_tableau::CliffordTableau;
+    ///
+    /// fn create_clifford_tableau(amount: usize) -> CliffordTableau {
+    ///     CliffordTableau::new(amount)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        let identity = |offset: usize| -> Vec<Vec<bool>> {
+            (0..2 * num_qubits)
+                .map(|row| (0..num_qubits).map(|qubit| row == qubit + offset).collect())
+                .collect()
+        };
+        CliffordTableau {
+            num_qubits,
+            x: identity(0),
+            z: identity(num_qubits),
+            signs: vec![false; 2 * num_qubits],
+        }
+    }
+
+    /// [`CliffordTableau::apply_gate`] will apply a Clifford [`QuantumGate`] to the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target` of a [`CliffordTableau`].
+    ///
+    /// Rotation gates are only Clifford gates when their angle is a multiple of $\frac{\pi}{2}$,
+    /// so any other angle will panic.
+    ///
+    /// # Example
+    /// [`CliffordTableau::apply_gate`] can be used to put a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_first_qubit(tableau: &mut CliffordTableau) {
+    ///     tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0)
+    /// }
+    /// ```
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        assert!(target < self.num_qubits, "Invalid tableau qubit index");
+
+        match gate.operator {
+            QuantumOperator::NOT => self.for_each_row(|_, z, sign| *sign ^= z[target]),
+            QuantumOperator::ROTATE => {
+                self.for_each_row(|x, z, sign| *sign ^= x[target] ^ z[target])
+            }
+            QuantumOperator::PHASE => self.for_each_row(|x, _, sign| *sign ^= x[target]),
+            QuantumOperator::SUPERPOSITION => self.apply_superposition(target),
+            QuantumOperator::RX(angle) => {
+                for _ in 0..quarter_turns(angle) {
+                    self.apply_superposition(target);
+                    self.apply_quarter_phase(target);
+                    self.apply_superposition(target);
+                }
+            }
+            QuantumOperator::RY(angle) => {
+                for _ in 0..quarter_turns(angle) {
+                    self.apply_gate(&QuantumGate::PHASE, target);
+                    self.apply_superposition(target);
+                }
+            }
+            QuantumOperator::RZ(angle) => {
+                for _ in 0..quarter_turns(angle) {
+                    self.apply_quarter_phase(target);
+                }
+            }
+        }
+    }
+
+    /// [`CliffordTableau::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) of a [`CliffordTableau`], using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`CliffordTableau::apply_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, quantum_gate::QuantumGate};
+    ///
+    /// fn create_bell_state() -> CliffordTableau {
+    ///     let mut tableau = CliffordTableau::new(2);
+    ///     tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     tableau.apply_cnot(0, 1);
+    ///     tableau
+    /// }
+    /// ```
+    pub fn apply_cnot(&mut self, control: usize, target: usize) {
+        assert!(
+            control < self.num_qubits && target < self.num_qubits && control != target,
+            "Invalid tableau qubit index"
+        );
+
+        self.for_each_row(|x, z, sign| {
+            *sign ^= x[control] && z[target] && !(x[target] ^ z[control]);
+            x[target] ^= x[control];
+            z[control] ^= z[target];
+        });
+    }
+
+    /// [`CliffordTableau::apply_cz`] will apply a controlled phase gate between two [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// of a [`CliffordTableau`], which flips the phase of the $|11\rangle$ state.
+    ///
+    /// # Example
+    /// [`CliffordTableau::apply_cz`] can be used to entangle two superpositioned qubits:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, quantum_gate::QuantumGate};
+    ///
+    /// fn create_two_qubit_graph_state() -> CliffordTableau {
+    ///     let mut tableau = CliffordTableau::new(2);
+    ///     tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     tableau.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    ///     tableau.apply_cz(0, 1);
+    ///     tableau
+    /// }
+    /// ```
+    pub fn apply_cz(&mut self, a: usize, b: usize) {
+        self.apply_gate(&QuantumGate::SUPERPOSITION, b);
+        self.apply_cnot(a, b);
+        self.apply_gate(&QuantumGate::SUPERPOSITION, b);
+    }
+
+    /// [`CliffordTableau::stabilizers`] will get the stabilizer generators of a [`CliffordTableau`],
+    /// which are the [`PauliStrings`](PauliString) that leave the state unchanged.
+    ///
+    /// # Example
+    /// [`CliffordTableau::stabilizers`] can be used to inspect a stabilizer state:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, pauli_string::PauliString};
+    ///
+    /// fn get_stabilizers(tableau: &CliffordTableau) -> Vec<PauliString> {
+    ///     tableau.stabilizers()
+    /// }
+    /// ```
+    pub fn stabilizers(&self) -> Vec<PauliString> {
+        (self.num_qubits..2 * self.num_qubits)
+            .map(|row| self.pauli_string(row))
+            .collect()
+    }
+
+    /// [`CliffordTableau::destabilizers`] will get the destabilizer generators of a [`CliffordTableau`],
+    /// which each anticommute with exactly one of the [`CliffordTableau::stabilizers`].
+    ///
+    /// # Example
+    /// [`CliffordTableau::destabilizers`] can be used to inspect a stabilizer state:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, pauli_string::PauliString};
+    ///
+    /// fn get_destabilizers(tableau: &CliffordTableau) -> Vec<PauliString> {
+    ///     tableau.destabilizers()
+    /// }
+    /// ```
+    pub fn destabilizers(&self) -> Vec<PauliString> {
+        (0..self.num_qubits)
+            .map(|row| self.pauli_string(row))
+            .collect()
+    }
+
+    /// [`CliffordTableau::measurement_outcome`] will get the outcome of measuring the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` if it is certain, where
+    /// `true` is $|0\rangle$ like [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure),
+    /// and [`None`] if the outcome is random.
+    ///
+    /// The [`CliffordTableau`] is not changed, because a certain outcome does not disturb the
+    /// state.
+    ///
+    /// # Example
+    /// [`CliffordTableau::measurement_outcome`] can be used to check a qubit is back to
+    /// $|0\rangle$:
+    /// ```rust
+    /// use rquant::quantum::types::clifford_tableau::CliffordTableau;
+    ///
+    /// fn is_reset(tableau: &CliffordTableau, target: usize) -> bool {
+    ///     tableau.measurement_outcome(target) == Some(true)
+    /// }
+    /// ```
+    pub fn measurement_outcome(&self, target: usize) -> Option<bool> {
+        assert!(target < self.num_qubits, "Invalid tableau qubit index");
+
+        // A stabilizer that does not commute with Z on the target makes the outcome random.
+        if (self.num_qubits..2 * self.num_qubits).any(|row| self.x[row][target]) {
+            return None;
+        }
+
+        // Otherwise +Z or -Z on the target is the product of the stabilizers whose destabilizer
+        // anticommutes with it.
+        let mut x = vec![false; self.num_qubits];
+        let mut z = vec![false; self.num_qubits];
+        let mut sign = false;
+        for row in (0..self.num_qubits).filter(|&row| self.x[row][target]) {
+            let stabilizer = row + self.num_qubits;
+            let phase: i32 = (0..self.num_qubits)
+                .map(|qubit| {
+                    pauli_product_phase(
+                        self.x[stabilizer][qubit],
+                        self.z[stabilizer][qubit],
+                        x[qubit],
+                        z[qubit],
+                    )
+                })
+                .sum::<i32>()
+                + 2 * (sign as i32 + self.signs[stabilizer] as i32);
+            sign = phase.rem_euclid(4) == 2;
+            for qubit in 0..self.num_qubits {
+                x[qubit] ^= self.x[stabilizer][qubit];
+                z[qubit] ^= self.z[stabilizer][qubit];
+            }
+        }
+        Some(!sign)
+    }
+
+    /// [`CliffordTableau::apply_superposition`] will apply the SUPERPOSITION gate to the `target`.
+    fn apply_superposition(&mut self, target: usize) {
+        self.for_each_row(|x, z, sign| {
+            *sign ^= x[target] && z[target];
+            std::mem::swap(&mut x[target], &mut z[target]);
+        });
+    }
+
+    /// [`CliffordTableau::apply_quarter_phase`] will rotate the `target` a quarter turn around it's
+    /// Z-axis, which is also referred to as an "S gate".
+    fn apply_quarter_phase(&mut self, target: usize) {
+        self.for_each_row(|x, z, sign| {
+            *sign ^= x[target] && z[target];
+            z[target] ^= x[target];
+        });
+    }
+
+    /// [`CliffordTableau::for_each_row`] will call `update` with the X bits, Z bits, and sign of
+    /// every generator of the [`CliffordTableau`].
+    fn for_each_row(&mut self, mut update: impl FnMut(&mut [bool], &mut [bool], &mut bool)) {
+        for row in 0..2 * self.num_qubits {
+            update(&mut self.x[row], &mut self.z[row], &mut self.signs[row]);
+        }
+    }
+
+    /// [`CliffordTableau::pauli_string`] will get the [`PauliString`] of the generator at `row`.
+    fn pauli_string(&self, row: usize) -> PauliString {
+        PauliString {
+            negative: self.signs[row],
+            paulis: (0..self.num_qubits)
+                .map(|qubit| match (self.x[row][qubit], self.z[row][qubit]) {
+                    (false, false) => Pauli::I,
+                    (true, false) => Pauli::X,
+                    (true, true) => Pauli::Y,
+                    (false, true) => Pauli::Z,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// [`pauli_product_phase`] will get the power of $i$ that multiplying the single-qubit Pauli with
+/// the X and Z bits `x1` and `z1` by the Pauli with the bits `x2` and `z2` adds, between -1 and 1.
+fn pauli_product_phase(x1: bool, z1: bool, x2: bool, z2: bool) -> i32 {
+    let (x2, z2) = (x2 as i32, z2 as i32);
+    match (x1, z1) {
+        (false, false) => 0,
+        (true, true) => z2 - x2,
+        (true, false) => z2 * (2 * x2 - 1),
+        (false, true) => x2 * (1 - 2 * z2),
+    }
+}
+
+/// [`quarter_turns`] will get how many quarter turns a rotation `angle` is, between 0 and 3,
+/// and panics if the `angle` is not a multiple of $\frac{\pi}{2}$.
+fn quarter_turns(angle: f64) -> usize {
+    let turns = (angle / FRAC_PI_2).round();
+    // Allow a small margin of error for floating-point inaccuracy
+    assert!(
+        (angle - turns * FRAC_PI_2).abs() < 1e-9,
+        "Rotation is not a Clifford gate"
+    );
+    turns.rem_euclid(4.0) as usize
+}
+
+/// Implement the [`fmt::Display`] trait for [`CliffordTableau`].
+impl fmt::Display for CliffordTableau {
+    /// Will return the [`CliffordTableau::destabilizers`] and [`CliffordTableau::stabilizers`] of a
+    /// [`CliffordTableau`] in [`PauliString`] form, separated by a line. For instance, a Bell state
+    /// will be presented as:
+    /// ```text
+    /// +ZI
+    /// +IX
+    /// ───
+    /// +XX
+    /// +ZZ
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`CliffordTableau`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::clifford_tableau::CliffordTableau;
+    ///
+    /// fn print_clifford_tableau(tableau: CliffordTableau) {
+    ///     println!("{tableau}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for destabilizer in self.destabilizers() {
+            writeln!(f, "{destabilizer}")?;
+        }
+        write!(f, "{}", "─".repeat(self.num_qubits + 1))?;
+        for stabilizer in self.stabilizers() {
+            write!(f, "\n{stabilizer}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use std::{cell::Cell, panic::Location};
+
+use crate::{logger::types::logger::Logger, quantum::types::clone_audit::CloneAudit};
+
+thread_local! {
+    /// Whether [`CloneAudit`] is enabled on the current thread.
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+
+    /// The amount of entangled clones [`CloneAudit`] has flagged on the current thread.
+    static FLAGGED: Cell<usize> = const { Cell::new(0) };
+}
+
+impl CloneAudit {
+    /// [`CloneAudit::enable`] will start flagging entangled clones on the current thread, and reset
+    /// [`CloneAudit::flagged`] to zero.
+    ///
+    /// # Example
+    /// [`CloneAudit::enable`] can be used to audit a block of code:
+    /// ```rust
+    /// use rquant::quantum::types::clone_audit::CloneAudit;
+    ///
+    /// fn count_entangled_clones(code: impl FnOnce()) -> usize {
+    ///     CloneAudit::enable();
+    ///     code();
+    ///     CloneAudit::disable();
+    ///     CloneAudit::flagged()
+    /// }
+    /// ```
+    pub fn enable() {
+        ENABLED.set(true);
+        FLAGGED.set(0);
+    }
+
+    /// [`CloneAudit::disable`] will stop flagging entangled clones on the current thread, and
+    /// keep [`CloneAudit::flagged`] as it was.
+    ///
+    /// # Example
+    /// [`CloneAudit::disable`] can be used to stop an audit:
+    /// ```rust
+    /// use rquant::quantum::types::clone_audit::CloneAudit;
+    ///
+    /// fn stop_audit() {
+    ///     CloneAudit::disable()
+    /// }
+    /// ```
+    pub fn disable() {
+        ENABLED.set(false);
+    }
+
+    /// [`CloneAudit::is_enabled`] returns `true` if [`CloneAudit`] is flagging entangled clones on
+    /// the current thread, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`CloneAudit::is_enabled`] can be used to check if an audit is running:
+    /// ```rust
+    /// use rquant::quantum::types::clone_audit::CloneAudit;
+    ///
+    /// fn is_auditing() -> bool {
+    ///     CloneAudit::is_enabled()
+    /// }
+    /// ```
+    pub fn is_enabled() -> bool {
+        ENABLED.get()
+    }
+
+    /// [`CloneAudit::flagged`] returns the amount of entangled clones that were flagged on the
+    /// current thread since [`CloneAudit::enable`] was called.
+    ///
+    /// # Example
+    /// [`CloneAudit::flagged`] can be used to fail a lesson that copies entangled state:
+    /// ```rust
+    /// use rquant::quantum::types::clone_audit::CloneAudit;
+    ///
+    /// fn has_cloned_entangled_state() -> bool {
+    ///     CloneAudit::flagged() > 0
+    /// }
+    /// ```
+    pub fn flagged() -> usize {
+        FLAGGED.get()
+    }
+
+    /// [`CloneAudit::flag`] will log a warning at the `location` of an entangled clone, and count
+    /// it in [`CloneAudit::flagged`], if [`CloneAudit`] is enabled.
+    pub(crate) fn flag(location: &Location) {
+        if Self::is_enabled() {
+            FLAGGED.set(FLAGGED.get() + 1);
+            Logger::warn(
+                "Cloned an entangled state vector, which is physically impossible (no-cloning theorem)",
+                location.file(),
+                location.line(),
+            );
+        }
+    }
+}
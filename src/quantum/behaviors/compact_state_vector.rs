@@ -0,0 +1,319 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    quantum::types::{
+        compact_state_vector::CompactStateVector, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate, state_vector::StateVector,
+    },
+    simulation::types::backend::Backend,
+};
+
+impl CompactStateVector {
+    /// [`CompactStateVector::new`] will create a new [`CompactStateVector`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), all initialized as $|0\rangle$.
+    ///
+    /// # Example
+    /// [`CompactStateVector::new`] can be used to create a single precision state:
+    /// ```rust
+    /// use rquant::quantum::types::compact_state_vector::CompactStateVector;
+    ///
+    /// fn create_compact_state() -> CompactStateVector {
+    ///     CompactStateVector::new(3)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); 1 << num_qubits];
+        amplitudes[0] = Complex::new(1.0, 0.0);
+        CompactStateVector {
+            num_qubits,
+            amplitudes,
+        }
+    }
+
+    /// [`CompactStateVector::from_state_vector`] will round the amplitudes of a [`StateVector`] to
+    /// single precision.
+    ///
+    /// # Example
+    /// [`CompactStateVector::from_state_vector`] can be used to shrink a prepared state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     state_vector::StateVector,
+    /// };
+    ///
+    /// fn compact_ghz_state() -> CompactStateVector {
+    ///     CompactStateVector::from_state_vector(&StateVector::ghz(3))
+    /// }
+    /// ```
+    pub fn from_state_vector(state_vector: &StateVector) -> Self {
+        CompactStateVector {
+            num_qubits: state_vector.num_qubits,
+            amplitudes: state_vector
+                .amplitudes
+                .iter()
+                .map(|amplitude| Complex::new(amplitude.re as f32, amplitude.im as f32))
+                .collect(),
+        }
+    }
+
+    /// [`CompactStateVector::to_state_vector`] will widen the amplitudes of a
+    /// [`CompactStateVector`] back to a double precision [`StateVector`].
+    ///
+    /// # Example
+    /// [`CompactStateVector::to_state_vector`] can be used to analyze a compact state with the
+    /// [`StateVector`] tools:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     state_vector::StateVector,
+    /// };
+    ///
+    /// fn widen_state(compact_state_vector: &CompactStateVector) -> StateVector {
+    ///     compact_state_vector.to_state_vector()
+    /// }
+    /// ```
+    pub fn to_state_vector(&self) -> StateVector {
+        StateVector {
+            num_qubits: self.num_qubits,
+            amplitudes: self
+                .amplitudes
+                .iter()
+                .map(|amplitude| Complex::new(amplitude.re as f64, amplitude.im as f64))
+                .collect(),
+        }
+    }
+
+    /// [`CompactStateVector::apply_gate`] will apply a [`QuantumGate`] to the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`, in the same way as
+    /// [`StateVector::apply_gate`].
+    ///
+    /// # Example
+    /// [`CompactStateVector::apply_gate`] can be used to put the first qubit into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn superposition_first_qubit(compact_state_vector: &mut CompactStateVector) {
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0)
+    /// }
+    /// ```
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        let mask = self.mask(target);
+        let matrix = gate
+            .matrix()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| Complex::new(entry.re as f32, entry.im as f32))
+                    .collect::<Vec<Complex<f32>>>()
+            })
+            .collect::<Vec<_>>();
+
+        for index in (0..self.amplitudes.len()).filter(|index| index & mask == 0) {
+            let (zero, one) = (self.amplitudes[index], self.amplitudes[index | mask]);
+            self.amplitudes[index] = matrix[0][0] * zero + matrix[0][1] * one;
+            self.amplitudes[index | mask] = matrix[1][0] * zero + matrix[1][1] * one;
+        }
+    }
+
+    /// [`CompactStateVector::apply_circuit`] will apply every
+    /// [`QuantumOperation`](crate::quantum::types::quantum_operation::QuantumOperation) of a
+    /// [`QuantumCircuit`] to a [`CompactStateVector`], in order.
+    ///
+    /// # Example
+    /// [`CompactStateVector::apply_circuit`] can be used to run a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn run_superposition_circuit() -> CompactStateVector {
+    ///     let mut compact_state_vector = CompactStateVector::new(1);
+    ///     compact_state_vector
+    ///         .apply_circuit(&QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0));
+    ///     compact_state_vector
+    /// }
+    /// ```
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) {
+        assert!(
+            circuit.num_qubits == self.num_qubits,
+            "Circuit must operate on every state vector qubit"
+        );
+
+        for operation in &circuit.operations {
+            Backend::apply_operation(self, operation);
+        }
+    }
+
+    /// [`CompactStateVector::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), in the same way as [`StateVector::apply_cnot`].
+    ///
+    /// # Example
+    /// [`CompactStateVector::apply_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn create_bell_state() -> CompactStateVector {
+    ///     let mut compact_state_vector = CompactStateVector::new(2);
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     compact_state_vector.apply_cnot(0, 1);
+    ///     compact_state_vector
+    /// }
+    /// ```
+    pub fn apply_cnot(&mut self, control: usize, target: usize) {
+        assert!(control != target, "Invalid state vector qubit index");
+
+        let (control_mask, target_mask) = (self.mask(control), self.mask(target));
+        for index in 0..self.amplitudes.len() {
+            if index & control_mask != 0 && index & target_mask == 0 {
+                self.amplitudes.swap(index, index | target_mask);
+            }
+        }
+    }
+
+    /// [`CompactStateVector::apply_cz`] will apply a controlled phase gate between two
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), in the same way as [`StateVector::apply_cz`].
+    ///
+    /// # Example
+    /// [`CompactStateVector::apply_cz`] can be used to entangle two superpositioned qubits:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn create_two_qubit_cluster_state() -> CompactStateVector {
+    ///     let mut compact_state_vector = CompactStateVector::new(2);
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    ///     compact_state_vector.apply_cz(0, 1);
+    ///     compact_state_vector
+    /// }
+    /// ```
+    pub fn apply_cz(&mut self, a: usize, b: usize) {
+        assert!(a != b, "Invalid state vector qubit index");
+
+        let mask = self.mask(a) | self.mask(b);
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if index & mask == mask {
+                *amplitude = -*amplitude;
+            }
+        }
+    }
+
+    /// [`CompactStateVector::measure`] will measure the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target`, collapse the [`CompactStateVector`] to the observed state, and return a
+    /// [`bool`] for it's "truthy" state, in the same way as [`StateVector::measure`].
+    ///
+    /// The probabilities are summed in double precision, so rounding does not build up over large
+    /// states.
+    ///
+    /// # Example
+    /// [`CompactStateVector::measure`] can be used to observe both qubits of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn observe_bell_state() -> (bool, bool) {
+    ///     let mut compact_state_vector = CompactStateVector::new(2);
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     compact_state_vector.apply_cnot(0, 1);
+    ///     (compact_state_vector.measure(0), compact_state_vector.measure(1))
+    /// }
+    /// ```
+    pub fn measure(&mut self, target: usize) -> bool {
+        self.measure_with(target, &mut rand::rng())
+    }
+
+    /// [`CompactStateVector::measure_with`] will measure the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` in the same way as
+    /// [`CompactStateVector::measure`], using a random number generator `rng` instead of the thread's
+    /// generator, so a seeded `rng` always collapses to the same state.
+    ///
+    /// # Example
+    /// [`CompactStateVector::measure_with`] can be used to reproduce a measurement of a Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{
+    ///     compact_state_vector::CompactStateVector,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn observe_seeded_bell_state(seed: u64) -> bool {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     let mut compact_state_vector = CompactStateVector::new(2);
+    ///     compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     compact_state_vector.apply_cnot(0, 1);
+    ///     compact_state_vector.measure_with(0, &mut rng)
+    /// }
+    /// ```
+    pub fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        let mask = self.mask(target);
+        let (prob_zero, total) = self.amplitudes.iter().enumerate().fold(
+            (0.0, 0.0),
+            |(prob_zero, total), (index, amplitude)| {
+                let probability = amplitude.norm_sqr() as f64;
+                let zero = if index & mask == 0 { probability } else { 0.0 };
+                (prob_zero + zero, total + probability)
+            },
+        );
+        let prob_zero = prob_zero / total;
+        let is_zero = rng.random_bool(prob_zero.clamp(0.0, 1.0));
+
+        let norm = ((if is_zero { prob_zero } else { 1.0 - prob_zero }) * total).sqrt() as f32;
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if (index & mask == 0) == is_zero {
+                *amplitude /= norm;
+            } else {
+                *amplitude = Complex::new(0.0, 0.0);
+            }
+        }
+        is_zero
+    }
+
+    /// [`CompactStateVector::mask`] will get the bit of a basis state index that holds the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at `target`.
+    fn mask(&self, target: usize) -> usize {
+        assert!(target < self.num_qubits, "Invalid state vector qubit index");
+
+        1 << (self.num_qubits - 1 - target)
+    }
+}
+
+/// Implement the [`Backend`] trait for [`CompactStateVector`].
+impl Backend for CompactStateVector {
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        CompactStateVector::apply_gate(self, gate, target)
+    }
+
+    fn apply_cnot(&mut self, control: usize, target: usize) {
+        CompactStateVector::apply_cnot(self, control, target)
+    }
+
+    fn apply_cz(&mut self, a: usize, b: usize) {
+        CompactStateVector::apply_cz(self, a, b)
+    }
+
+    fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        CompactStateVector::measure_with(self, target, rng)
+    }
+
+    fn sample_shots_with(&self, shots: usize, rng: &mut impl Rng) -> Vec<Vec<bool>> {
+        self.to_state_vector().sample_shots_with(shots, rng)
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use crate::quantum::{
+    behaviors::quantum_gate::operator_name,
+    types::{
+        cost_model::CostModel, quantum_gate::QuantumGate, quantum_operation::QuantumOperation,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+impl CostModel {
+    /// [`CostModel::new`] will create a new [`CostModel`] that weighs every gate type with a
+    /// `default_weight`.
+    ///
+    /// # Example
+    /// [`CostModel::new`] can be used to start a [`CostModel`] where only chosen gates cost
+    /// anything:
+    /// ```rust
+    /// use rquant::quantum::types::cost_model::CostModel;
+    ///
+    /// fn create_superposition_cost_model() -> CostModel {
+    ///     CostModel::new(0.0).with_weight("h", 1.0)
+    /// }
+    /// ```
+    pub fn new(default_weight: f64) -> Self {
+        CostModel {
+            weights: HashMap::new(),
+            default_weight,
+            non_clifford_weight: 0.0,
+            two_qubit_weight: 0.0,
+        }
+    }
+
+    /// [`CostModel::gate_count`] will create a new [`CostModel`] that weighs every gate as 1, so
+    /// the cost of a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) is
+    /// it's amount of gates.
+    ///
+    /// # Example
+    /// [`CostModel::gate_count`] can be used to count the gates of a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{cost_model::CostModel, quantum_circuit::QuantumCircuit};
+    ///
+    /// fn count_gates(circuit: &QuantumCircuit) -> f64 {
+    ///     circuit.cost(&CostModel::gate_count())
+    /// }
+    /// ```
+    pub fn gate_count() -> Self {
+        CostModel::new(1.0)
+    }
+
+    /// [`CostModel::t_count`] will create a new [`CostModel`] that only weighs gates that are not
+    /// Clifford gates, as 1, which is the expensive part of a fault-tolerant circuit.
+    ///
+    /// # Example
+    /// [`CostModel::t_count`] can be used to count the non-Clifford gates of a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{cost_model::CostModel, quantum_circuit::QuantumCircuit};
+    ///
+    /// fn count_t_gates(circuit: &QuantumCircuit) -> f64 {
+    ///     circuit.cost(&CostModel::t_count())
+    /// }
+    /// ```
+    pub fn t_count() -> Self {
+        CostModel::new(0.0).with_non_clifford_weight(1.0)
+    }
+
+    /// [`CostModel::with_weight`] will set the weight of a gate type by it's standard `name`, like
+    /// `x` or `rz`, then return the modified [`CostModel`].
+    ///
+    /// # Example
+    /// [`CostModel::with_weight`] can be used to make rotations cost more than Pauli gates:
+    /// ```rust
+    /// use rquant::quantum::types::cost_model::CostModel;
+    ///
+    /// fn create_rotation_cost_model() -> CostModel {
+    ///     CostModel::gate_count()
+    ///         .with_weight("rx", 5.0)
+    ///         .with_weight("ry", 5.0)
+    ///         .with_weight("rz", 5.0)
+    /// }
+    /// ```
+    pub fn with_weight(mut self, name: &str, weight: f64) -> Self {
+        self.weights.insert(name.to_string(), weight);
+        self
+    }
+
+    /// [`CostModel::with_non_clifford_weight`] will set the weight that is added to every gate that
+    /// is not a Clifford gate, then return the modified [`CostModel`].
+    ///
+    /// # Example
+    /// [`CostModel::with_non_clifford_weight`] can be used to make T gates much more expensive:
+    /// ```rust
+    /// use rquant::quantum::types::cost_model::CostModel;
+    ///
+    /// fn create_fault_tolerant_cost_model() -> CostModel {
+    ///     CostModel::gate_count().with_non_clifford_weight(100.0)
+    /// }
+    /// ```
+    pub fn with_non_clifford_weight(mut self, weight: f64) -> Self {
+        self.non_clifford_weight = weight;
+        self
+    }
+
+    /// [`CostModel::with_two_qubit_weight`] will set the weight that is added to every gate that
+    /// acts on more than one qubit, then return the modified [`CostModel`].
+    ///
+    /// # Example
+    /// [`CostModel::with_two_qubit_weight`] can be used to make entangling gates more expensive:
+    /// ```rust
+    /// use rquant::quantum::types::cost_model::CostModel;
+    ///
+    /// fn create_entangling_cost_model() -> CostModel {
+    ///     CostModel::gate_count().with_two_qubit_weight(10.0)
+    /// }
+    /// ```
+    pub fn with_two_qubit_weight(mut self, weight: f64) -> Self {
+        self.two_qubit_weight = weight;
+        self
+    }
+
+    /// [`CostModel::gate_cost`] will get the cost of one gate with an `operator`, which is the
+    /// weight of it's type, plus the non-Clifford weight if it is not a Clifford gate.
+    ///
+    /// # Example
+    /// [`CostModel::gate_cost`] can be used to get the cost of a T gate:
+    /// ```rust
+    /// use rquant::quantum::types::{cost_model::CostModel, quantum_operators::QuantumOperator};
+    ///
+    /// fn t_gate_cost(cost_model: &CostModel) -> f64 {
+    ///     cost_model.gate_cost(&QuantumOperator::RZ(std::f64::consts::FRAC_PI_4))
+    /// }
+    /// ```
+    pub fn gate_cost(&self, operator: &QuantumOperator) -> f64 {
+        let weight = self
+            .weights
+            .get(operator_name(operator))
+            .copied()
+            .unwrap_or(self.default_weight);
+        if QuantumGate::new(operator.clone()).is_clifford() {
+            weight
+        } else {
+            weight + self.non_clifford_weight
+        }
+    }
+
+    /// [`CostModel::operation_cost`] will get the cost of one [`QuantumOperation`], which is the
+    /// weight of it's type with a `c` in front of it's name for each control, like `cx`, plus the
+    /// non-Clifford weight if it is not a Clifford gate, plus the two-qubit weight if it has any
+    /// controls.
+    ///
+    /// # Example
+    /// [`CostModel::operation_cost`] can be used to get the cost of the first step of a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{cost_model::CostModel, quantum_circuit::QuantumCircuit};
+    ///
+    /// fn first_operation_cost(cost_model: &CostModel, circuit: &QuantumCircuit) -> f64 {
+    ///     cost_model.operation_cost(&circuit.operations[0])
+    /// }
+    /// ```
+    pub fn operation_cost(&self, operation: &QuantumOperation) -> f64 {
+        if operation.controls.is_empty() {
+            return self.gate_cost(&operation.operator);
+        }
+
+        let name = format!(
+            "{}{}",
+            "c".repeat(operation.controls.len()),
+            operator_name(&operation.operator)
+        );
+        let weight = self
+            .weights
+            .get(&name)
+            .copied()
+            .unwrap_or(self.default_weight);
+        // Only a Pauli gate with one control, like a controlled NOT gate, is a Clifford gate.
+        let is_clifford = operation.controls.len() == 1
+            && matches!(
+                operation.operator,
+                QuantumOperator::NOT | QuantumOperator::ROTATE | QuantumOperator::PHASE
+            );
+        if is_clifford {
+            weight + self.two_qubit_weight
+        } else {
+            weight + self.two_qubit_weight + self.non_clifford_weight
+        }
+    }
+}
@@ -0,0 +1,635 @@
+use std::collections::HashMap;
+
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    quantum::types::{
+        decision_diagram::DecisionDiagram, decision_node::DecisionNode,
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    },
+    simulation::types::backend::Backend,
+};
+
+/// An edge of a [`DecisionDiagram`], as the index of a [`DecisionNode`] and it's weight.
+type Edge = (usize, Complex<f64>);
+
+/// The index of the terminal [`DecisionNode`], which ends every path through the diagram.
+const TERMINAL: usize = 0;
+
+/// Weights closer than this are treated as equal when sharing nodes.
+const TOLERANCE: f64 = 1e-10;
+
+/// The edge of a branch with no amplitude.
+const ZERO: Edge = (TERMINAL, Complex::new(0.0, 0.0));
+
+impl DecisionDiagram {
+    /// [`DecisionDiagram::new`] will create a new [`DecisionDiagram`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), all initialized as $|0\rangle$.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::new`] can be used to create a state with many qubits:
+    /// ```rust
+    /// use rquant::quantum::types::decision_diagram::DecisionDiagram;
+    ///
+    /// fn create_large_state() -> DecisionDiagram {
+    ///     DecisionDiagram::new(64)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        let mut decision_diagram = DecisionDiagram {
+            num_qubits,
+            nodes: vec![DecisionNode {
+                qubit: num_qubits,
+                children: [ZERO, ZERO],
+            }],
+            root: (TERMINAL, Complex::new(1.0, 0.0)),
+            unique_table: HashMap::new(),
+        };
+        for qubit in (0..num_qubits).rev() {
+            let root = decision_diagram.root;
+            decision_diagram.root = decision_diagram.make_node(qubit, [root, ZERO]);
+        }
+        decision_diagram
+    }
+
+    /// [`DecisionDiagram::node_count`] will get the amount of [`DecisionNodes`](DecisionNode) used
+    /// to store the state, including the terminal node.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::node_count`] can be used to compare the size of the diagram with the
+    /// $2^n$ amplitudes of a state vector:
+    /// ```rust
+    /// use rquant::quantum::types::decision_diagram::DecisionDiagram;
+    ///
+    /// fn is_compact(decision_diagram: &DecisionDiagram) -> bool {
+    ///     decision_diagram.node_count() < 1 << decision_diagram.num_qubits
+    /// }
+    /// ```
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// [`DecisionDiagram::apply_gate`] will apply a [`QuantumGate`] to the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`, in the same way as
+    /// [`StateVector::apply_gate`].
+    ///
+    /// # Example
+    /// [`DecisionDiagram::apply_gate`] can be used to put the first qubit into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_first_qubit(decision_diagram: &mut DecisionDiagram) {
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0)
+    /// }
+    /// ```
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        assert!(
+            target < self.num_qubits,
+            "Invalid decision diagram qubit index"
+        );
+
+        let matrix = gate.matrix();
+        let mut cache = HashMap::new();
+        let (node, weight) = self.root;
+        let applied = self.apply_matrix(node, &matrix, target, &mut cache);
+        self.root = scale(applied, weight);
+        self.compact();
+    }
+
+    /// [`DecisionDiagram::apply_circuit`] will apply every
+    /// [`QuantumOperation`](crate::quantum::types::quantum_operation::QuantumOperation) of a
+    /// [`QuantumCircuit`] to a [`DecisionDiagram`], in order.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::apply_circuit`] can be used to run a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     decision_diagram::DecisionDiagram,
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn run_superposition_circuit() -> DecisionDiagram {
+    ///     let mut decision_diagram = DecisionDiagram::new(1);
+    ///     decision_diagram
+    ///         .apply_circuit(&QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0));
+    ///     decision_diagram
+    /// }
+    /// ```
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) {
+        assert!(
+            circuit.num_qubits == self.num_qubits,
+            "Circuit must operate on every decision diagram qubit"
+        );
+
+        for operation in &circuit.operations {
+            Backend::apply_operation(self, operation);
+        }
+    }
+
+    /// [`DecisionDiagram::apply_cz`] will apply a controlled Z gate to the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) `a` and `b`, in the same way as
+    /// [`StateVector::apply_cz`].
+    ///
+    /// # Example
+    /// [`DecisionDiagram::apply_cz`] can be used to entangle two qubits in superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, quantum_gate::QuantumGate};
+    ///
+    /// fn entangle_pair() -> DecisionDiagram {
+    ///     let mut decision_diagram = DecisionDiagram::new(2);
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    ///     decision_diagram.apply_cz(0, 1);
+    ///     decision_diagram
+    /// }
+    /// ```
+    pub fn apply_cz(&mut self, a: usize, b: usize) {
+        assert!(
+            a != b && a < self.num_qubits && b < self.num_qubits,
+            "Invalid decision diagram qubit index"
+        );
+
+        let mut cache = HashMap::new();
+        let (node, weight) = self.root;
+        let applied = self.apply_phase(node, (a.min(b), a.max(b)), false, &mut cache);
+        self.root = scale(applied, weight);
+        self.compact();
+    }
+
+    /// [`DecisionDiagram::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), in the same way as [`StateVector::apply_cnot`].
+    ///
+    /// # Example
+    /// [`DecisionDiagram::apply_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, quantum_gate::QuantumGate};
+    ///
+    /// fn create_bell_state() -> DecisionDiagram {
+    ///     let mut decision_diagram = DecisionDiagram::new(2);
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     decision_diagram.apply_cnot(0, 1);
+    ///     decision_diagram
+    /// }
+    /// ```
+    pub fn apply_cnot(&mut self, control: usize, target: usize) {
+        self.apply_gate(&QuantumGate::SUPERPOSITION, target);
+        self.apply_cz(control, target);
+        self.apply_gate(&QuantumGate::SUPERPOSITION, target);
+    }
+
+    /// [`DecisionDiagram::measure`] will measure the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target`, collapse the [`DecisionDiagram`] to the observed state, and return a
+    /// [`bool`] for it's "truthy" state, in the same way as [`StateVector::measure`].
+    ///
+    /// # Example
+    /// [`DecisionDiagram::measure`] can be used to observe both qubits of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, quantum_gate::QuantumGate};
+    ///
+    /// fn observe_bell_state() -> (bool, bool) {
+    ///     let mut decision_diagram = DecisionDiagram::new(2);
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     decision_diagram.apply_cnot(0, 1);
+    ///     (decision_diagram.measure(0), decision_diagram.measure(1))
+    /// }
+    /// ```
+    pub fn measure(&mut self, target: usize) -> bool {
+        self.measure_with(target, &mut rand::rng())
+    }
+
+    /// [`DecisionDiagram::measure_with`] will measure the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` in the same way as
+    /// [`DecisionDiagram::measure`], using a random number generator `rng` instead of the thread's
+    /// generator, so a seeded `rng` always collapses to the same state.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::measure_with`] can be used to reproduce a measurement of a Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, quantum_gate::QuantumGate};
+    ///
+    /// fn observe_seeded_bell_state(seed: u64) -> bool {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     let mut decision_diagram = DecisionDiagram::new(2);
+    ///     decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     decision_diagram.apply_cnot(0, 1);
+    ///     decision_diagram.measure_with(0, &mut rng)
+    /// }
+    /// ```
+    pub fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        assert!(
+            target < self.num_qubits,
+            "Invalid decision diagram qubit index"
+        );
+
+        let mut cache = HashMap::new();
+        let prob_zero = self.probability_of_zero(self.root.0, target, &mut cache);
+        let is_zero = rng.random_bool(prob_zero.clamp(0.0, 1.0));
+
+        let mut cache = HashMap::new();
+        let (node, weight) = self.root;
+        let (projected, projected_weight) = self.project(node, target, is_zero, &mut cache);
+        self.root = (
+            projected,
+            weight * projected_weight / projected_weight.norm(),
+        );
+        self.compact();
+        is_zero
+    }
+
+    /// [`DecisionDiagram::amplitude`] will get the [`Complex<f64>`] amplitude of one computational
+    /// basis state, where the first [`Qubit`](crate::quantum::types::qubit::Qubit) is the most
+    /// significant bit of the `index`.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::amplitude`] can be used to get the amplitude of $|0\ldots0\rangle$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::decision_diagram::DecisionDiagram;
+    ///
+    /// fn all_zero_amplitude(decision_diagram: &DecisionDiagram) -> Complex<f64> {
+    ///     decision_diagram.amplitude(0)
+    /// }
+    /// ```
+    pub fn amplitude(&self, index: usize) -> Complex<f64> {
+        let (mut node, mut amplitude) = self.root;
+        while node != TERMINAL && amplitude.norm() > 0.0 {
+            let qubit = self.nodes[node].qubit;
+            let (child, weight) =
+                self.nodes[node].children[(index >> (self.num_qubits - 1 - qubit)) & 1];
+            node = child;
+            amplitude *= weight;
+        }
+        amplitude
+    }
+
+    /// [`DecisionDiagram::to_state_vector`] will expand the [`DecisionDiagram`] into a dense
+    /// [`StateVector`], which is useful to cross-validate small circuits.
+    ///
+    /// # Example
+    /// [`DecisionDiagram::to_state_vector`] can be used to compare both backends:
+    /// ```rust
+    /// use rquant::quantum::types::{decision_diagram::DecisionDiagram, state_vector::StateVector};
+    ///
+    /// fn expand_decision_diagram(decision_diagram: &DecisionDiagram) -> StateVector {
+    ///     decision_diagram.to_state_vector()
+    /// }
+    /// ```
+    pub fn to_state_vector(&self) -> StateVector {
+        StateVector {
+            num_qubits: self.num_qubits,
+            amplitudes: (0..1 << self.num_qubits)
+                .map(|index| self.amplitude(index))
+                .collect(),
+        }
+    }
+
+    /// [`DecisionDiagram::make_node`] will get the edge to a [`DecisionNode`] with the `children`,
+    /// normalizing the children so the node holds a unit vector and reusing an equal node if one
+    /// is already stored.
+    fn make_node(&mut self, qubit: usize, children: [Edge; 2]) -> Edge {
+        let norm = (children[0].1.norm_sqr() + children[1].1.norm_sqr()).sqrt();
+        if norm < TOLERANCE {
+            return ZERO;
+        }
+
+        // Give the first branch with amplitude a real, positive weight so equal nodes match.
+        let leading = if children[0].1.norm() > TOLERANCE {
+            children[0].1
+        } else {
+            children[1].1
+        };
+        let factor = leading / leading.norm() * norm;
+        let normalized = children.map(|(node, weight)| {
+            let weight = weight / factor;
+            if weight.norm() < TOLERANCE {
+                ZERO
+            } else {
+                (node, weight)
+            }
+        });
+
+        let key = (
+            qubit,
+            normalized.map(|(node, weight)| {
+                (
+                    node,
+                    (weight.re / TOLERANCE).round() as i64,
+                    (weight.im / TOLERANCE).round() as i64,
+                )
+            }),
+        );
+        let node = *self.unique_table.entry(key).or_insert_with(|| {
+            self.nodes.push(DecisionNode {
+                qubit,
+                children: normalized,
+            });
+            self.nodes.len() - 1
+        });
+        (node, factor)
+    }
+
+    /// [`DecisionDiagram::add`] will get the edge to the sum of the states of two edges on the same
+    /// level of the diagram.
+    fn add(&mut self, first: Edge, second: Edge, cache: &mut AddCache) -> Edge {
+        if first.1.norm() < TOLERANCE {
+            return second;
+        }
+        if second.1.norm() < TOLERANCE {
+            return first;
+        }
+        if first.0 == second.0 {
+            return scale((first.0, Complex::new(1.0, 0.0)), first.1 + second.1);
+        }
+
+        // Factor out the first weight so sums that only differ by a global weight share a result.
+        let ratio = second.1 / first.1;
+        let key = (
+            first.0,
+            second.0,
+            (ratio.re / TOLERANCE).round() as i64,
+            (ratio.im / TOLERANCE).round() as i64,
+        );
+        if let Some(&result) = cache.get(&key) {
+            return scale(result, first.1);
+        }
+
+        let (first_node, second_node) = (self.nodes[first.0], self.nodes[second.0]);
+        let children = [0, 1].map(|branch| {
+            let (node, weight) = second_node.children[branch];
+            (first_node.children[branch], (node, weight * ratio))
+        });
+        let zero = self.add(children[0].0, children[0].1, cache);
+        let one = self.add(children[1].0, children[1].1, cache);
+        let result = self.make_node(first_node.qubit, [zero, one]);
+        cache.insert(key, result);
+        scale(result, first.1)
+    }
+
+    /// [`DecisionDiagram::apply_matrix`] will get the edge to the state of a node after applying a
+    /// single-qubit `matrix` to the `target` qubit.
+    fn apply_matrix(
+        &mut self,
+        node: usize,
+        matrix: &[Vec<Complex<f64>>],
+        target: usize,
+        cache: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if let Some(&result) = cache.get(&node) {
+            return result;
+        }
+
+        let DecisionNode { qubit, children } = self.nodes[node];
+        let result = if qubit == target {
+            let mut add_cache = HashMap::new();
+            let [zero, one] = [0, 1].map(|row| {
+                (
+                    scale(children[0], matrix[row][0]),
+                    scale(children[1], matrix[row][1]),
+                )
+            });
+            let zero = self.add(zero.0, zero.1, &mut add_cache);
+            let one = self.add(one.0, one.1, &mut add_cache);
+            self.make_node(qubit, [zero, one])
+        } else {
+            let [zero, one] = children;
+            let zero = self.apply_child(zero, matrix, target, cache);
+            let one = self.apply_child(one, matrix, target, cache);
+            self.make_node(qubit, [zero, one])
+        };
+        cache.insert(node, result);
+        result
+    }
+
+    /// [`DecisionDiagram::apply_child`] will apply a single-qubit `matrix` below an edge, skipping
+    /// branches with no amplitude.
+    fn apply_child(
+        &mut self,
+        (node, weight): Edge,
+        matrix: &[Vec<Complex<f64>>],
+        target: usize,
+        cache: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if weight.norm() < TOLERANCE {
+            return ZERO;
+        }
+        scale(self.apply_matrix(node, matrix, target, cache), weight)
+    }
+
+    /// [`DecisionDiagram::apply_phase`] will get the edge to the state of a node after flipping the
+    /// sign of every basis state where both of the `pair` qubits are $|1\rangle$, where `satisfied`
+    /// is `true` once the first qubit of the pair is $|1\rangle$.
+    fn apply_phase(
+        &mut self,
+        node: usize,
+        pair: (usize, usize),
+        satisfied: bool,
+        cache: &mut HashMap<(usize, bool), Edge>,
+    ) -> Edge {
+        let DecisionNode { qubit, children } = self.nodes[node];
+        if qubit > pair.1 || (qubit == pair.1 && !satisfied) {
+            return (node, Complex::new(1.0, 0.0));
+        }
+        if let Some(&result) = cache.get(&(node, satisfied)) {
+            return result;
+        }
+
+        let result = if qubit == pair.1 {
+            self.make_node(
+                qubit,
+                [children[0], scale(children[1], Complex::new(-1.0, 0.0))],
+            )
+        } else {
+            let [zero, one] = [0, 1].map(|branch| {
+                (
+                    children[branch],
+                    satisfied || (qubit == pair.0 && branch == 1),
+                )
+            });
+            let zero = self.apply_phase_child(zero, pair, cache);
+            let one = self.apply_phase_child(one, pair, cache);
+            self.make_node(qubit, [zero, one])
+        };
+        cache.insert((node, satisfied), result);
+        result
+    }
+
+    /// [`DecisionDiagram::apply_phase_child`] will apply the controlled Z sign flip below an edge,
+    /// skipping branches with no amplitude.
+    fn apply_phase_child(
+        &mut self,
+        ((node, weight), satisfied): (Edge, bool),
+        pair: (usize, usize),
+        cache: &mut HashMap<(usize, bool), Edge>,
+    ) -> Edge {
+        if weight.norm() < TOLERANCE {
+            return ZERO;
+        }
+        scale(self.apply_phase(node, pair, satisfied, cache), weight)
+    }
+
+    /// [`DecisionDiagram::probability_of_zero`] will get the probability of measuring the `target`
+    /// qubit as $|0\rangle$ in the unit vector of a node.
+    fn probability_of_zero(
+        &self,
+        node: usize,
+        target: usize,
+        cache: &mut HashMap<usize, f64>,
+    ) -> f64 {
+        if let Some(&probability) = cache.get(&node) {
+            return probability;
+        }
+
+        let DecisionNode { qubit, children } = self.nodes[node];
+        let probability = if qubit == target {
+            children[0].1.norm_sqr()
+        } else {
+            children
+                .iter()
+                .filter(|(_, weight)| weight.norm() > TOLERANCE)
+                .map(|&(child, weight)| {
+                    weight.norm_sqr() * self.probability_of_zero(child, target, cache)
+                })
+                .sum()
+        };
+        cache.insert(node, probability);
+        probability
+    }
+
+    /// [`DecisionDiagram::project`] will get the edge to the state of a node with every branch of
+    /// the `target` qubit removed, other than the measured one.
+    fn project(
+        &mut self,
+        node: usize,
+        target: usize,
+        is_zero: bool,
+        cache: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if let Some(&result) = cache.get(&node) {
+            return result;
+        }
+
+        let DecisionNode { qubit, children } = self.nodes[node];
+        let result = if qubit == target {
+            if is_zero {
+                self.make_node(qubit, [children[0], ZERO])
+            } else {
+                self.make_node(qubit, [ZERO, children[1]])
+            }
+        } else {
+            let [zero, one] = children;
+            let zero = self.project_child(zero, target, is_zero, cache);
+            let one = self.project_child(one, target, is_zero, cache);
+            self.make_node(qubit, [zero, one])
+        };
+        cache.insert(node, result);
+        result
+    }
+
+    /// [`DecisionDiagram::project_child`] will project the `target` qubit below an edge, skipping
+    /// branches with no amplitude.
+    fn project_child(
+        &mut self,
+        (node, weight): Edge,
+        target: usize,
+        is_zero: bool,
+        cache: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if weight.norm() < TOLERANCE {
+            return ZERO;
+        }
+        scale(self.project(node, target, is_zero, cache), weight)
+    }
+
+    /// [`DecisionDiagram::compact`] will drop every [`DecisionNode`] that is no longer reachable
+    /// from the root, which operations leave behind.
+    fn compact(&mut self) {
+        let old_nodes = std::mem::take(&mut self.nodes);
+        self.nodes.push(old_nodes[TERMINAL]);
+        self.unique_table.clear();
+
+        let mut remapped = HashMap::from([(TERMINAL, (TERMINAL, Complex::new(1.0, 0.0)))]);
+        let (root, weight) = self.root;
+        self.root = scale(self.rebuild(root, &old_nodes, &mut remapped), weight);
+    }
+
+    /// [`DecisionDiagram::rebuild`] will copy a node and every node below it from `old_nodes` into
+    /// the [`DecisionDiagram`].
+    fn rebuild(
+        &mut self,
+        node: usize,
+        old_nodes: &[DecisionNode],
+        remapped: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if let Some(&result) = remapped.get(&node) {
+            return result;
+        }
+
+        let DecisionNode { qubit, children } = old_nodes[node];
+        let [zero, one] = children;
+        let zero = self.rebuild_child(zero, old_nodes, remapped);
+        let one = self.rebuild_child(one, old_nodes, remapped);
+        let result = self.make_node(qubit, [zero, one]);
+        remapped.insert(node, result);
+        result
+    }
+
+    /// [`DecisionDiagram::rebuild_child`] will rebuild the node below an edge, skipping branches
+    /// with no amplitude.
+    fn rebuild_child(
+        &mut self,
+        (node, weight): Edge,
+        old_nodes: &[DecisionNode],
+        remapped: &mut HashMap<usize, Edge>,
+    ) -> Edge {
+        if weight.norm() < TOLERANCE {
+            return ZERO;
+        }
+        scale(self.rebuild(node, old_nodes, remapped), weight)
+    }
+}
+
+/// The cache of [`DecisionDiagram::add`], keyed by both nodes and the rounded ratio of their
+/// weights.
+type AddCache = HashMap<(usize, usize, i64, i64), Edge>;
+
+/// [`scale`] will multiply the weight of an edge by a `factor`, dropping it to the zero edge when no
+/// amplitude is left.
+fn scale((node, weight): Edge, factor: Complex<f64>) -> Edge {
+    let weight = weight * factor;
+    if weight.norm() < TOLERANCE {
+        ZERO
+    } else {
+        (node, weight)
+    }
+}
+
+/// Implement the [`Backend`] trait for [`DecisionDiagram`].
+impl Backend for DecisionDiagram {
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        DecisionDiagram::apply_gate(self, gate, target)
+    }
+
+    fn apply_cnot(&mut self, control: usize, target: usize) {
+        DecisionDiagram::apply_cnot(self, control, target)
+    }
+
+    fn apply_cz(&mut self, a: usize, b: usize) {
+        DecisionDiagram::apply_cz(self, a, b)
+    }
+
+    fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        DecisionDiagram::measure_with(self, target, rng)
+    }
+
+    fn sample_shots_with(&self, shots: usize, rng: &mut impl Rng) -> Vec<Vec<bool>> {
+        self.to_state_vector().sample_shots_with(shots, rng)
+    }
+}
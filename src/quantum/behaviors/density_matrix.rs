@@ -0,0 +1,241 @@
+use num_complex::Complex;
+
+use crate::quantum::{
+    behaviors::hamiltonian::matrix_multiply,
+    types::{density_matrix::DensityMatrix, qubit::Qubit, state_vector::StateVector},
+};
+
+impl DensityMatrix {
+    /// [`DensityMatrix::from_state_vector`] will create the [`DensityMatrix`]
+    /// $\rho = |\psi\rangle\langle\psi|$ of a pure [`StateVector`].
+    ///
+    /// # Example
+    /// [`DensityMatrix::from_state_vector`] can be used to get the density matrix of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{density_matrix::DensityMatrix, state_vector::StateVector};
+    ///
+    /// fn bell_density_matrix() -> DensityMatrix {
+    ///     DensityMatrix::from_state_vector(&StateVector::ghz(2))
+    /// }
+    /// ```
+    pub fn from_state_vector(state_vector: &StateVector) -> Self {
+        DensityMatrix {
+            num_qubits: state_vector.num_qubits,
+            elements: state_vector
+                .amplitudes
+                .iter()
+                .map(|row| {
+                    state_vector
+                        .amplitudes
+                        .iter()
+                        .map(|column| row * column.conj())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// [`DensityMatrix::from_qubit`] will create the [`DensityMatrix`] of a single [`Qubit`].
+    ///
+    /// # Example
+    /// [`DensityMatrix::from_qubit`] can be used to get the density matrix of $|0\rangle$:
+    /// ```rust
+    /// use rquant::quantum::types::{density_matrix::DensityMatrix, qubit::Qubit};
+    ///
+    /// fn zero_density_matrix() -> DensityMatrix {
+    ///     DensityMatrix::from_qubit(&Qubit::zero())
+    /// }
+    /// ```
+    pub fn from_qubit(qubit: &Qubit) -> Self {
+        Self::from_state_vector(&StateVector {
+            num_qubits: 1,
+            amplitudes: vec![qubit.initial_position(), qubit.possible_position()],
+        })
+    }
+
+    /// [`DensityMatrix::trace`] will get the sum of the diagonal of a [`DensityMatrix`], which is
+    /// `1.0` for any valid state.
+    ///
+    /// # Example
+    /// [`DensityMatrix::trace`] can be used to check that a channel preserves probability:
+    /// ```rust
+    /// use rquant::quantum::types::density_matrix::DensityMatrix;
+    ///
+    /// fn is_normalized(density_matrix: &DensityMatrix) -> bool {
+    ///     (density_matrix.trace() - 1.0).abs() < 1e-9
+    /// }
+    /// ```
+    pub fn trace(&self) -> f64 {
+        (0..self.elements.len())
+            .map(|index| self.elements[index][index].re)
+            .sum()
+    }
+
+    /// [`DensityMatrix::purity`] will get $\mathrm{Tr}(\rho^2)$ of a [`DensityMatrix`], which is
+    /// `1.0` for a pure state and $2^{-n}$ for the maximally mixed state of $n$ qubits.
+    ///
+    /// # Example
+    /// [`DensityMatrix::purity`] can be used to check if noise mixed a state:
+    /// ```rust
+    /// use rquant::quantum::types::density_matrix::DensityMatrix;
+    ///
+    /// fn is_mixed(density_matrix: &DensityMatrix) -> bool {
+    ///     density_matrix.purity() < 1.0 - 1e-9
+    /// }
+    /// ```
+    pub fn purity(&self) -> f64 {
+        // Tr(ρ²) is the sum of |ρ_ij|², because ρ is Hermitian.
+        self.elements
+            .iter()
+            .flatten()
+            .map(|element: &Complex<f64>| element.norm_sqr())
+            .sum()
+    }
+
+    /// [`DensityMatrix::fidelity`] will get the Uhlmann fidelity of the [`DensityMatrix`] $\rho$
+    /// that calls it and an `other` [`DensityMatrix`] $\sigma$, which is the squared overlap
+    /// $|\langle a|b\rangle|^2$ when both are pure:
+    /// $$ F(\rho, \sigma) = \left( \mathrm{Tr} \sqrt{\sqrt{\rho} \sigma \sqrt{\rho}} \right)^2 $$
+    ///
+    /// # Example
+    /// [`DensityMatrix::fidelity`] can be used to check how much noise changed a state:
+    /// ```rust
+    /// use rquant::quantum::types::density_matrix::DensityMatrix;
+    ///
+    /// fn infidelity(ideal: &DensityMatrix, noisy: &DensityMatrix) -> f64 {
+    ///     1.0 - ideal.fidelity(noisy)
+    /// }
+    /// ```
+    pub fn fidelity(&self, other: &DensityMatrix) -> f64 {
+        assert!(
+            self.num_qubits == other.num_qubits,
+            "Density matrices must have the same amount of qubits"
+        );
+
+        let (values, vectors) = hermitian_eigen(&self.elements);
+        let root: Vec<Vec<Complex<f64>>> = (0..values.len())
+            .map(|row| {
+                (0..values.len())
+                    .map(|column| {
+                        values
+                            .iter()
+                            .enumerate()
+                            .map(|(index, value)| {
+                                vectors[row][index]
+                                    * vectors[column][index].conj()
+                                    * value.max(0.0).sqrt()
+                            })
+                            .sum()
+                    })
+                    .collect()
+            })
+            .collect();
+        let product = matrix_multiply(&matrix_multiply(&root, &other.elements), &root);
+        hermitian_eigen(&product)
+            .0
+            .iter()
+            .map(|value| value.max(0.0).sqrt())
+            .sum::<f64>()
+            .powi(2)
+    }
+
+    /// [`DensityMatrix::trace_distance`] will get the trace distance
+    /// $\frac{1}{2}\mathrm{Tr}|\rho - \sigma|$ of the [`DensityMatrix`] $\rho$ that calls it and an
+    /// `other` [`DensityMatrix`] $\sigma$, which is the largest difference in probability that any
+    /// measurement can observe between them.
+    ///
+    /// # Example
+    /// [`DensityMatrix::trace_distance`] can be used to compare simulation results with a
+    /// tolerance instead of exactly:
+    /// ```rust
+    /// use rquant::quantum::types::density_matrix::DensityMatrix;
+    ///
+    /// fn is_close(a: &DensityMatrix, b: &DensityMatrix) -> bool {
+    ///     a.trace_distance(b) < 1e-6
+    /// }
+    /// ```
+    pub fn trace_distance(&self, other: &DensityMatrix) -> f64 {
+        assert!(
+            self.num_qubits == other.num_qubits,
+            "Density matrices must have the same amount of qubits"
+        );
+
+        let difference: Vec<Vec<Complex<f64>>> = self
+            .elements
+            .iter()
+            .zip(&other.elements)
+            .map(|(a, b)| a.iter().zip(b).map(|(x, y)| x - y).collect())
+            .collect();
+        hermitian_eigen(&difference)
+            .0
+            .iter()
+            .map(|value| value.abs())
+            .sum::<f64>()
+            / 2.0
+    }
+}
+
+/// [`hermitian_eigen`] will get the eigenvalues of a Hermitian `matrix` and a matrix whose columns
+/// are the matching eigenvectors, using cyclic Jacobi rotations.
+fn hermitian_eigen(matrix: &[Vec<Complex<f64>>]) -> (Vec<f64>, Vec<Vec<Complex<f64>>>) {
+    let dimension = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut vectors: Vec<Vec<Complex<f64>>> = (0..dimension)
+        .map(|row| {
+            (0..dimension)
+                .map(|column| Complex::new(if row == column { 1.0 } else { 0.0 }, 0.0))
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..100 {
+        let off_diagonal: f64 = (0..dimension)
+            .flat_map(|row| (0..dimension).map(move |column| (row, column)))
+            .filter(|(row, column)| row != column)
+            .map(|(row, column)| a[row][column].norm_sqr())
+            .sum();
+        if off_diagonal < 1e-24 {
+            break;
+        }
+
+        for p in 0..dimension {
+            for q in p + 1..dimension {
+                let magnitude = a[p][q].norm();
+                if magnitude < 1e-300 {
+                    continue;
+                }
+
+                // Remove the phase of a_pq, then zero it with a real rotation.
+                let phase = a[p][q] / magnitude;
+                let tau = (a[q][q].re - a[p][p].re) / (2.0 * magnitude);
+                let t = if tau >= 0.0 {
+                    1.0 / (tau + (1.0 + tau * tau).sqrt())
+                } else {
+                    -1.0 / (-tau + (1.0 + tau * tau).sqrt())
+                };
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = t * c;
+                let (pp, pq) = (Complex::new(c, 0.0), Complex::new(s, 0.0));
+                let (qp, qq) = (-phase.conj() * s, phase.conj() * c);
+
+                for row in a.iter_mut().chain(vectors.iter_mut()) {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = kp * pp + kq * qp;
+                    row[q] = kp * pq + kq * qq;
+                }
+                let (head, tail) = a.split_at_mut(q);
+                for (pk, qk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    (*pk, *qk) = (
+                        pp.conj() * *pk + qp.conj() * *qk,
+                        pq.conj() * *pk + qq.conj() * *qk,
+                    );
+                }
+            }
+        }
+    }
+
+    (
+        (0..dimension).map(|index| a[index][index].re).collect(),
+        vectors,
+    )
+}
@@ -0,0 +1,180 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    RwLock,
+};
+
+use num_complex::Complex;
+
+use crate::quantum::types::display_precision::DisplayPrecision;
+
+/// The value of [`DIGITS`] that displays amplitudes at full precision.
+const FULL_PRECISION: usize = usize::MAX;
+
+/// The amount of digits after the decimal point that are displayed, or [`FULL_PRECISION`].
+static DIGITS: AtomicUsize = AtomicUsize::new(FULL_PRECISION);
+
+/// The glyph displayed for the imaginary unit.
+static IMAGINARY_UNIT: RwLock<&'static str> = RwLock::new("i");
+
+/// [`set_display_precision`] will display every amplitude on every thread with an amount of
+/// `digits` after the decimal point, or at full precision for [`None`], in the same way as
+/// [`DisplayPrecision::set`] and [`DisplayPrecision::reset`].
+///
+/// # Example
+/// [`set_display_precision`] can be used to print a report with two digits from any thread:
+/// ```rust
+/// use std::thread;
+/// use rquant::quantum::{
+///     behaviors::display_precision::set_display_precision, types::state_vector::StateVector,
+/// };
+///
+/// fn print_short_ghz_state() {
+///     set_display_precision(Some(2));
+///     thread::spawn(|| println!("{}", StateVector::ghz(3)));
+/// }
+/// ```
+pub fn set_display_precision(digits: Option<usize>) {
+    DIGITS.store(digits.unwrap_or(FULL_PRECISION), Ordering::Relaxed);
+}
+
+impl DisplayPrecision {
+    /// [`DisplayPrecision::set`] will display every amplitude on every thread with an amount
+    /// of `digits` after the decimal point, which is respected by the [`Display`](std::fmt::Display)
+    /// implementations of [`Qubit`](crate::quantum::types::qubit::Qubit),
+    /// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister),
+    /// [`QuantumGate`](crate::quantum::types::quantum_gate::QuantumGate),
+    /// [`StateVector`](crate::quantum::types::state_vector::StateVector), and the simulation
+    /// reports that display them.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::set`] can be used to print a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// with three digits:
+    /// ```rust
+    /// use rquant::quantum::types::{display_precision::DisplayPrecision, qubit::Qubit};
+    ///
+    /// fn print_short_qubit(qubit: Qubit) {
+    ///     DisplayPrecision::set(3);
+    ///     println!("{qubit}");
+    /// }
+    /// ```
+    pub fn set(digits: usize) {
+        set_display_precision(Some(digits));
+    }
+
+    /// [`DisplayPrecision::reset`] will display every amplitude on every thread at full
+    /// precision again.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::reset`] can be used to undo [`DisplayPrecision::set`]:
+    /// ```rust
+    /// use rquant::quantum::types::display_precision::DisplayPrecision;
+    ///
+    /// fn display_full_precision() {
+    ///     DisplayPrecision::reset()
+    /// }
+    /// ```
+    pub fn reset() {
+        set_display_precision(None);
+    }
+
+    /// [`DisplayPrecision::digits`] returns the amount of digits after the decimal point that are
+    /// displayed on every thread, or [`None`] if amplitudes are displayed at full precision.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::digits`] can be used to check the current precision:
+    /// ```rust
+    /// use rquant::quantum::types::display_precision::DisplayPrecision;
+    ///
+    /// fn is_full_precision() -> bool {
+    ///     DisplayPrecision::digits().is_none()
+    /// }
+    /// ```
+    pub fn digits() -> Option<usize> {
+        match DIGITS.load(Ordering::Relaxed) {
+            FULL_PRECISION => None,
+            digits => Some(digits),
+        }
+    }
+
+    /// [`DisplayPrecision::set_imaginary_unit`] will display the imaginary unit of every amplitude
+    /// on every thread as a `glyph`, such as `"ⅈ"` or `"j"`, instead of `"i"`.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::set_imaginary_unit`] can be used to print amplitudes with a unicode
+    /// imaginary unit:
+    /// ```rust
+    /// use rquant::quantum::types::{display_precision::DisplayPrecision, qubit::Qubit};
+    ///
+    /// fn print_unicode_qubit(qubit: Qubit) {
+    ///     DisplayPrecision::set_imaginary_unit("ⅈ");
+    ///     println!("{qubit}");
+    /// }
+    /// ```
+    pub fn set_imaginary_unit(glyph: &'static str) {
+        *IMAGINARY_UNIT
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = glyph;
+    }
+
+    /// [`DisplayPrecision::imaginary_unit`] returns the glyph displayed for the imaginary unit on
+    /// every thread.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::imaginary_unit`] can be used to check the current glyph:
+    /// ```rust
+    /// use rquant::quantum::types::display_precision::DisplayPrecision;
+    ///
+    /// fn is_engineering_notation() -> bool {
+    ///     DisplayPrecision::imaginary_unit() == "j"
+    /// }
+    /// ```
+    pub fn imaginary_unit() -> &'static str {
+        *IMAGINARY_UNIT
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+
+    /// [`DisplayPrecision::format`] will format a complex `value` as $a+bi$ with the current
+    /// [`DisplayPrecision`], leaving out any part that displays as zero. For instance, amplitudes
+    /// are displayed as `0.71`, `0.71i`, or `-0.5+0.5i`.
+    ///
+    /// # Example
+    /// [`DisplayPrecision::format`] can be used to print an amplitude:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::display_precision::DisplayPrecision;
+    ///
+    /// fn print_amplitude(amplitude: Complex<f64>) {
+    ///     println!("{}", DisplayPrecision::format(amplitude));
+    /// }
+    /// ```
+    pub fn format(value: Complex<f64>) -> String {
+        let unit = Self::imaginary_unit();
+        match (Self::part(value.re), Self::part(value.im)) {
+            (Some(re), Some(im)) if im.starts_with('-') => format!("{re}{im}{unit}"),
+            (Some(re), Some(im)) => format!("{re}+{im}{unit}"),
+            (Some(re), None) => re,
+            (None, Some(im)) => format!("{im}{unit}"),
+            (None, None) => Self::real(0.0),
+        }
+    }
+
+    /// [`DisplayPrecision::real`] will format a `value` with the current [`DisplayPrecision`].
+    pub(crate) fn real(value: f64) -> String {
+        match Self::digits() {
+            Some(digits) => format!("{value:.digits$}"),
+            None => value.to_string(),
+        }
+    }
+
+    /// [`DisplayPrecision::part`] will format one part of a complex number with the current
+    /// [`DisplayPrecision`], and returns [`None`] if it displays as zero.
+    fn part(value: f64) -> Option<String> {
+        let formatted = Self::real(value);
+        if formatted.trim_start_matches('-').parse::<f64>() == Ok(0.0) {
+            None
+        } else {
+            Some(formatted)
+        }
+    }
+}
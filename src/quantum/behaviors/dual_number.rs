@@ -0,0 +1,150 @@
+use std::ops;
+
+use crate::quantum::types::dual_number::DualNumber;
+
+impl DualNumber {
+    /// [`DualNumber::constant`] will create a [`DualNumber`] that does not depend on any of
+    /// the `parameters`, so all of it's derivatives are zero.
+    ///
+    /// # Example
+    /// [`DualNumber::constant`] can be used to create a constant [`DualNumber`]:
+    /// ```rust
+    /// use rquant::quantum::types::dual_number::DualNumber;
+    ///
+    /// fn create_constant_one(parameters: usize) -> DualNumber {
+    ///     DualNumber::constant(1.0, parameters)
+    /// }
+    /// ```
+    pub fn constant(value: f64, parameters: usize) -> Self {
+        DualNumber {
+            value,
+            derivatives: vec![0.0; parameters],
+        }
+    }
+
+    /// [`DualNumber::variable`] will create a [`DualNumber`] for the parameter at `index`, so
+    /// it's derivative with respect to itself is one, and every other derivative is zero.
+    ///
+    /// # Example
+    /// [`DualNumber::variable`] can be used to differentiate with respect to an angle:
+    /// ```rust
+    /// use rquant::quantum::types::dual_number::DualNumber;
+    ///
+    /// fn create_angle_parameter(angle: f64) -> DualNumber {
+    ///     DualNumber::variable(angle, 0, 1)
+    /// }
+    /// ```
+    pub fn variable(value: f64, index: usize, parameters: usize) -> Self {
+        let mut derivatives = vec![0.0; parameters];
+        derivatives[index] = 1.0;
+        DualNumber { value, derivatives }
+    }
+
+    /// [`DualNumber::sin`] will get the sine of a [`DualNumber`], in radians.
+    ///
+    /// # Example
+    /// [`DualNumber::sin`] can be used to get the sine and it's derivative:
+    /// ```rust
+    /// use rquant::quantum::types::dual_number::DualNumber;
+    ///
+    /// fn sine_of_angle(angle: &DualNumber) -> DualNumber {
+    ///     angle.sin()
+    /// }
+    /// ```
+    pub fn sin(&self) -> Self {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    /// [`DualNumber::cos`] will get the cosine of a [`DualNumber`], in radians.
+    ///
+    /// # Example
+    /// [`DualNumber::cos`] can be used to get the cosine and it's derivative:
+    /// ```rust
+    /// use rquant::quantum::types::dual_number::DualNumber;
+    ///
+    /// fn cosine_of_angle(angle: &DualNumber) -> DualNumber {
+    ///     angle.cos()
+    /// }
+    /// ```
+    pub fn cos(&self) -> Self {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    /// [`DualNumber::scale`] will multiply a [`DualNumber`] by a constant `factor`.
+    ///
+    /// # Example
+    /// [`DualNumber::scale`] can be used to halve an angle:
+    /// ```rust
+    /// use rquant::quantum::types::dual_number::DualNumber;
+    ///
+    /// fn half_angle(angle: &DualNumber) -> DualNumber {
+    ///     angle.scale(0.5)
+    /// }
+    /// ```
+    pub fn scale(&self, factor: f64) -> Self {
+        self.chain(self.value * factor, factor)
+    }
+
+    /// [`DualNumber::chain`] will apply the chain rule to create a [`DualNumber`] with a new
+    /// `value`, given the `slope` of the function that produced it.
+    fn chain(&self, value: f64, slope: f64) -> Self {
+        DualNumber {
+            value,
+            derivatives: self.derivatives.iter().map(|d| d * slope).collect(),
+        }
+    }
+}
+
+/// Implement the `+` operator for [`DualNumber`].
+impl ops::Add for &DualNumber {
+    type Output = DualNumber;
+
+    /// Adds the values and derivatives of two [`DualNumbers`](DualNumber).
+    fn add(self, other: &DualNumber) -> DualNumber {
+        DualNumber {
+            value: self.value + other.value,
+            derivatives: self
+                .derivatives
+                .iter()
+                .zip(&other.derivatives)
+                .map(|(a, b)| a + b)
+                .collect(),
+        }
+    }
+}
+
+/// Implement the `-` operator for [`DualNumber`].
+impl ops::Sub for &DualNumber {
+    type Output = DualNumber;
+
+    /// Subtracts the values and derivatives of two [`DualNumbers`](DualNumber).
+    fn sub(self, other: &DualNumber) -> DualNumber {
+        DualNumber {
+            value: self.value - other.value,
+            derivatives: self
+                .derivatives
+                .iter()
+                .zip(&other.derivatives)
+                .map(|(a, b)| a - b)
+                .collect(),
+        }
+    }
+}
+
+/// Implement the `*` operator for [`DualNumber`].
+impl ops::Mul for &DualNumber {
+    type Output = DualNumber;
+
+    /// Multiplies two [`DualNumbers`](DualNumber) using the product rule.
+    fn mul(self, other: &DualNumber) -> DualNumber {
+        DualNumber {
+            value: self.value * other.value,
+            derivatives: self
+                .derivatives
+                .iter()
+                .zip(&other.derivatives)
+                .map(|(a, b)| a.mul_add(other.value, self.value * b))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::{error, fmt};
+
+use crate::quantum::types::gate_name_error::GateNameError;
+
+/// Implement the [`fmt::Display`] trait for [`GateNameError`].
+impl fmt::Display for GateNameError {
+    /// Will return a short description of why a gate name could not be read:
+    /// ```text
+    /// unsupported gate "ccx"
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`GateNameError`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_gate::QuantumGate;
+    ///
+    /// fn print_gate_error(name: &str) {
+    ///     if let Err(error) = QuantumGate::from_name(name) {
+    ///         println!("{error}");
+    ///     }
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GateNameError::Unknown(name) => write!(f, "unsupported gate \"{name}\""),
+            GateNameError::Parameters {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "gate \"{name}\" takes {expected} parameters, but was given {found}"
+            ),
+            GateNameError::InvalidParameter(parameter) => {
+                write!(f, "invalid gate parameter \"{parameter}\"")
+            }
+        }
+    }
+}
+
+/// Implement the [`error::Error`] trait for [`GateNameError`], so it can be returned with `?`
+/// alongside other errors.
+impl error::Error for GateNameError {}
@@ -0,0 +1,92 @@
+use crate::quantum::types::{
+    gate_recording::GateRecording, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    qubit::Qubit,
+};
+
+impl Qubit {
+    /// [`Qubit::record`] will start a [`GateRecording`] of a [`Qubit`], which remembers every
+    /// [`QuantumGate`] applied to it from now on.
+    ///
+    /// # Example
+    /// [`Qubit::record`] can be used to remember the gates applied to a [`Qubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     gate_recording::GateRecording, quantum_gate::QuantumGate, qubit::Qubit,
+    /// };
+    ///
+    /// fn record_superposition() -> GateRecording {
+    ///     Qubit::zero().record().apply_gate(&QuantumGate::SUPERPOSITION)
+    /// }
+    /// ```
+    pub fn record(&self) -> GateRecording {
+        GateRecording {
+            qubit: self.clone(),
+            operators: vec![],
+        }
+    }
+}
+
+impl GateRecording {
+    /// [`GateRecording::apply_gate`] will apply a [`QuantumGate`] to the [`Qubit`] of a
+    /// [`GateRecording`] and remember it, then return the modified [`GateRecording`].
+    ///
+    /// # Example
+    /// [`GateRecording::apply_gate`] can be used to record a NOT gate:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     gate_recording::GateRecording, quantum_gate::QuantumGate, qubit::Qubit,
+    /// };
+    ///
+    /// fn record_not(qubit: Qubit) -> GateRecording {
+    ///     qubit.record().apply_gate(&QuantumGate::NOT)
+    /// }
+    /// ```
+    pub fn apply_gate(mut self, gate: &QuantumGate) -> Self {
+        self.qubit = self.qubit.apply_gate(gate);
+        self.operators.push(gate.operator.clone());
+        self
+    }
+
+    /// [`GateRecording::replay_on`] will apply every recorded [`QuantumGate`] to another
+    /// [`Qubit`], in order, then return the modified [`Qubit`].
+    ///
+    /// # Example
+    /// [`GateRecording::replay_on`] can be used to apply the same gates to a new [`Qubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{gate_recording::GateRecording, qubit::Qubit};
+    ///
+    /// fn replay_on_one(recording: &GateRecording) -> Qubit {
+    ///     recording.replay_on(&Qubit::one())
+    /// }
+    /// ```
+    pub fn replay_on(&self, qubit: &Qubit) -> Qubit {
+        self.operators
+            .iter()
+            .fold(qubit.clone(), |qubit, operator| {
+                qubit.apply_gate(&QuantumGate::new(operator.clone()))
+            })
+    }
+
+    /// [`GateRecording::to_circuit`] will export every recorded [`QuantumGate`] to a single-qubit
+    /// [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`GateRecording::to_circuit`] can be used to turn gate-by-gate code into a
+    /// [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     gate_recording::GateRecording, quantum_circuit::QuantumCircuit,
+    /// };
+    ///
+    /// fn recorded_circuit(recording: &GateRecording) -> QuantumCircuit {
+    ///     recording.to_circuit()
+    /// }
+    /// ```
+    pub fn to_circuit(&self) -> QuantumCircuit {
+        self.operators
+            .iter()
+            .fold(QuantumCircuit::new(1), |circuit, operator| {
+                circuit.add_gate(&QuantumGate::new(operator.clone()), 0)
+            })
+    }
+}
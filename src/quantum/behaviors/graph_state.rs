@@ -0,0 +1,129 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::quantum::types::{
+    clifford_tableau::CliffordTableau, graph_state::GraphState, quantum_gate::QuantumGate,
+};
+
+impl GraphState {
+    /// [`GraphState::new`] will create a new [`GraphState`] of `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit),
+    /// given the `(a, b)` qubit index pairs of every edge.
+    ///
+    /// # Example
+    /// [`GraphState::new`] can be used to create a triangle [`GraphState`]:
+    /// ```rust
+    /// use rquant::quantum::types::graph_state::GraphState;
+    ///
+    /// fn create_triangle_graph_state() -> GraphState {
+    ///     GraphState::new(3, &[(0, 1), (1, 2), (0, 2)])
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize, adjacency: &[(usize, usize)]) -> Self {
+        // Every edge must connect two different qubits of the graph.
+        assert!(
+            adjacency
+                .iter()
+                .all(|&(a, b)| a < num_qubits && b < num_qubits && a != b),
+            "Invalid graph state edge"
+        );
+
+        let mut matrix = vec![vec![false; num_qubits]; num_qubits];
+        for &(a, b) in adjacency {
+            matrix[a][b] = true;
+            matrix[b][a] = true;
+        }
+        GraphState {
+            num_qubits,
+            adjacency: matrix,
+        }
+    }
+
+    /// [`GraphState::tableau`] will prepare a [`GraphState`] as a [`CliffordTableau`], by applying a
+    /// layer of [`QuantumGate::SUPERPOSITION`] gates followed by a controlled phase for every edge.
+    ///
+    /// # Example
+    /// [`GraphState::tableau`] can be used to inspect the stabilizers of a [`GraphState`]:
+    /// ```rust
+    /// use rquant::quantum::types::{clifford_tableau::CliffordTableau, graph_state::GraphState};
+    ///
+    /// fn prepare_graph_state(graph_state: &GraphState) -> CliffordTableau {
+    ///     graph_state.tableau()
+    /// }
+    /// ```
+    pub fn tableau(&self) -> CliffordTableau {
+        let mut tableau = CliffordTableau::new(self.num_qubits);
+        for qubit in 0..self.num_qubits {
+            tableau.apply_gate(&QuantumGate::SUPERPOSITION, qubit);
+        }
+        for a in 0..self.num_qubits {
+            for b in a + 1..self.num_qubits {
+                if self.adjacency[a][b] {
+                    tableau.apply_cz(a, b);
+                }
+            }
+        }
+        tableau
+    }
+
+    /// [`GraphState::local_complement`] will get the [`GraphState`] made by toggling every edge
+    /// between the neighbors of the [`Qubit`](crate::quantum::types::qubit::Qubit) at `vertex`.
+    ///
+    /// Local complementation can be done with only local Clifford gates, so both
+    /// [`GraphStates`](GraphState) have the same entanglement.
+    ///
+    /// # Example
+    /// [`GraphState::local_complement`] can be used to turn a line into a triangle:
+    /// ```rust
+    /// use rquant::quantum::types::graph_state::GraphState;
+    ///
+    /// fn line_to_triangle() -> GraphState {
+    ///     GraphState::new(3, &[(0, 1), (1, 2)]).local_complement(1)
+    /// }
+    /// ```
+    pub fn local_complement(&self, vertex: usize) -> Self {
+        let mut complement = self.clone();
+        for a in 0..self.num_qubits {
+            for b in 0..self.num_qubits {
+                if a != b && self.adjacency[vertex][a] && self.adjacency[vertex][b] {
+                    complement.adjacency[a][b] = !self.adjacency[a][b];
+                }
+            }
+        }
+        complement
+    }
+
+    /// [`GraphState::is_local_clifford_equivalent`] will determine if two [`GraphStates`](GraphState)
+    /// can be turned into each other using only local Clifford gates, which is the case exactly when
+    /// one graph can be reached from the other by a sequence of [`GraphState::local_complement`].
+    ///
+    /// Every graph reachable by local complementation is searched, so it is intended for small graphs.
+    ///
+    /// # Example
+    /// [`GraphState::is_local_clifford_equivalent`] can be used to compare entanglement:
+    /// ```rust
+    /// use rquant::quantum::types::graph_state::GraphState;
+    ///
+    /// fn have_same_entanglement(a: &GraphState, b: &GraphState) -> bool {
+    ///     a.is_local_clifford_equivalent(b)
+    /// }
+    /// ```
+    pub fn is_local_clifford_equivalent(&self, other: &GraphState) -> bool {
+        if self.num_qubits != other.num_qubits {
+            return false;
+        }
+
+        let mut visited = HashSet::from([self.adjacency.clone()]);
+        let mut queue = VecDeque::from([self.clone()]);
+        while let Some(graph_state) = queue.pop_front() {
+            if graph_state.adjacency == other.adjacency {
+                return true;
+            }
+            for vertex in 0..self.num_qubits {
+                let complement = graph_state.local_complement(vertex);
+                if visited.insert(complement.adjacency.clone()) {
+                    queue.push_back(complement);
+                }
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,607 @@
+use std::{f64::consts::FRAC_PI_2, fmt, ops};
+
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    quantum::types::{
+        hamiltonian::Hamiltonian, pauli::Pauli, pauli_string::PauliString,
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, qubit_register::QubitRegister,
+        state_vector::StateVector,
+    },
+    simulation::behaviors::simulation_outcome::sample_index,
+};
+
+impl Hamiltonian {
+    /// [`Hamiltonian::new`] will create a new [`Hamiltonian`] from the coefficient and
+    /// [`PauliString`] of each of it's `terms`.
+    ///
+    /// # Example
+    /// [`Hamiltonian::new`] can be used to create an Ising coupling with a transverse field:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     hamiltonian::Hamiltonian,
+    ///     pauli::Pauli,
+    ///     pauli_string::PauliString,
+    /// };
+    ///
+    /// fn create_ising_hamiltonian() -> Hamiltonian {
+    ///     Hamiltonian::new(
+    ///         2,
+    ///         &[
+    ///             (1.0, PauliString { negative: false, paulis: vec![Pauli::Z, Pauli::Z] }),
+    ///             (0.5, PauliString { negative: false, paulis: vec![Pauli::X, Pauli::I] }),
+    ///         ],
+    ///     )
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize, terms: &[(f64, PauliString)]) -> Self {
+        // Every term must act on the same qubits as the hamiltonian.
+        assert!(
+            terms
+                .iter()
+                .all(|(_, pauli_string)| pauli_string.paulis.len() == num_qubits),
+            "Invalid hamiltonian term length"
+        );
+
+        Hamiltonian {
+            num_qubits,
+            terms: terms.to_vec(),
+        }
+    }
+
+    /// [`Hamiltonian::parse`] will create a new [`Hamiltonian`] from an `expression` of signed,
+    /// weighted Pauli strings such as `"0.5*ZZ + 0.3*XI"`, where a term without a coefficient has a
+    /// coefficient of `1`, and the letters of each Pauli string are `I`, `X`, `Y`, or `Z`.
+    ///
+    /// # Example
+    /// [`Hamiltonian::parse`] can be used to create an Ising coupling with a transverse field:
+    /// ```rust
+    /// use rquant::quantum::types::observable::Observable;
+    ///
+    /// fn create_ising_observable() -> Observable {
+    ///     Observable::parse("ZZ + 0.5*XI - 0.5*IX")
+    /// }
+    /// ```
+    pub fn parse(expression: &str) -> Self {
+        let characters: Vec<char> = expression
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect();
+        assert!(!characters.is_empty(), "Invalid empty observable");
+
+        let mut terms = vec![];
+        let mut index = 0;
+        while index < characters.len() {
+            let mut coefficient = 1.0;
+            // Every term after the first needs a sign to separate it from the previous term.
+            assert!(
+                terms.is_empty() || matches!(characters[index], '+' | '-'),
+                "Invalid observable term separator"
+            );
+            while index < characters.len() && matches!(characters[index], '+' | '-') {
+                if characters[index] == '-' {
+                    coefficient = -coefficient;
+                }
+                index += 1;
+            }
+
+            let start = index;
+            while index < characters.len()
+                && (characters[index].is_ascii_digit()
+                    || matches!(characters[index], '.' | 'e' | 'E')
+                    || (matches!(characters[index], '+' | '-')
+                        && matches!(characters[index - 1], 'e' | 'E')))
+            {
+                index += 1;
+            }
+            if index > start {
+                let number: String = characters[start..index].iter().collect();
+                coefficient *= number
+                    .parse::<f64>()
+                    .unwrap_or_else(|_| panic!("Invalid observable coefficient {number}"));
+                if index < characters.len() && characters[index] == '*' {
+                    index += 1;
+                }
+            }
+
+            let mut paulis = vec![];
+            while index < characters.len() {
+                let pauli = match characters[index] {
+                    'I' => Pauli::I,
+                    'X' => Pauli::X,
+                    'Y' => Pauli::Y,
+                    'Z' => Pauli::Z,
+                    _ => break,
+                };
+                paulis.push(pauli);
+                index += 1;
+            }
+            assert!(!paulis.is_empty(), "Invalid observable term");
+            terms.push((
+                coefficient,
+                PauliString {
+                    negative: false,
+                    paulis,
+                },
+            ));
+        }
+
+        let num_qubits = terms[0].1.paulis.len();
+        Hamiltonian::new(num_qubits, &terms)
+    }
+
+    /// [`Hamiltonian::simplified`] will get a copy of the [`Hamiltonian`] where every term with the
+    /// same Pauli letters is combined into one term with a positive sign, in the order they first
+    /// appear, and every term with a coefficient of zero is removed.
+    ///
+    /// # Example
+    /// [`Hamiltonian::simplified`] can be used to count the distinct terms of an observable:
+    /// ```rust
+    /// use rquant::quantum::types::observable::Observable;
+    ///
+    /// fn count_terms(observable: &Observable) -> usize {
+    ///     observable.simplified().terms.len()
+    /// }
+    /// ```
+    pub fn simplified(&self) -> Self {
+        let mut terms: Vec<(f64, PauliString)> = vec![];
+        for (coefficient, pauli_string) in &self.terms {
+            let coefficient = if pauli_string.negative {
+                -coefficient
+            } else {
+                *coefficient
+            };
+            match terms
+                .iter_mut()
+                .find(|(_, term)| term.paulis == pauli_string.paulis)
+            {
+                Some((total, _)) => *total += coefficient,
+                None => terms.push((
+                    coefficient,
+                    PauliString {
+                        negative: false,
+                        paulis: pauli_string.paulis.clone(),
+                    },
+                )),
+            }
+        }
+        terms.retain(|(coefficient, _)| coefficient.abs() > 1e-12);
+
+        Hamiltonian {
+            num_qubits: self.num_qubits,
+            terms,
+        }
+    }
+
+    /// [`Hamiltonian::expectation`] will get the expectation value $\langle\psi|H|\psi\rangle$ of the
+    /// [`Hamiltonian`] on a [`StateVector`].
+    ///
+    /// # Example
+    /// [`Hamiltonian::expectation`] can be used to get the energy of a state:
+    /// ```rust
+    /// use rquant::quantum::types::{hamiltonian::Hamiltonian, state_vector::StateVector};
+    ///
+    /// fn get_energy(hamiltonian: &Hamiltonian, state_vector: &StateVector) -> f64 {
+    ///     hamiltonian.expectation(state_vector)
+    /// }
+    /// ```
+    pub fn expectation(&self, state_vector: &StateVector) -> f64 {
+        self.terms
+            .iter()
+            .map(|(coefficient, pauli_string)| coefficient * state_vector.expectation(pauli_string))
+            .sum()
+    }
+
+    /// [`Hamiltonian::register_expectation`] will get the expectation value
+    /// $\langle\psi|H|\psi\rangle$ of the [`Hamiltonian`] on the product state of a
+    /// [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`Hamiltonian::register_expectation`] can be used to measure an observable of a register:
+    /// ```rust
+    /// use rquant::quantum::types::{observable::Observable, qubit_register::QubitRegister};
+    ///
+    /// fn get_magnetization(qubit_register: &QubitRegister) -> f64 {
+    ///     Observable::parse("ZI + IZ").register_expectation(qubit_register)
+    /// }
+    /// ```
+    pub fn register_expectation(&self, qubit_register: &QubitRegister) -> f64 {
+        self.expectation(&StateVector::from_register(qubit_register))
+    }
+
+    /// [`Hamiltonian::measurement_groups`] will split the terms of a [`Hamiltonian`] into groups that
+    /// can all be measured with the same circuit, where every [`PauliString`] in a group qubit-wise
+    /// commutes: on each [`Qubit`](crate::quantum::types::qubit::Qubit), the terms either agree on a
+    /// [`Pauli`] or use [`Pauli::I`].
+    ///
+    /// Terms are placed greedily into the first group they fit, in the order of `terms`.
+    ///
+    /// # Example
+    /// [`Hamiltonian::measurement_groups`] can be used to count the circuits an energy estimate needs:
+    /// ```rust
+    /// use rquant::quantum::types::hamiltonian::Hamiltonian;
+    ///
+    /// fn count_measurement_circuits(hamiltonian: &Hamiltonian) -> usize {
+    ///     hamiltonian.measurement_groups().len()
+    /// }
+    /// ```
+    pub fn measurement_groups(&self) -> Vec<Hamiltonian> {
+        let mut groups: Vec<Hamiltonian> = vec![];
+        for term in &self.terms {
+            let group = groups.iter_mut().find(|group| {
+                group
+                    .terms
+                    .iter()
+                    .all(|(_, pauli_string)| qubit_wise_commute(pauli_string, &term.1))
+            });
+            match group {
+                Some(group) => group.terms.push(term.clone()),
+                None => groups.push(Hamiltonian {
+                    num_qubits: self.num_qubits,
+                    terms: vec![term.clone()],
+                }),
+            }
+        }
+        groups
+    }
+
+    /// [`Hamiltonian::measurement_circuit`] will get the [`QuantumCircuit`] that rotates every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) into the shared basis of the terms of a
+    /// [`Hamiltonian`], so measuring each qubit afterwards measures it's [`Pauli`].
+    ///
+    /// # Example
+    /// [`Hamiltonian::measurement_circuit`] can be used to get one circuit per measurement group:
+    /// ```rust
+    /// use rquant::quantum::types::{hamiltonian::Hamiltonian, quantum_circuit::QuantumCircuit};
+    ///
+    /// fn get_measurement_circuits(hamiltonian: &Hamiltonian) -> Vec<QuantumCircuit> {
+    ///     hamiltonian
+    ///         .measurement_groups()
+    ///         .iter()
+    ///         .map(|group| group.measurement_circuit())
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn measurement_circuit(&self) -> QuantumCircuit {
+        let terms: Vec<&PauliString> = self.terms.iter().map(|(_, term)| term).collect();
+        assert!(
+            terms
+                .iter()
+                .enumerate()
+                .all(|(index, term)| terms[index + 1..]
+                    .iter()
+                    .all(|other| qubit_wise_commute(term, other))),
+            "Hamiltonian terms must qubit-wise commute"
+        );
+
+        (0..self.num_qubits).fold(QuantumCircuit::new(self.num_qubits), |circuit, qubit| {
+            let pauli = terms
+                .iter()
+                .map(|term| term.paulis[qubit])
+                .find(|&pauli| pauli != Pauli::I);
+            match pauli {
+                Some(Pauli::X) => circuit.add_gate(&QuantumGate::SUPERPOSITION, qubit),
+                Some(Pauli::Y) => {
+                    circuit.add_gate(&QuantumGate::new(QuantumOperator::RX(FRAC_PI_2)), qubit)
+                }
+                _ => circuit,
+            }
+        })
+    }
+
+    /// [`Hamiltonian::sampled_expectation`] will estimate [`Hamiltonian::expectation`] from `shots`
+    /// measurements of each of the [`measurement_groups`](Hamiltonian::measurement_groups), like
+    /// an energy estimate on real hardware, where every group needs one circuit instead of every term.
+    ///
+    /// # Example
+    /// [`Hamiltonian::sampled_expectation`] can be used to estimate the energy of a state:
+    /// ```rust
+    /// use rquant::quantum::types::{hamiltonian::Hamiltonian, state_vector::StateVector};
+    ///
+    /// fn estimate_energy(hamiltonian: &Hamiltonian, state_vector: &StateVector) -> f64 {
+    ///     hamiltonian.sampled_expectation(state_vector, 1000)
+    /// }
+    /// ```
+    pub fn sampled_expectation(&self, state_vector: &StateVector, shots: usize) -> f64 {
+        self.sampled_expectation_with(state_vector, shots, &mut rand::rng())
+    }
+
+    /// [`Hamiltonian::sampled_expectation_with`] will estimate [`Hamiltonian::expectation`] in the
+    /// same way as [`Hamiltonian::sampled_expectation`], using a random number generator `rng` for
+    /// the measurements instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`Hamiltonian::sampled_expectation_with`] can be used to reproduce an energy estimate:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{hamiltonian::Hamiltonian, state_vector::StateVector};
+    ///
+    /// fn estimate_seeded_energy(
+    ///     hamiltonian: &Hamiltonian,
+    ///     state_vector: &StateVector,
+    ///     seed: u64,
+    /// ) -> f64 {
+    ///     hamiltonian.sampled_expectation_with(state_vector, 1000, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn sampled_expectation_with(
+        &self,
+        state_vector: &StateVector,
+        shots: usize,
+        rng: &mut impl Rng,
+    ) -> f64 {
+        assert!(
+            state_vector.num_qubits == self.num_qubits,
+            "State vector and hamiltonian must have the same amount of qubits"
+        );
+        assert!(shots > 0, "Sampled expectation needs at least one shot");
+
+        self.measurement_groups()
+            .iter()
+            .map(|group| {
+                // Build the rotated state directly, so an entangled state is not flagged as a clone.
+                let mut rotated = StateVector {
+                    num_qubits: state_vector.num_qubits,
+                    amplitudes: state_vector.amplitudes.clone(),
+                };
+                rotated.apply_circuit(&group.measurement_circuit());
+                let probabilities: Vec<f64> = rotated
+                    .amplitudes
+                    .iter()
+                    .map(|amplitude| amplitude.norm_sqr())
+                    .collect();
+
+                let mut totals = vec![0.0; group.terms.len()];
+                for _ in 0..shots {
+                    let index = sample_index(&probabilities, rng.random());
+                    for (total, (_, pauli_string)) in totals.iter_mut().zip(&group.terms) {
+                        *total += eigenvalue(pauli_string, index);
+                    }
+                }
+                group
+                    .terms
+                    .iter()
+                    .zip(totals)
+                    .map(|((coefficient, _), total)| coefficient * total / shots as f64)
+                    .sum::<f64>()
+            })
+            .sum()
+    }
+
+    /// [`Hamiltonian::matrix`] will get the $2^n \times 2^n$ matrix of the [`Hamiltonian`], where the
+    /// first [`Qubit`](crate::quantum::types::qubit::Qubit) is the most significant bit of each row
+    /// and column.
+    ///
+    /// # Example
+    /// [`Hamiltonian::matrix`] can be used to get the diagonal of a [`Hamiltonian`]:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::hamiltonian::Hamiltonian;
+    ///
+    /// fn get_diagonal(hamiltonian: &Hamiltonian) -> Vec<Complex<f64>> {
+    ///     let matrix = hamiltonian.matrix();
+    ///     (0..matrix.len()).map(|index| matrix[index][index]).collect()
+    /// }
+    /// ```
+    pub fn matrix(&self) -> Vec<Vec<Complex<f64>>> {
+        let dimension = 1 << self.num_qubits;
+        let mut matrix = vec![vec![Complex::new(0.0, 0.0); dimension]; dimension];
+        for (coefficient, pauli_string) in &self.terms {
+            let images = (0..dimension).map(|column| pauli_string.basis_image(column));
+            for (column, (row, phase)) in images.enumerate() {
+                matrix[row][column] += phase * coefficient;
+            }
+        }
+        matrix
+    }
+
+    /// [`Hamiltonian::propagator`] will get the matrix of $e^{-iHt}$ for a `time` $t$, using a
+    /// Taylor series with scaling and squaring.
+    pub(crate) fn propagator(&self, time: f64) -> Vec<Vec<Complex<f64>>> {
+        let generator: Vec<Vec<Complex<f64>>> = self
+            .matrix()
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| entry * Complex::new(0.0, -time))
+                    .collect()
+            })
+            .collect();
+        matrix_exponential(&generator)
+    }
+}
+
+/// Implement the `+` operator for [`Hamiltonian`].
+impl ops::Add for &Hamiltonian {
+    type Output = Hamiltonian;
+
+    /// Adds the terms of two [`Hamiltonians`](Hamiltonian), combining terms with the same Pauli
+    /// letters.
+    fn add(self, other: &Hamiltonian) -> Hamiltonian {
+        assert!(
+            self.num_qubits == other.num_qubits,
+            "Hamiltonians must have the same amount of qubits"
+        );
+
+        Hamiltonian {
+            num_qubits: self.num_qubits,
+            terms: self.terms.iter().chain(&other.terms).cloned().collect(),
+        }
+        .simplified()
+    }
+}
+
+/// Implement the `-` operator for [`Hamiltonian`].
+impl ops::Sub for &Hamiltonian {
+    type Output = Hamiltonian;
+
+    /// Subtracts the terms of two [`Hamiltonians`](Hamiltonian), combining terms with the same
+    /// Pauli letters.
+    fn sub(self, other: &Hamiltonian) -> Hamiltonian {
+        self + &-other
+    }
+}
+
+/// Implement the `*` operator for [`Hamiltonian`].
+impl ops::Mul<f64> for &Hamiltonian {
+    type Output = Hamiltonian;
+
+    /// Scales every coefficient of a [`Hamiltonian`] by a `factor`.
+    fn mul(self, factor: f64) -> Hamiltonian {
+        Hamiltonian {
+            num_qubits: self.num_qubits,
+            terms: self
+                .terms
+                .iter()
+                .map(|(coefficient, pauli_string)| (coefficient * factor, pauli_string.clone()))
+                .collect(),
+        }
+    }
+}
+
+/// Implement the unary `-` operator for [`Hamiltonian`].
+impl ops::Neg for &Hamiltonian {
+    type Output = Hamiltonian;
+
+    /// Negates every coefficient of a [`Hamiltonian`].
+    fn neg(self) -> Hamiltonian {
+        self * -1.0
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`Hamiltonian`].
+impl fmt::Display for Hamiltonian {
+    /// Will return the weighted Pauli strings of a [`Hamiltonian`] in the form read by
+    /// [`Hamiltonian::parse`], or `0` without any terms:
+    /// ```text
+    /// 0.5*ZZ - 0.3*XI
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print an [`Observable`](crate::quantum::types::observable::Observable) to
+    /// the console:
+    /// ```rust
+    /// use rquant::quantum::types::observable::Observable;
+    ///
+    /// fn print_observable(observable: Observable) {
+    ///     println!("{observable}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (index, (coefficient, pauli_string)) in self.terms.iter().enumerate() {
+            let coefficient = if pauli_string.negative {
+                -coefficient
+            } else {
+                *coefficient
+            };
+            match (index, coefficient < 0.0) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+            write!(f, "{}*", coefficient.abs())?;
+            for pauli in &pauli_string.paulis {
+                write!(f, "{pauli}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// [`matrix_multiply`] will multiply two square matrices.
+pub(crate) fn matrix_multiply(
+    a: &[Vec<Complex<f64>>],
+    b: &[Vec<Complex<f64>>],
+) -> Vec<Vec<Complex<f64>>> {
+    a.iter()
+        .map(|row| {
+            (0..b.len())
+                .map(|column| {
+                    row.iter()
+                        .zip(b)
+                        .map(|(entry, b_row)| entry * b_row[column])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// [`matrix_exponential`] will get $e^{A}$ of a square `generator` matrix $A$, using a Taylor
+/// series with scaling and squaring.
+pub(crate) fn matrix_exponential(generator: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    // Scale the generator down until it's norm is small, so the series converges quickly.
+    let norm: f64 = generator
+        .iter()
+        .map(|row| row.iter().map(|entry| entry.norm()).sum::<f64>())
+        .fold(0.0, f64::max);
+    let squarings = norm.max(1.0).log2().ceil() as i32 + 1;
+    let scale = 0.5_f64.powi(squarings);
+
+    let dimension = generator.len();
+    let identity: Vec<Vec<Complex<f64>>> = (0..dimension)
+        .map(|row| {
+            (0..dimension)
+                .map(|column| Complex::new(if row == column { 1.0 } else { 0.0 }, 0.0))
+                .collect()
+        })
+        .collect();
+    let mut term = identity.clone();
+    let mut propagator = identity;
+    for order in 1..=20 {
+        term = matrix_multiply(&term, generator)
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|entry| entry * scale / order as f64)
+                    .collect()
+            })
+            .collect();
+        for (row, term_row) in propagator.iter_mut().zip(&term) {
+            for (entry, term_entry) in row.iter_mut().zip(term_row) {
+                *entry += term_entry;
+            }
+        }
+    }
+    for _ in 0..squarings {
+        propagator = matrix_multiply(&propagator, &propagator);
+    }
+    propagator
+}
+
+/// [`qubit_wise_commute`] will check if two [`PauliStrings`](PauliString) agree on every
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) that neither leaves as [`Pauli::I`].
+fn qubit_wise_commute(first: &PauliString, second: &PauliString) -> bool {
+    first
+        .paulis
+        .iter()
+        .zip(&second.paulis)
+        .all(|(a, b)| a == b || *a == Pauli::I || *b == Pauli::I)
+}
+
+/// [`eigenvalue`] will get the $\pm 1$ value of a [`PauliString`] for a measured basis state `index`,
+/// after rotating into it's basis, where the first [`Qubit`](crate::quantum::types::qubit::Qubit) is
+/// the most significant bit.
+fn eigenvalue(pauli_string: &PauliString, index: usize) -> f64 {
+    let num_qubits = pauli_string.paulis.len();
+    let flips = pauli_string
+        .paulis
+        .iter()
+        .enumerate()
+        .filter(|(qubit, pauli)| {
+            **pauli != Pauli::I && index & (1 << (num_qubits - 1 - qubit)) != 0
+        })
+        .count();
+    let sign = if pauli_string.negative { -1.0 } else { 1.0 };
+    if flips % 2 == 0 {
+        sign
+    } else {
+        -sign
+    }
+}
@@ -0,0 +1,143 @@
+use std::{f64::consts::PI, fmt};
+
+use num_complex::Complex;
+
+use crate::quantum::types::{
+    heatmap::Heatmap, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+};
+
+/// The blocks that shade a magnitude, from `0.0` up to `1.0` in quarters.
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// The arrows that show a phase, counter-clockwise from `0` in eighths of a turn.
+const PHASES: [char; 8] = ['→', '↗', '↑', '↖', '←', '↙', '↓', '↘'];
+
+impl Heatmap {
+    /// [`Heatmap::new`] will create a new [`Heatmap`] of a square `matrix` whose size is a power
+    /// of two.
+    ///
+    /// # Example
+    /// [`Heatmap::new`] can be used to render the matrix of a
+    /// [`Hamiltonian`](crate::quantum::types::hamiltonian::Hamiltonian):
+    /// ```rust
+    /// use rquant::quantum::types::{hamiltonian::Hamiltonian, heatmap::Heatmap};
+    ///
+    /// fn render_hamiltonian(hamiltonian: &Hamiltonian) -> String {
+    ///     Heatmap::new(hamiltonian.matrix()).to_string()
+    /// }
+    /// ```
+    pub fn new(matrix: Vec<Vec<Complex<f64>>>) -> Self {
+        assert!(
+            matrix.len().is_power_of_two() && matrix.iter().all(|row| row.len() == matrix.len()),
+            "Heatmap matrix must be square with a power of two size"
+        );
+
+        Heatmap { matrix }
+    }
+}
+
+impl QuantumGate {
+    /// [`QuantumGate::heatmap`] will get a [`Heatmap`] of the `transform` of a [`QuantumGate`].
+    ///
+    /// # Example
+    /// [`QuantumGate::heatmap`] can be used to print a gate as a heatmap:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_gate::QuantumGate;
+    ///
+    /// fn print_superposition_heatmap() {
+    ///     println!("{}", QuantumGate::SUPERPOSITION.heatmap());
+    /// }
+    /// ```
+    pub fn heatmap(&self) -> Heatmap {
+        Heatmap::new(self.matrix())
+    }
+}
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::heatmap`] will get a [`Heatmap`] of the [`QuantumCircuit::unitary`] of a
+    /// [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::heatmap`] can be used to inspect a two-qubit circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn print_two_qubit_heatmap() {
+    ///     let circuit = QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::PHASE, 1);
+    ///     println!("{}", circuit.heatmap());
+    /// }
+    /// ```
+    pub fn heatmap(&self) -> Heatmap {
+        Heatmap::new(self.unitary())
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`Heatmap`].
+impl fmt::Display for Heatmap {
+    /// Gets the [`String`] representation of a [`Heatmap`], with a row for every basis state that
+    /// shows the shade and phase arrow of each element, followed by a legend. For instance,
+    /// [`QuantumGate::SUPERPOSITION`] will be presented as:
+    /// ```text
+    ///      |0〉 |1〉
+    /// |0〉  ▓→  ▓→
+    /// |1〉  ▓→  ▓←
+    ///
+    /// magnitude:   0  ░ ≤0.25  ▒ ≤0.50  ▓ ≤0.75  █ ≤1.00
+    /// phase: → 0  ↗ π/4  ↑ π/2  ↖ 3π/4  ← π  ↙ -3π/4  ↓ -π/2  ↘ -π/4
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`Heatmap`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::heatmap::Heatmap;
+    ///
+    /// fn print_heatmap(heatmap: Heatmap) {
+    ///     println!("{heatmap}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self.matrix.len().trailing_zeros() as usize;
+        let label = |index: usize| format!("|{index:0width$b}〉");
+        // Every column is as wide as it's label, plus a space between columns.
+        let column_width = width + 3;
+
+        write!(f, "{:prefix$}", "", prefix = width + 4)?;
+        for column in 0..self.matrix.len() {
+            write!(f, "{:<column_width$}", label(column))?;
+        }
+        writeln!(f)?;
+        for (row, values) in self.matrix.iter().enumerate() {
+            write!(f, "{}  ", label(row))?;
+            for value in values {
+                write!(f, "{:<column_width$}", cell(*value))?;
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(
+            f,
+            "magnitude: {} 0  {} ≤0.25  {} ≤0.50  {} ≤0.75  {} ≤1.00",
+            SHADES[0], SHADES[1], SHADES[2], SHADES[3], SHADES[4]
+        )?;
+        write!(
+            f,
+            "phase: {} 0  {} π/4  {} π/2  {} 3π/4  {} π  {} -3π/4  {} -π/2  {} -π/4",
+            PHASES[0], PHASES[1], PHASES[2], PHASES[3], PHASES[4], PHASES[5], PHASES[6], PHASES[7]
+        )
+    }
+}
+
+/// [`cell`] will get the shade of the magnitude and the phase arrow of a matrix element, leaving
+/// out the arrow for elements that are zero.
+fn cell(value: Complex<f64>) -> String {
+    let magnitude = value.norm();
+    if magnitude < 1e-9 {
+        return SHADES[0].to_string();
+    }
+    let shade = SHADES[((magnitude * 4.0).ceil() as usize).clamp(1, 4)];
+    let eighth = (value.arg() / (PI / 4.0)).round() as i64;
+    let phase = PHASES[eighth.rem_euclid(8) as usize];
+    format!("{shade}{phase}")
+}
@@ -0,0 +1,649 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    quantum::types::{
+        matrix_product_state::MatrixProductState, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate,
+    },
+    simulation::types::backend::Backend,
+};
+
+/// The tensor of one [`Qubit`](crate::quantum::types::qubit::Qubit), indexed by it's basis state,
+/// then the left bond, then the right bond.
+type Tensor = Vec<Vec<Vec<Complex<f64>>>>;
+
+/// Singular values below this are treated as zero and dropped from a bond.
+const SINGULAR_VALUE_CUTOFF: f64 = 1e-12;
+
+impl MatrixProductState {
+    /// [`MatrixProductState::new`] will create a new [`MatrixProductState`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), all initialized as $|0\rangle$, that keeps
+    /// at most `max_bond_dimension` singular values between neighboring qubits.
+    ///
+    /// # Example
+    /// [`MatrixProductState::new`] can be used to simulate more qubits than a state vector can hold:
+    /// ```rust
+    /// use rquant::quantum::types::matrix_product_state::MatrixProductState;
+    ///
+    /// fn create_large_state() -> MatrixProductState {
+    ///     MatrixProductState::new(100, 16)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize, max_bond_dimension: usize) -> Self {
+        assert!(max_bond_dimension > 0, "Bond dimension must be at least 1");
+
+        let zero = vec![
+            vec![vec![Complex::new(1.0, 0.0)]],
+            vec![vec![Complex::new(0.0, 0.0)]],
+        ];
+        MatrixProductState {
+            num_qubits,
+            max_bond_dimension,
+            tensors: vec![zero; num_qubits],
+            truncation_error: 0.0,
+        }
+    }
+
+    /// [`MatrixProductState::bond_dimensions`] will get the size of every bond between neighboring
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), which grows with their entanglement.
+    ///
+    /// # Example
+    /// [`MatrixProductState::bond_dimensions`] can be used to find the most entangled bond:
+    /// ```rust
+    /// use rquant::quantum::types::matrix_product_state::MatrixProductState;
+    ///
+    /// fn largest_bond(state: &MatrixProductState) -> usize {
+    ///     state.bond_dimensions().into_iter().max().unwrap_or(1)
+    /// }
+    /// ```
+    pub fn bond_dimensions(&self) -> Vec<usize> {
+        self.tensors
+            .iter()
+            .skip(1)
+            .map(|tensor| tensor[0].len())
+            .collect()
+    }
+
+    /// [`MatrixProductState::apply_gate`] will apply a [`QuantumGate`] to the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`, in the same way as
+    /// [`StateVector::apply_gate`](crate::quantum::types::state_vector::StateVector::apply_gate).
+    ///
+    /// # Example
+    /// [`MatrixProductState::apply_gate`] can be used to put the first qubit into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn superposition_first_qubit(state: &mut MatrixProductState) {
+    ///     state.apply_gate(&QuantumGate::SUPERPOSITION, 0)
+    /// }
+    /// ```
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        assert!(
+            target < self.num_qubits,
+            "Invalid matrix product state qubit index"
+        );
+
+        let matrix = gate.matrix();
+        let tensor = &self.tensors[target];
+        let updated: Tensor = (0..2)
+            .map(|state| {
+                (0..tensor[0].len())
+                    .map(|left| {
+                        (0..tensor[0][0].len())
+                            .map(|right| {
+                                matrix[state][0] * tensor[0][left][right]
+                                    + matrix[state][1] * tensor[1][left][right]
+                            })
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+        self.tensors[target] = updated;
+    }
+
+    /// [`MatrixProductState::apply_circuit`] will apply every
+    /// [`QuantumOperation`](crate::quantum::types::quantum_operation::QuantumOperation) of a
+    /// [`QuantumCircuit`] to a [`MatrixProductState`], in order.
+    ///
+    /// # Example
+    /// [`MatrixProductState::apply_circuit`] can be used to run a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn run_superposition_circuit() -> MatrixProductState {
+    ///     let mut state = MatrixProductState::new(1, 4);
+    ///     state.apply_circuit(&QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0));
+    ///     state
+    /// }
+    /// ```
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) {
+        assert!(
+            circuit.num_qubits == self.num_qubits,
+            "Circuit must operate on every matrix product state qubit"
+        );
+
+        for operation in &circuit.operations {
+            Backend::apply_operation(self, operation);
+        }
+    }
+
+    /// [`MatrixProductState::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), in the same way as
+    /// [`StateVector::apply_cnot`](crate::quantum::types::state_vector::StateVector::apply_cnot).
+    ///
+    /// Qubits that are not neighbors are swapped next to each other first, then swapped back.
+    ///
+    /// # Example
+    /// [`MatrixProductState::apply_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn create_bell_state() -> MatrixProductState {
+    ///     let mut state = MatrixProductState::new(2, 2);
+    ///     state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state.apply_cnot(0, 1);
+    ///     state
+    /// }
+    /// ```
+    pub fn apply_cnot(&mut self, control: usize, target: usize) {
+        assert!(
+            control != target && control < self.num_qubits && target < self.num_qubits,
+            "Invalid matrix product state qubit index"
+        );
+
+        // Move the control next to the target, one neighboring swap at a time.
+        let neighbor = if control < target {
+            target - 1
+        } else {
+            target + 1
+        };
+        let path: Vec<usize> = if control < neighbor {
+            (control..neighbor).collect()
+        } else {
+            (neighbor..control).rev().collect()
+        };
+        for &site in &path {
+            self.apply_two_qubit_matrix(&swap_matrix(), site);
+        }
+        if neighbor < target {
+            self.apply_two_qubit_matrix(&cnot_matrix(true), neighbor);
+        } else {
+            self.apply_two_qubit_matrix(&cnot_matrix(false), target);
+        }
+        for &site in path.iter().rev() {
+            self.apply_two_qubit_matrix(&swap_matrix(), site);
+        }
+    }
+
+    /// [`MatrixProductState::measure`] will measure the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target`, collapse the [`MatrixProductState`] to the observed state, and return a
+    /// [`bool`] for it's "truthy" state, in the same way as
+    /// [`StateVector::measure`](crate::quantum::types::state_vector::StateVector::measure).
+    ///
+    /// # Example
+    /// [`MatrixProductState::measure`] can be used to observe both qubits of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn observe_bell_state() -> (bool, bool) {
+    ///     let mut state = MatrixProductState::new(2, 2);
+    ///     state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state.apply_cnot(0, 1);
+    ///     (state.measure(0), state.measure(1))
+    /// }
+    /// ```
+    pub fn measure(&mut self, target: usize) -> bool {
+        self.measure_with(target, &mut rand::rng())
+    }
+
+    /// [`MatrixProductState::measure_with`] will measure the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` in the same way as
+    /// [`MatrixProductState::measure`], using a random number generator `rng` instead of the thread's
+    /// generator, so a seeded `rng` always collapses to the same state.
+    ///
+    /// # Example
+    /// [`MatrixProductState::measure_with`] can be used to reproduce a measurement of a Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn observe_seeded_bell_state(seed: u64) -> bool {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     let mut state = MatrixProductState::new(2, 2);
+    ///     state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state.apply_cnot(0, 1);
+    ///     state.measure_with(0, &mut rng)
+    /// }
+    /// ```
+    pub fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        assert!(
+            target < self.num_qubits,
+            "Invalid matrix product state qubit index"
+        );
+
+        let prob_zero = self.probability_of(target, 0) / self.probability_of(target, 2);
+        let is_zero = rng.random_bool(prob_zero.clamp(0.0, 1.0));
+
+        let (kept, dropped) = if is_zero { (0, 1) } else { (1, 0) };
+        let norm = if is_zero { prob_zero } else { 1.0 - prob_zero }.sqrt();
+        let tensor = &mut self.tensors[target];
+        for row in tensor[kept].iter_mut().flatten() {
+            *row /= norm;
+        }
+        for row in tensor[dropped].iter_mut().flatten() {
+            *row = Complex::new(0.0, 0.0);
+        }
+        is_zero
+    }
+
+    /// [`MatrixProductState::amplitude`] will get the [`Complex<f64>`] amplitude of one computational
+    /// basis state, where the first [`Qubit`](crate::quantum::types::qubit::Qubit) is the most
+    /// significant bit of the `index`.
+    ///
+    /// # Example
+    /// [`MatrixProductState::amplitude`] can be used to get the amplitude of $|0\ldots0\rangle$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::matrix_product_state::MatrixProductState;
+    ///
+    /// fn all_zero_amplitude(state: &MatrixProductState) -> Complex<f64> {
+    ///     state.amplitude(0)
+    /// }
+    /// ```
+    pub fn amplitude(&self, index: usize) -> Complex<f64> {
+        let mut row = vec![Complex::new(1.0, 0.0)];
+        for (qubit, tensor) in self.tensors.iter().enumerate() {
+            let state = (index >> (self.num_qubits - 1 - qubit)) & 1;
+            row = (0..tensor[state][0].len())
+                .map(|right| {
+                    row.iter()
+                        .zip(&tensor[state])
+                        .map(|(value, left)| value * left[right])
+                        .sum()
+                })
+                .collect();
+        }
+        row[0]
+    }
+
+    /// [`MatrixProductState::sample_with`] will sample a measurement of every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) of a [`MatrixProductState`] without
+    /// collapsing it, using a random number generator `rng`, and return a [`bool`] for the "truthy"
+    /// state of each one.
+    ///
+    /// Each [`Qubit`](crate::quantum::types::qubit::Qubit) is sampled in order, conditioned on the
+    /// ones before it, so a shot costs a sweep over the tensors instead of the $2^n$ amplitudes.
+    ///
+    /// # Example
+    /// [`MatrixProductState::sample_with`] can be used to take many shots of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     matrix_product_state::MatrixProductState,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn sample_bell_state() -> Vec<Vec<bool>> {
+    ///     let mut state = MatrixProductState::new(2, 2);
+    ///     state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state.apply_cnot(0, 1);
+    ///     (0..100)
+    ///         .map(|_| state.sample_with(&mut rand::rng()))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn sample_with(&self, rng: &mut impl Rng) -> Vec<bool> {
+        self.sample_from(&self.right_environments(), rng)
+    }
+
+    /// [`MatrixProductState::right_environments`] will contract the [`MatrixProductState`] with
+    /// it's own conjugate from the right, and get the environment to the right of every bond, where
+    /// the last one is the trivial environment past the last
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit).
+    pub(crate) fn right_environments(&self) -> Vec<Vec<Vec<Complex<f64>>>> {
+        let mut environments = vec![vec![vec![Complex::new(1.0, 0.0)]]];
+        for tensor in self.tensors.iter().rev() {
+            let environment = environments.last().expect("Missing right environment");
+            let left = tensor[0].len();
+            let mut next = vec![vec![Complex::new(0.0, 0.0); left]; left];
+            for matrix in tensor {
+                // Contract the environment with the ket first, then with the bra.
+                let half: Vec<Vec<Complex<f64>>> = matrix
+                    .iter()
+                    .map(|ket| {
+                        (0..environment.len())
+                            .map(|bra_right| {
+                                ket.iter()
+                                    .zip(environment)
+                                    .map(|(value, row)| value * row[bra_right])
+                                    .sum()
+                            })
+                            .collect()
+                    })
+                    .collect();
+                for (half_row, next_row) in half.iter().zip(next.iter_mut()) {
+                    for (bra, entry) in matrix.iter().zip(next_row.iter_mut()) {
+                        *entry += half_row
+                            .iter()
+                            .zip(bra)
+                            .map(|(value, bra_value)| value * bra_value.conj())
+                            .sum::<Complex<f64>>();
+                    }
+                }
+            }
+            environments.push(next);
+        }
+        environments.reverse();
+        environments
+    }
+
+    /// [`MatrixProductState::sample_from`] will sample every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) of a [`MatrixProductState`] in order with a
+    /// random number generator `rng`, using it's
+    /// [`right_environments`](MatrixProductState::right_environments) to get the probability of
+    /// each one given the [`Qubits`](crate::quantum::types::qubit::Qubit) before it.
+    pub(crate) fn sample_from(
+        &self,
+        environments: &[Vec<Vec<Complex<f64>>>],
+        rng: &mut impl Rng,
+    ) -> Vec<bool> {
+        let mut row = vec![Complex::new(1.0, 0.0)];
+        let mut values = Vec::with_capacity(self.num_qubits);
+        for (tensor, environment) in self.tensors.iter().zip(&environments[1..]) {
+            let [zero, one] = [0, 1].map(|state| {
+                let next: Vec<Complex<f64>> = (0..tensor[state][0].len())
+                    .map(|right| {
+                        row.iter()
+                            .zip(&tensor[state])
+                            .map(|(value, left)| value * left[right])
+                            .sum()
+                    })
+                    .collect();
+                let probability: Complex<f64> = next
+                    .iter()
+                    .zip(environment)
+                    .map(|(ket, environment_row)| {
+                        ket * environment_row
+                            .iter()
+                            .zip(&next)
+                            .map(|(entry, bra)| entry * bra.conj())
+                            .sum::<Complex<f64>>()
+                    })
+                    .sum();
+                (next, probability.re)
+            });
+            let prob_zero = zero.1 / (zero.1 + one.1);
+            let is_zero = rng.random_bool(prob_zero.clamp(0.0, 1.0));
+            row = if is_zero { zero.0 } else { one.0 };
+            values.push(is_zero);
+        }
+        values
+    }
+
+    /// [`MatrixProductState::probability_of`] will get the unnormalized probability of the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at `target` being in the basis `state`, or the
+    /// squared norm of the whole [`MatrixProductState`] for any other `state`.
+    fn probability_of(&self, target: usize, state: usize) -> f64 {
+        // Contract the state with it's own conjugate, one qubit at a time from the left.
+        let mut environment = vec![vec![Complex::new(1.0, 0.0)]];
+        for (qubit, tensor) in self.tensors.iter().enumerate() {
+            let states: Vec<usize> = if qubit == target && state < 2 {
+                vec![state]
+            } else {
+                vec![0, 1]
+            };
+            let right = tensor[0][0].len();
+            let mut next = vec![vec![Complex::new(0.0, 0.0); right]; right];
+            for &basis in &states {
+                let matrix = &tensor[basis];
+                // Contract the environment with the bra first, then with the ket.
+                let half: Vec<Vec<Complex<f64>>> = (0..right)
+                    .map(|bra| {
+                        (0..matrix.len())
+                            .map(|ket_left| {
+                                (0..matrix.len())
+                                    .map(|bra_left| {
+                                        matrix[bra_left][bra].conj()
+                                            * environment[bra_left][ket_left]
+                                    })
+                                    .sum()
+                            })
+                            .collect()
+                    })
+                    .collect();
+                for (bra, half_row) in half.iter().enumerate() {
+                    for (ket, entry) in next[bra].iter_mut().enumerate() {
+                        *entry += half_row
+                            .iter()
+                            .zip(matrix)
+                            .map(|(value, ket_left)| value * ket_left[ket])
+                            .sum::<Complex<f64>>();
+                    }
+                }
+            }
+            environment = next;
+        }
+        environment[0][0].re
+    }
+
+    /// [`MatrixProductState::apply_two_qubit_matrix`] will apply a $4 \times 4$ `matrix` to the
+    /// neighboring [`Qubits`](crate::quantum::types::qubit::Qubit) at `site` and `site + 1`, then
+    /// split them back apart with a singular value decomposition, keeping at most
+    /// `max_bond_dimension` singular values.
+    fn apply_two_qubit_matrix(&mut self, matrix: &[[f64; 4]; 4], site: usize) {
+        let (first, second) = (&self.tensors[site], &self.tensors[site + 1]);
+        let (left, middle, right) = (first[0].len(), second[0].len(), second[0][0].len());
+
+        // Contract both tensors over their shared bond, then apply the matrix to their states.
+        let contracted = |a: usize, b: usize, l: usize, r: usize| -> Complex<f64> {
+            (0..middle).map(|m| first[a][l][m] * second[b][m][r]).sum()
+        };
+        let mut theta = vec![vec![Complex::new(0.0, 0.0); 2 * right]; 2 * left];
+        for a in 0..2 {
+            for b in 0..2 {
+                for l in 0..left {
+                    for r in 0..right {
+                        let value = contracted(a, b, l, r);
+                        for (output, matrix_row) in matrix.iter().enumerate() {
+                            let weight = matrix_row[2 * a + b];
+                            if weight != 0.0 {
+                                theta[(output / 2) * left + l][(output % 2) * right + r] +=
+                                    value * weight;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let (u, singular_values, v) = singular_value_decomposition(&theta);
+        let total: f64 = singular_values.iter().map(|value| value * value).sum();
+        let kept: Vec<usize> = (0..singular_values.len())
+            .filter(|&index| singular_values[index] > SINGULAR_VALUE_CUTOFF)
+            .take(self.max_bond_dimension)
+            .collect();
+        let kept_weight: f64 = kept
+            .iter()
+            .map(|&index| singular_values[index].powi(2))
+            .sum();
+        self.truncation_error += (total - kept_weight).max(0.0) / total;
+        let scale = (total / kept_weight).sqrt();
+
+        self.tensors[site] = (0..2)
+            .map(|a| {
+                (0..left)
+                    .map(|l| kept.iter().map(|&k| u[a * left + l][k]).collect())
+                    .collect()
+            })
+            .collect();
+        self.tensors[site + 1] = (0..2)
+            .map(|b| {
+                kept.iter()
+                    .map(|&k| {
+                        (0..right)
+                            .map(|r| v[b * right + r][k].conj() * singular_values[k] * scale)
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+}
+
+/// [`swap_matrix`] will get the matrix that swaps two neighboring qubits.
+fn swap_matrix() -> [[f64; 4]; 4] {
+    [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]
+}
+
+/// [`cnot_matrix`] will get the controlled NOT matrix of two neighboring qubits, where the control
+/// is the first qubit if `control_first` is `true`, and the second qubit otherwise.
+fn cnot_matrix(control_first: bool) -> [[f64; 4]; 4] {
+    if control_first {
+        [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0, 0.0],
+        ]
+    } else {
+        [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+        ]
+    }
+}
+
+/// [`singular_value_decomposition`] will split a `matrix` into $U \Sigma V^\dagger$ with one-sided
+/// Jacobi rotations, returning the columns of $U$, the singular values in descending order, and
+/// the columns of $V$.
+#[allow(clippy::type_complexity)]
+fn singular_value_decomposition(
+    matrix: &[Vec<Complex<f64>>],
+) -> (Vec<Vec<Complex<f64>>>, Vec<f64>, Vec<Vec<Complex<f64>>>) {
+    let (rows, columns) = (matrix.len(), matrix[0].len());
+    let mut a = matrix.to_vec();
+    let mut v: Vec<Vec<Complex<f64>>> = (0..columns)
+        .map(|row| {
+            (0..columns)
+                .map(|column| Complex::new(if row == column { 1.0 } else { 0.0 }, 0.0))
+                .collect()
+        })
+        .collect();
+
+    // Rotate pairs of columns until every pair is orthogonal.
+    for _ in 0..60 {
+        let mut rotated = false;
+        for p in 0..columns {
+            for q in p + 1..columns {
+                let alpha: f64 = (0..rows).map(|row| a[row][p].norm_sqr()).sum();
+                let beta: f64 = (0..rows).map(|row| a[row][q].norm_sqr()).sum();
+                let gamma: Complex<f64> = (0..rows).map(|row| a[row][p].conj() * a[row][q]).sum();
+                if gamma.norm() <= 1e-15 * (alpha * beta).sqrt().max(1e-300) {
+                    continue;
+                }
+                rotated = true;
+
+                let phase = gamma / gamma.norm();
+                let zeta = (beta - alpha) / (2.0 * gamma.norm());
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+                for row in a.iter_mut().chain(v.iter_mut()) {
+                    let (x, y) = (row[p], row[q] * phase.conj());
+                    row[p] = x * c - y * s;
+                    row[q] = (x * s + y * c) * phase;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut order: Vec<(usize, f64)> = (0..columns)
+        .map(|column| {
+            let norm = (0..rows)
+                .map(|row| a[row][column].norm_sqr())
+                .sum::<f64>()
+                .sqrt();
+            (column, norm)
+        })
+        .collect();
+    order.sort_by(|first, second| second.1.total_cmp(&first.1));
+
+    let u = (0..rows)
+        .map(|row| {
+            order
+                .iter()
+                .map(|&(column, norm)| {
+                    if norm > SINGULAR_VALUE_CUTOFF {
+                        a[row][column] / norm
+                    } else {
+                        Complex::new(0.0, 0.0)
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let sorted_v = (0..columns)
+        .map(|row| order.iter().map(|&(column, _)| v[row][column]).collect())
+        .collect();
+    (u, order.iter().map(|&(_, norm)| norm).collect(), sorted_v)
+}
+
+/// Implement the [`Backend`] trait for [`MatrixProductState`].
+impl Backend for MatrixProductState {
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        MatrixProductState::apply_gate(self, gate, target)
+    }
+
+    fn apply_cnot(&mut self, control: usize, target: usize) {
+        MatrixProductState::apply_cnot(self, control, target)
+    }
+
+    fn apply_cz(&mut self, a: usize, b: usize) {
+        MatrixProductState::apply_gate(self, &QuantumGate::SUPERPOSITION, b);
+        MatrixProductState::apply_cnot(self, a, b);
+        MatrixProductState::apply_gate(self, &QuantumGate::SUPERPOSITION, b);
+    }
+
+    fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        MatrixProductState::measure_with(self, target, rng)
+    }
+
+    fn sample_shots_with(&self, shots: usize, rng: &mut impl Rng) -> Vec<Vec<bool>> {
+        let environments = self.right_environments();
+        (0..shots)
+            .map(|_| self.sample_from(&environments, rng))
+            .collect()
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use crate::quantum::types::{pauli::Pauli, quantum_operators::QuantumOperator};
+
+impl Pauli {
+    /// [`Pauli::operator`] will get the [`QuantumOperator`] that applies a [`Pauli`], and returns
+    /// [`None`] for [`Pauli::I`] because it does not change a [`Qubit`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`Pauli::operator`] can be used to get the [`QuantumOperator`] for a bit flip:
+    /// ```rust
+    /// use rquant::quantum::types::{pauli::Pauli, quantum_operators::QuantumOperator};
+    ///
+    /// fn get_bit_flip_operator() -> Option<QuantumOperator> {
+    ///     Pauli::X.operator()
+    /// }
+    /// ```
+    pub fn operator(&self) -> Option<QuantumOperator> {
+        match self {
+            Pauli::I => None,
+            Pauli::X => Some(QuantumOperator::NOT),
+            Pauli::Y => Some(QuantumOperator::ROTATE),
+            Pauli::Z => Some(QuantumOperator::PHASE),
+        }
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`Pauli`].
+impl fmt::Display for Pauli {
+    /// Will return the single letter name of a [`Pauli`], such as `X`.
+    ///
+    /// # Example
+    /// Can be used to print a [`Pauli`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::pauli::Pauli;
+    ///
+    /// fn print_pauli(pauli: Pauli) {
+    ///     println!("{pauli}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Pauli::I => "I",
+            Pauli::X => "X",
+            Pauli::Y => "Y",
+            Pauli::Z => "Z",
+        };
+        write!(f, "{name}")
+    }
+}
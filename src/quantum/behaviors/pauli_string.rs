@@ -0,0 +1,51 @@
+use std::fmt;
+
+use num_complex::Complex;
+
+use crate::quantum::types::{pauli::Pauli, pauli_string::PauliString};
+
+impl PauliString {
+    /// [`PauliString::basis_image`] will get the basis state and phase that the [`PauliString`]
+    /// maps the basis state at `index` onto, where the first [`Pauli`] acts on the most significant
+    /// bit of the `index`.
+    pub(crate) fn basis_image(&self, index: usize) -> (usize, Complex<f64>) {
+        let sign = if self.negative { -1.0 } else { 1.0 };
+        self.paulis.iter().enumerate().fold(
+            (index, Complex::new(sign, 0.0)),
+            |(image, phase), (target, pauli)| {
+                let mask = 1 << (self.paulis.len() - 1 - target);
+                let is_one = index & mask != 0;
+                match (pauli, is_one) {
+                    (Pauli::I, _) => (image, phase),
+                    (Pauli::X, _) => (image ^ mask, phase),
+                    (Pauli::Y, false) => (image ^ mask, phase * Complex::i()),
+                    (Pauli::Y, true) => (image ^ mask, phase * -Complex::i()),
+                    (Pauli::Z, false) => (image, phase),
+                    (Pauli::Z, true) => (image, -phase),
+                }
+            },
+        )
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`PauliString`].
+impl fmt::Display for PauliString {
+    /// Will return the sign and letters of a [`PauliString`], such as `+XZI` or `-YY`.
+    ///
+    /// # Example
+    /// Can be used to print a [`PauliString`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::pauli_string::PauliString;
+    ///
+    /// fn print_pauli_string(pauli_string: PauliString) {
+    ///     println!("{pauli_string}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", if self.negative { "-" } else { "+" })?;
+        for pauli in &self.paulis {
+            write!(f, "{pauli}")?;
+        }
+        Ok(())
+    }
+}
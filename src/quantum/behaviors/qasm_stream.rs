@@ -0,0 +1,377 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    f64::consts::PI,
+    io::BufRead,
+};
+
+use crate::quantum::{
+    behaviors::quantum_gate::operator_from_name,
+    types::{qasm_statement::QasmStatement, qasm_stream::QasmStream},
+};
+
+impl<R: BufRead> QasmStream<R> {
+    /// [`QasmStream::new`] will create a new [`QasmStream`] that reads an OpenQASM 2.0 program from
+    /// a `reader`.
+    ///
+    /// The `qreg`, `creg`, `barrier` and `measure` statements are supported, along with the `id`,
+    /// `x`, `y`, `z`, `h`, `s`, `sdg`, `t`, `tdg`, `rx`, `ry`, `rz`, `cx` and `cz` gates. The `s`
+    /// and `t` gates are run as Z rotations, which only differ from them by a global phase.
+    ///
+    /// # Example
+    /// [`QasmStream::new`] can be used to read the statements of a program:
+    /// ```rust
+    /// use rquant::quantum::types::{qasm_statement::QasmStatement, qasm_stream::QasmStream};
+    ///
+    /// fn read_bell_program() -> Vec<QasmStatement> {
+    ///     let program = "OPENQASM 2.0;\nqreg q[2];\nh q[0];\ncx q[0], q[1];\n";
+    ///     QasmStream::new(program.as_bytes()).collect()
+    /// }
+    /// ```
+    pub fn new(reader: R) -> Self {
+        QasmStream {
+            reader,
+            line_number: 0,
+            quantum_registers: HashMap::new(),
+            classical_registers: HashMap::new(),
+            pending: VecDeque::new(),
+            partial: String::new(),
+        }
+    }
+
+    /// [`QasmStream::num_qubits`] will get the amount of [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// declared by the quantum registers read so far.
+    ///
+    /// # Example
+    /// [`QasmStream::num_qubits`] can be used to size a state for a program:
+    /// ```rust
+    /// use rquant::quantum::types::qasm_stream::QasmStream;
+    ///
+    /// fn count_program_qubits() -> usize {
+    ///     let mut stream = QasmStream::new("qreg a[2];\nqreg b[3];\n".as_bytes());
+    ///     stream.by_ref().count();
+    ///     stream.num_qubits()
+    /// }
+    /// ```
+    pub fn num_qubits(&self) -> usize {
+        self.quantum_registers.values().map(|(_, size)| size).sum()
+    }
+
+    /// [`QasmStream::num_clbits`] will get the amount of classical bits declared by the classical
+    /// registers read so far.
+    ///
+    /// # Example
+    /// [`QasmStream::num_clbits`] can be used to size the measured outcome of a program:
+    /// ```rust
+    /// use rquant::quantum::types::qasm_stream::QasmStream;
+    ///
+    /// fn count_program_bits() -> usize {
+    ///     let mut stream = QasmStream::new("creg c[2];\n".as_bytes());
+    ///     stream.by_ref().count();
+    ///     stream.num_clbits()
+    /// }
+    /// ```
+    pub fn num_clbits(&self) -> usize {
+        self.classical_registers
+            .values()
+            .map(|(_, size)| size)
+            .sum()
+    }
+
+    /// [`QasmStream::try_next`] will read the next [`QasmStatement`] like [`Iterator::next`], but
+    /// return a message that points at the line being read instead of panicking when the program
+    /// is invalid.
+    pub(crate) fn try_next(&mut self) -> Result<Option<QasmStatement>, String> {
+        loop {
+            if let Some(statement) = self.pending.pop_front() {
+                return Ok(Some(statement));
+            }
+
+            let mut line = String::new();
+            let read = self
+                .reader
+                .read_line(&mut line)
+                .map_err(|error| self.error(&error.to_string()))?;
+            if read == 0 {
+                if !self.partial.trim().is_empty() {
+                    return Err(self.error("statement is missing it's semicolon"));
+                }
+                return Ok(None);
+            }
+            self.line_number += 1;
+
+            let code = line.split("//").next().unwrap_or_default();
+            self.partial.push_str(code);
+            self.partial.push(' ');
+            while let Some(end) = self.partial.find(';') {
+                let statement: String = self.partial.drain(..=end).collect();
+                self.parse(&statement[..statement.len() - 1])?;
+            }
+        }
+    }
+
+    /// [`QasmStream::parse`] will parse one `statement` without it's semicolon, and queue the
+    /// [`QasmStatements`](QasmStatement) it produces.
+    fn parse(&mut self, statement: &str) -> Result<(), String> {
+        let statement = statement.trim();
+        if statement.is_empty()
+            || statement.starts_with("OPENQASM")
+            || statement.starts_with("include")
+        {
+            return Ok(());
+        }
+
+        let name_end = statement
+            .find(|character: char| !character.is_ascii_alphanumeric() && character != '_')
+            .unwrap_or(statement.len());
+        let (name, rest) = statement.split_at(name_end);
+        let rest = rest.trim_start();
+        let (parameters, operands) = match rest.strip_prefix('(') {
+            Some(rest) => {
+                let mut depth = 0;
+                let end = rest
+                    .find(|character| {
+                        match character {
+                            '(' => depth += 1,
+                            ')' if depth == 0 => return true,
+                            ')' => depth -= 1,
+                            _ => {}
+                        }
+                        false
+                    })
+                    .ok_or_else(|| self.error("unclosed gate parameters"))?;
+                let parameters = rest[..end]
+                    .split(',')
+                    .map(|parameter| {
+                        evaluate(parameter).ok_or_else(|| self.error("invalid gate parameter"))
+                    })
+                    .collect::<Result<Vec<f64>, String>>()?;
+                (parameters, rest[end + 1..].trim())
+            }
+            None => (vec![], rest),
+        };
+
+        match name {
+            "qreg" | "creg" => {
+                let (register, size) = self.declaration(operands)?;
+                let registers = if name == "qreg" {
+                    &self.quantum_registers
+                } else {
+                    &self.classical_registers
+                };
+                let offset = registers.values().map(|(_, size)| size).sum();
+                if registers.contains_key(&register) {
+                    return Err(self.error("register is declared twice"));
+                }
+                if name == "qreg" {
+                    self.quantum_registers.insert(register, (offset, size));
+                    self.pending.push_back(QasmStatement::QuantumRegister(size));
+                } else {
+                    self.classical_registers.insert(register, (offset, size));
+                    self.pending
+                        .push_back(QasmStatement::ClassicalRegister(size));
+                }
+            }
+            "barrier" => {}
+            "measure" => {
+                let (qubits, clbits) = operands
+                    .split_once("->")
+                    .ok_or_else(|| self.error("measurement is missing it's classical bit"))?;
+                let qubits = self.resolve(qubits, true)?;
+                let clbits = self.resolve(clbits, false)?;
+                if qubits.len() != clbits.len() {
+                    return Err(self.error("measured registers have different sizes"));
+                }
+                for (qubit, clbit) in qubits.into_iter().zip(clbits) {
+                    self.pending.push_back(QasmStatement::Measure(qubit, clbit));
+                }
+            }
+            "cx" | "CX" | "cz" => {
+                let (controls, targets) = operands
+                    .split_once(',')
+                    .ok_or_else(|| self.error("two-qubit gate needs two operands"))?;
+                let (controls, targets) =
+                    (self.resolve(controls, true)?, self.resolve(targets, true)?);
+                let pairs: Vec<(usize, usize)> = match (controls.len(), targets.len()) {
+                    (1, _) => targets
+                        .iter()
+                        .map(|&target| (controls[0], target))
+                        .collect(),
+                    (_, 1) => controls
+                        .iter()
+                        .map(|&control| (control, targets[0]))
+                        .collect(),
+                    (first, second) if first == second => {
+                        controls.into_iter().zip(targets).collect()
+                    }
+                    _ => return Err(self.error("two-qubit gate registers have different sizes")),
+                };
+                for (control, target) in pairs {
+                    self.pending.push_back(if name == "cz" {
+                        QasmStatement::ControlledPhase(control, target)
+                    } else {
+                        QasmStatement::ControlledNot(control, target)
+                    });
+                }
+            }
+            _ => {
+                let operator = match name {
+                    "id" if parameters.is_empty() => None,
+                    _ => Some(
+                        operator_from_name(name, &parameters)
+                            .map_err(|error| self.error(&error.to_string()))?,
+                    ),
+                };
+                let targets = self.resolve(operands, true)?;
+                if let Some(operator) = operator {
+                    for target in targets {
+                        self.pending
+                            .push_back(QasmStatement::Gate(operator.clone(), target));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// [`QasmStream::declaration`] will parse the name and size of a register declaration like
+    /// `q[4]`.
+    fn declaration(&self, operand: &str) -> Result<(String, usize), String> {
+        let (name, size) = operand
+            .trim()
+            .strip_suffix(']')
+            .and_then(|operand| operand.split_once('['))
+            .ok_or_else(|| self.error("invalid register declaration"))?;
+        let size = size
+            .trim()
+            .parse()
+            .map_err(|_| self.error("invalid register size"))?;
+        Ok((name.trim().to_string(), size))
+    }
+
+    /// [`QasmStream::resolve`] will get the flat indices of an `operand`, which is either one bit of
+    /// a register like `q[1]` or a whole register like `q`.
+    fn resolve(&self, operand: &str, quantum: bool) -> Result<Vec<usize>, String> {
+        let registers = if quantum {
+            &self.quantum_registers
+        } else {
+            &self.classical_registers
+        };
+        let operand = operand.trim();
+        let (name, index) = match operand.strip_suffix(']').and_then(|o| o.split_once('[')) {
+            Some((name, index)) => (
+                name.trim(),
+                Some(
+                    index
+                        .trim()
+                        .parse::<usize>()
+                        .map_err(|_| self.error("invalid register index"))?,
+                ),
+            ),
+            None => (operand, None),
+        };
+        let &(offset, size) = registers
+            .get(name)
+            .ok_or_else(|| self.error(&format!("undeclared register \"{name}\"")))?;
+        match index {
+            Some(index) if index < size => Ok(vec![offset + index]),
+            Some(_) => Err(self.error("register index is out of bounds")),
+            None => Ok((offset..offset + size).collect()),
+        }
+    }
+
+    /// [`QasmStream::error`] will get a `message` that points at the line being read.
+    fn error(&self, message: &str) -> String {
+        format!("line {}: {message}", self.line_number)
+    }
+}
+
+/// Implement the [`Iterator`] trait for [`QasmStream`].
+impl<R: BufRead> Iterator for QasmStream<R> {
+    type Item = QasmStatement;
+
+    /// Will panic with a message that points at the line being read when the program is invalid.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.try_next()
+            .unwrap_or_else(|error| panic!("Invalid QASM on {error}"))
+    }
+}
+
+/// [`evaluate`] will get the value of a gate parameter `expression`, made of numbers, `pi`,
+/// parentheses and the `+`, `-`, `*` and `/` operators.
+pub(crate) fn evaluate(expression: &str) -> Option<f64> {
+    let tokens: Vec<char> = expression.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut position = 0;
+    let value = sum(&tokens, &mut position)?;
+    (position == tokens.len()).then_some(value)
+}
+
+/// [`sum`] will evaluate the terms of an expression that are added or subtracted.
+fn sum(tokens: &[char], position: &mut usize) -> Option<f64> {
+    let mut value = product(tokens, position)?;
+    while let Some(&operator) = tokens.get(*position).filter(|&&c| c == '+' || c == '-') {
+        *position += 1;
+        let term = product(tokens, position)?;
+        value = if operator == '+' {
+            value + term
+        } else {
+            value - term
+        };
+    }
+    Some(value)
+}
+
+/// [`product`] will evaluate the factors of an expression that are multiplied or divided.
+fn product(tokens: &[char], position: &mut usize) -> Option<f64> {
+    let mut value = factor(tokens, position)?;
+    while let Some(&operator) = tokens.get(*position).filter(|&&c| c == '*' || c == '/') {
+        *position += 1;
+        let factor = factor(tokens, position)?;
+        value = if operator == '*' {
+            value * factor
+        } else {
+            value / factor
+        };
+    }
+    Some(value)
+}
+
+/// [`factor`] will evaluate a number, `pi`, a negated factor or a parenthesized expression.
+fn factor(tokens: &[char], position: &mut usize) -> Option<f64> {
+    match tokens.get(*position)? {
+        '-' => {
+            *position += 1;
+            factor(tokens, position).map(|value| -value)
+        }
+        '(' => {
+            *position += 1;
+            let value = sum(tokens, position)?;
+            (tokens.get(*position) == Some(&')')).then(|| {
+                *position += 1;
+                value
+            })
+        }
+        'p' => (tokens.get(*position + 1) == Some(&'i')).then(|| {
+            *position += 2;
+            PI
+        }),
+        _ => {
+            let start = *position;
+            // An exponent can be signed, like 1e-3.
+            while tokens.get(*position).is_some_and(|c| {
+                c.is_ascii_digit()
+                    || *c == '.'
+                    || *c == 'e'
+                    || ((*c == '-' || *c == '+')
+                        && *position > start
+                        && tokens[*position - 1] == 'e')
+            }) {
+                *position += 1;
+            }
+            tokens[start..*position]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .ok()
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::quantum::{
+    behaviors::{quantum_circuit::rotation_angle, quantum_gate::operator_name},
+    types::{
+        quantum_circuit::QuantumCircuit, quantum_operation::QuantumOperation,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::to_qir`] will lower a [`QuantumCircuit`] to the textual form of the
+    /// [Quantum Intermediate Representation](https://github.com/qir-alliance/qir-spec) base profile,
+    /// so it can be consumed by QIR-compatible toolchains and hardware providers.
+    ///
+    /// Controlled gates are lowered to the `cnot`, `cz` and `ccx` intrinsics. Every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is measured at the end of the program, and
+    /// each measurement is recorded as an output result.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::to_qir`] can be used to get the QIR program of a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_program() -> String {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .to_qir()
+    /// }
+    /// ```
+    pub fn to_qir(&self) -> String {
+        let gates: String = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let qubits: Vec<String> = operation
+                    .qubits()
+                    .into_iter()
+                    .map(|index| format!("%Qubit* {}", qir_pointer(index, "Qubit")))
+                    .collect();
+                format!(
+                    "  call void @__quantum__qis__{}__body({}{})\n",
+                    qir_name(operation),
+                    rotation_angle(&operation.operator)
+                        .map(|angle| format!("double {angle:.16e}, "))
+                        .unwrap_or_default(),
+                    qubits.join(", ")
+                )
+            })
+            .collect();
+        let measurements: String = (0..self.num_qubits)
+            .map(|index| {
+                format!(
+                    "  call void @__quantum__qis__mz__body(%Qubit* {}, %Result* {})\n",
+                    qir_pointer(index, "Qubit"),
+                    qir_pointer(index, "Result")
+                )
+            })
+            .collect();
+        let outputs: String = (0..self.num_qubits)
+            .map(|index| {
+                format!(
+                    "  call void @__quantum__rt__result_record_output(%Result* {}, i8* null)\n",
+                    qir_pointer(index, "Result")
+                )
+            })
+            .collect();
+
+        let mut declarations: Vec<String> = vec![];
+        for operation in &self.operations {
+            let qubits = vec!["%Qubit*"; operation.qubits().len()].join(", ");
+            let parameters = match rotation_angle(&operation.operator) {
+                Some(_) => format!("double, {qubits}"),
+                None => qubits,
+            };
+            let declaration = format!(
+                "declare void @__quantum__qis__{}__body({parameters})\n\n",
+                qir_name(operation)
+            );
+            if !declarations.contains(&declaration) {
+                declarations.push(declaration);
+            }
+        }
+        let declarations = declarations.concat();
+
+        format!(
+            "; ModuleID = 'rquant'\n\
+             source_filename = \"rquant\"\n\n\
+             %Qubit = type opaque\n\
+             %Result = type opaque\n\n\
+             define void @main() #0 {{\n\
+             entry:\n\
+             {gates}{measurements}{outputs}  ret void\n\
+             }}\n\n\
+             {declarations}\
+             declare void @__quantum__qis__mz__body(%Qubit*, %Result* writeonly) #1\n\n\
+             declare void @__quantum__rt__result_record_output(%Result*, i8*)\n\n\
+             attributes #0 = {{ \"entry_point\" \"output_labeling_schema\" \"qir_profiles\"=\"base_profile\" \
+             \"required_num_qubits\"=\"{qubits}\" \"required_num_results\"=\"{qubits}\" }}\n\
+             attributes #1 = {{ \"irreversible\" }}\n\n\
+             !llvm.module.flags = !{{!0, !1, !2, !3}}\n\n\
+             !0 = !{{i32 1, !\"qir_major_version\", i32 1}}\n\
+             !1 = !{{i32 7, !\"qir_minor_version\", i32 0}}\n\
+             !2 = !{{i32 1, !\"dynamic_qubit_management\", i1 false}}\n\
+             !3 = !{{i32 1, !\"dynamic_result_management\", i1 false}}\n",
+            qubits = self.num_qubits
+        )
+    }
+}
+
+/// [`qir_name`] will get the name of the QIR intrinsic for a [`QuantumOperation`], which is `cnot`,
+/// `cz` or `ccx` for those controlled gates, and the name of it's [`QuantumOperator`] with a `c`
+/// for each control otherwise.
+fn qir_name(operation: &QuantumOperation) -> String {
+    match (operation.controls.len(), &operation.operator) {
+        (1, QuantumOperator::NOT) => "cnot".to_string(),
+        (1, QuantumOperator::PHASE) => "cz".to_string(),
+        (2, QuantumOperator::NOT) => "ccx".to_string(),
+        (controls, operator) => format!("{}{}", "c".repeat(controls), operator_name(operator)),
+    }
+}
+
+/// [`qir_pointer`] will get the static QIR pointer for a `Qubit` or `Result` at an `index`.
+fn qir_pointer(index: usize, kind: &str) -> String {
+    if index == 0 {
+        "null".to_string()
+    } else {
+        format!("inttoptr (i64 {index} to %{kind}*)")
+    }
+}
@@ -0,0 +1,823 @@
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    noise::types::{
+        error_budget::ErrorBudget, error_contribution::ErrorContribution,
+        error_source::ErrorSource, noise_channel::NoiseChannel, noise_model::NoiseModel,
+    },
+    quantum::{
+        behaviors::hamiltonian::matrix_multiply,
+        types::{
+            cost_model::CostModel, dual_number::DualNumber, pauli::Pauli,
+            quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+            quantum_operation::QuantumOperation, quantum_operators::QuantumOperator,
+            qubit_register::QubitRegister, qubit_view::QubitView, state_vector::StateVector,
+            unique_qubit::UniqueQubit,
+        },
+    },
+};
+
+/// A complex number with [`DualNumber`] real and imaginary parts.
+type DualComplex = (DualNumber, DualNumber);
+
+impl QuantumCircuit {
+    /// [`QuantumCircuit::new`] will create an empty [`QuantumCircuit`] that operates on
+    /// `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`QuantumCircuit::new`] can be used to create a new [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn create_quantum_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(2)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        QuantumCircuit {
+            num_qubits,
+            operations: vec![],
+            post_selections: vec![],
+        }
+    }
+
+    /// [`QuantumCircuit::add_gate`] will add a [`QuantumGate`] that targets the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target` to the end of the [`QuantumCircuit`], then return the modified [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::add_gate`] can be chained to build up a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn create_superposition_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::NOT, 1)
+    /// }
+    /// ```
+    pub fn add_gate(mut self, gate: &QuantumGate, target: usize) -> Self {
+        // A gate can only target a qubit that the circuit operates on.
+        assert!(target < self.num_qubits, "Invalid circuit qubit index");
+
+        self.operations.push(QuantumOperation {
+            operator: gate.operator.clone(),
+            target,
+            controls: vec![],
+        });
+        self
+    }
+
+    /// [`QuantumCircuit::add_controlled_gate`] will add a [`QuantumGate`] that targets the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` to the end of the
+    /// [`QuantumCircuit`], which is only applied where every one of the `controls` is $|1\rangle$,
+    /// then return the modified [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::add_controlled_gate`] can be used to build a Toffoli gate:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn create_toffoli_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(3).add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2)
+    /// }
+    /// ```
+    pub fn add_controlled_gate(
+        mut self,
+        gate: &QuantumGate,
+        controls: &[usize],
+        target: usize,
+    ) -> Self {
+        // A controlled gate can only use distinct qubits that the circuit operates on.
+        assert!(
+            controls
+                .iter()
+                .chain([&target])
+                .all(|&index| index < self.num_qubits),
+            "Invalid circuit qubit index"
+        );
+        let mut qubits: Vec<usize> = controls.iter().chain([&target]).copied().collect();
+        qubits.sort_unstable();
+        qubits.dedup();
+        assert!(
+            qubits.len() == controls.len() + 1,
+            "Controlled gate qubits must be unique"
+        );
+
+        self.operations.push(QuantumOperation {
+            operator: gate.operator.clone(),
+            target,
+            controls: controls.to_vec(),
+        });
+        self
+    }
+
+    /// [`QuantumCircuit::add_cnot`] will add a controlled NOT gate that targets the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`, using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), to the end of the [`QuantumCircuit`], then
+    /// return the modified [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::add_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn create_bell_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_cnot(0, 1)
+    /// }
+    /// ```
+    pub fn add_cnot(self, control: usize, target: usize) -> Self {
+        self.add_controlled_gate(&QuantumGate::NOT, &[control], target)
+    }
+
+    /// [`QuantumCircuit::add_cz`] will add a controlled Z gate between the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) `a` and `b` to the end of the
+    /// [`QuantumCircuit`], then return the modified [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::add_cz`] can be used to create a two-qubit cluster state:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn create_cluster_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 1)
+    ///         .add_cz(0, 1)
+    /// }
+    /// ```
+    pub fn add_cz(self, a: usize, b: usize) -> Self {
+        self.add_controlled_gate(&QuantumGate::PHASE, &[a], b)
+    }
+
+    /// [`QuantumCircuit::add_gate_to_view`] will add a [`QuantumGate`] that targets every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) in a [`QubitView`] to the end of the
+    /// [`QuantumCircuit`], then return the modified [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::add_gate_to_view`] can be used to put a counting register into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     qubit_view::QubitView,
+    /// };
+    ///
+    /// fn create_counting_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(4).add_gate_to_view(&QuantumGate::SUPERPOSITION, &QubitView::range(0, 2))
+    /// }
+    /// ```
+    pub fn add_gate_to_view(self, gate: &QuantumGate, view: &QubitView) -> Self {
+        view.iter()
+            .fold(self, |circuit, &target| circuit.add_gate(gate, target))
+    }
+
+    /// [`QuantumCircuit::inject_error`] will deliberately insert a [`Pauli`] error on the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target`, right before the operation at `op_index`, then return the modified [`QuantumCircuit`].
+    ///
+    /// An `op_index` equal to [`QuantumCircuit::len`] will inject the error at the end of the
+    /// [`QuantumCircuit`], and injecting [`Pauli::I`] leaves the [`QuantumCircuit`] unchanged.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::inject_error`] can be used to test error correction against a known fault:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     pauli::Pauli,
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    /// };
+    ///
+    /// fn bit_flip_after_superposition() -> QuantumCircuit {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .inject_error(1, Pauli::X, 0)
+    /// }
+    /// ```
+    pub fn inject_error(mut self, op_index: usize, pauli: Pauli, target: usize) -> Self {
+        // An error can only be injected between existing operations, on a qubit
+        // that the circuit operates on.
+        assert!(op_index <= self.len(), "Invalid circuit operation index");
+        assert!(target < self.num_qubits, "Invalid circuit qubit index");
+
+        if let Some(operator) = pauli.operator() {
+            self.operations.insert(
+                op_index,
+                QuantumOperation {
+                    operator,
+                    target,
+                    controls: vec![],
+                },
+            );
+        }
+        self
+    }
+
+    /// [`QuantumCircuit::post_select`] will flag the [`QuantumCircuit`] to only keep shots where the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` is measured as `value`, then
+    /// return the modified [`QuantumCircuit`].
+    ///
+    /// The `value` uses the same "truthy" state as [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure).
+    ///
+    /// # Example
+    /// [`QuantumCircuit::post_select`] can be used to keep the shots where an ancilla stays at $|0\rangle$:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn create_heralded_circuit() -> QuantumCircuit {
+    ///     QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 1)
+    ///         .post_select(1, true)
+    /// }
+    /// ```
+    pub fn post_select(mut self, target: usize, value: bool) -> Self {
+        assert!(target < self.num_qubits, "Invalid circuit qubit index");
+
+        self.post_selections.push((target, value));
+        self
+    }
+
+    /// [`QuantumCircuit::len`] returns the number of [`QuantumOperations`](QuantumOperation)
+    /// in the [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::len`] can be used to count the operations of a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn count_circuit_operations(circuit: &QuantumCircuit) -> usize {
+    ///     circuit.len()
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// [`QuantumCircuit::is_empty`] returns `true` if the [`QuantumCircuit`] has no
+    /// [`QuantumOperations`](QuantumOperation), and `false` otherwise.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::is_empty`] can be used to determine if a [`QuantumCircuit`]
+    /// has any operations:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn circuit_has_operations(circuit: &QuantumCircuit) -> bool {
+    ///     !circuit.is_empty()
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// [`QuantumCircuit::unitary`] will get the matrix of every
+    /// [`QuantumOperation`] of a [`QuantumCircuit`] combined, by row, in the same basis order as
+    /// [`StateVector::amplitudes`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::unitary`] can be used to compare two circuits:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn is_equivalent(first: &QuantumCircuit, second: &QuantumCircuit) -> bool {
+    ///     first.unitary() == second.unitary()
+    /// }
+    /// ```
+    pub fn unitary(&self) -> Vec<Vec<Complex<f64>>> {
+        let dimension = 1 << self.num_qubits;
+        let columns: Vec<Vec<Complex<f64>>> = (0..dimension)
+            .map(|column| {
+                let mut state_vector = StateVector::new(self.num_qubits);
+                state_vector.amplitudes[0] = Complex::new(0.0, 0.0);
+                state_vector.amplitudes[column] = Complex::new(1.0, 0.0);
+                state_vector.apply_circuit(self);
+                state_vector.amplitudes
+            })
+            .collect();
+        (0..dimension)
+            .map(|row| columns.iter().map(|column| column[row]).collect())
+            .collect()
+    }
+
+    /// [`QuantumCircuit::twirled`] will get a copy of the [`QuantumCircuit`] where every gate $G$
+    /// is surrounded by a uniformly random [`Pauli`] $P$ before it and $G P G^\dagger$ after it,
+    /// which leaves the [`QuantumCircuit::unitary`] unchanged up to a global phase.
+    ///
+    /// Running many twirled copies averages the noise after each gate into a stochastic Pauli
+    /// channel, which is what [`NoiseModel::twirled`](crate::noise::types::noise_model::NoiseModel::twirled)
+    /// models directly. A controlled gate with one control is surrounded by a [`Pauli`] on both of
+    /// it's qubits. A gate that does not turn $P$ into another [`Pauli`], such as a rotation by an
+    /// arbitrary angle or a gate with more than one control, is left as it is.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::twirled`] can be used to make one random twirl of a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn twirl_superposition() -> QuantumCircuit {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .twirled()
+    /// }
+    /// ```
+    pub fn twirled(&self) -> Self {
+        self.twirled_with(&mut rand::rng())
+    }
+
+    /// [`QuantumCircuit::twirled_with`] will twirl a [`QuantumCircuit`] in the same way as
+    /// [`QuantumCircuit::twirled`], using a random number generator `rng` to pick the [`Paulis`](Pauli)
+    /// instead of the thread's generator, so a seeded `rng` always makes the same twirl.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::twirled_with`] can be used to make a reproducible twirl of a circuit:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn twirl_seeded_superposition(seed: u64) -> QuantumCircuit {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .twirled_with(&mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn twirled_with(&self, rng: &mut impl Rng) -> Self {
+        let paulis = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z];
+        let mut operations = vec![];
+        for operation in &self.operations {
+            // Gates with more than one control, like a Toffoli gate, are not Clifford gates.
+            if operation.controls.len() > 1 {
+                operations.push(operation.clone());
+                continue;
+            }
+            let qubits = operation.qubits();
+            let before: Vec<Pauli> = qubits
+                .iter()
+                .map(|_| paulis[rng.random_range(0..paulis.len())])
+                .collect();
+            let gate = operation_matrix(operation);
+            let conjugated = matrix_multiply(
+                &matrix_multiply(&gate, &pauli_product(&before)),
+                &adjoint(&gate),
+            );
+            // Clifford gates turn every Pauli into another Pauli, up to a global phase.
+            let after = (0..paulis.len().pow(qubits.len() as u32))
+                .map(|combination| {
+                    (0..qubits.len())
+                        .rev()
+                        .map(|position| paulis[(combination >> (2 * position)) & 3])
+                        .collect::<Vec<Pauli>>()
+                })
+                .find(|after| {
+                    let trace: Complex<f64> = pauli_product(after)
+                        .iter()
+                        .flatten()
+                        .zip(conjugated.iter().flatten())
+                        .map(|(pauli_entry, entry)| pauli_entry.conj() * entry)
+                        .sum();
+                    (trace.norm() - gate.len() as f64).abs() < 1e-9
+                });
+
+            let Some(after) = after else {
+                operations.push(operation.clone());
+                continue;
+            };
+            let pauli_operations = |paulis: &[Pauli]| {
+                paulis
+                    .iter()
+                    .zip(&qubits)
+                    .filter_map(|(pauli, &target)| {
+                        pauli.operator().map(|operator| QuantumOperation {
+                            operator,
+                            target,
+                            controls: vec![],
+                        })
+                    })
+                    .collect::<Vec<QuantumOperation>>()
+            };
+            operations.extend(pauli_operations(&before));
+            operations.push(operation.clone());
+            operations.extend(pauli_operations(&after));
+        }
+
+        QuantumCircuit {
+            operations,
+            ..self.clone()
+        }
+    }
+
+    /// [`QuantumCircuit::parameters`] will get the angle of every parameterized operation
+    /// ([`QuantumOperator::RX`], [`QuantumOperator::RY`], and [`QuantumOperator::RZ`]) of the
+    /// [`QuantumCircuit`], in circuit order.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::parameters`] can be used to get the angles of an ansatz:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn get_ansatz_angles(ansatz: &QuantumCircuit) -> Vec<f64> {
+    ///     ansatz.parameters()
+    /// }
+    /// ```
+    pub fn parameters(&self) -> Vec<f64> {
+        self.operations
+            .iter()
+            .filter_map(|operation| rotation_angle(&operation.operator))
+            .collect()
+    }
+
+    /// [`QuantumCircuit::with_parameters`] will get a copy of the [`QuantumCircuit`] with the angle
+    /// of every parameterized operation replaced by the `parameters`, in circuit order.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::with_parameters`] can be used to update the angles of an ansatz:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     quantum_operators::QuantumOperator,
+    /// };
+    ///
+    /// fn create_rotated_ansatz(angle: f64) -> QuantumCircuit {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::new(QuantumOperator::RY(0.0)), 0)
+    ///         .with_parameters(&[angle])
+    /// }
+    /// ```
+    pub fn with_parameters(&self, parameters: &[f64]) -> Self {
+        assert!(
+            parameters.len() == self.parameters().len(),
+            "Circuit must have an angle for every parameter"
+        );
+
+        let mut parameters = parameters.iter().copied();
+        let mut next_parameter = || parameters.next().expect("Missing circuit parameter");
+        let operations = self
+            .operations
+            .iter()
+            .map(|operation| {
+                let operator = match operation.operator {
+                    QuantumOperator::RX(_) => QuantumOperator::RX(next_parameter()),
+                    QuantumOperator::RY(_) => QuantumOperator::RY(next_parameter()),
+                    QuantumOperator::RZ(_) => QuantumOperator::RZ(next_parameter()),
+                    ref operator => operator.clone(),
+                };
+                QuantumOperation {
+                    operator,
+                    target: operation.target,
+                    controls: operation.controls.clone(),
+                }
+            })
+            .collect();
+        QuantumCircuit {
+            num_qubits: self.num_qubits,
+            operations,
+            post_selections: self.post_selections.clone(),
+        }
+    }
+
+    /// [`QuantumCircuit::apply_unique`] will consume a [`UniqueQubit`] for every [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// the [`QuantumCircuit`] operates on, run the [`QuantumCircuit`] on them, and return the
+    /// resulting [`UniqueQubits`](UniqueQubit) in the same order.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::apply_unique`] can be used to run a [`QuantumCircuit`] without keeping any
+    /// copies of the original qubits around:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     qubit::Qubit,
+    ///     unique_qubit::UniqueQubit,
+    /// };
+    ///
+    /// fn flip_unique_qubits() -> Vec<UniqueQubit> {
+    ///     QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::NOT, 1)
+    ///         .apply_unique(vec![UniqueQubit::new(Qubit::zero()), UniqueQubit::new(Qubit::zero())])
+    /// }
+    /// ```
+    pub fn apply_unique(&self, qubits: Vec<UniqueQubit>) -> Vec<UniqueQubit> {
+        assert!(
+            qubits.len() == self.num_qubits,
+            "Circuit must have a unique qubit for every qubit"
+        );
+
+        let mut qubit_register = QubitRegister {
+            qubits: qubits.into_iter().map(UniqueQubit::into_qubit).collect(),
+        };
+        qubit_register.apply_circuit(self);
+        qubit_register
+            .qubits
+            .into_iter()
+            .map(UniqueQubit::new)
+            .collect()
+    }
+
+    /// [`QuantumCircuit::expectation_gradient`] will run the [`QuantumCircuit`] on [`Qubit::zero`](crate::quantum::types::qubit::Qubit::zero)
+    /// qubits, and get the expectation value of the Pauli-Z observable on the `target` qubit as a
+    /// [`DualNumber`].
+    ///
+    /// The angle of every parameterized operation ([`QuantumOperator::RX`], [`QuantumOperator::RY`], and
+    /// [`QuantumOperator::RZ`]) is propagated through the state as a [`DualNumber`], so the
+    /// [`DualNumber::derivatives`] hold the exact gradient of the expectation value with respect to
+    /// each angle, in circuit order, after a single pass through the [`QuantumCircuit`]. Every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is followed on it's own, so the
+    /// [`QuantumCircuit`] can not have any controlled gates.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::expectation_gradient`] can be used to get the slope of an expectation value:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     quantum_operators::QuantumOperator,
+    /// };
+    ///
+    /// fn slope_of_rotation(angle: f64) -> f64 {
+    ///     QuantumCircuit::new(1)
+    ///         .add_gate(&QuantumGate::new(QuantumOperator::RY(angle)), 0)
+    ///         .expectation_gradient(0)
+    ///         .derivatives[0]
+    /// }
+    /// ```
+    pub fn expectation_gradient(&self, target: usize) -> DualNumber {
+        assert!(target < self.num_qubits, "Invalid circuit qubit index");
+        // Only a circuit without controlled gates keeps every qubit independent.
+        assert!(
+            self.operations
+                .iter()
+                .all(|operation| operation.controls.is_empty()),
+            "Expectation gradient can not follow a controlled gate"
+        );
+
+        let parameters = self
+            .operations
+            .iter()
+            .filter(|operation| rotation_angle(&operation.operator).is_some())
+            .count();
+        let constant = |value: Complex<f64>| -> DualComplex {
+            (
+                DualNumber::constant(value.re, parameters),
+                DualNumber::constant(value.im, parameters),
+            )
+        };
+
+        let mut amplitudes = [
+            constant(Complex::new(1.0, 0.0)),
+            constant(Complex::new(0.0, 0.0)),
+        ];
+        let mut parameter = 0;
+        for operation in &self.operations {
+            let matrix = match rotation_angle(&operation.operator) {
+                Some(angle) => {
+                    let half_angle = DualNumber::variable(angle, parameter, parameters).scale(0.5);
+                    parameter += 1;
+                    rotation_matrix(&operation.operator, &half_angle, parameters)
+                }
+                None => QuantumGate::new(operation.operator.clone())
+                    .transform
+                    .map(|row| {
+                        [
+                            constant(row.initial_position),
+                            constant(row.possible_position),
+                        ]
+                    }),
+            };
+            if operation.target == target {
+                amplitudes = [
+                    dual_complex_add(
+                        &dual_complex_mul(&matrix[0][0], &amplitudes[0]),
+                        &dual_complex_mul(&matrix[0][1], &amplitudes[1]),
+                    ),
+                    dual_complex_add(
+                        &dual_complex_mul(&matrix[1][0], &amplitudes[0]),
+                        &dual_complex_mul(&matrix[1][1], &amplitudes[1]),
+                    ),
+                ];
+            }
+        }
+
+        &dual_complex_norm_sqr(&amplitudes[0]) - &dual_complex_norm_sqr(&amplitudes[1])
+    }
+
+    /// [`QuantumCircuit::error_budget`] will estimate the probability that the [`QuantumCircuit`]
+    /// runs without an error on a [`NoiseModel`], by multiplying the
+    /// [`process_fidelity`](crate::noise::types::kraus_channel::KrausChannel::process_fidelity) of
+    /// the noise every gate, idle period, and measurement goes through, the same way
+    /// [`NoiseModel::run`] applies it, without running any shots.
+    ///
+    /// Only steps that can have an error are kept in the [`ErrorBudget`].
+    ///
+    /// # Example
+    /// [`QuantumCircuit::error_budget`] can be used to check a circuit is worth running:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    /// };
+    ///
+    /// fn is_worth_running(circuit: &QuantumCircuit) -> bool {
+    ///     let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.01));
+    ///     circuit.error_budget(&noise_model).success_probability > 0.5
+    /// }
+    /// ```
+    pub fn error_budget(&self, noise_model: &NoiseModel) -> ErrorBudget {
+        let fidelity = |channels: &[NoiseChannel]| -> f64 {
+            channels
+                .iter()
+                .map(|channel| channel.kraus_channel().process_fidelity())
+                .product()
+        };
+        let mut contributions = vec![];
+        let mut add = |source, target, fidelity: f64| {
+            if fidelity < 1.0 - 1e-12 {
+                contributions.push(ErrorContribution {
+                    source,
+                    target,
+                    fidelity,
+                });
+            }
+        };
+
+        let mut elapsed = vec![0.0; self.num_qubits];
+        for (index, operation) in self.operations.iter().enumerate() {
+            let mut gate_fidelity = fidelity(&noise_model.gate_noise);
+            if let Some(decoherence) = &noise_model.decoherence {
+                let duration = decoherence.duration(&operation.operator);
+                gate_fidelity *= fidelity(&decoherence.channels(duration));
+                for target in operation.qubits() {
+                    elapsed[target] += duration;
+                }
+            }
+            for target in operation.qubits() {
+                add(ErrorSource::Gate(index), target, gate_fidelity);
+            }
+        }
+        if let Some(decoherence) = &noise_model.decoherence {
+            let end = elapsed.iter().cloned().fold(0.0, f64::max);
+            for (target, time) in elapsed.iter().enumerate() {
+                add(
+                    ErrorSource::Idle,
+                    target,
+                    fidelity(&decoherence.channels(end - time)),
+                );
+            }
+        }
+        for target in 0..self.num_qubits {
+            add(
+                ErrorSource::Measurement,
+                target,
+                fidelity(&noise_model.measurement_noise),
+            );
+            if let Some(readout_error) = &noise_model.readout_error {
+                let misread = (readout_error.zero_to_one + readout_error.one_to_zero) / 2.0;
+                add(ErrorSource::Readout, target, 1.0 - misread);
+            }
+        }
+
+        ErrorBudget {
+            success_probability: contributions
+                .iter()
+                .map(|contribution| contribution.fidelity)
+                .product(),
+            contributions,
+        }
+    }
+
+    /// [`QuantumCircuit::cost`] will get the total cost of the gates of a [`QuantumCircuit`] with
+    /// a [`CostModel`], so circuits can be compared by a user-chosen objective.
+    ///
+    /// # Example
+    /// [`QuantumCircuit::cost`] can be used to pick the circuit with the fewest T gates:
+    /// ```rust
+    /// use rquant::quantum::types::{cost_model::CostModel, quantum_circuit::QuantumCircuit};
+    ///
+    /// fn cheapest(circuits: Vec<QuantumCircuit>) -> Option<QuantumCircuit> {
+    ///     let cost_model = CostModel::t_count();
+    ///     circuits
+    ///         .into_iter()
+    ///         .min_by(|first, second| first.cost(&cost_model).total_cmp(&second.cost(&cost_model)))
+    /// }
+    /// ```
+    pub fn cost(&self, cost_model: &CostModel) -> f64 {
+        self.operations
+            .iter()
+            .map(|operation| cost_model.operation_cost(operation))
+            .sum()
+    }
+}
+
+/// [`rotation_angle`] will get the angle of a parameterized [`QuantumOperator`], and returns
+/// [`None`] for any [`QuantumOperator`] without parameters.
+pub(crate) fn rotation_angle(operator: &QuantumOperator) -> Option<f64> {
+    match operator {
+        QuantumOperator::RX(angle) | QuantumOperator::RY(angle) | QuantumOperator::RZ(angle) => {
+            Some(*angle)
+        }
+        _ => None,
+    }
+}
+
+/// [`rotation_matrix`] will get the matrix of a parameterized [`QuantumOperator`] using a
+/// [`DualNumber`] `half_angle`.
+fn rotation_matrix(
+    operator: &QuantumOperator,
+    half_angle: &DualNumber,
+    parameters: usize,
+) -> [[DualComplex; 2]; 2] {
+    let (sin, cos) = (half_angle.sin(), half_angle.cos());
+    let negative_sin = sin.scale(-1.0);
+    let zero = DualNumber::constant(0.0, parameters);
+    match operator {
+        QuantumOperator::RX(_) => [
+            [
+                (cos.clone(), zero.clone()),
+                (zero.clone(), negative_sin.clone()),
+            ],
+            [(zero.clone(), negative_sin), (cos, zero)],
+        ],
+        QuantumOperator::RY(_) => [
+            [(cos.clone(), zero.clone()), (negative_sin, zero.clone())],
+            [(sin, zero.clone()), (cos, zero)],
+        ],
+        _ => [
+            [(cos.clone(), negative_sin), (zero.clone(), zero.clone())],
+            [(zero.clone(), zero), (cos, sin)],
+        ],
+    }
+}
+
+/// [`dual_complex_add`] will add two [`DualComplex`] numbers.
+fn dual_complex_add(a: &DualComplex, b: &DualComplex) -> DualComplex {
+    (&a.0 + &b.0, &a.1 + &b.1)
+}
+
+/// [`dual_complex_mul`] will multiply two [`DualComplex`] numbers.
+fn dual_complex_mul(a: &DualComplex, b: &DualComplex) -> DualComplex {
+    (
+        &(&a.0 * &b.0) - &(&a.1 * &b.1),
+        &(&a.0 * &b.1) + &(&a.1 * &b.0),
+    )
+}
+
+/// [`dual_complex_norm_sqr`] will get the square of the magnitude of a [`DualComplex`] number.
+fn dual_complex_norm_sqr(a: &DualComplex) -> DualNumber {
+    &(&a.0 * &a.0) + &(&a.1 * &a.1)
+}
+
+/// [`pauli_matrix`] will get the $2 \times 2$ matrix of a [`Pauli`].
+fn pauli_matrix(pauli: Pauli) -> Vec<Vec<Complex<f64>>> {
+    match pauli.operator() {
+        Some(operator) => QuantumGate::new(operator).matrix(),
+        None => vec![
+            vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+            vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+        ],
+    }
+}
+
+/// [`pauli_product`] will get the matrix of the tensor product of `paulis`, where the first
+/// [`Pauli`] is the most significant bit of each row and column.
+fn pauli_product(paulis: &[Pauli]) -> Vec<Vec<Complex<f64>>> {
+    paulis
+        .iter()
+        .fold(vec![vec![Complex::new(1.0, 0.0)]], |product, &pauli| {
+            let matrix = pauli_matrix(pauli);
+            (0..product.len() * 2)
+                .map(|row| {
+                    (0..product.len() * 2)
+                        .map(|column| product[row / 2][column / 2] * matrix[row % 2][column % 2])
+                        .collect()
+                })
+                .collect()
+        })
+}
+
+/// [`operation_matrix`] will get the matrix of a [`QuantumOperation`] with at most one control,
+/// where the control is the most significant bit of each row and column.
+fn operation_matrix(operation: &QuantumOperation) -> Vec<Vec<Complex<f64>>> {
+    let gate = QuantumGate::new(operation.operator.clone()).matrix();
+    if operation.controls.is_empty() {
+        return gate;
+    }
+    (0..4)
+        .map(|row| {
+            (0..4)
+                .map(|column| match (row < 2, column < 2) {
+                    (true, true) if row == column => Complex::new(1.0, 0.0),
+                    (false, false) => gate[row - 2][column - 2],
+                    _ => Complex::new(0.0, 0.0),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// [`adjoint`] will get the conjugate transpose of a square matrix.
+fn adjoint(matrix: &[Vec<Complex<f64>>]) -> Vec<Vec<Complex<f64>>> {
+    (0..matrix.len())
+        .map(|row| matrix.iter().map(|column| column[row].conj()).collect())
+        .collect()
+}
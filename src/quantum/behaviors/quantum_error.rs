@@ -0,0 +1,46 @@
+use std::{error, fmt};
+
+use crate::quantum::types::quantum_error::QuantumError;
+
+/// Implement the [`fmt::Display`] trait for [`QuantumError`].
+impl fmt::Display for QuantumError {
+    /// Will return a short description of why an operation could not be done:
+    /// ```text
+    /// qubit index 3 is out of range for a register of 2 qubits
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`QuantumError`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, qubit_register::QubitRegister};
+    ///
+    /// fn print_quantum_error(qubit_register: &mut QubitRegister, target: usize) {
+    ///     if let Err(error) = qubit_register.try_apply_single_qubit_gate(&QuantumGate::NOT, target) {
+    ///         println!("{error}");
+    ///     }
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            QuantumError::InvalidQubitIndex { index, len } => write!(
+                f,
+                "qubit index {index} is out of range for a register of {len} qubits"
+            ),
+            QuantumError::InvalidBitstring {
+                character,
+                position,
+            } => write!(
+                f,
+                "bitstring has an invalid character '{character}' at position {position}"
+            ),
+            QuantumError::InvalidBitstringLength { length, len } => write!(
+                f,
+                "bitstring of length {length} does not match a register of {len} qubits"
+            ),
+        }
+    }
+}
+
+/// Implement the [`error::Error`] trait for [`QuantumError`], so it can be returned with `?`
+/// alongside other errors.
+impl error::Error for QuantumError {}
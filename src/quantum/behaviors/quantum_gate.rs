@@ -1,10 +1,17 @@
-use std::fmt;
+use std::{
+    f64::consts::{FRAC_PI_2, FRAC_PI_4},
+    fmt,
+};
 
 use num_complex::Complex;
 
-use crate::quantum::types::{
-    quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
-    quantum_position::QuantumPosition,
+use crate::quantum::{
+    behaviors::qasm_stream::evaluate,
+    types::{
+        display_precision::DisplayPrecision, gate_name_error::GateNameError,
+        quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+        quantum_position::QuantumPosition,
+    },
 };
 
 impl QuantumGate {
@@ -37,7 +44,10 @@ impl QuantumGate {
     ///     !qubit
     /// }
     /// ```
-    pub const NOT: QuantumGate = QuantumGate::new(QuantumOperator::NOT);
+    pub const NOT: QuantumGate = QuantumGate::from_transform(
+        QuantumOperator::NOT,
+        [QuantumPosition::ONE, QuantumPosition::ZERO],
+    );
 
     /// The [`ROTATE`](QuantumGate::ROTATE) gate rotates a [`Qubit`](crate::quantum::types::qubit::Qubit)
     /// 180 degrees around it's Y-axis.
@@ -60,7 +70,13 @@ impl QuantumGate {
     ///     qubit.apply_gate(&QuantumGate::ROTATE)
     /// }
     /// ```
-    pub const ROTATE: QuantumGate = QuantumGate::new(QuantumOperator::ROTATE);
+    pub const ROTATE: QuantumGate = QuantumGate::from_transform(
+        QuantumOperator::ROTATE,
+        [
+            QuantumPosition::BACK_QUARTER_TURN,
+            QuantumPosition::QUARTER_TURN,
+        ],
+    );
 
     /// The [`PHASE`](QuantumGate::PHASE) gate leaves the state of a [`Qubit`](crate::quantum::types::qubit::Qubit)
     /// unchanged, and flips the phase of the $|1\rangle$ state by $\pi$.
@@ -83,7 +99,10 @@ impl QuantumGate {
     ///     qubit.apply_gate(&QuantumGate::PHASE)
     /// }
     /// ```
-    pub const PHASE: QuantumGate = QuantumGate::new(QuantumOperator::PHASE);
+    pub const PHASE: QuantumGate = QuantumGate::from_transform(
+        QuantumOperator::PHASE,
+        [QuantumPosition::ZERO, QuantumPosition::FLIP],
+    );
 
     /// The [`SUPERPOSITION`](QuantumGate::SUPERPOSITION) turns the amplitude of the $|0\rangle$ and $|1\rangle$
     /// states of a [`Qubit`](crate::quantum::types::qubit::Qubit) into an equal superposition of $|0\rangle$
@@ -106,7 +125,19 @@ impl QuantumGate {
     ///     qubit.apply_gate(&QuantumGate::SUPERPOSITION)
     /// }
     /// ```
-    pub const SUPERPOSITION: QuantumGate = QuantumGate::new(QuantumOperator::SUPERPOSITION);
+    pub const SUPERPOSITION: QuantumGate = QuantumGate::from_transform(
+        QuantumOperator::SUPERPOSITION,
+        [
+            QuantumPosition::new(
+                Complex::new(1.0 / std::f64::consts::SQRT_2, 0.0),
+                Complex::new(1.0 / std::f64::consts::SQRT_2, 0.0),
+            ),
+            QuantumPosition::new(
+                Complex::new(1.0 / std::f64::consts::SQRT_2, 0.0),
+                Complex::new(-1.0 / std::f64::consts::SQRT_2, 0.0),
+            ),
+        ],
+    );
 
     /// [`QuantumGate::new`] will create a [`QuantumGate`] to transform a [`Qubit`](crate::quantum::types::qubit::Qubit)
     /// in complex vector space based on the provided [`QuantumOperator`].
@@ -122,21 +153,206 @@ impl QuantumGate {
     /// }
     /// ```
     pub const fn new(operator: QuantumOperator) -> Self {
-        let transform = match operator {
-            QuantumOperator::NOT => [QuantumPosition::ONE, QuantumPosition::ZERO],
-            QuantumOperator::ROTATE => [
-                QuantumPosition::BACK_QUARTER_TURN,
-                QuantumPosition::QUARTER_TURN,
-            ],
-            QuantumOperator::PHASE => [QuantumPosition::ZERO, QuantumPosition::FLIP],
-            QuantumOperator::SUPERPOSITION => {
-                let factor = 1.0 / std::f64::consts::SQRT_2;
-                [
-                    QuantumPosition::new(Complex::new(factor, 0.0), Complex::new(factor, 0.0)),
-                    QuantumPosition::new(Complex::new(factor, 0.0), Complex::new(-factor, 0.0)),
-                ]
+        match operator {
+            QuantumOperator::NOT => QuantumGate::NOT,
+            QuantumOperator::ROTATE => QuantumGate::ROTATE,
+            QuantumOperator::PHASE => QuantumGate::PHASE,
+            QuantumOperator::SUPERPOSITION => QuantumGate::SUPERPOSITION,
+            QuantumOperator::RX(angle) => {
+                let (sin, cos) = sin_cos(angle / 2.0);
+                QuantumGate::from_transform(
+                    operator,
+                    [
+                        QuantumPosition::new(Complex::new(cos, 0.0), Complex::new(0.0, -sin)),
+                        QuantumPosition::new(Complex::new(0.0, -sin), Complex::new(cos, 0.0)),
+                    ],
+                )
+            }
+            QuantumOperator::RY(angle) => {
+                let (sin, cos) = sin_cos(angle / 2.0);
+                QuantumGate::from_transform(
+                    operator,
+                    [
+                        QuantumPosition::new(Complex::new(cos, 0.0), Complex::new(-sin, 0.0)),
+                        QuantumPosition::new(Complex::new(sin, 0.0), Complex::new(cos, 0.0)),
+                    ],
+                )
+            }
+            QuantumOperator::RZ(angle) => {
+                let (sin, cos) = sin_cos(angle / 2.0);
+                QuantumGate::from_transform(
+                    operator,
+                    [
+                        QuantumPosition::new(Complex::new(cos, -sin), Complex::new(0.0, 0.0)),
+                        QuantumPosition::new(Complex::new(0.0, 0.0), Complex::new(cos, sin)),
+                    ],
+                )
             }
+        }
+    }
+
+    /// [`QuantumGate::from_name`] will create a new [`QuantumGate`] from it's standard lowercase
+    /// name, with any parameters in parentheses, like `h`, `x` or `rz(pi/2)`.
+    ///
+    /// The `x`, `y`, `z`, `h`, `s`, `sdg`, `t`, `tdg`, `rx`, `ry` and `rz` names are supported. The
+    /// `s` and `t` gates are Z rotations, which only differ from them by a global phase. Parameters
+    /// can be numbers, `pi`, parentheses and the `+`, `-`, `*` and `/` operators.
+    ///
+    /// # Example
+    /// [`QuantumGate::from_name`] can be used to read gates typed by a user:
+    /// ```rust
+    /// use rquant::quantum::types::{gate_name_error::GateNameError, quantum_gate::QuantumGate};
+    ///
+    /// fn read_gates(names: &[&str]) -> Result<Vec<QuantumGate>, GateNameError> {
+    ///     names.iter().map(|name| QuantumGate::from_name(name)).collect()
+    /// }
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, GateNameError> {
+        let name = name.trim();
+        let (name, parameters) = match name.split_once('(') {
+            Some((name, rest)) => {
+                let rest = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| GateNameError::InvalidParameter(rest.to_string()))?;
+                let parameters = rest
+                    .split(',')
+                    .map(|parameter| {
+                        evaluate(parameter)
+                            .ok_or_else(|| GateNameError::InvalidParameter(parameter.to_string()))
+                    })
+                    .collect::<Result<Vec<f64>, GateNameError>>()?;
+                (name.trim(), parameters)
+            }
+            None => (name, vec![]),
         };
+        QuantumGate::from_name_with_parameters(name, &parameters)
+    }
+
+    /// [`QuantumGate::from_name_with_parameters`] will create a new [`QuantumGate`] from it's
+    /// standard lowercase name without parentheses, like `h` or `rz`, and it's already evaluated
+    /// `parameters`.
+    ///
+    /// # Example
+    /// [`QuantumGate::from_name_with_parameters`] can be used to read a rotation with a computed
+    /// angle:
+    /// ```rust
+    /// use rquant::quantum::types::{gate_name_error::GateNameError, quantum_gate::QuantumGate};
+    ///
+    /// fn read_rotation(axis: &str, angle: f64) -> Result<QuantumGate, GateNameError> {
+    ///     QuantumGate::from_name_with_parameters(&format!("r{axis}"), &[angle])
+    /// }
+    /// ```
+    pub fn from_name_with_parameters(
+        name: &str,
+        parameters: &[f64],
+    ) -> Result<Self, GateNameError> {
+        operator_from_name(name, parameters).map(QuantumGate::new)
+    }
+
+    /// [`QuantumGate::name`] will get the standard lowercase name of a [`QuantumGate`], with it's
+    /// angle in parentheses for a rotation, which [`QuantumGate::from_name`] reads back into the
+    /// same [`QuantumGate`].
+    ///
+    /// # Example
+    /// [`QuantumGate::name`] can be used to label the gates of a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate};
+    ///
+    /// fn gate_labels(circuit: &QuantumCircuit) -> Vec<String> {
+    ///     circuit
+    ///         .operations
+    ///         .iter()
+    ///         .map(|operation| QuantumGate::new(operation.operator.clone()).name())
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn name(&self) -> String {
+        match self.operator {
+            QuantumOperator::RX(angle)
+            | QuantumOperator::RY(angle)
+            | QuantumOperator::RZ(angle) => {
+                format!("{}({angle})", operator_name(&self.operator))
+            }
+            _ => operator_name(&self.operator).to_string(),
+        }
+    }
+
+    /// [`QuantumGate::is_unitary`] returns `true` if the `transform` of a [`QuantumGate`] is a
+    /// unitary matrix $U^\dagger U = I$ within a `tolerance`, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`QuantumGate::is_unitary`] can be used to check that a gate preserves probability:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_gate::QuantumGate;
+    ///
+    /// fn is_superposition_unitary() -> bool {
+    ///     QuantumGate::SUPERPOSITION.is_unitary(1e-9)
+    /// }
+    /// ```
+    pub fn is_unitary(&self, tolerance: f64) -> bool {
+        let [first, second] = self.transform;
+        let columns = [
+            [first.initial_position, second.initial_position],
+            [first.possible_position, second.possible_position],
+        ];
+        (0..2).all(|a| {
+            (0..2).all(|b| {
+                let product: Complex<f64> = (0..2)
+                    .map(|row| columns[a][row].conj() * columns[b][row])
+                    .sum();
+                let identity = if a == b { 1.0 } else { 0.0 };
+                (product - identity).norm() <= tolerance
+            })
+        })
+    }
+
+    /// [`QuantumGate::is_clifford`] returns `true` if a [`QuantumGate`] maps Pauli operators to
+    /// Pauli operators, which is every gate except a rotation by an angle that is not a multiple
+    /// of $\frac{\pi}{2}$, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`QuantumGate::is_clifford`] can be used to check if a gate fits on a
+    /// [`CliffordTableau`](crate::quantum::types::clifford_tableau::CliffordTableau):
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator};
+    ///
+    /// fn is_t_gate_clifford() -> bool {
+    ///     QuantumGate::new(QuantumOperator::RZ(std::f64::consts::FRAC_PI_4)).is_clifford()
+    /// }
+    /// ```
+    pub fn is_clifford(&self) -> bool {
+        match self.operator {
+            QuantumOperator::RX(angle)
+            | QuantumOperator::RY(angle)
+            | QuantumOperator::RZ(angle) => {
+                let turns = (angle / FRAC_PI_2).round();
+                (angle - turns * FRAC_PI_2).abs() < 1e-9
+            }
+            _ => true,
+        }
+    }
+
+    /// [`QuantumGate::matrix`] will get the `transform` of a [`QuantumGate`] as a matrix by row.
+    ///
+    /// # Example
+    /// [`QuantumGate::matrix`] can be used to apply a [`QuantumGate`] with controls:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn apply_toffoli(state_vector: &mut StateVector) {
+    ///     state_vector.apply_unitary(&QuantumGate::NOT.matrix(), &[2], &[0, 1]);
+    /// }
+    /// ```
+    pub fn matrix(&self) -> Vec<Vec<Complex<f64>>> {
+        self.transform
+            .iter()
+            .map(|row| vec![row.initial_position, row.possible_position])
+            .collect()
+    }
+
+    /// [`QuantumGate::from_transform`] will create a [`QuantumGate`] from a [`QuantumOperator`]
+    /// and the `transform` that it applies.
+    const fn from_transform(operator: QuantumOperator, transform: [QuantumPosition; 2]) -> Self {
         QuantumGate {
             operator,
             transform,
@@ -144,6 +360,53 @@ impl QuantumGate {
     }
 }
 
+/// [`operator_from_name`] will get the [`QuantumOperator`] of a standard gate `name` with it's
+/// `parameters`, which is the one mapping of gate names shared by every text format.
+pub(crate) fn operator_from_name(
+    name: &str,
+    parameters: &[f64],
+) -> Result<QuantumOperator, GateNameError> {
+    let expected = match name {
+        "x" | "y" | "z" | "h" | "s" | "sdg" | "t" | "tdg" => 0,
+        "rx" | "ry" | "rz" => 1,
+        _ => return Err(GateNameError::Unknown(name.to_string())),
+    };
+    if parameters.len() != expected {
+        return Err(GateNameError::Parameters {
+            name: name.to_string(),
+            expected,
+            found: parameters.len(),
+        });
+    }
+
+    Ok(match name {
+        "x" => QuantumOperator::NOT,
+        "y" => QuantumOperator::ROTATE,
+        "z" => QuantumOperator::PHASE,
+        "h" => QuantumOperator::SUPERPOSITION,
+        "s" => QuantumOperator::RZ(FRAC_PI_2),
+        "sdg" => QuantumOperator::RZ(-FRAC_PI_2),
+        "t" => QuantumOperator::RZ(FRAC_PI_4),
+        "tdg" => QuantumOperator::RZ(-FRAC_PI_4),
+        "rx" => QuantumOperator::RX(parameters[0]),
+        "ry" => QuantumOperator::RY(parameters[0]),
+        _ => QuantumOperator::RZ(parameters[0]),
+    })
+}
+
+/// [`operator_name`] will get the standard name of a [`QuantumOperator`], without it's parameters.
+pub(crate) fn operator_name(operator: &QuantumOperator) -> &'static str {
+    match operator {
+        QuantumOperator::NOT => "x",
+        QuantumOperator::ROTATE => "y",
+        QuantumOperator::PHASE => "z",
+        QuantumOperator::SUPERPOSITION => "h",
+        QuantumOperator::RX(_) => "rx",
+        QuantumOperator::RY(_) => "ry",
+        QuantumOperator::RZ(_) => "rz",
+    }
+}
+
 /// Implement the [`fmt::Display`] trait for [`QuantumGate`].
 impl fmt::Display for QuantumGate {
     /// Will return a [`String`] representation of a [`QuantumGate`].
@@ -165,10 +428,46 @@ impl fmt::Display for QuantumGate {
         write!(
             f,
             "\n┏          ┓\n┃ {} {} ┃\n┃ {} {} ┃\n┗          ┛",
-            self.transform[0].initial_position,
-            self.transform[1].initial_position,
-            self.transform[0].possible_position,
-            self.transform[1].possible_position
+            DisplayPrecision::format(self.transform[0].initial_position),
+            DisplayPrecision::format(self.transform[1].initial_position),
+            DisplayPrecision::format(self.transform[0].possible_position),
+            DisplayPrecision::format(self.transform[1].possible_position)
         )
     }
 }
+
+/// [`sin_cos`] will get the sine and cosine of an `angle` in a `const` context, where
+/// [`f64::sin_cos`] can not be used, by reducing the `angle` to within $\frac{\pi}{4}$ of a
+/// multiple of $\frac{\pi}{2}$ and summing the Taylor series of both.
+const fn sin_cos(angle: f64) -> (f64, f64) {
+    // pi / 2 is split into a high part with trailing zero bits and a low part, so the reduced
+    // angle does not lose precision when a multiple of it is subtracted.
+    const FRAC_PI_2_HIGH: f64 = 1.570_796_326_734_125_6;
+    const FRAC_PI_2_LOW: f64 = 6.077_100_506_506_192e-11;
+
+    let quotient = angle / FRAC_PI_2;
+    let quadrant = if quotient < 0.0 {
+        (quotient - 0.5) as i64
+    } else {
+        (quotient + 0.5) as i64
+    };
+    let reduced = (angle - quadrant as f64 * FRAC_PI_2_HIGH) - quadrant as f64 * FRAC_PI_2_LOW;
+
+    // Both series are nested, so the smallest terms are added first.
+    let squared = reduced * reduced;
+    let (mut sin, mut cos) = (1.0, 1.0);
+    let mut term = 10;
+    while term > 0 {
+        sin = 1.0 - squared / ((2 * term) * (2 * term + 1)) as f64 * sin;
+        cos = 1.0 - squared / ((2 * term - 1) * (2 * term)) as f64 * cos;
+        term -= 1;
+    }
+    sin *= reduced;
+
+    match quadrant.rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
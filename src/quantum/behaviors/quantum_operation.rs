@@ -0,0 +1,28 @@
+use crate::quantum::types::quantum_operation::QuantumOperation;
+
+impl QuantumOperation {
+    /// [`QuantumOperation::qubits`] will get the index of every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) a [`QuantumOperation`] acts on, which is it's
+    /// `controls` followed by it's `target`.
+    ///
+    /// # Example
+    /// [`QuantumOperation::qubits`] can be used to find every qubit touched by a circuit:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_circuit::QuantumCircuit;
+    ///
+    /// fn touched_qubits(circuit: &QuantumCircuit) -> Vec<usize> {
+    ///     circuit
+    ///         .operations
+    ///         .iter()
+    ///         .flat_map(|operation| operation.qubits())
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn qubits(&self) -> Vec<usize> {
+        self.controls
+            .iter()
+            .chain([&self.target])
+            .copied()
+            .collect()
+    }
+}
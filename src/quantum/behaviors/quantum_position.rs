@@ -69,4 +69,56 @@ impl QuantumPosition {
         // Allow a small margin of error for floating-point inaccuracy
         (sum_of_squares - 1.0).abs() < 10.0 * f64::EPSILON
     }
+
+    /// [`QuantumPosition::normalized`] will get a copy of a [`QuantumPosition`] with it's
+    /// amplitudes rescaled so the sum of their squares is one, which removes the drift that
+    /// floating point error builds up over many gates.
+    ///
+    /// The algorithm can be illustrated in the following statement:
+    /// $$ \frac{\alpha|0\rangle + \beta|1\rangle}{\sqrt{|\alpha|^2 + |\beta|^2}} $$
+    ///
+    /// # Example
+    /// [`QuantumPosition::normalized`] can be used to make a [`QuantumPosition`] from unscaled
+    /// amplitudes:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::quantum_position::QuantumPosition;
+    ///
+    /// fn create_plus_position() -> QuantumPosition {
+    ///     QuantumPosition::new(Complex::new(1.0, 0.0), Complex::new(1.0, 0.0)).normalized()
+    /// }
+    /// ```
+    pub fn normalized(&self) -> Self {
+        let norm = (self.initial_position.norm_sqr() + self.possible_position.norm_sqr()).sqrt();
+        // A position without any amplitude has no direction to rescale.
+        assert!(norm > 0.0, "Invalid qubit positions");
+
+        QuantumPosition::new(self.initial_position / norm, self.possible_position / norm)
+    }
+
+    /// [`QuantumPosition::bloch_vector`] will get the `[x, y, z]` coordinates of a [`QuantumPosition`]
+    /// on the Bloch sphere.
+    ///
+    /// The coordinates are calculated from the initial amplitude $\alpha$ and possible amplitude
+    /// $\beta$ with the following statements:
+    /// $$ x = 2\operatorname{Re}(\alpha^*\beta), \quad y = 2\operatorname{Im}(\alpha^*\beta),
+    /// \quad z = |\alpha|^2 - |\beta|^2 $$
+    ///
+    /// # Example
+    /// [`QuantumPosition::bloch_vector`] can be used to get the Bloch vector of a [`QuantumPosition`]:
+    /// ```rust
+    /// use rquant::quantum::types::quantum_position::QuantumPosition;
+    ///
+    /// fn get_zero_bloch_vector() -> [f64; 3] {
+    ///     QuantumPosition::ZERO.bloch_vector()
+    /// }
+    /// ```
+    pub fn bloch_vector(&self) -> [f64; 3] {
+        let coherence = self.initial_position.conj() * self.possible_position;
+        [
+            2.0 * coherence.re,
+            2.0 * coherence.im,
+            self.initial_position.norm_sqr() - self.possible_position.norm_sqr(),
+        ]
+    }
 }
@@ -1,10 +1,11 @@
 use crate::quantum::types::{
-    quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
-    quantum_position::QuantumPosition, qubit::Qubit,
+    amplitude_error::AmplitudeError, bloch_trajectory::BlochTrajectory,
+    display_precision::DisplayPrecision, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator, quantum_position::QuantumPosition, qubit::Qubit,
 };
 use num_complex::Complex;
 use rand::Rng;
-use std::{fmt, ops};
+use std::{fmt, iter, ops};
 
 impl Qubit {
     /// [`Qubit::new`] will create a new [`Qubit`] with a [`QuantumPosition`]
@@ -29,6 +30,35 @@ impl Qubit {
         }
     }
 
+    /// [`Qubit::try_new`] will create a new [`Qubit`] with a [`QuantumPosition`] in complex vector
+    /// space, or return an [`AmplitudeError`] instead of panicking like [`Qubit::new`] when the
+    /// squares of it's amplitudes do not add up to 1.
+    ///
+    /// # Example
+    /// [`Qubit::try_new`] can be used to create a [`Qubit`] from amplitudes read from a user:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::{
+    ///     amplitude_error::AmplitudeError, qubit::Qubit, quantum_position::QuantumPosition,
+    /// };
+    ///
+    /// fn read_qubit(alpha: Complex<f64>, beta: Complex<f64>) -> Result<Qubit, AmplitudeError> {
+    ///     Qubit::try_new(QuantumPosition::new(alpha, beta))
+    /// }
+    /// ```
+    pub fn try_new(position: QuantumPosition) -> Result<Self, AmplitudeError> {
+        if !position.has_valid_amplitude() {
+            return Err(AmplitudeError {
+                sum_of_squares: position.initial_position.norm_sqr()
+                    + position.possible_position.norm_sqr(),
+            });
+        }
+
+        Ok(Qubit {
+            positions: vec![position],
+        })
+    }
+
     /// [`Qubit::zero`] will return a new [`Qubit`] with it's position set to [`QuantumPosition::ZERO`].
     ///
     /// [`Qubit::zero`] can be represented by the following matrix:
@@ -122,9 +152,67 @@ impl Qubit {
         Qubit::new(QuantumPosition::BACK_QUARTER_TURN)
     }
 
+    /// [`Qubit::from_bloch_angles`] will return a new [`Qubit`] at the point of the Bloch sphere
+    /// with a polar angle `theta` $\theta$ from $|0\rangle$ and an azimuthal angle `phi` $\phi$
+    /// from the x-axis, both in radians:
+    /// $$ \cos\frac{\theta}{2}|0\rangle + e^{i\phi}\sin\frac{\theta}{2}|1\rangle $$
+    ///
+    /// # Example
+    /// [`Qubit::from_bloch_angles`] can be used to get a [`Qubit`] on the y-axis:
+    /// ```rust
+    /// use std::f64::consts::FRAC_PI_2;
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn get_plus_i_qubit() -> Qubit {
+    ///     Qubit::from_bloch_angles(FRAC_PI_2, FRAC_PI_2)
+    /// }
+    /// ```
+    pub fn from_bloch_angles(theta: f64, phi: f64) -> Self {
+        let (sin, cos) = (theta / 2.0).sin_cos();
+        Qubit::new(QuantumPosition::new(
+            Complex::new(cos, 0.0),
+            Complex::from_polar(sin, phi),
+        ))
+    }
+
+    /// [`Qubit::from_probabilities`] will return a new [`Qubit`] that measures as $|0\rangle$ with
+    /// probability `p0` and as $|1\rangle$ with probability `p1`, with a `relative_phase` $\phi$
+    /// in radians between them:
+    /// $$ \sqrt{p_0}|0\rangle + e^{i\phi}\sqrt{p_1}|1\rangle $$
+    ///
+    /// The probabilities must not be negative, and must add up to 1 within `1e-9`. They are
+    /// rescaled to add up to exactly 1.
+    ///
+    /// # Example
+    /// [`Qubit::from_probabilities`] can be used to get a [`Qubit`] that is measured as
+    /// $|1\rangle$ a quarter of the time:
+    /// ```rust
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn get_quarter_one_qubit() -> Qubit {
+    ///     Qubit::from_probabilities(0.75, 0.25, 0.0)
+    /// }
+    /// ```
+    pub fn from_probabilities(p0: f64, p1: f64, relative_phase: f64) -> Self {
+        // The probabilities of the qubit's measurement outcomes must add up to 1.
+        assert!(
+            p0 >= 0.0 && p1 >= 0.0 && (p0 + p1 - 1.0).abs() < 1e-9,
+            "Invalid qubit probabilities"
+        );
+
+        let total = p0 + p1;
+        Qubit::new(QuantumPosition::new(
+            Complex::new((p0 / total).sqrt(), 0.0),
+            Complex::from_polar((p1 / total).sqrt(), relative_phase),
+        ))
+    }
+
     /// [`Qubit::update`] will move the [`Qubit`] that calls it to a new [`QuantumPosition`] in
     /// complex vector space, and maintains the old position inside of [`Qubit::positions`].
     ///
+    /// The new position is placed at the front of [`Qubit::positions`], so it becomes the
+    /// current position.
+    ///
     /// # Example
     /// [`Qubit::update`] can be used to update the position of a [`Qubit`].
     /// ```rust
@@ -135,7 +223,60 @@ impl Qubit {
     /// }
     /// ```
     pub fn update(&mut self, new_position: QuantumPosition) {
-        self.positions.push(new_position);
+        self.positions.insert(0, new_position);
+    }
+
+    /// [`Qubit::bloch_coordinates`] will get the `(x, y, z)` coordinates of the current
+    /// [`QuantumPosition`] of the [`Qubit`] on the Bloch sphere, where $|0\rangle$ points up
+    /// along $z$ and an equal superposition points along $x$.
+    ///
+    /// # Example
+    /// [`Qubit::bloch_coordinates`] can be used to check that a Hadamard gate turns $z$ into $x$:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_points_along_x() -> bool {
+    ///     let (x, _, _) = Qubit::zero()
+    ///         .apply_gate(&QuantumGate::SUPERPOSITION)
+    ///         .bloch_coordinates();
+    ///     (x - 1.0).abs() < 1e-9
+    /// }
+    /// ```
+    pub fn bloch_coordinates(&self) -> (f64, f64, f64) {
+        let [x, y, z] = self.position().bloch_vector();
+        (x, y, z)
+    }
+
+    /// [`Qubit::bloch_trajectory`] will get a [`BlochTrajectory`] of how a sequence of `gates`
+    /// moves the [`Qubit`] that calls it, with a frame for it's current position followed by a
+    /// frame after each [`QuantumGate`].
+    ///
+    /// # Example
+    /// [`Qubit::bloch_trajectory`] can be used to export how a [`QuantumGate`] moves a
+    /// [`Qubit`] around the Bloch sphere:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, qubit::Qubit};
+    ///
+    /// fn export_superposition_trajectory() -> String {
+    ///     Qubit::zero()
+    ///         .bloch_trajectory(&[QuantumGate::SUPERPOSITION])
+    ///         .to_csv()
+    /// }
+    /// ```
+    pub fn bloch_trajectory(&self, gates: &[QuantumGate]) -> BlochTrajectory {
+        let positions = gates
+            .iter()
+            .scan(Qubit::new(self.position()), |qubit, gate| {
+                *qubit = qubit.apply_gate(gate);
+                Some(qubit.position())
+            });
+
+        BlochTrajectory {
+            frames: iter::once(self.position())
+                .chain(positions)
+                .map(|position| position.bloch_vector())
+                .collect(),
+        }
     }
 
     /// [`Qubit::apply_gate`] will apply a [`QuantumGate`] to the [`QuantumPosition`]
@@ -153,17 +294,27 @@ impl Qubit {
     /// }
     /// ```
     pub fn apply_gate(&self, gate: &QuantumGate) -> Self {
-        let first_gate = gate.transform[0];
-        let second_gate = gate.transform[1];
-
-        let qubit_position = QuantumPosition::new(
-            first_gate.initial_position * self.initial_position()
-                + first_gate.possible_position * self.possible_position(),
-            second_gate.initial_position * self.initial_position()
-                + second_gate.possible_position * self.possible_position(),
-        );
+        Qubit::new(self.gate_position(gate))
+    }
 
-        Qubit::new(qubit_position)
+    /// [`Qubit::apply_gate_mut`] will apply a [`QuantumGate`] to the [`QuantumPosition`] of the
+    /// [`Qubit`] that calls it in place, moving it to the same position as [`Qubit::apply_gate`]
+    /// and keeping the old position inside of [`Qubit::positions`], like [`Qubit::update`].
+    ///
+    /// # Example
+    /// [`Qubit::apply_gate_mut`] can be used to apply many gates to a [`Qubit`] in a loop:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn rotate_qubit_many_times(qubit: &mut Qubit) {
+    ///     for _ in 0..1000 {
+    ///         qubit.apply_gate_mut(&QuantumGate::ROTATE);
+    ///     }
+    /// }
+    /// ```
+    pub fn apply_gate_mut(&mut self, gate: &QuantumGate) {
+        let position = self.gate_position(gate);
+        self.update(position);
     }
 
     /// [`Qubit::measure`] will measure a [`Qubit`] position in complex vector space,
@@ -183,9 +334,164 @@ impl Qubit {
     /// }
     /// ```
     pub fn measure(&self) -> bool {
+        self.measure_with(&mut rand::rng())
+    }
+
+    /// [`Qubit::measure_with`] will measure a [`Qubit`] in the same way as [`Qubit::measure`],
+    /// using a random number generator `rng` instead of the thread's generator, so a seeded `rng`
+    /// always observes the same outcome.
+    ///
+    /// # Example
+    /// [`Qubit::measure_with`] can be used to reproduce a measurement:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn observe_seeded_superposition(seed: u64) -> bool {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     Qubit::zero()
+    ///         .apply_gate(&QuantumGate::SUPERPOSITION)
+    ///         .measure_with(&mut rng)
+    /// }
+    /// ```
+    pub fn measure_with(&self, rng: &mut impl Rng) -> bool {
         let prob_zero = self.initial_position().norm_sqr();
-        let mut rng = rand::rng();
-        rng.random_bool(prob_zero)
+        rng.random_bool(prob_zero.clamp(0.0, 1.0))
+    }
+
+    /// [`Qubit::probabilities`] will get the exact probability of measuring a [`Qubit`] as
+    /// $|0\rangle$ and as $|1\rangle$, in that order, without measuring it.
+    ///
+    /// # Example
+    /// [`Qubit::probabilities`] can be used to get the chance of a `false` measurement:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn superposition_one_probability() -> f64 {
+    ///     Qubit::zero()
+    ///         .apply_gate(&QuantumGate::SUPERPOSITION)
+    ///         .probabilities()[1]
+    /// }
+    /// ```
+    pub fn probabilities(&self) -> [f64; 2] {
+        [
+            self.initial_position().norm_sqr(),
+            self.possible_position().norm_sqr(),
+        ]
+    }
+
+    /// [`Qubit::amplitudes`] will get the complex amplitudes of $|0\rangle$ and $|1\rangle$ of
+    /// the [`Qubit`] that calls it.
+    ///
+    /// # Example
+    /// [`Qubit::amplitudes`] can be used to get the relative phase of a [`Qubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn relative_phase(qubit: &Qubit) -> f64 {
+    ///     let [zero, one] = qubit.amplitudes();
+    ///     one.arg() - zero.arg()
+    /// }
+    /// ```
+    pub fn amplitudes(&self) -> [Complex<f64>; 2] {
+        [self.initial_position(), self.possible_position()]
+    }
+
+    /// [`Qubit::inner_product`] will get the inner product $\langle a|b\rangle$ of the [`Qubit`]
+    /// that calls it and an `other` [`Qubit`].
+    ///
+    /// # Example
+    /// [`Qubit::inner_product`] can be used to check if two qubits are orthogonal:
+    /// ```rust
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn is_orthogonal(a: &Qubit, b: &Qubit) -> bool {
+    ///     a.inner_product(b).norm() < 1e-9
+    /// }
+    /// ```
+    pub fn inner_product(&self, other: &Qubit) -> Complex<f64> {
+        self.initial_position().conj() * other.initial_position()
+            + self.possible_position().conj() * other.possible_position()
+    }
+
+    /// [`Qubit::fidelity`] will get the fidelity $|\langle a|b\rangle|^2$ of the [`Qubit`] that
+    /// calls it and an `other` [`Qubit`], which is `1.0` for the same state up to a global phase
+    /// and `0.0` for orthogonal states.
+    ///
+    /// # Example
+    /// [`Qubit::fidelity`] can be used to compare a qubit with a tolerance instead of exactly:
+    /// ```rust
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn is_close(a: &Qubit, b: &Qubit) -> bool {
+    ///     a.fidelity(b) > 1.0 - 1e-9
+    /// }
+    /// ```
+    pub fn fidelity(&self, other: &Qubit) -> f64 {
+        self.inner_product(other).norm_sqr()
+    }
+
+    /// [`Qubit::trace_distance`] will get the trace distance $\sqrt{1 - F}$ of the [`Qubit`] that
+    /// calls it and an `other` [`Qubit`], which is the largest difference in probability that any
+    /// measurement can observe between them.
+    ///
+    /// # Example
+    /// [`Qubit::trace_distance`] can be used to get how distinguishable two qubits are:
+    /// ```rust
+    /// use rquant::quantum::types::qubit::Qubit;
+    ///
+    /// fn distinguishability(a: &Qubit, b: &Qubit) -> f64 {
+    ///     a.trace_distance(b)
+    /// }
+    /// ```
+    pub fn trace_distance(&self, other: &Qubit) -> f64 {
+        (1.0 - self.fidelity(other)).max(0.0).sqrt()
+    }
+
+    /// [`Qubit::measure_mut`] will measure a [`Qubit`] in the same way as [`Qubit::measure`], then
+    /// collapse it to the observed basis state, so measuring it again always agrees.
+    ///
+    /// The collapsed position is added with [`Qubit::update`], so the [`Qubit`] keeps a record of
+    /// where it was before it was measured.
+    ///
+    /// # Example
+    /// [`Qubit::measure_mut`] can be used to observe a superposition once:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn observe_superposition_twice() -> (bool, bool) {
+    ///     let mut qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    ///     (qubit.measure_mut(), qubit.measure_mut())
+    /// }
+    /// ```
+    pub fn measure_mut(&mut self) -> bool {
+        self.measure_mut_with(&mut rand::rng())
+    }
+
+    /// [`Qubit::measure_mut_with`] will measure and collapse a [`Qubit`] in the same way as
+    /// [`Qubit::measure_mut`], using a random number generator `rng` instead of the thread's
+    /// generator, so a seeded `rng` always collapses to the same state.
+    ///
+    /// # Example
+    /// [`Qubit::measure_mut_with`] can be used to reproduce a collapse:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::{qubit::Qubit, quantum_gate::QuantumGate};
+    ///
+    /// fn collapse_seeded_superposition(seed: u64) -> Qubit {
+    ///     let mut qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    ///     qubit.measure_mut_with(&mut StdRng::seed_from_u64(seed));
+    ///     qubit
+    /// }
+    /// ```
+    pub fn measure_mut_with(&mut self, rng: &mut impl Rng) -> bool {
+        let measured = self.measure_with(rng);
+        self.update(if measured {
+            QuantumPosition::ZERO
+        } else {
+            QuantumPosition::ONE
+        });
+        measured
     }
 
     /// [`Qubit::initial_position`] will retrieve the current initial position
@@ -232,6 +538,28 @@ impl Qubit {
             .cloned()
             .expect("Must have an initial qubit position.")
     }
+
+    /// [`Qubit::gate_position`] will get the [`QuantumPosition`] of the [`Qubit`] that calls it
+    /// after a [`QuantumGate`] is applied.
+    fn gate_position(&self, gate: &QuantumGate) -> QuantumPosition {
+        let first_gate = gate.transform[0];
+        let second_gate = gate.transform[1];
+
+        let qubit_position = QuantumPosition::new(
+            first_gate.initial_position * self.initial_position()
+                + first_gate.possible_position * self.possible_position(),
+            second_gate.initial_position * self.initial_position()
+                + second_gate.possible_position * self.possible_position(),
+        );
+
+        // Rescale away the floating point drift that builds up over many gates, once it is
+        // too large for a new qubit.
+        if qubit_position.has_valid_amplitude() {
+            qubit_position
+        } else {
+            qubit_position.normalized()
+        }
+    }
 }
 
 /// Implement the `!` operator for [`Qubit`].
@@ -267,7 +595,7 @@ impl fmt::Display for Qubit {
     /// $$ 1|0\rangle $$
     ///
     /// While a more complex [`Qubit`], such as [`Qubit::back_quarter_turn`] will be presented as:
-    /// $$ 0|0-1i\rangle $$
+    /// $$ 0|-1i\rangle $$
     ///
     /// # Example
     /// Can be used to print a [`Qubit`] to the console:
@@ -279,19 +607,8 @@ impl fmt::Display for Qubit {
     /// }
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let initial_includes_imaginary = self.initial_position().im != 0.0;
-        let alpha = if initial_includes_imaginary {
-            self.initial_position().to_string()
-        } else {
-            self.initial_position().re.to_string()
-        };
-
-        let possible_includes_imaginary = self.possible_position().im != 0.0;
-        let beta = if possible_includes_imaginary {
-            self.possible_position().to_string()
-        } else {
-            self.possible_position().re.to_string()
-        };
+        let alpha = DisplayPrecision::format(self.initial_position());
+        let beta = DisplayPrecision::format(self.possible_position());
 
         write!(f, "{}|{}〉", alpha, beta)
     }
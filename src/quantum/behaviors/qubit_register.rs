@@ -1,5 +1,12 @@
+use std::ops::Range;
+
+use num_complex::Complex;
+use rand::Rng;
+
 use crate::quantum::types::{
-    quantum_gate::QuantumGate, qubit::Qubit, qubit_register::QubitRegister,
+    basis::Basis, graph_state::GraphState, quantum_circuit::QuantumCircuit,
+    quantum_error::QuantumError, quantum_gate::QuantumGate, quantum_position::QuantumPosition,
+    qubit::Qubit, qubit_register::QubitRegister, qubit_view::QubitView, state_vector::StateVector,
 };
 
 impl QubitRegister {
@@ -21,6 +28,108 @@ impl QubitRegister {
         }
     }
 
+    /// [`QubitRegister::ghz`] creates a [`StateVector`] in the Greenberger–Horne–Zeilinger state of
+    /// `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit), using [`StateVector::ghz`].
+    ///
+    /// Every [`Qubit`] of a [`QubitRegister`] is independent, so it can not hold an entangled GHZ
+    /// state, and a [`StateVector`] is returned instead.
+    ///
+    /// # Example
+    /// [`QubitRegister::ghz`] can be used to create a three qubit GHZ state:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit_register::QubitRegister, state_vector::StateVector};
+    ///
+    /// fn create_ghz_state() -> StateVector {
+    ///     QubitRegister::ghz(3)
+    /// }
+    /// ```
+    pub fn ghz(num_qubits: usize) -> StateVector {
+        StateVector::ghz(num_qubits)
+    }
+
+    /// [`QubitRegister::graph_state`] creates a [`GraphState`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), given the `(a, b)` qubit index pairs of
+    /// every edge, using [`GraphState::new`].
+    ///
+    /// Every [`Qubit`] of a [`QubitRegister`] is independent, so it can not hold an entangled graph
+    /// state, and a [`GraphState`] is returned instead.
+    ///
+    /// # Example
+    /// [`QubitRegister::graph_state`] can be used to create a triangle [`GraphState`]:
+    /// ```rust
+    /// use rquant::quantum::types::{graph_state::GraphState, qubit_register::QubitRegister};
+    ///
+    /// fn create_triangle_graph_state() -> GraphState {
+    ///     QubitRegister::graph_state(3, &[(0, 1), (1, 2), (0, 2)])
+    /// }
+    /// ```
+    pub fn graph_state(num_qubits: usize, adjacency: &[(usize, usize)]) -> GraphState {
+        GraphState::new(num_qubits, adjacency)
+    }
+
+    /// [`QubitRegister::from_bitstring`] creates a new [`QubitRegister`] from a classical
+    /// `bitstring` like `"01101"`, where every `0` is a [`Qubit::zero`] and every `1` is a
+    /// [`Qubit::one`], in order, or returns a [`QuantumError`] for the first other character.
+    ///
+    /// # Example
+    /// [`QubitRegister::from_bitstring`] can be used to set up the input of an algorithm:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_error::QuantumError, qubit_register::QubitRegister};
+    ///
+    /// fn create_input_register() -> Result<QubitRegister, QuantumError> {
+    ///     QubitRegister::from_bitstring("01101")
+    /// }
+    /// ```
+    pub fn from_bitstring(bitstring: &str) -> Result<Self, QuantumError> {
+        let qubits = bitstring
+            .chars()
+            .enumerate()
+            .map(|(position, character)| match character {
+                '0' => Ok(Qubit::zero()),
+                '1' => Ok(Qubit::one()),
+                _ => Err(QuantumError::InvalidBitstring {
+                    character,
+                    position,
+                }),
+            })
+            .collect::<Result<Vec<Qubit>, QuantumError>>()?;
+        Ok(QubitRegister { qubits })
+    }
+
+    /// [`QubitRegister::from_basis_state`] creates a new [`QubitRegister`] of `num_qubits`
+    /// [`Qubits`](Qubit) in the computational basis state $|value\rangle$, where the first
+    /// [`Qubit`] is the most significant bit.
+    ///
+    /// # Example
+    /// [`QubitRegister::from_basis_state`] can be used to start an arithmetic test from a number:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn create_five_register() -> QubitRegister {
+    ///     QubitRegister::from_basis_state(5, 4)
+    /// }
+    /// ```
+    pub fn from_basis_state(value: u64, num_qubits: usize) -> Self {
+        // The value must be representable with the qubits of the register.
+        assert!(
+            num_qubits >= 64 || value >> num_qubits == 0,
+            "Basis state does not fit in the register"
+        );
+
+        QubitRegister {
+            qubits: (0..num_qubits)
+                .map(|index| {
+                    let bit = num_qubits - 1 - index;
+                    if bit < 64 && (value >> bit) & 1 == 1 {
+                        Qubit::one()
+                    } else {
+                        Qubit::zero()
+                    }
+                })
+                .collect(),
+        }
+    }
+
     /// [`QubitRegister::len`] returns the number of qubits in the register.
     ///
     /// # Example
@@ -87,9 +196,118 @@ impl QubitRegister {
         self.qubits.get_mut(index)
     }
 
+    /// [`QubitRegister::view`] will create a [`QubitView`] of the [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// whose bits are set in `mask`, where bit `i` selects the qubit at index `i`.
+    ///
+    /// # Example
+    /// [`QubitRegister::view`] can be used to focus on the counting qubits of a register:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit_register::QubitRegister, qubit_view::QubitView};
+    ///
+    /// fn counting_distribution(qubit_register: &QubitRegister) -> Vec<f64> {
+    ///     let counting: QubitView = qubit_register.view(0b0011);
+    ///     qubit_register.marginal_probabilities(&counting)
+    /// }
+    /// ```
+    pub fn view(&self, mask: usize) -> QubitView {
+        let view = QubitView::new(mask);
+        assert!(
+            view.iter().all(|&target| target < self.len()),
+            "Invalid qubit index"
+        );
+        view
+    }
+
+    /// [`QubitRegister::slice`] will create a [`QubitView`] of the neighboring
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices in `range`.
+    ///
+    /// # Example
+    /// [`QubitRegister::slice`] can be used to name the counting and work qubits of a register:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit_register::QubitRegister, qubit_view::QubitView};
+    ///
+    /// fn split_register(qubit_register: &QubitRegister) -> (QubitView, QubitView) {
+    ///     (qubit_register.slice(0..3), qubit_register.slice(3..qubit_register.len()))
+    /// }
+    /// ```
+    pub fn slice(&self, range: Range<usize>) -> QubitView {
+        assert!(range.end <= self.len(), "Invalid qubit index");
+        QubitView::range(range.start, range.len())
+    }
+
+    /// [`QubitRegister::apply_gate_to_view`] applies a single-qubit [`QuantumGate`] to every
+    /// [`Qubit`] selected by a [`QubitView`] of a [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`QubitRegister::apply_gate_to_view`] can be used to put the counting qubits into
+    /// superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, qubit_register::QubitRegister};
+    ///
+    /// fn prepare_counting_qubits(qubit_register: &mut QubitRegister) {
+    ///     let counting = qubit_register.slice(0..3);
+    ///     qubit_register.apply_gate_to_view(&QuantumGate::SUPERPOSITION, &counting)
+    /// }
+    /// ```
+    pub fn apply_gate_to_view(&mut self, gate: &QuantumGate, view: &QubitView) {
+        for &target in view.iter() {
+            self.apply_single_qubit_gate(gate, target);
+        }
+    }
+
+    /// [`QubitRegister::swap`] applies a SWAP gate between the [`Qubits`](Qubit) at indices `a`
+    /// and `b` of a [`QubitRegister`], which exchanges their places.
+    ///
+    /// # Example
+    /// [`QubitRegister::swap`] can be used to reverse the output of a Fourier transform:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn reverse_two_qubits(qubit_register: &mut QubitRegister) {
+    ///     qubit_register.swap(0, 1)
+    /// }
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        assert!(a < self.len() && b < self.len(), "Invalid qubit index");
+        self.qubits.swap(a, b);
+    }
+
+    /// [`QubitRegister::permute`] reorders the [`Qubits`](Qubit) of a [`QubitRegister`], so the
+    /// [`Qubit`] at index `i` afterwards is the one that was at index `permutation[i]`.
+    ///
+    /// # Example
+    /// [`QubitRegister::permute`] can be used to route qubits into a new order:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn rotate_left(qubit_register: &mut QubitRegister) {
+    ///     qubit_register.permute(&[1, 2, 0])
+    /// }
+    /// ```
+    pub fn permute(&mut self, permutation: &[usize]) {
+        // Every qubit must be placed exactly once.
+        let mut placed = vec![false; self.len()];
+        assert!(
+            permutation.len() == self.len()
+                && permutation.iter().all(
+                    |&index| index < self.len() && !std::mem::replace(&mut placed[index], true)
+                ),
+            "Invalid qubit permutation"
+        );
+
+        self.qubits = permutation
+            .iter()
+            .map(|&index| self.qubits[index].clone())
+            .collect();
+    }
+
     /// [`QubitRegister::apply_single_qubit_gate`] applies a single-qubit [`QuantumGate`]
     /// to a specific [`Qubit`] in a [`QubitRegister`].
     ///
+    /// An error is printed and the [`QubitRegister`] is left unchanged if `target_qubit` is not in
+    /// the [`QubitRegister`], and [`QubitRegister::try_apply_single_qubit_gate`] can be used to
+    /// handle it instead.
+    ///
     /// # Example
     /// [`QubitRegister::apply_single_qubit_gate`] can be used to apply a single-qubit
     /// [`QuantumGate`] to a [`Qubit`] in a [`QubitRegister`]:
@@ -105,12 +323,501 @@ impl QubitRegister {
     /// }
     /// ```
     pub fn apply_single_qubit_gate(&mut self, gate: &QuantumGate, target_qubit: usize) {
-        if let Some(qubit) = self.qubits.get_mut(target_qubit) {
-            *qubit = qubit.apply_gate(gate);
-        } else {
+        if self
+            .try_apply_single_qubit_gate(gate, target_qubit)
+            .is_err()
+        {
             eprintln!("Error: Invalid qubit index");
         }
     }
+
+    /// [`QubitRegister::try_apply_single_qubit_gate`] applies a single-qubit [`QuantumGate`] to a
+    /// specific [`Qubit`] in a [`QubitRegister`] like [`QubitRegister::apply_single_qubit_gate`],
+    /// or returns a [`QuantumError`] instead of printing an error when `target_qubit` is not in the
+    /// [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`QubitRegister::try_apply_single_qubit_gate`] can be used to apply a gate to a qubit
+    /// chosen by a user:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_error::QuantumError,
+    ///     quantum_gate::QuantumGate,
+    ///     qubit_register::QubitRegister,
+    /// };
+    ///
+    /// fn flip_chosen_qubit(
+    ///     qubit_register: &mut QubitRegister,
+    ///     target: usize,
+    /// ) -> Result<(), QuantumError> {
+    ///     qubit_register.try_apply_single_qubit_gate(&QuantumGate::NOT, target)
+    /// }
+    /// ```
+    pub fn try_apply_single_qubit_gate(
+        &mut self,
+        gate: &QuantumGate,
+        target_qubit: usize,
+    ) -> Result<(), QuantumError> {
+        let len = self.len();
+        let qubit = self
+            .qubits
+            .get_mut(target_qubit)
+            .ok_or(QuantumError::InvalidQubitIndex {
+                index: target_qubit,
+                len,
+            })?;
+        qubit.apply_gate_mut(gate);
+        Ok(())
+    }
+
+    /// [`QubitRegister::apply_circuit`] applies every operation of a [`QuantumCircuit`],
+    /// in order, to the [`Qubits`](crate::quantum::types::qubit::Qubit) in a [`QubitRegister`].
+    ///
+    /// Every [`Qubit`] in a [`QubitRegister`] is independent, so a controlled gate will panic, and
+    /// needs [`StateVector::apply_circuit`] instead.
+    ///
+    /// # Example
+    /// [`QubitRegister::apply_circuit`] can be used to run a [`QuantumCircuit`] on a
+    /// [`QubitRegister`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     qubit_register::QubitRegister,
+    /// };
+    ///
+    /// fn flip_first_qubit_in_register(qubit_register: &mut QubitRegister) {
+    ///     let circuit = QuantumCircuit::new(qubit_register.len()).add_gate(&QuantumGate::NOT, 0);
+    ///     qubit_register.apply_circuit(&circuit)
+    /// }
+    /// ```
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) {
+        // Every qubit of a register is independent, so it can not be entangled by a control.
+        assert!(
+            circuit
+                .operations
+                .iter()
+                .all(|operation| operation.controls.is_empty()),
+            "Qubit register can not apply a controlled gate"
+        );
+
+        for operation in &circuit.operations {
+            let gate = QuantumGate::new(operation.operator.clone());
+            self.apply_single_qubit_gate(&gate, operation.target);
+        }
+    }
+
+    /// [`QubitRegister::rotate_basis`] will rotate the [`Qubits`](Qubit) at the indices of `qubits`
+    /// from a [`Basis`] onto the computational basis, so measuring them afterwards measures in
+    /// that [`Basis`].
+    ///
+    /// Every [`Qubit`] in a [`QubitRegister`] is independent, so [`Basis::Bell`] will panic, and
+    /// needs [`StateVector::rotate_basis`](crate::quantum::types::state_vector::StateVector::rotate_basis)
+    /// instead.
+    ///
+    /// # Example
+    /// [`QubitRegister::rotate_basis`] can be used to measure two qubits in the X basis:
+    /// ```rust
+    /// use rquant::quantum::types::{basis::Basis, qubit_register::QubitRegister};
+    ///
+    /// fn measure_in_x_basis(qubit_register: &mut QubitRegister) -> (bool, bool) {
+    ///     qubit_register.rotate_basis(Basis::X, &[0, 1]);
+    ///     (qubit_register.measure_mut(0), qubit_register.measure_mut(1))
+    /// }
+    /// ```
+    pub fn rotate_basis(&mut self, basis: Basis, qubits: &[usize]) {
+        assert!(
+            qubits.iter().all(|&target| target < self.len()),
+            "Invalid qubit index"
+        );
+        let rotation = basis
+            .single_qubit_rotation()
+            .expect("Bell basis needs entangled qubits, so it can not be used on a register");
+
+        for &target in qubits {
+            for operator in &rotation {
+                self.qubits[target].apply_gate_mut(&QuantumGate::new(operator.clone()));
+            }
+        }
+    }
+
+    /// [`QubitRegister::measure_mut`] will measure the [`Qubit`] at index `target` of a
+    /// [`QubitRegister`] with [`Qubit::measure_mut`], collapsing it to the observed basis state.
+    ///
+    /// Every [`Qubit`] in a [`QubitRegister`] is independent, so the rest of the register is
+    /// already normalized and does not change.
+    ///
+    /// # Example
+    /// [`QubitRegister::measure_mut`] can be used to read out the first qubit of a register:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_first_qubit(qubit_register: &mut QubitRegister) -> bool {
+    ///     qubit_register.measure_mut(0)
+    /// }
+    /// ```
+    pub fn measure_mut(&mut self, target: usize) -> bool {
+        self.measure_mut_with(target, &mut rand::rng())
+    }
+
+    /// [`QubitRegister::measure_mut_with`] will measure and collapse the [`Qubit`] at index
+    /// `target` in the same way as [`QubitRegister::measure_mut`], using a random number generator
+    /// `rng` instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`QubitRegister::measure_mut_with`] can be used to reproduce a read out:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_first_qubit_seeded(qubit_register: &mut QubitRegister, seed: u64) -> bool {
+    ///     qubit_register.measure_mut_with(0, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn measure_mut_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        assert!(target < self.len(), "Invalid qubit index");
+        self.qubits[target].measure_mut_with(rng)
+    }
+
+    /// [`QubitRegister::reset`] will measure the [`Qubit`] at index `target` with
+    /// [`QubitRegister::measure_mut`], then flip it back to $|0\rangle$ if it was measured as
+    /// $|1\rangle$, so it can be reused as an ancilla.
+    ///
+    /// # Example
+    /// [`QubitRegister::reset`] can be used to reuse an ancilla after it is read:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_and_reuse_ancilla(qubit_register: &mut QubitRegister) -> bool {
+    ///     let syndrome = qubit_register.measure_mut(2);
+    ///     qubit_register.reset(2);
+    ///     syndrome
+    /// }
+    /// ```
+    pub fn reset(&mut self, target: usize) {
+        self.reset_with(target, &mut rand::rng())
+    }
+
+    /// [`QubitRegister::reset_with`] will reset the [`Qubit`] at index `target` in the same way as
+    /// [`QubitRegister::reset`], using a random number generator `rng` for the measurement instead
+    /// of the thread's generator.
+    ///
+    /// # Example
+    /// [`QubitRegister::reset_with`] can be used to reuse an ancilla in a reproducible run:
+    /// ```rust
+    /// use rand::Rng;
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn reset_ancilla(qubit_register: &mut QubitRegister, rng: &mut impl Rng) {
+    ///     qubit_register.reset_with(2, rng)
+    /// }
+    /// ```
+    pub fn reset_with(&mut self, target: usize, rng: &mut impl Rng) {
+        if !self.measure_mut_with(target, rng) {
+            self.qubits[target].update(QuantumPosition::ZERO);
+        }
+    }
+
+    /// [`QubitRegister::reset_all`] will [`reset`](QubitRegister::reset) every [`Qubit`] of a
+    /// [`QubitRegister`] to $|0\rangle$.
+    ///
+    /// # Example
+    /// [`QubitRegister::reset_all`] can be used to run another program on the same register:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn start_over(qubit_register: &mut QubitRegister) {
+    ///     qubit_register.reset_all()
+    /// }
+    /// ```
+    pub fn reset_all(&mut self) {
+        (0..self.len()).for_each(|target| self.reset(target));
+    }
+
+    /// [`QubitRegister::measure_qubits`] will measure only the [`Qubits`](Qubit) at the indices of
+    /// `qubits` with [`QubitRegister::measure_mut`], in order, and return the measured values.
+    ///
+    /// The measured [`Qubits`](Qubit) collapse, and the rest keep their superposition.
+    ///
+    /// # Example
+    /// [`QubitRegister::measure_qubits`] can be used to read out a data qubit and leave an
+    /// ancilla alone:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_data_qubits(qubit_register: &mut QubitRegister) -> Vec<bool> {
+    ///     qubit_register.measure_qubits(&[0, 1])
+    /// }
+    /// ```
+    pub fn measure_qubits(&mut self, qubits: &[usize]) -> Vec<bool> {
+        qubits
+            .iter()
+            .map(|&target| self.measure_mut(target))
+            .collect()
+    }
+
+    /// [`QubitRegister::measure_all`] will measure every [`Qubit`] of a [`QubitRegister`] with
+    /// [`QubitRegister::measure_mut`], and return the outcome as a bitstring like `"0110"`, where
+    /// the first character is the first [`Qubit`], in the same format as
+    /// [`QubitRegister::from_bitstring`].
+    ///
+    /// A [`QubitRegister`] is a product state, so measuring every [`Qubit`] in turn samples the
+    /// joint distribution of [`QubitRegister::probabilities`] exactly.
+    ///
+    /// # Example
+    /// [`QubitRegister::measure_all`] can be used to read out the result of an algorithm:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_result(qubit_register: &mut QubitRegister) -> String {
+    ///     qubit_register.measure_all()
+    /// }
+    /// ```
+    pub fn measure_all(&mut self) -> String {
+        (0..self.len())
+            .map(|target| if self.measure_mut(target) { '0' } else { '1' })
+            .collect()
+    }
+
+    /// [`QubitRegister::measure_all_as_u64`] will measure every [`Qubit`] of a [`QubitRegister`]
+    /// like [`QubitRegister::measure_all`], and return the outcome as the number of the measured
+    /// basis state, where the first [`Qubit`] is the most significant bit.
+    ///
+    /// # Example
+    /// [`QubitRegister::measure_all_as_u64`] can be used to read out the result of arithmetic:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn read_sum(qubit_register: &mut QubitRegister) -> u64 {
+    ///     qubit_register.measure_all_as_u64()
+    /// }
+    /// ```
+    pub fn measure_all_as_u64(&mut self) -> u64 {
+        // Every qubit must have a bit in the measured number.
+        assert!(self.len() <= 64, "Register is too large for a u64");
+
+        self.measure_all()
+            .chars()
+            .fold(0, |value, bit| value << 1 | u64::from(bit == '1'))
+    }
+
+    /// [`QubitRegister::amplitudes`] will get the complex amplitude of every basis state of a
+    /// [`QubitRegister`], where the first [`Qubit`] is the most significant bit.
+    ///
+    /// # Example
+    /// [`QubitRegister::amplitudes`] can be used to get the amplitude of every qubit being
+    /// $|1\rangle$:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn all_one_amplitude(qubit_register: &QubitRegister) -> Complex<f64> {
+    ///     *qubit_register.amplitudes().last().unwrap()
+    /// }
+    /// ```
+    pub fn amplitudes(&self) -> Vec<Complex<f64>> {
+        StateVector::from_register(self).amplitudes
+    }
+
+    /// [`QubitRegister::probabilities`] will get the exact probability of every basis state of a
+    /// [`QubitRegister`], where the first [`Qubit`] is the most significant bit, without measuring
+    /// it.
+    ///
+    /// # Example
+    /// [`QubitRegister::probabilities`] can be used to get the chance that every qubit is
+    /// $|0\rangle$:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn all_zero_probability(qubit_register: &QubitRegister) -> f64 {
+    ///     qubit_register.probabilities()[0]
+    /// }
+    /// ```
+    pub fn probabilities(&self) -> Vec<f64> {
+        let targets: Vec<usize> = (0..self.len()).collect();
+        self.marginal_probabilities(&targets)
+    }
+
+    /// [`QubitRegister::probability_of`] will get the exact probability of measuring the
+    /// classical `bitstring`, like `"0110"`, from a [`QubitRegister`] without measuring it, or
+    /// return a [`QuantumError`] if the `bitstring` is not one `0` or `1` for every [`Qubit`].
+    ///
+    /// # Example
+    /// [`QubitRegister::probability_of`] can be used to check an algorithm without sampling noise:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_error::QuantumError, qubit_register::QubitRegister};
+    ///
+    /// fn finds_answer(qubit_register: &QubitRegister) -> Result<bool, QuantumError> {
+    ///     Ok(qubit_register.probability_of("0110")? > 0.9)
+    /// }
+    /// ```
+    pub fn probability_of(&self, bitstring: &str) -> Result<f64, QuantumError> {
+        let length = bitstring.chars().count();
+        if length != self.len() {
+            return Err(QuantumError::InvalidBitstringLength {
+                length,
+                len: self.len(),
+            });
+        }
+
+        bitstring.chars().zip(&self.qubits).enumerate().try_fold(
+            1.0,
+            |probability, (position, (character, qubit))| match character {
+                '0' => Ok(probability * qubit.probabilities()[0]),
+                '1' => Ok(probability * qubit.probabilities()[1]),
+                _ => Err(QuantumError::InvalidBitstring {
+                    character,
+                    position,
+                }),
+            },
+        )
+    }
+
+    /// [`QubitRegister::probability_of_index`] will get the exact probability of measuring the
+    /// basis state $|index\rangle$ from a [`QubitRegister`] without measuring it, where the first
+    /// [`Qubit`] is the most significant bit.
+    ///
+    /// # Example
+    /// [`QubitRegister::probability_of_index`] can be used to check the result of an arithmetic
+    /// circuit:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn is_five(qubit_register: &QubitRegister) -> bool {
+    ///     qubit_register.probability_of_index(5) > 1.0 - 1e-9
+    /// }
+    /// ```
+    pub fn probability_of_index(&self, index: u64) -> f64 {
+        // The index must be a basis state of the register.
+        assert!(
+            self.len() >= 64 || index >> self.len() == 0,
+            "Basis state does not fit in the register"
+        );
+
+        self.qubits
+            .iter()
+            .enumerate()
+            .map(|(position, qubit)| {
+                let bit = self.len() - 1 - position;
+                qubit.probabilities()[usize::from(bit < 64 && (index >> bit) & 1 == 1)]
+            })
+            .product()
+    }
+
+    /// [`QubitRegister::marginal_probabilities`] will get the exact probability distribution over
+    /// the [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of `targets`, where each
+    /// index of the distribution is a basis state with the first target as the most significant bit.
+    ///
+    /// # Example
+    /// [`QubitRegister::marginal_probabilities`] can be used to focus on two qubits of a register:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn first_and_last_distribution(qubit_register: &QubitRegister) -> Vec<f64> {
+    ///     qubit_register.marginal_probabilities(&[0, qubit_register.len() - 1])
+    /// }
+    /// ```
+    pub fn marginal_probabilities(&self, targets: &[usize]) -> Vec<f64> {
+        assert!(
+            targets.iter().all(|&target| target < self.len()),
+            "Invalid qubit index"
+        );
+
+        // Every qubit in a register is independent, so the marginal is a product distribution.
+        targets.iter().fold(vec![1.0], |probabilities, &target| {
+            let qubit = &self.qubits[target];
+            probabilities
+                .iter()
+                .flat_map(|probability| qubit.probabilities().map(|outcome| probability * outcome))
+                .collect()
+        })
+    }
+
+    /// [`QubitRegister::overlap`] will get the inner product $\langle\phi|\psi\rangle$ of two
+    /// [`QubitRegisters`](QubitRegister) with the same amount of [`Qubits`](Qubit), which is the
+    /// product of the inner products of each pair of [`Qubits`](Qubit).
+    ///
+    /// # Example
+    /// [`QubitRegister::overlap`] can be used to check if two registers are orthogonal:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn is_orthogonal(first: &QubitRegister, second: &QubitRegister) -> bool {
+    ///     first.overlap(second).norm() < 1e-9
+    /// }
+    /// ```
+    pub fn overlap(&self, other: &QubitRegister) -> Complex<f64> {
+        assert!(
+            self.len() == other.len(),
+            "Qubit registers must have the same amount of qubits"
+        );
+
+        self.qubits
+            .iter()
+            .zip(&other.qubits)
+            .map(|(first, second)| first.inner_product(second))
+            .product()
+    }
+
+    /// [`QubitRegister::fidelity`] will get the fidelity $|\langle\phi|\psi\rangle|^2$ of the
+    /// [`QubitRegister`] that calls it and an `other` [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`QubitRegister::fidelity`] can be used to compare simulated registers with a tolerance:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn is_close(first: &QubitRegister, second: &QubitRegister) -> bool {
+    ///     first.fidelity(second) > 1.0 - 1e-9
+    /// }
+    /// ```
+    pub fn fidelity(&self, other: &QubitRegister) -> f64 {
+        self.overlap(other).norm_sqr()
+    }
+
+    /// [`QubitRegister::trace_distance`] will get the trace distance $\sqrt{1 - F}$ of the
+    /// [`QubitRegister`] that calls it and an `other` [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`QubitRegister::trace_distance`] can be used to get how distinguishable two registers are:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn distinguishability(first: &QubitRegister, second: &QubitRegister) -> f64 {
+    ///     first.trace_distance(second)
+    /// }
+    /// ```
+    pub fn trace_distance(&self, other: &QubitRegister) -> f64 {
+        (1.0 - self.fidelity(other)).max(0.0).sqrt()
+    }
+
+    /// [`QubitRegister::fidelity_matrix`] will get the fidelity $|\langle\phi_i|\phi_j\rangle|^2$
+    /// of every pair of [`QubitRegisters`](QubitRegister), where the value at row $i$ and column
+    /// $j$ compares the registers at those indices.
+    ///
+    /// # Example
+    /// [`QubitRegister::fidelity_matrix`] can be used to check that codewords are orthogonal:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_register::QubitRegister;
+    ///
+    /// fn are_orthogonal(codewords: &[QubitRegister]) -> bool {
+    ///     let fidelities = QubitRegister::fidelity_matrix(codewords);
+    ///     (0..codewords.len()).all(|row| {
+    ///         (0..codewords.len()).all(|column| row == column || fidelities[row][column] < 1e-9)
+    ///     })
+    /// }
+    /// ```
+    pub fn fidelity_matrix(registers: &[QubitRegister]) -> Vec<Vec<f64>> {
+        registers
+            .iter()
+            .map(|first| {
+                registers
+                    .iter()
+                    .map(|second| first.fidelity(second))
+                    .collect()
+            })
+            .collect()
+    }
 }
 
 /// Implement the [`std::fmt::Display`] trait for [`QubitRegister`].
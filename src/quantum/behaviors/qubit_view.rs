@@ -0,0 +1,140 @@
+use crate::quantum::types::qubit_view::QubitView;
+
+impl QubitView {
+    /// [`QubitView::new`] will create a [`QubitView`] that selects every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) whose bit is set in `mask`.
+    ///
+    /// # Example
+    /// [`QubitView::new`] can be used to select the first and third qubits:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn create_first_and_third_view() -> QubitView {
+    ///     QubitView::new(0b101)
+    /// }
+    /// ```
+    pub fn new(mask: usize) -> Self {
+        QubitView {
+            mask,
+            qubits: (0..usize::BITS as usize)
+                .filter(|index| mask >> index & 1 == 1)
+                .collect(),
+        }
+    }
+
+    /// [`QubitView::range`] will create a [`QubitView`] that selects the `len` neighboring
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) starting at index `start`.
+    ///
+    /// # Example
+    /// [`QubitView::range`] can be used to split a register into a counting and work register:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn split_register(counting: usize, work: usize) -> (QubitView, QubitView) {
+    ///     (QubitView::range(0, counting), QubitView::range(counting, work))
+    /// }
+    /// ```
+    pub fn range(start: usize, len: usize) -> Self {
+        assert!(
+            start + len <= usize::BITS as usize,
+            "Qubit view can not address that many qubits"
+        );
+
+        let ones = if len == usize::BITS as usize {
+            usize::MAX
+        } else {
+            (1 << len) - 1
+        };
+        QubitView::new(ones << start)
+    }
+
+    /// [`QubitView::len`] will get the amount of [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// selected by the [`QubitView`].
+    ///
+    /// # Example
+    /// [`QubitView::len`] can be used to size a circuit for a subsystem:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn count_selected_qubits(view: &QubitView) -> usize {
+    ///     view.len()
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.qubits.len()
+    }
+
+    /// [`QubitView::is_empty`] will return `true` if the [`QubitView`] selects no
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), and `false` otherwise.
+    ///
+    /// # Example
+    /// [`QubitView::is_empty`] can be used to skip empty subsystems:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn view_has_qubits(view: &QubitView) -> bool {
+    ///     !view.is_empty()
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.qubits.is_empty()
+    }
+
+    /// [`QubitView::get`] will get the register index of the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at position `index` in the [`QubitView`], and returns [`None`] if the `index` is out of bounds.
+    ///
+    /// # Example
+    /// [`QubitView::get`] can be used to find the register index of the first selected qubit:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn first_selected_qubit(view: &QubitView) -> Option<usize> {
+    ///     view.get(0)
+    /// }
+    /// ```
+    pub fn get(&self, index: usize) -> Option<usize> {
+        self.qubits.get(index).copied()
+    }
+
+    /// [`QubitView::contains`] will return `true` if the [`QubitView`] selects the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at register index `target`.
+    ///
+    /// # Example
+    /// [`QubitView::contains`] can be used to check if a qubit is part of a subsystem:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn is_in_work_register(work: &QubitView, target: usize) -> bool {
+    ///     work.contains(target)
+    /// }
+    /// ```
+    pub fn contains(&self, target: usize) -> bool {
+        target < usize::BITS as usize && self.mask >> target & 1 == 1
+    }
+
+    /// [`QubitView::complement`] will create a [`QubitView`] of every other
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) in a register of `num_qubits`.
+    ///
+    /// # Example
+    /// [`QubitView::complement`] can be used to get the work register from the counting register:
+    /// ```rust
+    /// use rquant::quantum::types::qubit_view::QubitView;
+    ///
+    /// fn work_register(counting: &QubitView, num_qubits: usize) -> QubitView {
+    ///     counting.complement(num_qubits)
+    /// }
+    /// ```
+    pub fn complement(&self, num_qubits: usize) -> Self {
+        QubitView::new(!self.mask & QubitView::range(0, num_qubits).mask)
+    }
+}
+
+/// Implement the [`std::ops::Deref`] trait for [`QubitView`], so it can be passed anywhere a
+/// slice of qubit indices is accepted.
+impl std::ops::Deref for QubitView {
+    type Target = [usize];
+
+    fn deref(&self) -> &Self::Target {
+        &self.qubits
+    }
+}
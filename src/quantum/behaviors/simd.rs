@@ -0,0 +1,94 @@
+use std::arch::x86_64::{
+    __m128d, _mm_add_pd, _mm_loadu_pd, _mm_mul_pd, _mm_set_pd, _mm_setzero_pd, _mm_shuffle_pd,
+    _mm_storeu_pd, _mm_unpackhi_pd, _mm_unpacklo_pd, _mm_xor_pd,
+};
+
+use num_complex::Complex;
+
+use crate::quantum::types::quantum_gate::QuantumGate;
+
+/// [`apply_gate`] will apply a single-qubit [`QuantumGate`] to every pair of `amplitudes` that
+/// differ only in the `mask` bit, two lanes of a [`Complex<f64>`] at a time.
+pub(crate) fn apply_gate(amplitudes: &mut [Complex<f64>], mask: usize, gate: &QuantumGate) {
+    // SAFETY: SSE2 is part of every `x86_64` target.
+    unsafe { apply_gate_sse2(amplitudes, mask, gate) }
+}
+
+/// [`dot`] will get the sum of the products of a `row` of a unitary and the `amplitudes` it is
+/// applied to, two lanes of a [`Complex<f64>`] at a time.
+pub(crate) fn dot(row: &[Complex<f64>], amplitudes: &[Complex<f64>]) -> Complex<f64> {
+    // SAFETY: SSE2 is part of every `x86_64` target.
+    unsafe { dot_sse2(row, amplitudes) }
+}
+
+/// [`apply_gate_sse2`] is the body of [`apply_gate`], compiled with SSE2 enabled.
+#[target_feature(enable = "sse2")]
+fn apply_gate_sse2(amplitudes: &mut [Complex<f64>], mask: usize, gate: &QuantumGate) {
+    let matrix = [
+        [
+            load(&gate.transform[0].initial_position),
+            load(&gate.transform[0].possible_position),
+        ],
+        [
+            load(&gate.transform[1].initial_position),
+            load(&gate.transform[1].possible_position),
+        ],
+    ];
+
+    // Every block of 2 * mask amplitudes holds mask pairs, with the |0> half first.
+    for block in amplitudes.chunks_exact_mut(2 * mask) {
+        let (zeros, ones) = block.split_at_mut(mask);
+        for (zero, one) in zeros.iter_mut().zip(ones) {
+            let (a, b) = (load(zero), load(one));
+            store(
+                zero,
+                _mm_add_pd(multiply(matrix[0][0], a), multiply(matrix[0][1], b)),
+            );
+            store(
+                one,
+                _mm_add_pd(multiply(matrix[1][0], a), multiply(matrix[1][1], b)),
+            );
+        }
+    }
+}
+
+/// [`dot_sse2`] is the body of [`dot`], compiled with SSE2 enabled.
+#[target_feature(enable = "sse2")]
+fn dot_sse2(row: &[Complex<f64>], amplitudes: &[Complex<f64>]) -> Complex<f64> {
+    let mut sum = _mm_setzero_pd();
+    for (entry, amplitude) in row.iter().zip(amplitudes) {
+        sum = _mm_add_pd(sum, multiply(load(entry), load(amplitude)));
+    }
+    let mut result = Complex::new(0.0, 0.0);
+    store(&mut result, sum);
+    result
+}
+
+/// [`multiply`] will multiply two complex numbers packed as `[re, im]` lanes.
+#[target_feature(enable = "sse2")]
+fn multiply(a: __m128d, b: __m128d) -> __m128d {
+    let (b_re, b_im) = (_mm_unpacklo_pd(b, b), _mm_unpackhi_pd(b, b));
+    let swapped = _mm_shuffle_pd::<0b01>(a, a);
+    // Flip the sign of the low lane, so it becomes re * re - im * im.
+    let sign = _mm_set_pd(0.0, -0.0);
+    _mm_add_pd(
+        _mm_mul_pd(a, b_re),
+        _mm_xor_pd(_mm_mul_pd(swapped, b_im), sign),
+    )
+}
+
+/// [`load`] will pack a [`Complex<f64>`] into `[re, im]` lanes.
+#[target_feature(enable = "sse2")]
+fn load(value: &Complex<f64>) -> __m128d {
+    // SAFETY: `Complex<f64>` is `repr(C)` with the real part followed by the imaginary part, so
+    // it is two readable `f64` values.
+    unsafe { _mm_loadu_pd(value as *const Complex<f64> as *const f64) }
+}
+
+/// [`store`] will unpack `[re, im]` lanes into a [`Complex<f64>`].
+#[target_feature(enable = "sse2")]
+fn store(value: &mut Complex<f64>, lanes: __m128d) {
+    // SAFETY: `Complex<f64>` is `repr(C)` with the real part followed by the imaginary part, so
+    // it is two writable `f64` values.
+    unsafe { _mm_storeu_pd(value as *mut Complex<f64> as *mut f64, lanes) }
+}
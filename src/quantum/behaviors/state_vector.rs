@@ -0,0 +1,807 @@
+use std::{fmt, panic::Location};
+
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{
+    quantum::types::{
+        basis::Basis, clone_audit::CloneAudit, display_precision::DisplayPrecision,
+        hamiltonian::Hamiltonian, pauli_string::PauliString, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate, quantum_operation::QuantumOperation,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+    simulation::{
+        behaviors::simulation_outcome::{basis_values, probabilities, sample_index},
+        types::backend::Backend,
+    },
+};
+
+impl StateVector {
+    /// [`StateVector::new`] will create a new [`StateVector`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit), all initialized as $|0\rangle$.
+    ///
+    /// # Example
+    /// [`StateVector::new`] can be used to create a new [`StateVector`]:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn create_state_vector(amount: usize) -> StateVector {
+    ///     StateVector::new(amount)
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize) -> Self {
+        let mut amplitudes = vec![Complex::new(0.0, 0.0); 1 << num_qubits];
+        amplitudes[0] = Complex::new(1.0, 0.0);
+        StateVector {
+            num_qubits,
+            amplitudes,
+        }
+    }
+
+    /// [`StateVector::from_register`] will create a new [`StateVector`] from the tensor product of
+    /// every [`Qubit`](crate::quantum::types::qubit::Qubit) in a [`QubitRegister`].
+    ///
+    /// # Example
+    /// [`StateVector::from_register`] can be used to entangle the qubits of a [`QubitRegister`]:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit_register::QubitRegister, state_vector::StateVector};
+    ///
+    /// fn entangle_register(qubit_register: &QubitRegister) -> StateVector {
+    ///     let mut state_vector = StateVector::from_register(qubit_register);
+    ///     state_vector.apply_cnot(0, 1);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn from_register(qubit_register: &QubitRegister) -> Self {
+        let amplitudes =
+            qubit_register
+                .qubits
+                .iter()
+                .fold(vec![Complex::new(1.0, 0.0)], |amplitudes, qubit| {
+                    amplitudes
+                        .iter()
+                        .flat_map(|amplitude| {
+                            [
+                                amplitude * qubit.initial_position(),
+                                amplitude * qubit.possible_position(),
+                            ]
+                        })
+                        .collect()
+                });
+        StateVector {
+            num_qubits: qubit_register.len(),
+            amplitudes,
+        }
+    }
+
+    /// [`StateVector::ghz`] will create a new [`StateVector`] in the Greenberger–Horne–Zeilinger
+    /// state of `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit):
+    /// $$ \frac{|0\ldots0\rangle + |1\ldots1\rangle}{\sqrt{2}} $$
+    ///
+    /// Measuring any [`Qubit`](crate::quantum::types::qubit::Qubit) of a GHZ state determines the
+    /// outcome of every other [`Qubit`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`StateVector::ghz`] can be used to create a three qubit GHZ state:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn create_ghz_state() -> StateVector {
+    ///     StateVector::ghz(3)
+    /// }
+    /// ```
+    pub fn ghz(num_qubits: usize) -> Self {
+        assert!(num_qubits > 0, "GHZ state must have at least one qubit");
+
+        let mut state_vector = StateVector::new(num_qubits);
+        state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+        for target in 1..num_qubits {
+            state_vector.apply_cnot(target - 1, target);
+        }
+        state_vector
+    }
+
+    /// [`StateVector::dicke`] will create a new [`StateVector`] in the Dicke state of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) with Hamming weight `weight`, which is the
+    /// uniform superposition of every basis state with exactly `weight` ones:
+    /// $$ |D^n_k\rangle = \binom{n}{k}^{-1/2} \sum_{|x| = k} |x\rangle $$
+    ///
+    /// # Example
+    /// [`StateVector::dicke`] can be used to create the three qubit W state:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn create_w_state() -> StateVector {
+    ///     StateVector::dicke(3, 1)
+    /// }
+    /// ```
+    pub fn dicke(num_qubits: usize, weight: usize) -> Self {
+        assert!(
+            weight <= num_qubits,
+            "Dicke state weight can not exceed the amount of qubits"
+        );
+        StateVector::hamming_weights(num_qubits, &[weight])
+    }
+
+    /// [`StateVector::hamming_weights`] will create a new [`StateVector`] of `num_qubits`
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) in the uniform superposition of every basis
+    /// state whose Hamming weight is one of `weights`.
+    ///
+    /// Restricting a state to a fixed set of Hamming weights keeps it inside the feasible subspace
+    /// of constrained QAOA problems, or inside a fixed particle number sector of a chemistry mapping.
+    ///
+    /// # Example
+    /// [`StateVector::hamming_weights`] can be used to superpose every even parity basis state:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn create_even_parity_state() -> StateVector {
+    ///     StateVector::hamming_weights(4, &[0, 2, 4])
+    /// }
+    /// ```
+    pub fn hamming_weights(num_qubits: usize, weights: &[usize]) -> Self {
+        let mut amplitudes: Vec<Complex<f64>> = (0..1usize << num_qubits)
+            .map(|index| {
+                let weight = index.count_ones() as usize;
+                if weights.contains(&weight) {
+                    Complex::new(1.0, 0.0)
+                } else {
+                    Complex::new(0.0, 0.0)
+                }
+            })
+            .collect();
+
+        let count = amplitudes
+            .iter()
+            .filter(|amplitude| amplitude.re > 0.0)
+            .count();
+        assert!(
+            count > 0,
+            "Hamming weights must select at least one basis state"
+        );
+
+        let scale = 1.0 / (count as f64).sqrt();
+        amplitudes
+            .iter_mut()
+            .for_each(|amplitude| *amplitude *= scale);
+        StateVector {
+            num_qubits,
+            amplitudes,
+        }
+    }
+
+    /// [`StateVector::apply_gate`] will apply a single-qubit [`QuantumGate`] to the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` of a [`StateVector`].
+    ///
+    /// # Example
+    /// [`StateVector::apply_gate`] can be used to put the first qubit into superposition:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn superposition_first_qubit(state_vector: &mut StateVector) {
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0)
+    /// }
+    /// ```
+    pub fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        let mask = self.mask(target);
+        #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+        crate::quantum::behaviors::simd::apply_gate(&mut self.amplitudes, mask, gate);
+        #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+        for index in (0..self.amplitudes.len()).filter(|index| index & mask == 0) {
+            let (zero, one) = (self.amplitudes[index], self.amplitudes[index | mask]);
+            self.amplitudes[index] = gate.transform[0].initial_position * zero
+                + gate.transform[0].possible_position * one;
+            self.amplitudes[index | mask] = gate.transform[1].initial_position * zero
+                + gate.transform[1].possible_position * one;
+        }
+    }
+
+    /// [`StateVector::apply_circuit`] will apply every
+    /// [`QuantumOperation`](crate::quantum::types::quantum_operation::QuantumOperation) of a
+    /// [`QuantumCircuit`] to a [`StateVector`], in order.
+    ///
+    /// # Example
+    /// [`StateVector::apply_circuit`] can be used to run a [`QuantumCircuit`] on a [`StateVector`]:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_circuit::QuantumCircuit,
+    ///     quantum_gate::QuantumGate,
+    ///     state_vector::StateVector,
+    /// };
+    ///
+    /// fn run_superposition_circuit() -> StateVector {
+    ///     let mut state_vector = StateVector::new(1);
+    ///     state_vector.apply_circuit(&QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0));
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_circuit(&mut self, circuit: &QuantumCircuit) {
+        assert!(
+            circuit.num_qubits == self.num_qubits,
+            "Circuit must operate on every state vector qubit"
+        );
+
+        for operation in &circuit.operations {
+            self.apply_operation(operation);
+        }
+    }
+
+    /// [`StateVector::apply_operation`] will apply a [`QuantumOperation`] to a [`StateVector`],
+    /// where the gate is only applied on the basis states where every one of it's `controls` is
+    /// $|1\rangle$.
+    ///
+    /// # Example
+    /// [`StateVector::apply_operation`] can be used to run the first step of a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_circuit::QuantumCircuit, state_vector::StateVector};
+    ///
+    /// fn run_first_operation(circuit: &QuantumCircuit) -> StateVector {
+    ///     let mut state_vector = StateVector::new(circuit.num_qubits);
+    ///     state_vector.apply_operation(&circuit.operations[0]);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_operation(&mut self, operation: &QuantumOperation) {
+        let gate = QuantumGate::new(operation.operator.clone());
+        if operation.controls.is_empty() {
+            self.apply_gate(&gate, operation.target);
+        } else {
+            self.apply_unitary(&gate.matrix(), &[operation.target], &operation.controls);
+        }
+    }
+
+    /// [`StateVector::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) of a [`StateVector`], using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`StateVector::apply_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn create_bell_state() -> StateVector {
+    ///     let mut state_vector = StateVector::new(2);
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state_vector.apply_cnot(0, 1);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_cnot(&mut self, control: usize, target: usize) {
+        assert!(control != target, "Invalid state vector qubit index");
+
+        let (control_mask, target_mask) = (self.mask(control), self.mask(target));
+        for index in 0..self.amplitudes.len() {
+            if index & control_mask != 0 && index & target_mask == 0 {
+                self.amplitudes.swap(index, index | target_mask);
+            }
+        }
+    }
+
+    /// [`StateVector::apply_cz`] will apply a controlled phase gate between two
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) of a [`StateVector`], which flips the phase of
+    /// the $|11\rangle$ state.
+    ///
+    /// # Example
+    /// [`StateVector::apply_cz`] can be used to entangle two superpositioned qubits:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn create_two_qubit_cluster_state() -> StateVector {
+    ///     let mut state_vector = StateVector::new(2);
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    ///     state_vector.apply_cz(0, 1);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_cz(&mut self, a: usize, b: usize) {
+        assert!(a != b, "Invalid state vector qubit index");
+
+        let mask = self.mask(a) | self.mask(b);
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if index & mask == mask {
+                *amplitude = -*amplitude;
+            }
+        }
+    }
+
+    /// [`StateVector::apply_swap`] will apply a SWAP gate between two
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) of a [`StateVector`], which exchanges their
+    /// states by relabeling them in every amplitude.
+    ///
+    /// # Example
+    /// [`StateVector::apply_swap`] can be used to move a qubit next to another:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn move_flipped_qubit() -> StateVector {
+    ///     let mut state_vector = StateVector::new(3);
+    ///     state_vector.apply_gate(&QuantumGate::NOT, 0);
+    ///     state_vector.apply_swap(0, 2);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn apply_swap(&mut self, a: usize, b: usize) {
+        assert!(a != b, "Invalid state vector qubit index");
+
+        let (a_mask, b_mask) = (self.mask(a), self.mask(b));
+        for index in 0..self.amplitudes.len() {
+            if index & a_mask != 0 && index & b_mask == 0 {
+                self.amplitudes.swap(index, index ^ a_mask ^ b_mask);
+            }
+        }
+    }
+
+    /// [`StateVector::rotate_basis`] will rotate the [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// at the indices of `qubits` from a [`Basis`] onto the computational basis, so measuring them
+    /// afterwards measures in that [`Basis`].
+    ///
+    /// [`Basis::Bell`] rotates each pair of `qubits` in order, so the first and second qubit are a
+    /// pair, then the third and fourth, where $|\Phi^+\rangle$ is measured as `(true, true)`.
+    ///
+    /// # Example
+    /// [`StateVector::rotate_basis`] can be used to tell the Bell states apart:
+    /// ```rust
+    /// use rquant::quantum::types::{basis::Basis, state_vector::StateVector};
+    ///
+    /// fn is_phi_plus(mut state_vector: StateVector) -> bool {
+    ///     state_vector.rotate_basis(Basis::Bell, &[0, 1]);
+    ///     state_vector.measure(0) && state_vector.measure(1)
+    /// }
+    /// ```
+    pub fn rotate_basis(&mut self, basis: Basis, qubits: &[usize]) {
+        assert!(
+            qubits.iter().all(|&target| target < self.num_qubits),
+            "Invalid state vector qubit index"
+        );
+
+        match basis.single_qubit_rotation() {
+            Some(rotation) => {
+                for &target in qubits {
+                    for operator in &rotation {
+                        self.apply_gate(&QuantumGate::new(operator.clone()), target);
+                    }
+                }
+            }
+            None => {
+                assert!(
+                    qubits.len().is_multiple_of(2),
+                    "Bell basis needs pairs of qubits"
+                );
+                for pair in qubits.chunks(2) {
+                    self.apply_cnot(pair[0], pair[1]);
+                    self.apply_gate(&QuantumGate::SUPERPOSITION, pair[0]);
+                }
+            }
+        }
+    }
+
+    /// [`StateVector::apply_unitary`] will apply a $2^k \times 2^k$ unitary `matrix` to the `targets`
+    /// of a [`StateVector`], where the first target is the most significant bit of each row and
+    /// column, only on the basis states where every one of the `controls` is $|1\rangle$.
+    ///
+    /// # Example
+    /// [`StateVector::apply_unitary`] can be used to apply a controlled Hadamard gate:
+    /// ```rust
+    /// use num_complex::Complex;
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn controlled_superposition(state_vector: &mut StateVector) {
+    ///     let amplitude = Complex::new(std::f64::consts::FRAC_1_SQRT_2, 0.0);
+    ///     let hadamard = vec![vec![amplitude, amplitude], vec![amplitude, -amplitude]];
+    ///     state_vector.apply_unitary(&hadamard, &[1], &[0]);
+    /// }
+    /// ```
+    pub fn apply_unitary(
+        &mut self,
+        matrix: &[Vec<Complex<f64>>],
+        targets: &[usize],
+        controls: &[usize],
+    ) {
+        let dimension = 1 << targets.len();
+        assert!(
+            matrix.len() == dimension && matrix.iter().all(|row| row.len() == dimension),
+            "Unitary must match the amount of targets"
+        );
+        let mut qubits: Vec<usize> = targets.iter().chain(controls).copied().collect();
+        qubits.sort_unstable();
+        qubits.dedup();
+        assert!(
+            qubits.len() == targets.len() + controls.len(),
+            "Unitary qubits must be unique"
+        );
+
+        let target_masks: Vec<usize> = targets.iter().map(|&target| self.mask(target)).collect();
+        let target_mask = target_masks.iter().fold(0, |mask, target| mask | target);
+        let control_mask = controls
+            .iter()
+            .fold(0, |mask, &control| mask | self.mask(control));
+        // Every target basis state, as an offset from the basis state where all targets are |0>.
+        let offsets: Vec<usize> = (0..dimension)
+            .map(|row| {
+                target_masks
+                    .iter()
+                    .enumerate()
+                    .filter(|(bit, _)| row & (1 << (targets.len() - 1 - bit)) != 0)
+                    .fold(0, |offset, (_, mask)| offset | mask)
+            })
+            .collect();
+
+        for base in 0..self.amplitudes.len() {
+            if base & target_mask != 0 || base & control_mask != control_mask {
+                continue;
+            }
+            let amplitudes: Vec<Complex<f64>> = offsets
+                .iter()
+                .map(|offset| self.amplitudes[base | offset])
+                .collect();
+            for (row, offset) in offsets.iter().enumerate() {
+                #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+                let amplitude = crate::quantum::behaviors::simd::dot(&matrix[row], &amplitudes);
+                #[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+                let amplitude = matrix[row]
+                    .iter()
+                    .zip(&amplitudes)
+                    .map(|(entry, amplitude)| entry * amplitude)
+                    .sum();
+                self.amplitudes[base | offset] = amplitude;
+            }
+        }
+    }
+
+    /// [`StateVector::evolve`] will apply the time evolution $e^{-iHt}$ of a [`Hamiltonian`] for a
+    /// `time` $t$ to a [`StateVector`], by exponentiating the full matrix of the [`Hamiltonian`],
+    /// so it is only meant for a few qubits.
+    ///
+    /// # Example
+    /// [`StateVector::evolve`] can be used to watch a spin precess in a transverse field:
+    /// ```rust
+    /// use rquant::quantum::types::{observable::Observable, state_vector::StateVector};
+    ///
+    /// fn precess(time: f64) -> StateVector {
+    ///     let mut state_vector = StateVector::new(1);
+    ///     state_vector.evolve(&Observable::parse("X"), time);
+    ///     state_vector
+    /// }
+    /// ```
+    pub fn evolve(&mut self, hamiltonian: &Hamiltonian, time: f64) {
+        assert!(
+            hamiltonian.num_qubits == self.num_qubits,
+            "State vector and hamiltonian must have the same amount of qubits"
+        );
+
+        let targets: Vec<usize> = (0..self.num_qubits).collect();
+        self.apply_unitary(&hamiltonian.propagator(time), &targets, &[]);
+    }
+
+    /// [`StateVector::measure`] will measure the [`Qubit`](crate::quantum::types::qubit::Qubit) at
+    /// index `target`, collapse the [`StateVector`] to the observed state, and return a [`bool`] for it's
+    /// "truthy" state, in the same way as [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure).
+    ///
+    /// # Example
+    /// [`StateVector::measure`] can be used to observe one qubit of a Bell state, which also
+    /// determines the other qubit:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn observe_bell_state() -> (bool, bool) {
+    ///     let mut state_vector = StateVector::new(2);
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state_vector.apply_cnot(0, 1);
+    ///     (state_vector.measure(0), state_vector.measure(1))
+    /// }
+    /// ```
+    pub fn measure(&mut self, target: usize) -> bool {
+        self.measure_with(target, &mut rand::rng())
+    }
+
+    /// [`StateVector::measure_with`] will measure the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` in the same way as
+    /// [`StateVector::measure`], using a random number generator `rng` instead of the thread's
+    /// generator, so a seeded `rng` always collapses to the same state.
+    ///
+    /// # Example
+    /// [`StateVector::measure_with`] can be used to reproduce a measurement of a Bell state:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn observe_seeded_bell_state(seed: u64) -> bool {
+    ///     let mut rng = StdRng::seed_from_u64(seed);
+    ///     StateVector::ghz(2).measure_with(0, &mut rng)
+    /// }
+    /// ```
+    pub fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        let mask = self.mask(target);
+        let prob_zero: f64 = self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index & mask == 0)
+            .map(|(_, amplitude)| amplitude.norm_sqr())
+            .sum();
+        let is_zero = rng.random_bool(prob_zero.clamp(0.0, 1.0));
+
+        let norm = if is_zero { prob_zero } else { 1.0 - prob_zero }.sqrt();
+        for (index, amplitude) in self.amplitudes.iter_mut().enumerate() {
+            if (index & mask == 0) == is_zero {
+                *amplitude /= norm;
+            } else {
+                *amplitude = Complex::new(0.0, 0.0);
+            }
+        }
+        is_zero
+    }
+
+    /// [`StateVector::measure_qubits`] will measure only the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of `qubits` with
+    /// [`StateVector::measure`], in order, and return the measured values.
+    ///
+    /// The rest of the [`StateVector`] is left in the superposition that is consistent with the
+    /// measured values.
+    ///
+    /// # Example
+    /// [`StateVector::measure_qubits`] can be used to measure two qubits of a GHZ state, which
+    /// leaves the third qubit in the same state:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn measure_ghz_pair() -> (Vec<bool>, bool) {
+    ///     let mut state_vector = StateVector::ghz(3);
+    ///     let measured = state_vector.measure_qubits(&[0, 1]);
+    ///     (measured, state_vector.measure(2))
+    /// }
+    /// ```
+    pub fn measure_qubits(&mut self, qubits: &[usize]) -> Vec<bool> {
+        qubits.iter().map(|&target| self.measure(target)).collect()
+    }
+
+    /// [`StateVector::marginal_probabilities`] will get the exact probability distribution over the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of `targets`, where each index
+    /// of the distribution is a basis state with the first target as the most significant bit.
+    ///
+    /// # Example
+    /// [`StateVector::marginal_probabilities`] can be used to get the distribution of one qubit of a
+    /// GHZ state:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn first_qubit_distribution() -> Vec<f64> {
+    ///     StateVector::ghz(3).marginal_probabilities(&[0])
+    /// }
+    /// ```
+    pub fn marginal_probabilities(&self, targets: &[usize]) -> Vec<f64> {
+        let masks: Vec<usize> = targets.iter().map(|&target| self.mask(target)).collect();
+        let mut probabilities = vec![0.0; 1 << targets.len()];
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            let marginal_index = masks.iter().fold(0, |marginal_index, mask| {
+                (marginal_index << 1) | usize::from(index & mask != 0)
+            });
+            probabilities[marginal_index] += amplitude.norm_sqr();
+        }
+        probabilities
+    }
+
+    /// [`StateVector::inner_product`] will get the inner product $\langle a|b\rangle$ of the
+    /// [`StateVector`] that calls it and an `other` [`StateVector`].
+    ///
+    /// # Example
+    /// [`StateVector::inner_product`] can be used to check if two states are equal up to a global
+    /// phase:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn are_same_state(a: &StateVector, b: &StateVector) -> bool {
+    ///     (a.inner_product(b).norm() - 1.0).abs() < 1e-9
+    /// }
+    /// ```
+    pub fn inner_product(&self, other: &StateVector) -> Complex<f64> {
+        assert!(
+            self.num_qubits == other.num_qubits,
+            "State vectors must have the same amount of qubits"
+        );
+
+        self.amplitudes
+            .iter()
+            .zip(&other.amplitudes)
+            .map(|(a, b)| a.conj() * b)
+            .sum()
+    }
+
+    /// [`StateVector::fidelity`] will get the fidelity $|\langle a|b\rangle|^2$ of the
+    /// [`StateVector`] that calls it and an `other` [`StateVector`].
+    ///
+    /// # Example
+    /// [`StateVector::fidelity`] can be used to compare a simulated state with a tolerance:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn is_bell_state(state_vector: &StateVector) -> bool {
+    ///     state_vector.fidelity(&StateVector::ghz(2)) > 1.0 - 1e-9
+    /// }
+    /// ```
+    pub fn fidelity(&self, other: &StateVector) -> f64 {
+        self.inner_product(other).norm_sqr()
+    }
+
+    /// [`StateVector::trace_distance`] will get the trace distance $\sqrt{1 - F}$ of the
+    /// [`StateVector`] that calls it and an `other` [`StateVector`].
+    ///
+    /// # Example
+    /// [`StateVector::trace_distance`] can be used to get how distinguishable two states are:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn distinguishability(a: &StateVector, b: &StateVector) -> f64 {
+    ///     a.trace_distance(b)
+    /// }
+    /// ```
+    pub fn trace_distance(&self, other: &StateVector) -> f64 {
+        (1.0 - self.fidelity(other)).max(0.0).sqrt()
+    }
+
+    /// [`StateVector::expectation`] will get the expectation value $\langle\psi|P|\psi\rangle$ of
+    /// a [`PauliString`] $P$ on the [`StateVector`].
+    ///
+    /// # Example
+    /// [`StateVector::expectation`] can be used to check the parity of a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     pauli::Pauli,
+    ///     pauli_string::PauliString,
+    ///     state_vector::StateVector,
+    /// };
+    ///
+    /// fn bell_state_parity() -> f64 {
+    ///     let parity = PauliString { negative: false, paulis: vec![Pauli::Z, Pauli::Z] };
+    ///     StateVector::ghz(2).expectation(&parity)
+    /// }
+    /// ```
+    pub fn expectation(&self, pauli_string: &PauliString) -> f64 {
+        assert!(
+            pauli_string.paulis.len() == self.num_qubits,
+            "Pauli string must have a Pauli for every state vector qubit"
+        );
+
+        self.amplitudes
+            .iter()
+            .enumerate()
+            .map(|(index, amplitude)| {
+                // Each Pauli string maps a basis state onto a single basis state, with a phase.
+                let (image, phase) = pauli_string.basis_image(index);
+                (self.amplitudes[image].conj() * phase * amplitude).re
+            })
+            .sum()
+    }
+
+    /// [`StateVector::is_entangled`] returns `true` if the [`StateVector`] can not be written as a
+    /// tensor product of single [`Qubits`](crate::quantum::types::qubit::Qubit), and `false` otherwise.
+    ///
+    /// A pure state is a product state exactly when every qubit on it's own is also pure, so the
+    /// purity $\mathrm{Tr}(\rho^2)$ of each single qubit is checked.
+    ///
+    /// # Example
+    /// [`StateVector::is_entangled`] can be used to check that a Bell state is entangled:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector};
+    ///
+    /// fn is_bell_state_entangled() -> bool {
+    ///     let mut state_vector = StateVector::new(2);
+    ///     state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     state_vector.apply_cnot(0, 1);
+    ///     state_vector.is_entangled()
+    /// }
+    /// ```
+    pub fn is_entangled(&self) -> bool {
+        (0..self.num_qubits).any(|target| {
+            let mask = self.mask(target);
+            let (mut zero, mut one, mut coherence) = (0.0, 0.0, Complex::new(0.0, 0.0));
+            for index in (0..self.amplitudes.len()).filter(|index| index & mask == 0) {
+                let (a, b) = (self.amplitudes[index], self.amplitudes[index | mask]);
+                zero += a.norm_sqr();
+                one += b.norm_sqr();
+                coherence += a * b.conj();
+            }
+            let purity = zero * zero + one * one + 2.0 * coherence.norm_sqr();
+            purity < 1.0 - 1e-9
+        })
+    }
+
+    /// [`StateVector::mask`] will get the bit of a basis state index that holds the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`.
+    pub(crate) fn mask(&self, target: usize) -> usize {
+        assert!(target < self.num_qubits, "Invalid state vector qubit index");
+
+        1 << (self.num_qubits - 1 - target)
+    }
+}
+
+/// Implement the [`Clone`] trait for [`StateVector`].
+impl Clone for StateVector {
+    /// Clones a [`StateVector`], and flags the caller with [`CloneAudit`] if the
+    /// [`StateVector`] is entangled.
+    #[track_caller]
+    fn clone(&self) -> Self {
+        if CloneAudit::is_enabled() && self.is_entangled() {
+            CloneAudit::flag(Location::caller());
+        }
+        StateVector {
+            num_qubits: self.num_qubits,
+            amplitudes: self.amplitudes.clone(),
+        }
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`StateVector`].
+impl fmt::Display for StateVector {
+    /// Gets the [`String`] representation of a [`StateVector`] as a sum of every basis state with a
+    /// non-zero amplitude. For instance, a Bell state will be presented as:
+    /// $$ 0.7071067811865475|00\rangle + 0.7071067811865475|11\rangle $$
+    ///
+    /// # Example
+    /// Can be used to print a [`StateVector`] to the console:
+    /// ```rust
+    /// use rquant::quantum::types::state_vector::StateVector;
+    ///
+    /// fn print_state_vector(state_vector: StateVector) {
+    ///     println!("{state_vector}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let terms: Vec<String> = self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(_, amplitude)| amplitude.norm_sqr() > f64::EPSILON)
+            .map(|(index, amplitude)| {
+                let formatted = DisplayPrecision::format(*amplitude);
+                // Wrap amplitudes with both parts, so the sum of terms stays readable.
+                let amplitude = if formatted[1..].contains(['+', '-']) {
+                    format!("({formatted})")
+                } else {
+                    formatted
+                };
+                format!("{amplitude}|{index:0width$b}〉", width = self.num_qubits)
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+/// Implement the [`Backend`] trait for [`StateVector`].
+impl Backend for StateVector {
+    fn num_qubits(&self) -> usize {
+        self.num_qubits
+    }
+
+    fn apply_gate(&mut self, gate: &QuantumGate, target: usize) {
+        StateVector::apply_gate(self, gate, target)
+    }
+
+    fn apply_cnot(&mut self, control: usize, target: usize) {
+        StateVector::apply_cnot(self, control, target)
+    }
+
+    fn apply_cz(&mut self, a: usize, b: usize) {
+        StateVector::apply_cz(self, a, b)
+    }
+
+    fn apply_swap(&mut self, a: usize, b: usize) {
+        StateVector::apply_swap(self, a, b)
+    }
+
+    fn apply_operation(&mut self, operation: &QuantumOperation) {
+        StateVector::apply_operation(self, operation)
+    }
+
+    fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool {
+        StateVector::measure_with(self, target, rng)
+    }
+
+    fn sample_shots_with(&self, shots: usize, rng: &mut impl Rng) -> Vec<Vec<bool>> {
+        let probabilities = probabilities(self);
+        (0..shots)
+            .map(|_| basis_values(sample_index(&probabilities, rng.random()), self.num_qubits))
+            .collect()
+    }
+}
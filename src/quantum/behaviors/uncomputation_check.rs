@@ -0,0 +1,81 @@
+use crate::quantum::types::{
+    clifford_tableau::CliffordTableau, qasm_statement::QasmStatement, quantum_gate::QuantumGate,
+    uncomputation_check::UncomputationCheck,
+};
+
+impl UncomputationCheck {
+    /// [`UncomputationCheck::verify`] will follow a program of [`QasmStatements`](QasmStatement)
+    /// on `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit) with a [`CliffordTableau`],
+    /// and check that every qubit in `ancillas` is certainly $|0\rangle$ at the end.
+    ///
+    /// The check is exact rather than sampled, so an ancilla that is only left entangled with a
+    /// small amplitude is still found. Register declarations are skipped, and the first
+    /// non-Clifford gate or measurement stops the check.
+    ///
+    /// # Example
+    /// [`UncomputationCheck::verify`] can be used to check a computed parity is uncomputed:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     qasm_statement::QasmStatement, quantum_operators::QuantumOperator,
+    ///     uncomputation_check::UncomputationCheck,
+    /// };
+    ///
+    /// fn is_parity_uncomputed() -> bool {
+    ///     let program = [
+    ///         QasmStatement::Gate(QuantumOperator::SUPERPOSITION, 0),
+    ///         QasmStatement::ControlledNot(0, 2),
+    ///         QasmStatement::ControlledNot(1, 2),
+    ///         QasmStatement::ControlledPhase(2, 1),
+    ///         QasmStatement::ControlledNot(1, 2),
+    ///         QasmStatement::ControlledNot(0, 2),
+    ///     ];
+    ///     UncomputationCheck::verify(3, &program, &[2]) == UncomputationCheck::Clean
+    /// }
+    /// ```
+    pub fn verify(num_qubits: usize, statements: &[QasmStatement], ancillas: &[usize]) -> Self {
+        assert!(
+            ancillas.iter().all(|&ancilla| ancilla < num_qubits),
+            "Invalid ancilla qubit index"
+        );
+
+        let mut tableau = CliffordTableau::new(num_qubits);
+        // The index of the statement after the last time each ancilla was |0>.
+        let mut broken_at = vec![None; ancillas.len()];
+        for (index, statement) in statements.iter().enumerate() {
+            match statement {
+                QasmStatement::QuantumRegister(_) | QasmStatement::ClassicalRegister(_) => continue,
+                QasmStatement::Gate(operator, target) => {
+                    let gate = QuantumGate::new(operator.clone());
+                    if !gate.is_clifford() {
+                        return UncomputationCheck::Unverifiable(index);
+                    }
+                    tableau.apply_gate(&gate, *target);
+                }
+                QasmStatement::ControlledNot(control, target) => {
+                    tableau.apply_cnot(*control, *target)
+                }
+                QasmStatement::ControlledPhase(a, b) => tableau.apply_cz(*a, *b),
+                QasmStatement::Measure(_, _) => return UncomputationCheck::Unverifiable(index),
+            }
+
+            for (broken, &ancilla) in broken_at.iter_mut().zip(ancillas) {
+                let is_zero = tableau.measurement_outcome(ancilla) == Some(true);
+                *broken = match (is_zero, *broken) {
+                    (true, _) => None,
+                    (false, None) => Some(index),
+                    (false, broken) => broken,
+                };
+            }
+        }
+
+        // Report the ancilla that broke first, since later ones may only be broken by it.
+        broken_at
+            .iter()
+            .zip(ancillas)
+            .filter_map(|(broken, &ancilla)| broken.map(|statement| (statement, ancilla)))
+            .min()
+            .map_or(UncomputationCheck::Clean, |(statement, ancilla)| {
+                UncomputationCheck::Dirty { ancilla, statement }
+            })
+    }
+}
@@ -0,0 +1,124 @@
+use crate::quantum::types::{
+    quantum_gate::QuantumGate, qubit::Qubit, qubit_register::QubitRegister,
+    state_vector::StateVector, unique_qubit::UniqueQubit,
+};
+
+impl UniqueQubit {
+    /// [`UniqueQubit::new`] will take ownership of a [`Qubit`], and create a new [`UniqueQubit`] from it.
+    ///
+    /// # Example
+    /// [`UniqueQubit::new`] can be used to create a new [`UniqueQubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, unique_qubit::UniqueQubit};
+    ///
+    /// fn create_unique_qubit() -> UniqueQubit {
+    ///     UniqueQubit::new(Qubit::zero())
+    /// }
+    /// ```
+    pub fn new(qubit: Qubit) -> Self {
+        UniqueQubit { qubit }
+    }
+
+    /// [`UniqueQubit::apply_gate`] will consume a [`UniqueQubit`], apply a [`QuantumGate`] to it, and
+    /// return the resulting [`UniqueQubit`].
+    ///
+    /// # Example
+    /// [`UniqueQubit::apply_gate`] can be used to apply a NOT gate to a [`UniqueQubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{quantum_gate::QuantumGate, unique_qubit::UniqueQubit};
+    ///
+    /// fn invert_unique_qubit(qubit: UniqueQubit) -> UniqueQubit {
+    ///     qubit.apply_gate(&QuantumGate::NOT)
+    /// }
+    /// ```
+    pub fn apply_gate(self, gate: &QuantumGate) -> Self {
+        UniqueQubit {
+            qubit: self.qubit.apply_gate(gate),
+        }
+    }
+
+    /// [`UniqueQubit::measure`] will consume a [`UniqueQubit`] and return a [`bool`] for it's "truthy"
+    /// state, in the same way as [`Qubit::measure`], since measuring destroys the superposition.
+    ///
+    /// # Example
+    /// [`UniqueQubit::measure`] can be used to observe a [`UniqueQubit`] a single time:
+    /// ```rust
+    /// use rquant::quantum::types::unique_qubit::UniqueQubit;
+    ///
+    /// fn observe_unique_qubit(qubit: UniqueQubit) -> bool {
+    ///     qubit.measure()
+    /// }
+    /// ```
+    pub fn measure(self) -> bool {
+        self.qubit.measure()
+    }
+
+    /// [`UniqueQubit::split_via_cnot`] will consume a [`UniqueQubit`], and apply a controlled NOT gate
+    /// from it onto a new $|0\rangle$ qubit.
+    ///
+    /// This is the closest thing to copying a qubit that physics allows: basis states are copied,
+    /// but a superposition such as $\alpha|0\rangle + \beta|1\rangle$ becomes the entangled state
+    /// $\alpha|00\rangle + \beta|11\rangle$, rather than two independent copies.
+    ///
+    /// # Example
+    /// [`UniqueQubit::split_via_cnot`] can be used to create a Bell state:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_gate::QuantumGate,
+    ///     qubit::Qubit,
+    ///     state_vector::StateVector,
+    ///     unique_qubit::UniqueQubit,
+    /// };
+    ///
+    /// fn create_bell_state() -> StateVector {
+    ///     UniqueQubit::new(Qubit::zero())
+    ///         .apply_gate(&QuantumGate::SUPERPOSITION)
+    ///         .split_via_cnot()
+    /// }
+    /// ```
+    pub fn split_via_cnot(self) -> StateVector {
+        self.entangle_via_cnot(UniqueQubit::new(Qubit::zero()))
+    }
+
+    /// [`UniqueQubit::entangle_via_cnot`] will consume a control [`UniqueQubit`] and a `target`
+    /// [`UniqueQubit`], and apply a controlled NOT gate between them, returning their joint
+    /// [`StateVector`] with the control first.
+    ///
+    /// # Example
+    /// [`UniqueQubit::entangle_via_cnot`] can be used to entangle two existing qubits:
+    /// ```rust
+    /// use rquant::quantum::types::{
+    ///     quantum_gate::QuantumGate,
+    ///     qubit::Qubit,
+    ///     state_vector::StateVector,
+    ///     unique_qubit::UniqueQubit,
+    /// };
+    ///
+    /// fn entangle_qubits(control: UniqueQubit, target: UniqueQubit) -> StateVector {
+    ///     control.entangle_via_cnot(target)
+    /// }
+    /// ```
+    pub fn entangle_via_cnot(self, target: UniqueQubit) -> StateVector {
+        let mut state_vector = StateVector::from_register(&QubitRegister {
+            qubits: vec![self.qubit, target.qubit],
+        });
+        state_vector.apply_cnot(0, 1);
+        state_vector
+    }
+
+    /// [`UniqueQubit::into_qubit`] will consume a [`UniqueQubit`], and give up ownership of it's
+    /// [`Qubit`].
+    ///
+    /// # Example
+    /// [`UniqueQubit::into_qubit`] can be used to get the [`Qubit`] back out of a [`UniqueQubit`]:
+    /// ```rust
+    /// use rquant::quantum::types::{qubit::Qubit, unique_qubit::UniqueQubit};
+    ///
+    /// fn release_unique_qubit(qubit: UniqueQubit) -> Qubit {
+    ///     qubit.into_qubit()
+    /// }
+    /// ```
+    pub fn into_qubit(self) -> Qubit {
+        self.qubit
+    }
+}
@@ -0,0 +1,68 @@
+/// [`circuit!`](crate::circuit) will build a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit)
+/// from the amount of [`Qubits`](crate::quantum::types::qubit::Qubit) it operates on, followed by
+/// a comma separated list of gates that each have a standard name, any parameters in parentheses,
+/// and a target.
+///
+/// When the amount of [`Qubits`](crate::quantum::types::qubit::Qubit) and a target are both
+/// literals, a target that is out of range is rejected at compile time. Any other target is
+/// checked when the [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) is
+/// built, and a target that is not a literal or a variable must be wrapped in braces.
+///
+/// # Example
+/// [`circuit!`](crate::circuit) can be used to build a circuit with a rotation:
+/// ```rust
+/// use rquant::{circuit, quantum::types::quantum_circuit::QuantumCircuit};
+///
+/// fn create_rotated_superposition(angle: f64) -> QuantumCircuit {
+///     circuit!(2; h 0, rz(angle) 0, x 1)
+/// }
+/// ```
+///
+/// A literal target outside of the circuit does not compile:
+/// ```compile_fail
+/// use rquant::circuit;
+///
+/// let circuit = circuit!(2; h 0, x 2);
+/// ```
+#[macro_export]
+macro_rules! circuit {
+    ($num_qubits:literal; $($gates:tt)*) => {{
+        let circuit = $crate::quantum::types::quantum_circuit::QuantumCircuit::new($num_qubits);
+        $crate::circuit!(@gates circuit, [$num_qubits]; $($gates)*)
+    }};
+    ($num_qubits:expr; $($gates:tt)*) => {{
+        let circuit = $crate::quantum::types::quantum_circuit::QuantumCircuit::new($num_qubits);
+        $crate::circuit!(@gates circuit, []; $($gates)*)
+    }};
+    (@gates $circuit:ident, $bound:tt; $(,)?) => {
+        $circuit
+    };
+    (@gates $circuit:ident, $bound:tt; $gate:ident ($($parameter:expr),*) $target:tt $(, $($gates:tt)*)?) => {{
+        let $circuit = $circuit.add_gate(
+            &$crate::circuit!(@gate $gate, [$($parameter),*]),
+            $crate::circuit!(@target $bound, $target),
+        );
+        $crate::circuit!(@gates $circuit, $bound; $($($gates)*)?)
+    }};
+    (@gates $circuit:ident, $bound:tt; $gate:ident $target:tt $(, $($gates:tt)*)?) => {{
+        let $circuit = $circuit.add_gate(
+            &$crate::circuit!(@gate $gate, []),
+            $crate::circuit!(@target $bound, $target),
+        );
+        $crate::circuit!(@gates $circuit, $bound; $($($gates)*)?)
+    }};
+    (@gate $gate:ident, [$($parameter:expr),*]) => {
+        $crate::quantum::types::quantum_gate::QuantumGate::from_name_with_parameters(
+            stringify!($gate),
+            &[$(($parameter) as f64),*],
+        )
+        .unwrap_or_else(|error| panic!("Invalid circuit gate: {error}"))
+    };
+    (@target [$num_qubits:literal], $target:literal) => {{
+        const { assert!($target < $num_qubits, "Invalid circuit qubit index") };
+        $target
+    }};
+    (@target $bound:tt, $target:expr) => {
+        $target
+    };
+}
@@ -0,0 +1,8 @@
+/// [`AmplitudeError`] is the reason a [`QuantumPosition`](crate::quantum::types::quantum_position::QuantumPosition)
+/// could not be made into a [`Qubit`](crate::quantum::types::qubit::Qubit), because the squares
+/// of it's amplitudes do not add up to 1.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AmplitudeError {
+    /// The sum of the squares of the amplitudes, $|\alpha|^2 + |\beta|^2$.
+    pub sum_of_squares: f64,
+}
@@ -0,0 +1,18 @@
+/// [`Basis`] is a measurement basis, which decides which states a measurement tells apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Basis {
+    /// The computational basis of $|0\rangle$ and $|1\rangle$, which needs no rotation.
+    Z,
+
+    /// The basis of $|+\rangle$ and $|-\rangle$, which are measured as $|0\rangle$ and
+    /// $|1\rangle$ after a SUPERPOSITION gate.
+    X,
+
+    /// The basis of $|{+i}\rangle$ and $|{-i}\rangle$, which are measured as $|0\rangle$ and
+    /// $|1\rangle$ after an $S^\dagger$ and a SUPERPOSITION gate.
+    Y,
+
+    /// The two-qubit basis of the four Bell states, which are measured as the four basis states
+    /// after a controlled NOT and a SUPERPOSITION gate on the first qubit.
+    Bell,
+}
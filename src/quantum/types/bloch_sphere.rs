@@ -0,0 +1,8 @@
+/// [`BlochSphere`] is a text rendering of a single [`Qubit`](crate::quantum::types::qubit::Qubit)
+/// on the Bloch sphere, seen from the front, where $|0\rangle$ is at the top, $|1\rangle$ is at
+/// the bottom, and the marker shows if the state is on the near or far half of the sphere.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlochSphere {
+    /// The `[x, y, z]` Bloch vector shown on the [`BlochSphere`].
+    pub vector: [f64; 3],
+}
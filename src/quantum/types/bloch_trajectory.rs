@@ -0,0 +1,11 @@
+/// [`BlochTrajectory`] is a timeline of Bloch vectors that shows how a
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) has moved around the Bloch sphere.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlochTrajectory {
+    /// A collection of `[x, y, z]` Bloch vectors.
+    ///
+    /// The first element represents the initial [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// position, and the last element represents the current [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// position.
+    pub frames: Vec<[f64; 3]>,
+}
@@ -0,0 +1,20 @@
+/// [`CliffordTableau`] tracks a stabilizer state of many [`Qubits`](crate::quantum::types::qubit::Qubit)
+/// by it's stabilizer and destabilizer generators, rather than by it's amplitudes.
+///
+/// Only Clifford gates can be applied to a [`CliffordTableau`], which allows it to describe
+/// entangled states of many qubits efficiently.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CliffordTableau {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`CliffordTableau`].
+    pub num_qubits: usize,
+
+    /// The X bits of every generator, where the first `num_qubits` rows are the destabilizers,
+    /// and the last `num_qubits` rows are the stabilizers.
+    pub(crate) x: Vec<Vec<bool>>,
+
+    /// The Z bits of every generator, in the same order as `x`.
+    pub(crate) z: Vec<Vec<bool>>,
+
+    /// The sign bit of every generator, where `true` is a sign of $-1$.
+    pub(crate) signs: Vec<bool>,
+}
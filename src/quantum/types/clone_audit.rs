@@ -0,0 +1,4 @@
+/// [`CloneAudit`] will flag every place that duplicates an entangled
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector) while it is enabled, which helps
+/// show where code relies on copying quantum state in a way the no-cloning theorem forbids.
+pub struct CloneAudit;
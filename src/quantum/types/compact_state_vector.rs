@@ -0,0 +1,15 @@
+use num_complex::Complex;
+
+/// [`CompactStateVector`] holds the joint amplitudes of many
+/// [`Qubits`](crate::quantum::types::qubit::Qubit) in single precision, which uses half the memory
+/// of a [`StateVector`](crate::quantum::types::state_vector::StateVector) and so holds one more
+/// qubit in the same space, at the cost of about seven significant digits of precision.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompactStateVector {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`CompactStateVector`].
+    pub num_qubits: usize,
+
+    /// The [`Complex<f32>`] amplitude of every computational basis state, where the first
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is the most significant bit of the index.
+    pub amplitudes: Vec<Complex<f32>>,
+}
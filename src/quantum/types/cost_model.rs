@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+/// [`CostModel`] assigns a weight to every gate type, so a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) can be scored by a
+/// user-chosen objective rather than just it's gate count.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CostModel {
+    /// The weight of each gate type, by it's standard name without parameters, like `h`, `rz`, or
+    /// `cx` for a controlled gate.
+    pub weights: HashMap<String, f64>,
+
+    /// The weight of a gate type that has no weight of it's own.
+    pub default_weight: f64,
+
+    /// The weight added to every gate that is not a Clifford gate, like a T gate.
+    pub non_clifford_weight: f64,
+
+    /// The weight added to every gate that acts on more than one qubit, like a controlled NOT gate.
+    pub two_qubit_weight: f64,
+}
@@ -0,0 +1,31 @@
+use std::collections::HashMap;
+
+use num_complex::Complex;
+
+use crate::quantum::types::decision_node::DecisionNode;
+
+/// The key of a [`DecisionNode`] in the unique table of a [`DecisionDiagram`], as it's qubit and
+/// the child node and rounded real and imaginary weight of both of it's edges.
+pub type NodeKey = (usize, [(usize, i64, i64); 2]);
+
+/// [`DecisionDiagram`] is an experimental representation of the joint state of many
+/// [`Qubits`](crate::quantum::types::qubit::Qubit) that shares every repeated part of the state,
+/// which can be exponentially smaller than a
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector) for structured circuits like
+/// arithmetic and oracles.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecisionDiagram {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`DecisionDiagram`].
+    pub num_qubits: usize,
+
+    /// Every [`DecisionNode`] of the [`DecisionDiagram`], where the first node is the terminal node.
+    pub nodes: Vec<DecisionNode>,
+
+    /// The edge to the node of the first [`Qubit`](crate::quantum::types::qubit::Qubit), as the
+    /// index of the node and the weight of the whole state.
+    pub root: (usize, Complex<f64>),
+
+    /// The index of every [`DecisionNode`], keyed by it's qubit and rounded edges, so that equal
+    /// nodes are only stored once.
+    pub unique_table: HashMap<NodeKey, usize>,
+}
@@ -0,0 +1,14 @@
+use num_complex::Complex;
+
+/// [`DecisionNode`] is one node of a [`DecisionDiagram`](crate::quantum::types::decision_diagram::DecisionDiagram),
+/// which splits the state on the value of a single [`Qubit`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DecisionNode {
+    /// The index of the [`Qubit`](crate::quantum::types::qubit::Qubit) this node splits on, or the
+    /// amount of qubits for the terminal node.
+    pub qubit: usize,
+
+    /// The edges to the $|0\rangle$ and $|1\rangle$ branches, as the index of the child node and
+    /// the weight the child is multiplied by.
+    pub children: [(usize, Complex<f64>); 2],
+}
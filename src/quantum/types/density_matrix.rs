@@ -0,0 +1,15 @@
+use num_complex::Complex;
+
+/// [`DensityMatrix`] is the state of many [`Qubits`](crate::quantum::types::qubit::Qubit) as a
+/// matrix $\rho$, which can describe mixed states that a
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector) cannot, such as the result
+/// of noise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DensityMatrix {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`DensityMatrix`].
+    pub num_qubits: usize,
+
+    /// The elements of the [`DensityMatrix`] by row, in the same basis order as
+    /// [`StateVector::amplitudes`](crate::quantum::types::state_vector::StateVector::amplitudes).
+    pub elements: Vec<Vec<Complex<f64>>>,
+}
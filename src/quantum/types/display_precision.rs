@@ -0,0 +1,4 @@
+/// [`DisplayPrecision`] will control how many digits after the decimal point are shown when
+/// amplitudes are displayed, which keeps multi-qubit states readable instead of printing every
+/// [`f64`] at full precision.
+pub struct DisplayPrecision;
@@ -0,0 +1,11 @@
+/// [`DualNumber`] is a real number that carries it's derivatives along with it's value, such
+/// that exact gradients can be calculated in a single forward pass of arithmetic.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DualNumber {
+    /// The real value of the [`DualNumber`].
+    pub value: f64,
+
+    /// The partial derivatives of `value`, with one entry per parameter being
+    /// differentiated.
+    pub derivatives: Vec<f64>,
+}
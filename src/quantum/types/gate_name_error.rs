@@ -0,0 +1,22 @@
+/// [`GateNameError`] is the reason a textual gate name, like `h` or `rz(pi/2)`, could not be read
+/// as a [`QuantumGate`](crate::quantum::types::quantum_gate::QuantumGate).
+#[derive(Clone, Debug, PartialEq)]
+pub enum GateNameError {
+    /// The name is not one of the standard gate names.
+    Unknown(String),
+
+    /// The gate was given a different amount of parameters than it takes.
+    Parameters {
+        /// The name of the gate.
+        name: String,
+
+        /// The amount of parameters the gate takes.
+        expected: usize,
+
+        /// The amount of parameters the gate was given.
+        found: usize,
+    },
+
+    /// A parameter could not be evaluated as a number.
+    InvalidParameter(String),
+}
@@ -0,0 +1,15 @@
+use crate::quantum::types::{quantum_operators::QuantumOperator, qubit::Qubit};
+
+/// [`GateRecording`] is a [`Qubit`] that remembers every
+/// [`QuantumGate`](crate::quantum::types::quantum_gate::QuantumGate) applied to it, so the same
+/// gates can be replayed on another [`Qubit`] or exported to a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit).
+#[derive(Clone, Debug, PartialEq)]
+pub struct GateRecording {
+    /// The [`Qubit`] that every recorded gate was applied to.
+    pub qubit: Qubit,
+
+    /// The [`QuantumOperators`](QuantumOperator) of every recorded gate, in the order they were
+    /// applied.
+    pub operators: Vec<QuantumOperator>,
+}
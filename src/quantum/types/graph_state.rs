@@ -0,0 +1,12 @@
+/// [`GraphState`] is an entangled state of many [`Qubits`](crate::quantum::types::qubit::Qubit)
+/// described by a graph, where each vertex is a qubit and each edge is a controlled phase
+/// between two qubits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphState {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`GraphState`].
+    pub num_qubits: usize,
+
+    /// The adjacency matrix of the graph, where `adjacency[a][b]` is `true` if the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) at `a` and `b` share an edge.
+    pub adjacency: Vec<Vec<bool>>,
+}
@@ -0,0 +1,12 @@
+use crate::quantum::types::pauli_string::PauliString;
+
+/// [`Hamiltonian`] is an observable made of a weighted sum of [`PauliStrings`](PauliString):
+/// $$ H = \sum_i c_i P_i $$
+#[derive(Clone, Debug, PartialEq)]
+pub struct Hamiltonian {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) the [`Hamiltonian`] acts on.
+    pub num_qubits: usize,
+
+    /// The coefficient $c_i$ and [`PauliString`] $P_i$ of every term of the [`Hamiltonian`].
+    pub terms: Vec<(f64, PauliString)>,
+}
@@ -0,0 +1,11 @@
+use num_complex::Complex;
+
+/// [`Heatmap`] is a text rendering of a gate matrix, where the magnitude of each element is a
+/// shaded block and it's phase is an arrow, which keeps $4 \times 4$ and $8 \times 8$ unitaries
+/// readable in a terminal.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heatmap {
+    /// The square matrix of the [`Heatmap`] by row, in the same basis order as
+    /// [`StateVector::amplitudes`](crate::quantum::types::state_vector::StateVector::amplitudes).
+    pub matrix: Vec<Vec<Complex<f64>>>,
+}
@@ -0,0 +1,23 @@
+use num_complex::Complex;
+
+/// [`MatrixProductState`] holds the joint state of many [`Qubits`](crate::quantum::types::qubit::Qubit)
+/// as a chain of small tensors, one per qubit, which can describe weakly entangled states of far
+/// more qubits than a [`StateVector`](crate::quantum::types::state_vector::StateVector).
+///
+/// The bonds between neighboring tensors are truncated to a `max_bond_dimension`, so strongly
+/// entangled states are approximated instead of growing exponentially.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatrixProductState {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`MatrixProductState`].
+    pub num_qubits: usize,
+
+    /// The largest amount of singular values kept on each bond between neighboring qubits.
+    pub max_bond_dimension: usize,
+
+    /// The tensor of every [`Qubit`](crate::quantum::types::qubit::Qubit), indexed by it's basis
+    /// state, then the left bond, then the right bond.
+    pub tensors: Vec<Vec<Vec<Vec<Complex<f64>>>>>,
+
+    /// The total probability weight that has been discarded by truncating bonds.
+    pub truncation_error: f64,
+}
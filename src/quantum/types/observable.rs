@@ -0,0 +1,9 @@
+use crate::quantum::types::hamiltonian::Hamiltonian;
+
+/// [`Observable`] is a weighted sum of [`PauliStrings`](crate::quantum::types::pauli_string::PauliString)
+/// that is measured rather than used to evolve a state:
+/// $$ O = \sum_i c_i P_i $$
+///
+/// An [`Observable`] is the same type as a [`Hamiltonian`], so it can be
+/// [`parsed`](Hamiltonian::parse), added, scaled, and evaluated with every [`Hamiltonian`] behavior.
+pub type Observable = Hamiltonian;
@@ -0,0 +1,17 @@
+/// [`Pauli`] is a collection of the single-qubit Pauli operators, which are used to describe
+/// errors and observables of [`Qubits`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pauli {
+    /// The identity [`Pauli`] operator, which leaves a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// unchanged.
+    I,
+
+    /// The X [`Pauli`] operator, which is a bit flip.
+    X,
+
+    /// The Y [`Pauli`] operator, which is a bit flip and a phase flip.
+    Y,
+
+    /// The Z [`Pauli`] operator, which is a phase flip.
+    Z,
+}
@@ -0,0 +1,13 @@
+use crate::quantum::types::pauli::Pauli;
+
+/// [`PauliString`] is a signed tensor product of [`Paulis`](Pauli), with one [`Pauli`] per
+/// [`Qubit`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PauliString {
+    /// Whether the [`PauliString`] has a sign of $-1$ instead of $+1$.
+    pub negative: bool,
+
+    /// The [`Paulis`](Pauli) of the [`PauliString`], where the first element applies to the
+    /// first [`Qubit`](crate::quantum::types::qubit::Qubit).
+    pub paulis: Vec<Pauli>,
+}
@@ -0,0 +1,25 @@
+use crate::quantum::types::quantum_operators::QuantumOperator;
+
+/// [`QasmStatement`] is one instruction read from an OpenQASM 2.0 program by a
+/// [`QasmStream`](crate::quantum::types::qasm_stream::QasmStream), with every register resolved
+/// to flat [`Qubit`](crate::quantum::types::qubit::Qubit) and classical bit indices.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QasmStatement {
+    /// Declares a quantum register of some amount of qubits, after every earlier quantum register.
+    QuantumRegister(usize),
+
+    /// Declares a classical register of some amount of bits, after every earlier classical register.
+    ClassicalRegister(usize),
+
+    /// Applies a single-qubit [`QuantumOperator`] to the qubit at an index.
+    Gate(QuantumOperator, usize),
+
+    /// Applies a controlled NOT gate from the control qubit to the target qubit.
+    ControlledNot(usize, usize),
+
+    /// Applies a controlled Z gate to both qubits.
+    ControlledPhase(usize, usize),
+
+    /// Measures the qubit at the first index into the classical bit at the second index.
+    Measure(usize, usize),
+}
@@ -0,0 +1,27 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::quantum::types::qasm_statement::QasmStatement;
+
+/// [`QasmStream`] reads [`QasmStatements`](QasmStatement) from an OpenQASM 2.0 program one line at
+/// a time, so programs with millions of gates can be run without holding them in memory.
+#[derive(Debug)]
+pub struct QasmStream<R> {
+    /// The reader the program is read from.
+    pub reader: R,
+
+    /// The number of the last line that was read, which is reported when a line can not be parsed.
+    pub line_number: usize,
+
+    /// The first index and size of every quantum register, by name.
+    pub quantum_registers: HashMap<String, (usize, usize)>,
+
+    /// The first index and size of every classical register, by name.
+    pub classical_registers: HashMap<String, (usize, usize)>,
+
+    /// The [`QasmStatements`](QasmStatement) that have been parsed but not returned yet, like the
+    /// gates of one statement that targets a whole register.
+    pub pending: VecDeque<QasmStatement>,
+
+    /// The start of a statement that continues on the next line.
+    pub partial: String,
+}
@@ -0,0 +1,19 @@
+use crate::quantum::types::quantum_operation::QuantumOperation;
+
+/// [`QuantumCircuit`] is an ordered collection of [`QuantumOperations`](QuantumOperation)
+/// that can be applied to a [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister).
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumCircuit {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) the [`QuantumCircuit`]
+    /// operates on.
+    pub num_qubits: usize,
+
+    /// The [`QuantumOperations`](QuantumOperation) of the [`QuantumCircuit`], in the order
+    /// they are applied.
+    pub operations: Vec<QuantumOperation>,
+
+    /// The post-selection flags of the [`QuantumCircuit`], where each flag is the index of a
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) and the measured value a shot must have to
+    /// be kept.
+    pub post_selections: Vec<(usize, bool)>,
+}
@@ -0,0 +1,32 @@
+/// [`QuantumError`] is the reason an operation on a
+/// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister) could not be done.
+#[derive(Clone, Debug, PartialEq)]
+pub enum QuantumError {
+    /// A [`Qubit`](crate::quantum::types::qubit::Qubit) index is not in the register.
+    InvalidQubitIndex {
+        /// The index that was asked for.
+        index: usize,
+
+        /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the register.
+        len: usize,
+    },
+
+    /// A bitstring has a character that is not `0` or `1`.
+    InvalidBitstring {
+        /// The invalid character.
+        character: char,
+
+        /// The position of the invalid character in the bitstring.
+        position: usize,
+    },
+
+    /// A bitstring does not have one character for every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) in the register.
+    InvalidBitstringLength {
+        /// The length of the bitstring.
+        length: usize,
+
+        /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the register.
+        len: usize,
+    },
+}
@@ -0,0 +1,19 @@
+use crate::quantum::types::quantum_operators::QuantumOperator;
+
+/// [`QuantumOperation`] is a single step of a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit),
+/// which applies a [`QuantumOperator`] to one [`Qubit`](crate::quantum::types::qubit::Qubit), only
+/// on the basis states where every one of it's `controls` is $|1\rangle$.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantumOperation {
+    /// The [`QuantumOperator`] that is applied to the `target`.
+    pub operator: QuantumOperator,
+
+    /// The index of the [`Qubit`](crate::quantum::types::qubit::Qubit) in a
+    /// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister) that the
+    /// `operator` is applied to.
+    pub target: usize,
+
+    /// The indices of the [`Qubits`](crate::quantum::types::qubit::Qubit) that must all be
+    /// $|1\rangle$ for the `operator` to be applied, which is empty for a single-qubit operation.
+    pub controls: Vec<usize>,
+}
@@ -60,4 +60,24 @@ pub enum QuantumOperator {
     /// $$H|0\rangle = \frac{1}{\sqrt{2}}(|0\rangle + |1\rangle)$$
     /// $$H|1\rangle = \frac{1}{\sqrt{2}}(|0\rangle - |1\rangle)$$
     SUPERPOSITION,
+    /// The RX [`QuantumOperator`] rotates a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// around it's X-axis by an angle $\theta$, in radians.
+    ///
+    /// The RX [`QuantumOperator`] can be represented by the following matrix:
+    /// $$R_x(\theta)=\begin{pmatrix} \cos\frac{\theta}{2} & -i\sin\frac{\theta}{2} \\\
+    /// -i\sin\frac{\theta}{2} & \cos\frac{\theta}{2} \end{pmatrix}$$
+    RX(f64),
+    /// The RY [`QuantumOperator`] rotates a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// around it's Y-axis by an angle $\theta$, in radians.
+    ///
+    /// The RY [`QuantumOperator`] can be represented by the following matrix:
+    /// $$R_y(\theta)=\begin{pmatrix} \cos\frac{\theta}{2} & -\sin\frac{\theta}{2} \\\
+    /// \sin\frac{\theta}{2} & \cos\frac{\theta}{2} \end{pmatrix}$$
+    RY(f64),
+    /// The RZ [`QuantumOperator`] rotates a [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// around it's Z-axis by an angle $\theta$, in radians.
+    ///
+    /// The RZ [`QuantumOperator`] can be represented by the following matrix:
+    /// $$R_z(\theta)=\begin{pmatrix} e^{-i\theta/2} & 0 \\\ 0 & e^{i\theta/2} \end{pmatrix}$$
+    RZ(f64),
 }
@@ -0,0 +1,13 @@
+/// [`QubitView`] addresses a subset of the [`Qubits`](crate::quantum::types::qubit::Qubit) in a
+/// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister), like the counting or
+/// work register of a larger algorithm, without copying any of them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QubitView {
+    /// The bitmask of the selected [`Qubits`](crate::quantum::types::qubit::Qubit), where bit `i`
+    /// selects the qubit at index `i`.
+    pub mask: usize,
+
+    /// The indices of the selected [`Qubits`](crate::quantum::types::qubit::Qubit), in ascending
+    /// order.
+    pub qubits: Vec<usize>,
+}
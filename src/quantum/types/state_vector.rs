@@ -0,0 +1,17 @@
+use num_complex::Complex;
+
+/// [`StateVector`] holds the joint amplitudes of many [`Qubits`](crate::quantum::types::qubit::Qubit),
+/// which allows it to describe entangled states that a
+/// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister) can not.
+///
+/// Cloning an entangled [`StateVector`] is flagged by
+/// [`CloneAudit`](crate::quantum::types::clone_audit::CloneAudit) when it is enabled.
+#[derive(Debug, PartialEq)]
+pub struct StateVector {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) in the [`StateVector`].
+    pub num_qubits: usize,
+
+    /// The [`Complex<f64>`] amplitude of every computational basis state, where the first
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is the most significant bit of the index.
+    pub amplitudes: Vec<Complex<f64>>,
+}
@@ -0,0 +1,22 @@
+/// [`UncomputationCheck`] is the result of verifying that every ancilla
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) of a program returns to $|0\rangle$ before it
+/// is released.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UncomputationCheck {
+    /// Every ancilla is certainly $|0\rangle$ at the end of the program.
+    Clean,
+
+    /// An `ancilla` is left flipped, in superposition, or entangled, where `statement` is the index
+    /// of the statement after which it never returns to $|0\rangle$.
+    Dirty {
+        /// The index of the ancilla [`Qubit`](crate::quantum::types::qubit::Qubit).
+        ancilla: usize,
+
+        /// The index of the statement that breaks the ancilla.
+        statement: usize,
+    },
+
+    /// The statement at an index can not be followed symbolically, because it is a non-Clifford
+    /// gate or a measurement, so nothing after it was verified.
+    Unverifiable(usize),
+}
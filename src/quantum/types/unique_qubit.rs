@@ -0,0 +1,19 @@
+use crate::quantum::types::qubit::Qubit;
+
+/// [`UniqueQubit`] is a move-only handle to a [`Qubit`], which does not implement [`Clone`], so the
+/// type system enforces the no-cloning theorem: once a [`UniqueQubit`] is used, it is gone.
+///
+/// # Example
+/// A [`UniqueQubit`] can not be copied:
+/// ```rust,compile_fail
+/// use rquant::quantum::types::unique_qubit::UniqueQubit;
+///
+/// fn clone_unique_qubit(qubit: UniqueQubit) -> (UniqueQubit, UniqueQubit) {
+///     (qubit.clone(), qubit)
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct UniqueQubit {
+    /// The [`Qubit`] the [`UniqueQubit`] has sole ownership of.
+    pub(crate) qubit: Qubit,
+}
@@ -0,0 +1,67 @@
+use std::sync::{atomic::Ordering, Arc};
+
+use crate::simulation::types::cancellation_token::CancellationToken;
+
+impl CancellationToken {
+    /// [`CancellationToken::new`] will create a new [`CancellationToken`] that has not been
+    /// cancelled.
+    ///
+    /// # Example
+    /// [`CancellationToken::new`] can be used to create a token for a run:
+    /// ```rust
+    /// use rquant::simulation::types::cancellation_token::CancellationToken;
+    ///
+    /// fn create_token() -> CancellationToken {
+    ///     CancellationToken::new()
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+
+    /// [`CancellationToken::cancel`] will ask every run that shares the [`CancellationToken`] to
+    /// stop before it's next shot.
+    ///
+    /// # Example
+    /// [`CancellationToken::cancel`] can be used to stop a run from another thread:
+    /// ```rust
+    /// use std::thread;
+    /// use rquant::simulation::types::cancellation_token::CancellationToken;
+    ///
+    /// fn cancel_from_thread(token: &CancellationToken) {
+    ///     let token = token.clone();
+    ///     thread::spawn(move || token.cancel());
+    /// }
+    /// ```
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// [`CancellationToken::is_cancelled`] returns `true` if [`CancellationToken::cancel`] has been
+    /// called on the [`CancellationToken`] or any of it's clones, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`CancellationToken::is_cancelled`] can be used to stop a loop of your own:
+    /// ```rust
+    /// use rquant::simulation::types::cancellation_token::CancellationToken;
+    ///
+    /// fn count_until_cancelled(token: &CancellationToken) -> usize {
+    ///     let mut count = 0;
+    ///     while !token.is_cancelled() && count < 100 {
+    ///         count += 1;
+    ///     }
+    ///     count
+    /// }
+    /// ```
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Implement the [`PartialEq`] trait for [`CancellationToken`].
+impl PartialEq for CancellationToken {
+    /// Will return `true` if both [`CancellationTokens`](CancellationToken) share the same flag.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cancelled, &other.cancelled)
+    }
+}
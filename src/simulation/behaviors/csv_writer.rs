@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+
+use crate::simulation::types::{csv_writer::CsvWriter, observer::Observer};
+
+impl<W: Write> CsvWriter<W> {
+    /// [`CsvWriter::new`] will create a new [`CsvWriter`] that writes to a `writer`, starting with
+    /// a `shot,bitstring` header row.
+    ///
+    /// # Example
+    /// [`CsvWriter::new`] can be used to stream shots to a file:
+    /// ```rust
+    /// use std::{fs::File, io::BufWriter};
+    /// use rquant::simulation::types::csv_writer::CsvWriter;
+    ///
+    /// fn create_file_writer(file: File) -> CsvWriter<BufWriter<File>> {
+    ///     CsvWriter::new(BufWriter::new(file))
+    /// }
+    /// ```
+    pub fn new(writer: W) -> Self {
+        CsvWriter {
+            writer,
+            has_header: false,
+            error: None,
+        }
+    }
+
+    /// [`CsvWriter::write_shot`] will write one row for a `shot` with measured `values`, where each
+    /// "truthy" value is a `0` in the bitstring, in the same way as
+    /// [`QubitRegister::measure_all`](crate::quantum::types::qubit_register::QubitRegister::measure_all).
+    ///
+    /// # Example
+    /// [`CsvWriter::write_shot`] can be used to write shots that were recorded elsewhere:
+    /// ```rust
+    /// use std::io;
+    /// use rquant::simulation::types::csv_writer::CsvWriter;
+    ///
+    /// fn write_bell_shots() -> io::Result<Vec<u8>> {
+    ///     let mut csv_writer = CsvWriter::new(vec![]);
+    ///     csv_writer.write_shot(0, &[true, true])?;
+    ///     csv_writer.write_shot(1, &[false, false])?;
+    ///     csv_writer.finish()
+    /// }
+    /// ```
+    pub fn write_shot(&mut self, shot: usize, values: &[bool]) -> io::Result<()> {
+        self.write_header()?;
+        let bitstring: String = values
+            .iter()
+            .map(|&value| if value { '0' } else { '1' })
+            .collect();
+        writeln!(self.writer, "{shot},{bitstring}")
+    }
+
+    /// [`CsvWriter::finish`] will flush a [`CsvWriter`] and return it's writer, or the first error
+    /// of a write while observing.
+    ///
+    /// # Example
+    /// [`CsvWriter::finish`] can be used to get the CSV of a run as text:
+    /// ```rust
+    /// use std::io;
+    /// use rquant::simulation::types::csv_writer::CsvWriter;
+    ///
+    /// fn to_text(csv_writer: CsvWriter<Vec<u8>>) -> io::Result<String> {
+    ///     Ok(String::from_utf8_lossy(&csv_writer.finish()?).into_owned())
+    /// }
+    /// ```
+    pub fn finish(mut self) -> io::Result<W> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.write_header()?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+
+    /// [`CsvWriter::write_header`] will write the header row, if it has not been written yet.
+    fn write_header(&mut self) -> io::Result<()> {
+        if !self.has_header {
+            writeln!(self.writer, "shot,bitstring")?;
+            self.has_header = true;
+        }
+        Ok(())
+    }
+}
+
+/// Implement the [`Observer`] trait for [`CsvWriter`].
+impl<W: Write> Observer for CsvWriter<W> {
+    /// Will write every accepted shot, and keep the first error for [`CsvWriter::finish`].
+    ///
+    /// # Example
+    /// Can be used to stream the shots of an [`Executor`](crate::simulation::types::executor::Executor)
+    /// to CSV:
+    /// ```rust
+    /// use std::io;
+    /// use rquant::{
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::{
+    ///         csv_writer::CsvWriter, executor::Executor, simulation_config::SimulationConfig,
+    ///     },
+    /// };
+    ///
+    /// fn stream_superposition(writer: impl io::Write) -> io::Result<()> {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     let mut csv_writer = CsvWriter::new(writer);
+    ///     Executor::new(SimulationConfig::new(1000))
+    ///         .with_observer(&mut csv_writer)
+    ///         .observe(&circuit);
+    ///     csv_writer.finish().map(|_| ())
+    /// }
+    /// ```
+    fn on_shot_end(&mut self, shot: usize, values: &[bool], accepted: bool) {
+        if accepted && self.error.is_none() {
+            self.error = self.write_shot(shot, values).err();
+        }
+    }
+}
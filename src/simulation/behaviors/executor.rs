@@ -0,0 +1,181 @@
+use std::time::Instant;
+
+use crate::{
+    quantum::types::{quantum_circuit::QuantumCircuit, qubit_register::QubitRegister},
+    simulation::{
+        behaviors::{simulation::ideal_sampler, simulation_outcome::sample_shot},
+        types::{
+            executor::Executor, observer::Observer, simulation_config::SimulationConfig,
+            simulation_outcome::SimulationOutcome,
+        },
+    },
+};
+
+impl<'a> Executor<'a> {
+    /// [`Executor::new`] will create a new [`Executor`] that runs with a [`SimulationConfig`], and
+    /// with no [`Observers`](Observer).
+    ///
+    /// # Example
+    /// [`Executor::new`] can be used to run a thousand reproducible shots:
+    /// ```rust
+    /// use rquant::simulation::types::{executor::Executor, simulation_config::SimulationConfig};
+    ///
+    /// fn create_executor() -> Executor<'static> {
+    ///     Executor::new(SimulationConfig::new(1000).with_seed(42))
+    /// }
+    /// ```
+    pub fn new(config: SimulationConfig) -> Self {
+        Executor {
+            config,
+            observers: vec![],
+        }
+    }
+
+    /// [`Executor::with_observer`] will register an [`Observer`] that is told about every shot and
+    /// measurement of the [`Executor`], then return the modified [`Executor`].
+    ///
+    /// # Example
+    /// [`Executor::with_observer`] can be used to count the shots as they run:
+    /// ```rust
+    /// use rquant::simulation::types::{
+    ///     executor::Executor, observer::Observer, simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// struct ShotCounter(usize);
+    ///
+    /// impl Observer for ShotCounter {
+    ///     fn on_shot_end(&mut self, _shot: usize, _values: &[bool], _accepted: bool) {
+    ///         self.0 += 1;
+    ///     }
+    /// }
+    ///
+    /// fn count_shots(counter: &mut ShotCounter) -> Executor<'_> {
+    ///     Executor::new(SimulationConfig::new(1000)).with_observer(counter)
+    /// }
+    /// ```
+    pub fn with_observer(mut self, observer: &'a mut dyn Observer) -> Self {
+        self.observers.push(observer);
+        self
+    }
+
+    /// [`Executor::observe`] will run every shot of a [`QuantumCircuit`] from $|0\ldots0\rangle$,
+    /// measuring every [`Qubit`](crate::quantum::types::qubit::Qubit) at the end of each shot, and
+    /// only report the shots to the [`Observers`](Observer) instead of keeping them. Returns `true`
+    /// if the run was stopped early by the
+    /// [`cancellation`](SimulationConfig::cancellation) or [`timeout`](SimulationConfig::timeout)
+    /// of the [`SimulationConfig`], and `false` otherwise.
+    ///
+    /// # Example
+    /// [`Executor::observe`] can be used to compute a running estimate of a probability:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::{
+    ///         executor::Executor, observer::Observer, simulation_config::SimulationConfig,
+    ///     },
+    /// };
+    ///
+    /// struct ZeroCounter(usize, usize);
+    ///
+    /// impl Observer for ZeroCounter {
+    ///     fn on_measurement(&mut self, _shot: usize, _target: usize, value: bool) {
+    ///         self.0 += value as usize;
+    ///         self.1 += 1;
+    ///     }
+    /// }
+    ///
+    /// fn estimate_zero_probability() -> f64 {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     let mut counter = ZeroCounter(0, 0);
+    ///     Executor::new(SimulationConfig::new(1000))
+    ///         .with_observer(&mut counter)
+    ///         .observe(&circuit);
+    ///     counter.0 as f64 / counter.1 as f64
+    /// }
+    /// ```
+    pub fn observe(&mut self, circuit: &QuantumCircuit) -> bool {
+        self.execute(circuit, |_| {})
+    }
+
+    /// [`Executor::run`] will run every shot of a [`QuantumCircuit`] in the same way as
+    /// [`Executor::observe`], and also keep them in a [`SimulationOutcome`] with the
+    /// [`post_selections`](QuantumCircuit::post_selections) of the [`QuantumCircuit`] applied.
+    ///
+    /// If the run is stopped early, the [`SimulationOutcome`] holds the shots that were run before
+    /// it stopped, and is marked as [`truncated`](SimulationOutcome::truncated).
+    ///
+    /// # Example
+    /// [`Executor::run`] can be used to sample a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::{
+    ///         executor::Executor, simulation_config::SimulationConfig,
+    ///         simulation_outcome::SimulationOutcome,
+    ///     },
+    /// };
+    ///
+    /// fn sample_superposition() -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     Executor::new(SimulationConfig::new(1000)).run(&circuit)
+    /// }
+    /// ```
+    pub fn run(&mut self, circuit: &QuantumCircuit) -> SimulationOutcome {
+        let mut shots = Vec::with_capacity(self.config.shots);
+        let truncated = self.execute(circuit, |values| shots.push(values.to_vec()));
+        SimulationOutcome {
+            truncated,
+            ..SimulationOutcome::new(circuit.num_qubits, shots)
+        }
+        .with_post_selections(circuit)
+    }
+
+    /// [`Executor::execute`] will run every shot of a [`QuantumCircuit`], report it to the
+    /// [`Observers`](Observer), and pass the measured values of each shot to `keep`, until it is
+    /// cancelled or runs out of time, which returns `true`.
+    fn execute(&mut self, circuit: &QuantumCircuit, mut keep: impl FnMut(&[bool])) -> bool {
+        let start = Instant::now();
+        let mut rng = self.config.rng();
+        let register = QubitRegister::new(circuit.num_qubits);
+        let ideal = ideal_sampler(
+            &register,
+            circuit,
+            &self.config.noise_model,
+            self.config.backend,
+        );
+
+        for shot in 0..self.config.shots {
+            if self.config.is_stopped(start) {
+                return true;
+            }
+
+            for observer in self.observers.iter_mut() {
+                observer.on_shot_start(shot);
+            }
+
+            let values = sample_shot(
+                &register,
+                circuit,
+                &self.config.noise_model,
+                ideal.as_ref(),
+                &mut rng,
+            );
+            for (target, &value) in values.iter().enumerate() {
+                for observer in self.observers.iter_mut() {
+                    observer.on_measurement(shot, target, value);
+                }
+            }
+
+            let accepted = circuit
+                .post_selections
+                .iter()
+                .all(|&(target, value)| values[target] == value);
+            for observer in self.observers.iter_mut() {
+                observer.on_shot_end(shot, &values, accepted);
+            }
+            self.config.report_progress(shot + 1, self.config.shots);
+            keep(&values);
+        }
+        false
+    }
+}
@@ -0,0 +1,180 @@
+use std::fmt;
+
+use crate::simulation::types::goodness_of_fit::GoodnessOfFit;
+
+/// The most iterations of the series and continued fraction of the incomplete gamma function.
+const MAX_ITERATIONS: usize = 500;
+
+/// The relative precision the incomplete gamma function is computed to.
+const EPSILON: f64 = 1e-14;
+
+impl GoodnessOfFit {
+    /// [`GoodnessOfFit::chi_square`] will run a Pearson chi-square test of `observed` counts
+    /// against `expected` probabilities of the same outcomes.
+    ///
+    /// Outcomes that are not expected are left out of the degrees of freedom, and observing one
+    /// gives an infinite statistic with a p-value of `0.0`.
+    ///
+    /// # Example
+    /// [`GoodnessOfFit::chi_square`] can be used to check that a coin is fair:
+    /// ```rust
+    /// use rquant::simulation::types::goodness_of_fit::GoodnessOfFit;
+    ///
+    /// fn is_fair(heads: u64, tails: u64) -> bool {
+    ///     GoodnessOfFit::chi_square(&[heads, tails], &[0.5, 0.5]).p_value > 0.05
+    /// }
+    /// ```
+    pub fn chi_square(observed: &[u64], expected: &[f64]) -> Self {
+        assert!(
+            observed.len() == expected.len(),
+            "Every observed count must have an expected probability"
+        );
+        assert!(
+            expected.iter().all(|&probability| probability >= 0.0)
+                && expected.iter().sum::<f64>() > 0.0,
+            "Invalid expected distribution"
+        );
+
+        let shots = observed.iter().sum::<u64>() as f64;
+        let total_probability: f64 = expected.iter().sum();
+        let statistic = observed
+            .iter()
+            .zip(expected)
+            .map(|(&count, &probability)| {
+                let expected_count = probability / total_probability * shots;
+                match (count, expected_count > 0.0) {
+                    (0, false) => 0.0,
+                    (_, false) => f64::INFINITY,
+                    (count, true) => (count as f64 - expected_count).powi(2) / expected_count,
+                }
+            })
+            .sum();
+        let degrees_of_freedom = expected
+            .iter()
+            .filter(|&&probability| probability > 0.0)
+            .count()
+            .saturating_sub(1);
+
+        GoodnessOfFit {
+            statistic,
+            degrees_of_freedom,
+            p_value: chi_square_survival(statistic, degrees_of_freedom),
+        }
+    }
+
+    /// [`GoodnessOfFit::is_consistent`] returns `true` if the observed counts fit the expected
+    /// distribution at a `significance` level, like `0.05`, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`GoodnessOfFit::is_consistent`] can be used to assert on a noisy simulation:
+    /// ```rust
+    /// use rquant::simulation::types::goodness_of_fit::GoodnessOfFit;
+    ///
+    /// fn assert_fits(fit: GoodnessOfFit) {
+    ///     assert!(fit.is_consistent(0.01), "{fit}");
+    /// }
+    /// ```
+    pub fn is_consistent(&self, significance: f64) -> bool {
+        self.p_value >= significance
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`GoodnessOfFit`].
+impl fmt::Display for GoodnessOfFit {
+    /// Will format the statistic, degrees of freedom and p-value of a [`GoodnessOfFit`]:
+    /// ```text
+    /// chi-square 2.31 with 3 degrees of freedom (p = 0.5105)
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`GoodnessOfFit`] to the console:
+    /// ```rust
+    /// use rquant::simulation::types::goodness_of_fit::GoodnessOfFit;
+    ///
+    /// fn print_fit(fit: &GoodnessOfFit) {
+    ///     println!("{fit}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "chi-square {:.2} with {} degrees of freedom (p = {:.4})",
+            self.statistic, self.degrees_of_freedom, self.p_value
+        )
+    }
+}
+
+/// [`chi_square_survival`] will get the probability of a chi-square distribution with
+/// `degrees_of_freedom` being at least `statistic`, which is the regularized upper incomplete
+/// gamma function $Q(k/2, x/2)$.
+fn chi_square_survival(statistic: f64, degrees_of_freedom: usize) -> f64 {
+    if degrees_of_freedom == 0 || statistic.is_infinite() {
+        return if statistic > 0.0 { 0.0 } else { 1.0 };
+    }
+    if statistic <= 0.0 {
+        return 1.0;
+    }
+
+    let (a, x) = (degrees_of_freedom as f64 / 2.0, statistic / 2.0);
+    let log_prefactor = a * x.ln() - x - ln_gamma(a);
+    if x < a + 1.0 {
+        // The series of the lower incomplete gamma function converges quickly here.
+        let (mut term, mut sum) = (1.0 / a, 1.0 / a);
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * log_prefactor.exp()).clamp(0.0, 1.0)
+    } else {
+        // The continued fraction of the upper incomplete gamma function, with Lentz's method.
+        let tiny = f64::MIN_POSITIVE / EPSILON;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut fraction = d;
+        for n in 1..MAX_ITERATIONS {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            d = if d.abs() < tiny { tiny } else { d };
+            c = b + an / c;
+            c = if c.abs() < tiny { tiny } else { c };
+            d = 1.0 / d;
+            let delta = d * c;
+            fraction *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (fraction * log_prefactor.exp()).clamp(0.0, 1.0)
+    }
+}
+
+/// [`ln_gamma`] will get the natural logarithm of the gamma function of a positive `x`, with the
+/// Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (index, coefficient)| {
+            sum + coefficient / (x + index as f64 + 1.0)
+        });
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
@@ -0,0 +1,92 @@
+use std::{fmt, sync::Arc};
+
+use crate::simulation::types::progress_hook::ProgressHook;
+
+/// The amount of times a [`ProgressHook`] is called during a run, not counting the last shot.
+const UPDATES: usize = 100;
+
+/// The amount of characters in the bar of [`ProgressHook::terminal`].
+const BAR_WIDTH: usize = 40;
+
+impl ProgressHook {
+    /// [`ProgressHook::new`] will create a new [`ProgressHook`] that calls `callback` with the
+    /// amount of completed shots and the total amount of shots as a simulation runs.
+    ///
+    /// # Example
+    /// [`ProgressHook::new`] can be used to log the progress of a long simulation:
+    /// ```rust
+    /// use rquant::{
+    ///     log_info,
+    ///     simulation::types::{progress_hook::ProgressHook, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn create_logged_config() -> SimulationConfig {
+    ///     SimulationConfig::new(10_000_000).with_progress(ProgressHook::new(|completed, total| {
+    ///         log_info!("{completed} of {total} shots");
+    ///     }))
+    /// }
+    /// ```
+    pub fn new(callback: impl Fn(usize, usize) + Send + Sync + 'static) -> Self {
+        ProgressHook {
+            callback: Arc::new(callback),
+        }
+    }
+
+    /// [`ProgressHook::terminal`] will create a new [`ProgressHook`] that draws a progress bar
+    /// on the standard error of the terminal.
+    ///
+    /// # Example
+    /// [`ProgressHook::terminal`] can be used to watch a long simulation from the command line:
+    /// ```rust
+    /// use rquant::simulation::types::{
+    ///     progress_hook::ProgressHook, simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// fn create_watched_config() -> SimulationConfig {
+    ///     SimulationConfig::new(10_000_000).with_progress(ProgressHook::terminal())
+    /// }
+    /// ```
+    pub fn terminal() -> Self {
+        ProgressHook::new(|completed, total| {
+            let total = total.max(1);
+            let filled = completed * BAR_WIDTH / total;
+            eprint!(
+                "\r[{}{}] {:>3}% ({completed}/{total})",
+                "#".repeat(filled),
+                " ".repeat(BAR_WIDTH - filled),
+                completed * 100 / total
+            );
+            if completed >= total {
+                eprintln!();
+            }
+        })
+    }
+
+    /// [`ProgressHook::report`] will call the callback of a [`ProgressHook`] about once every
+    /// hundredth of the `total` shots, and always for the last shot.
+    pub(crate) fn report(&self, completed: usize, total: usize) {
+        let step = (total / UPDATES).max(1);
+        if completed == total || completed.is_multiple_of(step) {
+            (self.callback)(completed, total);
+        }
+    }
+}
+
+/// Implement the [`fmt::Debug`] trait for [`ProgressHook`].
+impl fmt::Debug for ProgressHook {
+    /// Will format a [`ProgressHook`] by name, because it's callback can not be printed:
+    /// ```text
+    /// ProgressHook
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("ProgressHook")
+    }
+}
+
+/// Implement the [`PartialEq`] trait for [`ProgressHook`].
+impl PartialEq for ProgressHook {
+    /// Will return `true` if both [`ProgressHooks`](ProgressHook) share the same callback.
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.callback, &other.callback)
+    }
+}
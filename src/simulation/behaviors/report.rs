@@ -0,0 +1,230 @@
+use std::fmt;
+
+use crate::simulation::types::{goodness_of_fit::GoodnessOfFit, report::Report};
+
+/// The height in pixels of the tallest bar of an HTML report histogram.
+const HISTOGRAM_HEIGHT: f64 = 100.0;
+
+impl Report {
+    /// [`Report::new`] will create a new [`Report`] for a `subject` from the `counts` of every
+    /// outcome, where the total is the sum of the `counts`.
+    ///
+    /// # Example
+    /// [`Report::new`] can be used to report results that were recorded elsewhere:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn create_bell_report() -> Report {
+    ///     Report::new("Bell state", vec![(String::from("00"), 51), (String::from("11"), 49)])
+    /// }
+    /// ```
+    pub fn new(subject: &str, counts: Vec<(String, u64)>) -> Self {
+        Report {
+            subject: subject.to_string(),
+            total: counts.iter().map(|(_, count)| count).sum(),
+            counts,
+        }
+    }
+
+    /// [`Report::count`] will get the count of an `outcome`, which is `0` if it was never seen.
+    ///
+    /// # Example
+    /// [`Report::count`] can be used to check how often a qubit was "truthy":
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn true_count(report: &Report) -> u64 {
+    ///     report.count("true")
+    /// }
+    /// ```
+    pub fn count(&self, outcome: &str) -> u64 {
+        self.counts
+            .iter()
+            .find(|(counted, _)| counted == outcome)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// [`Report::frequency`] will get the fraction of shots that had an `outcome`, which is `0.0`
+    /// without any shots.
+    ///
+    /// # Example
+    /// [`Report::frequency`] can be used to assert on the results of a simulation:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn is_balanced(report: &Report) -> bool {
+    ///     (report.frequency("true") - 0.5).abs() < 0.05
+    /// }
+    /// ```
+    pub fn frequency(&self, outcome: &str) -> f64 {
+        self.fraction(self.count(outcome))
+    }
+
+    /// [`Report::frequencies`] will get the fraction of shots of every outcome, in the same order
+    /// as the [`counts`](Report::counts).
+    ///
+    /// # Example
+    /// [`Report::frequencies`] can be used to find the most likely outcome:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn most_likely(report: &Report) -> Option<String> {
+    ///     report
+    ///         .frequencies()
+    ///         .into_iter()
+    ///         .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    ///         .map(|(outcome, _)| outcome)
+    /// }
+    /// ```
+    pub fn frequencies(&self) -> Vec<(String, f64)> {
+        self.counts
+            .iter()
+            .map(|(outcome, count)| (outcome.clone(), self.fraction(*count)))
+            .collect()
+    }
+
+    /// [`Report::goodness_of_fit`] will run a chi-square test of the counts of a [`Report`]
+    /// against the `expected` probability of every outcome, like the exact distribution of the
+    /// state that was simulated, to tell if the differences are just sampling noise.
+    ///
+    /// Outcomes that are missing from `expected` have a probability of `0.0`.
+    ///
+    /// # Example
+    /// [`Report::goodness_of_fit`] can be used to check that a qubit in superposition is fair:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn is_fair(report: &Report) -> bool {
+    ///     let expected = [(String::from("true"), 0.5), (String::from("false"), 0.5)];
+    ///     report.goodness_of_fit(&expected).is_consistent(0.05)
+    /// }
+    /// ```
+    pub fn goodness_of_fit(&self, expected: &[(String, f64)]) -> GoodnessOfFit {
+        let unexpected = self
+            .counts
+            .iter()
+            .filter(|(outcome, _)| expected.iter().all(|(expected, _)| expected != outcome))
+            .map(|(_, count)| (*count, 0.0));
+        let (observed, probabilities): (Vec<u64>, Vec<f64>) = expected
+            .iter()
+            .map(|(outcome, probability)| (self.count(outcome), *probability))
+            .chain(unexpected)
+            .unzip();
+        GoodnessOfFit::chi_square(&observed, &probabilities)
+    }
+
+    /// [`Report::to_html`] will get a [`Report`] as a self-contained HTML snippet, with a table of
+    /// counts and an inline SVG histogram, for rich output in notebooks.
+    ///
+    /// # Example
+    /// [`Report::to_html`] can be used to show a [`Report`] in an
+    /// [evcxr](https://github.com/evcxr/evcxr) Jupyter notebook:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn show_report(report: &Report) {
+    ///     let html = report.to_html();
+    ///     println!("EVCXR_BEGIN_CONTENT text/html\n{html}\nEVCXR_END_CONTENT");
+    /// }
+    /// ```
+    pub fn to_html(&self) -> String {
+        let rows: String = self
+            .counts
+            .iter()
+            .map(|(outcome, count)| {
+                format!(
+                    "<tr><td>{}</td><td>{count}</td><td>{:.2}%</td></tr>",
+                    escape(outcome),
+                    self.fraction(*count) * 100.0
+                )
+            })
+            .collect();
+        let bars: String = self
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(index, (outcome, count))| {
+                let height = self.fraction(*count) * HISTOGRAM_HEIGHT;
+                let x = 20 + index * 80;
+                format!(
+                    "<rect x=\"{x}\" y=\"{:.2}\" width=\"60\" height=\"{height:.2}\" fill=\"#4c72b0\"/>\
+                     <text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                    10.0 + HISTOGRAM_HEIGHT - height,
+                    x + 30,
+                    HISTOGRAM_HEIGHT + 26.0,
+                    escape(outcome)
+                )
+            })
+            .collect();
+
+        format!(
+            "<div class=\"rquant-report\">\
+             <table><caption>Simulation report results for {}</caption>\
+             <tr><th>outcome</th><th>count</th><th>percentage</th></tr>{rows}\
+             <tr><td>total</td><td>{}</td><td></td></tr></table>\
+             <svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" font-family=\"sans-serif\" font-size=\"12\">{bars}</svg>\
+             </div>",
+            escape(&self.subject),
+            self.total,
+            20 + self.counts.len() * 80,
+            HISTOGRAM_HEIGHT + 40.0
+        )
+    }
+
+    /// [`Report::fraction`] will get the fraction of the [`total`](Report::total) that `count` is,
+    /// which is `0.0` without any shots.
+    fn fraction(&self, count: u64) -> f64 {
+        match self.total {
+            0 => 0.0,
+            total => count as f64 / total as f64,
+        }
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`Report`].
+impl fmt::Display for Report {
+    /// Will format the count and percentage of every outcome of a [`Report`] for the console:
+    /// ```text
+    /// Simulation report results for |0⟩
+    ///   true  :  503 (50.30%)
+    ///   false :  497 (49.70%)
+    ///   total : 1000
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`Report`] to the console:
+    /// ```rust
+    /// use rquant::simulation::types::report::Report;
+    ///
+    /// fn print_report(report: &Report) {
+    ///     println!("{report}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .counts
+            .iter()
+            .map(|(outcome, _)| outcome.chars().count())
+            .chain(["total".len()])
+            .max()
+            .unwrap_or(0);
+
+        write!(f, "Simulation report results for {}", self.subject)?;
+        for (outcome, count) in &self.counts {
+            write!(
+                f,
+                "\n  {outcome:<width$} :  {count} ({:.2}%)",
+                self.fraction(*count) * 100.0
+            )?;
+        }
+        write!(f, "\n  {:<width$} : {}", "total", self.total)
+    }
+}
+
+/// [`escape`] will replace the characters of `text` that have a meaning in HTML with entities.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
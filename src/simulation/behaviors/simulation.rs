@@ -1,29 +1,360 @@
+use std::{collections::HashMap, time::Instant};
+
+use rand::Rng;
+
 use crate::{
-    quantum::types::{quantum_gate::QuantumGate, qubit::Qubit, qubit_register::QubitRegister},
-    simulation::types::simulation::Simulation,
+    noise::types::noise_model::NoiseModel,
+    quantum::types::{
+        compact_state_vector::CompactStateVector, decision_diagram::DecisionDiagram,
+        matrix_product_state::MatrixProductState, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate, quantum_operators::QuantumOperator, qubit::Qubit,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+    simulation::{
+        behaviors::simulation_outcome::{
+            basis_values, probabilities, sample_index, sample_shot, IdealSampler,
+        },
+        types::{
+            backend::Backend, backend_kind::BackendKind, simulation::Simulation,
+            simulation_config::SimulationConfig, simulation_outcome::SimulationOutcome,
+        },
+    },
 };
 
 /// Implement the [`Simulation<T>`] trait for [`Qubit`].
 impl Simulation<Qubit> for Qubit {
-    fn simulate_superposition(&self, amount: i32) -> Vec<bool> {
-        (0..amount)
-            .map(|_| self.apply_gate(&QuantumGate::SUPERPOSITION).measure())
-            .collect()
+    fn simulate_superposition(&self, config: &SimulationConfig) -> Vec<bool> {
+        config.run_shots(config.shots, |rng| measure_superposition(self, config, rng))
+    }
+
+    /// Will yield a single measured value for every shot of a [`Qubit`] in superposition.
+    ///
+    /// # Example
+    /// Can be used to find how many shots it takes to observe $|1\rangle$:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn shots_until_one(qubit: &Qubit) -> Option<usize> {
+    ///     qubit
+    ///         .simulate_iter(&SimulationConfig::new(1_000_000))
+    ///         .position(|shot| !shot[0])
+    /// }
+    /// ```
+    fn simulate_iter<'a>(
+        &'a self,
+        config: &'a SimulationConfig,
+    ) -> impl Iterator<Item = Vec<bool>> + 'a {
+        superposition_shots(std::slice::from_ref(self), config)
+    }
+
+    /// Will count the `"0"` and `"1"` outcomes of a [`Qubit`] in superposition.
+    ///
+    /// # Example
+    /// Can be used to get how often a [`Qubit`] was observed as $|1\rangle$:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn count_ones(qubit: &Qubit) -> u64 {
+    ///     qubit
+    ///         .simulate_counts(&SimulationConfig::new(1000))
+    ///         .get("1")
+    ///         .copied()
+    ///         .unwrap_or(0)
+    /// }
+    /// ```
+    fn simulate_counts(&self, config: &SimulationConfig) -> HashMap<String, u64> {
+        count_outcomes(std::slice::from_ref(self), config)
+    }
+
+    /// Will run a one qubit [`QuantumCircuit`] from the state of a [`Qubit`].
+    ///
+    /// # Example
+    /// Can be used to sample a [`Qubit`] after a rotation:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    ///         quantum_operators::QuantumOperator, qubit::Qubit,
+    ///     },
+    ///     simulation::types::{
+    ///         simulation::Simulation, simulation_config::SimulationConfig,
+    ///         simulation_outcome::SimulationOutcome,
+    ///     },
+    /// };
+    ///
+    /// fn sample_rotation(angle: f64) -> SimulationOutcome {
+    ///     let circuit =
+    ///         QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RX(angle)), 0);
+    ///     Qubit::zero().simulate(&circuit, &SimulationConfig::new(1000))
+    /// }
+    /// ```
+    fn simulate(&self, circuit: &QuantumCircuit, config: &SimulationConfig) -> SimulationOutcome {
+        simulate_circuit(std::slice::from_ref(self), circuit, config)
     }
 }
 
 /// Implement the [`Simulation<T>`] trait for [`QubitRegister`].
 impl Simulation<QubitRegister> for QubitRegister {
-    fn simulate_superposition(&self, amount: i32) -> Vec<bool> {
+    fn simulate_superposition(&self, config: &SimulationConfig) -> Vec<bool> {
+        let shots = config.run_shots(config.shots, |rng| {
+            self.qubits
+                .iter()
+                .map(|qubit| measure_superposition(qubit, config, rng))
+                .collect::<Vec<bool>>()
+        });
+
+        // Every qubit keeps it's outcomes together, in the order of the shots.
         (0..self.len())
-            .flat_map(|i| {
-                (0..amount).map(move |_| {
-                    self.get(i)
-                        .expect("Qubit invalid")
-                        .apply_gate(&QuantumGate::SUPERPOSITION)
-                        .measure()
-                })
-            })
+            .flat_map(|target| shots.iter().map(move |shot| shot[target]))
             .collect()
     }
+
+    /// Will yield the measured value of every [`Qubit`] of a [`QubitRegister`] in superposition,
+    /// one shot at a time.
+    ///
+    /// # Example
+    /// Can be used to estimate a probability from millions of shots in constant memory:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit_register::QubitRegister,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn all_zero_frequency(qubit_register: &QubitRegister) -> f64 {
+    ///     let config = SimulationConfig::new(10_000_000);
+    ///     let all_zero = qubit_register
+    ///         .simulate_iter(&config)
+    ///         .filter(|shot| shot.iter().all(|&value| value))
+    ///         .count();
+    ///     all_zero as f64 / config.shots as f64
+    /// }
+    /// ```
+    fn simulate_iter<'a>(
+        &'a self,
+        config: &'a SimulationConfig,
+    ) -> impl Iterator<Item = Vec<bool>> + 'a {
+        superposition_shots(&self.qubits, config)
+    }
+
+    /// Will count the outcome bitstrings of a [`QubitRegister`] in superposition, where the first
+    /// character is the first [`Qubit`].
+    ///
+    /// # Example
+    /// Can be used to get how often every [`Qubit`] of a [`QubitRegister`] was observed as
+    /// $|0\rangle$:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit_register::QubitRegister,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn count_all_zero(qubit_register: &QubitRegister) -> u64 {
+    ///     let all_zero = "0".repeat(qubit_register.len());
+    ///     qubit_register
+    ///         .simulate_counts(&SimulationConfig::new(1000))
+    ///         .get(&all_zero)
+    ///         .copied()
+    ///         .unwrap_or(0)
+    /// }
+    /// ```
+    fn simulate_counts(&self, config: &SimulationConfig) -> HashMap<String, u64> {
+        count_outcomes(&self.qubits, config)
+    }
+
+    /// Will run a [`QuantumCircuit`] from the state of every [`Qubit`] of a [`QubitRegister`].
+    ///
+    /// # Example
+    /// Can be used to sample a [`QubitRegister`] after an arbitrary gate sequence:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    ///         qubit_register::QubitRegister,
+    ///     },
+    ///     simulation::types::{
+    ///         simulation::Simulation, simulation_config::SimulationConfig,
+    ///         simulation_outcome::SimulationOutcome,
+    ///     },
+    /// };
+    ///
+    /// fn sample_flipped_superposition() -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::NOT, 1);
+    ///     QubitRegister::new(2).simulate(&circuit, &SimulationConfig::new(1000))
+    /// }
+    /// ```
+    fn simulate(&self, circuit: &QuantumCircuit, config: &SimulationConfig) -> SimulationOutcome {
+        simulate_circuit(&self.qubits, circuit, config)
+    }
+}
+
+/// [`superposition_shots`] will lazily measure every [`Qubit`] of `qubits` in superposition for
+/// every shot of a `config`.
+fn superposition_shots<'a>(
+    qubits: &'a [Qubit],
+    config: &'a SimulationConfig,
+) -> impl Iterator<Item = Vec<bool>> + 'a {
+    let start = Instant::now();
+    let mut rng = config.rng();
+    (0..config.shots).map_while(move |shot| {
+        if config.is_stopped(start) {
+            return None;
+        }
+        let values = qubits
+            .iter()
+            .map(|qubit| measure_superposition(qubit, config, &mut rng))
+            .collect();
+        config.report_progress(shot + 1, config.shots);
+        Some(values)
+    })
+}
+
+/// [`count_outcomes`] will measure every [`Qubit`] of `qubits` in superposition for every shot of
+/// a `config`, and count each outcome bitstring, in the same way as
+/// [`QubitRegister::measure_all`].
+fn count_outcomes(qubits: &[Qubit], config: &SimulationConfig) -> HashMap<String, u64> {
+    let bitstrings = config.run_shots(config.shots, |rng| {
+        qubits
+            .iter()
+            .map(|qubit| {
+                if measure_superposition(qubit, config, rng) {
+                    '0'
+                } else {
+                    '1'
+                }
+            })
+            .collect::<String>()
+    });
+    bitstrings
+        .into_iter()
+        .fold(HashMap::new(), |mut counts, bitstring| {
+            *counts.entry(bitstring).or_insert(0) += 1;
+            counts
+        })
+}
+
+/// [`measure_superposition`] will put a [`Qubit`] in superposition and measure it, with the
+/// [`NoiseModel`] of a `config`.
+fn measure_superposition(qubit: &Qubit, config: &SimulationConfig, rng: &mut impl Rng) -> bool {
+    if config.noise_model == NoiseModel::new() {
+        return qubit
+            .apply_gate(&QuantumGate::SUPERPOSITION)
+            .measure_with(rng);
+    }
+
+    // Every qubit is independent, so each one is simulated with noise on it's own.
+    let register = QubitRegister {
+        qubits: vec![qubit.clone()],
+    };
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    sample_shot(&register, &circuit, &config.noise_model, None, rng)[0]
+}
+
+/// [`simulate_circuit`] will run a [`QuantumCircuit`] from the state of `qubits` for every shot of
+/// a `config`, and keep the shots that pass it's post-selections.
+///
+/// Without noise the state is the same for every shot, so it is only simulated once.
+fn simulate_circuit(
+    qubits: &[Qubit],
+    circuit: &QuantumCircuit,
+    config: &SimulationConfig,
+) -> SimulationOutcome {
+    assert!(
+        circuit.num_qubits == qubits.len(),
+        "Circuit must operate on every qubit"
+    );
+
+    let register = QubitRegister {
+        qubits: qubits.to_vec(),
+    };
+    let ideal = ideal_sampler(&register, circuit, &config.noise_model, config.backend);
+
+    let shots = config.run_shots(config.shots, |rng| {
+        sample_shot(&register, circuit, &config.noise_model, ideal.as_ref(), rng)
+    });
+
+    SimulationOutcome {
+        truncated: shots.len() < config.shots,
+        ..SimulationOutcome::new(circuit.num_qubits, shots)
+    }
+    .with_post_selections(circuit)
+}
+
+/// [`ideal_sampler`] will run a [`QuantumCircuit`] from the state of a [`QubitRegister`] on a
+/// [`BackendKind`] when a [`NoiseModel`] has no errors, because every shot then has the same state,
+/// and get an [`IdealSampler`] of it's final state, or [`None`] otherwise.
+///
+/// A [`MatrixProductState`] is sampled one qubit at a time, so it is never expanded into all of it's
+/// amplitudes.
+pub(crate) fn ideal_sampler(
+    register: &QubitRegister,
+    circuit: &QuantumCircuit,
+    noise_model: &NoiseModel,
+    backend: BackendKind,
+) -> Option<IdealSampler> {
+    if *noise_model != NoiseModel::new() {
+        assert!(
+            backend == BackendKind::StateVector,
+            "Noise can only be simulated on the state vector backend"
+        );
+        return None;
+    }
+
+    let num_qubits = register.len();
+    let state_vector = match backend {
+        BackendKind::StateVector => {
+            let mut state_vector = StateVector::from_register(register);
+            state_vector.apply_circuit(circuit);
+            state_vector
+        }
+        BackendKind::CompactStateVector => {
+            let mut state =
+                CompactStateVector::from_state_vector(&StateVector::from_register(register));
+            state.apply_circuit(circuit);
+            state.to_state_vector()
+        }
+        BackendKind::MatrixProductState { max_bond_dimension } => {
+            let mut state = MatrixProductState::new(num_qubits, max_bond_dimension);
+            prepare(&mut state, register, circuit);
+            let environments = state.right_environments();
+            return Some(Box::new(move |mut rng| {
+                state.sample_from(&environments, &mut rng)
+            }));
+        }
+        BackendKind::DecisionDiagram => {
+            let mut state = DecisionDiagram::new(num_qubits);
+            prepare(&mut state, register, circuit);
+            state.to_state_vector()
+        }
+    };
+    let probabilities = probabilities(&state_vector);
+    Some(Box::new(move |rng| {
+        basis_values(sample_index(&probabilities, rng.random()), num_qubits)
+    }))
+}
+
+/// [`prepare`] will rotate every [`Qubit`] of a `backend` from $|0\rangle$ to the state of the
+/// same [`Qubit`] of a [`QubitRegister`], up to a global phase, then run a [`QuantumCircuit`] on
+/// it.
+fn prepare(backend: &mut impl Backend, register: &QubitRegister, circuit: &QuantumCircuit) {
+    for (target, qubit) in register.qubits.iter().enumerate() {
+        let (x, y, z) = qubit.bloch_coordinates();
+        let rotations = [
+            QuantumOperator::RY(z.clamp(-1.0, 1.0).acos()),
+            QuantumOperator::RZ(y.atan2(x)),
+        ];
+        for rotation in rotations {
+            backend.apply_gate(&QuantumGate::new(rotation), target);
+        }
+    }
+    for operation in &circuit.operations {
+        backend.apply_operation(operation);
+    }
 }
@@ -0,0 +1,263 @@
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use rand::{rngs::StdRng, SeedableRng};
+
+#[cfg(feature = "parallel")]
+use rand::Rng;
+
+use crate::{
+    noise::types::noise_model::NoiseModel,
+    simulation::types::{
+        backend_kind::BackendKind, cancellation_token::CancellationToken,
+        progress_hook::ProgressHook, simulation_config::SimulationConfig,
+    },
+};
+
+impl SimulationConfig {
+    /// [`SimulationConfig::new`] will create a new [`SimulationConfig`] that runs an amount of
+    /// `shots` without noise on a single thread, with different outcomes on every run.
+    ///
+    /// # Example
+    /// [`SimulationConfig::new`] can be used to simulate a [`Qubit`](crate::quantum::types::qubit::Qubit):
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn simulate_thousand_shots(qubit: &Qubit) -> Vec<bool> {
+    ///     qubit.simulate_superposition(&SimulationConfig::new(1000))
+    /// }
+    /// ```
+    pub fn new(shots: usize) -> Self {
+        SimulationConfig {
+            shots,
+            seed: None,
+            noise_model: NoiseModel::new(),
+            backend: BackendKind::default(),
+            threads: 1,
+            progress: None,
+            cancellation: None,
+            timeout: None,
+        }
+    }
+
+    /// [`SimulationConfig::with_seed`] will seed the random number generator of a
+    /// [`SimulationConfig`], so every run has the same outcomes.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_seed`] can be used to make a simulation reproducible:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_config::SimulationConfig;
+    ///
+    /// fn create_reproducible_config() -> SimulationConfig {
+    ///     SimulationConfig::new(1000).with_seed(42)
+    /// }
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// [`SimulationConfig::with_noise_model`] will run every shot of a [`SimulationConfig`] with
+    /// the errors of a [`NoiseModel`].
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_noise_model`] can be used to simulate noisy hardware:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     simulation::types::simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// fn create_noisy_config() -> SimulationConfig {
+    ///     let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.01));
+    ///     SimulationConfig::new(1000).with_noise_model(noise_model)
+    /// }
+    /// ```
+    pub fn with_noise_model(mut self, noise_model: NoiseModel) -> Self {
+        self.noise_model = noise_model;
+        self
+    }
+
+    /// [`SimulationConfig::with_backend`] will choose the [`BackendKind`] circuits of a
+    /// [`SimulationConfig`] are run on.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_backend`] can be used to simulate many weakly entangled qubits:
+    /// ```rust
+    /// use rquant::simulation::types::{
+    ///     backend_kind::BackendKind, simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// fn create_mps_config() -> SimulationConfig {
+    ///     SimulationConfig::new(1000).with_backend(BackendKind::MatrixProductState {
+    ///         max_bond_dimension: 16,
+    ///     })
+    /// }
+    /// ```
+    pub fn with_backend(mut self, backend: BackendKind) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// [`SimulationConfig::with_threads`] will split the shots of a [`SimulationConfig`] between
+    /// an amount of `threads`.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_threads`] can be used to run a large simulation on four cores:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_config::SimulationConfig;
+    ///
+    /// fn create_parallel_config() -> SimulationConfig {
+    ///     SimulationConfig::new(100_000).with_threads(4)
+    /// }
+    /// ```
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "Invalid thread count");
+        self.threads = threads;
+        self
+    }
+
+    /// [`SimulationConfig::with_progress`] will tell a [`ProgressHook`] how many shots of a
+    /// [`SimulationConfig`] have completed as it runs.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_progress`] can be used to show a progress bar for a long run:
+    /// ```rust
+    /// use rquant::simulation::types::{
+    ///     progress_hook::ProgressHook, simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// fn create_watched_config() -> SimulationConfig {
+    ///     SimulationConfig::new(10_000_000).with_progress(ProgressHook::terminal())
+    /// }
+    /// ```
+    pub fn with_progress(mut self, progress: ProgressHook) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// [`SimulationConfig::with_cancellation`] will stop a run of a [`SimulationConfig`] before
+    /// it's next shot once a [`CancellationToken`] is cancelled.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_cancellation`] can be used to let a Ctrl-C handler stop a long run:
+    /// ```rust
+    /// use rquant::simulation::types::{
+    ///     cancellation_token::CancellationToken, simulation_config::SimulationConfig,
+    /// };
+    ///
+    /// fn create_cancellable_config(token: &CancellationToken) -> SimulationConfig {
+    ///     SimulationConfig::new(1_000_000).with_cancellation(token.clone())
+    /// }
+    /// ```
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// [`SimulationConfig::with_timeout`] will stop starting new shots once a run of a
+    /// [`SimulationConfig`] has taken a `timeout`.
+    ///
+    /// # Example
+    /// [`SimulationConfig::with_timeout`] can be used to give a run a time budget:
+    /// ```rust
+    /// use std::time::Duration;
+    /// use rquant::simulation::types::simulation_config::SimulationConfig;
+    ///
+    /// fn create_budgeted_config() -> SimulationConfig {
+    ///     SimulationConfig::new(1_000_000).with_timeout(Duration::from_secs(5))
+    /// }
+    /// ```
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// [`SimulationConfig::is_stopped`] returns `true` if the [`CancellationToken`] of a
+    /// [`SimulationConfig`] was cancelled, or a run that began at `start` has run out of time.
+    pub(crate) fn is_stopped(&self, start: Instant) -> bool {
+        let cancelled = self
+            .cancellation
+            .as_ref()
+            .is_some_and(|token| token.is_cancelled());
+        let timed_out = self
+            .timeout
+            .is_some_and(|timeout| start.elapsed() >= timeout);
+        cancelled || timed_out
+    }
+
+    /// [`SimulationConfig::report_progress`] will tell the [`ProgressHook`] of a
+    /// [`SimulationConfig`], if any, that `completed` of `total` shots have run.
+    pub(crate) fn report_progress(&self, completed: usize, total: usize) {
+        if let Some(progress) = &self.progress {
+            progress.report(completed, total);
+        }
+    }
+
+    /// [`SimulationConfig::run_shots`] will run a `shot` an `amount` of times and collect the
+    /// outcomes in order, splitting the shots between the [`threads`](SimulationConfig::threads)
+    /// of a [`SimulationConfig`] with the `parallel` feature. Fewer outcomes are returned if the
+    /// run is cancelled or runs out of time.
+    ///
+    /// Every thread has it's own random number generator, seeded from the generator of
+    /// [`SimulationConfig::rng`], so a seeded run with the same amount of threads always has the
+    /// same outcomes.
+    pub(crate) fn run_shots<T: Send>(
+        &self,
+        amount: usize,
+        shot: impl Fn(&mut StdRng) -> T + Sync,
+    ) -> Vec<T> {
+        let start = Instant::now();
+        let mut rng = self.rng();
+        let completed = AtomicUsize::new(0);
+        let shot = |rng: &mut StdRng| {
+            let outcome = shot(rng);
+            self.report_progress(completed.fetch_add(1, Ordering::Relaxed) + 1, amount);
+            outcome
+        };
+
+        #[cfg(feature = "parallel")]
+        if self.threads > 1 && amount > 1 {
+            let chunk = amount.div_ceil(self.threads);
+            let seeds: Vec<u64> = (0..self.threads).map(|_| rng.random()).collect();
+            return std::thread::scope(|scope| {
+                let handles: Vec<_> = seeds
+                    .iter()
+                    .enumerate()
+                    .map(|(thread, &seed)| {
+                        let shots = chunk.min(amount.saturating_sub(thread * chunk));
+                        let shot = &shot;
+                        scope.spawn(move || {
+                            let mut rng = StdRng::seed_from_u64(seed);
+                            (0..shots)
+                                .map_while(|_| (!self.is_stopped(start)).then(|| shot(&mut rng)))
+                                .collect::<Vec<T>>()
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("Simulation thread panicked"))
+                    .collect()
+            });
+        }
+
+        (0..amount)
+            .map_while(|_| (!self.is_stopped(start)).then(|| shot(&mut rng)))
+            .collect()
+    }
+
+    /// [`SimulationConfig::rng`] will get the random number generator of a run, which is seeded
+    /// with the [`seed`](SimulationConfig::seed) if there is one.
+    pub(crate) fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        }
+    }
+}
@@ -0,0 +1,664 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Write},
+};
+
+use rand::{Rng, RngCore};
+
+use crate::{
+    formats::types::{format::Format, format_error::FormatError},
+    noise::types::noise_model::NoiseModel,
+    quantum::types::{
+        qasm_statement::QasmStatement, qasm_stream::QasmStream, quantum_circuit::QuantumCircuit,
+        quantum_gate::QuantumGate, qubit_register::QubitRegister, state_vector::StateVector,
+    },
+    simulation::{
+        behaviors::simulation::ideal_sampler,
+        types::{
+            backend::Backend, backend_kind::BackendKind, csv_writer::CsvWriter,
+            qubit_marginal::QubitMarginal, simulation_outcome::SimulationOutcome,
+        },
+    },
+};
+
+/// The z-score of a two-sided 95% confidence interval.
+const CONFIDENCE_Z: f64 = 1.96;
+
+impl SimulationOutcome {
+    /// [`SimulationOutcome::new`] will create a new [`SimulationOutcome`] from measured `shots` of
+    /// `num_qubits` [`Qubits`](crate::quantum::types::qubit::Qubit).
+    ///
+    /// # Example
+    /// [`SimulationOutcome::new`] can be used to create a [`SimulationOutcome`] from recorded shots:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn create_outcome() -> SimulationOutcome {
+    ///     SimulationOutcome::new(2, vec![vec![true, false], vec![false, false]])
+    /// }
+    /// ```
+    pub fn new(num_qubits: usize, shots: Vec<Vec<bool>>) -> Self {
+        assert!(
+            shots.iter().all(|shot| shot.len() == num_qubits),
+            "Every shot must measure every qubit"
+        );
+
+        SimulationOutcome {
+            num_qubits,
+            total_shots: shots.len(),
+            shots,
+            truncated: false,
+        }
+    }
+
+    /// [`SimulationOutcome::from_circuit`] will run a [`QuantumCircuit`] from $|0\ldots0\rangle$ an
+    /// `amount` of times, measure every [`Qubit`](crate::quantum::types::qubit::Qubit) at the end of
+    /// each shot, and keep the shots that pass the
+    /// [`post_selections`](QuantumCircuit::post_selections) of the [`QuantumCircuit`].
+    ///
+    /// # Example
+    /// [`SimulationOutcome::from_circuit`] can be used to sample a [`QuantumCircuit`]:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_superposition() -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     SimulationOutcome::from_circuit(&circuit, 1000)
+    /// }
+    /// ```
+    pub fn from_circuit(circuit: &QuantumCircuit, amount: usize) -> Self {
+        SimulationOutcome::from_circuit_with(circuit, amount, &mut rand::rng())
+    }
+
+    /// [`SimulationOutcome::from_circuit_with`] will sample a [`QuantumCircuit`] in the same way
+    /// as [`SimulationOutcome::from_circuit`], using a random number generator `rng` for the
+    /// sampling instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::from_circuit_with`] can be used to reproduce shots:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_seeded_superposition(seed: u64) -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    ///     SimulationOutcome::from_circuit_with(&circuit, 1000, &mut StdRng::seed_from_u64(seed))
+    /// }
+    /// ```
+    pub fn from_circuit_with(circuit: &QuantumCircuit, amount: usize, rng: &mut impl Rng) -> Self {
+        SimulationOutcome::from_noisy_circuit_with(circuit, amount, &NoiseModel::new(), rng)
+    }
+
+    /// [`SimulationOutcome::from_noisy_circuit`] will run a [`QuantumCircuit`] from
+    /// $|0\ldots0\rangle$ an `amount` of times with the errors of a [`NoiseModel`], measure every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at the end of each shot, and keep the shots
+    /// that pass the [`post_selections`](QuantumCircuit::post_selections) of the [`QuantumCircuit`].
+    ///
+    /// Each shot applies the noise stochastically, so the [`QuantumCircuit`] is run once per shot.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::from_noisy_circuit`] can be used to sample a [`QuantumCircuit`] on
+    /// noisy hardware:
+    /// ```rust
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_noisy_not() -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+    ///     let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.05));
+    ///     SimulationOutcome::from_noisy_circuit(&circuit, 1000, &noise_model)
+    /// }
+    /// ```
+    pub fn from_noisy_circuit(
+        circuit: &QuantumCircuit,
+        amount: usize,
+        noise_model: &NoiseModel,
+    ) -> Self {
+        SimulationOutcome::from_noisy_circuit_with(circuit, amount, noise_model, &mut rand::rng())
+    }
+
+    /// [`SimulationOutcome::from_noisy_circuit_with`] will sample a [`QuantumCircuit`] in the same
+    /// way as [`SimulationOutcome::from_noisy_circuit`], using a random number generator `rng` for
+    /// the noise, sampling and readout instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::from_noisy_circuit_with`] can be used to reproduce noisy shots:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    ///     quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_seeded_noisy_not(seed: u64) -> SimulationOutcome {
+    ///     let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+    ///     let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.05));
+    ///     SimulationOutcome::from_noisy_circuit_with(
+    ///         &circuit,
+    ///         1000,
+    ///         &noise_model,
+    ///         &mut StdRng::seed_from_u64(seed),
+    ///     )
+    /// }
+    /// ```
+    pub fn from_noisy_circuit_with(
+        circuit: &QuantumCircuit,
+        amount: usize,
+        noise_model: &NoiseModel,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let register = QubitRegister::new(circuit.num_qubits);
+        let ideal = ideal_sampler(&register, circuit, noise_model, BackendKind::StateVector);
+        let shots = (0..amount)
+            .map(|_| sample_shot(&register, circuit, noise_model, ideal.as_ref(), rng))
+            .collect();
+
+        SimulationOutcome::new(circuit.num_qubits, shots).with_post_selections(circuit)
+    }
+
+    /// [`SimulationOutcome::execute_qasm_stream`] will run an OpenQASM 2.0 program from a `reader`
+    /// on a copy of a [`Backend`], applying each gate as soon as it is read, then sample the
+    /// measurements of the final state a `shots` amount of times, or return a [`FormatError`] if
+    /// the program can not be read or run.
+    ///
+    /// Only the current statement is held in memory, so machine-generated programs with millions
+    /// of gates run in constant memory. Measurements must come after the last gate on their qubit,
+    /// and every shot has one value per classical bit, where unmeasured bits are `true`.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::execute_qasm_stream`] can be used to sample a Bell state program:
+    /// ```rust
+    /// use rquant::{
+    ///     formats::types::format_error::FormatError,
+    ///     quantum::types::state_vector::StateVector,
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_bell_program() -> Result<SimulationOutcome, FormatError> {
+    ///     let program = "OPENQASM 2.0;
+    ///         qreg q[2];
+    ///         creg c[2];
+    ///         h q[0];
+    ///         cx q[0], q[1];
+    ///         measure q -> c;";
+    ///     SimulationOutcome::execute_qasm_stream(program.as_bytes(), &StateVector::new(2), 1000)
+    /// }
+    /// ```
+    pub fn execute_qasm_stream<R: BufRead, B: Backend + Clone>(
+        reader: R,
+        backend: &B,
+        shots: usize,
+    ) -> Result<Self, FormatError> {
+        SimulationOutcome::execute_qasm_stream_with(reader, backend, shots, &mut rand::rng())
+    }
+
+    /// [`SimulationOutcome::execute_qasm_stream_with`] will run an OpenQASM 2.0 program in the
+    /// same way as [`SimulationOutcome::execute_qasm_stream`], using a random number generator
+    /// `rng` for the measurements instead of the thread's generator.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::execute_qasm_stream_with`] can be used to reproduce a Bell state
+    /// program:
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use rquant::{
+    ///     formats::types::format_error::FormatError,
+    ///     quantum::types::state_vector::StateVector,
+    ///     simulation::types::simulation_outcome::SimulationOutcome,
+    /// };
+    ///
+    /// fn sample_seeded_bell_program(seed: u64) -> Result<SimulationOutcome, FormatError> {
+    ///     let program = "OPENQASM 2.0;
+    ///         qreg q[2];
+    ///         creg c[2];
+    ///         h q[0];
+    ///         cx q[0], q[1];
+    ///         measure q -> c;";
+    ///     SimulationOutcome::execute_qasm_stream_with(
+    ///         program.as_bytes(),
+    ///         &StateVector::new(2),
+    ///         1000,
+    ///         &mut StdRng::seed_from_u64(seed),
+    ///     )
+    /// }
+    /// ```
+    pub fn execute_qasm_stream_with<R: BufRead, B: Backend + Clone>(
+        reader: R,
+        backend: &B,
+        shots: usize,
+        rng: &mut impl Rng,
+    ) -> Result<Self, FormatError> {
+        let error = |message| FormatError {
+            format: Format::Qasm,
+            message,
+        };
+        let mut state = backend.clone();
+        let mut stream = QasmStream::new(reader);
+        let mut measurements: Vec<(usize, usize)> = vec![];
+
+        while let Some(statement) = stream.try_next().map_err(error)? {
+            let qubits = match statement {
+                QasmStatement::Gate(_, target) => vec![target],
+                QasmStatement::ControlledNot(a, b) | QasmStatement::ControlledPhase(a, b) => {
+                    vec![a, b]
+                }
+                _ => vec![],
+            };
+            if let Some(&(measured, _)) = measurements
+                .iter()
+                .find(|(measured, _)| qubits.contains(measured))
+            {
+                return Err(error(format!(
+                    "line {}: gate on qubit {measured} after it is measured",
+                    stream.line_number
+                )));
+            }
+
+            match statement {
+                QasmStatement::QuantumRegister(_) if stream.num_qubits() > state.num_qubits() => {
+                    return Err(error(format!(
+                        "line {}: program declares more qubits than the backend holds",
+                        stream.line_number
+                    )));
+                }
+                QasmStatement::Gate(operator, target) => {
+                    state.apply_gate(&QuantumGate::new(operator), target)
+                }
+                QasmStatement::ControlledNot(control, target) => state.apply_cnot(control, target),
+                QasmStatement::ControlledPhase(a, b) => state.apply_cz(a, b),
+                QasmStatement::Measure(qubit, clbit) => measurements.push((qubit, clbit)),
+                _ => {}
+            }
+        }
+
+        let num_clbits = stream.num_clbits();
+        let shots = state
+            .sample_shots_with(shots, rng)
+            .into_iter()
+            .map(|measured| {
+                let mut values = vec![true; num_clbits];
+                for &(qubit, clbit) in &measurements {
+                    values[clbit] = measured[qubit];
+                }
+                values
+            })
+            .collect();
+        Ok(SimulationOutcome::new(num_clbits, shots))
+    }
+
+    /// [`SimulationOutcome::post_select`] will get a [`SimulationOutcome`] with only the shots where
+    /// the [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` was measured as `value`.
+    ///
+    /// The [`total_shots`](SimulationOutcome::total_shots) are kept, so the statistics of the
+    /// returned [`SimulationOutcome`] are renormalized over the kept shots, and
+    /// [`SimulationOutcome::acceptance_rate`] reports how many shots passed.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::post_select`] can be used to condition on an ancilla outcome:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn condition_on_ancilla(outcome: &SimulationOutcome) -> SimulationOutcome {
+    ///     outcome.post_select(1, true)
+    /// }
+    /// ```
+    pub fn post_select(&self, target: usize, value: bool) -> Self {
+        assert!(target < self.num_qubits, "Invalid outcome qubit index");
+
+        SimulationOutcome {
+            num_qubits: self.num_qubits,
+            shots: self
+                .shots
+                .iter()
+                .filter(|shot| shot[target] == value)
+                .cloned()
+                .collect(),
+            total_shots: self.total_shots,
+            truncated: self.truncated,
+        }
+    }
+
+    /// [`SimulationOutcome::probability`] will get the fraction of kept shots where the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` was measured as `true`, and
+    /// returns `0.0` if there are no kept shots.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::probability`] can be used to estimate the probability of a qubit:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn first_qubit_probability(outcome: &SimulationOutcome) -> f64 {
+    ///     outcome.probability(0)
+    /// }
+    /// ```
+    pub fn probability(&self, target: usize) -> f64 {
+        assert!(target < self.num_qubits, "Invalid outcome qubit index");
+
+        if self.shots.is_empty() {
+            return 0.0;
+        }
+
+        let true_count = self.shots.iter().filter(|shot| shot[target]).count();
+        true_count as f64 / self.shots.len() as f64
+    }
+
+    /// [`SimulationOutcome::marginal_probabilities`] will get the sampled probability distribution
+    /// over the [`Qubits`](crate::quantum::types::qubit::Qubit) at the indices of `targets`, in the
+    /// same order as [`QubitRegister::marginal_probabilities`](crate::quantum::types::qubit_register::QubitRegister::marginal_probabilities),
+    /// where a `false` measurement is the $|1\rangle$ bit of a basis state.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::marginal_probabilities`] can be used to compare sampled and exact
+    /// distributions:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn first_two_qubits_distribution(outcome: &SimulationOutcome) -> Vec<f64> {
+    ///     outcome.marginal_probabilities(&[0, 1])
+    /// }
+    /// ```
+    pub fn marginal_probabilities(&self, targets: &[usize]) -> Vec<f64> {
+        assert!(
+            targets.iter().all(|&target| target < self.num_qubits),
+            "Invalid outcome qubit index"
+        );
+
+        let mut counts = vec![0; 1 << targets.len()];
+        for shot in &self.shots {
+            let index = targets.iter().fold(0, |index, &target| {
+                (index << 1) | usize::from(!shot[target])
+            });
+            counts[index] += 1;
+        }
+        counts
+            .iter()
+            .map(|&count| count as f64 / self.shots.len().max(1) as f64)
+            .collect()
+    }
+
+    /// [`SimulationOutcome::per_qubit_marginals`] will get the observed frequency of $|0\rangle$
+    /// and $|1\rangle$ for every [`Qubit`](crate::quantum::types::qubit::Qubit) on it's own, with
+    /// a 95% confidence interval, which is still meaningful for large registers where almost every
+    /// bitstring is seen once.
+    ///
+    /// Without any kept shots, every frequency is `0.0` and every interval is `(0.0, 1.0)`.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::per_qubit_marginals`] can be used to find qubits that are stuck:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn stuck_qubits(outcome: &SimulationOutcome) -> Vec<usize> {
+    ///     outcome
+    ///         .per_qubit_marginals()
+    ///         .iter()
+    ///         .enumerate()
+    ///         .filter(|(_, marginal)| marginal.confidence_interval.0 > 0.99)
+    ///         .map(|(target, _)| target)
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn per_qubit_marginals(&self) -> Vec<QubitMarginal> {
+        let shots = self.shots.len() as f64;
+        (0..self.num_qubits)
+            .map(|target| {
+                if self.shots.is_empty() {
+                    return QubitMarginal {
+                        zero: 0.0,
+                        one: 0.0,
+                        confidence_interval: (0.0, 1.0),
+                    };
+                }
+                let zero = self.probability(target);
+
+                // The Wilson score interval stays inside [0, 1], even when a qubit never flips.
+                let z_squared = CONFIDENCE_Z * CONFIDENCE_Z;
+                let center = (zero + z_squared / (2.0 * shots)) / (1.0 + z_squared / shots);
+                let margin = CONFIDENCE_Z / (1.0 + z_squared / shots)
+                    * (zero * (1.0 - zero) / shots + z_squared / (4.0 * shots * shots)).sqrt();
+                QubitMarginal {
+                    zero,
+                    one: 1.0 - zero,
+                    confidence_interval: ((center - margin).max(0.0), (center + margin).min(1.0)),
+                }
+            })
+            .collect()
+    }
+
+    /// [`SimulationOutcome::counts`] will count how many kept shots had each outcome bitstring,
+    /// where a "truthy" measurement is a `0`, in the same way as
+    /// [`Simulation<T>::simulate_counts`](crate::simulation::types::simulation::Simulation::simulate_counts).
+    ///
+    /// # Example
+    /// [`SimulationOutcome::counts`] can be used to report the shots of a circuit:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit_register::QubitRegister,
+    ///     simulation::types::{
+    ///         simulation_outcome::SimulationOutcome, simulation_report::SimulationReport,
+    ///     },
+    /// };
+    ///
+    /// fn report_outcome(outcome: &SimulationOutcome) {
+    ///     outcome.counts().report(QubitRegister::new(outcome.num_qubits));
+    /// }
+    /// ```
+    pub fn counts(&self) -> HashMap<String, u64> {
+        self.shots.iter().fold(HashMap::new(), |mut counts, shot| {
+            let bitstring = shot
+                .iter()
+                .map(|&value| if value { '0' } else { '1' })
+                .collect();
+            *counts.entry(bitstring).or_insert(0) += 1;
+            counts
+        })
+    }
+
+    /// [`SimulationOutcome::correlation`] will get the sampled correlation $\langle Z_i Z_j\rangle$
+    /// between the [`Qubits`](crate::quantum::types::qubit::Qubit) at indices `i` and `j`, which is
+    /// the fraction of kept shots where they agree minus the fraction where they disagree.
+    ///
+    /// A Bell state $\frac{|00\rangle + |11\rangle}{\sqrt{2}}$ has a correlation of `1.0`, and
+    /// independent qubits in superposition have a correlation near `0.0`.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::correlation`] can be used to verify a Bell state:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn is_bell_correlated(outcome: &SimulationOutcome) -> bool {
+    ///     outcome.correlation(0, 1) > 0.99
+    /// }
+    /// ```
+    pub fn correlation(&self, i: usize, j: usize) -> f64 {
+        let probabilities = self.marginal_probabilities(&[i, j]);
+        probabilities[0b00] + probabilities[0b11] - probabilities[0b01] - probabilities[0b10]
+    }
+
+    /// [`SimulationOutcome::mutual_information`] will get the sampled mutual information, in bits,
+    /// between the [`Qubits`](crate::quantum::types::qubit::Qubit) at indices `i` and `j`:
+    /// $$ I(i;j) = \sum_{a,b} p(a,b) \log_2 \frac{p(a,b)}{p(a)p(b)} $$
+    ///
+    /// # Example
+    /// [`SimulationOutcome::mutual_information`] can be used to find unintended couplings:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn are_coupled(outcome: &SimulationOutcome, i: usize, j: usize) -> bool {
+    ///     outcome.mutual_information(i, j) > 0.1
+    /// }
+    /// ```
+    pub fn mutual_information(&self, i: usize, j: usize) -> f64 {
+        let joint = self.marginal_probabilities(&[i, j]);
+        let first = [joint[0b00] + joint[0b01], joint[0b10] + joint[0b11]];
+        let second = [joint[0b00] + joint[0b10], joint[0b01] + joint[0b11]];
+        (0..4)
+            .filter(|&index| joint[index] > 0.0)
+            .map(|index| {
+                let independent = first[index >> 1] * second[index & 1];
+                joint[index] * (joint[index] / independent).log2()
+            })
+            .sum()
+    }
+
+    /// [`SimulationOutcome::acceptance_rate`] will get the fraction of
+    /// [`total_shots`](SimulationOutcome::total_shots) that were kept after post-selection, and
+    /// returns `0.0` if no shots were simulated.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::acceptance_rate`] can be used to get the success rate of a probabilistic
+    /// protocol:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn success_rate(outcome: &SimulationOutcome) -> f64 {
+    ///     outcome.post_select(1, true).acceptance_rate()
+    /// }
+    /// ```
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.total_shots == 0 {
+            return 0.0;
+        }
+        self.shots.len() as f64 / self.total_shots as f64
+    }
+
+    /// [`SimulationOutcome::write_csv`] will write every kept shot of a [`SimulationOutcome`] to a
+    /// `writer` as CSV, in the same way as a [`CsvWriter`].
+    ///
+    /// # Example
+    /// [`SimulationOutcome::write_csv`] can be used to save shots for pandas or Excel:
+    /// ```rust
+    /// use std::{fs::File, io};
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn save_shots(outcome: &SimulationOutcome, file: File) -> io::Result<()> {
+    ///     outcome.write_csv(io::BufWriter::new(file))
+    /// }
+    /// ```
+    pub fn write_csv(&self, writer: impl Write) -> io::Result<()> {
+        let mut csv_writer = CsvWriter::new(writer);
+        for (shot, values) in self.shots.iter().enumerate() {
+            csv_writer.write_shot(shot, values)?;
+        }
+        csv_writer.finish().map(|_| ())
+    }
+
+    /// [`SimulationOutcome::len`] returns the amount of kept shots in the [`SimulationOutcome`].
+    ///
+    /// # Example
+    /// [`SimulationOutcome::len`] can be used to count the kept shots:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn count_shots(outcome: &SimulationOutcome) -> usize {
+    ///     outcome.len()
+    /// }
+    /// ```
+    pub fn len(&self) -> usize {
+        self.shots.len()
+    }
+
+    /// [`SimulationOutcome::is_empty`] returns `true` if the [`SimulationOutcome`] has no kept
+    /// shots, and `false` otherwise.
+    ///
+    /// # Example
+    /// [`SimulationOutcome::is_empty`] can be used to check if post-selection removed every shot:
+    /// ```rust
+    /// use rquant::simulation::types::simulation_outcome::SimulationOutcome;
+    ///
+    /// fn has_shots(outcome: &SimulationOutcome) -> bool {
+    ///     !outcome.is_empty()
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.shots.is_empty()
+    }
+
+    /// [`SimulationOutcome::with_post_selections`] will keep the shots that pass every
+    /// [`post_selection`](QuantumCircuit::post_selections) of a [`QuantumCircuit`].
+    pub(crate) fn with_post_selections(self, circuit: &QuantumCircuit) -> Self {
+        circuit
+            .post_selections
+            .iter()
+            .fold(self, |outcome, &(target, value)| {
+                outcome.post_select(target, value)
+            })
+    }
+}
+
+/// [`sample_index`] will pick an index from a distribution of `probabilities`, using a `random`
+/// value between 0 and 1.
+pub(crate) fn sample_index(probabilities: &[f64], random: f64) -> usize {
+    let mut cumulative = 0.0;
+    for (index, probability) in probabilities.iter().enumerate() {
+        cumulative += probability;
+        if random < cumulative {
+            return index;
+        }
+    }
+    // Rounding can leave the cumulative probability just under 1.
+    probabilities
+        .iter()
+        .rposition(|&probability| probability > 0.0)
+        .unwrap_or(0)
+}
+
+/// [`probabilities`] will get the probability of every basis state of a [`StateVector`].
+pub(crate) fn probabilities(state_vector: &StateVector) -> Vec<f64> {
+    state_vector
+        .amplitudes
+        .iter()
+        .map(|amplitude| amplitude.norm_sqr())
+        .collect()
+}
+
+/// [`IdealSampler`] will sample the measured value of every
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) from the final state of a run without noise, which
+/// is the same for every shot.
+pub(crate) type IdealSampler = Box<dyn Fn(&mut dyn RngCore) -> Vec<bool> + Sync>;
+
+/// [`basis_values`] will get the "truthy" state of every one of `num_qubits`
+/// [`Qubits`](crate::quantum::types::qubit::Qubit) in the basis state at `index`.
+pub(crate) fn basis_values(index: usize, num_qubits: usize) -> Vec<bool> {
+    (0..num_qubits)
+        .map(|target| index & (1 << (num_qubits - 1 - target)) == 0)
+        .collect()
+}
+
+/// [`sample_shot`] will run one shot of a [`QuantumCircuit`] from the state of a
+/// [`QubitRegister`], and measure every [`Qubit`](crate::quantum::types::qubit::Qubit) at the end
+/// of it through the readout errors of a [`NoiseModel`].
+///
+/// The `ideal` [`IdealSampler`] is used when the final state is known, otherwise the
+/// [`QuantumCircuit`] is run with the errors of the [`NoiseModel`] for this shot.
+pub(crate) fn sample_shot(
+    register: &QubitRegister,
+    circuit: &QuantumCircuit,
+    noise_model: &NoiseModel,
+    ideal: Option<&IdealSampler>,
+    rng: &mut impl Rng,
+) -> Vec<bool> {
+    let measured = match ideal {
+        Some(ideal) => ideal(rng),
+        None => {
+            let mut state_vector = StateVector::from_register(register);
+            noise_model.run_with(circuit, &mut state_vector, rng);
+            let index = sample_index(&probabilities(&state_vector), rng.random());
+            basis_values(index, circuit.num_qubits)
+        }
+    };
+    measured
+        .into_iter()
+        .map(|value| noise_model.read_with(value, rng))
+        .collect()
+}
@@ -1,41 +1,92 @@
+use std::collections::HashMap;
+
 use crate::{
-    log_info,
     quantum::types::{qubit::Qubit, qubit_register::QubitRegister},
-    simulation::types::simulation_report::SimulationReport,
+    simulation::types::{report::Report, simulation_report::SimulationReport},
 };
 
 /// Implement the [`SimulationReport<Qubit>`] trait for [`Vec<T>`] of [`bool`].
 impl SimulationReport<Qubit> for Vec<bool> {
-    fn report(&self, report_for: Qubit) {
-        let total = self.len() as f64;
-        let true_count = self.iter().filter(|&p| *p).count() as f64;
-        let false_count = self.iter().filter(|&p| !*p).count() as f64;
-        log_info!(
-            "Simulation report results for {}\n  true  :  {} ({:.2}%)\n  false :  {} ({:.2}%)\n  total : {}",
-            report_for,
-            true_count as u32,
-            (true_count / total) * 100.0,
-            false_count as u32,
-            (false_count / total) * 100.0,
-            self.len()
-        );
+    /// Will get the `true` and `false` counts of the results.
+    ///
+    /// # Example
+    /// Can be used to check the results of a [`Qubit`] simulation:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::{
+    ///         simulation::Simulation, simulation_config::SimulationConfig,
+    ///         simulation_report::SimulationReport,
+    ///     },
+    /// };
+    ///
+    /// fn true_frequency(qubit: Qubit) -> f64 {
+    ///     qubit
+    ///         .simulate_superposition(&SimulationConfig::new(1000))
+    ///         .generate_report(qubit)
+    ///         .frequency("true")
+    /// }
+    /// ```
+    fn generate_report(&self, report_for: Qubit) -> Report {
+        Report::new(&report_for.to_string(), bool_counts(self))
     }
 }
 
 /// Implement the [`SimulationReport<QubitRegister>`] trait for [`Vec<T>`] of [`bool`].
 impl SimulationReport<QubitRegister> for Vec<bool> {
-    fn report(&self, report_for: QubitRegister) {
-        let total = self.len() as f64;
-        let true_count = self.iter().filter(|&p| *p).count() as f64;
-        let false_count = self.iter().filter(|&p| !*p).count() as f64;
-        log_info!(
-            "Simulation report results for {}\n  true  :  {} ({:.2}%)\n  false :  {} ({:.2}%)\n  total : {}",
-            report_for,
-            true_count as u32,
-            (true_count / total) * 100.0,
-            false_count as u32,
-            (false_count / total) * 100.0,
-            self.len()
-        );
+    fn generate_report(&self, report_for: QubitRegister) -> Report {
+        Report::new(&report_for.to_string(), bool_counts(self))
+    }
+}
+
+/// Implement the [`SimulationReport<Qubit>`] trait for a [`HashMap`] of outcome counts.
+impl SimulationReport<Qubit> for HashMap<String, u64> {
+    fn generate_report(&self, report_for: Qubit) -> Report {
+        Report::new(&report_for.to_string(), sorted_counts(self))
     }
 }
+
+/// Implement the [`SimulationReport<QubitRegister>`] trait for a [`HashMap`] of outcome counts.
+impl SimulationReport<QubitRegister> for HashMap<String, u64> {
+    /// Will get the count of every outcome bitstring, in order.
+    ///
+    /// # Example
+    /// Can be used to report the joint outcomes of a [`QubitRegister`] simulation:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit_register::QubitRegister,
+    ///     simulation::types::{
+    ///         simulation::Simulation, simulation_config::SimulationConfig,
+    ///         simulation_report::SimulationReport,
+    ///     },
+    /// };
+    ///
+    /// fn report_joint_outcomes(qubit_register: QubitRegister) {
+    ///     qubit_register
+    ///         .simulate_counts(&SimulationConfig::new(1000))
+    ///         .report(qubit_register);
+    /// }
+    /// ```
+    fn generate_report(&self, report_for: QubitRegister) -> Report {
+        Report::new(&report_for.to_string(), sorted_counts(self))
+    }
+}
+
+/// [`bool_counts`] will count the `true` and `false` values of `results`.
+fn bool_counts(results: &[bool]) -> Vec<(String, u64)> {
+    let true_count = results.iter().filter(|&result| *result).count() as u64;
+    vec![
+        (String::from("true"), true_count),
+        (String::from("false"), results.len() as u64 - true_count),
+    ]
+}
+
+/// [`sorted_counts`] will get the outcomes of `counts` ordered by their bitstring.
+fn sorted_counts(counts: &HashMap<String, u64>) -> Vec<(String, u64)> {
+    let mut outcomes: Vec<(String, u64)> = counts
+        .iter()
+        .map(|(outcome, &count)| (outcome.clone(), count))
+        .collect();
+    outcomes.sort();
+    outcomes
+}
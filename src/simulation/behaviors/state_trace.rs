@@ -0,0 +1,173 @@
+use std::fmt;
+
+use crate::{
+    quantum::types::{
+        display_precision::DisplayPrecision, quantum_circuit::QuantumCircuit,
+        state_vector::StateVector,
+    },
+    simulation::types::{
+        amplitude_change::AmplitudeChange, state_trace::StateTrace, trace_step::TraceStep,
+    },
+};
+
+impl StateTrace {
+    /// [`StateTrace::TOLERANCE`] is the smallest change of an amplitude that a [`StateTrace`]
+    /// records, so rounding errors are not reported as changes.
+    pub const TOLERANCE: f64 = 1e-12;
+
+    /// [`StateTrace::record`] will run every operation of a [`QuantumCircuit`] on a copy of a
+    /// [`StateVector`], and record which amplitudes each operation changed.
+    ///
+    /// # Example
+    /// [`StateTrace::record`] can be used to print how a circuit changes a state, one gate at a
+    /// time:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    ///     },
+    ///     simulation::types::state_trace::StateTrace,
+    /// };
+    ///
+    /// fn print_trace() {
+    ///     let circuit = QuantumCircuit::new(2)
+    ///         .add_gate(&QuantumGate::SUPERPOSITION, 0)
+    ///         .add_gate(&QuantumGate::NOT, 1);
+    ///     println!("{}", StateTrace::record(&circuit, &StateVector::new(2)));
+    /// }
+    /// ```
+    pub fn record(circuit: &QuantumCircuit, state_vector: &StateVector) -> Self {
+        assert!(
+            circuit.num_qubits == state_vector.num_qubits,
+            "Circuit must operate on every state vector qubit"
+        );
+
+        // Build the traced state directly, so an entangled state is not flagged as a clone.
+        let mut state_vector = StateVector {
+            num_qubits: state_vector.num_qubits,
+            amplitudes: state_vector.amplitudes.clone(),
+        };
+        let steps = circuit
+            .operations
+            .iter()
+            .map(|operation| {
+                let before = state_vector.amplitudes.clone();
+                state_vector.apply_operation(operation);
+                let changes = before
+                    .iter()
+                    .zip(&state_vector.amplitudes)
+                    .enumerate()
+                    .filter(|(_, (before, after))| (*after - *before).norm() > Self::TOLERANCE)
+                    .map(|(index, (before, after))| AmplitudeChange {
+                        index,
+                        before: *before,
+                        after: *after,
+                    })
+                    .collect();
+                TraceStep {
+                    operation: operation.clone(),
+                    changes,
+                }
+            })
+            .collect();
+
+        StateTrace {
+            num_qubits: circuit.num_qubits,
+            steps,
+        }
+    }
+
+    /// [`StateTrace::largest_step`] will get the index of the [`TraceStep`] that moved the state the
+    /// furthest, by the distance between the amplitudes before and after it, or [`None`] if no step
+    /// changed the state.
+    ///
+    /// # Example
+    /// [`StateTrace::largest_step`] can be used to find the gate that changed a state the most:
+    /// ```rust
+    /// use rquant::simulation::types::state_trace::StateTrace;
+    ///
+    /// fn print_largest_step(trace: &StateTrace) {
+    ///     if let Some(step) = trace.largest_step() {
+    ///         println!("{:?}", trace.steps[step].operation);
+    ///     }
+    /// }
+    /// ```
+    pub fn largest_step(&self) -> Option<usize> {
+        self.steps
+            .iter()
+            .enumerate()
+            .filter(|(_, step)| !step.changes.is_empty())
+            .map(|(index, step)| {
+                let distance: f64 = step
+                    .changes
+                    .iter()
+                    .map(|change| (change.after - change.before).norm_sqr())
+                    .sum();
+                (index, distance)
+            })
+            .fold(
+                None,
+                |largest: Option<(usize, f64)>, (index, distance)| match largest {
+                    Some((_, most)) if most >= distance => largest,
+                    _ => Some((index, distance)),
+                },
+            )
+            .map(|(index, _)| index)
+    }
+}
+
+/// Implement the [`fmt::Display`] trait for [`StateTrace`].
+impl fmt::Display for StateTrace {
+    /// Will return every step of a [`StateTrace`] with it's operation, followed by each changed
+    /// amplitude before and after the step, and how far it moved:
+    /// ```text
+    /// step 0: SUPERPOSITION on qubit 0
+    ///   |00〉 1 -> 0.7071067811865475 (0.2929)
+    ///   |10〉 0 -> 0.7071067811865475 (0.7071)
+    /// step 1: NOT on qubit 1
+    ///   |00〉 0.7071067811865475 -> 0 (0.7071)
+    ///   |01〉 0 -> 0.7071067811865475 (0.7071)
+    ///   |10〉 0.7071067811865475 -> 0 (0.7071)
+    ///   |11〉 0 -> 0.7071067811865475 (0.7071)
+    /// ```
+    ///
+    /// # Example
+    /// Can be used to print a [`StateTrace`] to the console:
+    /// ```rust
+    /// use rquant::simulation::types::state_trace::StateTrace;
+    ///
+    /// fn print_trace(trace: StateTrace) {
+    ///     println!("{trace}");
+    /// }
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, step) in self.steps.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "step {index}: {:?} on qubit {}",
+                step.operation.operator, step.operation.target
+            )?;
+            if !step.operation.controls.is_empty() {
+                write!(f, " controlled by {:?}", step.operation.controls)?;
+            }
+            if step.changes.is_empty() {
+                write!(f, " (no change)")?;
+            }
+            for change in &step.changes {
+                write!(
+                    f,
+                    "\n  |{:0width$b}〉 {} -> {} ({:.4})",
+                    change.index,
+                    DisplayPrecision::format(change.before),
+                    DisplayPrecision::format(change.after),
+                    (change.after - change.before).norm(),
+                    width = self.num_qubits
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    quantum::types::{quantum_circuit::QuantumCircuit, qubit_register::QubitRegister},
+    simulation::types::{
+        report::Report, simulation::Simulation, simulation_config::SimulationConfig,
+        simulation_report::SimulationReport, sweep::Sweep,
+    },
+};
+
+impl Sweep {
+    /// [`Sweep::run`] will simulate a parameterized [`QuantumCircuit`] from $|0\ldots0\rangle$ with
+    /// a [`SimulationConfig`] at every combination of the `values` of each of it's
+    /// [`parameters`](QuantumCircuit::parameters), and collect a [`Report`] per point.
+    ///
+    /// # Example
+    /// [`Sweep::run`] can be used to get the Rabi curve of a qubit:
+    /// ```rust
+    /// use std::f64::consts::TAU;
+    /// use rquant::{
+    ///     quantum::types::{
+    ///         quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    ///         quantum_operators::QuantumOperator,
+    ///     },
+    ///     simulation::types::{simulation_config::SimulationConfig, sweep::Sweep},
+    /// };
+    ///
+    /// fn rabi_curve() -> Sweep {
+    ///     let circuit =
+    ///         QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RX(0.0)), 0);
+    ///     let angles = Sweep::linspace(0.0, TAU, 33);
+    ///     Sweep::run(&circuit, &[angles], &SimulationConfig::new(1000))
+    /// }
+    /// ```
+    pub fn run(circuit: &QuantumCircuit, values: &[Vec<f64>], config: &SimulationConfig) -> Self {
+        assert!(
+            circuit.parameters().len() == values.len(),
+            "Circuit must have a parameter for every range"
+        );
+
+        let points = values.iter().fold(vec![vec![]], |points, parameter| {
+            parameter
+                .iter()
+                .flat_map(|&value| {
+                    points.iter().map(move |point: &Vec<f64>| {
+                        let mut point = point.clone();
+                        point.push(value);
+                        point
+                    })
+                })
+                .collect()
+        });
+        let register = QubitRegister::new(circuit.num_qubits);
+        let reports = points
+            .iter()
+            .map(|point| {
+                let outcome = register.simulate(&circuit.with_parameters(point), config);
+                let parameters: Vec<String> =
+                    point.iter().map(|value| format!("{value:.4}")).collect();
+                Report {
+                    subject: format!("parameters ({})", parameters.join(", ")),
+                    ..outcome.counts().generate_report(register.clone())
+                }
+            })
+            .collect();
+
+        Sweep { points, reports }
+    }
+
+    /// [`Sweep::linspace`] will get an amount of `points` evenly spaced from `start` to `end`,
+    /// including both ends.
+    ///
+    /// # Example
+    /// [`Sweep::linspace`] can be used to sweep an angle around the Bloch sphere:
+    /// ```rust
+    /// use std::f64::consts::TAU;
+    /// use rquant::simulation::types::sweep::Sweep;
+    ///
+    /// fn full_turn() -> Vec<f64> {
+    ///     Sweep::linspace(0.0, TAU, 65)
+    /// }
+    /// ```
+    pub fn linspace(start: f64, end: f64, points: usize) -> Vec<f64> {
+        match points {
+            0 => vec![],
+            1 => vec![start],
+            _ => (0..points)
+                .map(|point| start + (end - start) * point as f64 / (points - 1) as f64)
+                .collect(),
+        }
+    }
+
+    /// [`Sweep::frequencies`] will get the frequency of an `outcome` at every point of a
+    /// [`Sweep`], which is the curve of that outcome over the parameters.
+    ///
+    /// # Example
+    /// [`Sweep::frequencies`] can be used to find the angle that flips a qubit:
+    /// ```rust
+    /// use rquant::simulation::types::sweep::Sweep;
+    ///
+    /// fn flip_angle(sweep: &Sweep) -> Option<f64> {
+    ///     sweep
+    ///         .frequencies("1")
+    ///         .into_iter()
+    ///         .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    ///         .map(|(point, _)| point[0])
+    /// }
+    /// ```
+    pub fn frequencies(&self, outcome: &str) -> Vec<(Vec<f64>, f64)> {
+        self.points
+            .iter()
+            .zip(&self.reports)
+            .map(|(point, report)| (point.clone(), report.frequency(outcome)))
+            .collect()
+    }
+}
@@ -0,0 +1,17 @@
+use num_complex::Complex;
+
+/// [`AmplitudeChange`] is how one amplitude of a
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector) changed during a single
+/// [`TraceStep`](crate::simulation::types::trace_step::TraceStep).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AmplitudeChange {
+    /// The index of the basis state, where the first
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) is the most significant bit.
+    pub index: usize,
+
+    /// The amplitude of the basis state before the step.
+    pub before: Complex<f64>,
+
+    /// The amplitude of the basis state after the step.
+    pub after: Complex<f64>,
+}
@@ -0,0 +1,71 @@
+use rand::Rng;
+
+use crate::quantum::types::{
+    quantum_gate::QuantumGate, quantum_operation::QuantumOperation,
+    quantum_operators::QuantumOperator,
+};
+
+/// [`Backend`] is a [`trait`] that will allow any state representation to run gates and
+/// measurements, so a simulation can pick between a dense
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector) and the more compact
+/// representations.
+pub trait Backend {
+    /// [`Backend::num_qubits`] will get the amount of [`Qubits`](crate::quantum::types::qubit::Qubit)
+    /// the [`Backend`] holds.
+    fn num_qubits(&self) -> usize;
+
+    /// [`Backend::apply_gate`] will apply a [`QuantumGate`] to the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target`.
+    fn apply_gate(&mut self, gate: &QuantumGate, target: usize);
+
+    /// [`Backend::apply_cnot`] will apply a controlled NOT gate to the `target`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), using the `control`
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit).
+    fn apply_cnot(&mut self, control: usize, target: usize);
+
+    /// [`Backend::apply_cz`] will apply a controlled Z gate to the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) `a` and `b`.
+    fn apply_cz(&mut self, a: usize, b: usize);
+
+    /// [`Backend::apply_swap`] will exchange the states of the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) `a` and `b`, which is three controlled NOT
+    /// gates unless the [`Backend`] has a faster way.
+    fn apply_swap(&mut self, a: usize, b: usize) {
+        self.apply_cnot(a, b);
+        self.apply_cnot(b, a);
+        self.apply_cnot(a, b);
+    }
+
+    /// [`Backend::apply_operation`] will apply a [`QuantumOperation`], which can have one control
+    /// on a NOT or PHASE gate unless the [`Backend`] can apply any controlled gate.
+    fn apply_operation(&mut self, operation: &QuantumOperation) {
+        match (operation.controls.as_slice(), &operation.operator) {
+            ([], operator) => {
+                self.apply_gate(&QuantumGate::new(operator.clone()), operation.target)
+            }
+            (&[control], QuantumOperator::NOT) => self.apply_cnot(control, operation.target),
+            (&[control], QuantumOperator::PHASE) => self.apply_cz(control, operation.target),
+            _ => panic!("Backend can only apply a controlled NOT or PHASE gate"),
+        }
+    }
+
+    /// [`Backend::measure`] will measure the [`Qubit`](crate::quantum::types::qubit::Qubit) at
+    /// index `target`, collapse the [`Backend`] to the observed state, and return a [`bool`] for
+    /// it's "truthy" state, in the same way as
+    /// [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure).
+    fn measure(&mut self, target: usize) -> bool {
+        self.measure_with(target, &mut rand::rng())
+    }
+
+    /// [`Backend::measure_with`] will measure the
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at index `target` in the same way as
+    /// [`Backend::measure`], using a random number generator `rng` instead of the thread's
+    /// generator.
+    fn measure_with(&mut self, target: usize, rng: &mut impl Rng) -> bool;
+
+    /// [`Backend::sample_shots_with`] will sample a measurement of every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) an amount of `shots` times without collapsing
+    /// the [`Backend`], using a random number generator `rng`, and return a [`bool`] for the
+    /// "truthy" state of each one in every shot.
+    fn sample_shots_with(&self, shots: usize, rng: &mut impl Rng) -> Vec<Vec<bool>>;
+}
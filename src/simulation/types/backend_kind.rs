@@ -0,0 +1,24 @@
+/// [`BackendKind`] is the choice of [`Backend`](crate::simulation::types::backend::Backend) a
+/// simulation runs it's circuits on.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BackendKind {
+    /// The dense [`StateVector`](crate::quantum::types::state_vector::StateVector), which holds
+    /// every amplitude.
+    #[default]
+    StateVector,
+
+    /// The [`CompactStateVector`](crate::quantum::types::compact_state_vector::CompactStateVector),
+    /// which holds every amplitude at half the memory.
+    CompactStateVector,
+
+    /// The [`MatrixProductState`](crate::quantum::types::matrix_product_state::MatrixProductState),
+    /// for circuits with little entanglement.
+    MatrixProductState {
+        /// The largest bond dimension kept between neighboring qubits.
+        max_bond_dimension: usize,
+    },
+
+    /// The [`DecisionDiagram`](crate::quantum::types::decision_diagram::DecisionDiagram), for
+    /// structured circuits like arithmetic and oracles.
+    DecisionDiagram,
+}
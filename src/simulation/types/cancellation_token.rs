@@ -0,0 +1,11 @@
+use std::sync::{atomic::AtomicBool, Arc};
+
+/// [`CancellationToken`] is a flag that can be shared with a long simulation run and set from
+/// anywhere, like a Ctrl-C handler or another thread, to stop it cleanly between shots.
+///
+/// Every clone of a [`CancellationToken`] shares the same flag.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    /// Whether the run has been asked to stop.
+    pub cancelled: Arc<AtomicBool>,
+}
@@ -0,0 +1,19 @@
+use std::io::{self, Write};
+
+/// [`CsvWriter`] streams the shots of a simulation to any [`Write`] as CSV rows of the shot index
+/// and the outcome bitstring, so large runs can be analyzed without buffering every shot.
+///
+/// It is also an [`Observer`](crate::simulation::types::observer::Observer), so it can be
+/// registered with an [`Executor`](crate::simulation::types::executor::Executor) to write the
+/// accepted shots as they run.
+pub struct CsvWriter<W: Write> {
+    /// The destination of the CSV rows.
+    pub writer: W,
+
+    /// Whether the header row has been written.
+    pub has_header: bool,
+
+    /// The first error of a write that happened while observing, which is returned by
+    /// [`CsvWriter::finish`](crate::simulation::types::csv_writer::CsvWriter::finish).
+    pub error: Option<io::Error>,
+}
@@ -0,0 +1,13 @@
+use crate::simulation::types::{observer::Observer, simulation_config::SimulationConfig};
+
+/// [`Executor`] runs the shots of a [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit),
+/// and reports every shot and measurement to it's registered [`Observers`](Observer).
+pub struct Executor<'a> {
+    /// The [`SimulationConfig`] every run is made with. The [`Observers`](Observer) are told about
+    /// the shots in order, so every shot is run on the calling thread.
+    pub config: SimulationConfig,
+
+    /// The [`Observers`](Observer) that are told about every shot and measurement, in the order
+    /// they were registered.
+    pub observers: Vec<&'a mut dyn Observer>,
+}
@@ -0,0 +1,15 @@
+/// [`GoodnessOfFit`] is the result of a Pearson chi-square test between the observed counts of a
+/// simulation and an expected distribution, which tells if the differences between them are
+/// likely to just be sampling noise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GoodnessOfFit {
+    /// The chi-square statistic $\sum \frac{(O - E)^2}{E}$ of the observed and expected counts.
+    pub statistic: f64,
+
+    /// The amount of outcomes that could be observed, minus one.
+    pub degrees_of_freedom: usize,
+
+    /// The probability of a statistic at least this large if the expected distribution is right,
+    /// where a small p-value means the observed counts do not fit it.
+    pub p_value: f64,
+}
@@ -0,0 +1,19 @@
+/// [`Observer`] is a [`trait`] that will allow any type to watch the shots of an
+/// [`Executor`](crate::simulation::types::executor::Executor) as they run, so statistics can be
+/// computed online without buffering every raw outcome.
+///
+/// Every callback does nothing by default, so an [`Observer`] only needs to implement the events
+/// it cares about.
+pub trait Observer {
+    /// [`Observer::on_shot_start`] will be called before the `shot` at an index is run.
+    fn on_shot_start(&mut self, _shot: usize) {}
+
+    /// [`Observer::on_measurement`] will be called when the [`Qubit`](crate::quantum::types::qubit::Qubit)
+    /// at index `target` is measured during a `shot`, with it's "truthy" `value`, in the same way as
+    /// [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure).
+    fn on_measurement(&mut self, _shot: usize, _target: usize, _value: bool) {}
+
+    /// [`Observer::on_shot_end`] will be called after a `shot` is run, with every measured `value`
+    /// and whether the shot passed the post-selections of the circuit.
+    fn on_shot_end(&mut self, _shot: usize, _values: &[bool], _accepted: bool) {}
+}
@@ -0,0 +1,11 @@
+use std::sync::Arc;
+
+/// [`ProgressHook`] is a callback that is told how many shots of a long simulation have completed,
+/// so a run that takes minutes is not a silent black box.
+///
+/// Every clone of a [`ProgressHook`] shares the same callback.
+#[derive(Clone)]
+pub struct ProgressHook {
+    /// The callback, which is given the amount of completed shots and the total amount of shots.
+    pub callback: Arc<dyn Fn(usize, usize) + Send + Sync>,
+}
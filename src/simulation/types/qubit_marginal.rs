@@ -0,0 +1,17 @@
+/// [`QubitMarginal`] is the observed frequency of each outcome of one
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) over the kept shots of a
+/// [`SimulationOutcome`](crate::simulation::types::simulation_outcome::SimulationOutcome).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct QubitMarginal {
+    /// The fraction of kept shots where the [`Qubit`](crate::quantum::types::qubit::Qubit) was
+    /// measured as $|0\rangle$.
+    pub zero: f64,
+
+    /// The fraction of kept shots where the [`Qubit`](crate::quantum::types::qubit::Qubit) was
+    /// measured as $|1\rangle$.
+    pub one: f64,
+
+    /// The 95% Wilson score confidence interval of `zero`, as the lower and upper bound, where the
+    /// interval of `one` is `(1 - upper, 1 - lower)`.
+    pub confidence_interval: (f64, f64),
+}
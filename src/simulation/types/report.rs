@@ -0,0 +1,14 @@
+/// [`Report`] is the structured results of a simulation, which a
+/// [`SimulationReport<T>`](crate::simulation::types::simulation_report::SimulationReport) logs or
+/// renders, so they can also be used programmatically.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    /// The description of the state the simulation was run for.
+    pub subject: String,
+
+    /// The count of every outcome, in the order they are reported.
+    pub counts: Vec<(String, u64)>,
+
+    /// The amount of shots that were simulated.
+    pub total: u64,
+}
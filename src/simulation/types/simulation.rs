@@ -1,7 +1,82 @@
+use std::collections::HashMap;
+
+use crate::{
+    quantum::types::quantum_circuit::QuantumCircuit,
+    simulation::types::{
+        simulation_config::SimulationConfig, simulation_outcome::SimulationOutcome,
+    },
+};
+
 /// [`Simulation<T>`] is a [`trait`] that will allow any generic type
 /// to simulate behaviors.
 pub trait Simulation<T> {
-    /// [`Simulation<T>::simulate_superposition`] will simulate
-    /// superposition an `amount` of times.
-    fn simulate_superposition(&self, amount: i32) -> Vec<bool>;
+    /// [`Simulation<T>::simulate_superposition`] will simulate superposition for the
+    /// [`shots`](SimulationConfig::shots) of a [`SimulationConfig`].
+    fn simulate_superposition(&self, config: &SimulationConfig) -> Vec<bool>;
+
+    /// [`Simulation<T>::simulate_iter`] will simulate superposition for the
+    /// [`shots`](SimulationConfig::shots) of a [`SimulationConfig`] lazily, yielding the measured
+    /// value of every [`Qubit`](crate::quantum::types::qubit::Qubit) one shot at a time, so long
+    /// runs do not hold every outcome in memory and can stop early.
+    ///
+    /// The shots are always run on the calling thread.
+    fn simulate_iter<'a>(
+        &'a self,
+        config: &'a SimulationConfig,
+    ) -> impl Iterator<Item = Vec<bool>> + 'a;
+
+    /// [`Simulation<T>::simulate_counts`] will simulate superposition for the
+    /// [`shots`](SimulationConfig::shots) of a [`SimulationConfig`], measuring every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) together in each shot, and count how many
+    /// shots had each outcome bitstring, where a `0` is a "truthy" measurement.
+    fn simulate_counts(&self, config: &SimulationConfig) -> HashMap<String, u64>;
+
+    /// [`Simulation<T>::simulate`] will run a [`QuantumCircuit`] from the state of the
+    /// [`Qubits`](crate::quantum::types::qubit::Qubit) for the [`shots`](SimulationConfig::shots)
+    /// of a [`SimulationConfig`] on it's [`backend`](SimulationConfig::backend), measure every
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) at the end of each shot, and keep the shots
+    /// that pass the [`post_selections`](QuantumCircuit::post_selections) of the
+    /// [`QuantumCircuit`].
+    fn simulate(&self, circuit: &QuantumCircuit, config: &SimulationConfig) -> SimulationOutcome;
+
+    /// [`Simulation<T>::auto_shots`] will run a pilot batch of
+    /// [`Simulation<T>::simulate_superposition`], estimate the variance of the outcomes, and return
+    /// the amount of shots needed for the fraction of `true` outcomes to reach a
+    /// `target_std_error`.
+    ///
+    /// The standard error of $n$ shots with a probability $p$ is $\sqrt{p(1-p)/n}$, so the amount
+    /// of shots is solved for as:
+    /// $$ n = \left\lceil \frac{p(1-p)}{\sigma^2} \right\rceil $$
+    ///
+    /// # Example
+    /// [`Simulation<T>::auto_shots`] can be used to pick the amount of shots for a simulation:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+    /// };
+    ///
+    /// fn simulate_precisely(qubit: Qubit) -> Vec<bool> {
+    ///     let shots = qubit.auto_shots(0.01);
+    ///     qubit.simulate_superposition(&SimulationConfig::new(shots))
+    /// }
+    /// ```
+    fn auto_shots(&self, target_std_error: f64) -> usize {
+        // The pilot batch only needs to be large enough to give a rough estimate of the variance.
+        const PILOT_SHOTS: usize = 100;
+
+        assert!(
+            target_std_error > 0.0,
+            "Target standard error must be positive"
+        );
+
+        let pilot = self.simulate_superposition(&SimulationConfig::new(PILOT_SHOTS));
+        let true_count = pilot.iter().filter(|&outcome| *outcome).count() as f64;
+
+        // Smooth the estimate, so a pilot batch with a single outcome still has some variance.
+        let probability = (true_count + 1.0) / (pilot.len() as f64 + 2.0);
+        let variance = probability * (1.0 - probability);
+        let shots = (variance / (target_std_error * target_std_error)).ceil();
+        shots.clamp(PILOT_SHOTS as f64, i32::MAX as f64) as usize
+    }
 }
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use crate::{
+    noise::types::noise_model::NoiseModel,
+    simulation::types::{
+        backend_kind::BackendKind, cancellation_token::CancellationToken,
+        progress_hook::ProgressHook,
+    },
+};
+
+/// [`SimulationConfig`] is every setting of a [`Simulation<T>`](crate::simulation::types::simulation::Simulation)
+/// run, which is built with the `with_` functions of it's
+/// [`behaviors`](crate::simulation::behaviors::simulation_config).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationConfig {
+    /// The amount of shots to run.
+    pub shots: usize,
+
+    /// The seed of the random number generator for the noise and measurements of every shot, or
+    /// [`None`] for different outcomes on every run.
+    pub seed: Option<u64>,
+
+    /// The [`NoiseModel`] every shot is run with.
+    pub noise_model: NoiseModel,
+
+    /// The [`BackendKind`] circuits are run on.
+    pub backend: BackendKind,
+
+    /// The amount of threads the shots are split between, which needs the `parallel` feature, and
+    /// runs every shot on the calling thread without it.
+    pub threads: usize,
+
+    /// The [`ProgressHook`] that is told how many shots have completed, if any.
+    pub progress: Option<ProgressHook>,
+
+    /// The [`CancellationToken`] that stops the run before it's next shot once it is cancelled,
+    /// if any.
+    pub cancellation: Option<CancellationToken>,
+
+    /// The time budget of the run, after which no more shots are started, if any.
+    pub timeout: Option<Duration>,
+}
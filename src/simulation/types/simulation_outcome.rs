@@ -0,0 +1,20 @@
+/// [`SimulationOutcome`] is the collection of measured shots of a simulation, where every shot has
+/// one measured value per [`Qubit`](crate::quantum::types::qubit::Qubit).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationOutcome {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) measured in every shot.
+    pub num_qubits: usize,
+
+    /// The kept shots, where each value is the "truthy" state of a measured
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit), in the same way as
+    /// [`Qubit::measure`](crate::quantum::types::qubit::Qubit::measure).
+    pub shots: Vec<Vec<bool>>,
+
+    /// The amount of shots that were simulated, including the shots removed by post-selection.
+    pub total_shots: usize,
+
+    /// Whether the run was stopped early by a
+    /// [`CancellationToken`](crate::simulation::types::cancellation_token::CancellationToken) or a
+    /// timeout, so fewer shots were simulated than were asked for.
+    pub truncated: bool,
+}
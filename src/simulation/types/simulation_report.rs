@@ -1,6 +1,38 @@
+use crate::{log_info, simulation::types::report::Report};
+
 /// [`SimulationReport<T>`] is a [`trait`] that allows reporting for any type that implements it.
 pub trait SimulationReport<T> {
-    /// [`SimulationReport<T>::report`] will log out results, normally of an implementation of some
-    /// [`Simulation<T>`](crate::simulation::types::simulation).
-    fn report(&self, report_for: T);
+    /// [`SimulationReport<T>::generate_report`] will get the results, normally of an
+    /// implementation of some [`Simulation<T>`](crate::simulation::types::simulation), as a
+    /// [`Report`] that can be used programmatically.
+    fn generate_report(&self, report_for: T) -> Report;
+
+    /// [`SimulationReport<T>::report`] will log out the [`Report`] of
+    /// [`SimulationReport<T>::generate_report`].
+    fn report(&self, report_for: T) {
+        log_info!("{}", self.generate_report(report_for));
+    }
+
+    /// [`SimulationReport<T>::to_html`] will get the [`Report`] of
+    /// [`SimulationReport<T>::generate_report`] as a self-contained HTML snippet, with a table of
+    /// counts and an inline SVG histogram, for rich output in notebooks.
+    ///
+    /// # Example
+    /// [`SimulationReport<T>::to_html`] can be used to show the results of a
+    /// [`Qubit`](crate::quantum::types::qubit::Qubit) simulation as rich output in an
+    /// [evcxr](https://github.com/evcxr/evcxr) Jupyter notebook:
+    /// ```rust
+    /// use rquant::{
+    ///     quantum::types::qubit::Qubit,
+    ///     simulation::types::simulation_report::SimulationReport,
+    /// };
+    ///
+    /// fn show_results(results: Vec<bool>) {
+    ///     let html = results.to_html(Qubit::zero());
+    ///     println!("EVCXR_BEGIN_CONTENT text/html\n{html}\nEVCXR_END_CONTENT");
+    /// }
+    /// ```
+    fn to_html(&self, report_for: T) -> String {
+        self.generate_report(report_for).to_html()
+    }
 }
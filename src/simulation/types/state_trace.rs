@@ -0,0 +1,14 @@
+use crate::simulation::types::trace_step::TraceStep;
+
+/// [`StateTrace`] is a debug recording of a
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) run on a
+/// [`StateVector`](crate::quantum::types::state_vector::StateVector), with the amplitudes that
+/// changed after every operation, which shows exactly which gate sends a state somewhere unexpected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateTrace {
+    /// The amount of [`Qubits`](crate::quantum::types::qubit::Qubit) of the traced state.
+    pub num_qubits: usize,
+
+    /// The [`TraceSteps`](TraceStep) of the trace, one for every operation of the circuit.
+    pub steps: Vec<TraceStep>,
+}
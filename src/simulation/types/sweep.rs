@@ -0,0 +1,14 @@
+use crate::simulation::types::report::Report;
+
+/// [`Sweep`] is the [`Report`] of a parameterized
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit) at every point of a
+/// grid of parameter values, like the Rabi curve of a rotation angle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sweep {
+    /// The parameters of every point of the grid, where the first parameter changes the fastest.
+    pub points: Vec<Vec<f64>>,
+
+    /// The [`Report`] of the shots at every point, in the same order as the
+    /// [`points`](Sweep::points).
+    pub reports: Vec<Report>,
+}
@@ -0,0 +1,17 @@
+use crate::{
+    quantum::types::quantum_operation::QuantumOperation,
+    simulation::types::amplitude_change::AmplitudeChange,
+};
+
+/// [`TraceStep`] is one [`QuantumOperation`] of a traced
+/// [`QuantumCircuit`](crate::quantum::types::quantum_circuit::QuantumCircuit), and every amplitude
+/// it changed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TraceStep {
+    /// The [`QuantumOperation`] that was applied.
+    pub operation: QuantumOperation,
+
+    /// The [`AmplitudeChanges`](AmplitudeChange) of every basis state that the `operation` changed,
+    /// in basis state order.
+    pub changes: Vec<AmplitudeChange>,
+}
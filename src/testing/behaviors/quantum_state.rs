@@ -0,0 +1,27 @@
+use num_complex::Complex;
+
+use crate::{
+    quantum::types::{qubit::Qubit, qubit_register::QubitRegister, state_vector::StateVector},
+    testing::types::quantum_state::QuantumState,
+};
+
+/// Implement the [`QuantumState`] trait for [`Qubit`].
+impl QuantumState for Qubit {
+    fn state_amplitudes(&self) -> Vec<Complex<f64>> {
+        vec![self.initial_position(), self.possible_position()]
+    }
+}
+
+/// Implement the [`QuantumState`] trait for [`QubitRegister`].
+impl QuantumState for QubitRegister {
+    fn state_amplitudes(&self) -> Vec<Complex<f64>> {
+        StateVector::from_register(self).amplitudes
+    }
+}
+
+/// Implement the [`QuantumState`] trait for [`StateVector`].
+impl QuantumState for StateVector {
+    fn state_amplitudes(&self) -> Vec<Complex<f64>> {
+        self.amplitudes.to_vec()
+    }
+}
@@ -0,0 +1,97 @@
+/// [`assert_state_close!`](crate::assert_state_close) will assert that every amplitude of a
+/// [`QuantumState`](crate::testing::types::quantum_state::QuantumState) is within a tolerance of
+/// the expected [`Complex<f64>`](num_complex::Complex) amplitudes.
+///
+/// # Example
+/// [`assert_state_close!`](crate::assert_state_close) can be used to check the state of a
+/// [`QubitRegister`](crate::quantum::types::qubit_register::QubitRegister):
+/// ```rust
+/// use num_complex::Complex;
+/// use rquant::{assert_state_close, quantum::types::qubit_register::QubitRegister};
+///
+/// fn check_empty_register(qubit_register: &QubitRegister) {
+///     assert_state_close!(
+///         qubit_register,
+///         [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+///         1e-9
+///     );
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_state_close {
+    ($state:expr, $expected:expr, $tolerance:expr) => {{
+        use $crate::testing::types::quantum_state::QuantumState as _;
+        let actual = ($state).state_amplitudes();
+        let expected: Vec<$crate::num_complex::Complex<f64>> = $expected.into_iter().collect();
+        let tolerance = $tolerance;
+        assert_eq!(
+            expected.len(),
+            actual.len(),
+            "Expected {} amplitudes, but the state has {}",
+            expected.len(),
+            actual.len()
+        );
+        for (index, (actual, expected)) in actual.iter().zip(&expected).enumerate() {
+            assert!(
+                (actual - expected).norm() <= tolerance,
+                "Amplitude {index} is {actual}, expected {expected} within {tolerance}"
+            );
+        }
+    }};
+}
+
+/// [`assert_prob!`](crate::assert_prob) will assert that the probability of a
+/// [`Qubit`](crate::quantum::types::qubit::Qubit) being measured as `true` is within a tolerance of
+/// an expected probability.
+///
+/// # Example
+/// [`assert_prob!`](crate::assert_prob) can be used to check a qubit is in an even superposition:
+/// ```rust
+/// use rquant::{assert_prob, quantum::types::{qubit::Qubit, quantum_gate::QuantumGate}};
+///
+/// fn check_superposition() {
+///     assert_prob!(Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION), 0.5, 0.01);
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_prob {
+    ($qubit:expr, $expected:expr, $tolerance:expr) => {{
+        let actual = $qubit.initial_position().norm_sqr();
+        assert!(
+            (actual - $expected).abs() <= $tolerance,
+            "Measurement probability is {actual}, expected {} within {}",
+            $expected,
+            $tolerance
+        );
+    }};
+}
+
+/// [`assert_unitary!`](crate::assert_unitary) will assert that a
+/// [`QuantumGate`](crate::quantum::types::quantum_gate::QuantumGate) is unitary, with an optional
+/// tolerance that defaults to `1e-9`.
+///
+/// # Example
+/// [`assert_unitary!`](crate::assert_unitary) can be used to check a rotation gate:
+/// ```rust
+/// use rquant::{
+///     assert_unitary,
+///     quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator},
+/// };
+///
+/// fn check_rotation(angle: f64) {
+///     assert_unitary!(QuantumGate::new(QuantumOperator::RX(angle)));
+/// }
+/// ```
+#[macro_export]
+macro_rules! assert_unitary {
+    ($gate:expr) => {
+        $crate::assert_unitary!($gate, 1e-9)
+    };
+    ($gate:expr, $tolerance:expr) => {{
+        let gate = &$gate;
+        assert!(
+            gate.is_unitary($tolerance),
+            "Quantum gate is not unitary:{gate}"
+        );
+    }};
+}
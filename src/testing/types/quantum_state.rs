@@ -0,0 +1,10 @@
+use num_complex::Complex;
+
+/// [`QuantumState`] is a [`trait`] that will allow any type holding quantum state to be compared
+/// by it's amplitudes in the [`testing`](crate::testing) macros.
+pub trait QuantumState {
+    /// [`QuantumState::state_amplitudes`] will get the [`Complex<f64>`] amplitude of every
+    /// computational basis state, where the first [`Qubit`](crate::quantum::types::qubit::Qubit) is
+    /// the most significant bit of the index.
+    fn state_amplitudes(&self) -> Vec<Complex<f64>>;
+}
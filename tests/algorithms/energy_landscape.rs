@@ -0,0 +1,111 @@
+use std::f64::consts::PI;
+
+use rquant::{
+    algorithms::types::energy_landscape::EnergyLandscape,
+    quantum::types::{
+        hamiltonian::Hamiltonian, pauli::Pauli, pauli_string::PauliString,
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+fn z_hamiltonian() -> Hamiltonian {
+    Hamiltonian::new(
+        1,
+        &[(
+            1.0,
+            PauliString {
+                negative: false,
+                paulis: vec![Pauli::Z],
+            },
+        )],
+    )
+}
+
+fn rotation_circuit(rotations: usize) -> QuantumCircuit {
+    (0..rotations).fold(QuantumCircuit::new(1), |circuit, _| {
+        circuit.add_gate(&QuantumGate::new(QuantumOperator::RY(0.0)), 0)
+    })
+}
+
+#[test]
+fn scan_shouldmatchanalyticenergy_withsingleparameter() {
+    let landscape = EnergyLandscape::scan(&rotation_circuit(1), &z_hamiltonian(), &[(0.0, PI)], 5);
+
+    assert_eq!(1, landscape.energies.len());
+    assert_eq!(5, landscape.energies[0].len());
+    for (column, energy) in landscape.energies[0].iter().enumerate() {
+        // The energy of RY(angle) on Z is cos(angle).
+        let angle = landscape.parameters(column, 0)[0];
+        assert!((energy - angle.cos()).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn scan_shouldcreategrid_withtwoparameters() {
+    let landscape = EnergyLandscape::scan(
+        &rotation_circuit(2),
+        &z_hamiltonian(),
+        &[(0.0, PI), (0.0, 1.0)],
+        3,
+    );
+
+    assert_eq!(3, landscape.energies.len());
+    assert!(landscape.energies.iter().all(|row| row.len() == 3));
+    assert_eq!(vec![PI / 2.0, 1.0], landscape.parameters(1, 2));
+    // Two rotations around the same axis add up.
+    assert!((landscape.energies[2][1] - (PI / 2.0 + 1.0).cos()).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn scan_shouldpanic_withmismatchedparameters() {
+    EnergyLandscape::scan(&rotation_circuit(2), &z_hamiltonian(), &[(0.0, PI)], 5);
+}
+
+#[test]
+#[should_panic]
+fn scan_shouldpanic_withthreeparameters() {
+    EnergyLandscape::scan(
+        &rotation_circuit(3),
+        &z_hamiltonian(),
+        &[(0.0, PI), (0.0, PI), (0.0, PI)],
+        5,
+    );
+}
+
+#[test]
+fn minimum_shouldfindlowestenergy() {
+    let landscape = EnergyLandscape::scan(&rotation_circuit(1), &z_hamiltonian(), &[(-PI, PI)], 9);
+
+    let (parameters, energy) = landscape.minimum();
+
+    assert!((parameters[0].abs() - PI).abs() < 1e-12);
+    assert!((energy + 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn tocsv_shouldexporteverypoint() {
+    let landscape = EnergyLandscape::scan(
+        &rotation_circuit(2),
+        &z_hamiltonian(),
+        &[(0.0, PI), (0.0, PI)],
+        2,
+    );
+
+    let csv = landscape.to_csv();
+
+    assert_eq!(5, csv.lines().count());
+    assert_eq!(Some("parameter_0,parameter_1,energy"), csv.lines().next());
+    assert_eq!(Some("0,0,1"), csv.lines().nth(1));
+}
+
+#[test]
+fn display_shouldshadelevels_fromlowtohigh() {
+    let landscape = EnergyLandscape::scan(&rotation_circuit(1), &z_hamiltonian(), &[(0.0, PI)], 3);
+
+    let contour = landscape.to_string();
+
+    assert_eq!(Some("@+."), contour.lines().next());
+    assert!(contour.ends_with("energy: . -1.0000  @ 1.0000"));
+}
@@ -0,0 +1,105 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex;
+use rquant::{
+    algorithms::types::hhl::Hhl,
+    quantum::types::{hamiltonian::Hamiltonian, pauli::Pauli, pauli_string::PauliString},
+};
+
+fn pauli_string(paulis: &[Pauli]) -> PauliString {
+    PauliString {
+        negative: false,
+        paulis: paulis.to_vec(),
+    }
+}
+
+fn overlap(a: &[Complex<f64>], b: &[Complex<f64>]) -> f64 {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| x.conj() * y)
+        .sum::<Complex<f64>>()
+        .norm()
+}
+
+#[test]
+fn solve_shouldinvertmatrix_withtwobytwosystem() {
+    // A = 2I - X has the eigenvalues 1 and 3, and A^-1 |0> is proportional to [2, 1].
+    let hamiltonian = Hamiltonian::new(
+        1,
+        &[
+            (2.0, pauli_string(&[Pauli::I])),
+            (-1.0, pauli_string(&[Pauli::X])),
+        ],
+    );
+    let hhl = Hhl::new(hamiltonian, 2, PI / 2.0);
+
+    let result = hhl.solve(&[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]);
+
+    let expected = [
+        Complex::new(2.0 / 5.0_f64.sqrt(), 0.0),
+        Complex::new(1.0 / 5.0_f64.sqrt(), 0.0),
+    ];
+    assert!((overlap(&expected, &result.solution) - 1.0).abs() < 1e-9);
+    assert!(result.success_probability > 0.0 && result.success_probability <= 1.0);
+}
+
+#[test]
+fn solve_shouldinvertmatrix_withfourbyfoursystem() {
+    // A = 2II - 0.5XI - 0.5IX has the eigenvalues 1, 2, 2 and 3.
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (2.0, pauli_string(&[Pauli::I, Pauli::I])),
+            (-0.5, pauli_string(&[Pauli::X, Pauli::I])),
+            (-0.5, pauli_string(&[Pauli::I, Pauli::X])),
+        ],
+    );
+    let matrix = hamiltonian.matrix();
+    let hhl = Hhl::new(hamiltonian, 2, PI / 2.0);
+    let b = [
+        Complex::new(0.5, 0.0),
+        Complex::new(0.5, 0.0),
+        Complex::new(0.5, 0.0),
+        Complex::new(-0.5, 0.0),
+    ];
+
+    let result = hhl.solve(&b);
+
+    let product: Vec<Complex<f64>> = matrix
+        .iter()
+        .map(|row| row.iter().zip(&result.solution).map(|(a, x)| a * x).sum())
+        .collect();
+    let norm = product.iter().map(|x| x.norm_sqr()).sum::<f64>().sqrt();
+    let normalized: Vec<Complex<f64>> = product.iter().map(|x| x / norm).collect();
+    assert!((overlap(&b, &normalized) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn solve_shouldreturneigenvector_witheigenvectorinput() {
+    let hamiltonian = Hamiltonian::new(
+        1,
+        &[
+            (2.0, pauli_string(&[Pauli::I])),
+            (-1.0, pauli_string(&[Pauli::X])),
+        ],
+    );
+    let hhl = Hhl::new(hamiltonian, 2, PI / 2.0);
+    let eigenvector = [
+        Complex::new(1.0 / 2.0_f64.sqrt(), 0.0),
+        Complex::new(-1.0 / 2.0_f64.sqrt(), 0.0),
+    ];
+
+    let result = hhl.solve(&eigenvector);
+
+    // The eigenvalue is 3, so the ancilla is rotated by 1/3 of the smallest eigenvalue.
+    assert!((overlap(&eigenvector, &result.solution) - 1.0).abs() < 1e-9);
+    assert!((result.success_probability - 1.0 / 9.0).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn solve_shouldpanic_withwronginputlength() {
+    let hhl = Hhl::new(Hamiltonian::new(1, &[]), 2, PI / 2.0);
+
+    hhl.solve(&[Complex::new(1.0, 0.0)]);
+}
@@ -0,0 +1,49 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::algorithms::types::mermin_ghz::MerminGhz;
+
+#[test]
+fn run_shouldmeasuredeterministicparities_withghzstate() {
+    let experiment = MerminGhz::run(200);
+
+    assert_eq!(1.0, experiment.xxx);
+    assert_eq!(-1.0, experiment.xyy);
+    assert_eq!(-1.0, experiment.yxy);
+    assert_eq!(-1.0, experiment.yyx);
+}
+
+#[test]
+fn merminvalue_shouldviolatelocalrealism() {
+    let experiment = MerminGhz::run_with(50, &mut StdRng::seed_from_u64(3));
+
+    assert_eq!(4.0, experiment.mermin_value());
+    assert!(experiment.violates_local_realism());
+}
+
+#[test]
+fn violateslocalrealism_shouldbefalse_atbound() {
+    let experiment = MerminGhz {
+        shots: 1,
+        xxx: 1.0,
+        xyy: -1.0,
+        yxy: 0.0,
+        yyx: 0.0,
+    };
+
+    assert!(!experiment.violates_local_realism());
+}
+
+#[test]
+fn display_shouldreportparitiesandbound() {
+    let experiment = MerminGhz::run(10);
+
+    assert_eq!(
+        "XXX: +1.0000\nXYY: -1.0000\nYXY: -1.0000\nYYX: -1.0000\nmermin value: 4.0000 (local realism allows at most 2)",
+        experiment.to_string()
+    );
+}
+
+#[test]
+#[should_panic]
+fn run_shouldpanic_withoutshots() {
+    MerminGhz::run(0);
+}
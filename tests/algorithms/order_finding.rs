@@ -0,0 +1,73 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::algorithms::types::order_finding::OrderFinding;
+
+#[test]
+fn new_shouldusetwiceasmanycountingqubits_asmodulusbits() {
+    let order_finding = OrderFinding::new(7, 15);
+
+    assert_eq!(8, order_finding.precision);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withsharedfactor() {
+    OrderFinding::new(6, 15);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withlargemodulus() {
+    OrderFinding::new(2, 23);
+}
+
+#[test]
+fn phasedistribution_shouldpeak_atmultiplesofinverseorder() {
+    // 7 has order 4 modulo 15, so the phase peaks are at 0, 1/4, 2/4, and 3/4.
+    let distribution = OrderFinding::new(7, 15).phase_distribution();
+
+    for peak in [0, 64, 128, 192] {
+        assert!((distribution[peak] - 0.25).abs() < 1e-9);
+    }
+    assert!((distribution.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn measure_shouldreturnphasepeak() {
+    // 7 has order 4 modulo 15, so only multiples of 256 / 4 can be measured.
+    for _ in 0..5 {
+        assert_eq!(0, OrderFinding::new(7, 15).measure() % 64);
+    }
+}
+
+#[test]
+fn measurewith_shouldrepeatphases_withsameseed() {
+    let order_finding = OrderFinding::new(7, 15);
+    let (mut first, mut second) = (StdRng::seed_from_u64(3), StdRng::seed_from_u64(3));
+
+    let first: Vec<u64> = (0..2)
+        .map(|_| order_finding.measure_with(&mut first))
+        .collect();
+    let second: Vec<u64> = (0..2)
+        .map(|_| order_finding.measure_with(&mut second))
+        .collect();
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn findorder_shouldfindorder() {
+    assert_eq!(Some(4), OrderFinding::new(7, 15).find_order(20));
+    assert_eq!(Some(6), OrderFinding::new(2, 21).find_order(20));
+}
+
+#[test]
+fn findorderwith_shouldrepeatorders_withsameseed() {
+    let order_finding = OrderFinding::new(7, 15);
+
+    for seed in 0..3 {
+        assert_eq!(
+            order_finding.find_order_with(1, &mut StdRng::seed_from_u64(seed)),
+            order_finding.find_order_with(1, &mut StdRng::seed_from_u64(seed))
+        );
+    }
+}
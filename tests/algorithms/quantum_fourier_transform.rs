@@ -0,0 +1,98 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+
+use num_complex::Complex;
+use rquant::{
+    algorithms::types::quantum_fourier_transform::QuantumFourierTransform,
+    quantum::types::{quantum_gate::QuantumGate, state_vector::StateVector},
+};
+
+#[test]
+fn unitary_shouldmatchfouriermatrix_withexacttransform() {
+    let unitary = QuantumFourierTransform::new(3).unitary();
+
+    for (row, entries) in unitary.iter().enumerate() {
+        for (column, entry) in entries.iter().enumerate() {
+            let angle = 2.0 * PI * (row * column) as f64 / 8.0;
+            let expected = Complex::from_polar(1.0 / 8.0_f64.sqrt(), angle);
+            assert!((entry - expected).norm() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn apply_shouldtransformselectedqubits() {
+    let mut state_vector = StateVector::new(3);
+    state_vector.apply_gate(&QuantumGate::NOT, 2);
+
+    QuantumFourierTransform::new(2).apply(&mut state_vector, &[0, 2]);
+
+    // |01> on qubits 0 and 2 becomes (|00> + i|01> - |10> - i|11>) / 2.
+    let expected = [
+        (0b000, Complex::new(0.5, 0.0)),
+        (0b001, Complex::new(0.0, 0.5)),
+        (0b100, Complex::new(-0.5, 0.0)),
+        (0b101, Complex::new(0.0, -0.5)),
+    ];
+    for (index, amplitude) in expected {
+        assert!((state_vector.amplitudes[index] - amplitude).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn applyinverse_shouldundotransform() {
+    let mut state_vector = StateVector::new(3);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state_vector.apply_gate(&QuantumGate::NOT, 2);
+    let expected = state_vector.amplitudes.clone();
+
+    let qft = QuantumFourierTransform::new(3);
+    qft.apply(&mut state_vector, &[2, 0, 1]);
+    qft.apply_inverse(&mut state_vector, &[2, 0, 1]);
+
+    for (amplitude, expected) in state_vector.amplitudes.iter().zip(&expected) {
+        assert!((amplitude - expected).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn approximateqft_shoulddroprotations_belowthreshold() {
+    let qft = QuantumFourierTransform::new(4);
+
+    assert_eq!(6, qft.rotations().len());
+    assert_eq!(5, qft.approximate_qft(PI / 4.0).rotations().len());
+    assert_eq!(3, qft.approximate_qft(FRAC_PI_2).rotations().len());
+    assert!(qft
+        .approximate_qft(FRAC_PI_2)
+        .rotations()
+        .iter()
+        .all(|&(control, target, _)| control == target + 1));
+}
+
+#[test]
+fn fidelity_shouldbeone_withexacttransform() {
+    assert!((QuantumFourierTransform::new(4).fidelity() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn cutoffreport_shouldlosefidelity_asrotationsaredropped() {
+    let report = QuantumFourierTransform::new(4).cutoff_report(&[0.0, 0.5, 1.0, 2.0]);
+
+    assert_eq!(
+        vec![6, 5, 3, 0],
+        report
+            .iter()
+            .map(|point| point.rotations)
+            .collect::<Vec<_>>()
+    );
+    assert!((report[0].fidelity - 1.0).abs() < 1e-9);
+    assert!(report[1].fidelity > 0.97);
+    assert!(report
+        .windows(2)
+        .all(|pair| pair[1].fidelity <= pair[0].fidelity + 1e-9));
+}
+
+#[test]
+#[should_panic]
+fn apply_shouldpanic_withwrongqubitcount() {
+    QuantumFourierTransform::new(2).apply(&mut StateVector::new(3), &[0, 1, 2]);
+}
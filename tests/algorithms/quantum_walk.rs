@@ -0,0 +1,72 @@
+use num_complex::Complex;
+use rquant::{
+    algorithms::types::{quantum_walk::QuantumWalk, walk_topology::WalkTopology},
+    quantum::types::{
+        quantum_operators::QuantumOperator, quantum_position::QuantumPosition, qubit::Qubit,
+    },
+};
+
+fn balanced_coin() -> Qubit {
+    let amplitude = std::f64::consts::FRAC_1_SQRT_2;
+    Qubit::new(QuantumPosition::new(
+        Complex::new(amplitude, 0.0),
+        Complex::new(0.0, amplitude),
+    ))
+}
+
+#[test]
+fn probabilities_shouldsplitwalker_afteronestep() {
+    let walk = QuantumWalk::new(WalkTopology::Line, 5, 2);
+
+    let probabilities = walk.probabilities(1);
+
+    assert!((probabilities[1] - 0.5).abs() < 1e-12);
+    assert!((probabilities[3] - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn probabilities_shouldbesymmetric_withbalancedcoin() {
+    let walk = QuantumWalk::new(WalkTopology::Line, 41, 20)
+        .with_coin(QuantumOperator::SUPERPOSITION, balanced_coin());
+
+    let probabilities = walk.probabilities(15);
+
+    for offset in 1..=15 {
+        assert!((probabilities[20 - offset] - probabilities[20 + offset]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn probabilities_shouldspreadfasterthanclassicalwalk() {
+    let steps = 20;
+    let walk = QuantumWalk::new(WalkTopology::Line, 2 * steps + 1, steps)
+        .with_coin(QuantumOperator::SUPERPOSITION, balanced_coin());
+
+    let probabilities = walk.probabilities(steps);
+    let variance: f64 = probabilities
+        .iter()
+        .enumerate()
+        .map(|(position, probability)| {
+            let distance = position as f64 - steps as f64;
+            probability * distance * distance
+        })
+        .sum();
+
+    // A classical random walk has a variance equal to the amount of steps.
+    assert!(variance > 4.0 * steps as f64);
+}
+
+#[test]
+fn probabilities_shouldconserveprobability_oncycleandline() {
+    for topology in [WalkTopology::Cycle, WalkTopology::Line] {
+        let probabilities = QuantumWalk::new(topology, 7, 0).probabilities(30);
+
+        assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withinvalidstart() {
+    QuantumWalk::new(WalkTopology::Cycle, 4, 4);
+}
@@ -0,0 +1,44 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::algorithms::types::{order_finding::OrderFinding, shor::Shor};
+
+#[test]
+fn factor_shouldfactorcomposite() {
+    for modulus in [15, 21] {
+        let (a, b) = Shor::factor(modulus, 50).expect("Unable to factor modulus.");
+
+        assert_eq!(modulus, a * b);
+        assert!(a > 1 && b > 1);
+    }
+}
+
+#[test]
+fn factorwith_shouldrepeatfactors_withsameseed() {
+    for seed in 0..3 {
+        assert_eq!(
+            Shor::factor_with(15, 1, &mut StdRng::seed_from_u64(seed)),
+            Shor::factor_with(15, 1, &mut StdRng::seed_from_u64(seed))
+        );
+    }
+}
+
+#[test]
+fn factor_shouldfactorevenmodulus() {
+    assert_eq!(Some((2, 7)), Shor::factor(14, 1));
+}
+
+#[test]
+fn factor_shouldreturnnone_forprime() {
+    assert_eq!(None, Shor::factor(13, 10));
+}
+
+#[test]
+fn factor_shouldreturnnone_forsmallmodulus() {
+    for modulus in [0, 1, 2] {
+        assert_eq!(None, Shor::factor(modulus, 10));
+    }
+}
+
+#[test]
+fn factor_shouldreturnnone_forevenmodulusabovelimit() {
+    assert_eq!(None, Shor::factor(OrderFinding::MAX_MODULUS + 1, 10));
+}
@@ -0,0 +1,72 @@
+use std::f64::consts::PI;
+
+use rquant::{
+    algorithms::types::{gradient_descent::GradientDescent, vqe::Vqe},
+    quantum::types::{
+        hamiltonian::Hamiltonian, pauli::Pauli, pauli_string::PauliString,
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+fn pauli_string(paulis: &[Pauli]) -> PauliString {
+    PauliString {
+        negative: false,
+        paulis: paulis.to_vec(),
+    }
+}
+
+fn single_qubit_vqe(angle: f64) -> Vqe {
+    Vqe::new(
+        QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RY(angle)), 0),
+        Hamiltonian::new(1, &[(1.0, pauli_string(&[Pauli::Z]))]),
+    )
+}
+
+#[test]
+fn gradient_shouldmatchanalyticslope() {
+    let angle = 0.7;
+
+    let gradient = single_qubit_vqe(angle).gradient(&[angle]);
+
+    // The energy of RY(angle) on Z is cos(angle).
+    assert!((gradient[0] + angle.sin()).abs() < 1e-12);
+}
+
+#[test]
+fn run_shouldreachgroundstate_withsinglequbit() {
+    let result = single_qubit_vqe(0.3).run(&mut GradientDescent::new(0.4), 100);
+
+    assert_eq!(101, result.energy_history.len());
+    assert!((result.optimal_energy + 1.0).abs() < 1e-6);
+    assert!((result.optimal_parameters[0].abs() - PI).abs() < 1e-3);
+}
+
+#[test]
+fn run_shouldlowerenergy_withtwoqubithamiltonian() {
+    let ansatz = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(0.2)), 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(0.1)), 1);
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (1.0, pauli_string(&[Pauli::Z, Pauli::Z])),
+            (1.0, pauli_string(&[Pauli::Z, Pauli::I])),
+        ],
+    );
+
+    let result = Vqe::new(ansatz, hamiltonian).run(&mut GradientDescent::new(0.3), 200);
+
+    // The ground state is |10>, where both ZZ and ZI are -1.
+    assert!(result.optimal_energy < result.energy_history[0]);
+    assert!((result.optimal_energy + 2.0).abs() < 1e-3);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withmismatchedqubits() {
+    Vqe::new(
+        QuantumCircuit::new(2),
+        Hamiltonian::new(1, &[(1.0, pauli_string(&[Pauli::Z]))]),
+    );
+}
@@ -0,0 +1,29 @@
+use rquant::{
+    benchmarking::types::clifford::Clifford,
+    quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator},
+};
+
+#[test]
+fn group_shouldhavetwentyfourelements() {
+    assert_eq!(24, Clifford::group().len());
+}
+
+#[test]
+fn inverse_shouldundoeveryclifford() {
+    for clifford in Clifford::group() {
+        let mut operators = clifford.operators.clone();
+        operators.extend(Clifford::inverse(&clifford.matrix()).operators);
+
+        let product = Clifford { operators }.matrix();
+
+        assert!(product[0][1].norm() < 1e-9 && product[1][0].norm() < 1e-9);
+        assert!((product[0][0] - product[1][1]).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn fromgate_shouldfindcliffordgates() {
+    assert!(Clifford::from_gate(&QuantumGate::SUPERPOSITION).is_some());
+    assert!(Clifford::from_gate(&QuantumGate::NOT).is_some());
+    assert!(Clifford::from_gate(&QuantumGate::new(QuantumOperator::RZ(0.3))).is_none());
+}
@@ -0,0 +1,55 @@
+use rquant::{
+    benchmarking::types::gate_set_tomography::GateSetTomography,
+    noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    quantum::types::quantum_operators::QuantumOperator,
+};
+
+#[test]
+fn run_shouldgetidealptm_forgate() {
+    let gst = GateSetTomography::new(NoiseModel::new(), &[QuantumOperator::NOT], 10);
+
+    let estimate = &gst.run()[0];
+
+    assert_eq!(QuantumOperator::NOT, estimate.operator);
+    for (row, values) in estimate.ideal.iter().enumerate() {
+        for (column, value) in values.iter().enumerate() {
+            let expected = match (row == column, row) {
+                (false, _) => 0.0,
+                (true, 0 | 1) => 1.0,
+                (true, _) => -1.0,
+            };
+            assert!((value - expected).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn run_shouldmatchidealgate_withoutnoise() {
+    let gst = GateSetTomography::new(NoiseModel::new(), &[QuantumOperator::SUPERPOSITION], 4000);
+
+    let estimate = &gst.run()[0];
+
+    assert!(estimate.average_gate_fidelity > 0.97);
+    assert!(estimate
+        .error_generator
+        .iter()
+        .flatten()
+        .all(|value| value.abs() < 0.15));
+}
+
+#[test]
+fn run_shouldfinddepolarizingerror_withgatenoise() {
+    // Depolarizing with a probability p has an average gate fidelity of 1 - p/2.
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.1));
+    let gst = GateSetTomography::new(noise_model, &[QuantumOperator::NOT], 4000);
+
+    let estimate = &gst.run()[0];
+
+    assert!((estimate.average_gate_fidelity - 0.95).abs() < 0.03);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withoutgates() {
+    GateSetTomography::new(NoiseModel::new(), &[], 10);
+}
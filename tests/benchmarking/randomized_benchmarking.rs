@@ -0,0 +1,68 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::{
+    benchmarking::types::randomized_benchmarking::RandomizedBenchmarking,
+    noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator},
+};
+
+#[test]
+fn run_shouldhaveperfectfidelity_withoutnoise() {
+    let benchmark = RandomizedBenchmarking::new(NoiseModel::new(), &[1, 5, 10], 5, 20);
+
+    let result = benchmark.run();
+
+    assert!(result
+        .survival_probabilities
+        .iter()
+        .all(|&survival| survival == 1.0));
+    assert!((result.average_gate_fidelity - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn run_shoulddecay_withdepolarizingnoise() {
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.02));
+    let benchmark = RandomizedBenchmarking::new(noise_model, &[1, 10, 30], 10, 200);
+
+    let result = benchmark.run();
+
+    assert!(result.survival_probabilities[0] > result.survival_probabilities[2]);
+    assert!(result.average_gate_fidelity < 1.0 && result.average_gate_fidelity > 0.9);
+}
+
+#[test]
+fn runinterleaved_shouldestimategatefidelity_withdepolarizingnoise() {
+    // A depolarizing probability p gives every gate a fidelity of 1 - p/2.
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.02));
+    let benchmark = RandomizedBenchmarking::new(noise_model, &[1, 5, 10, 20], 30, 20);
+
+    let result = benchmark.run_interleaved(&QuantumGate::SUPERPOSITION);
+
+    assert!((result.gate_fidelity - 0.99).abs() < 0.025);
+    assert!(result.confidence_interval.0 <= result.gate_fidelity);
+    assert!(result.confidence_interval.1 >= result.gate_fidelity);
+}
+
+#[test]
+fn runwith_shouldreproduceresult_withsameseed() {
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::Depolarizing(0.05));
+    let benchmark = RandomizedBenchmarking::new(noise_model, &[1, 5, 10], 5, 20);
+
+    let first = benchmark.run_with(&mut StdRng::seed_from_u64(7));
+    let second = benchmark.run_with(&mut StdRng::seed_from_u64(7));
+
+    assert_eq!(first, second);
+}
+
+#[test]
+#[should_panic]
+fn runinterleaved_shouldpanic_withnoncliffordgate() {
+    let benchmark = RandomizedBenchmarking::new(NoiseModel::new(), &[1, 2], 1, 1);
+
+    benchmark.run_interleaved(&QuantumGate::new(QuantumOperator::RX(0.1)));
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withsinglelength() {
+    RandomizedBenchmarking::new(NoiseModel::new(), &[5, 5], 1, 1);
+}
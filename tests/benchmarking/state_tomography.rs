@@ -0,0 +1,85 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::{
+    benchmarking::types::state_tomography::StateTomography,
+    quantum::types::{basis::Basis, density_matrix::DensityMatrix, state_vector::StateVector},
+};
+
+fn plus_tomography() -> StateTomography {
+    StateTomography::new(1)
+        .with_counts(&[Basis::X], &[100, 0])
+        .with_counts(&[Basis::Y], &[50, 50])
+        .with_counts(&[Basis::Z], &[50, 50])
+}
+
+fn distance(first: &DensityMatrix, second: &DensityMatrix) -> f64 {
+    first
+        .elements
+        .iter()
+        .flatten()
+        .zip(second.elements.iter().flatten())
+        .map(|(a, b)| (a - b).norm())
+        .fold(0.0, f64::max)
+}
+
+#[test]
+fn linearinversion_shouldreconstructplusstate() {
+    let result = plus_tomography().linear_inversion();
+
+    assert!(result
+        .elements
+        .iter()
+        .flatten()
+        .all(|element| (element.re - 0.5).abs() < 1e-12 && element.im.abs() < 1e-12));
+}
+
+#[test]
+fn linearinversion_shouldbemaximallymixed_withoutmeasurements() {
+    let result = StateTomography::new(1).linear_inversion();
+
+    assert!((result.purity() - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn maximumlikelihood_shouldreconstructplusstate() {
+    let result = plus_tomography().maximum_likelihood(500);
+
+    assert!((result.purity() - 1.0).abs() < 1e-3);
+    assert!((result.elements[0][1].re - 0.5).abs() < 1e-3);
+}
+
+#[test]
+fn maximumlikelihood_shouldstayphysical_withunphysicalcounts() {
+    // Bloch vector (1, 0, 1) is outside the Bloch sphere.
+    let tomography = StateTomography::new(1)
+        .with_counts(&[Basis::X], &[100, 0])
+        .with_counts(&[Basis::Y], &[50, 50])
+        .with_counts(&[Basis::Z], &[100, 0]);
+
+    let linear = tomography.linear_inversion();
+    let likely = tomography.maximum_likelihood(500);
+
+    assert!(linear.purity() > 1.0);
+    assert!((likely.trace() - 1.0).abs() < 1e-12);
+    assert!(likely.purity() <= 1.0 + 1e-9);
+}
+
+#[test]
+fn measurewith_shouldreconstructbellstate() {
+    let bell = StateVector::ghz(2);
+    let tomography =
+        StateTomography::new(2).measure_with(&bell, 4000, &mut StdRng::seed_from_u64(7));
+
+    assert_eq!(9, tomography.measurements.len());
+    assert!(
+        distance(
+            &DensityMatrix::from_state_vector(&bell),
+            &tomography.linear_inversion()
+        ) < 0.05
+    );
+}
+
+#[test]
+#[should_panic]
+fn withcounts_shouldpanic_withbellbasis() {
+    StateTomography::new(2).with_counts(&[Basis::Bell, Basis::Z], &[1, 0, 0, 0]);
+}
@@ -0,0 +1,43 @@
+use num_complex::Complex;
+use rquant::bosonic::types::fock_state::FockState;
+
+#[test]
+fn annihilation_shouldlowernumberstate_bysqrtn() {
+    let state = FockState::number(5, 3);
+    let lowered: Vec<Complex<f64>> = FockState::annihilation(5)
+        .iter()
+        .map(|row| row.iter().zip(&state.amplitudes).map(|(a, b)| a * b).sum())
+        .collect();
+
+    assert!((lowered[2].re - 3f64.sqrt()).abs() < 1e-12);
+    assert!(lowered
+        .iter()
+        .enumerate()
+        .all(|(level, amplitude)| level == 2 || amplitude.norm() < 1e-12));
+}
+
+#[test]
+fn creation_shouldbeconjugatetransposeofannihilation() {
+    let annihilation = FockState::annihilation(4);
+    let creation = FockState::creation(4);
+
+    for row in 0..4 {
+        for column in 0..4 {
+            assert_eq!(creation[row][column], annihilation[column][row].conj());
+        }
+    }
+}
+
+#[test]
+fn coherent_shouldhavemeannumber_ofalphasquared() {
+    let state = FockState::coherent(30, Complex::new(1.2, 0.5));
+
+    assert!((state.mean_number() - 1.69).abs() < 1e-9);
+    assert!((state.probabilities().iter().sum::<f64>() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn number_shouldpanic_withexcitationsabovecutoff() {
+    FockState::number(3, 3);
+}
@@ -0,0 +1,41 @@
+use std::f64::consts::FRAC_PI_2;
+
+use rquant::{
+    bosonic::types::{fock_state::FockState, jaynes_cummings::JaynesCummings},
+    quantum::types::qubit::Qubit,
+};
+
+#[test]
+fn evolve_shouldoscillateexcitation_withvacuumrabifrequency() {
+    let coupling = 0.3;
+    let mut system = JaynesCummings::new(&Qubit::one(), &FockState::new(4), 1.0, 1.0, coupling);
+
+    system.evolve(0.5 / coupling);
+    assert!((system.excited_probability() - 0.5f64.cos().powi(2)).abs() < 1e-9);
+
+    system.evolve((FRAC_PI_2 - 0.5) / coupling);
+    assert!(system.excited_probability() < 1e-9);
+    assert!((system.field_probabilities()[1] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn evolve_shouldoscillatefaster_withmorephotons() {
+    // The |1>|n> to |0>|n + 1> exchange has a frequency of g√(n + 1).
+    let coupling = 0.2;
+    let mut system =
+        JaynesCummings::new(&Qubit::one(), &FockState::number(5, 3), 1.0, 1.0, coupling);
+
+    system.evolve(FRAC_PI_2 / (coupling * 2.0));
+
+    assert!(system.excited_probability() < 1e-9);
+    assert!((system.field_probabilities()[4] - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn evolve_shouldleavegroundvacuumunchanged() {
+    let mut system = JaynesCummings::new(&Qubit::zero(), &FockState::new(3), 1.0, 1.0, 0.5);
+
+    system.evolve(2.0);
+
+    assert!((system.amplitudes[0].norm() - 1.0).abs() < 1e-9);
+}
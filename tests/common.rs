@@ -6,8 +6,114 @@ pub fn setup() {
 
 #[cfg(test)]
 mod quantum {
+    mod bloch_sphere;
+    mod bloch_trajectory;
+    mod circuit;
+    mod clifford_tableau;
+    mod clone_audit;
+    mod compact_state_vector;
+    mod cost_model;
+    mod decision_diagram;
+    mod density_matrix;
+    mod dual_number;
+    mod gate_recording;
+    mod graph_state;
+    mod hamiltonian;
+    mod heatmap;
+    mod matrix_product_state;
+    mod pauli;
+    mod qasm_stream;
+    #[cfg(feature = "qir")]
+    mod qir;
+    mod quantum_circuit;
     mod quantum_gate;
     mod quantum_position;
     mod qubit;
     mod qubit_register;
+    mod qubit_view;
+    mod state_vector;
+    mod uncomputation_check;
+    mod unique_qubit;
+}
+
+#[cfg(test)]
+mod error_correction {
+    mod lookup_table_decoder;
+    mod minimum_weight_matching_decoder;
+    mod parity_check_code;
+    mod repetition_code;
+    mod steane_code;
+}
+
+#[cfg(test)]
+mod algorithms {
+    mod energy_landscape;
+    mod hhl;
+    mod mermin_ghz;
+    mod order_finding;
+    mod quantum_fourier_transform;
+    mod quantum_walk;
+    mod shor;
+    mod vqe;
+}
+
+#[cfg(test)]
+mod noise {
+    mod decoherence_model;
+    mod error_budget;
+    mod kraus_channel;
+    mod lindblad_equation;
+    mod noise_channel;
+    mod noise_model;
+    mod readout_error;
+}
+
+#[cfg(test)]
+mod benchmarking {
+    mod clifford;
+    mod gate_set_tomography;
+    mod randomized_benchmarking;
+    mod state_tomography;
+}
+
+#[cfg(test)]
+mod mbqc {
+    mod measurement_pattern;
+}
+
+#[cfg(test)]
+mod bosonic {
+    mod fock_state;
+    mod jaynes_cummings;
+}
+
+#[cfg(test)]
+mod formats {
+    mod format;
+    mod json;
+    mod qasm;
+    mod quirk;
+}
+
+#[cfg(test)]
+mod testing {
+    mod assert;
+}
+
+#[cfg(test)]
+mod simulation {
+    mod auto_shots;
+    mod csv_writer;
+    mod executor;
+    mod goodness_of_fit;
+    mod progress_hook;
+    mod report;
+    mod simulate;
+    mod simulate_counts;
+    mod simulate_iter;
+    mod simulation_config;
+    mod simulation_outcome;
+    mod simulation_report;
+    mod state_trace;
+    mod sweep;
 }
@@ -0,0 +1,164 @@
+//! The display precision is shared by every thread, so these tests run in their own binary and
+//! take turns with [`LOCK`].
+
+use std::{
+    sync::{Mutex, MutexGuard},
+    thread,
+};
+
+use num_complex::Complex;
+use rquant::quantum::{
+    behaviors::display_precision::set_display_precision,
+    types::{
+        display_precision::DisplayPrecision, quantum_gate::QuantumGate, qubit::Qubit,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+
+fn lock() -> MutexGuard<'static, ()> {
+    LOCK.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+#[test]
+fn set_shouldroundqubitdisplay() {
+    let _lock = lock();
+    DisplayPrecision::set(3);
+
+    let displayed = Qubit::zero()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .to_string();
+
+    DisplayPrecision::reset();
+    assert_eq!("0.707|0.707〉", displayed);
+}
+
+#[test]
+fn set_shouldroundregisterdisplay() {
+    let _lock = lock();
+    let qubit_register = QubitRegister {
+        qubits: vec![
+            Qubit::zero(),
+            Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION),
+        ],
+    };
+    DisplayPrecision::set(2);
+
+    let displayed = qubit_register.to_string();
+
+    DisplayPrecision::reset();
+    assert_eq!("<1.00|0.00〉, 0.71|0.71〉>", displayed);
+}
+
+#[test]
+fn set_shouldroundstatevectordisplay() {
+    let _lock = lock();
+    DisplayPrecision::set(2);
+
+    let displayed = StateVector::ghz(2).to_string();
+
+    DisplayPrecision::reset();
+    assert_eq!("0.71|00〉 + 0.71|11〉", displayed);
+}
+
+#[test]
+fn set_shouldroundgatedisplay() {
+    let _lock = lock();
+    DisplayPrecision::set(1);
+
+    let displayed = QuantumGate::SUPERPOSITION.to_string();
+
+    DisplayPrecision::reset();
+    assert!(displayed.contains("┃ 0.7 0.7 ┃"));
+    assert!(displayed.contains("┃ 0.7 -0.7 ┃"));
+}
+
+#[test]
+fn reset_shoulddisplayfullprecision() {
+    let _lock = lock();
+    DisplayPrecision::set(2);
+    DisplayPrecision::reset();
+
+    assert_eq!(None, DisplayPrecision::digits());
+    assert_eq!(
+        "0.7071067811865475|00〉 + 0.7071067811865475|11〉",
+        StateVector::ghz(2).to_string()
+    );
+}
+
+#[test]
+fn format_shouldleaveoutzeroparts() {
+    let _lock = lock();
+    assert_eq!("0.5", DisplayPrecision::format(Complex::new(0.5, 0.0)));
+    assert_eq!("0.5i", DisplayPrecision::format(Complex::new(0.0, 0.5)));
+    assert_eq!(
+        "-0.5+0.5i",
+        DisplayPrecision::format(Complex::new(-0.5, 0.5))
+    );
+    assert_eq!(
+        "0.5-0.5i",
+        DisplayPrecision::format(Complex::new(0.5, -0.5))
+    );
+    assert_eq!("0", DisplayPrecision::format(Complex::new(0.0, -0.0)));
+}
+
+#[test]
+fn format_shouldleaveoutpartsthatroundtozero() {
+    let _lock = lock();
+    DisplayPrecision::set(2);
+
+    let displayed = DisplayPrecision::format(Complex::new(0.708, -0.0001));
+
+    DisplayPrecision::reset();
+    assert_eq!("0.71", displayed);
+}
+
+#[test]
+fn setimaginaryunit_shoulddisplayglyph() {
+    let _lock = lock();
+    DisplayPrecision::set_imaginary_unit("ⅈ");
+
+    let displayed = Qubit::back_quarter_turn().to_string();
+
+    DisplayPrecision::set_imaginary_unit("i");
+    assert_eq!("0|-1ⅈ〉", displayed);
+}
+
+#[test]
+fn display_shouldwrapcomplexamplitudes_withstatevector() {
+    let _lock = lock();
+    let mut state_vector = StateVector::new(1);
+    state_vector.amplitudes = vec![Complex::new(0.5, 0.5), Complex::new(0.0, 0.5)];
+    DisplayPrecision::set(1);
+
+    let displayed = state_vector.to_string();
+
+    DisplayPrecision::reset();
+    assert_eq!("(0.5+0.5i)|0〉 + 0.5i|1〉", displayed);
+}
+
+#[test]
+fn set_shouldroundstatevectordisplay_onotherthreads() {
+    let _lock = lock();
+    DisplayPrecision::set(2);
+
+    let displayed = thread::spawn(|| StateVector::ghz(2).to_string())
+        .join()
+        .unwrap();
+
+    DisplayPrecision::reset();
+    assert_eq!("0.71|00〉 + 0.71|11〉", displayed);
+}
+
+#[test]
+fn setdisplayprecision_shouldsetandresetdigits() {
+    let _lock = lock();
+
+    set_display_precision(Some(4));
+    let digits = DisplayPrecision::digits();
+    set_display_precision(None);
+
+    assert_eq!(Some(4), digits);
+    assert_eq!(None, DisplayPrecision::digits());
+}
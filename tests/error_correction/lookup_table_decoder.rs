@@ -0,0 +1,29 @@
+use rquant::error_correction::types::{
+    decoder::Decoder, lookup_table_decoder::LookupTableDecoder, parity_check_code::ParityCheckCode,
+};
+
+#[test]
+fn decode_shouldcorrectsingleerrors_forbitflipcode() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    let decoder = LookupTableDecoder::new(&code, 1);
+
+    for qubit in 0..3 {
+        assert_eq!(vec![qubit], decoder.decode(&code.syndrome(&[qubit])));
+    }
+}
+
+#[test]
+fn decode_shouldnotcorrect_withemptysyndrome() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    let decoder = LookupTableDecoder::new(&code, 1);
+
+    assert!(decoder.decode(&[false, false]).is_empty());
+}
+
+#[test]
+fn decode_shouldnotcorrect_withunknownsyndrome() {
+    let code = ParityCheckCode::new(4, vec![vec![0, 1], vec![2, 3]]);
+    let decoder = LookupTableDecoder::new(&code, 0);
+
+    assert!(decoder.decode(&[true, false]).is_empty());
+}
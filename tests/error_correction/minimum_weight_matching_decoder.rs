@@ -0,0 +1,46 @@
+use rquant::error_correction::types::{
+    decoder::Decoder, minimum_weight_matching_decoder::MinimumWeightMatchingDecoder,
+    parity_check_code::ParityCheckCode,
+};
+
+fn repetition_code(num_qubits: usize) -> ParityCheckCode {
+    ParityCheckCode::new(
+        num_qubits,
+        (0..num_qubits - 1).map(|i| vec![i, i + 1]).collect(),
+    )
+}
+
+#[test]
+fn decode_shouldcorrectsingleerrors_forrepetitioncode() {
+    let code = repetition_code(5);
+    let decoder = MinimumWeightMatchingDecoder::new(code.clone());
+
+    for qubit in 0..5 {
+        assert_eq!(vec![qubit], decoder.decode(&code.syndrome(&[qubit])));
+    }
+}
+
+#[test]
+fn decode_shouldcorrecttwoerrors_forrepetitioncode() {
+    let code = repetition_code(5);
+    let decoder = MinimumWeightMatchingDecoder::new(code.clone());
+
+    assert_eq!(vec![1, 3], decoder.decode(&code.syndrome(&[1, 3])));
+    assert_eq!(vec![0, 1], decoder.decode(&code.syndrome(&[0, 1])));
+}
+
+#[test]
+fn decode_shouldnotcorrect_withemptysyndrome() {
+    let decoder = MinimumWeightMatchingDecoder::new(repetition_code(3));
+
+    assert!(decoder.decode(&[false, false]).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withqubitinthreechecks() {
+    MinimumWeightMatchingDecoder::new(ParityCheckCode::new(
+        3,
+        vec![vec![0, 1], vec![0, 2], vec![0]],
+    ));
+}
@@ -0,0 +1,79 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::error_correction::types::{
+    lookup_table_decoder::LookupTableDecoder, parity_check_code::ParityCheckCode,
+};
+
+#[test]
+fn new_shouldcreatecode_withvalidchecks() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+
+    assert_eq!(3, code.num_qubits);
+    assert_eq!(2, code.checks.len());
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withinvalidqubitindex() {
+    ParityCheckCode::new(2, vec![vec![0, 2]]);
+}
+
+#[test]
+fn syndrome_shouldflagchecks_forerrors() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+
+    assert_eq!(vec![false, false], code.syndrome(&[]));
+    assert_eq!(vec![true, false], code.syndrome(&[0]));
+    assert_eq!(vec![true, true], code.syndrome(&[1]));
+    assert_eq!(vec![true, false], code.syndrome(&[1, 2]));
+}
+
+#[test]
+fn checksfor_shouldreturnchecks_containingqubit() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+
+    assert_eq!(vec![0, 1], code.checks_for(1));
+    assert_eq!(vec![1], code.checks_for(2));
+}
+
+#[test]
+fn logicalerrorrate_shouldbezero_withoutnoise() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    let decoder = LookupTableDecoder::new(&code, 1);
+
+    let result = code.logical_error_rate(&decoder, 0.0, 5, 50);
+
+    assert_eq!(0.0, result.logical_error_rate);
+    assert_eq!(5, result.rounds);
+    assert_eq!(50, result.shots);
+}
+
+#[test]
+fn logicalerrorrate_shouldbeone_whenallqubitsflip() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    let decoder = LookupTableDecoder::new(&code, 1);
+
+    let result = code.logical_error_rate(&decoder, 1.0, 1, 10);
+
+    assert_eq!(1.0, result.logical_error_rate);
+}
+
+#[test]
+fn logicalerrorrate_shouldbebelowphysicalerrorrate_forrepetitioncode() {
+    let code = ParityCheckCode::new(5, (0..4).map(|i| vec![i, i + 1]).collect());
+    let decoder = LookupTableDecoder::new(&code, 2);
+
+    let result = code.logical_error_rate(&decoder, 0.05, 1, 2000);
+
+    assert!(result.logical_error_rate < result.physical_error_rate);
+}
+
+#[test]
+fn logicalerrorratewith_shouldrepeatrate_withsameseed() {
+    let code = ParityCheckCode::new(3, vec![vec![0, 1], vec![1, 2]]);
+    let decoder = LookupTableDecoder::new(&code, 1);
+    let rate = |seed| {
+        code.logical_error_rate_with(&decoder, 0.2, 3, 200, &mut StdRng::seed_from_u64(seed))
+    };
+
+    assert_eq!(rate(8), rate(8));
+}
@@ -0,0 +1,99 @@
+use num_complex::Complex;
+use rquant::{
+    error_correction::types::repetition_code::RepetitionCode,
+    noise::types::noise_channel::NoiseChannel,
+    quantum::types::{quantum_gate::QuantumGate, quantum_position::QuantumPosition, qubit::Qubit},
+};
+
+fn uneven_qubit() -> Qubit {
+    Qubit::new(QuantumPosition::new(
+        Complex::new(0.6, 0.0),
+        Complex::new(0.0, 0.8),
+    ))
+}
+
+fn assert_same_qubit(expected: &Qubit, actual: &Qubit) {
+    assert!((expected.initial_position() - actual.initial_position()).norm() < 1e-9);
+    assert!((expected.possible_position() - actual.possible_position()).norm() < 1e-9);
+}
+
+#[test]
+fn encode_shouldrepeatqubit_withbitflipcode() {
+    let state_vector = RepetitionCode::BitFlip.encode(&Qubit::one());
+
+    assert!((state_vector.amplitudes[0b11100] - Complex::new(1.0, 0.0)).norm() < 1e-9);
+}
+
+#[test]
+fn syndrome_shouldlocateerror_withsinglebitflip() {
+    let code = RepetitionCode::BitFlip;
+    let expected = [vec![true, false], vec![true, true], vec![false, true]];
+
+    for (qubit, syndrome) in expected.iter().enumerate() {
+        let mut state_vector = code.encode(&uneven_qubit());
+        state_vector.apply_gate(&QuantumGate::NOT, qubit);
+
+        assert_eq!(*syndrome, code.syndrome(&mut state_vector));
+        assert!((state_vector.marginal_probabilities(&[3, 4])[0] - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn correct_shouldrestorequbit_withsinglebitflip() {
+    let code = RepetitionCode::BitFlip;
+
+    for qubit in 0..3 {
+        let mut state_vector = code.encode(&uneven_qubit());
+        state_vector.apply_gate(&QuantumGate::NOT, qubit);
+        let syndrome = code.syndrome(&mut state_vector);
+        code.correct(&mut state_vector, &syndrome);
+
+        assert_same_qubit(&uneven_qubit(), &code.decode(state_vector));
+    }
+}
+
+#[test]
+fn correct_shouldrestorequbit_withsinglephaseflip() {
+    let code = RepetitionCode::PhaseFlip;
+
+    for qubit in 0..3 {
+        let mut state_vector = code.encode(&uneven_qubit());
+        state_vector.apply_gate(&QuantumGate::PHASE, qubit);
+        let syndrome = code.syndrome(&mut state_vector);
+        code.correct(&mut state_vector, &syndrome);
+
+        assert_same_qubit(&uneven_qubit(), &code.decode(state_vector));
+    }
+}
+
+#[test]
+fn syndrome_shouldbeempty_withouterrors() {
+    for code in [RepetitionCode::BitFlip, RepetitionCode::PhaseFlip] {
+        let mut state_vector = code.encode(&uneven_qubit());
+
+        assert_eq!(vec![false, false], code.syndrome(&mut state_vector));
+    }
+}
+
+#[test]
+fn transmit_shouldlowererrorrate_withbitflipchannel() {
+    let shots = 2000;
+    let failures = (0..shots)
+        .filter(|_| {
+            RepetitionCode::BitFlip
+                .transmit(&Qubit::one(), &NoiseChannel::BitFlip(0.1))
+                .measure()
+        })
+        .count();
+
+    // A single qubit fails 10% of the time, but the code only fails on two or more flips,
+    // about 2.8% of the time.
+    assert!((failures as f64 / shots as f64) < 0.06);
+}
+
+#[test]
+fn transmit_shouldkeepqubit_withoutnoise() {
+    let qubit = RepetitionCode::BitFlip.transmit(&uneven_qubit(), &NoiseChannel::BitFlip(0.0));
+
+    assert_same_qubit(&uneven_qubit(), &qubit);
+}
@@ -0,0 +1,82 @@
+use num_complex::Complex;
+use rquant::{
+    error_correction::types::steane_code::SteaneCode,
+    noise::types::noise_channel::NoiseChannel,
+    quantum::types::{quantum_gate::QuantumGate, quantum_position::QuantumPosition, qubit::Qubit},
+};
+
+fn uneven_qubit() -> Qubit {
+    Qubit::new(QuantumPosition::new(
+        Complex::new(0.6, 0.0),
+        Complex::new(0.0, 0.8),
+    ))
+}
+
+fn assert_same_qubit(expected: &Qubit, actual: &Qubit) {
+    // Correcting a Y error with X and Z leaves a global phase behind.
+    let overlap = expected.initial_position().conj() * actual.initial_position()
+        + expected.possible_position().conj() * actual.possible_position();
+    assert!((overlap.norm() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn decode_shouldreturnencodedqubit() {
+    let qubit = SteaneCode.decode(SteaneCode.encode(&uneven_qubit()));
+
+    assert_same_qubit(&uneven_qubit(), &qubit);
+}
+
+#[test]
+fn syndrome_shouldbeempty_withouterrors() {
+    let mut state_vector = SteaneCode.encode(&uneven_qubit());
+
+    assert_eq!(vec![false; 6], SteaneCode.syndrome(&mut state_vector));
+}
+
+#[test]
+fn syndrome_shouldbeunique_foreverysinglequbiterror() {
+    let mut syndromes = vec![];
+    for gate in [QuantumGate::NOT, QuantumGate::PHASE] {
+        for qubit in 0..7 {
+            let mut state_vector = SteaneCode.encode(&uneven_qubit());
+            state_vector.apply_gate(&gate, qubit);
+            syndromes.push(SteaneCode.syndrome(&mut state_vector));
+        }
+    }
+
+    for (index, syndrome) in syndromes.iter().enumerate() {
+        assert!(syndrome.iter().any(|&flagged| flagged));
+        assert!(!syndromes[index + 1..].contains(syndrome));
+    }
+}
+
+#[test]
+fn correct_shouldrestorequbit_withanysinglequbiterror() {
+    for gate in [QuantumGate::NOT, QuantumGate::PHASE, QuantumGate::ROTATE] {
+        for qubit in 0..7 {
+            let mut state_vector = SteaneCode.encode(&uneven_qubit());
+            state_vector.apply_gate(&gate, qubit);
+            let syndrome = SteaneCode.syndrome(&mut state_vector);
+            SteaneCode.correct(&mut state_vector, &syndrome);
+
+            assert_same_qubit(&uneven_qubit(), &SteaneCode.decode(state_vector));
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn correct_shouldpanic_withinvalidsyndrome() {
+    let mut state_vector = SteaneCode.encode(&Qubit::zero());
+
+    SteaneCode.correct(&mut state_vector, &[true, false, true]);
+}
+
+#[test]
+fn logicalerrorrate_shouldbelowerthanphysical_withdepolarizingnoise() {
+    let result =
+        SteaneCode.logical_error_rate(&uneven_qubit(), &NoiseChannel::Depolarizing(0.01), 1000);
+
+    assert_eq!(0.01, result.physical_error_rate);
+    assert!(result.logical_error_rate < result.physical_error_rate);
+}
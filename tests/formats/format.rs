@@ -0,0 +1,49 @@
+use rquant::{
+    formats::{self, types::format::Format},
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+/// [`golden_circuit`] is the [`QuantumCircuit`] that every golden file was exported from.
+pub(crate) fn golden_circuit() -> QuantumCircuit {
+    QuantumCircuit::new(3)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 2)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(0.5)), 2)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(-1.25)), 0)
+        .add_gate(&QuantumGate::PHASE, 1)
+        .add_gate(&QuantumGate::ROTATE, 2)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(2.0)), 1)
+}
+
+#[test]
+fn validate_shouldacceptgoldenfiles_foreveryformat() {
+    let files = [
+        (include_str!("golden/circuit.qasm"), Format::Qasm),
+        (include_str!("golden/circuit.json"), Format::Json),
+        (include_str!("golden/circuit.quirk.json"), Format::Quirk),
+    ];
+
+    for (file, format) in files {
+        assert_eq!(Ok(()), formats::validate(file, format));
+    }
+}
+
+#[test]
+fn validate_shouldreturnerror_withwrongformat() {
+    let error = formats::validate(include_str!("golden/circuit.json"), Format::Qasm).unwrap_err();
+
+    assert_eq!(Format::Qasm, error.format);
+}
+
+#[test]
+fn validate_shouldreturnerror_withmissingsemicolon() {
+    let error = formats::validate("qreg q[1];\nh q[0]\n", Format::Qasm).unwrap_err();
+
+    assert_eq!(
+        "Invalid QASM: line 2: statement is missing it's semicolon",
+        error.to_string()
+    );
+}
@@ -0,0 +1,12 @@
+OPENQASM 2.0;
+include "qelib1.inc";
+qreg q[3];
+creg c[3];
+h q[0];
+x q[2];
+rz(0.5) q[2];
+ry(-1.25) q[0];
+z q[1];
+y q[2];
+rx(2) q[1];
+measure q -> c;
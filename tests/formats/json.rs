@@ -0,0 +1,83 @@
+use rquant::{
+    formats::types::format::Format,
+    quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+};
+
+use super::format::golden_circuit;
+
+#[test]
+fn tojson_shouldmatchgoldenfile() {
+    assert_eq!(
+        include_str!("golden/circuit.json").trim_end(),
+        golden_circuit().post_select(2, true).to_json()
+    );
+}
+
+#[test]
+fn fromjson_shouldreadgoldenfile() {
+    let circuit = QuantumCircuit::from_json(include_str!("golden/circuit.json")).unwrap();
+
+    assert_eq!(golden_circuit().post_select(2, true), circuit);
+}
+
+#[test]
+fn fromjson_shouldreadformatteddocument_withoutpostselections() {
+    let document = "{\n  \"num_qubits\": 1,\n  \"operations\": [\n    { \"target\": 0, \"gate\": \"rz(pi/2)\" }\n  ]\n}\n";
+
+    let circuit = QuantumCircuit::from_json(document).unwrap();
+
+    assert_eq!(
+        QuantumCircuit::from_json(&circuit.to_json()).unwrap(),
+        circuit
+    );
+    assert!(circuit.post_selections.is_empty());
+}
+
+#[test]
+fn fromjson_shouldroundtrip_withcontrolledgates() {
+    let circuit =
+        QuantumCircuit::new(3)
+            .add_cnot(0, 1)
+            .add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2);
+
+    assert!(circuit
+        .to_json()
+        .contains(r#"{"gate":"x","target":2,"controls":[0,1]}"#));
+    assert_eq!(
+        circuit,
+        QuantumCircuit::from_json(&circuit.to_json()).unwrap()
+    );
+}
+
+#[test]
+fn fromjson_shouldreturnerror_withcontrolontarget() {
+    let document = r#"{"num_qubits":2,"operations":[{"gate":"x","target":1,"controls":[1]}]}"#;
+
+    let error = QuantumCircuit::from_json(document).unwrap_err();
+
+    assert_eq!(
+        "operation 0: \"controls\" must be other qubit indices",
+        error.message
+    );
+}
+
+#[test]
+fn fromjson_shouldreturnerror_withoutofboundstarget() {
+    let document =
+        r#"{"num_qubits":1,"operations":[{"gate":"h","target":0},{"gate":"x","target":1}]}"#;
+
+    let error = QuantumCircuit::from_json(document).unwrap_err();
+
+    assert_eq!(Format::Json, error.format);
+    assert_eq!(
+        "operation 1: \"target\" is not a qubit index",
+        error.message
+    );
+}
+
+#[test]
+fn fromjson_shouldreturnerror_withmalformeddocument() {
+    let error = QuantumCircuit::from_json(r#"{"num_qubits":1,"operations":[}"#).unwrap_err();
+
+    assert_eq!("character 31: invalid value", error.message);
+}
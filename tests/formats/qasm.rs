@@ -0,0 +1,79 @@
+use rquant::{
+    formats::types::format::Format,
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+use super::format::golden_circuit;
+
+#[test]
+fn toqasm_shouldmatchgoldenfile() {
+    assert_eq!(
+        include_str!("golden/circuit.qasm"),
+        golden_circuit().to_qasm()
+    );
+}
+
+#[test]
+fn fromqasm_shouldreadgoldenfile() {
+    let circuit = QuantumCircuit::from_qasm(include_str!("golden/circuit.qasm")).unwrap();
+
+    assert_eq!(golden_circuit(), circuit);
+}
+
+#[test]
+fn fromqasm_shouldroundtrip_withirrationalangles() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(
+            &QuantumGate::new(QuantumOperator::RZ(std::f64::consts::PI / 3.0)),
+            1,
+        )
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(-1e-7)), 0);
+
+    assert_eq!(
+        circuit,
+        QuantumCircuit::from_qasm(&circuit.to_qasm()).unwrap()
+    );
+}
+
+#[test]
+fn fromqasm_shouldreadcontrolledgates() {
+    let circuit =
+        QuantumCircuit::from_qasm("qreg q[2];\nh q[0];\ncx q[0], q[1];\ncz q[1], q[0];\n").unwrap();
+
+    assert_eq!(
+        QuantumCircuit::new(2)
+            .add_gate(&QuantumGate::SUPERPOSITION, 0)
+            .add_cnot(0, 1)
+            .add_cz(1, 0),
+        circuit
+    );
+}
+
+#[test]
+fn toqasm_shouldroundtrip_withcontrolledgates() {
+    let circuit = QuantumCircuit::new(2).add_cnot(1, 0).add_cz(0, 1);
+
+    assert!(circuit.to_qasm().contains("cx q[1],q[0];\ncz q[0],q[1];\n"));
+    assert_eq!(
+        circuit,
+        QuantumCircuit::from_qasm(&circuit.to_qasm()).unwrap()
+    );
+}
+
+#[test]
+fn fromqasm_shouldreturnerror_withtwoqubitgate() {
+    let error = QuantumCircuit::from_qasm("qreg q[2];\nh q[0];\nswap q[0], q[1];\n").unwrap_err();
+
+    assert_eq!(Format::Qasm, error.format);
+    assert!(error.message.starts_with("line 3:"));
+}
+
+#[test]
+fn fromqasm_shouldreturnerror_withunsupportedgate() {
+    let error = QuantumCircuit::from_qasm("qreg q[1];\nu3(0, 0, 0) q[0];\n").unwrap_err();
+
+    assert_eq!("line 2: unsupported gate \"u3\"", error.message);
+}
@@ -0,0 +1,87 @@
+use std::f64::consts::PI;
+
+use rquant::quantum::types::{
+    quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    quantum_operation::QuantumOperation, quantum_operators::QuantumOperator,
+};
+
+use super::format::golden_circuit;
+
+#[test]
+fn toquirk_shouldmatchgoldenfile() {
+    assert_eq!(
+        include_str!("golden/circuit.quirk.json").trim_end(),
+        golden_circuit().post_select(2, true).to_quirk()
+    );
+}
+
+#[test]
+fn fromquirk_shouldreadgoldenfile() {
+    let circuit = QuantumCircuit::from_quirk(include_str!("golden/circuit.quirk.json")).unwrap();
+
+    assert_eq!(golden_circuit().post_select(2, true), circuit);
+}
+
+#[test]
+fn fromquirk_shouldreadrootgates_asrotations() {
+    let circuit =
+        QuantumCircuit::from_quirk(r#"{"cols":[["Z^½","X^-½"],[{"id":"Rzft","arg":"pi/8"}]]}"#)
+            .unwrap();
+
+    assert_eq!(2, circuit.num_qubits);
+    assert_eq!(
+        vec![
+            QuantumOperation {
+                operator: QuantumOperator::RZ(PI / 2.0),
+                target: 0,
+                controls: vec![]
+            },
+            QuantumOperation {
+                operator: QuantumOperator::RX(-PI / 2.0),
+                target: 1,
+                controls: vec![]
+            },
+            QuantumOperation {
+                operator: QuantumOperator::RZ(PI / 8.0),
+                target: 0,
+                controls: vec![]
+            },
+        ],
+        circuit.operations
+    );
+}
+
+#[test]
+fn fromquirk_shouldreadcontrolledgate() {
+    let circuit = QuantumCircuit::from_quirk(r#"{"cols":[["H"],["•","X"]]}"#).unwrap();
+
+    assert_eq!(
+        QuantumCircuit::new(2)
+            .add_gate(&QuantumGate::SUPERPOSITION, 0)
+            .add_cnot(0, 1),
+        circuit
+    );
+}
+
+#[test]
+fn toquirk_shouldroundtrip_withcontrolledgates() {
+    let circuit =
+        QuantumCircuit::new(3)
+            .add_cz(2, 0)
+            .add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2);
+
+    assert_eq!(
+        circuit,
+        QuantumCircuit::from_quirk(&circuit.to_quirk()).unwrap()
+    );
+}
+
+#[test]
+fn fromquirk_shouldreturnerror_withgateafterpostselection() {
+    let error = QuantumCircuit::from_quirk(r#"{"cols":[["|0⟩⟨0|"],["H"]]}"#).unwrap_err();
+
+    assert_eq!(
+        "column 1: gates after a post-selection are not supported",
+        error.message
+    );
+}
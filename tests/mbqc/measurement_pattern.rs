@@ -0,0 +1,84 @@
+use std::f64::consts::PI;
+
+use rquant::{
+    mbqc::types::measurement_pattern::MeasurementPattern,
+    quantum::types::{
+        graph_state::GraphState, pauli::Pauli, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, qubit::Qubit, qubit_register::QubitRegister,
+        state_vector::StateVector,
+    },
+};
+
+fn input_qubit() -> Qubit {
+    Qubit::zero()
+        .apply_gate(&QuantumGate::new(QuantumOperator::RY(0.9)))
+        .apply_gate(&QuantumGate::new(QuantumOperator::RZ(0.4)))
+}
+
+fn assert_same_state(expected: &StateVector, actual: &StateVector) {
+    assert!((expected.inner_product(actual).norm() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn execute_shouldmatchrotationcircuit_withtwoqubitcluster() {
+    let alpha = PI / 3.0;
+    let pattern = MeasurementPattern::new(GraphState::new(2, &[(0, 1)]), &[0], &[1])
+        .measure(0, -alpha, &[], &[])
+        .correct(1, Pauli::X, &[0]);
+    let mut expected = StateVector::from_register(&QubitRegister {
+        qubits: vec![input_qubit()],
+    });
+    expected.apply_gate(&QuantumGate::new(QuantumOperator::RZ(alpha)), 0);
+    expected.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    for _ in 0..20 {
+        let result = pattern.execute(&QubitRegister {
+            qubits: vec![input_qubit()],
+        });
+
+        assert_same_state(&expected, &result);
+    }
+}
+
+#[test]
+fn execute_shouldmatchcircuit_withadaptivemeasurements() {
+    let (alpha, beta) = (0.7, -1.3);
+    let pattern = MeasurementPattern::new(GraphState::new(3, &[(0, 1), (1, 2)]), &[0], &[2])
+        .measure(0, -alpha, &[], &[])
+        .measure(1, -beta, &[0], &[])
+        .correct(2, Pauli::X, &[1])
+        .correct(2, Pauli::Z, &[0]);
+    let mut expected = StateVector::from_register(&QubitRegister {
+        qubits: vec![input_qubit()],
+    });
+    expected.apply_gate(&QuantumGate::new(QuantumOperator::RZ(alpha)), 0);
+    expected.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    expected.apply_gate(&QuantumGate::new(QuantumOperator::RZ(beta)), 0);
+    expected.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    for _ in 0..20 {
+        let result = pattern.execute(&QubitRegister {
+            qubits: vec![input_qubit()],
+        });
+
+        assert_same_state(&expected, &result);
+    }
+}
+
+#[test]
+#[should_panic]
+fn measure_shouldpanic_withunmeasuredsignal() {
+    MeasurementPattern::new(GraphState::new(3, &[(0, 1), (1, 2)]), &[0], &[2]).measure(
+        1,
+        0.0,
+        &[0],
+        &[],
+    );
+}
+
+#[test]
+#[should_panic]
+fn execute_shouldpanic_withunmeasuredqubit() {
+    MeasurementPattern::new(GraphState::new(2, &[(0, 1)]), &[0], &[1])
+        .execute(&QubitRegister::new(1));
+}
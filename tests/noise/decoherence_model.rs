@@ -0,0 +1,94 @@
+use rquant::{
+    noise::types::{decoherence_model::DecoherenceModel, noise_model::NoiseModel},
+    quantum::types::{
+        density_matrix::DensityMatrix, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, qubit::Qubit, state_vector::StateVector,
+    },
+};
+
+#[test]
+fn channels_shoulddecaycoherence_att2() {
+    let model = DecoherenceModel::new(100.0, 80.0, 0.0);
+    let plus = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+    let density_matrix = model.channels(40.0).iter().fold(
+        DensityMatrix::from_qubit(&plus),
+        |density_matrix, channel| {
+            channel
+                .kraus_channel()
+                .apply_to_density_matrix(&density_matrix, &[0])
+        },
+    );
+
+    let expected = 0.5 * (-40.0_f64 / 80.0).exp();
+    assert!((density_matrix.elements[0][1].norm() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn channels_shoulddecayexcitedpopulation_att1() {
+    let model = DecoherenceModel::new(100.0, 200.0, 0.0);
+
+    let density_matrix = model.channels(100.0).iter().fold(
+        DensityMatrix::from_qubit(&Qubit::one()),
+        |density_matrix, channel| {
+            channel
+                .kraus_channel()
+                .apply_to_density_matrix(&density_matrix, &[0])
+        },
+    );
+
+    assert!((density_matrix.elements[1][1].re - (-1.0_f64).exp()).abs() < 1e-12);
+}
+
+#[test]
+fn idle_shouldnotchangequbit_withoutelapsedtime() {
+    let model = DecoherenceModel::new(100.0, 80.0, 0.05);
+
+    assert_eq!(Qubit::one(), model.idle(&Qubit::one(), 0.0));
+}
+
+#[test]
+fn duration_shoulduseoverride_ignoringangle() {
+    let model =
+        DecoherenceModel::new(100.0, 80.0, 0.05).with_gate_duration(QuantumOperator::RX(0.0), 0.2);
+
+    assert_eq!(0.2, model.duration(&QuantumOperator::RX(1.5)));
+    assert_eq!(0.05, model.duration(&QuantumOperator::NOT));
+}
+
+#[test]
+fn run_shoulddecayidlequbits_untilcircuitend() {
+    // The second qubit is flipped instantly, then decays while the first qubit is gated.
+    let model = DecoherenceModel::new(1.0, 2.0, 0.0)
+        .with_gate_duration(QuantumOperator::NOT, 1e6)
+        .with_gate_duration(QuantumOperator::RX(0.0), 0.0);
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(
+            &QuantumGate::new(QuantumOperator::RX(std::f64::consts::PI)),
+            1,
+        )
+        .add_gate(&QuantumGate::NOT, 0)
+        .add_gate(&QuantumGate::NOT, 0);
+    let mut state_vector = StateVector::new(2);
+
+    NoiseModel::new()
+        .with_decoherence(model)
+        .run(&circuit, &mut state_vector);
+
+    assert!((state_vector.amplitudes[0b00].norm_sqr() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_witht2overtwicet1() {
+    DecoherenceModel::new(10.0, 25.0, 0.0);
+}
+
+#[test]
+fn channels_shouldonlycontainpaulichannels_whentwirled() {
+    let channels = DecoherenceModel::new(100.0, 80.0, 0.05)
+        .twirled()
+        .channels(10.0);
+
+    assert!(channels.iter().all(|channel| *channel == channel.twirled()));
+}
@@ -0,0 +1,114 @@
+use rquant::{
+    noise::types::{
+        decoherence_model::DecoherenceModel, error_source::ErrorSource,
+        noise_channel::NoiseChannel, noise_model::NoiseModel, readout_error::ReadoutError,
+    },
+    quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+};
+
+fn two_gate_circuit() -> QuantumCircuit {
+    QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 0)
+}
+
+#[test]
+fn errorbudget_shouldbecertain_withoutnoise() {
+    let budget = two_gate_circuit().error_budget(&NoiseModel::new());
+
+    assert_eq!(1.0, budget.success_probability);
+    assert!(budget.contributions.is_empty());
+}
+
+#[test]
+fn errorbudget_shouldmultiplyfidelities_alongcircuit() {
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::BitFlip(0.01))
+        .with_readout_error(ReadoutError::new(0.02, 0.04));
+
+    let budget = two_gate_circuit().error_budget(&noise_model);
+
+    // Two noisy gates, and a readout of each qubit that is misread 3% of the time.
+    assert_eq!(4, budget.contributions.len());
+    let expected = 0.99 * 0.99 * 0.97 * 0.97;
+    assert!((budget.success_probability - expected).abs() < 1e-12);
+}
+
+#[test]
+fn errorbudget_shouldaddnoise_toeveryqubitofcontrolledgate() {
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.01));
+
+    let budget = QuantumCircuit::new(2)
+        .add_cnot(0, 1)
+        .error_budget(&noise_model);
+
+    let targets: Vec<usize> = budget
+        .contributions
+        .iter()
+        .map(|contribution| contribution.target)
+        .collect();
+    assert_eq!(vec![0, 1], targets);
+    assert!((budget.success_probability - 0.99 * 0.99).abs() < 1e-12);
+}
+
+#[test]
+fn errorbudget_shouldaddidletime_forfasterqubits() {
+    let noise_model = NoiseModel::new().with_decoherence(DecoherenceModel::new(100.0, 80.0, 1.0));
+
+    let budget = two_gate_circuit().error_budget(&noise_model);
+
+    let idle: Vec<usize> = budget
+        .contributions
+        .iter()
+        .filter(|contribution| contribution.source == ErrorSource::Idle)
+        .map(|contribution| contribution.target)
+        .collect();
+    assert_eq!(vec![1], idle);
+    assert!(budget.success_probability < 1.0);
+}
+
+#[test]
+fn largestcontributors_shouldorderbylowestfidelity() {
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::PhaseFlip(0.001))
+        .with_measurement_noise(NoiseChannel::BitFlip(0.05));
+
+    let budget = two_gate_circuit().error_budget(&noise_model);
+    let largest = budget.largest_contributors(2);
+
+    assert_eq!(2, largest.len());
+    assert!(largest
+        .iter()
+        .all(|contribution| contribution.source == ErrorSource::Measurement));
+}
+
+#[test]
+fn share_shouldadduptoone() {
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::Depolarizing(0.02))
+        .with_readout_error(ReadoutError::new(0.01, 0.01));
+
+    let budget = two_gate_circuit().error_budget(&noise_model);
+    let total: f64 = budget
+        .contributions
+        .iter()
+        .map(|contribution| budget.share(contribution))
+        .sum();
+
+    assert!((total - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn display_shouldlistsuccessandcontributions() {
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.01));
+
+    let report = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::NOT, 0)
+        .error_budget(&noise_model)
+        .to_string();
+
+    assert_eq!(
+        "success probability: 0.9900\ngate 0 on qubit 0: fidelity 0.9900 (100.0% of error)",
+        report
+    );
+}
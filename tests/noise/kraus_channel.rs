@@ -0,0 +1,162 @@
+use num_complex::Complex;
+use rquant::{
+    noise::types::{kraus_channel::KrausChannel, noise_channel::NoiseChannel},
+    quantum::types::{
+        density_matrix::DensityMatrix, quantum_gate::QuantumGate, qubit::Qubit,
+        state_vector::StateVector,
+    },
+};
+
+fn reset_channel() -> KrausChannel {
+    let (zero, one) = (Complex::new(0.0, 0.0), Complex::new(1.0, 0.0));
+    KrausChannel::new(vec![
+        vec![vec![one, zero], vec![zero, zero]],
+        vec![vec![zero, one], vec![zero, zero]],
+    ])
+}
+
+#[test]
+fn apply_shouldresetqubit_withresetchannel() {
+    let qubit = reset_channel().apply(&Qubit::one());
+
+    assert_eq!(1.0, qubit.initial_position().norm_sqr());
+}
+
+#[test]
+fn applytodensitymatrix_shouldmixstate_withdepolarizingchannel() {
+    let density_matrix = DensityMatrix::from_qubit(&Qubit::zero());
+
+    let depolarized = NoiseChannel::Depolarizing(1.0)
+        .kraus_channel()
+        .apply_to_density_matrix(&density_matrix, &[0]);
+
+    assert!((depolarized.trace() - 1.0).abs() < 1e-12);
+    assert!((depolarized.purity() - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn applytodensitymatrix_shoulddephasebellstate_withphaseflip() {
+    let density_matrix = DensityMatrix::from_state_vector(&StateVector::ghz(2));
+
+    let dephased = NoiseChannel::PhaseFlip(0.5)
+        .kraus_channel()
+        .apply_to_density_matrix(&density_matrix, &[1]);
+
+    assert!((dephased.elements[0b00][0b00].re - 0.5).abs() < 1e-12);
+    assert!((dephased.elements[0b11][0b11].re - 0.5).abs() < 1e-12);
+    assert!(dephased.elements[0b00][0b11].norm() < 1e-12);
+}
+
+#[test]
+fn applytodensitymatrix_shouldmatchstatevector_withunitarychannel() {
+    let h = QuantumGate::SUPERPOSITION;
+    let unitary = KrausChannel::new(vec![h
+        .transform
+        .iter()
+        .map(|row| vec![row.initial_position, row.possible_position])
+        .collect()]);
+    let mut state_vector = StateVector::new(1);
+
+    let density_matrix =
+        unitary.apply_to_density_matrix(&DensityMatrix::from_state_vector(&state_vector), &[0]);
+    unitary.apply_to_state_vector(&mut state_vector, &[0]);
+
+    let expected = DensityMatrix::from_state_vector(&state_vector);
+    let elements = expected.elements.iter().flatten();
+    assert!(elements
+        .zip(density_matrix.elements.iter().flatten())
+        .all(|(expected, actual)| (expected - actual).norm() < 1e-12));
+}
+
+#[test]
+fn numqubits_shouldcountoperatorqubits() {
+    let identity = (0..4)
+        .map(|row| {
+            (0..4)
+                .map(|column| Complex::new(if row == column { 1.0 } else { 0.0 }, 0.0))
+                .collect()
+        })
+        .collect();
+
+    assert_eq!(2, KrausChannel::new(vec![identity]).num_qubits());
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withincompleteoperators() {
+    let (zero, half) = (Complex::new(0.0, 0.0), Complex::new(0.5, 0.0));
+    KrausChannel::new(vec![vec![vec![half, zero], vec![zero, half]]]);
+}
+
+#[test]
+fn processfidelity_shouldbenoerrorprobability_withpaulichannel() {
+    let kraus_channel = NoiseChannel::Pauli(0.01, 0.02, 0.03).kraus_channel();
+
+    assert!((kraus_channel.process_fidelity() - 0.94).abs() < 1e-12);
+}
+
+#[test]
+fn processfidelity_shouldbequarter_withresetchannel() {
+    // Resetting keeps |0> but loses |1>, and all of it's coherence.
+    assert!((reset_channel().process_fidelity() - 0.25).abs() < 1e-12);
+}
+
+#[test]
+fn compose_shouldcombineflipprobabilities_withbitflips() {
+    let first = NoiseChannel::BitFlip(0.1).kraus_channel();
+    let second = NoiseChannel::BitFlip(0.2).kraus_channel();
+
+    let composed = first.compose(&second);
+
+    assert!(composed.is_complete(1e-12));
+    assert!((composed.process_fidelity() - (0.9 * 0.8 + 0.1 * 0.2)).abs() < 1e-12);
+}
+
+#[test]
+fn compose_shouldapplyselffirst() {
+    let flip = KrausChannel::new(vec![QuantumGate::NOT
+        .transform
+        .iter()
+        .map(|row| vec![row.initial_position, row.possible_position])
+        .collect()]);
+    let density_matrix = DensityMatrix::from_qubit(&Qubit::zero());
+
+    // Resetting then flipping always ends in |1>.
+    let result = reset_channel()
+        .compose(&flip)
+        .apply_to_density_matrix(&density_matrix, &[0]);
+
+    assert!((result.elements[1][1].re - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn compose_shouldpanic_withdifferentqubitcounts() {
+    let single = reset_channel();
+    single.compose(&single.tensor(&single));
+}
+
+#[test]
+fn tensor_shouldactoneachqubit() {
+    let depolarizing = NoiseChannel::Depolarizing(0.3).kraus_channel();
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::NOT, 0);
+    state_vector.apply_gate(&QuantumGate::NOT, 1);
+    let density_matrix = DensityMatrix::from_state_vector(&state_vector);
+
+    let result = reset_channel()
+        .tensor(&depolarizing)
+        .apply_to_density_matrix(&density_matrix, &[0, 1]);
+    let expected = depolarizing.apply_to_density_matrix(
+        &reset_channel().apply_to_density_matrix(&density_matrix, &[0]),
+        &[1],
+    );
+
+    assert_eq!(2, reset_channel().tensor(&depolarizing).num_qubits());
+    assert!(result
+        .elements
+        .iter()
+        .flatten()
+        .zip(expected.elements.iter().flatten())
+        .all(|(actual, expected)| (actual - expected).norm() < 1e-12));
+}
@@ -0,0 +1,59 @@
+use num_complex::Complex;
+use rquant::{
+    noise::types::lindblad_equation::LindbladEquation,
+    quantum::types::{
+        density_matrix::DensityMatrix, hamiltonian::Hamiltonian, observable::Observable,
+        qubit::Qubit, state_vector::StateVector,
+    },
+};
+
+fn lowering(rate: f64) -> Vec<Vec<Complex<f64>>> {
+    let (zero, decay) = (Complex::new(0.0, 0.0), Complex::new(rate.sqrt(), 0.0));
+    vec![vec![zero, decay], vec![zero, zero]]
+}
+
+#[test]
+fn evolve_shoulddecayexcitedpopulation_exponentially() {
+    let equation = LindbladEquation::new(Hamiltonian::new(1, &[]), vec![lowering(0.5)]);
+
+    let result = equation.evolve(&DensityMatrix::from_qubit(&Qubit::one()), 2.0, 200);
+
+    assert!((result.elements[1][1].re - (-1.0_f64).exp()).abs() < 1e-9);
+    assert!((result.trace() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn evolve_shouldmatchstatevector_withoutcollapseoperators() {
+    let hamiltonian = Observable::parse("ZZ + 0.5*XI");
+    let equation = LindbladEquation::new(hamiltonian.clone(), vec![]);
+    let mut state_vector = StateVector::new(2);
+
+    let result = equation.evolve(&DensityMatrix::from_state_vector(&state_vector), 1.0, 200);
+    state_vector.evolve(&hamiltonian, 1.0);
+
+    let expected = DensityMatrix::from_state_vector(&state_vector);
+    assert!(result
+        .elements
+        .iter()
+        .flatten()
+        .zip(expected.elements.iter().flatten())
+        .all(|(actual, expected)| (actual - expected).norm() < 1e-8));
+}
+
+#[test]
+fn derivative_shouldbezero_withgroundstate() {
+    let equation = LindbladEquation::new(Observable::parse("Z"), vec![lowering(1.0)]);
+
+    let derivative = equation.derivative(&DensityMatrix::from_qubit(&Qubit::zero()));
+
+    assert!(derivative
+        .iter()
+        .flatten()
+        .all(|element| element.norm() < 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withwrongoperatorsize() {
+    LindbladEquation::new(Hamiltonian::new(2, &[]), vec![lowering(1.0)]);
+}
@@ -0,0 +1,126 @@
+use num_complex::Complex;
+use rquant::{
+    noise::types::noise_channel::NoiseChannel,
+    quantum::types::{quantum_gate::QuantumGate, qubit::Qubit, state_vector::StateVector},
+};
+
+fn is_complete(channel: NoiseChannel) -> bool {
+    let operators = channel.kraus_operators();
+    (0..2).all(|row| {
+        (0..2).all(|column| {
+            let sum: Complex<f64> = operators
+                .iter()
+                .map(|operator| {
+                    (0..2)
+                        .map(|k| operator[k][row].conj() * operator[k][column])
+                        .sum::<Complex<f64>>()
+                })
+                .sum();
+            let expected = if row == column { 1.0 } else { 0.0 };
+            (sum - Complex::new(expected, 0.0)).norm() < 1e-12
+        })
+    })
+}
+
+#[test]
+fn krausoperators_shouldbecomplete_foreverychannel() {
+    assert!(is_complete(NoiseChannel::BitFlip(0.3)));
+    assert!(is_complete(NoiseChannel::PhaseFlip(0.3)));
+    assert!(is_complete(NoiseChannel::Depolarizing(0.3)));
+    assert!(is_complete(NoiseChannel::AmplitudeDamping(0.3)));
+}
+
+#[test]
+fn apply_shouldalwaysflip_withcertainbitflip() {
+    let qubit = NoiseChannel::BitFlip(1.0).apply(&Qubit::zero());
+
+    assert_eq!(Qubit::one(), qubit);
+}
+
+#[test]
+fn apply_shouldneverchange_withzeroprobability() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+    let noisy = NoiseChannel::Depolarizing(0.0).apply(&qubit);
+
+    assert!((noisy.initial_position() - qubit.initial_position()).norm() < 1e-12);
+    assert!((noisy.possible_position() - qubit.possible_position()).norm() < 1e-12);
+}
+
+#[test]
+fn apply_shoulddecaytozero_withcertainamplitudedamping() {
+    let qubit = NoiseChannel::AmplitudeDamping(1.0).apply(&Qubit::one());
+
+    assert_eq!(1.0, qubit.initial_position().norm_sqr());
+}
+
+#[test]
+fn apply_shouldflipatprobability_withbitflip() {
+    let flips = (0..2000)
+        .filter(|_| !NoiseChannel::BitFlip(0.25).apply(&Qubit::zero()).measure())
+        .count();
+
+    assert!((flips as f64 / 2000.0 - 0.25).abs() < 0.05);
+}
+
+#[test]
+fn applytostatevector_shouldflipbothbellqubits_withcertainbitflips() {
+    let mut state_vector = StateVector::ghz(2);
+
+    NoiseChannel::BitFlip(1.0).apply_to_state_vector(&mut state_vector, 0);
+
+    assert!((state_vector.amplitudes[0b01].norm_sqr() - 0.5).abs() < 1e-12);
+    assert!((state_vector.amplitudes[0b10].norm_sqr() - 0.5).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn krausoperators_shouldpanic_withinvalidprobability() {
+    NoiseChannel::PhaseFlip(1.5).kraus_operators();
+}
+
+#[test]
+fn krausoperators_shouldbecomplete_forpauliandoverrotation() {
+    assert!(is_complete(NoiseChannel::Pauli(0.1, 0.2, 0.3)));
+    assert!(is_complete(NoiseChannel::OverRotation(0.4)));
+}
+
+#[test]
+#[should_panic]
+fn krausoperators_shouldpanic_withnegativepauliprobability() {
+    NoiseChannel::Pauli(0.2, -0.1, 0.0).kraus_operators();
+}
+
+#[test]
+fn twirled_shouldbebitflip_withoverrotation() {
+    let NoiseChannel::Pauli(x, y, z) = NoiseChannel::OverRotation(0.4).twirled() else {
+        panic!("Twirled channel should be a pauli channel");
+    };
+
+    assert!((x - 0.2_f64.sin().powi(2)).abs() < 1e-12);
+    assert!(y.abs() < 1e-12 && z.abs() < 1e-12);
+}
+
+#[test]
+fn twirled_shouldmatchknownpaulichannel_withamplitudedamping() {
+    let gamma: f64 = 0.3;
+    let NoiseChannel::Pauli(x, y, z) = NoiseChannel::AmplitudeDamping(gamma).twirled() else {
+        panic!("Twirled channel should be a pauli channel");
+    };
+
+    assert!((x - gamma / 4.0).abs() < 1e-12);
+    assert!((y - gamma / 4.0).abs() < 1e-12);
+    assert!((z - (1.0 - gamma / 2.0 - (1.0 - gamma).sqrt()) / 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn twirled_shouldnotchange_withpaulichannels() {
+    for channel in [
+        NoiseChannel::BitFlip(0.1),
+        NoiseChannel::PhaseFlip(0.1),
+        NoiseChannel::Depolarizing(0.1),
+        NoiseChannel::Pauli(0.1, 0.0, 0.2),
+    ] {
+        assert_eq!(channel, channel.twirled());
+    }
+}
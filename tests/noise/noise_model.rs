@@ -0,0 +1,86 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::{
+    noise::types::{
+        decoherence_model::DecoherenceModel, noise_channel::NoiseChannel, noise_model::NoiseModel,
+    },
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+#[test]
+fn run_shouldmatchnoiselesscircuit_withoutchannels() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 1);
+    let mut noiseless = StateVector::new(2);
+    noiseless.apply_circuit(&circuit);
+
+    let mut noisy = StateVector::new(2);
+    NoiseModel::new().run(&circuit, &mut noisy);
+
+    assert_eq!(noiseless, noisy);
+}
+
+#[test]
+fn runwith_shouldrepeatnoise_withsameseed() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 1);
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::Depolarizing(0.3))
+        .with_decoherence(DecoherenceModel::new(10.0, 8.0, 1.0));
+
+    for seed in 0..20 {
+        let (mut first, mut second) = (StateVector::new(2), StateVector::new(2));
+        noise_model.run_with(&circuit, &mut first, &mut StdRng::seed_from_u64(seed));
+        noise_model.run_with(&circuit, &mut second, &mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(first.amplitudes, second.amplitudes);
+    }
+}
+
+#[test]
+fn run_shouldapplygatenoise_aftereverygate() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::NOT, 0)
+        .add_gate(&QuantumGate::NOT, 0);
+    let mut state_vector = StateVector::new(1);
+
+    NoiseModel::new()
+        .with_gate_noise(NoiseChannel::BitFlip(1.0))
+        .run(&circuit, &mut state_vector);
+
+    // Two NOT gates and two certain bit flips return to |0>.
+    assert_eq!(1.0, state_vector.amplitudes[0].norm_sqr());
+}
+
+#[test]
+fn fromnoisycircuit_shouldflipmeasurements_withmeasurementnoise() {
+    let circuit = QuantumCircuit::new(1);
+    let noise_model = NoiseModel::new().with_measurement_noise(NoiseChannel::BitFlip(0.2));
+
+    let outcome = SimulationOutcome::from_noisy_circuit(&circuit, 2000, &noise_model);
+
+    assert!((outcome.probability(0) - 0.8).abs() < 0.05);
+}
+
+#[test]
+fn twirled_shouldtwirleverychannel() {
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::OverRotation(0.2))
+        .with_measurement_noise(NoiseChannel::BitFlip(0.1))
+        .with_decoherence(DecoherenceModel::new(100.0, 80.0, 0.05))
+        .twirled();
+
+    assert_eq!(
+        vec![NoiseChannel::OverRotation(0.2).twirled()],
+        noise_model.gate_noise
+    );
+    assert_eq!(
+        vec![NoiseChannel::BitFlip(0.1)],
+        noise_model.measurement_noise
+    );
+    assert!(noise_model.decoherence.unwrap().twirled);
+}
@@ -0,0 +1,62 @@
+use rquant::{
+    noise::types::{noise_model::NoiseModel, readout_error::ReadoutError},
+    quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, qubit::Qubit},
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+#[test]
+fn measure_shouldalwaysmisread_withcertainerror() {
+    let readout_error = ReadoutError::new(1.0, 1.0);
+
+    assert!(!readout_error.measure(&Qubit::zero()));
+    assert!(readout_error.measure(&Qubit::one()));
+}
+
+#[test]
+fn read_shouldonlymisreadone_withasymmetricerror() {
+    let readout_error = ReadoutError::new(0.0, 1.0);
+
+    assert!(readout_error.read(true));
+    assert!(readout_error.read(false));
+}
+
+#[test]
+fn confusionmatrix_shouldhavecolumnsthatsumtoone() {
+    let matrix = ReadoutError::new(0.1, 0.3).confusion_matrix();
+
+    assert!((matrix[0][0] + matrix[1][0] - 1.0).abs() < 1e-12);
+    assert!((matrix[0][1] + matrix[1][1] - 1.0).abs() < 1e-12);
+    assert!((matrix[1][0] - 0.1).abs() < 1e-12);
+}
+
+#[test]
+fn mitigate_shouldinvertexpectedreadout_withtwoqubits() {
+    let readout_error = ReadoutError::new(0.1, 0.2);
+    // The expected readout of |01>, where the second qubit reads |0> with probability 0.2.
+    let measured = [0.9 * 0.2, 0.9 * 0.8, 0.1 * 0.2, 0.1 * 0.8];
+
+    let mitigated = readout_error.mitigate(&measured);
+
+    assert!((mitigated[0b01] - 1.0).abs() < 1e-12);
+    assert!(mitigated[0b00].abs() < 1e-12);
+}
+
+#[test]
+fn mitigate_shouldcorrectsampledoutcome() {
+    let readout_error = ReadoutError::new(0.1, 0.1);
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+    let noise_model = NoiseModel::new().with_readout_error(readout_error);
+
+    let outcome = SimulationOutcome::from_noisy_circuit(&circuit, 4000, &noise_model);
+    let measured = outcome.marginal_probabilities(&[0]);
+    let mitigated = readout_error.mitigate(&measured);
+
+    assert!((measured[1] - 0.9).abs() < 0.05);
+    assert!((mitigated[1] - 1.0).abs() < 0.05);
+}
+
+#[test]
+#[should_panic]
+fn mitigate_shouldpanic_withuninvertiblereadout() {
+    ReadoutError::new(0.5, 0.5).mitigate(&[0.5, 0.5]);
+}
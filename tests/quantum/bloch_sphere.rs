@@ -0,0 +1,39 @@
+use rquant::quantum::types::{
+    bloch_sphere::BlochSphere, quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+    qubit::Qubit,
+};
+
+#[test]
+fn display_shouldplacemarker_atpoleofzeroqubit() {
+    let displayed = Qubit::zero().draw_bloch().to_string();
+    let rows: Vec<&str> = displayed.lines().collect();
+
+    assert_eq!("|0〉", rows[0].trim());
+    assert_eq!("····●····", rows[1].trim());
+    assert_eq!("|1〉", rows[12].trim());
+    assert_eq!("x: 0.00  y: 0.00  z: 1.00  (● near, ○ far)", rows[13]);
+}
+
+#[test]
+fn display_shouldplacemarker_onequatorofsuperposition() {
+    let displayed = Qubit::zero()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .draw_bloch()
+        .to_string();
+
+    let equator = displayed.lines().nth(6).unwrap();
+    assert!(equator.starts_with("|-〉·"));
+    assert!(equator.ends_with("● |+〉"));
+}
+
+#[test]
+fn display_shouldusefarmarker_withnegativey() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::new(QuantumOperator::RX(1.0)));
+
+    assert!(qubit.draw_bloch().to_string().contains('○'));
+    assert!(!BlochSphere::new([0.0, 1.0, 0.0])
+        .to_string()
+        .lines()
+        .take(13)
+        .any(|row| row.contains('○')));
+}
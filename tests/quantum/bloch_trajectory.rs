@@ -0,0 +1,29 @@
+use rquant::quantum::types::bloch_trajectory::BlochTrajectory;
+
+#[test]
+fn tojson_shouldexportframes() {
+    let trajectory = BlochTrajectory {
+        frames: vec![[0.0, 0.0, 1.0], [1.0, 0.0, 0.0]],
+    };
+
+    assert_eq!(
+        "[{\"frame\":0,\"x\":0,\"y\":0,\"z\":1},{\"frame\":1,\"x\":1,\"y\":0,\"z\":0}]",
+        trajectory.to_json()
+    );
+}
+
+#[test]
+fn tojson_shouldexportemptyarray_withoutframes() {
+    let trajectory = BlochTrajectory { frames: vec![] };
+
+    assert_eq!("[]", trajectory.to_json());
+}
+
+#[test]
+fn tocsv_shouldexportheaderandframes() {
+    let trajectory = BlochTrajectory {
+        frames: vec![[0.0, 0.0, 1.0], [0.0, 1.0, 0.0]],
+    };
+
+    assert_eq!("frame,x,y,z\n0,0,0,1\n1,0,1,0\n", trajectory.to_csv());
+}
@@ -0,0 +1,48 @@
+use std::f64::consts::FRAC_PI_2;
+
+use rquant::{
+    circuit,
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+};
+
+#[test]
+fn circuit_shouldmatchaddgate_withliteraltargets() {
+    let expected = QuantumCircuit::new(3)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(FRAC_PI_2)), 1)
+        .add_gate(&QuantumGate::NOT, 2);
+
+    assert_eq!(expected, circuit!(3; h 0, rz(FRAC_PI_2) 1, x 2));
+}
+
+#[test]
+fn circuit_shouldacceptruntimetargets_andqubitcounts() {
+    let (num_qubits, target) = (2, 1);
+
+    let circuit = circuit!(num_qubits; ry(0.5) target, z {target - 1},);
+
+    assert_eq!(2, circuit.num_qubits);
+    assert_eq!(1, circuit.operations[0].target);
+    assert_eq!(QuantumOperator::PHASE, circuit.operations[1].operator);
+}
+
+#[test]
+fn circuit_shouldbeempty_withoutgates() {
+    assert!(circuit!(4;).is_empty());
+}
+
+#[test]
+#[should_panic]
+fn circuit_shouldpanic_withruntimetargetoutofrange() {
+    let target = 2;
+    circuit!(2; h target);
+}
+
+#[test]
+#[should_panic]
+fn circuit_shouldpanic_withunknowngate() {
+    circuit!(1; ccx 0);
+}
@@ -0,0 +1,119 @@
+use rquant::quantum::types::{
+    clifford_tableau::CliffordTableau, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator,
+};
+use std::f64::consts::FRAC_PI_2;
+
+fn stabilizer_strings(tableau: &CliffordTableau) -> Vec<String> {
+    tableau
+        .stabilizers()
+        .iter()
+        .map(|stabilizer| stabilizer.to_string())
+        .collect()
+}
+
+#[test]
+fn new_shouldstabilizezerostate() {
+    let tableau = CliffordTableau::new(2);
+
+    assert_eq!(vec!["+ZI", "+IZ"], stabilizer_strings(&tableau));
+}
+
+#[test]
+fn applygate_shouldflipstabilizersign_withnotgate() {
+    let mut tableau = CliffordTableau::new(1);
+
+    tableau.apply_gate(&QuantumGate::NOT, 0);
+
+    assert_eq!(vec!["-Z"], stabilizer_strings(&tableau));
+}
+
+#[test]
+fn applygate_shouldstabilizeplusstate_withsuperpositiongate() {
+    let mut tableau = CliffordTableau::new(1);
+
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    assert_eq!(vec!["+X"], stabilizer_strings(&tableau));
+}
+
+#[test]
+fn applygate_shouldrotateplusstate_withquarterturns() {
+    let mut tableau = CliffordTableau::new(1);
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    tableau.apply_gate(&QuantumGate::new(QuantumOperator::RZ(FRAC_PI_2)), 0);
+    assert_eq!(vec!["+Y"], stabilizer_strings(&tableau));
+
+    tableau.apply_gate(&QuantumGate::new(QuantumOperator::RZ(FRAC_PI_2)), 0);
+    assert_eq!(vec!["-X"], stabilizer_strings(&tableau));
+}
+
+#[test]
+fn applygate_shouldmatchsuperposition_withryquarterturn() {
+    let mut tableau = CliffordTableau::new(1);
+
+    tableau.apply_gate(&QuantumGate::new(QuantumOperator::RY(FRAC_PI_2)), 0);
+
+    assert_eq!(vec!["+X"], stabilizer_strings(&tableau));
+}
+
+#[test]
+#[should_panic]
+fn applygate_shouldpanic_withnoncliffordrotation() {
+    let mut tableau = CliffordTableau::new(1);
+
+    tableau.apply_gate(&QuantumGate::new(QuantumOperator::RX(0.3)), 0);
+}
+
+#[test]
+fn applycnot_shouldcreatebellstate() {
+    let mut tableau = CliffordTableau::new(2);
+
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    tableau.apply_cnot(0, 1);
+
+    assert_eq!(vec!["+XX", "+ZZ"], stabilizer_strings(&tableau));
+}
+
+#[test]
+fn measurementoutcome_shouldbecertain_withbasisstates() {
+    let mut tableau = CliffordTableau::new(2);
+    tableau.apply_gate(&QuantumGate::NOT, 1);
+
+    assert_eq!(Some(true), tableau.measurement_outcome(0));
+    assert_eq!(Some(false), tableau.measurement_outcome(1));
+}
+
+#[test]
+fn measurementoutcome_shouldberandom_withbellstate() {
+    let mut tableau = CliffordTableau::new(2);
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    tableau.apply_cnot(0, 1);
+
+    assert_eq!(None, tableau.measurement_outcome(0));
+    assert_eq!(None, tableau.measurement_outcome(1));
+}
+
+#[test]
+fn measurementoutcome_shouldfollowsign_throughentanglinggates() {
+    let mut tableau = CliffordTableau::new(3);
+    tableau.apply_gate(&QuantumGate::NOT, 0);
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    tableau.apply_cnot(0, 2);
+    tableau.apply_cnot(1, 2);
+    tableau.apply_cnot(1, 2);
+
+    // The target holds the parity of a flipped qubit and a superposition that was cancelled out.
+    assert_eq!(Some(false), tableau.measurement_outcome(2));
+}
+
+#[test]
+fn display_shouldshowdestabilizersandstabilizers() {
+    let mut tableau = CliffordTableau::new(2);
+
+    tableau.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    tableau.apply_cnot(0, 1);
+
+    assert_eq!("+ZI\n+IX\n───\n+XX\n+ZZ", tableau.to_string());
+}
@@ -0,0 +1,61 @@
+use rquant::{
+    quantum::types::{
+        clone_audit::CloneAudit, quantum_gate::QuantumGate, state_vector::StateVector,
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+fn bell_state() -> StateVector {
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state_vector.apply_cnot(0, 1);
+    state_vector
+}
+
+#[test]
+fn flagged_shouldcountclone_withentangledstate() {
+    let state_vector = bell_state();
+
+    CloneAudit::enable();
+    let _clone = state_vector.clone();
+    CloneAudit::disable();
+
+    assert_eq!(1, CloneAudit::flagged());
+}
+
+#[test]
+fn flagged_shouldnotcountclone_withproductstate() {
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    CloneAudit::enable();
+    let _clone = state_vector.clone();
+    CloneAudit::disable();
+
+    assert_eq!(0, CloneAudit::flagged());
+}
+
+#[test]
+fn flagged_shouldnotcountclone_whendisabled() {
+    let state_vector = bell_state();
+
+    CloneAudit::enable();
+    CloneAudit::disable();
+    let _clone = state_vector.clone();
+
+    assert_eq!(0, CloneAudit::flagged());
+}
+
+#[test]
+fn flagged_shouldnotcountclones_whenexecutingqasmstream() {
+    let program = "qreg q[2];\ncreg c[2];\nh q[0];\ncx q[0], q[1];\nmeasure q -> c;\n";
+
+    CloneAudit::enable();
+    let outcome =
+        SimulationOutcome::execute_qasm_stream(program.as_bytes(), &StateVector::new(2), 100)
+            .unwrap();
+    CloneAudit::disable();
+
+    assert_eq!(100, outcome.len());
+    assert_eq!(0, CloneAudit::flagged());
+}
@@ -0,0 +1,107 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rquant::{
+    quantum::types::{
+        compact_state_vector::CompactStateVector, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, state_vector::StateVector,
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+#[test]
+fn new_shouldusehalfthememory_ofstatevector() {
+    let compact_state_vector = CompactStateVector::new(4);
+
+    assert_eq!(
+        std::mem::size_of_val(StateVector::new(4).amplitudes.as_slice()) / 2,
+        std::mem::size_of_val(compact_state_vector.amplitudes.as_slice())
+    );
+    assert_eq!(1.0, compact_state_vector.amplitudes[0].re);
+}
+
+#[test]
+fn applygate_shouldmatchstatevector_withinsingleprecision() {
+    let mut rng = rand::rng();
+    let mut compact_state_vector = CompactStateVector::new(4);
+    let mut state_vector = StateVector::new(4);
+
+    for _ in 0..50 {
+        let (first, second) = (rng.random_range(0..4), rng.random_range(0..4));
+        let gate = QuantumGate::new(QuantumOperator::RY(rng.random_range(0.0..6.0)));
+        compact_state_vector.apply_gate(&gate, first);
+        state_vector.apply_gate(&gate, first);
+        compact_state_vector.apply_gate(&QuantumGate::ROTATE, second);
+        state_vector.apply_gate(&QuantumGate::ROTATE, second);
+        if first != second {
+            compact_state_vector.apply_cnot(first, second);
+            state_vector.apply_cnot(first, second);
+            compact_state_vector.apply_cz(second, first);
+            state_vector.apply_cz(second, first);
+        }
+    }
+
+    let widened = compact_state_vector.to_state_vector();
+    for (actual, expected) in widened.amplitudes.iter().zip(&state_vector.amplitudes) {
+        assert!((actual - expected).norm() < 1e-5);
+    }
+}
+
+#[test]
+fn fromstatevector_shouldroundtrip_withinsingleprecision() {
+    let state_vector = StateVector::ghz(3);
+
+    let widened = CompactStateVector::from_state_vector(&state_vector).to_state_vector();
+
+    assert!((widened.inner_product(&state_vector).norm() - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn measure_shouldcorrelatebellstate() {
+    let mut compact_state_vector = CompactStateVector::new(2);
+    compact_state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    compact_state_vector.apply_cnot(0, 1);
+
+    let first = compact_state_vector.measure(0);
+
+    assert_eq!(first, compact_state_vector.measure(1));
+    assert!(
+        (compact_state_vector
+            .amplitudes
+            .iter()
+            .map(|a| a.norm_sqr())
+            .sum::<f32>()
+            - 1.0)
+            .abs()
+            < 1e-6
+    );
+}
+
+#[test]
+fn executeqasmstream_shouldacceptcompactbackend() {
+    let program = "qreg q[2];\ncreg c[2];\nx q[0];\ncx q[0], q[1];\nmeasure q -> c;\n";
+
+    let outcome =
+        SimulationOutcome::execute_qasm_stream(program.as_bytes(), &CompactStateVector::new(2), 5)
+            .unwrap();
+
+    assert!(outcome.shots.iter().all(|shot| shot == &vec![false, false]));
+}
+
+#[test]
+fn measurewith_shouldrepeatoutcomes_withsameseed() {
+    let measure = |seed| {
+        let mut state = CompactStateVector::new(3);
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..3)
+            .map(|target| {
+                state.apply_gate(&QuantumGate::SUPERPOSITION, target);
+                state.measure_with(target, &mut rng)
+            })
+            .collect::<Vec<bool>>()
+    };
+
+    let outcomes: Vec<Vec<bool>> = (0..20).map(measure).collect();
+
+    assert_eq!(outcomes, (0..20).map(measure).collect::<Vec<Vec<bool>>>());
+    assert!(outcomes.iter().flatten().any(|&value| value));
+    assert!(outcomes.iter().flatten().any(|&value| !value));
+}
@@ -0,0 +1,48 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+use rquant::quantum::types::{
+    cost_model::CostModel, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator,
+};
+
+fn circuit() -> QuantumCircuit {
+    QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(FRAC_PI_4)), 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(FRAC_PI_2)), 1)
+        .add_gate(&QuantumGate::NOT, 1)
+}
+
+#[test]
+fn cost_shouldcountgates_withgatecountmodel() {
+    assert_eq!(4.0, circuit().cost(&CostModel::gate_count()));
+}
+
+#[test]
+fn cost_shouldcountnoncliffordgates_withtcountmodel() {
+    assert_eq!(1.0, circuit().cost(&CostModel::t_count()));
+}
+
+#[test]
+fn cost_shouldusegateweights_withdefaultforothergates() {
+    let cost_model = CostModel::new(0.5)
+        .with_weight("rz", 3.0)
+        .with_non_clifford_weight(10.0);
+
+    assert_eq!(0.5 + 13.0 + 3.0 + 0.5, circuit().cost(&cost_model));
+}
+
+#[test]
+fn cost_shouldaddtwoqubitweight_withcontrolledgates() {
+    let circuit =
+        QuantumCircuit::new(3)
+            .add_cnot(0, 1)
+            .add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2);
+    let cost_model = CostModel::gate_count()
+        .with_weight("cx", 2.0)
+        .with_two_qubit_weight(10.0)
+        .with_non_clifford_weight(100.0);
+
+    // The Toffoli gate is not a Clifford gate, and has no weight of it's own.
+    assert_eq!(2.0 + 10.0 + 1.0 + 10.0 + 100.0, circuit.cost(&cost_model));
+}
@@ -0,0 +1,122 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use rquant::quantum::types::{
+    decision_diagram::DecisionDiagram, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator, state_vector::StateVector,
+};
+
+fn assert_matches_state_vector(decision_diagram: &DecisionDiagram, state_vector: &StateVector) {
+    let expanded = decision_diagram.to_state_vector();
+    for (actual, expected) in expanded.amplitudes.iter().zip(&state_vector.amplitudes) {
+        assert!((actual - expected).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn new_shouldstartinzerostate() {
+    let decision_diagram = DecisionDiagram::new(3);
+
+    assert_eq!(1.0, decision_diagram.amplitude(0).re);
+    assert_eq!(0.0, decision_diagram.amplitude(5).norm());
+    assert_eq!(4, decision_diagram.node_count());
+}
+
+#[test]
+fn applygate_shouldmatchstatevector_withrandomcircuit() {
+    let mut rng = rand::rng();
+    let mut decision_diagram = DecisionDiagram::new(4);
+    let mut state_vector = StateVector::new(4);
+
+    for _ in 0..40 {
+        let (first, second) = (rng.random_range(0..4), rng.random_range(0..4));
+        match rng.random_range(0..4) {
+            0 => {
+                let gate = QuantumGate::new(QuantumOperator::RY(rng.random_range(0.0..6.0)));
+                decision_diagram.apply_gate(&gate, first);
+                state_vector.apply_gate(&gate, first);
+            }
+            1 => {
+                decision_diagram.apply_gate(&QuantumGate::ROTATE, first);
+                state_vector.apply_gate(&QuantumGate::ROTATE, first);
+            }
+            2 if first != second => {
+                decision_diagram.apply_cnot(first, second);
+                state_vector.apply_cnot(first, second);
+            }
+            3 if first != second => {
+                decision_diagram.apply_cz(first, second);
+                state_vector.apply_cz(first, second);
+            }
+            _ => {
+                decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, first);
+                state_vector.apply_gate(&QuantumGate::SUPERPOSITION, first);
+            }
+        }
+    }
+
+    assert_matches_state_vector(&decision_diagram, &state_vector);
+}
+
+#[test]
+fn applycnot_shouldstaysmall_withlargeghzstate() {
+    let mut decision_diagram = DecisionDiagram::new(60);
+
+    decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    for target in 1..60 {
+        decision_diagram.apply_cnot(target - 1, target);
+    }
+
+    // Both branches of a GHZ state are a chain, so the diagram only grows linearly.
+    assert!(decision_diagram.node_count() <= 2 * 60);
+    assert!((decision_diagram.amplitude(0).norm_sqr() - 0.5).abs() < 1e-9);
+    assert!((decision_diagram.amplitude(usize::MAX >> 4).norm_sqr() - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn applygate_shouldsharenodes_withuniformsuperposition() {
+    let mut decision_diagram = DecisionDiagram::new(40);
+
+    for target in 0..40 {
+        decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, target);
+    }
+
+    assert_eq!(41, decision_diagram.node_count());
+}
+
+#[test]
+fn measure_shouldcollapsestate() {
+    let mut decision_diagram = DecisionDiagram::new(3);
+    decision_diagram.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    decision_diagram.apply_cnot(0, 2);
+
+    let is_zero = decision_diagram.measure(2);
+
+    let index = if is_zero { 0b000 } else { 0b101 };
+    assert!((decision_diagram.amplitude(index).norm() - 1.0).abs() < 1e-9);
+    assert_eq!(is_zero, decision_diagram.measure(0));
+}
+
+#[test]
+#[should_panic]
+fn applygate_shouldpanic_withinvalidtarget() {
+    DecisionDiagram::new(2).apply_gate(&QuantumGate::NOT, 2);
+}
+
+#[test]
+fn measurewith_shouldrepeatoutcomes_withsameseed() {
+    let measure = |seed| {
+        let mut state = DecisionDiagram::new(3);
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..3)
+            .map(|target| {
+                state.apply_gate(&QuantumGate::SUPERPOSITION, target);
+                state.measure_with(target, &mut rng)
+            })
+            .collect::<Vec<bool>>()
+    };
+
+    let outcomes: Vec<Vec<bool>> = (0..20).map(measure).collect();
+
+    assert_eq!(outcomes, (0..20).map(measure).collect::<Vec<Vec<bool>>>());
+    assert!(outcomes.iter().flatten().any(|&value| value));
+    assert!(outcomes.iter().flatten().any(|&value| !value));
+}
@@ -0,0 +1,78 @@
+use num_complex::Complex;
+use rquant::quantum::types::{
+    density_matrix::DensityMatrix, quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+    qubit::Qubit, state_vector::StateVector,
+};
+
+#[test]
+fn fromstatevector_shouldbeouterproduct() {
+    let density_matrix = DensityMatrix::from_state_vector(&StateVector::ghz(2));
+
+    assert!((density_matrix.elements[0b00][0b11].re - 0.5).abs() < 1e-12);
+    assert!(density_matrix.elements[0b01][0b01].norm() < 1e-12);
+}
+
+#[test]
+fn purity_shouldbeone_withpurestate() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+    let density_matrix = DensityMatrix::from_qubit(&qubit);
+
+    assert!((density_matrix.trace() - 1.0).abs() < 1e-12);
+    assert!((density_matrix.purity() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn fidelity_shouldmatchstatevector_withpurestates() {
+    let mut a = StateVector::ghz(2);
+    a.apply_gate(&QuantumGate::new(QuantumOperator::RX(0.7)), 0);
+    let mut b = StateVector::new(2);
+    b.apply_gate(&QuantumGate::new(QuantumOperator::RY(1.1)), 1);
+    b.apply_gate(&QuantumGate::new(QuantumOperator::RX(0.3)), 0);
+
+    let fidelity =
+        DensityMatrix::from_state_vector(&a).fidelity(&DensityMatrix::from_state_vector(&b));
+    let trace_distance =
+        DensityMatrix::from_state_vector(&a).trace_distance(&DensityMatrix::from_state_vector(&b));
+
+    assert!((fidelity - a.fidelity(&b)).abs() < 1e-6);
+    assert!((trace_distance - a.trace_distance(&b)).abs() < 1e-9);
+}
+
+#[test]
+fn fidelity_shouldmatchclassicalformula_withdiagonalstates() {
+    let diagonal = |p: f64| DensityMatrix {
+        num_qubits: 1,
+        elements: vec![
+            vec![Complex::new(p, 0.0), Complex::new(0.0, 0.0)],
+            vec![Complex::new(0.0, 0.0), Complex::new(1.0 - p, 0.0)],
+        ],
+    };
+    let (a, b) = (diagonal(0.2), diagonal(0.7));
+
+    let expected = ((0.2f64 * 0.7).sqrt() + (0.8f64 * 0.3).sqrt()).powi(2);
+    assert!((a.fidelity(&b) - expected).abs() < 1e-9);
+    assert!((a.trace_distance(&b) - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn tracedistance_shouldbehalf_betweenmaximallymixedandpure() {
+    let mixed = DensityMatrix {
+        num_qubits: 1,
+        elements: vec![
+            vec![Complex::new(0.5, 0.0), Complex::new(0.0, 0.0)],
+            vec![Complex::new(0.0, 0.0), Complex::new(0.5, 0.0)],
+        ],
+    };
+    let plus = DensityMatrix::from_qubit(&Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION));
+
+    assert!((mixed.fidelity(&plus) - 0.5).abs() < 1e-9);
+    assert!((mixed.trace_distance(&plus) - 0.5).abs() < 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn fidelity_shouldpanic_withdifferentamountsofqubits() {
+    DensityMatrix::from_state_vector(&StateVector::new(1))
+        .fidelity(&DensityMatrix::from_state_vector(&StateVector::new(2)));
+}
@@ -0,0 +1,38 @@
+use rquant::quantum::types::dual_number::DualNumber;
+
+#[test]
+fn variable_shouldhaveunitderivative_forownindex() {
+    let dual_number = DualNumber::variable(2.0, 1, 3);
+
+    assert_eq!(2.0, dual_number.value);
+    assert_eq!(vec![0.0, 1.0, 0.0], dual_number.derivatives);
+}
+
+#[test]
+fn mul_shouldapplyproductrule() {
+    let x = DualNumber::variable(3.0, 0, 1);
+
+    let result = &x * &x;
+
+    assert_eq!(9.0, result.value);
+    assert_eq!(vec![6.0], result.derivatives);
+}
+
+#[test]
+fn sin_shouldhavecosinederivative() {
+    let x = DualNumber::variable(0.3, 0, 1);
+
+    let result = x.sin();
+
+    assert_eq!(0.3_f64.sin(), result.value);
+    assert_eq!(vec![0.3_f64.cos()], result.derivatives);
+}
+
+#[test]
+fn addandsub_shouldcombinederivatives() {
+    let x = DualNumber::variable(1.0, 0, 2);
+    let y = DualNumber::variable(2.0, 1, 2);
+
+    assert_eq!(vec![1.0, 1.0], (&x + &y).derivatives);
+    assert_eq!(vec![1.0, -1.0], (&x - &y).derivatives);
+}
@@ -0,0 +1,63 @@
+use rquant::quantum::types::{
+    quantum_gate::QuantumGate, quantum_operators::QuantumOperator, qubit::Qubit,
+    state_vector::StateVector,
+};
+
+#[test]
+fn record_shouldremembereverygate() {
+    let recording = Qubit::zero()
+        .record()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .apply_gate(&QuantumGate::PHASE);
+
+    assert_eq!(
+        vec![QuantumOperator::SUPERPOSITION, QuantumOperator::PHASE],
+        recording.operators
+    );
+}
+
+#[test]
+fn replayon_shouldmatchrecordedqubit_withsamestart() {
+    let recording = Qubit::zero()
+        .record()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .apply_gate(&QuantumGate::new(QuantumOperator::RZ(0.3)));
+
+    let replayed = recording.replay_on(&Qubit::zero());
+
+    assert_eq!(
+        recording.qubit.initial_position(),
+        replayed.initial_position()
+    );
+    assert_eq!(
+        recording.qubit.possible_position(),
+        replayed.possible_position()
+    );
+}
+
+#[test]
+fn replayon_shouldflipotherqubit_withnotgate() {
+    let recording = Qubit::zero().record().apply_gate(&QuantumGate::NOT);
+
+    assert_eq!(Qubit::zero(), recording.replay_on(&Qubit::one()));
+}
+
+#[test]
+fn tocircuit_shouldrunrecordedgates() {
+    let recording = Qubit::zero()
+        .record()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .apply_gate(&QuantumGate::NOT);
+    let mut state_vector = StateVector::new(1);
+
+    state_vector.apply_circuit(&recording.to_circuit());
+
+    assert_eq!(
+        recording.qubit.initial_position(),
+        state_vector.amplitudes[0]
+    );
+    assert_eq!(
+        recording.qubit.possible_position(),
+        state_vector.amplitudes[1]
+    );
+}
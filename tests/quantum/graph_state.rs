@@ -0,0 +1,55 @@
+use rquant::quantum::types::graph_state::GraphState;
+
+#[test]
+fn new_shouldcreatesymmetricadjacency() {
+    let graph_state = GraphState::new(3, &[(0, 1)]);
+
+    assert!(graph_state.adjacency[0][1]);
+    assert!(graph_state.adjacency[1][0]);
+    assert!(!graph_state.adjacency[1][2]);
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withselfloop() {
+    GraphState::new(2, &[(1, 1)]);
+}
+
+#[test]
+fn tableau_shouldhavegraphstabilizers() {
+    let graph_state = GraphState::new(3, &[(0, 1), (1, 2), (0, 2)]);
+
+    let stabilizers: Vec<String> = graph_state
+        .tableau()
+        .stabilizers()
+        .iter()
+        .map(|stabilizer| stabilizer.to_string())
+        .collect();
+
+    assert_eq!(vec!["+XZZ", "+ZXZ", "+ZZX"], stabilizers);
+}
+
+#[test]
+fn localcomplement_shouldturnlineintotriangle() {
+    let line = GraphState::new(3, &[(0, 1), (1, 2)]);
+
+    let result = line.local_complement(1);
+
+    assert_eq!(GraphState::new(3, &[(0, 1), (1, 2), (0, 2)]), result);
+}
+
+#[test]
+fn islocalcliffordequivalent_shouldbetrue_forlineandtriangle() {
+    let line = GraphState::new(3, &[(0, 1), (1, 2)]);
+    let triangle = GraphState::new(3, &[(0, 1), (1, 2), (0, 2)]);
+
+    assert!(line.is_local_clifford_equivalent(&triangle));
+}
+
+#[test]
+fn islocalcliffordequivalent_shouldbefalse_fordifferententanglement() {
+    let disconnected = GraphState::new(3, &[]);
+    let line = GraphState::new(3, &[(0, 1), (1, 2)]);
+
+    assert!(!disconnected.is_local_clifford_equivalent(&line));
+}
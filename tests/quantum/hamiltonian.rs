@@ -0,0 +1,247 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::quantum::types::{
+    hamiltonian::Hamiltonian, observable::Observable, pauli::Pauli, pauli_string::PauliString,
+    quantum_gate::QuantumGate, qubit_register::QubitRegister, state_vector::StateVector,
+};
+
+fn pauli_string(paulis: &[Pauli]) -> PauliString {
+    PauliString {
+        negative: false,
+        paulis: paulis.to_vec(),
+    }
+}
+
+#[test]
+fn expectation_shouldweighteachterm() {
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (2.0, pauli_string(&[Pauli::Z, Pauli::Z])),
+            (0.5, pauli_string(&[Pauli::X, Pauli::X])),
+        ],
+    );
+
+    let energy = hamiltonian.expectation(&StateVector::ghz(2));
+
+    assert!((energy - 2.5).abs() < 1e-12);
+}
+
+#[test]
+fn expectation_shouldbezero_withoutterms() {
+    let hamiltonian = Hamiltonian::new(1, &[]);
+
+    assert_eq!(0.0, hamiltonian.expectation(&StateVector::new(1)));
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withwrongtermlength() {
+    Hamiltonian::new(2, &[(1.0, pauli_string(&[Pauli::Z]))]);
+}
+
+#[test]
+fn matrix_shouldsumweightedpaulimatrices() {
+    let hamiltonian = Hamiltonian::new(
+        1,
+        &[
+            (2.0, pauli_string(&[Pauli::I])),
+            (-1.0, pauli_string(&[Pauli::X])),
+        ],
+    );
+
+    let matrix = hamiltonian.matrix();
+
+    assert_eq!(2.0, matrix[0][0].re);
+    assert_eq!(-1.0, matrix[0][1].re);
+    assert_eq!(-1.0, matrix[1][0].re);
+    assert_eq!(2.0, matrix[1][1].re);
+}
+
+#[test]
+fn measurementgroups_shouldgroupqubitwisecommutingterms() {
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (1.0, pauli_string(&[Pauli::Z, Pauli::Z])),
+            (0.5, pauli_string(&[Pauli::X, Pauli::X])),
+            (0.3, pauli_string(&[Pauli::Z, Pauli::I])),
+            (0.2, pauli_string(&[Pauli::I, Pauli::X])),
+            (0.1, pauli_string(&[Pauli::Y, Pauli::Y])),
+        ],
+    );
+
+    let groups = hamiltonian.measurement_groups();
+
+    assert_eq!(3, groups.len());
+    assert_eq!(
+        vec![1.0, 0.3],
+        groups[0].terms.iter().map(|t| t.0).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![0.5, 0.2],
+        groups[1].terms.iter().map(|t| t.0).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![0.1],
+        groups[2].terms.iter().map(|t| t.0).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn measurementcircuit_shouldrotateintopaulibasis() {
+    let group = Hamiltonian::new(3, &[(1.0, pauli_string(&[Pauli::X, Pauli::Y, Pauli::Z]))]);
+    let mut state_vector = StateVector::ghz(3);
+    let exact = group.expectation(&state_vector);
+
+    state_vector.apply_circuit(&group.measurement_circuit());
+    let rotated = Hamiltonian::new(3, &[(1.0, pauli_string(&[Pauli::Z, Pauli::Z, Pauli::Z]))]);
+
+    assert!((exact - rotated.expectation(&state_vector)).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn measurementcircuit_shouldpanic_withnoncommutingterms() {
+    Hamiltonian::new(
+        1,
+        &[
+            (1.0, pauli_string(&[Pauli::X])),
+            (1.0, pauli_string(&[Pauli::Z])),
+        ],
+    )
+    .measurement_circuit();
+}
+
+#[test]
+fn sampledexpectation_shouldapproachexpectation() {
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (1.0, pauli_string(&[Pauli::Z, Pauli::Z])),
+            (0.5, pauli_string(&[Pauli::X, Pauli::X])),
+            (-0.7, pauli_string(&[Pauli::Y, Pauli::Y])),
+            (0.4, pauli_string(&[Pauli::Z, Pauli::I])),
+        ],
+    );
+    let state_vector = StateVector::ghz(2);
+
+    let sampled = hamiltonian.sampled_expectation(&state_vector, 2000);
+
+    assert!((sampled - hamiltonian.expectation(&state_vector)).abs() < 0.1);
+}
+
+#[test]
+fn sampledexpectationwith_shouldrepeatestimate_withsameseed() {
+    let hamiltonian = Hamiltonian::new(
+        2,
+        &[
+            (1.0, pauli_string(&[Pauli::Z, Pauli::Z])),
+            (0.5, pauli_string(&[Pauli::Z, Pauli::I])),
+        ],
+    );
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    let first =
+        hamiltonian.sampled_expectation_with(&state_vector, 100, &mut StdRng::seed_from_u64(5));
+    let second =
+        hamiltonian.sampled_expectation_with(&state_vector, 100, &mut StdRng::seed_from_u64(5));
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn parse_shouldreadweightedterms() {
+    let observable = Observable::parse("0.5*ZZ + 0.3*XI");
+
+    assert_eq!(
+        Hamiltonian::new(
+            2,
+            &[
+                (0.5, pauli_string(&[Pauli::Z, Pauli::Z])),
+                (0.3, pauli_string(&[Pauli::X, Pauli::I])),
+            ],
+        ),
+        observable
+    );
+}
+
+#[test]
+fn parse_shouldreadsignsandimplicitcoefficients() {
+    let observable = Observable::parse("-ZZ - 2.5e-1 YI + IX");
+
+    let coefficients: Vec<f64> = observable.terms.iter().map(|(c, _)| *c).collect();
+    assert_eq!(vec![-1.0, -0.25, 1.0], coefficients);
+    assert_eq!(vec![Pauli::Y, Pauli::I], observable.terms[1].1.paulis);
+}
+
+#[test]
+#[should_panic]
+fn parse_shouldpanic_withinvalidpauli() {
+    Observable::parse("0.5*ZA");
+}
+
+#[test]
+#[should_panic]
+fn parse_shouldpanic_withmismatchedtermlengths() {
+    Observable::parse("ZZ + X");
+}
+
+#[test]
+fn display_shouldroundtrip_throughparse() {
+    let observable = Observable::parse("0.5*ZZ - 0.3*XI");
+
+    assert_eq!("0.5*ZZ - 0.3*XI", observable.to_string());
+    assert_eq!(observable, Observable::parse(&observable.to_string()));
+}
+
+#[test]
+fn add_shouldcombineliketerms() {
+    let first = Observable::parse("0.5*ZZ + XI");
+    let second = Observable::parse("0.25*ZZ - XI + IY");
+
+    let result = &first + &second;
+
+    assert_eq!(Observable::parse("0.75*ZZ + IY"), result);
+}
+
+#[test]
+fn sub_shouldcancel_withsameobservable() {
+    let observable = Observable::parse("0.5*ZZ + 0.3*XI");
+
+    assert!((&observable - &observable).terms.is_empty());
+}
+
+#[test]
+fn mul_shouldscalecoefficients() {
+    let observable = Observable::parse("0.5*ZZ - XI");
+
+    assert_eq!(Observable::parse("ZZ - 2*XI"), &observable * 2.0);
+}
+
+#[test]
+fn simplified_shouldfoldnegativesign_intocoefficient() {
+    let observable = Hamiltonian::new(
+        1,
+        &[(
+            2.0,
+            PauliString {
+                negative: true,
+                paulis: vec![Pauli::Z],
+            },
+        )],
+    );
+
+    assert_eq!(Observable::parse("-2*Z"), observable.simplified());
+}
+
+#[test]
+fn registerexpectation_shouldmatchstatevectorexpectation() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 1);
+    let observable = Observable::parse("0.5*ZZ + 0.3*ZI + 0.2*IZ");
+
+    let result = observable.register_expectation(&qubit_register);
+
+    assert!((result - (-0.5 + 0.3 - 0.2)).abs() < 1e-12);
+}
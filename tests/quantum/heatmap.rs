@@ -0,0 +1,44 @@
+use num_complex::Complex;
+use rquant::quantum::types::{
+    heatmap::Heatmap, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+};
+
+#[test]
+fn display_shouldshadeandphaseeachelement() {
+    let displayed = QuantumGate::SUPERPOSITION.heatmap().to_string();
+    let rows: Vec<&str> = displayed.lines().collect();
+
+    assert_eq!("|0〉  ▓→  ▓→", rows[1].trim_end());
+    assert_eq!("|1〉  ▓→  ▓←", rows[2].trim_end());
+}
+
+#[test]
+fn display_shouldhaverowforeverybasisstate_withtwoqubitcircuit() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 1);
+
+    let displayed = circuit.heatmap().to_string();
+
+    assert!(displayed.lines().nth(4).unwrap().starts_with("|11〉"));
+    assert!(displayed.contains("phase:"));
+}
+
+#[test]
+fn display_shouldleaveoutphase_withzeroelement() {
+    let heatmap = Heatmap::new(vec![
+        vec![Complex::new(0.0, 1.0), Complex::new(0.0, 0.0)],
+        vec![Complex::new(0.0, 0.0), Complex::new(0.2, 0.0)],
+    ]);
+
+    let rows: Vec<String> = heatmap.to_string().lines().map(String::from).collect();
+
+    assert_eq!("|0〉  █↑", rows[1].trim_end());
+    assert_eq!("|1〉      ░→", rows[2].trim_end());
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withnonsquarematrix() {
+    Heatmap::new(vec![vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)]]);
+}
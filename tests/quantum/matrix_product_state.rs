@@ -0,0 +1,144 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::quantum::types::{
+    matrix_product_state::MatrixProductState, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator, state_vector::StateVector,
+};
+
+fn assert_matches_state_vector(state: &MatrixProductState, state_vector: &StateVector) {
+    for (index, expected) in state_vector.amplitudes.iter().enumerate() {
+        assert!((state.amplitude(index) - expected).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn new_shouldstartinzerostate() {
+    let state = MatrixProductState::new(3, 4);
+
+    assert_eq!(1.0, state.amplitude(0).re);
+    assert_eq!(vec![1, 1], state.bond_dimensions());
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withzerobonddimension() {
+    MatrixProductState::new(2, 0);
+}
+
+#[test]
+fn applycnot_shouldmatchstatevector_withghzstate() {
+    let mut state = MatrixProductState::new(4, 4);
+    let mut state_vector = StateVector::new(4);
+
+    state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    for target in 1..4 {
+        state.apply_cnot(target - 1, target);
+        state_vector.apply_cnot(target - 1, target);
+    }
+
+    assert_matches_state_vector(&state, &state_vector);
+    assert_eq!(vec![2, 2, 2], state.bond_dimensions());
+    assert!(state.truncation_error < 1e-12);
+}
+
+#[test]
+fn applycnot_shouldmatchstatevector_withdistantqubits() {
+    let mut state = MatrixProductState::new(4, 8);
+    let mut state_vector = StateVector::new(4);
+    let rotation = QuantumGate::new(QuantumOperator::RY(0.8));
+
+    for target in 0..4 {
+        state.apply_gate(&rotation, target);
+        state_vector.apply_gate(&rotation, target);
+    }
+    state.apply_cnot(3, 0);
+    state_vector.apply_cnot(3, 0);
+    state.apply_gate(&QuantumGate::ROTATE, 1);
+    state_vector.apply_gate(&QuantumGate::ROTATE, 1);
+    state.apply_cnot(0, 2);
+    state_vector.apply_cnot(0, 2);
+
+    assert_matches_state_vector(&state, &state_vector);
+}
+
+#[test]
+fn applycnot_shouldtruncatebond_withsmallbonddimension() {
+    let mut state = MatrixProductState::new(2, 1);
+
+    state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state.apply_cnot(0, 1);
+
+    assert_eq!(vec![1], state.bond_dimensions());
+    assert!((state.truncation_error - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn measure_shouldagreeacrossqubits_withlargeghzstate() {
+    let mut state = MatrixProductState::new(50, 2);
+
+    state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    for target in 1..50 {
+        state.apply_cnot(target - 1, target);
+    }
+
+    let first = state.measure(0);
+    assert!((1..50).all(|target| state.measure(target) == first));
+}
+
+#[test]
+fn measure_shouldcollapsestate() {
+    let mut state = MatrixProductState::new(2, 2);
+
+    state.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state.apply_cnot(0, 1);
+    let is_zero = state.measure(1);
+
+    let index = if is_zero { 0b00 } else { 0b11 };
+    assert!((state.amplitude(index).norm() - 1.0).abs() < 1e-9);
+    assert_eq!(is_zero, state.measure(0));
+}
+
+#[test]
+fn measurewith_shouldrepeatoutcomes_withsameseed() {
+    let measure = |seed| {
+        let mut state = MatrixProductState::new(3, 2);
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..3)
+            .map(|target| {
+                state.apply_gate(&QuantumGate::SUPERPOSITION, target);
+                state.measure_with(target, &mut rng)
+            })
+            .collect::<Vec<bool>>()
+    };
+
+    let outcomes: Vec<Vec<bool>> = (0..20).map(measure).collect();
+
+    assert_eq!(outcomes, (0..20).map(measure).collect::<Vec<Vec<bool>>>());
+    assert!(outcomes.iter().flatten().any(|&value| value));
+    assert!(outcomes.iter().flatten().any(|&value| !value));
+}
+
+#[test]
+fn samplewith_shouldmatchamplitudes() {
+    let mut state = MatrixProductState::new(3, 4);
+    state.apply_gate(&QuantumGate::new(QuantumOperator::RY(1.0)), 0);
+    state.apply_cnot(0, 1);
+    state.apply_gate(&QuantumGate::new(QuantumOperator::RX(2.0)), 2);
+    state.apply_cnot(1, 2);
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let shots = 20000;
+    let mut counts = [0; 8];
+    for _ in 0..shots {
+        let index = state
+            .sample_with(&mut rng)
+            .iter()
+            .fold(0, |index, &value| (index << 1) | usize::from(!value));
+        counts[index] += 1;
+    }
+
+    for (index, count) in counts.iter().enumerate() {
+        let expected = state.amplitude(index).norm_sqr();
+        assert!((*count as f64 / shots as f64 - expected).abs() < 0.02);
+    }
+}
@@ -0,0 +1,13 @@
+use rquant::quantum::types::{pauli::Pauli, quantum_operators::QuantumOperator};
+
+#[test]
+fn operator_shouldbenone_foridentity() {
+    assert_eq!(None, Pauli::I.operator());
+}
+
+#[test]
+fn operator_shouldmatchpauligates() {
+    assert_eq!(Some(QuantumOperator::NOT), Pauli::X.operator());
+    assert_eq!(Some(QuantumOperator::ROTATE), Pauli::Y.operator());
+    assert_eq!(Some(QuantumOperator::PHASE), Pauli::Z.operator());
+}
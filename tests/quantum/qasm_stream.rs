@@ -0,0 +1,67 @@
+use std::f64::consts::PI;
+
+use rquant::quantum::types::{
+    qasm_statement::QasmStatement, qasm_stream::QasmStream, quantum_operators::QuantumOperator,
+};
+
+#[test]
+fn next_shouldresolveregisterstoflatindices() {
+    let program = "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg a[2];\nqreg b[2];\ncreg c[1];\nx b[1];\ncx a[0], b[0];\nmeasure b[1] -> c[0];\n";
+
+    let statements: Vec<QasmStatement> = QasmStream::new(program.as_bytes()).collect();
+
+    assert_eq!(
+        vec![
+            QasmStatement::QuantumRegister(2),
+            QasmStatement::QuantumRegister(2),
+            QasmStatement::ClassicalRegister(1),
+            QasmStatement::Gate(QuantumOperator::NOT, 3),
+            QasmStatement::ControlledNot(0, 2),
+            QasmStatement::Measure(3, 0),
+        ],
+        statements
+    );
+}
+
+#[test]
+fn next_shouldbroadcastgates_overwholeregister() {
+    let program = "qreg q[3]; h q; // comment; with a semicolon\nbarrier q;";
+
+    let statements: Vec<QasmStatement> = QasmStream::new(program.as_bytes()).skip(1).collect();
+
+    assert_eq!(
+        (0..3)
+            .map(|target| QasmStatement::Gate(QuantumOperator::SUPERPOSITION, target))
+            .collect::<Vec<_>>(),
+        statements
+    );
+}
+
+#[test]
+fn next_shouldevaluateparameterexpressions() {
+    let program =
+        "qreg q[1];\nrz(-pi / 4) q[0];\nrx(2 * (pi - 1.5e0)) q[0];\nry(2.5e-1)\n  q[0];\n";
+
+    let statements: Vec<QasmStatement> = QasmStream::new(program.as_bytes()).skip(1).collect();
+
+    assert_eq!(
+        vec![
+            QasmStatement::Gate(QuantumOperator::RZ(-PI / 4.0), 0),
+            QasmStatement::Gate(QuantumOperator::RX(2.0 * (PI - 1.5)), 0),
+            QasmStatement::Gate(QuantumOperator::RY(0.25), 0),
+        ],
+        statements
+    );
+}
+
+#[test]
+#[should_panic(expected = "line 2")]
+fn next_shouldpanic_withunsupportedgate() {
+    QasmStream::new("qreg q[1];\nu3(0, 0, 0) q[0];\n".as_bytes()).for_each(drop);
+}
+
+#[test]
+#[should_panic]
+fn next_shouldpanic_withoutofboundsindex() {
+    QasmStream::new("qreg q[1];\nx q[1];\n".as_bytes()).for_each(drop);
+}
@@ -0,0 +1,71 @@
+use rquant::quantum::types::{
+    quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+};
+
+#[test]
+fn toqir_shouldcallgates_withstaticqubits() {
+    let qir = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 1)
+        .to_qir();
+
+    assert!(qir.contains("call void @__quantum__qis__h__body(%Qubit* null)"));
+    assert!(qir.contains("call void @__quantum__qis__x__body(%Qubit* inttoptr (i64 1 to %Qubit*))"));
+    assert!(qir.contains("declare void @__quantum__qis__h__body(%Qubit*)"));
+    assert!(qir.contains("declare void @__quantum__qis__x__body(%Qubit*)"));
+}
+
+#[test]
+fn toqir_shouldmeasureandrecord_everyqubit() {
+    let qir = QuantumCircuit::new(3).to_qir();
+
+    assert_eq!(
+        3,
+        qir.matches("call void @__quantum__qis__mz__body").count()
+    );
+    assert_eq!(
+        3,
+        qir.matches("call void @__quantum__rt__result_record_output")
+            .count()
+    );
+    assert!(qir.contains("\"required_num_qubits\"=\"3\""));
+    assert!(qir.contains("\"required_num_results\"=\"3\""));
+}
+
+#[test]
+fn toqir_shoulddeclaregates_once() {
+    let qir = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::PHASE, 0)
+        .add_gate(&QuantumGate::PHASE, 0)
+        .to_qir();
+
+    assert_eq!(
+        1,
+        qir.matches("declare void @__quantum__qis__z__body").count()
+    );
+}
+
+#[test]
+fn toqir_shouldpassangle_forrotationgates() {
+    let qir = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(0.5)), 0)
+        .to_qir();
+
+    assert!(qir.contains(
+        "call void @__quantum__qis__rz__body(double 5.0000000000000000e-1, %Qubit* null)"
+    ));
+    assert!(qir.contains("declare void @__quantum__qis__rz__body(double, %Qubit*)"));
+}
+
+#[test]
+fn toqir_shouldlowercontrolledgates() {
+    let qir = QuantumCircuit::new(2).add_cnot(0, 1).add_cz(1, 0).to_qir();
+
+    assert!(qir.contains(
+        "call void @__quantum__qis__cnot__body(%Qubit* null, %Qubit* inttoptr (i64 1 to %Qubit*))"
+    ));
+    assert!(qir.contains(
+        "call void @__quantum__qis__cz__body(%Qubit* inttoptr (i64 1 to %Qubit*), %Qubit* null)"
+    ));
+    assert!(qir.contains("declare void @__quantum__qis__cnot__body(%Qubit*, %Qubit*)"));
+}
@@ -0,0 +1,250 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::quantum::types::{
+    pauli::Pauli, quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator, qubit::Qubit, qubit_register::QubitRegister,
+};
+
+#[test]
+fn new_shouldcreateemptycircuit() {
+    let circuit = QuantumCircuit::new(3);
+
+    assert_eq!(3, circuit.num_qubits);
+    assert!(circuit.is_empty());
+}
+
+#[test]
+fn addgate_shouldaddoperations_inorder() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::NOT, 1);
+
+    assert_eq!(2, circuit.len());
+    assert_eq!(
+        QuantumOperator::SUPERPOSITION,
+        circuit.operations[0].operator
+    );
+    assert_eq!(0, circuit.operations[0].target);
+    assert_eq!(QuantumOperator::NOT, circuit.operations[1].operator);
+    assert_eq!(1, circuit.operations[1].target);
+}
+
+#[test]
+#[should_panic]
+fn addgate_shouldpanic_withinvalidtarget() {
+    QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 1);
+}
+
+#[test]
+fn addcnot_shouldentanglequbits() {
+    let unitary = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_cnot(0, 1)
+        .unitary();
+
+    // The first column is the Bell state (|00> + |11>) / sqrt(2).
+    assert!((unitary[0b00][0].re - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    assert!((unitary[0b11][0].re - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    assert_eq!(0.0, unitary[0b01][0].norm());
+    assert_eq!(0.0, unitary[0b10][0].norm());
+}
+
+#[test]
+fn addcontrolledgate_shouldonlyflip_whenallcontrolsareone() {
+    let unitary = QuantumCircuit::new(3)
+        .add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2)
+        .unitary();
+
+    assert_eq!(1.0, unitary[0b111][0b110].re);
+    assert_eq!(1.0, unitary[0b110][0b111].re);
+    assert_eq!(1.0, unitary[0b101][0b101].re);
+}
+
+#[test]
+#[should_panic(expected = "Controlled gate qubits must be unique")]
+fn addcontrolledgate_shouldpanic_withcontrolontarget() {
+    QuantumCircuit::new(2).add_controlled_gate(&QuantumGate::NOT, &[1], 1);
+}
+
+#[test]
+#[should_panic(expected = "Qubit register can not apply a controlled gate")]
+fn applycircuit_shouldpanic_withcontrolledgateonregister() {
+    QubitRegister::new(2).apply_circuit(&QuantumCircuit::new(2).add_cnot(0, 1));
+}
+
+#[test]
+fn applycircuit_shouldapplyoperations_toregister() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 1)
+        .add_gate(&QuantumGate::PHASE, 1);
+    let mut qubit_register = QubitRegister::new(2);
+
+    qubit_register.apply_circuit(&circuit);
+
+    assert_eq!(Some(&Qubit::zero()), qubit_register.get(0));
+    assert_eq!(
+        Qubit::flip().positions[0],
+        qubit_register.qubits[1].positions[0]
+    );
+}
+
+#[test]
+fn expectationgradient_shouldmatchanalyticgradient_forrotations() {
+    let (a, b) = (0.4, 1.1);
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(a)), 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(b)), 0);
+
+    let result = circuit.expectation_gradient(0);
+
+    // <Z> = cos(a)cos(b) for RX(b)RY(a)|0>
+    assert!((result.value - a.cos() * b.cos()).abs() < 1e-12);
+    assert!((result.derivatives[0] + a.sin() * b.cos()).abs() < 1e-12);
+    assert!((result.derivatives[1] + a.cos() * b.sin()).abs() < 1e-12);
+}
+
+#[test]
+fn expectationgradient_shouldbezero_forotherqubitparameters() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(0.7)), 1);
+
+    let result = circuit.expectation_gradient(0);
+
+    assert!((result.value + 1.0).abs() < 1e-12);
+    assert_eq!(vec![0.0], result.derivatives);
+}
+
+#[test]
+fn injecterror_shouldinsertpauli_beforeoperation() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .inject_error(1, Pauli::Z, 0);
+
+    assert_eq!(3, circuit.len());
+    assert_eq!(QuantumOperator::PHASE, circuit.operations[1].operator);
+    assert_eq!(0, circuit.operations[1].target);
+}
+
+#[test]
+fn injecterror_shouldbeobservable_inregister() {
+    let circuit = QuantumCircuit::new(1).inject_error(0, Pauli::X, 0);
+    let mut qubit_register = QubitRegister::new(1);
+
+    qubit_register.apply_circuit(&circuit);
+
+    assert_eq!(
+        Qubit::one().positions[0],
+        qubit_register.qubits[0].positions[0]
+    );
+}
+
+#[test]
+fn injecterror_shouldnotchangecircuit_withidentity() {
+    let circuit = QuantumCircuit::new(1).inject_error(0, Pauli::I, 0);
+
+    assert!(circuit.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn injecterror_shouldpanic_withinvalidoperationindex() {
+    QuantumCircuit::new(1).inject_error(1, Pauli::X, 0);
+}
+
+#[test]
+fn withparameters_shouldreplacerotationangles() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(0.1)), 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(0.2)), 0);
+
+    let result = circuit.with_parameters(&[1.0, 2.0]);
+
+    assert_eq!(vec![0.1, 0.2], circuit.parameters());
+    assert_eq!(vec![1.0, 2.0], result.parameters());
+    assert_eq!(
+        QuantumOperator::SUPERPOSITION,
+        result.operations[1].operator
+    );
+}
+
+#[test]
+fn unitary_shouldmatchgatematrix_withsinglegate() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    assert_eq!(QuantumGate::SUPERPOSITION.matrix(), circuit.unitary());
+}
+
+#[test]
+fn unitary_shouldtargetleastsignificantbit_withsecondqubit() {
+    let unitary = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 1)
+        .unitary();
+
+    assert_eq!(1.0, unitary[0b01][0b00].re);
+    assert_eq!(1.0, unitary[0b10][0b11].re);
+}
+
+fn is_equivalent_up_to_phase(first: &QuantumCircuit, second: &QuantumCircuit) -> bool {
+    let (first, second) = (first.unitary(), second.unitary());
+    let overlap: num_complex::Complex<f64> = first
+        .iter()
+        .flatten()
+        .zip(second.iter().flatten())
+        .map(|(a, b)| a.conj() * b)
+        .sum();
+    (overlap.norm() - first.len() as f64).abs() < 1e-9
+}
+
+#[test]
+fn twirled_shouldkeepunitary_withcliffordgates() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::PHASE, 1)
+        .add_gate(
+            &QuantumGate::new(QuantumOperator::RZ(std::f64::consts::FRAC_PI_2)),
+            0,
+        )
+        .add_gate(&QuantumGate::ROTATE, 1);
+
+    for _ in 0..20 {
+        assert!(is_equivalent_up_to_phase(&circuit, &circuit.twirled()));
+    }
+}
+
+#[test]
+fn twirled_shouldkeepunitary_withrotationgates() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(0.3)), 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RZ(0.7)), 0);
+
+    for _ in 0..20 {
+        assert!(is_equivalent_up_to_phase(&circuit, &circuit.twirled()));
+    }
+}
+
+#[test]
+fn twirled_shouldkeepunitary_withcontrolledgates() {
+    let circuit = QuantumCircuit::new(3)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_cnot(0, 1)
+        .add_cz(2, 1)
+        .add_controlled_gate(&QuantumGate::NOT, &[0, 1], 2);
+
+    for _ in 0..20 {
+        assert!(is_equivalent_up_to_phase(&circuit, &circuit.twirled()));
+    }
+}
+
+#[test]
+fn twirledwith_shouldrepeattwirl_withsameseed() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_cnot(0, 1);
+
+    let first = circuit.twirled_with(&mut StdRng::seed_from_u64(11));
+    let second = circuit.twirled_with(&mut StdRng::seed_from_u64(11));
+
+    assert_eq!(first, second);
+}
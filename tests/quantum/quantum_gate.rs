@@ -1,4 +1,8 @@
-use rquant::quantum::types::{quantum_gate::QuantumGate, quantum_operators::QuantumOperator};
+use rquant::quantum::types::{
+    gate_name_error::GateNameError, quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+    qubit::Qubit,
+};
+use std::f64::consts::PI;
 
 #[test]
 fn new_shouldmakequantumgate_withvalidoperator() {
@@ -15,3 +19,113 @@ fn new_shouldmakequantumgate_withvalidoperator() {
         assert!(!quantum_gate.transform.is_empty());
     });
 }
+
+#[test]
+fn new_shouldmakerotationgates_withangle() {
+    [
+        QuantumOperator::RX(0.5),
+        QuantumOperator::RY(0.5),
+        QuantumOperator::RZ(0.5),
+    ]
+    .iter()
+    .for_each(|op| {
+        let quantum_gate = QuantumGate::new(op.clone());
+        assert_eq!(*op, quantum_gate.operator);
+        assert!(quantum_gate
+            .transform
+            .iter()
+            .all(|row| row.has_valid_amplitude()));
+    });
+}
+
+#[test]
+fn new_shouldmakerotationgate_inconstcontext() {
+    const HALF_TURN: QuantumGate = QuantumGate::new(QuantumOperator::RY(PI));
+
+    assert!(HALF_TURN.transform[0].initial_position.norm() < 1e-15);
+    assert!((HALF_TURN.transform[1].initial_position.re - 1.0).abs() < 1e-15);
+}
+
+#[test]
+fn new_shouldmatchstandardtrigonometry_forrotationangles() {
+    for step in -2000..=2000 {
+        let angle = step as f64 * 0.0137;
+        let (sin, cos) = (angle / 2.0).sin_cos();
+
+        let transform = QuantumGate::new(QuantumOperator::RY(angle)).transform;
+
+        assert!((transform[0].initial_position.re - cos).abs() < 1e-15);
+        assert!((transform[1].initial_position.re - sin).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn new_shouldmakenotgate_withrotationbypi() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::new(QuantumOperator::RX(PI)));
+
+    assert!(qubit.initial_position().norm() < 1e-12);
+    assert!((qubit.possible_position().norm() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn fromname_shouldreadstandardnames_andparameters() {
+    assert_eq!(
+        QuantumOperator::SUPERPOSITION,
+        QuantumGate::from_name("h").unwrap().operator
+    );
+    assert_eq!(
+        QuantumOperator::NOT,
+        QuantumGate::from_name(" x ").unwrap().operator
+    );
+    assert_eq!(
+        QuantumOperator::RZ(0.5),
+        QuantumGate::from_name("rz(0.5)").unwrap().operator
+    );
+    assert_eq!(
+        QuantumOperator::RX(PI / 2.0),
+        QuantumGate::from_name("rx(pi/2)").unwrap().operator
+    );
+    assert_eq!(
+        QuantumOperator::RZ(-PI / 4.0),
+        QuantumGate::from_name("tdg").unwrap().operator
+    );
+}
+
+#[test]
+fn fromname_shouldreturnerror_withinvalidnames() {
+    assert_eq!(
+        Err(GateNameError::Unknown("ccx".to_string())),
+        QuantumGate::from_name("ccx").map(|gate| gate.operator)
+    );
+    assert_eq!(
+        Err(GateNameError::Parameters {
+            name: "rz".to_string(),
+            expected: 1,
+            found: 0
+        }),
+        QuantumGate::from_name("rz").map(|gate| gate.operator)
+    );
+    assert!(matches!(
+        QuantumGate::from_name("ry(half)"),
+        Err(GateNameError::InvalidParameter(_))
+    ));
+}
+
+#[test]
+fn name_shouldreadbackintosamegate() {
+    [
+        QuantumOperator::NOT,
+        QuantumOperator::ROTATE,
+        QuantumOperator::PHASE,
+        QuantumOperator::SUPERPOSITION,
+        QuantumOperator::RX(0.25),
+        QuantumOperator::RY(-1.5),
+        QuantumOperator::RZ(PI / 3.0),
+    ]
+    .into_iter()
+    .for_each(|operator| {
+        let name = QuantumGate::new(operator.clone()).name();
+        assert_eq!(operator, QuantumGate::from_name(&name).unwrap().operator);
+    });
+    assert_eq!("rz(0.5)", QuantumGate::new(QuantumOperator::RZ(0.5)).name());
+}
@@ -15,3 +15,26 @@ fn hasvalidposition_shouldbefalse_withinvalidamplitude() {
 
     assert!(!quantum_position.has_valid_amplitude());
 }
+
+#[test]
+fn blochvector_shouldpointup_forzeroposition() {
+    assert_eq!([0.0, 0.0, 1.0], QuantumPosition::ZERO.bloch_vector());
+}
+
+#[test]
+fn blochvector_shouldpointdown_foroneposition() {
+    assert_eq!([0.0, 0.0, -1.0], QuantumPosition::ONE.bloch_vector());
+}
+
+#[test]
+fn blochvector_shouldpointalongx_forequalsuperposition() {
+    let factor = 1.0 / std::f64::consts::SQRT_2;
+    let quantum_position =
+        QuantumPosition::new(Complex::new(factor, 0.0), Complex::new(factor, 0.0));
+
+    let [x, y, z] = quantum_position.bloch_vector();
+
+    assert!((x - 1.0).abs() < 1e-12);
+    assert!(y.abs() < 1e-12);
+    assert!(z.abs() < 1e-12);
+}
@@ -1,6 +1,10 @@
+use rand::{rngs::StdRng, SeedableRng};
 use rquant::quantum::{
     constants::ket::{KET_BACK_ROTATION, KET_ONE, KET_ZERO},
-    types::{quantum_gate::QuantumGate, quantum_position::QuantumPosition, qubit::Qubit},
+    types::{
+        quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+        quantum_position::QuantumPosition, qubit::Qubit,
+    },
 };
 
 #[test]
@@ -83,6 +87,28 @@ fn update_shouldaddposition_toexistingpositions() {
     );
 }
 
+#[test]
+fn update_shouldmakenewposition_current() {
+    let mut qubit = Qubit::zero();
+
+    qubit.update(QuantumPosition::ONE);
+
+    assert_eq!(
+        QuantumPosition::ONE.initial_position,
+        qubit.initial_position()
+    );
+    assert_eq!(QuantumPosition::ZERO, qubit.positions[1]);
+}
+
+#[test]
+fn measure_shouldusenewposition_afterupdate() {
+    let mut qubit = Qubit::zero();
+
+    qubit.update(QuantumPosition::ONE);
+
+    assert!((0..100).all(|_| !qubit.measure()));
+}
+
 #[test]
 #[should_panic]
 fn measure_shouldpanic_withoutanypositions() {
@@ -101,6 +127,60 @@ fn measure_shouldreturntrue_forzeroqubit() {
     assert!(Qubit::zero().measure());
 }
 
+#[test]
+fn probabilities_shouldmatchamplitudes() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::ROTATE);
+
+    assert_eq!([0.0, 1.0], Qubit::one().probabilities());
+    assert_eq!([0.0, 1.0], qubit.probabilities());
+    let [zero, one] = Qubit::zero()
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .probabilities();
+    assert!((zero - 0.5).abs() < 1e-12 && (one - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn amplitudes_shouldmatchpositions() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+    assert_eq!(
+        [qubit.initial_position(), qubit.possible_position()],
+        qubit.amplitudes()
+    );
+}
+
+#[test]
+fn measurewith_shouldrepeatoutcomes_withsameseed() {
+    let qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    let (mut first, mut second) = (StdRng::seed_from_u64(7), StdRng::seed_from_u64(7));
+
+    let first: Vec<bool> = (0..50).map(|_| qubit.measure_with(&mut first)).collect();
+    let second: Vec<bool> = (0..50).map(|_| qubit.measure_with(&mut second)).collect();
+
+    assert_eq!(first, second);
+    assert!(first.contains(&true) && first.contains(&false));
+}
+
+#[test]
+fn measuremut_shouldagree_withrepeatedmeasurements() {
+    for _ in 0..20 {
+        let mut qubit = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+        let first = qubit.measure_mut();
+
+        assert!((0..10).all(|_| qubit.measure_mut() == first));
+    }
+}
+
+#[test]
+fn measuremut_shouldcollapsetobasisstate_andkeephistory() {
+    let mut qubit = Qubit::one();
+
+    assert!(!qubit.measure_mut());
+    assert_eq!(QuantumPosition::ONE, qubit.positions[0]);
+    assert_eq!(2, qubit.positions.len());
+}
+
 #[test]
 #[should_panic]
 fn applygate_shouldpanic_withoutanypositions() {
@@ -139,6 +219,54 @@ fn applygate_shouldrotateonequbit_withrotategate() {
     assert_eq!(expected, result);
 }
 
+#[test]
+#[should_panic]
+fn applygatemut_shouldpanic_withoutanypositions() {
+    let mut qubit = Qubit { positions: vec![] };
+
+    qubit.apply_gate_mut(&QuantumGate::NOT);
+}
+
+#[test]
+fn applygatemut_shouldmatchapplygate_andkeephistory() {
+    let mut qubit = Qubit::one();
+    qubit.update(QuantumPosition::ZERO);
+    let expected = qubit.apply_gate(&QuantumGate::SUPERPOSITION);
+
+    qubit.apply_gate_mut(&QuantumGate::SUPERPOSITION);
+
+    assert_eq!(expected.positions[0], qubit.positions[0]);
+    assert_eq!(
+        vec![QuantumPosition::ZERO, QuantumPosition::ONE],
+        qubit.positions[1..]
+    );
+}
+
+#[test]
+fn applygatemut_shouldrotateonequbit_withrotategate() {
+    let expected = QuantumPosition::new(KET_BACK_ROTATION, KET_ZERO);
+    let mut qubit = Qubit::one();
+
+    qubit.apply_gate_mut(&QuantumGate::ROTATE);
+
+    assert_eq!(expected, qubit.positions[0]);
+}
+
+#[test]
+fn applygatemut_shouldmatchapplygate_aftermanyrotations() {
+    let gate = QuantumGate::new(QuantumOperator::RX(0.1));
+    let mut expected = Qubit::zero();
+    let mut qubit = Qubit::zero();
+
+    for _ in 0..10_000 {
+        expected = expected.apply_gate(&gate);
+        qubit.apply_gate_mut(&gate);
+    }
+
+    assert_eq!(expected.positions[0], qubit.positions[0]);
+    assert_eq!(10_001, qubit.positions.len());
+}
+
 #[test]
 fn notoperator_shouldflipqubit() {
     let qubit = Qubit::zero();
@@ -147,3 +275,119 @@ fn notoperator_shouldflipqubit() {
 
     assert_eq!(Qubit::one(), result);
 }
+
+#[test]
+fn blochtrajectory_shouldaddframe_aftereachgate() {
+    let trajectory =
+        Qubit::zero().bloch_trajectory(&[QuantumGate::SUPERPOSITION, QuantumGate::PHASE]);
+
+    let expected = [[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [-1.0, 0.0, 0.0]];
+    assert_eq!(expected.len(), trajectory.frames.len());
+    for (frame, expected_frame) in trajectory.frames.iter().zip(expected) {
+        for (coordinate, expected_coordinate) in frame.iter().zip(expected_frame) {
+            assert!((coordinate - expected_coordinate).abs() < 1e-12);
+        }
+    }
+}
+
+#[test]
+fn blochtrajectory_shouldnotmovequbit() {
+    let qubit = Qubit::zero();
+
+    let trajectory = qubit.bloch_trajectory(&[QuantumGate::NOT]);
+
+    assert_eq!(vec![[0.0, 0.0, 1.0], [0.0, 0.0, -1.0]], trajectory.frames);
+    assert_eq!(Qubit::zero(), qubit);
+}
+
+#[test]
+fn fidelity_shouldbehalf_betweenzeroandsuperposition() {
+    let zero = Qubit::zero();
+    let plus = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+
+    assert!((zero.inner_product(&plus).re - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    assert!((zero.fidelity(&plus) - 0.5).abs() < 1e-12);
+    assert!((zero.trace_distance(&plus) - std::f64::consts::FRAC_1_SQRT_2).abs() < 1e-12);
+    assert!(zero.trace_distance(&Qubit::one()) > 1.0 - 1e-12);
+}
+
+#[test]
+fn blochcoordinates_shouldfollowgates_aroundthesphere() {
+    let plus = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    let (x, y, z) = plus.bloch_coordinates();
+    assert!((x - 1.0).abs() < 1e-12 && y.abs() < 1e-12 && z.abs() < 1e-12);
+
+    let (x, y, z) = plus.apply_gate(&QuantumGate::PHASE).bloch_coordinates();
+    assert!((x + 1.0).abs() < 1e-12 && y.abs() < 1e-12 && z.abs() < 1e-12);
+
+    assert_eq!(Qubit::one().bloch_coordinates(), (0.0, 0.0, -1.0));
+}
+
+#[test]
+fn fromblochangles_shouldmatchblochcoordinates() {
+    [
+        (0.0, 0.0),
+        (1.2, 0.4),
+        (2.5, -1.7),
+        (std::f64::consts::PI, 0.3),
+    ]
+    .iter()
+    .for_each(|&(theta, phi)| {
+        let (x, y, z) = Qubit::from_bloch_angles(theta, phi).bloch_coordinates();
+        assert!((x - theta.sin() * phi.cos()).abs() < 1e-12);
+        assert!((y - theta.sin() * phi.sin()).abs() < 1e-12);
+        assert!((z - theta.cos()).abs() < 1e-12);
+    });
+}
+
+#[test]
+fn fromprobabilities_shouldmatchmeasurementprobabilities() {
+    let qubit = Qubit::from_probabilities(0.2, 0.8, std::f64::consts::FRAC_PI_2);
+
+    assert!((qubit.initial_position().norm_sqr() - 0.2).abs() < 1e-12);
+    assert!((qubit.possible_position().norm_sqr() - 0.8).abs() < 1e-12);
+    assert!((qubit.possible_position().arg() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "Invalid qubit probabilities")]
+fn fromprobabilities_shouldpanic_withprobabilitiesthatdonotsumtoone() {
+    Qubit::from_probabilities(0.5, 0.6, 0.0);
+}
+
+#[test]
+fn trynew_shouldreturnerror_withinvalidamplitudes() {
+    let position = QuantumPosition::new(KET_ONE, KET_ONE);
+
+    let result = Qubit::try_new(position);
+
+    assert_eq!(2.0, result.unwrap_err().sum_of_squares);
+    assert!(Qubit::try_new(position.normalized()).is_ok());
+}
+
+#[test]
+fn applygate_shouldnotpanic_withdriftedamplitudes() {
+    let drift = 1.0 + 1e-14;
+    let qubit = Qubit {
+        positions: vec![QuantumPosition::new(KET_ONE * drift, KET_ZERO)],
+    };
+
+    let result = qubit.apply_gate(&QuantumGate::SUPERPOSITION);
+
+    let sum_of_squares =
+        result.initial_position().norm_sqr() + result.possible_position().norm_sqr();
+    assert!((sum_of_squares - 1.0).abs() < 10.0 * f64::EPSILON);
+}
+
+#[test]
+fn measuremutwith_shouldcollapsetosamestate_withsameseed() {
+    for seed in 0..20 {
+        let superposition = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+        let (mut first, mut second) = (superposition.clone(), superposition);
+
+        first.measure_mut_with(&mut StdRng::seed_from_u64(seed));
+        second.measure_mut_with(&mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(first.amplitudes(), second.amplitudes());
+    }
+}
@@ -1,7 +1,65 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use rand::{rngs::StdRng, SeedableRng};
 use rquant::quantum::types::{
-    quantum_gate::QuantumGate, qubit::Qubit, qubit_register::QubitRegister,
+    basis::Basis, graph_state::GraphState, quantum_error::QuantumError, quantum_gate::QuantumGate,
+    quantum_operators::QuantumOperator, qubit::Qubit, qubit_register::QubitRegister,
+    state_vector::StateVector,
 };
 
+#[test]
+fn ghz_shouldmatchstatevectorghz() {
+    assert_eq!(StateVector::ghz(3), QubitRegister::ghz(3));
+}
+
+#[test]
+fn graphstate_shouldmatchgraphstatenew() {
+    let edges = [(0, 1), (1, 2)];
+
+    assert_eq!(
+        GraphState::new(3, &edges),
+        QubitRegister::graph_state(3, &edges)
+    );
+}
+
+#[test]
+fn frombitstring_shouldsetqubits_inorder() {
+    let qubit_register = QubitRegister::from_bitstring("0110").unwrap();
+
+    let expected = vec![Qubit::zero(), Qubit::one(), Qubit::one(), Qubit::zero()];
+    assert_eq!(expected, qubit_register.qubits);
+}
+
+#[test]
+fn frombitstring_shouldreturnerror_withinvalidcharacter() {
+    let result = QubitRegister::from_bitstring("01x1");
+
+    assert_eq!(
+        Err(QuantumError::InvalidBitstring {
+            character: 'x',
+            position: 2
+        }),
+        result
+    );
+}
+
+#[test]
+fn frombasisstate_shouldmatchbitstring_withmostsignificantbitfirst() {
+    let qubit_register = QubitRegister::from_basis_state(6, 4);
+
+    assert_eq!(
+        QubitRegister::from_bitstring("0110").unwrap(),
+        qubit_register
+    );
+    assert_eq!(1.0, qubit_register.probabilities()[6]);
+}
+
+#[test]
+#[should_panic(expected = "Basis state does not fit in the register")]
+fn frombasisstate_shouldpanic_withvaluetoolarge() {
+    QubitRegister::from_basis_state(8, 3);
+}
+
 #[test]
 fn new_shouldcreateblankregister_withzeroqubits() {
     let qubit_register = QubitRegister::new(0);
@@ -81,3 +139,316 @@ fn applysinglequbitgate_shouldmodifyregister_withvalidgate() {
 
     assert_eq!(expected, modified_qubit.initial_position());
 }
+
+#[test]
+fn applysinglequbitgate_shouldleaveregisterunchanged_withinvalidindex() {
+    let mut qubit_register = QubitRegister::new(2);
+
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 2);
+
+    assert_eq!(QubitRegister::new(2), qubit_register);
+}
+
+#[test]
+fn tryapplysinglequbitgate_shouldreturnerror_withinvalidindex() {
+    let mut qubit_register = QubitRegister::new(2);
+
+    let result = qubit_register.try_apply_single_qubit_gate(&QuantumGate::NOT, 3);
+
+    assert_eq!(
+        Err(QuantumError::InvalidQubitIndex { index: 3, len: 2 }),
+        result
+    );
+}
+
+#[test]
+fn measureall_shouldreturnbasisstate_andcollapseregister() {
+    let mut qubit_register = QubitRegister::from_basis_state(11, 5);
+
+    assert_eq!("01011", qubit_register.measure_all());
+    assert_eq!(11, qubit_register.measure_all_as_u64());
+}
+
+#[test]
+fn measureall_shouldcollapsesuperposition_tomeasuredbitstring() {
+    let mut qubit_register = QubitRegister::new(3);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 2);
+
+    let bitstring = qubit_register.measure_all();
+
+    assert_eq!('0', bitstring.chars().nth(1).unwrap());
+    assert_eq!(bitstring, qubit_register.measure_all());
+}
+
+#[test]
+fn reset_shouldreturnqubittozero() {
+    let mut qubit_register = QubitRegister::from_bitstring("11").unwrap();
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+
+    qubit_register.reset(0);
+
+    assert_eq!(vec![1.0, 0.0], qubit_register.marginal_probabilities(&[0]));
+    assert_eq!("01", qubit_register.measure_all());
+}
+
+#[test]
+fn resetall_shouldreturneveryqubittozero() {
+    let mut qubit_register = QubitRegister::from_basis_state(13, 4);
+
+    qubit_register.reset_all();
+
+    assert_eq!(0, qubit_register.measure_all_as_u64());
+}
+
+#[test]
+fn marginalprobabilities_shouldbeproductdistribution() {
+    let qubit_register = QubitRegister {
+        qubits: vec![
+            Qubit::one(),
+            Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION),
+            Qubit::zero(),
+        ],
+    };
+
+    let probabilities = qubit_register.marginal_probabilities(&[1, 0]);
+
+    let expected = [0.0, 0.5, 0.0, 0.5];
+    for (probability, expected) in probabilities.iter().zip(expected) {
+        assert!((probability - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+fn marginalprobabilities_shouldpanic_withinvalidqubitindex() {
+    QubitRegister::new(2).marginal_probabilities(&[2]);
+}
+
+#[test]
+fn measuremut_shouldcollapseonlytarget() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 1);
+
+    let measured = qubit_register.measure_mut(0);
+
+    let expected = if measured { [1.0, 0.0] } else { [0.0, 1.0] };
+    assert_eq!(
+        expected.to_vec(),
+        qubit_register.marginal_probabilities(&[0])
+    );
+    let untouched = qubit_register.marginal_probabilities(&[1]);
+    assert!((untouched[0] - 0.5).abs() < 1e-12);
+    assert_eq!(measured, qubit_register.measure_mut(0));
+}
+
+#[test]
+#[should_panic]
+fn measuremut_shouldpanic_withinvalidindex() {
+    QubitRegister::new(1).measure_mut(1);
+}
+
+#[test]
+fn rotatebasis_shouldmeasureeigenstates_ascomputationalbasis() {
+    let mut qubit_register = QubitRegister::new(3);
+    // |+>, |+i>, and |1> are the first eigenstates of X and Y, and the second of Z.
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 1);
+    qubit_register.apply_single_qubit_gate(
+        &QuantumGate::new(QuantumOperator::RZ(std::f64::consts::FRAC_PI_2)),
+        1,
+    );
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 2);
+
+    qubit_register.rotate_basis(Basis::X, &[0]);
+    qubit_register.rotate_basis(Basis::Y, &[1]);
+    qubit_register.rotate_basis(Basis::Z, &[2]);
+
+    let probabilities = qubit_register.marginal_probabilities(&[0, 1, 2]);
+    assert!((probabilities[0b001] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn rotatebasis_shouldpanic_withbellbasis() {
+    QubitRegister::new(2).rotate_basis(Basis::Bell, &[0, 1]);
+}
+
+#[test]
+fn measurequbits_shouldcollapseonlyselectedqubits() {
+    let mut qubit_register = QubitRegister::new(3);
+    for target in 0..3 {
+        qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, target);
+    }
+
+    let measured = qubit_register.measure_qubits(&[2, 0]);
+
+    assert_eq!(2, measured.len());
+    assert_eq!(
+        vec![measured[1], measured[0]],
+        qubit_register.measure_qubits(&[0, 2])
+    );
+    let untouched = qubit_register.marginal_probabilities(&[1]);
+    assert!((untouched[0] - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn amplitudes_shouldbetensorproduct() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 1);
+
+    let amplitudes = qubit_register.amplitudes();
+
+    assert_eq!(4, amplitudes.len());
+    assert!(amplitudes[0b00].norm() < 1e-12 && amplitudes[0b10].norm() < 1e-12);
+    assert!((amplitudes[0b01].re - FRAC_1_SQRT_2).abs() < 1e-12);
+    assert!((amplitudes[0b11].re - FRAC_1_SQRT_2).abs() < 1e-12);
+}
+
+#[test]
+fn probabilityof_shouldmatchprobabilities() {
+    let mut qubit_register = QubitRegister::new(3);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 2);
+
+    let probabilities = qubit_register.probabilities();
+
+    assert!((qubit_register.probability_of("101").unwrap() - probabilities[0b101]).abs() < 1e-12);
+    assert!((qubit_register.probability_of("100").unwrap() - probabilities[0b100]).abs() < 1e-12);
+    assert!((qubit_register.probability_of_index(0b001) - 0.5).abs() < 1e-12);
+    assert!(qubit_register.probability_of_index(0b011).abs() < 1e-12);
+}
+
+#[test]
+fn probabilityof_shouldreturnerror_withinvalidbitstring() {
+    let qubit_register = QubitRegister::new(3);
+
+    assert_eq!(
+        Err(QuantumError::InvalidBitstringLength { length: 2, len: 3 }),
+        qubit_register.probability_of("01")
+    );
+    assert_eq!(
+        Err(QuantumError::InvalidBitstring {
+            character: 'x',
+            position: 1
+        }),
+        qubit_register.probability_of("0x1")
+    );
+}
+
+#[test]
+#[should_panic(expected = "Basis state does not fit in the register")]
+fn probabilityofindex_shouldpanic_withlargeindex() {
+    QubitRegister::new(2).probability_of_index(4);
+}
+
+#[test]
+fn probabilities_shouldcovereverybasisstate() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 1);
+
+    let probabilities = qubit_register.probabilities();
+
+    assert_eq!(4, probabilities.len());
+    assert!((probabilities[0b01] - 0.5).abs() < 1e-12);
+    assert!((probabilities[0b11] - 0.5).abs() < 1e-12);
+    assert!((probabilities.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn overlap_shouldmultiplyqubitoverlaps() {
+    let first = QubitRegister::new(2);
+    let mut second = QubitRegister::new(2);
+    second.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    second.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 1);
+
+    assert!((first.overlap(&second).re - 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn fidelitymatrix_shouldcompareeverypair() {
+    let zero = QubitRegister::new(2);
+    let mut one = QubitRegister::new(2);
+    one.apply_single_qubit_gate(&QuantumGate::NOT, 1);
+    let mut plus = QubitRegister::new(2);
+    plus.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 1);
+
+    let fidelities = QubitRegister::fidelity_matrix(&[zero, one, plus]);
+
+    assert!((fidelities[0][0] - 1.0).abs() < 1e-12);
+    assert!(fidelities[0][1].abs() < 1e-12);
+    assert!((fidelities[1][2] - 0.5).abs() < 1e-12);
+    assert_eq!(fidelities[2][1], fidelities[1][2]);
+}
+
+#[test]
+#[should_panic]
+fn overlap_shouldpanic_withdifferentlengths() {
+    QubitRegister::new(1).overlap(&QubitRegister::new(2));
+}
+
+#[test]
+fn tracedistance_shouldbeone_withorthogonalregisters() {
+    let zero = QubitRegister::new(2);
+    let mut one = QubitRegister::new(2);
+    one.apply_single_qubit_gate(&QuantumGate::NOT, 0);
+
+    assert!(zero.fidelity(&one).abs() < 1e-12);
+    assert!((zero.trace_distance(&one) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn swap_shouldexchangequbits() {
+    let mut qubit_register = QubitRegister::from_bitstring("100").unwrap();
+
+    qubit_register.swap(0, 2);
+
+    assert_eq!("001", qubit_register.measure_all());
+}
+
+#[test]
+fn permute_shouldreorderqubits() {
+    let mut qubit_register = QubitRegister::from_bitstring("110").unwrap();
+
+    qubit_register.permute(&[2, 0, 1]);
+
+    assert_eq!("011", qubit_register.measure_all());
+}
+
+#[test]
+#[should_panic(expected = "Invalid qubit permutation")]
+fn permute_shouldpanic_withrepeatedindex() {
+    QubitRegister::new(3).permute(&[0, 0, 1]);
+}
+
+#[test]
+fn measuremutwith_shouldrepeatoutcomes_withsameseed() {
+    let measure = |seed| {
+        let mut qubit_register = QubitRegister {
+            qubits: vec![Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION); 8],
+        };
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..8)
+            .map(|target| qubit_register.measure_mut_with(target, &mut rng))
+            .collect::<Vec<bool>>()
+    };
+
+    assert_eq!(measure(5), measure(5));
+}
+
+#[test]
+fn resetwith_shouldleavequbitzero() {
+    let mut qubit_register = QubitRegister {
+        qubits: vec![Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION); 2],
+    };
+
+    qubit_register.reset_with(1, &mut StdRng::seed_from_u64(3));
+
+    assert_eq!(
+        Qubit::zero().amplitudes(),
+        qubit_register.qubits[1].amplitudes()
+    );
+}
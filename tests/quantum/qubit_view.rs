@@ -0,0 +1,99 @@
+use rquant::quantum::types::{
+    quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, qubit::Qubit,
+    qubit_register::QubitRegister, qubit_view::QubitView, state_vector::StateVector,
+};
+
+#[test]
+fn new_shouldselectsetbits_inascendingorder() {
+    let view = QubitView::new(0b1010);
+
+    assert_eq!(vec![1, 3], view.qubits);
+    assert_eq!(2, view.len());
+    assert!(view.contains(3));
+    assert!(!view.contains(0));
+}
+
+#[test]
+fn range_shouldselectneighboringqubits() {
+    assert_eq!(0b1100, QubitView::range(2, 2).mask);
+    assert_eq!(usize::MAX, QubitView::range(0, usize::BITS as usize).mask);
+}
+
+#[test]
+fn complement_shouldselectremainingqubits() {
+    let counting = QubitView::range(0, 2);
+
+    assert_eq!(vec![2, 3, 4], counting.complement(5).qubits);
+}
+
+#[test]
+fn view_shouldbeacceptedby_marginalprobabilities() {
+    let mut qubit_register = QubitRegister::new(3);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 2);
+    let view = qubit_register.view(0b110);
+
+    assert_eq!(
+        vec![0.0, 1.0, 0.0, 0.0],
+        qubit_register.marginal_probabilities(&view)
+    );
+}
+
+#[test]
+#[should_panic]
+fn view_shouldpanic_withqubitoutsideregister() {
+    QubitRegister::new(2).view(0b100);
+}
+
+#[test]
+fn addgatetoview_shouldtargeteveryselectedqubit() {
+    let mut qubit_register = QubitRegister::new(4);
+    let work = qubit_register.view(0b0011).complement(4);
+    let circuit = QuantumCircuit::new(4).add_gate_to_view(&QuantumGate::NOT, &work);
+
+    qubit_register.apply_circuit(&circuit);
+
+    assert_eq!(2, circuit.len());
+    assert_eq!(Some(&Qubit::zero()), qubit_register.get(1));
+    assert_eq!(
+        Qubit::one().positions[0],
+        qubit_register.qubits[2].positions[0]
+    );
+    assert_eq!(
+        Qubit::one().positions[0],
+        qubit_register.qubits[3].positions[0]
+    );
+}
+
+#[test]
+fn view_shouldbeacceptedby_statevectormarginals() {
+    let mut state_vector = StateVector::new(3);
+    state_vector.apply_gate(&QuantumGate::NOT, 0);
+
+    let probabilities = state_vector.marginal_probabilities(&QubitView::new(0b011));
+
+    assert_eq!(vec![0.0, 0.0, 1.0, 0.0], probabilities);
+}
+
+#[test]
+fn slice_shouldselectrange_ofregister() {
+    let qubit_register = QubitRegister::new(5);
+
+    assert_eq!(vec![1, 2, 3], qubit_register.slice(1..4).qubits);
+    assert!(qubit_register.slice(2..2).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Invalid qubit index")]
+fn slice_shouldpanic_withrangeoutsideregister() {
+    QubitRegister::new(3).slice(1..4);
+}
+
+#[test]
+fn applygatetoview_shouldonlychangeselectedqubits() {
+    let mut qubit_register = QubitRegister::new(4);
+    let work = qubit_register.slice(2..4);
+
+    qubit_register.apply_gate_to_view(&QuantumGate::NOT, &work);
+
+    assert_eq!("0011", qubit_register.measure_all());
+}
@@ -0,0 +1,365 @@
+use num_complex::Complex;
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::{
+    quantum::types::{
+        basis::Basis, observable::Observable, pauli::Pauli, pauli_string::PauliString,
+        quantum_gate::QuantumGate, quantum_operators::QuantumOperator, qubit::Qubit,
+        qubit_register::QubitRegister, state_vector::StateVector,
+    },
+    simulation::types::backend::Backend,
+};
+
+#[test]
+fn fromregister_shouldbetensorproduct() {
+    let qubit_register = QubitRegister {
+        qubits: vec![Qubit::one(), Qubit::zero()],
+    };
+
+    let state_vector = StateVector::from_register(&qubit_register);
+
+    assert_eq!(1.0, state_vector.amplitudes[0b10].re);
+    assert_eq!(
+        1.0,
+        state_vector
+            .amplitudes
+            .iter()
+            .map(|a| a.norm_sqr())
+            .sum::<f64>()
+    );
+}
+
+#[test]
+fn applygate_shouldmatchtensorproduct_withcomplexgates() {
+    let gates = [
+        QuantumGate::new(QuantumOperator::RY(0.4)),
+        QuantumGate::new(QuantumOperator::RX(1.3)),
+        QuantumGate::ROTATE,
+    ];
+    let mut qubit_register = QubitRegister::new(3);
+    let mut state_vector = StateVector::new(3);
+
+    for (target, gate) in gates.iter().enumerate() {
+        qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, target);
+        qubit_register.apply_single_qubit_gate(gate, target);
+        state_vector.apply_gate(&QuantumGate::SUPERPOSITION, target);
+        state_vector.apply_gate(gate, target);
+    }
+
+    let expected = StateVector::from_register(&qubit_register);
+    for (actual, expected) in state_vector.amplitudes.iter().zip(&expected.amplitudes) {
+        assert!((actual - expected).norm() < 1e-12);
+    }
+}
+
+#[test]
+fn applycnot_shouldcreatebellstate() {
+    let mut state_vector = StateVector::new(2);
+
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state_vector.apply_cnot(0, 1);
+
+    assert!((state_vector.amplitudes[0b00].norm_sqr() - 0.5).abs() < 1e-12);
+    assert!((state_vector.amplitudes[0b11].norm_sqr() - 0.5).abs() < 1e-12);
+    assert_eq!(0.0, state_vector.amplitudes[0b01].norm_sqr());
+}
+
+#[test]
+fn measure_shouldcorrelatebellstate() {
+    for _ in 0..20 {
+        let mut state_vector = StateVector::new(2);
+        state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+        state_vector.apply_cnot(0, 1);
+
+        assert_eq!(state_vector.measure(0), state_vector.measure(1));
+    }
+}
+
+#[test]
+fn measurewith_shouldcollapsetosamestate_withsameseed() {
+    for seed in 0..20 {
+        let (mut first, mut second) = (StateVector::ghz(3), StateVector::ghz(3));
+
+        first.measure_with(1, &mut StdRng::seed_from_u64(seed));
+        second.measure_with(1, &mut StdRng::seed_from_u64(seed));
+
+        assert_eq!(first.amplitudes, second.amplitudes);
+    }
+}
+
+#[test]
+fn sampleshotswith_shouldcorrelateghzstate_withoutcollapsing() {
+    let state_vector = StateVector::ghz(3);
+
+    let shots = state_vector.sample_shots_with(50, &mut StdRng::seed_from_u64(4));
+
+    assert_eq!(50, shots.len());
+    assert!(shots
+        .iter()
+        .all(|shot| shot.iter().all(|&value| value == shot[0])));
+    assert!(shots.iter().any(|shot| shot[0]) && shots.iter().any(|shot| !shot[0]));
+    assert_eq!(StateVector::ghz(3), state_vector);
+}
+
+#[test]
+fn applycz_shouldflipphaseofoneone() {
+    let mut state_vector = StateVector::from_register(&QubitRegister {
+        qubits: vec![Qubit::one(), Qubit::one()],
+    });
+
+    state_vector.apply_cz(0, 1);
+
+    assert_eq!(-1.0, state_vector.amplitudes[0b11].re);
+}
+
+#[test]
+#[should_panic]
+fn applycnot_shouldpanic_withsamequbit() {
+    StateVector::new(2).apply_cnot(1, 1);
+}
+
+#[test]
+fn isentangled_shouldbetrue_withbellstate() {
+    let mut state_vector = StateVector::new(2);
+
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    assert!(!state_vector.is_entangled());
+
+    state_vector.apply_cnot(0, 1);
+    assert!(state_vector.is_entangled());
+}
+
+#[test]
+fn ghz_shouldhaveequalamplitudes_onallzeroandallone() {
+    let state_vector = StateVector::ghz(4);
+
+    assert!((state_vector.amplitudes[0b0000].norm_sqr() - 0.5).abs() < 1e-12);
+    assert!((state_vector.amplitudes[0b1111].norm_sqr() - 0.5).abs() < 1e-12);
+    assert!(state_vector.is_entangled());
+}
+
+#[test]
+fn ghz_shouldcorrelatemeasurements_acrossallqubits() {
+    let outcomes: Vec<Vec<bool>> = (0..200)
+        .map(|_| {
+            let mut state_vector = StateVector::ghz(5);
+            (0..5).map(|target| state_vector.measure(target)).collect()
+        })
+        .collect();
+
+    assert!(outcomes
+        .iter()
+        .all(|shot| shot.iter().all(|&outcome| outcome == shot[0])));
+    assert!(outcomes.iter().any(|shot| shot[0]));
+    assert!(outcomes.iter().any(|shot| !shot[0]));
+}
+
+#[test]
+fn dicke_shouldhaveexactamplitudes_onweightkstates() {
+    let state_vector = StateVector::dicke(4, 2);
+    let amplitude = 1.0 / 6f64.sqrt();
+
+    for (index, value) in state_vector.amplitudes.iter().enumerate() {
+        let expected = if index.count_ones() == 2 {
+            amplitude
+        } else {
+            0.0
+        };
+        assert!((value.re - expected).abs() < 1e-12);
+        assert!(value.im.abs() < 1e-12);
+    }
+    assert!(state_vector.is_entangled());
+}
+
+#[test]
+fn dicke_shouldbebasisstate_withweightzeroorall() {
+    let none = StateVector::dicke(3, 0);
+    let all = StateVector::dicke(3, 3);
+
+    assert!((none.amplitudes[0b000].re - 1.0).abs() < 1e-12);
+    assert!((all.amplitudes[0b111].re - 1.0).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn dicke_shouldpanic_withweightabovequbits() {
+    StateVector::dicke(2, 3);
+}
+
+#[test]
+fn hammingweights_shouldsuperposeeveryselectedweight() {
+    let state_vector = StateVector::hamming_weights(3, &[1, 2]);
+    let amplitude = 1.0 / 6f64.sqrt();
+
+    assert!(state_vector.amplitudes[0b000].norm() < 1e-12);
+    assert!(state_vector.amplitudes[0b111].norm() < 1e-12);
+    assert!((state_vector.amplitudes[0b001].re - amplitude).abs() < 1e-12);
+    assert!((state_vector.amplitudes[0b110].re - amplitude).abs() < 1e-12);
+}
+
+#[test]
+fn expectation_shouldmatchpaulieigenvalues() {
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 1);
+    let z_x = PauliString {
+        negative: false,
+        paulis: vec![Pauli::Z, Pauli::X],
+    };
+    let minus_y_y = PauliString {
+        negative: true,
+        paulis: vec![Pauli::Y, Pauli::Y],
+    };
+
+    assert!((state_vector.expectation(&z_x) - 1.0).abs() < 1e-12);
+    assert!((StateVector::ghz(2).expectation(&minus_y_y) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn marginalprobabilities_shouldsumoverotherqubits() {
+    let probabilities = StateVector::ghz(3).marginal_probabilities(&[2, 0]);
+
+    assert!((probabilities[0b00] - 0.5).abs() < 1e-12);
+    assert!((probabilities[0b11] - 0.5).abs() < 1e-12);
+    assert_eq!(0.0, probabilities[0b01]);
+}
+
+#[test]
+fn applyunitary_shouldapplycontrolledmatrix_onlywhencontrolisone() {
+    let not = vec![
+        vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+        vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+    ];
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_unitary(&not, &[1], &[0]);
+    assert_eq!(Complex::new(1.0, 0.0), state_vector.amplitudes[0b00]);
+
+    state_vector.apply_gate(&QuantumGate::NOT, 0);
+    state_vector.apply_unitary(&not, &[1], &[0]);
+    assert_eq!(Complex::new(1.0, 0.0), state_vector.amplitudes[0b11]);
+}
+
+#[test]
+#[should_panic]
+fn applyunitary_shouldpanic_withrepeatedqubits() {
+    let identity = vec![
+        vec![Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+        vec![Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)],
+    ];
+    StateVector::new(2).apply_unitary(&identity, &[0], &[0]);
+}
+
+#[test]
+fn rotatebasis_shouldmapbellstates_todistinctbasisstates() {
+    // |Phi+>, |Psi+>, |Phi->, and |Psi-> from X and Z on the first qubit of |Phi+>.
+    let bell_states =
+        [(false, false), (true, false), (false, true), (true, true)].map(|(flip, phase)| {
+            let mut state_vector = StateVector::ghz(2);
+            if flip {
+                state_vector.apply_gate(&QuantumGate::NOT, 0);
+            }
+            if phase {
+                state_vector.apply_gate(&QuantumGate::PHASE, 0);
+            }
+            state_vector.rotate_basis(Basis::Bell, &[0, 1]);
+            state_vector
+                .amplitudes
+                .iter()
+                .position(|amplitude| (amplitude.norm_sqr() - 1.0).abs() < 1e-12)
+        });
+
+    assert_eq!(
+        [Some(0b00), Some(0b01), Some(0b10), Some(0b11)],
+        bell_states
+    );
+}
+
+#[test]
+fn rotatebasis_shouldmatchregister_withsinglequbitbases() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::SUPERPOSITION, 0);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::ROTATE, 1);
+    let mut state_vector = StateVector::from_register(&qubit_register);
+
+    qubit_register.rotate_basis(Basis::Y, &[0, 1]);
+    state_vector.rotate_basis(Basis::Y, &[0, 1]);
+
+    assert_eq!(
+        StateVector::from_register(&qubit_register).marginal_probabilities(&[0, 1]),
+        state_vector.marginal_probabilities(&[0, 1])
+    );
+}
+
+#[test]
+#[should_panic]
+fn rotatebasis_shouldpanic_withunpairedbellqubit() {
+    StateVector::new(3).rotate_basis(Basis::Bell, &[0, 1, 2]);
+}
+
+#[test]
+fn measurequbits_shouldleaveconditionalsuperposition() {
+    for _ in 0..20 {
+        // (|00> + |11>)|+>, where measuring the first qubit decides the second.
+        let mut state_vector = StateVector::new(3);
+        state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+        state_vector.apply_cnot(0, 1);
+        state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 2);
+
+        let measured = state_vector.measure_qubits(&[0]);
+
+        let probabilities = state_vector.marginal_probabilities(&[1, 2]);
+        let second = if measured[0] { 0b00 } else { 0b10 };
+        assert!((probabilities[second] - 0.5).abs() < 1e-12);
+        assert!((probabilities[second | 0b01] - 0.5).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn evolve_shouldflipqubit_withquarterperiodoftransversefield() {
+    let mut state_vector = StateVector::new(1);
+
+    state_vector.evolve(&Observable::parse("X"), std::f64::consts::FRAC_PI_2);
+
+    // e^{-iXπ/2} is -iX.
+    assert!(state_vector.amplitudes[0].norm() < 1e-9);
+    assert!((state_vector.amplitudes[1] - Complex::new(0.0, -1.0)).norm() < 1e-9);
+}
+
+#[test]
+fn evolve_shouldconserveenergyandnorm() {
+    let hamiltonian = Observable::parse("ZZ + 0.7*XI + 0.3*IY");
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    let energy = hamiltonian.expectation(&state_vector);
+
+    state_vector.evolve(&hamiltonian, 1.3);
+
+    let norm: f64 = state_vector.amplitudes.iter().map(|a| a.norm_sqr()).sum();
+    assert!((norm - 1.0).abs() < 1e-9);
+    assert!((hamiltonian.expectation(&state_vector) - energy).abs() < 1e-9);
+}
+
+#[test]
+fn fidelity_shouldbehalf_betweenbellstateandzero() {
+    let bell = StateVector::ghz(2);
+
+    assert!((bell.fidelity(&StateVector::new(2)) - 0.5).abs() < 1e-12);
+    assert!(bell.trace_distance(&StateVector::ghz(2)) < 1e-6);
+}
+
+#[test]
+fn applyswap_shouldmatchthreecnots() {
+    let mut swapped = StateVector::new(3);
+    swapped.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    swapped.apply_gate(&QuantumGate::new(QuantumOperator::RY(0.7)), 2);
+    let mut expected = swapped.clone();
+
+    swapped.apply_swap(0, 2);
+    expected.apply_cnot(0, 2);
+    expected.apply_cnot(2, 0);
+    expected.apply_cnot(0, 2);
+
+    swapped
+        .amplitudes
+        .iter()
+        .zip(&expected.amplitudes)
+        .for_each(|(first, second)| assert!((first - second).norm() < 1e-12));
+}
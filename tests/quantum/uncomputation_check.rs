@@ -0,0 +1,83 @@
+use std::f64::consts::FRAC_PI_4;
+
+use rquant::quantum::types::{
+    qasm_statement::QasmStatement, quantum_operators::QuantumOperator,
+    uncomputation_check::UncomputationCheck,
+};
+
+fn compute_parity() -> Vec<QasmStatement> {
+    vec![
+        QasmStatement::QuantumRegister(3),
+        QasmStatement::Gate(QuantumOperator::SUPERPOSITION, 0),
+        QasmStatement::Gate(QuantumOperator::NOT, 1),
+        QasmStatement::ControlledNot(0, 2),
+        QasmStatement::ControlledNot(1, 2),
+        QasmStatement::ControlledPhase(2, 0),
+    ]
+}
+
+#[test]
+fn verify_shouldbeclean_withuncomputedancilla() {
+    let mut program = compute_parity();
+    program.extend([
+        QasmStatement::ControlledNot(1, 2),
+        QasmStatement::ControlledNot(0, 2),
+    ]);
+
+    assert_eq!(
+        UncomputationCheck::Clean,
+        UncomputationCheck::verify(3, &program, &[2])
+    );
+}
+
+#[test]
+fn verify_shouldreportbreakingstatement_withpartialuncomputation() {
+    let mut program = compute_parity();
+    program.push(QasmStatement::ControlledNot(1, 2));
+
+    // The ancilla is |0> again until the CNOT from the superposition entangles it for good.
+    assert_eq!(
+        UncomputationCheck::Dirty {
+            ancilla: 2,
+            statement: 3
+        },
+        UncomputationCheck::verify(3, &program, &[2])
+    );
+}
+
+#[test]
+fn verify_shouldreportlaststatement_withflippedancilla() {
+    let program = [
+        QasmStatement::Gate(QuantumOperator::NOT, 1),
+        QasmStatement::ControlledNot(1, 0),
+        QasmStatement::Gate(QuantumOperator::NOT, 0),
+        QasmStatement::ControlledNot(1, 0),
+    ];
+
+    assert_eq!(
+        UncomputationCheck::Dirty {
+            ancilla: 0,
+            statement: 3
+        },
+        UncomputationCheck::verify(2, &program, &[0])
+    );
+}
+
+#[test]
+fn verify_shouldbeunverifiable_withnoncliffordgate() {
+    let program = [
+        QasmStatement::Gate(QuantumOperator::SUPERPOSITION, 0),
+        QasmStatement::Gate(QuantumOperator::RZ(FRAC_PI_4), 0),
+    ];
+
+    assert_eq!(
+        UncomputationCheck::Unverifiable(1),
+        UncomputationCheck::verify(2, &program, &[1])
+    );
+}
+
+#[test]
+#[should_panic]
+fn verify_shouldpanic_withinvalidancilla() {
+    UncomputationCheck::verify(2, &[], &[2]);
+}
@@ -0,0 +1,49 @@
+use rquant::quantum::types::{
+    quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, qubit::Qubit,
+    unique_qubit::UniqueQubit,
+};
+
+#[test]
+fn applygate_shouldflipqubit_withnotgate() {
+    let qubit = UniqueQubit::new(Qubit::zero()).apply_gate(&QuantumGate::NOT);
+
+    assert!(!qubit.measure());
+}
+
+#[test]
+fn splitviacnot_shouldcopyonebasisstate() {
+    let state_vector = UniqueQubit::new(Qubit::one()).split_via_cnot();
+
+    assert_eq!(1.0, state_vector.amplitudes[0b11].norm_sqr());
+}
+
+#[test]
+fn splitviacnot_shouldentangle_withsuperposition() {
+    let state_vector = UniqueQubit::new(Qubit::zero())
+        .apply_gate(&QuantumGate::SUPERPOSITION)
+        .split_via_cnot();
+
+    assert!((state_vector.amplitudes[0b00].norm_sqr() - 0.5).abs() < 1e-12);
+    assert!((state_vector.amplitudes[0b11].norm_sqr() - 0.5).abs() < 1e-12);
+    assert_eq!(0.0, state_vector.amplitudes[0b01].norm_sqr());
+}
+
+#[test]
+fn applyunique_shouldruncircuit() {
+    let circuit = QuantumCircuit::new(2).add_gate(&QuantumGate::NOT, 1);
+
+    let qubits = circuit.apply_unique(vec![
+        UniqueQubit::new(Qubit::zero()),
+        UniqueQubit::new(Qubit::zero()),
+    ]);
+
+    let qubits: Vec<Qubit> = qubits.into_iter().map(UniqueQubit::into_qubit).collect();
+    assert_eq!(1.0, qubits[0].initial_position().norm_sqr());
+    assert_eq!(1.0, qubits[1].possible_position().norm_sqr());
+}
+
+#[test]
+#[should_panic]
+fn applyunique_shouldpanic_withwrongamountofqubits() {
+    QuantumCircuit::new(2).apply_unique(vec![UniqueQubit::new(Qubit::zero())]);
+}
@@ -0,0 +1,27 @@
+use rquant::{quantum::types::qubit::Qubit, simulation::types::simulation::Simulation};
+
+#[test]
+fn autoshots_shouldchoosemoreshots_withsmallerstderror() {
+    let qubit = Qubit::zero();
+
+    let coarse = qubit.auto_shots(0.05);
+    let fine = qubit.auto_shots(0.005);
+
+    assert!(fine > coarse);
+}
+
+#[test]
+fn autoshots_shouldreachtargetstderror_withsuperposition() {
+    let target_std_error = 0.01;
+
+    let shots = Qubit::zero().auto_shots(target_std_error);
+
+    // A superposition has a variance of 0.25, so it needs about 2500 shots.
+    assert!((2000..=2600).contains(&shots));
+}
+
+#[test]
+#[should_panic]
+fn autoshots_shouldpanic_withzerostderror() {
+    Qubit::zero().auto_shots(0.0);
+}
@@ -0,0 +1,68 @@
+use std::io::{self, Write};
+
+use rquant::{
+    quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    simulation::types::{
+        csv_writer::CsvWriter, executor::Executor, simulation_config::SimulationConfig,
+        simulation_outcome::SimulationOutcome,
+    },
+};
+
+struct FailingWriter;
+
+impl Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::other("disk full"))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn writecsv_shouldwriteheader_andbitstrings() {
+    let outcome = SimulationOutcome::new(2, vec![vec![true, false], vec![false, false]]);
+    let mut csv = vec![];
+
+    outcome.write_csv(&mut csv).unwrap();
+
+    assert_eq!(
+        "shot,bitstring\n0,01\n1,11\n",
+        String::from_utf8(csv).unwrap()
+    );
+}
+
+#[test]
+fn finish_shouldwriteheader_withoutshots() {
+    let csv = CsvWriter::new(vec![]).finish().unwrap();
+
+    assert_eq!("shot,bitstring\n", String::from_utf8(csv).unwrap());
+}
+
+#[test]
+fn onshotend_shouldstreamacceptedshots_fromexecutor() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 1)
+        .post_select(0, true);
+    let mut csv_writer = CsvWriter::new(vec![]);
+
+    Executor::new(SimulationConfig::new(3))
+        .with_observer(&mut csv_writer)
+        .observe(&circuit);
+
+    let csv = String::from_utf8(csv_writer.finish().unwrap()).unwrap();
+    assert_eq!("shot,bitstring\n0,01\n1,01\n2,01\n", csv);
+}
+
+#[test]
+fn finish_shouldreturnerror_whenwritingfails() {
+    let circuit = QuantumCircuit::new(1);
+    let mut csv_writer = CsvWriter::new(FailingWriter);
+
+    Executor::new(SimulationConfig::new(2))
+        .with_observer(&mut csv_writer)
+        .observe(&circuit);
+
+    assert!(csv_writer.finish().is_err());
+}
@@ -0,0 +1,232 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use rquant::{
+    noise::types::{
+        noise_channel::NoiseChannel, noise_model::NoiseModel, readout_error::ReadoutError,
+    },
+    quantum::types::{quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate},
+    simulation::types::{
+        backend_kind::BackendKind, cancellation_token::CancellationToken, executor::Executor,
+        observer::Observer, progress_hook::ProgressHook, simulation_config::SimulationConfig,
+    },
+};
+
+/// A running Beta posterior over the probability of measuring one qubit as $|0\rangle$.
+struct BetaEstimate {
+    target: usize,
+    alpha: f64,
+    beta: f64,
+}
+
+impl Observer for BetaEstimate {
+    fn on_measurement(&mut self, _shot: usize, target: usize, value: bool) {
+        if target == self.target {
+            if value {
+                self.alpha += 1.0;
+            } else {
+                self.beta += 1.0;
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct EventLog {
+    events: Vec<String>,
+}
+
+impl Observer for EventLog {
+    fn on_shot_start(&mut self, shot: usize) {
+        self.events.push(format!("start {shot}"));
+    }
+
+    fn on_measurement(&mut self, shot: usize, target: usize, value: bool) {
+        self.events.push(format!("measure {shot} {target} {value}"));
+    }
+
+    fn on_shot_end(&mut self, shot: usize, values: &[bool], accepted: bool) {
+        self.events
+            .push(format!("end {shot} {} {accepted}", values.len()));
+    }
+}
+
+#[test]
+fn observe_shouldreportevents_inorder() {
+    let circuit = QuantumCircuit::new(2).add_gate(&QuantumGate::NOT, 1);
+    let mut log = EventLog::default();
+
+    Executor::new(SimulationConfig::new(2))
+        .with_observer(&mut log)
+        .observe(&circuit);
+
+    assert_eq!(
+        vec![
+            "start 0",
+            "measure 0 0 true",
+            "measure 0 1 false",
+            "end 0 2 true",
+            "start 1",
+            "measure 1 0 true",
+            "measure 1 1 false",
+            "end 1 2 true",
+        ],
+        log.events
+    );
+}
+
+#[test]
+fn observe_shouldupdaterunningestimate_withoutbufferingshots() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    let mut estimate = BetaEstimate {
+        target: 0,
+        alpha: 1.0,
+        beta: 1.0,
+    };
+
+    Executor::new(SimulationConfig::new(2000))
+        .with_observer(&mut estimate)
+        .observe(&circuit);
+
+    let mean = estimate.alpha / (estimate.alpha + estimate.beta);
+    assert_eq!(2002.0, estimate.alpha + estimate.beta);
+    assert!((mean - 0.5).abs() < 0.05);
+}
+
+#[test]
+fn run_shouldnotifyeveryobserver_andkeepshots() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+    let noise_model = NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.2));
+    let (mut first, mut second) = (EventLog::default(), EventLog::default());
+
+    let outcome = Executor::new(SimulationConfig::new(50).with_noise_model(noise_model))
+        .with_observer(&mut first)
+        .with_observer(&mut second)
+        .run(&circuit);
+
+    assert_eq!(50, outcome.len());
+    assert_eq!(150, first.events.len());
+    assert_eq!(first.events.len(), second.events.len());
+}
+
+#[test]
+fn run_shouldflagrejectedshots_withpostselection() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .post_select(0, true);
+    let mut log = EventLog::default();
+
+    let outcome = Executor::new(SimulationConfig::new(200))
+        .with_observer(&mut log)
+        .run(&circuit);
+
+    let rejected = log
+        .events
+        .iter()
+        .filter(|event| event.ends_with("false") && event.starts_with("end"))
+        .count();
+    assert_eq!(200, outcome.total_shots);
+    assert_eq!(200 - rejected, outcome.len());
+}
+
+#[test]
+fn run_shouldrepeatshots_withsameseed() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 1);
+    let noise_model = NoiseModel::new()
+        .with_gate_noise(NoiseChannel::BitFlip(0.2))
+        .with_readout_error(ReadoutError::new(0.1, 0.1));
+    let run = |seed| {
+        Executor::new(
+            SimulationConfig::new(100)
+                .with_noise_model(noise_model.clone())
+                .with_seed(seed),
+        )
+        .run(&circuit)
+    };
+
+    assert_eq!(run(3).shots, run(3).shots);
+    assert_ne!(run(3).shots, run(4).shots);
+}
+
+/// Cancels a run once a number of shots have ended.
+struct CancelAfter {
+    shots: usize,
+    token: CancellationToken,
+}
+
+impl Observer for CancelAfter {
+    fn on_shot_end(&mut self, shot: usize, _values: &[bool], _accepted: bool) {
+        if shot + 1 == self.shots {
+            self.token.cancel();
+        }
+    }
+}
+
+#[test]
+fn run_shouldreturnpartialoutcome_whencancelled() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    let token = CancellationToken::new();
+    let mut canceller = CancelAfter {
+        shots: 10,
+        token: token.clone(),
+    };
+
+    let outcome = Executor::new(SimulationConfig::new(1000).with_cancellation(token))
+        .with_observer(&mut canceller)
+        .run(&circuit);
+
+    assert!(outcome.truncated);
+    assert_eq!(10, outcome.total_shots);
+}
+
+#[test]
+fn run_shouldrunnoshots_withexpiredtimeout() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+
+    let outcome =
+        Executor::new(SimulationConfig::new(1000).with_timeout(std::time::Duration::ZERO))
+            .run(&circuit);
+
+    assert!(outcome.truncated);
+    assert!(outcome.shots.is_empty());
+}
+
+#[test]
+fn run_shouldnotbetruncated_whenallshotsrun() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+
+    let outcome = Executor::new(
+        SimulationConfig::new(100)
+            .with_cancellation(CancellationToken::new())
+            .with_timeout(std::time::Duration::from_secs(60)),
+    )
+    .run(&circuit);
+
+    assert!(!outcome.truncated);
+    assert_eq!(100, outcome.total_shots);
+}
+
+#[test]
+fn run_shouldreportprogress_andusebackend_ofconfig() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_cnot(0, 1);
+    let completed = Arc::new(AtomicUsize::new(0));
+    let recorded = Arc::clone(&completed);
+    let config = SimulationConfig::new(100)
+        .with_backend(BackendKind::MatrixProductState {
+            max_bond_dimension: 2,
+        })
+        .with_progress(ProgressHook::new(move |shots, _| {
+            recorded.store(shots, Ordering::Relaxed);
+        }));
+
+    let outcome = Executor::new(config).run(&circuit);
+
+    assert_eq!(100, completed.load(Ordering::Relaxed));
+    assert!(outcome.shots.iter().all(|shot| shot[0] == shot[1]));
+}
@@ -0,0 +1,57 @@
+use rquant::{
+    quantum::types::{quantum_gate::QuantumGate, qubit_register::QubitRegister},
+    simulation::types::{
+        goodness_of_fit::GoodnessOfFit, simulation::Simulation,
+        simulation_config::SimulationConfig, simulation_report::SimulationReport,
+    },
+};
+
+#[test]
+fn chisquare_shouldmatchknownpvalues() {
+    // The p-values are from the closed forms of the chi-square distribution with 1 and 3 degrees
+    // of freedom.
+    let fit = GoodnessOfFit::chi_square(&[60, 40], &[0.48, 0.52]);
+    let small = GoodnessOfFit::chi_square(&[21, 29, 25, 25], &[0.25; 4]);
+
+    assert!((fit.statistic - 5.769_230_769).abs() < 1e-6);
+    assert_eq!(1, fit.degrees_of_freedom);
+    assert!((fit.p_value - 0.016_309).abs() < 1e-5);
+    assert!((small.statistic - 1.28).abs() < 1e-9);
+    assert!((small.p_value - 0.733_888).abs() < 1e-5);
+    assert!(!fit.is_consistent(0.05) && small.is_consistent(0.05));
+}
+
+#[test]
+fn chisquare_shouldhavezeropvalue_withunexpectedoutcome() {
+    let fit = GoodnessOfFit::chi_square(&[10, 1], &[1.0, 0.0]);
+
+    assert!(fit.statistic.is_infinite());
+    assert_eq!(0, fit.degrees_of_freedom);
+    assert_eq!(0.0, fit.p_value);
+}
+
+#[test]
+fn goodnessoffit_shouldfitexactdistribution_ofregister() {
+    let mut qubit_register = QubitRegister::new(2);
+    qubit_register.apply_single_qubit_gate(&QuantumGate::NOT, 1);
+    let all_zero: Vec<(String, f64)> = QubitRegister::new(2)
+        .probabilities()
+        .iter()
+        .enumerate()
+        .map(|(index, &probability)| (format!("{index:02b}"), probability))
+        .collect();
+
+    let report = qubit_register
+        .simulate_counts(&SimulationConfig::new(4000).with_seed(7))
+        .generate_report(qubit_register);
+    let fit = report.goodness_of_fit(&[
+        (String::from("00"), 0.25),
+        (String::from("01"), 0.25),
+        (String::from("10"), 0.25),
+        (String::from("11"), 0.25),
+    ]);
+    let skewed = report.goodness_of_fit(&all_zero);
+
+    assert!(fit.is_consistent(1e-6), "{fit}");
+    assert_eq!(0.0, skewed.p_value);
+}
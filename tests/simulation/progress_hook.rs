@@ -0,0 +1,54 @@
+use std::sync::{Arc, Mutex};
+
+use rquant::{
+    quantum::types::qubit_register::QubitRegister,
+    simulation::types::{
+        progress_hook::ProgressHook, simulation::Simulation, simulation_config::SimulationConfig,
+    },
+};
+
+type ProgressCalls = Arc<Mutex<Vec<(usize, usize)>>>;
+
+fn recording_hook() -> (ProgressHook, ProgressCalls) {
+    let calls = Arc::new(Mutex::new(vec![]));
+    let recorded = Arc::clone(&calls);
+    let hook = ProgressHook::new(move |completed, total| {
+        recorded.lock().unwrap().push((completed, total));
+    });
+    (hook, calls)
+}
+
+#[test]
+fn withprogress_shouldreportaboutonceperpercent() {
+    let (hook, calls) = recording_hook();
+    let config = SimulationConfig::new(1000).with_progress(hook);
+
+    QubitRegister::new(2).simulate_superposition(&config);
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(100, calls.len());
+    assert_eq!((10, 1000), calls[0]);
+    assert_eq!(Some(&(1000, 1000)), calls.last());
+}
+
+#[test]
+fn withprogress_shouldreportlastshot_ofiterator() {
+    let (hook, calls) = recording_hook();
+    let config = SimulationConfig::new(7).with_progress(hook);
+
+    let shots = QubitRegister::new(1).simulate_iter(&config).count();
+
+    assert_eq!(7, shots);
+    assert_eq!(
+        (1..=7).map(|completed| (completed, 7)).collect::<Vec<_>>(),
+        *calls.lock().unwrap()
+    );
+}
+
+#[test]
+fn eq_shouldcomparesharedcallbacks() {
+    let hook = ProgressHook::terminal();
+
+    assert_eq!(hook, hook.clone());
+    assert_ne!(hook, ProgressHook::terminal());
+}
@@ -0,0 +1,44 @@
+use rquant::{
+    quantum::types::qubit::Qubit,
+    simulation::types::{report::Report, simulation_report::SimulationReport},
+};
+
+#[test]
+fn generatereport_shouldcountoutcomes() {
+    let results = vec![true, false, false, false];
+
+    let report = results.generate_report(Qubit::zero());
+
+    assert_eq!(Qubit::zero().to_string(), report.subject);
+    assert_eq!(4, report.total);
+    assert_eq!(1, report.count("true"));
+    assert_eq!(3, report.count("false"));
+    assert_eq!(0.75, report.frequency("false"));
+}
+
+#[test]
+fn frequencies_shouldkeeporder_andbezero_withoutshots() {
+    let report = Report::new(
+        "empty",
+        vec![(String::from("1"), 0), (String::from("0"), 0)],
+    );
+
+    assert_eq!(
+        vec![(String::from("1"), 0.0), (String::from("0"), 0.0)],
+        report.frequencies()
+    );
+    assert_eq!(0, report.count("missing"));
+}
+
+#[test]
+fn display_shouldalignoutcomes() {
+    let report = Report::new(
+        "|0⟩",
+        vec![(String::from("true"), 3), (String::from("false"), 1)],
+    );
+
+    assert_eq!(
+        "Simulation report results for |0⟩\n  true  :  3 (75.00%)\n  false :  1 (25.00%)\n  total : 4",
+        report.to_string()
+    );
+}
@@ -0,0 +1,131 @@
+use rquant::{
+    noise::types::{noise_channel::NoiseChannel, noise_model::NoiseModel},
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, qubit::Qubit, qubit_register::QubitRegister,
+    },
+    simulation::types::{
+        backend_kind::BackendKind, simulation::Simulation, simulation_config::SimulationConfig,
+    },
+};
+
+#[test]
+fn simulate_shouldruncircuit_fromqubitstate() {
+    let circuit = QuantumCircuit::new(2).add_gate(&QuantumGate::NOT, 0);
+    let qubit_register = QubitRegister::from_bitstring("01").unwrap();
+
+    let outcome = qubit_register.simulate(&circuit, &SimulationConfig::new(50));
+
+    assert_eq!(50, outcome.len());
+    assert!(outcome.shots.iter().all(|shot| shot == &vec![false, false]));
+}
+
+#[test]
+fn simulate_shouldagree_acrossbackends() {
+    let circuit = QuantumCircuit::new(3)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(0.9)), 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(2.1)), 2);
+    let qubit_register = QubitRegister {
+        qubits: vec![
+            Qubit::from_bloch_angles(1.2, 0.4),
+            Qubit::from_bloch_angles(0.7, 2.5),
+            Qubit::one(),
+        ],
+    };
+    let backends = [
+        BackendKind::StateVector,
+        BackendKind::CompactStateVector,
+        BackendKind::MatrixProductState {
+            max_bond_dimension: 4,
+        },
+        BackendKind::DecisionDiagram,
+    ];
+
+    let distributions: Vec<Vec<f64>> = backends
+        .iter()
+        .map(|&backend| {
+            let config = SimulationConfig::new(20000)
+                .with_seed(3)
+                .with_backend(backend);
+            qubit_register
+                .simulate(&circuit, &config)
+                .marginal_probabilities(&[0, 1, 2])
+        })
+        .collect();
+
+    for distribution in &distributions[1..] {
+        distribution
+            .iter()
+            .zip(&distributions[0])
+            .for_each(|(probability, expected)| assert!((probability - expected).abs() < 0.02));
+    }
+}
+
+#[test]
+fn simulate_shouldcorrelatequbits_withcontrolledgate() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_cnot(0, 1);
+    let backends = [
+        BackendKind::StateVector,
+        BackendKind::MatrixProductState {
+            max_bond_dimension: 2,
+        },
+        BackendKind::DecisionDiagram,
+    ];
+
+    for backend in backends {
+        let config = SimulationConfig::new(200)
+            .with_seed(8)
+            .with_backend(backend);
+        let outcome = QubitRegister::new(2).simulate(&circuit, &config);
+
+        assert!(outcome.shots.iter().all(|shot| shot[0] == shot[1]));
+        assert!(outcome.shots.iter().any(|shot| shot[0]));
+        assert!(outcome.shots.iter().any(|shot| !shot[0]));
+    }
+}
+
+#[test]
+fn simulate_shouldpostselect() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .post_select(0, true);
+
+    let outcome = Qubit::zero().simulate(&circuit, &SimulationConfig::new(1000).with_seed(5));
+
+    assert!(outcome.shots.iter().all(|shot| shot[0]));
+    assert!((outcome.acceptance_rate() - 0.5).abs() < 0.1);
+}
+
+#[test]
+#[should_panic(expected = "Noise can only be simulated on the state vector backend")]
+fn simulate_shouldpanic_withnoiseondecisiondiagram() {
+    let config = SimulationConfig::new(10)
+        .with_noise_model(NoiseModel::new().with_gate_noise(NoiseChannel::BitFlip(0.1)))
+        .with_backend(BackendKind::DecisionDiagram);
+
+    Qubit::zero().simulate(&QuantumCircuit::new(1), &config);
+}
+
+#[test]
+fn simulate_shouldsampleghzstate_withmanyqubitmatrixproductstate() {
+    let circuit = (1..80).fold(
+        QuantumCircuit::new(80).add_gate(&QuantumGate::SUPERPOSITION, 0),
+        |circuit, target| circuit.add_cnot(target - 1, target),
+    );
+    let config = SimulationConfig::new(50).with_backend(BackendKind::MatrixProductState {
+        max_bond_dimension: 2,
+    });
+
+    let outcome = QubitRegister::new(80).simulate(&circuit, &config);
+
+    assert_eq!(50, outcome.len());
+    assert!(outcome
+        .shots
+        .iter()
+        .all(|shot| shot.iter().all(|&value| value == shot[0])));
+    assert!(outcome.shots.iter().any(|shot| shot[0]));
+    assert!(outcome.shots.iter().any(|shot| !shot[0]));
+}
@@ -0,0 +1,29 @@
+use rquant::{
+    quantum::types::{quantum_gate::QuantumGate, qubit::Qubit, qubit_register::QubitRegister},
+    simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+};
+
+#[test]
+fn simulatecounts_shouldkeyoutcomesbybitstring() {
+    let counts = QubitRegister::new(3).simulate_counts(&SimulationConfig::new(1000));
+
+    assert_eq!(1000, counts.values().sum::<u64>());
+    assert!(counts
+        .keys()
+        .all(|outcome| outcome.len() == 3 && outcome.chars().all(|bit| bit == '0' || bit == '1')));
+    assert!(counts.len() > 1);
+}
+
+#[test]
+fn simulatecounts_shouldcountsingleoutcome_withbasisstate() {
+    // A Hadamard of |+> is always |0>.
+    let plus = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    let qubit_register = QubitRegister {
+        qubits: vec![plus.clone(), plus],
+    };
+
+    let counts = qubit_register.simulate_counts(&SimulationConfig::new(100));
+
+    assert_eq!(Some(&100), counts.get("00"));
+    assert_eq!(1, counts.len());
+}
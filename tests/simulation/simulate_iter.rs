@@ -0,0 +1,35 @@
+use rquant::{
+    quantum::types::{qubit::Qubit, qubit_register::QubitRegister},
+    simulation::types::{simulation::Simulation, simulation_config::SimulationConfig},
+};
+
+#[test]
+fn simulateiter_shouldyieldeveryshot() {
+    let config = SimulationConfig::new(300);
+
+    let shots: Vec<Vec<bool>> = QubitRegister::new(3).simulate_iter(&config).collect();
+
+    assert_eq!(300, shots.len());
+    assert!(shots.iter().all(|shot| shot.len() == 3));
+}
+
+#[test]
+fn simulateiter_shouldmatchsimulatesuperposition_withseed() {
+    let config = SimulationConfig::new(200).with_seed(17);
+    let qubit = Qubit::zero();
+
+    let lazy: Vec<bool> = qubit.simulate_iter(&config).map(|shot| shot[0]).collect();
+
+    assert_eq!(qubit.simulate_superposition(&config), lazy);
+}
+
+#[test]
+fn simulateiter_shouldstopearly() {
+    let config = SimulationConfig::new(usize::MAX);
+
+    let first_one = Qubit::zero()
+        .simulate_iter(&config)
+        .position(|shot| !shot[0]);
+
+    assert!(first_one.is_some());
+}
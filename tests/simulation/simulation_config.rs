@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use rquant::{
+    noise::types::{noise_model::NoiseModel, readout_error::ReadoutError},
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, qubit::Qubit,
+        qubit_register::QubitRegister,
+    },
+    simulation::types::{
+        backend_kind::BackendKind, cancellation_token::CancellationToken, simulation::Simulation,
+        simulation_config::SimulationConfig,
+    },
+};
+
+#[test]
+fn new_shouldrunshots_withoutnoise() {
+    let config = SimulationConfig::new(250);
+
+    assert_eq!(250, Qubit::zero().simulate_superposition(&config).len());
+    assert_eq!(NoiseModel::new(), config.noise_model);
+    assert_eq!(BackendKind::StateVector, config.backend);
+    assert_eq!((None, 1), (config.seed, config.threads));
+}
+
+#[test]
+fn withseed_shouldrepeatoutcomes() {
+    let config = SimulationConfig::new(200).with_seed(42);
+    let qubit_register = QubitRegister::new(3);
+
+    assert_eq!(
+        qubit_register.simulate_superposition(&config),
+        qubit_register.simulate_superposition(&config)
+    );
+    assert_eq!(
+        qubit_register.simulate_counts(&config),
+        qubit_register.simulate_counts(&config)
+    );
+}
+
+#[test]
+fn withnoisemodel_shouldapplyreadouterror() {
+    // A Hadamard of |+> is always |0>, which the readout error always reads as |1>.
+    let plus = Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION);
+    let noise_model = NoiseModel::new().with_readout_error(ReadoutError::new(1.0, 0.0));
+    let config = SimulationConfig::new(100).with_noise_model(noise_model);
+
+    let outcomes = plus.simulate_superposition(&config);
+
+    assert!(outcomes.iter().all(|&outcome| !outcome));
+}
+
+#[test]
+#[should_panic(expected = "Invalid thread count")]
+fn withthreads_shouldpanic_withzerothreads() {
+    SimulationConfig::new(100).with_threads(0);
+}
+
+#[test]
+fn withthreads_shouldruneveryshot_andrepeatoutcomes_withseed() {
+    let config = SimulationConfig::new(1001).with_seed(9).with_threads(4);
+    let qubit_register = QubitRegister::new(2);
+
+    let outcomes = qubit_register.simulate_superposition(&config);
+    let counts = qubit_register.simulate_counts(&config);
+
+    assert_eq!(2002, outcomes.len());
+    assert_eq!(outcomes, qubit_register.simulate_superposition(&config));
+    assert_eq!(1001, counts.values().sum::<u64>());
+    assert_eq!(counts, qubit_register.simulate_counts(&config));
+}
+
+#[test]
+fn withcancellation_shouldrunnoshots_whencancelled() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let config = SimulationConfig::new(100).with_cancellation(token);
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+
+    let outcome = Qubit::zero().simulate(&circuit, &config);
+
+    assert!(outcome.truncated);
+    assert!(outcome.is_empty());
+    assert!(Qubit::zero().simulate_superposition(&config).is_empty());
+    assert_eq!(0, QubitRegister::new(2).simulate_iter(&config).count());
+}
+
+#[test]
+fn withtimeout_shouldnotbetruncated_whenallshotsrun() {
+    let config = SimulationConfig::new(100).with_timeout(Duration::from_secs(60));
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+
+    let outcome = Qubit::zero().simulate(&circuit, &config);
+
+    assert!(!outcome.truncated);
+    assert_eq!(100, outcome.len());
+}
@@ -0,0 +1,290 @@
+use rand::{rngs::StdRng, SeedableRng};
+use rquant::{
+    quantum::types::{
+        decision_diagram::DecisionDiagram, matrix_product_state::MatrixProductState,
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate, state_vector::StateVector,
+    },
+    simulation::types::simulation_outcome::SimulationOutcome,
+};
+
+#[test]
+fn postselect_shouldkeepmatchingshots() {
+    let outcome = SimulationOutcome::new(
+        2,
+        vec![
+            vec![true, true],
+            vec![false, true],
+            vec![true, false],
+            vec![true, false],
+        ],
+    );
+
+    let result = outcome.post_select(1, false);
+
+    assert_eq!(2, result.len());
+    assert_eq!(4, result.total_shots);
+    assert_eq!(0.5, result.acceptance_rate());
+    assert_eq!(1.0, result.probability(0));
+}
+
+#[test]
+fn postselect_shouldleaveemptyoutcome_withoutmatchingshots() {
+    let outcome = SimulationOutcome::new(1, vec![vec![true], vec![true]]);
+
+    let result = outcome.post_select(0, false);
+
+    assert!(result.is_empty());
+    assert_eq!(0.0, result.probability(0));
+    assert_eq!(0.0, result.acceptance_rate());
+}
+
+#[test]
+fn fromcircuit_shouldapplycircuitpostselections() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 1)
+        .post_select(1, true);
+
+    let outcome = SimulationOutcome::from_circuit(&circuit, 2000);
+
+    assert!(outcome.shots.iter().all(|shot| shot[1]));
+    assert!((outcome.acceptance_rate() - 0.5).abs() < 0.1);
+    assert!((outcome.probability(0) - 0.5).abs() < 0.1);
+}
+
+#[test]
+fn fromcircuit_shouldmeasurebasisstate() {
+    let circuit = QuantumCircuit::new(2).add_gate(&QuantumGate::NOT, 1);
+
+    let outcome = SimulationOutcome::from_circuit(&circuit, 50);
+
+    assert!(outcome.shots.iter().all(|shot| shot == &vec![true, false]));
+}
+
+#[test]
+#[should_panic]
+fn new_shouldpanic_withincompleteshot() {
+    SimulationOutcome::new(2, vec![vec![true]]);
+}
+
+#[test]
+fn marginalprobabilities_shouldcountsampledshots() {
+    let outcome = SimulationOutcome::new(
+        3,
+        vec![
+            vec![true, false, true],
+            vec![true, false, false],
+            vec![false, true, true],
+            vec![true, false, true],
+        ],
+    );
+
+    let probabilities = outcome.marginal_probabilities(&[0, 1]);
+
+    assert_eq!(vec![0.0, 0.75, 0.25, 0.0], probabilities);
+}
+
+#[test]
+fn perqubitmarginals_shouldcountfrequencies_withintervals() {
+    let outcome = SimulationOutcome::new(
+        2,
+        vec![
+            vec![true, false],
+            vec![true, true],
+            vec![true, false],
+            vec![true, true],
+        ],
+    );
+
+    let marginals = outcome.per_qubit_marginals();
+
+    assert_eq!(2, marginals.len());
+    assert_eq!((1.0, 0.0), (marginals[0].zero, marginals[0].one));
+    assert_eq!(1.0, marginals[0].confidence_interval.1);
+    assert!(marginals[0].confidence_interval.0 > 0.4);
+    assert_eq!((0.5, 0.5), (marginals[1].zero, marginals[1].one));
+    let (lower, upper) = marginals[1].confidence_interval;
+    assert!(lower < 0.5 && upper > 0.5);
+    assert!((0.5 - lower - (upper - 0.5)).abs() < 1e-12);
+}
+
+#[test]
+fn perqubitmarginals_shouldnarrowintervals_withmoreshots() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::SUPERPOSITION, 0);
+    let width = |shots| {
+        let (lower, upper) = SimulationOutcome::from_circuit(&circuit, shots).per_qubit_marginals()
+            [0]
+        .confidence_interval;
+        upper - lower
+    };
+
+    assert!(width(2000) < width(20));
+}
+
+#[test]
+fn perqubitmarginals_shouldcovereveryprobability_withoutshots() {
+    let marginals = SimulationOutcome::new(3, vec![]).per_qubit_marginals();
+
+    assert_eq!(3, marginals.len());
+    assert!(marginals
+        .iter()
+        .all(|marginal| marginal.confidence_interval == (0.0, 1.0)));
+}
+
+#[test]
+fn correlation_shouldbeone_withbellstateshots() {
+    let outcome = SimulationOutcome::new(
+        2,
+        vec![
+            vec![true, true],
+            vec![false, false],
+            vec![false, false],
+            vec![true, true],
+        ],
+    );
+
+    assert_eq!(1.0, outcome.correlation(0, 1));
+    assert!((outcome.mutual_information(0, 1) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn correlation_shouldbezero_withindependentqubits() {
+    let outcome = SimulationOutcome::new(
+        2,
+        vec![
+            vec![true, true],
+            vec![true, false],
+            vec![false, true],
+            vec![false, false],
+        ],
+    );
+
+    assert_eq!(0.0, outcome.correlation(0, 1));
+    assert!(outcome.mutual_information(0, 1).abs() < 1e-12);
+}
+
+#[test]
+fn correlation_shouldbenegative_withanticorrelatedqubits() {
+    let circuit = QuantumCircuit::new(2).add_gate(&QuantumGate::NOT, 0);
+
+    let outcome = SimulationOutcome::from_circuit(&circuit, 10);
+
+    assert!((outcome.correlation(0, 1) + 1.0).abs() < 1e-12);
+    // Deterministic qubits share no information, even when they disagree.
+    assert!(outcome.mutual_information(0, 1).abs() < 1e-12);
+}
+
+#[test]
+fn executeqasmstream_shouldcorrelatebellstate_withstatevector() {
+    let program =
+        "OPENQASM 2.0;\nqreg q[2];\ncreg c[2];\nh q[0];\ncx q[0], q[1];\nmeasure q -> c;\n";
+
+    let outcome =
+        SimulationOutcome::execute_qasm_stream(program.as_bytes(), &StateVector::new(2), 200)
+            .unwrap();
+
+    assert_eq!(200, outcome.len());
+    assert!(outcome.shots.iter().all(|shot| shot[0] == shot[1]));
+    assert!((outcome.probability(0) - 0.5).abs() < 0.15);
+}
+
+#[test]
+fn executeqasmstream_shouldrunlongprogram_withcompactbackend() {
+    let mut program = String::from("qreg q[40];\ncreg c[40];\nh q[0];\n");
+    for target in 1..40 {
+        program.push_str(&format!("cx q[{}], q[{target}];\n", target - 1));
+    }
+    for _ in 0..1000 {
+        program.push_str("z q[5];\nz q[5];\n");
+    }
+    program.push_str("measure q -> c;\n");
+
+    let outcome = SimulationOutcome::execute_qasm_stream(
+        program.as_bytes(),
+        &MatrixProductState::new(40, 2),
+        20,
+    )
+    .unwrap();
+
+    assert!(outcome
+        .shots
+        .iter()
+        .all(|shot| shot.iter().all(|&value| value == shot[0])));
+}
+
+#[test]
+fn executeqasmstream_shouldleaveunmeasuredbitstrue() {
+    let program = "qreg q[1];\ncreg c[2];\nx q[0];\nmeasure q[0] -> c[1];\n";
+
+    let outcome =
+        SimulationOutcome::execute_qasm_stream(program.as_bytes(), &DecisionDiagram::new(1), 10)
+            .unwrap();
+
+    assert!(outcome.shots.iter().all(|shot| shot == &vec![true, false]));
+}
+
+#[test]
+fn executeqasmstream_shouldreturnerror_withgateaftermeasurement() {
+    let program = "qreg q[1];\ncreg c[1];\nmeasure q[0] -> c[0];\nx q[0];\n";
+
+    let error = SimulationOutcome::execute_qasm_stream(program.as_bytes(), &StateVector::new(1), 1)
+        .unwrap_err();
+
+    assert_eq!(
+        "line 4: gate on qubit 0 after it is measured",
+        error.message
+    );
+}
+
+#[test]
+fn executeqasmstream_shouldreturnerror_withmorequbitsthanbackend() {
+    let program = "qreg q[3];\ncreg c[3];\n";
+
+    let error = SimulationOutcome::execute_qasm_stream(program.as_bytes(), &StateVector::new(2), 1)
+        .unwrap_err();
+
+    assert!(error.message.contains("more qubits than the backend holds"));
+}
+
+#[test]
+fn counts_shouldkeyshotsbybitstring() {
+    let outcome = SimulationOutcome::new(
+        2,
+        vec![vec![true, false], vec![true, false], vec![false, false]],
+    );
+
+    let counts = outcome.counts();
+
+    assert_eq!(2, counts.len());
+    assert_eq!(Some(&2), counts.get("01"));
+    assert_eq!(Some(&1), counts.get("11"));
+}
+
+#[test]
+fn fromcircuitwith_shouldrepeatshots_withsameseed() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::SUPERPOSITION, 0)
+        .add_gate(&QuantumGate::SUPERPOSITION, 1);
+    let sample = |seed| {
+        SimulationOutcome::from_circuit_with(&circuit, 100, &mut StdRng::seed_from_u64(seed))
+    };
+
+    assert_eq!(sample(2), sample(2));
+    assert_ne!(sample(2), sample(3));
+}
+
+#[test]
+fn executeqasmstreamwith_shouldrepeatshots_withsameseed() {
+    let program = "OPENQASM 2.0; qreg q[2]; creg c[2]; h q[0]; h q[1]; measure q -> c;";
+    let sample = |seed| {
+        SimulationOutcome::execute_qasm_stream_with(
+            program.as_bytes(),
+            &MatrixProductState::new(2, 2),
+            100,
+            &mut StdRng::seed_from_u64(seed),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(sample(4), sample(4));
+}
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use rquant::{
+    quantum::types::{qubit::Qubit, qubit_register::QubitRegister},
+    simulation::types::simulation_report::SimulationReport,
+};
+
+#[test]
+fn tohtml_shouldtabulatecounts_andpercentages() {
+    let results = vec![true, false, false, false];
+
+    let html = results.to_html(Qubit::zero());
+
+    assert!(html.starts_with("<div class=\"rquant-report\">"));
+    assert!(html.contains("<tr><td>true</td><td>1</td><td>25.00%</td></tr>"));
+    assert!(html.contains("<tr><td>false</td><td>3</td><td>75.00%</td></tr>"));
+    assert!(html.contains("<tr><td>total</td><td>4</td><td></td></tr>"));
+    assert_eq!(2, html.matches("<rect ").count());
+    assert!(html.ends_with("</svg></div>"));
+}
+
+#[test]
+fn tohtml_shouldreportzeropercent_withoutresults() {
+    let html = Vec::<bool>::new().to_html(QubitRegister::new(2));
+
+    assert!(html.contains("<tr><td>true</td><td>0</td><td>0.00%</td></tr>"));
+    assert!(!html.contains("NaN"));
+}
+
+#[test]
+fn tohtml_shouldtabulatebitstrings_inorder() {
+    let counts = HashMap::from([(String::from("11"), 3), (String::from("00"), 1)]);
+
+    let html = counts.to_html(QubitRegister::new(2));
+
+    let zero = html.find("<tr><td>00</td><td>1</td><td>25.00%</td></tr>");
+    let one = html.find("<tr><td>11</td><td>3</td><td>75.00%</td></tr>");
+    assert!(zero.is_some() && one.is_some() && zero < one);
+    assert!(html.contains("<tr><td>total</td><td>4</td><td></td></tr>"));
+    assert_eq!(2, html.matches("<rect ").count());
+}
@@ -0,0 +1,69 @@
+use rquant::{
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator, state_vector::StateVector,
+    },
+    simulation::types::state_trace::StateTrace,
+};
+
+#[test]
+fn record_shouldrecordchangedamplitudes_aftereachoperation() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 1)
+        .add_gate(&QuantumGate::PHASE, 0);
+
+    let trace = StateTrace::record(&circuit, &StateVector::new(2));
+
+    assert_eq!(2, trace.steps.len());
+    let changed: Vec<usize> = trace.steps[0].changes.iter().map(|c| c.index).collect();
+    assert_eq!(vec![0b00, 0b01], changed);
+    assert_eq!(1.0, trace.steps[0].changes[1].after.re);
+    // A phase on |0> for qubit 0 does nothing.
+    assert!(trace.steps[1].changes.is_empty());
+}
+
+#[test]
+fn record_shouldnotchangeinitialstate() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::NOT, 0);
+    let state_vector = StateVector::new(1);
+
+    StateTrace::record(&circuit, &state_vector);
+
+    assert_eq!(1.0, state_vector.amplitudes[0].re);
+}
+
+#[test]
+fn largeststep_shouldfindstepthatmovedstatemost() {
+    let circuit = QuantumCircuit::new(1)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(0.1)), 0)
+        .add_gate(&QuantumGate::NOT, 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RX(0.2)), 0);
+
+    let trace = StateTrace::record(&circuit, &StateVector::new(1));
+
+    assert_eq!(Some(1), trace.largest_step());
+}
+
+#[test]
+fn largeststep_shouldbenone_withoutchanges() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::PHASE, 0);
+
+    assert_eq!(
+        None,
+        StateTrace::record(&circuit, &StateVector::new(1)).largest_step()
+    );
+}
+
+#[test]
+fn display_shouldprinteverystep() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::NOT, 1)
+        .add_gate(&QuantumGate::PHASE, 0);
+
+    let trace = StateTrace::record(&circuit, &StateVector::new(2));
+
+    assert_eq!(
+        "step 0: NOT on qubit 1\n  |00〉 1 -> 0 (1.0000)\n  |01〉 0 -> 1 (1.0000)\nstep 1: PHASE on qubit 0 (no change)",
+        trace.to_string()
+    );
+}
@@ -0,0 +1,51 @@
+use std::f64::consts::PI;
+
+use rquant::{
+    quantum::types::{
+        quantum_circuit::QuantumCircuit, quantum_gate::QuantumGate,
+        quantum_operators::QuantumOperator,
+    },
+    simulation::types::{simulation_config::SimulationConfig, sweep::Sweep},
+};
+
+#[test]
+fn linspace_shouldincludebothends() {
+    assert_eq!(vec![0.0, 0.5, 1.0], Sweep::linspace(0.0, 1.0, 3));
+    assert_eq!(vec![2.0], Sweep::linspace(2.0, 3.0, 1));
+}
+
+#[test]
+fn run_shouldfollowrabicurve() {
+    let circuit = QuantumCircuit::new(1).add_gate(&QuantumGate::new(QuantumOperator::RX(0.0)), 0);
+
+    let sweep = Sweep::run(
+        &circuit,
+        &[Sweep::linspace(0.0, PI, 5)],
+        &SimulationConfig::new(4000).with_seed(11),
+    );
+
+    assert_eq!(5, sweep.reports.len());
+    for (point, frequency) in sweep.frequencies("1") {
+        let expected = (point[0] / 2.0).sin().powi(2);
+        assert!((frequency - expected).abs() < 0.05);
+    }
+    assert_eq!("parameters (3.1416)", sweep.reports[4].subject);
+}
+
+#[test]
+fn run_shouldcovergrid_withfirstparameterfastest() {
+    let circuit = QuantumCircuit::new(2)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(0.0)), 0)
+        .add_gate(&QuantumGate::new(QuantumOperator::RY(0.0)), 1);
+
+    let sweep = Sweep::run(
+        &circuit,
+        &[vec![0.0, PI], vec![0.0, PI, 0.5]],
+        &SimulationConfig::new(10),
+    );
+
+    assert_eq!(6, sweep.points.len());
+    assert_eq!(vec![PI, 0.0], sweep.points[1]);
+    assert_eq!(vec![0.0, PI], sweep.points[2]);
+    assert_eq!(10, sweep.reports[3].total);
+}
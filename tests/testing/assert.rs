@@ -0,0 +1,77 @@
+use num_complex::Complex;
+use rquant::{
+    assert_prob, assert_state_close, assert_unitary,
+    quantum::types::{
+        quantum_gate::QuantumGate, quantum_operators::QuantumOperator,
+        quantum_position::QuantumPosition, qubit::Qubit, qubit_register::QubitRegister,
+        state_vector::StateVector,
+    },
+};
+
+#[test]
+fn assertstateclose_shouldpass_withmatchingregister() {
+    let qubit_register = QubitRegister {
+        qubits: vec![Qubit::zero(), Qubit::one()],
+    };
+
+    assert_state_close!(
+        qubit_register,
+        [0.0, 1.0, 0.0, 0.0].map(|re| Complex::new(re, 0.0)),
+        1e-9
+    );
+}
+
+#[test]
+fn assertstateclose_shouldpass_withbellstate() {
+    let mut state_vector = StateVector::new(2);
+    state_vector.apply_gate(&QuantumGate::SUPERPOSITION, 0);
+    state_vector.apply_cnot(0, 1);
+    let half = std::f64::consts::FRAC_1_SQRT_2;
+
+    assert_state_close!(
+        state_vector,
+        [half, 0.0, 0.0, half].map(|re| Complex::new(re, 0.0)),
+        1e-9
+    );
+}
+
+#[test]
+#[should_panic]
+fn assertstateclose_shouldpanic_withdifferentstate() {
+    assert_state_close!(
+        Qubit::one(),
+        [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)],
+        1e-9
+    );
+}
+
+#[test]
+fn assertprob_shouldpass_withsuperposition() {
+    assert_prob!(
+        Qubit::zero().apply_gate(&QuantumGate::SUPERPOSITION),
+        0.5,
+        0.01
+    );
+}
+
+#[test]
+#[should_panic]
+fn assertprob_shouldpanic_withoutoftoleranceprobability() {
+    assert_prob!(Qubit::zero(), 0.5, 0.01);
+}
+
+#[test]
+fn assertunitary_shouldpass_withrotation() {
+    assert_unitary!(QuantumGate::new(QuantumOperator::RY(0.3)));
+}
+
+#[test]
+#[should_panic]
+fn assertunitary_shouldpanic_withnonunitarygate() {
+    let gate = QuantumGate {
+        operator: QuantumOperator::NOT,
+        transform: [QuantumPosition::ZERO, QuantumPosition::ZERO],
+    };
+
+    assert_unitary!(gate);
+}